@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use api_models::analytics::{
     active_payments::{ActivePaymentsMetrics, ActivePaymentsMetricsBucketIdentifier},
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use time::PrimitiveDateTime;
 
@@ -47,7 +47,7 @@ where
     T: AnalyticsDataSource + ActivePaymentsMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
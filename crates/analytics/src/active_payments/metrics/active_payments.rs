@@ -1,7 +1,7 @@
 use std::collections::HashSet;
 
 use api_models::analytics::{
-    active_payments::ActivePaymentsMetricsBucketIdentifier, Granularity, TimeRange,
+    active_payments::ActivePaymentsMetricsBucketIdentifier, TimeRange, TimeSeries,
 };
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
@@ -22,7 +22,7 @@ where
     T: AnalyticsDataSource + super::ActivePaymentsMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -0,0 +1,128 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    alerts::AlertMetric, payments::PaymentMetrics, payouts::PayoutMetrics, refunds::RefundMetrics,
+    GetPaymentMetricRequest, GetPayoutMetricRequest, GetRefundMetricRequest, TimeRange,
+};
+use bigdecimal::ToPrimitive;
+
+use crate::{enums::AuthInfo, errors::AnalyticsResult, AnalyticsProvider};
+
+/// Computes the value of an alert metric over the time range, without any grouping.
+///
+/// Returns `None` when there is no data to compute a rate or an average from, additive metrics
+/// evaluate to zero instead.
+pub async fn get_alert_metric_value(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    metric: &AlertMetric,
+    time_range: TimeRange,
+) -> AnalyticsResult<Option<f64>> {
+    let value = match metric {
+        AlertMetric::Payments { metric, filters } => {
+            let req = GetPaymentMetricRequest {
+                time_series: None,
+                time_range,
+                time_zone: None,
+                group_by_names: vec![],
+                filters: filters.clone(),
+                metrics: HashSet::from([metric.clone()]),
+                distribution: None,
+                delta: false,
+            };
+            crate::payments::get_metrics(pool, &None, auth, req)
+                .await?
+                .query_data
+                .into_iter()
+                .next()
+                .and_then(|bucket| {
+                    let values = bucket.values;
+                    match metric {
+                        PaymentMetrics::PaymentSuccessRate => values.payment_success_rate,
+                        PaymentMetrics::PaymentCount => {
+                            values.payment_count.and_then(|count| count.to_f64())
+                        }
+                        PaymentMetrics::PaymentSuccessCount => values
+                            .payment_success_count
+                            .and_then(|count| count.to_f64()),
+                        PaymentMetrics::PaymentProcessedAmount => values
+                            .payment_processed_amount
+                            .and_then(|amount| amount.to_f64()),
+                        PaymentMetrics::AvgTicketSize => values.avg_ticket_size,
+                        PaymentMetrics::RetriesCount => {
+                            values.retries_count.and_then(|count| count.to_f64())
+                        }
+                        PaymentMetrics::ConnectorSuccessRate => values.connector_success_rate,
+                        _ => None,
+                    }
+                })
+        }
+        AlertMetric::Refunds { metric, filters } => {
+            let req = GetRefundMetricRequest {
+                time_series: None,
+                time_range,
+                time_zone: None,
+                group_by_names: vec![],
+                filters: filters.clone(),
+                metrics: HashSet::from([metric.clone()]),
+                distribution: None,
+                delta: false,
+            };
+            crate::refunds::get_metrics(pool, &None, auth, req)
+                .await?
+                .query_data
+                .into_iter()
+                .next()
+                .and_then(|bucket| {
+                    let values = bucket.values;
+                    match metric {
+                        RefundMetrics::RefundSuccessRate => values.refund_success_rate,
+                        RefundMetrics::RefundCount => {
+                            values.refund_count.and_then(|count| count.to_f64())
+                        }
+                        RefundMetrics::RefundSuccessCount => {
+                            values.refund_success_count.and_then(|count| count.to_f64())
+                        }
+                        RefundMetrics::RefundProcessedAmount => values
+                            .refund_processed_amount
+                            .and_then(|amount| amount.to_f64()),
+                        _ => None,
+                    }
+                })
+        }
+        AlertMetric::Payouts { metric, filters } => {
+            let req = GetPayoutMetricRequest {
+                time_series: None,
+                time_range,
+                time_zone: None,
+                group_by_names: vec![],
+                filters: filters.clone(),
+                metrics: HashSet::from([metric.clone()]),
+                distribution: None,
+                delta: false,
+            };
+            crate::payouts::get_metrics(pool, &None, auth, req)
+                .await?
+                .query_data
+                .into_iter()
+                .next()
+                .and_then(|bucket| {
+                    let values = bucket.values;
+                    match metric {
+                        PayoutMetrics::PayoutSuccessRate => values.payout_success_rate,
+                        PayoutMetrics::PayoutCount => {
+                            values.payout_count.and_then(|count| count.to_f64())
+                        }
+                        PayoutMetrics::PayoutProcessedAmount => values
+                            .payout_processed_amount
+                            .and_then(|amount| amount.to_f64()),
+                        PayoutMetrics::PayoutAvgTimeToFulfilment => {
+                            values.payout_avg_time_to_fulfilment_in_secs
+                        }
+                    }
+                })
+        }
+    };
+
+    Ok(value.or_else(|| metric.is_additive().then_some(0.0)))
+}
@@ -22,6 +22,7 @@ use super::{
 use crate::{
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
+    query::normalize_time_range,
     types::FiltersError,
     AnalyticsProvider,
 };
@@ -94,6 +95,13 @@ pub async fn get_api_event_metrics(
     merchant_id: &common_utils::id_type::MerchantId,
     req: GetApiEventMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<ApiMetricsBucketResponse>> {
+    let req = GetApiEventMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<ApiEventMetricsBucketIdentifier, ApiEventMetricRow> =
         HashMap::new();
 
@@ -117,7 +125,7 @@ pub async fn get_api_event_metrics(
                         &req.group_by_names.clone(),
                         &merchant_id_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        req.time_series,
                         &req.time_range,
                     )
                     .await
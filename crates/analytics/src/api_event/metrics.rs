@@ -2,7 +2,7 @@ use api_models::analytics::{
     api_event::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use time::PrimitiveDateTime;
 
@@ -45,7 +45,7 @@ where
         dimensions: &[ApiEventDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &ApiEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>>;
@@ -57,7 +57,7 @@ where
     T: AnalyticsDataSource + ApiEventMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -66,7 +66,7 @@ where
         dimensions: &[ApiEventDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &ApiEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventFilters, ApiEventMetricsBucketIdentifier},
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
@@ -23,7 +23,7 @@ where
     T: AnalyticsDataSource + super::ApiEventMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -32,7 +32,7 @@ where
         _dimensions: &[ApiEventDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &ApiEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
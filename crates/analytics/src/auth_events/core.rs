@@ -21,6 +21,7 @@ use crate::{
     auth_events::AuthEventMetricAccumulator,
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
+    query::normalize_time_range,
     AnalyticsProvider,
 };
 
@@ -30,6 +31,13 @@ pub async fn get_metrics(
     merchant_id: &common_utils::id_type::MerchantId,
     req: GetAuthEventMetricRequest,
 ) -> AnalyticsResult<AuthEventMetricsResponse<MetricsBucketResponse>> {
+    let req = GetAuthEventMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         AuthEventMetricsBucketIdentifier,
         AuthEventMetricsAccumulator,
@@ -47,7 +55,7 @@ pub async fn get_metrics(
                     &req.group_by_names.clone(),
                     &merchant_id_scoped,
                     &req.filters,
-                    req.time_series.map(|t| t.granularity),
+                    req.time_series,
                     &req.time_range,
                 )
                 .await
@@ -4,7 +4,7 @@ use api_models::analytics::{
     auth_events::{
         AuthEventDimensions, AuthEventFilters, AuthEventMetrics, AuthEventMetricsBucketIdentifier,
     },
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
@@ -89,7 +89,7 @@ where
         merchant_id: &common_utils::id_type::MerchantId,
         dimensions: &[AuthEventDimensions],
         filters: &AuthEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>>;
@@ -101,7 +101,7 @@ where
     T: AnalyticsDataSource + AuthEventMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -110,7 +110,7 @@ where
         merchant_id: &common_utils::id_type::MerchantId,
         dimensions: &[AuthEventDimensions],
         filters: &AuthEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>> {
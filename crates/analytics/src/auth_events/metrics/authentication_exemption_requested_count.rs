@@ -2,7 +2,7 @@ use std::collections::HashSet;
 
 use api_models::analytics::{
     auth_events::{AuthEventDimensions, AuthEventFilters, AuthEventMetricsBucketIdentifier},
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
@@ -23,7 +23,7 @@ where
     T: AnalyticsDataSource + super::AuthEventMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -32,7 +32,7 @@ where
         merchant_id: &common_utils::id_type::MerchantId,
         dimensions: &[AuthEventDimensions],
         filters: &AuthEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>> {
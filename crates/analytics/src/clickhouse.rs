@@ -15,10 +15,16 @@ use super::{
     payments::{
         distribution::PaymentDistributionRow, filters::PaymentFilterRow, metrics::PaymentMetricRow,
     },
+    payouts::{
+        distribution::PayoutDistributionRow,
+        filters::PayoutFilterRow,
+        metrics::{PayoutFulfilmentTime, PayoutMetricRow},
+    },
     query::{Aggregate, ToSql, Window},
     refunds::{
         distribution::RefundDistributionRow, filters::RefundFilterRow, metrics::RefundMetricRow,
     },
+    reports::ReportRow,
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
 };
@@ -137,6 +143,7 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::PaymentSessionized
             | AnalyticsCollection::Refund
             | AnalyticsCollection::RefundSessionized
+            | AnalyticsCollection::Payout
             | AnalyticsCollection::FraudCheck
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::PaymentIntentSessionized
@@ -177,6 +184,9 @@ impl super::payment_intents::metrics::PaymentIntentMetricAnalytics for Clickhous
 impl super::refunds::metrics::RefundMetricAnalytics for ClickhouseClient {}
 impl super::refunds::filters::RefundFilterAnalytics for ClickhouseClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for ClickhouseClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for ClickhouseClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for ClickhouseClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for ClickhouseClient {}
 impl super::frm::metrics::FrmMetricAnalytics for ClickhouseClient {}
 impl super::frm::filters::FrmFilterAnalytics for ClickhouseClient {}
 impl super::sdk_events::filters::SdkEventFilterAnalytics for ClickhouseClient {}
@@ -185,6 +195,11 @@ impl super::sdk_events::events::SdkEventsFilterAnalytics for ClickhouseClient {}
 impl super::active_payments::metrics::ActivePaymentsMetricAnalytics for ClickhouseClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for ClickhouseClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for ClickhouseClient {}
+impl super::reports::ReportAnalytics for ClickhouseClient {
+    fn report_column(column: &'static str) -> String {
+        column.to_string()
+    }
+}
 impl super::api_event::events::ApiLogsFilterAnalytics for ClickhouseClient {}
 impl super::api_event::filters::ApiEventFilterAnalytics for ClickhouseClient {}
 impl super::api_event::metrics::ApiEventMetricAnalytics for ClickhouseClient {}
@@ -348,6 +363,36 @@ impl TryInto<FrmFilterRow> for serde_json::Value {
         ))
     }
 }
+impl TryInto<PayoutMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutFilterRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutDistributionRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutDistributionRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutDistributionRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<DisputeMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -458,6 +503,16 @@ impl TryInto<ActivePaymentsMetricRow> for serde_json::Value {
     }
 }
 
+impl TryInto<ReportRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ReportRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ReportRow in clickhouse results",
+        ))
+    }
+}
+
 impl ToSql<ClickhouseClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.assume_utc().unix_timestamp().to_string())
@@ -471,6 +526,7 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::PaymentSessionized => Ok("sessionizer_payment_attempts".to_string()),
             Self::Refund => Ok("refunds".to_string()),
             Self::RefundSessionized => Ok("sessionizer_refunds".to_string()),
+            Self::Payout => Ok("payout".to_string()),
             Self::FraudCheck => Ok("fraud_check".to_string()),
             Self::SdkEvents => Ok("sdk_events_audit".to_string()),
             Self::SdkEventsAnalytics => Ok("sdk_events".to_string()),
@@ -489,6 +545,12 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
     }
 }
 
+impl ToSql<ClickhouseClient> for PayoutFulfilmentTime {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok("dateDiff('second', created_at, last_modified_at)".to_string())
+    }
+}
+
 impl<T> ToSql<ClickhouseClient> for Aggregate<T>
 where
     T: ToSql<ClickhouseClient>,
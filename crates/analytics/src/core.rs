@@ -46,6 +46,11 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_dispute_dimensions(),
         },
+        AnalyticsDomain::Payouts => GetInfoResponse {
+            metrics: utils::get_payout_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_payout_dimensions(),
+        },
     };
     Ok(info)
 }
@@ -22,7 +22,9 @@ use crate::{
     disputes::DisputeMetricAccumulator,
     enums::AuthInfo,
     errors::{AnalyticsError, AnalyticsResult},
-    metrics, AnalyticsProvider,
+    metrics,
+    query::normalize_time_range,
+    AnalyticsProvider,
 };
 
 pub async fn get_metrics(
@@ -30,6 +32,13 @@ pub async fn get_metrics(
     auth: &AuthInfo,
     req: GetDisputeMetricRequest,
 ) -> AnalyticsResult<DisputesMetricsResponse<DisputeMetricsBucketResponse>> {
+    let req = GetDisputeMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         DisputeMetricsBucketIdentifier,
         DisputeMetricsAccumulator,
@@ -53,7 +62,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &auth_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        req.time_series,
                         &req.time_range,
                     )
                     .await
@@ -19,7 +19,9 @@ use super::{
 use crate::{
     errors::{AnalyticsError, AnalyticsResult},
     frm::FrmMetricAccumulator,
-    metrics, AnalyticsProvider,
+    metrics,
+    query::normalize_time_range,
+    AnalyticsProvider,
 };
 
 pub async fn get_metrics(
@@ -27,6 +29,13 @@ pub async fn get_metrics(
     merchant_id: &common_utils::id_type::MerchantId,
     req: GetFrmMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<FrmMetricsBucketResponse>> {
+    let req = GetFrmMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<FrmMetricsBucketIdentifier, FrmMetricsAccumulator> =
         HashMap::new();
     let mut set = tokio::task::JoinSet::new();
@@ -46,7 +55,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &merchant_id_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        req.time_series,
                         &req.time_range,
                     )
                     .await
@@ -1,6 +1,6 @@
 use api_models::analytics::{
     frm::{FrmDimensions, FrmFilters, FrmMetrics, FrmMetricsBucketIdentifier, FrmTransactionType},
-    Granularity, TimeRange,
+    TimeRange, TimeSeries,
 };
 use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
@@ -35,7 +35,7 @@ where
     T: AnalyticsDataSource + FrmMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -44,7 +44,7 @@ where
         dimensions: &[FrmDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &FrmFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(FrmMetricsBucketIdentifier, FrmMetricRow)>>;
@@ -56,7 +56,7 @@ where
     T: AnalyticsDataSource + FrmMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -65,7 +65,7 @@ where
         dimensions: &[FrmDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &FrmFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(FrmMetricsBucketIdentifier, FrmMetricRow)>> {
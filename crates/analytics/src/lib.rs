@@ -1,4 +1,5 @@
 pub mod active_payments;
+pub mod alerts;
 pub mod api_event;
 pub mod auth_events;
 mod clickhouse;
@@ -14,8 +15,10 @@ pub mod opensearch;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
+pub mod payouts;
 mod query;
 pub mod refunds;
+pub mod reports;
 pub mod routing_events;
 pub mod sdk_events;
 pub mod search;
@@ -30,6 +33,10 @@ use hyperswitch_interfaces::secrets_interface::{
     secret_state::{RawSecret, SecretStateContainer, SecuredSecret},
     SecretManagementInterface, SecretsManagementError,
 };
+use payouts::{
+    distribution::{PayoutDistribution, PayoutDistributionRow},
+    metrics::{PayoutMetric, PayoutMetricRow},
+};
 use refunds::distribution::{RefundDistribution, RefundDistributionRow};
 pub use types::AnalyticsDomain;
 pub mod lambda_utils;
@@ -52,11 +59,12 @@ use api_models::analytics::{
         PaymentIntentMetricsBucketIdentifier,
     },
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
     sdk_events::{
         SdkEventDimensions, SdkEventFilters, SdkEventMetrics, SdkEventMetricsBucketIdentifier,
     },
-    Granularity, PaymentDistributionBody, RefundDistributionBody, TimeRange,
+    PaymentDistributionBody, PayoutDistributionBody, RefundDistributionBody, TimeRange, TimeSeries,
 };
 use clickhouse::ClickhouseClient;
 pub use clickhouse::ClickhouseConfig;
@@ -119,7 +127,7 @@ impl AnalyticsProvider {
         dimensions: &[PaymentDimensions],
         auth: &AuthInfo,
         filters: &PaymentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
         // Metrics to get the fetch time for each payment metric
@@ -223,7 +231,7 @@ impl AnalyticsProvider {
         dimensions: &[PaymentDimensions],
         auth: &AuthInfo,
         filters: &PaymentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>> {
         // Metrics to get the fetch time for each payment metric
@@ -333,7 +341,7 @@ impl AnalyticsProvider {
         dimensions: &[PaymentIntentDimensions],
         auth: &AuthInfo,
         filters: &PaymentIntentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(PaymentIntentMetricsBucketIdentifier, PaymentIntentMetricRow)>>
     {
@@ -438,7 +446,7 @@ impl AnalyticsProvider {
         dimensions: &[RefundDimensions],
         auth: &AuthInfo,
         filters: &RefundFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(RefundMetricsBucketIdentifier, RefundMetricRow)>> {
         // Metrics to get the fetch time for each refund metric
@@ -538,7 +546,7 @@ impl AnalyticsProvider {
         dimensions: &[RefundDimensions],
         auth: &AuthInfo,
         filters: &RefundFilters,
-        granularity: &Option<Granularity>,
+        granularity: &Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<Vec<(RefundMetricsBucketIdentifier, RefundDistributionRow)>> {
         // Metrics to get the fetch time for each payment metric
@@ -648,7 +656,7 @@ impl AnalyticsProvider {
         dimensions: &[FrmDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &FrmFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<Vec<(FrmMetricsBucketIdentifier, FrmMetricRow)>> {
         // Metrics to get the fetch time for each refund metric
@@ -748,7 +756,7 @@ impl AnalyticsProvider {
         dimensions: &[DisputeDimensions],
         auth: &AuthInfo,
         filters: &DisputeFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(DisputeMetricsBucketIdentifier, DisputeMetricRow)>> {
         // Metrics to get the fetch time for each refund metric
@@ -842,13 +850,215 @@ impl AnalyticsProvider {
         .await
     }
 
+    pub async fn get_payout_metrics(
+        &self,
+        metric: &PayoutMetrics,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<TimeSeries>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        // Metrics to get the fetch time for each payout metric
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        metric.load_metrics(
+                            dimensions,
+                            auth,
+                            filters,
+                            granularity,
+                            time_range,
+                            pool,
+                        )
+                        .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        metric.load_metrics(
+                            dimensions,
+                            auth,
+                            filters,
+                            granularity,
+                            time_range,
+                            pool,
+                        )
+                        .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            metric.load_metrics(
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics metrics")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            metric,
+            self,
+        )
+        .await
+    }
+
+    pub async fn get_payout_distribution(
+        &self,
+        distribution: &PayoutDistributionBody,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<TimeSeries>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>> {
+        // Metrics to get the fetch time for each payout distribution
+        metrics::request::record_operation_time(
+            async {
+                match self {
+                    Self::Sqlx(pool) => {
+                        distribution.distribution_for.load_distribution(
+                            distribution,
+                            dimensions,
+                            auth,
+                            filters,
+                            granularity,
+                            time_range,
+                            pool,
+                        )
+                        .await
+                    }
+                    Self::Clickhouse(pool) => {
+                        distribution.distribution_for.load_distribution(
+                            distribution,
+                            dimensions,
+                            auth,
+                            filters,
+                            granularity,
+                            time_range,
+                            pool,
+                        )
+                        .await
+                    }
+                    Self::CombinedCkh(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        ckh_result
+                    }
+                    Self::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                        let (ckh_result, sqlx_result) = tokio::join!(
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                ckh_pool,
+                            ),
+                            distribution.distribution_for.load_distribution(
+                                distribution,
+                                dimensions,
+                                auth,
+                                filters,
+                                granularity,
+                                time_range,
+                                sqlx_pool,
+                            )
+                        );
+                        match (&sqlx_result, &ckh_result) {
+                            (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                                logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics distribution")
+                            }
+                            _ => {}
+                        };
+                        sqlx_result
+                    }
+                }
+            },
+            &metrics::METRIC_FETCH_TIME,
+            &distribution.distribution_for,
+            self,
+        )
+        .await
+    }
+
     pub async fn get_sdk_event_metrics(
         &self,
         metric: &SdkEventMetrics,
         dimensions: &[SdkEventDimensions],
         publishable_key: &str,
         filters: &SdkEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>> {
         match self {
@@ -914,7 +1124,7 @@ impl AnalyticsProvider {
         dimensions: &[AuthEventDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &AuthEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>> {
         match self {
@@ -953,7 +1163,7 @@ impl AnalyticsProvider {
         dimensions: &[ApiEventDimensions],
         merchant_id: &common_utils::id_type::MerchantId,
         filters: &ApiEventFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
     ) -> types::MetricsResult<HashSet<(ApiEventMetricsBucketIdentifier, ApiEventMetricRow)>> {
         match self {
@@ -1008,6 +1218,16 @@ impl AnalyticsProvider {
             ),
         }
     }
+
+    /// Client for the Postgres database, when the provider is configured to read from it
+    pub fn get_sqlx_client(&self) -> Option<&SqlxClient> {
+        match self {
+            Self::Sqlx(sqlx_pool)
+            | Self::CombinedCkh(sqlx_pool, _)
+            | Self::CombinedSqlx(sqlx_pool, _) => Some(sqlx_pool),
+            Self::Clickhouse(_) => None,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -1124,11 +1344,26 @@ impl Default for AnalyticsConfig {
 
 #[derive(Clone, Debug, serde::Deserialize, Default, serde::Serialize)]
 pub struct ReportConfig {
+    /// Where reports are generated, either by the configured AWS Lambda functions or by the
+    /// scheduler
+    #[serde(default)]
+    pub generator: ReportGenerator,
     pub payment_function: String,
     pub refund_function: String,
     pub dispute_function: String,
     pub authentication_function: String,
     pub region: String,
+    /// Format of the files of reports generated by the scheduler
+    #[serde(default)]
+    pub file_format: reports::ReportFileFormat,
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportGenerator {
+    #[default]
+    Lambda,
+    Scheduler,
 }
 
 /// Analytics Flow routes Enums
@@ -1141,14 +1376,20 @@ pub enum AnalyticsFlow {
     GetPaymentMetrics,
     GetPaymentIntentMetrics,
     GetRefundsMetrics,
+    GetPayoutMetrics,
     GetFrmMetrics,
     GetSdkMetrics,
+    GetPaymentFunnel,
+    GetDeclineReasons,
+    GetRoutingRuleMetrics,
+    GetProcessingCost,
     GetAuthMetrics,
     GetAuthEventFilters,
     GetActivePaymentsMetrics,
     GetPaymentFilters,
     GetPaymentIntentFilters,
     GetRefundFilters,
+    GetPayoutFilters,
     GetFrmFilters,
     GetSdkEventFilters,
     GetApiEvents,
@@ -1156,6 +1397,7 @@ pub enum AnalyticsFlow {
     GeneratePaymentReport,
     GenerateDisputeReport,
     GenerateRefundReport,
+    DownloadReport,
     GenerateAuthenticationReport,
     GetApiEventMetrics,
     GetApiEventFilters,
@@ -1167,6 +1409,11 @@ pub enum AnalyticsFlow {
     GetDisputeMetrics,
     GetSankey,
     GetRoutingEvents,
+    CreateAlertRule,
+    ListAlertRules,
+    GetAlertRule,
+    UpdateAlertRule,
+    DeleteAlertRule,
 }
 
 impl FlowMetric for AnalyticsFlow {}
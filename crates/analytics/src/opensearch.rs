@@ -47,6 +47,10 @@ pub struct OpenSearchIndexes {
     pub sessionizer_payment_intents: String,
     pub sessionizer_refunds: String,
     pub sessionizer_disputes: String,
+    pub customers: String,
+    pub payouts: String,
+    pub mandates: String,
+    pub payment_methods: String,
 }
 
 #[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize, PartialEq, Eq, Hash)]
@@ -92,6 +96,10 @@ impl Default for OpenSearchConfig {
                 sessionizer_payment_intents: "sessionizer-payment-intent-events".to_string(),
                 sessionizer_refunds: "sessionizer-refund-events".to_string(),
                 sessionizer_disputes: "sessionizer-dispute-events".to_string(),
+                customers: "hyperswitch-customer-events".to_string(),
+                payouts: "hyperswitch-payout-events".to_string(),
+                mandates: "hyperswitch-mandate-events".to_string(),
+                payment_methods: "hyperswitch-payment-method-events".to_string(),
             },
             enabled: false,
         }
@@ -247,6 +255,10 @@ impl OpenSearchClient {
             }
             SearchIndex::SessionizerRefunds => self.indexes.sessionizer_refunds.clone(),
             SearchIndex::SessionizerDisputes => self.indexes.sessionizer_disputes.clone(),
+            SearchIndex::Customers => self.indexes.customers.clone(),
+            SearchIndex::Payouts => self.indexes.payouts.clone(),
+            SearchIndex::Mandates => self.indexes.mandates.clone(),
+            SearchIndex::PaymentMethods => self.indexes.payment_methods.clone(),
         }
     }
 
@@ -382,6 +394,30 @@ impl OpenSearchIndexes {
             ))
         })?;
 
+        when(self.customers.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Opensearch Customers index must not be empty".into(),
+            ))
+        })?;
+
+        when(self.payouts.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Opensearch Payouts index must not be empty".into(),
+            ))
+        })?;
+
+        when(self.mandates.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Opensearch Mandates index must not be empty".into(),
+            ))
+        })?;
+
+        when(self.payment_methods.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Opensearch Payment Methods index must not be empty".into(),
+            ))
+        })?;
+
         Ok(())
     }
 }
@@ -499,6 +535,11 @@ impl OpenSearchQueryBuilder {
                 "payment_id.keyword",
                 "amount",
                 "customer_id.keyword",
+                "customer_phone.keyword",
+                "payout_id.keyword",
+                "mandate_id.keyword",
+                "payment_method_id.keyword",
+                "fingerprint_id.keyword",
             ]),
         }
     }
@@ -523,6 +564,7 @@ impl OpenSearchQueryBuilder {
         match index {
             SearchIndex::Refunds | SearchIndex::SessionizerRefunds => "refund_status.keyword",
             SearchIndex::Disputes | SearchIndex::SessionizerDisputes => "dispute_status.keyword",
+            SearchIndex::Mandates => "mandate_status.keyword",
             _ => "status.keyword",
         }
     }
@@ -531,6 +573,7 @@ impl OpenSearchQueryBuilder {
         match index {
             SearchIndex::Refunds | SearchIndex::SessionizerRefunds => "refund_amount",
             SearchIndex::Disputes | SearchIndex::SessionizerDisputes => "dispute_amount",
+            SearchIndex::Mandates => "mandate_amount",
             _ => "amount",
         }
     }
@@ -638,7 +681,22 @@ impl OpenSearchQueryBuilder {
         payload
     }
 
-    pub fn build_auth_array(&self) -> Vec<Value> {
+    /// Customers, payouts, mandates and payment methods are not tagged with the organization they
+    /// belong to, so they can only be scoped by the merchant and the profile
+    fn is_organization_scoped(index: SearchIndex) -> bool {
+        !matches!(
+            index,
+            SearchIndex::Customers
+                | SearchIndex::Payouts
+                | SearchIndex::Mandates
+                | SearchIndex::PaymentMethods
+        )
+    }
+
+    pub fn build_auth_array(&self, index: SearchIndex) -> Vec<Value> {
+        if !Self::is_organization_scoped(index) {
+            return self.build_merchant_auth_array(index);
+        }
         self.search_params
             .iter()
             .map(|user_level| match user_level {
@@ -719,6 +777,61 @@ impl OpenSearchQueryBuilder {
             .collect::<Vec<Value>>()
     }
 
+    /// Organization level access is expected to be expanded to the merchants of the organization
+    /// by the caller, as the organization cannot be matched for these indexes
+    fn build_merchant_auth_array(&self, index: SearchIndex) -> Vec<Value> {
+        let auth_array = self
+            .search_params
+            .iter()
+            .filter_map(|user_level| match user_level {
+                AuthInfo::OrgLevel { .. } => None,
+                AuthInfo::MerchantLevel { merchant_ids, .. } => Some(json!({
+                    "bool": {
+                        "must": [
+                            {
+                                "terms": {
+                                    "merchant_id.keyword": merchant_ids
+                                }
+                            }
+                        ]
+                    }
+                })),
+                // Only payouts are tied to a profile, so profile level access does not extend to
+                // the entities shared by all the profiles of the merchant
+                AuthInfo::ProfileLevel {
+                    merchant_id,
+                    profile_ids,
+                    ..
+                } => (index == SearchIndex::Payouts).then(|| {
+                    json!({
+                        "bool": {
+                            "must": [
+                                {
+                                    "term": {
+                                        "merchant_id.keyword": {
+                                            "value": merchant_id
+                                        }
+                                    }
+                                },
+                                {
+                                    "terms": {
+                                        "profile_id.keyword": profile_ids
+                                    }
+                                }
+                            ]
+                        }
+                    })
+                }),
+            })
+            .collect::<Vec<Value>>();
+
+        if auth_array.is_empty() {
+            vec![json!({ "match_none": {} })]
+        } else {
+            auth_array
+        }
+    }
+
     /// # Panics
     ///
     /// This function will panic if:
@@ -735,8 +848,6 @@ impl OpenSearchQueryBuilder {
             .iter()
             .partition(|(k, _)| self.case_sensitive_fields.contains(k.as_str()));
 
-        let should_array = self.build_auth_array();
-
         query_obj.insert("bool".to_string(), Value::Object(bool_obj.clone()));
 
         let mut sort_obj = Map::new();
@@ -769,7 +880,7 @@ impl OpenSearchQueryBuilder {
                 payload = self.build_case_insensitive_filters(
                     payload,
                     &case_insensitive_filters,
-                    should_array.clone(),
+                    self.build_auth_array(*index),
                     *index,
                 );
                 payload
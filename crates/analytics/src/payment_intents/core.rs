@@ -30,6 +30,7 @@ use crate::{
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
     payment_intents::PaymentIntentMetricAccumulator,
+    query::normalize_time_range,
     AnalyticsProvider,
 };
 
@@ -72,6 +73,13 @@ pub async fn get_metrics(
     auth: &AuthInfo,
     req: GetPaymentIntentMetricRequest,
 ) -> AnalyticsResult<PaymentIntentsMetricsResponse<MetricsBucketResponse>> {
+    let req = GetPaymentIntentMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         PaymentIntentMetricsBucketIdentifier,
         PaymentIntentMetricsAccumulator,
@@ -97,7 +105,7 @@ pub async fn get_metrics(
                         &req.group_by_names.clone(),
                         &auth_scoped,
                         &req.filters,
-                        req.time_series.map(|t| t.granularity),
+                        req.time_series,
                         &req.time_range,
                     )
                     .await
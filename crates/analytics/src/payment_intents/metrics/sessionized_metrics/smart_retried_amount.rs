@@ -5,7 +5,7 @@ use api_models::{
         payment_intents::{
             PaymentIntentDimensions, PaymentIntentFilters, PaymentIntentMetricsBucketIdentifier,
         },
-        Granularity, TimeRange,
+        TimeRange, TimeSeries,
     },
     enums::IntentStatus,
 };
@@ -32,7 +32,7 @@ where
     T: AnalyticsDataSource + super::PaymentIntentMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -41,7 +41,7 @@ where
         dimensions: &[PaymentIntentDimensions],
         auth: &AuthInfo,
         filters: &PaymentIntentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(PaymentIntentMetricsBucketIdentifier, PaymentIntentMetricRow)>>
@@ -1,8 +1,11 @@
 pub mod accumulator;
 mod core;
+pub mod decline_reasons;
 pub mod distribution;
 pub mod filters;
 pub mod metrics;
+pub mod processing_cost;
+pub mod routing_rules;
 pub mod types;
 pub use accumulator::{
     PaymentDistributionAccumulator, PaymentMetricAccumulator, PaymentMetricsAccumulator,
@@ -0,0 +1,273 @@
+use api_models::analytics::{
+    decline_reasons::{DeclineReasonDimensions, DeclineReasonFilters},
+    GetDeclineReasonsRequest, TimeRange,
+};
+use common_utils::errors::{ParsingError, ReportSwitchExt};
+use diesel_models::enums::AttemptStatus;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{
+        normalize_time_range, Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder,
+        QueryFilter, SeriesBucket,
+    },
+    types::{AnalyticsCollection, MetricsError, MetricsResult},
+    AnalyticsProvider,
+};
+
+/// Failures of payment attempts sharing the same connector error, for a combination of
+/// dimensions and a time bucket
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct DeclineReasonRow {
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    #[serde(default)]
+    pub card_network: Option<String>,
+    #[serde(default)]
+    pub card_issuer: Option<String>,
+    #[serde(default)]
+    pub card_bin: Option<String>,
+    pub count: u64,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+/// Failures of payment attempts along with the time bucket they were aggregated in
+#[derive(Debug)]
+pub struct DeclineReasonBucketRow {
+    pub row: DeclineReasonRow,
+    pub time_range: TimeRange,
+}
+
+impl TryInto<DeclineReasonRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<DeclineReasonRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse DeclineReasonRow in clickhouse results",
+        ))
+    }
+}
+
+/// Card details are not stored in dedicated columns, they are read from the additional card
+/// information stored in the payment method data of the attempt
+fn get_dimension_expression(dimension: DeclineReasonDimensions) -> String {
+    match dimension {
+        DeclineReasonDimensions::Connector | DeclineReasonDimensions::CardNetwork => {
+            dimension.to_string()
+        }
+        DeclineReasonDimensions::CardIssuer => {
+            "nullIf(JSONExtractString(payment_method_data, 'card', 'card_issuer'), '')".to_string()
+        }
+        DeclineReasonDimensions::CardBin => {
+            "nullIf(JSONExtractString(payment_method_data, 'card', 'card_isin'), '')".to_string()
+        }
+    }
+}
+
+fn set_decline_reason_filters(
+    filters: &DeclineReasonFilters,
+    builder: &mut QueryBuilder<ClickhouseClient>,
+) -> MetricsResult<()> {
+    if !filters.connector.is_empty() {
+        builder
+            .add_filter_in_range_clause(DeclineReasonDimensions::Connector, &filters.connector)
+            .attach_printable("Error adding connector filter")
+            .switch()?;
+    }
+    if !filters.card_network.is_empty() {
+        let card_networks: Vec<String> = filters
+            .card_network
+            .iter()
+            .flat_map(|cn| {
+                [
+                    format!("\"{cn}\""),
+                    cn.to_string(),
+                    format!("\"{cn}\"").to_uppercase(),
+                ]
+            })
+            .collect();
+        builder
+            .add_filter_in_range_clause(
+                DeclineReasonDimensions::CardNetwork,
+                card_networks.as_slice(),
+            )
+            .attach_printable("Error adding card network filter")
+            .switch()?;
+    }
+    if !filters.card_issuer.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                get_dimension_expression(DeclineReasonDimensions::CardIssuer),
+                &filters.card_issuer,
+            )
+            .attach_printable("Error adding card issuer filter")
+            .switch()?;
+    }
+    if !filters.card_bin.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                get_dimension_expression(DeclineReasonDimensions::CardBin),
+                &filters.card_bin,
+            )
+            .attach_printable("Error adding card bin filter")
+            .switch()?;
+    }
+    if !filters.unified_code.is_empty() {
+        builder
+            .add_filter_in_range_clause("unified_code", &filters.unified_code)
+            .attach_printable("Error adding unified code filter")
+            .switch()?;
+    }
+    Ok(())
+}
+
+async fn load_decline_reasons(
+    pool: &ClickhouseClient,
+    auth: &AuthInfo,
+    req: &GetDeclineReasonsRequest,
+) -> MetricsResult<Vec<DeclineReasonBucketRow>> {
+    let mut query_builder = QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::Payment);
+
+    // The connector error is always selected, so that the unified error can be enriched with
+    // the GSM rule the connector error maps to
+    for column in [
+        "connector",
+        "error_code",
+        "error_message",
+        "unified_code",
+        "unified_message",
+    ] {
+        query_builder.add_select_column(column).switch()?;
+    }
+    for dim in req.group_by_names.iter() {
+        if *dim != DeclineReasonDimensions::Connector {
+            query_builder
+                .add_select_column(format!("{} AS {dim}", get_dimension_expression(*dim)))
+                .switch()?;
+        }
+    }
+    query_builder
+        .add_select_column(Aggregate::<&str>::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder
+        .add_filter_clause("status", AttemptStatus::Failure)
+        .switch()?;
+    query_builder
+        .add_custom_filter_clause("error_code", "NULL", FilterTypes::IsNotNull)
+        .switch()?;
+    set_decline_reason_filters(&req.filters, &mut query_builder)?;
+
+    for column in [
+        "connector",
+        "error_code",
+        "error_message",
+        "unified_code",
+        "unified_message",
+    ] {
+        query_builder
+            .add_group_by_clause(column)
+            .attach_printable("Error grouping by connector error")
+            .switch()?;
+    }
+    for dim in req.group_by_names.iter() {
+        if *dim != DeclineReasonDimensions::Connector {
+            query_builder
+                .add_group_by_clause(get_dimension_expression(*dim))
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+    }
+    if let Some(granularity) = req.time_series {
+        granularity
+            .set_group_by_clause(&mut query_builder)
+            .attach_printable("Error adding granularity")
+            .switch()?;
+    }
+
+    query_builder
+        .add_order_by_clause("count", Order::Descending)
+        .attach_printable("Error adding order by clause")
+        .switch()?;
+
+    query_builder
+        .execute_query::<DeclineReasonRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?
+        .into_iter()
+        .map(|row| {
+            Ok(DeclineReasonBucketRow {
+                time_range: TimeRange {
+                    start_time: match (req.time_series, row.start_bucket) {
+                        (Some(g), Some(st)) => g.clip_to_start(st)?,
+                        _ => req.time_range.start_time,
+                    },
+                    end_time: req.time_series.as_ref().map_or_else(
+                        || Ok(req.time_range.end_time),
+                        |g| row.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                    )?,
+                },
+                row,
+            })
+        })
+        .collect::<error_stack::Result<Vec<_>, crate::query::PostProcessingError>>()
+        .change_context(MetricsError::PostProcessingFailure)
+}
+
+/// Fetches the failed payment attempts grouped by their connector error along with the
+/// requested dimensions, the connector errors are mapped to their unified errors by the caller
+pub async fn get_decline_reasons(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetDeclineReasonsRequest,
+) -> AnalyticsResult<Vec<DeclineReasonBucketRow>> {
+    let req = GetDeclineReasonsRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
+
+    match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Decline reasons not implemented for SQLX",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => load_decline_reasons(ckh_pool, auth, &req)
+            .await
+            .change_context(AnalyticsError::UnknownError),
+    }
+}
@@ -2,7 +2,7 @@ use api_models::analytics::{
     payments::{
         PaymentDimensions, PaymentDistributions, PaymentFilters, PaymentMetricsBucketIdentifier,
     },
-    Granularity, PaymentDistributionBody, TimeRange,
+    PaymentDistributionBody, TimeRange, TimeSeries,
 };
 use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
@@ -57,7 +57,7 @@ where
         dimensions: &[PaymentDimensions],
         auth: &AuthInfo,
         filters: &PaymentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>>;
@@ -69,7 +69,7 @@ where
     T: AnalyticsDataSource + PaymentDistributionAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -79,7 +79,7 @@ where
         dimensions: &[PaymentDimensions],
         auth: &AuthInfo,
         filters: &PaymentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>> {
@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use api_models::{
     analytics::{
         payments::{PaymentDimensions, PaymentFilters, PaymentMetricsBucketIdentifier},
-        Granularity, TimeRange,
+        TimeRange, TimeSeries,
     },
     enums::IntentStatus,
 };
@@ -30,7 +30,7 @@ where
     T: AnalyticsDataSource + super::PaymentMetricAnalytics,
     PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
+    TimeSeries: GroupByClause<T>,
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
@@ -39,7 +39,7 @@ where
         _dimensions: &[PaymentDimensions],
         auth: &AuthInfo,
         _filters: &PaymentFilters,
-        granularity: Option<Granularity>,
+        granularity: Option<TimeSeries>,
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
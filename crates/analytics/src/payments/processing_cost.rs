@@ -0,0 +1,227 @@
+use api_models::analytics::{
+    processing_cost::{ProcessingCostDimensions, ProcessingCostFilters},
+    GetProcessingCostRequest, TimeRange,
+};
+use common_utils::errors::{ParsingError, ReportSwitchExt};
+use diesel_models::enums::AttemptStatus;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{
+        normalize_time_range, Aggregate, GroupByClause, QueryBuilder, QueryFilter, SeriesBucket,
+    },
+    types::{AnalyticsCollection, MetricsError, MetricsResult},
+    AnalyticsProvider,
+};
+
+/// Charged payment attempts sharing all the attributes which decide the fees charged by the
+/// connector, for a time bucket
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ProcessingCostRow {
+    pub merchant_id: String,
+    pub merchant_connector_id: Option<String>,
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub currency: Option<String>,
+    pub payment_count: u64,
+    pub total_amount: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+/// Charged payment attempts along with the time bucket they were aggregated in
+#[derive(Debug)]
+pub struct ProcessingCostBucketRow {
+    pub row: ProcessingCostRow,
+    pub time_range: TimeRange,
+}
+
+impl TryInto<ProcessingCostRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<ProcessingCostRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ProcessingCostRow in clickhouse results",
+        ))
+    }
+}
+
+/// Attributes of the payment attempts which the fee rules of a connector can depend on, the
+/// card issuing country is read from the additional card information of the attempt
+const FEE_ATTRIBUTES: [(&str, &str); 8] = [
+    ("merchant_id", "merchant_id"),
+    ("merchant_connector_id", "merchant_connector_id"),
+    ("connector", "connector"),
+    ("payment_method", "payment_method"),
+    ("payment_method_type", "payment_method_type"),
+    ("card_network", "card_network"),
+    (
+        "card_issuing_country",
+        "nullIf(JSONExtractString(payment_method_data, 'card', 'card_issuing_country'), '')",
+    ),
+    ("currency", "currency"),
+];
+
+fn set_processing_cost_filters(
+    filters: &ProcessingCostFilters,
+    builder: &mut QueryBuilder<ClickhouseClient>,
+) -> MetricsResult<()> {
+    if !filters.connector.is_empty() {
+        builder
+            .add_filter_in_range_clause(ProcessingCostDimensions::Connector, &filters.connector)
+            .attach_printable("Error adding connector filter")
+            .switch()?;
+    }
+    if !filters.merchant_connector_id.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                ProcessingCostDimensions::MerchantConnectorId,
+                &filters.merchant_connector_id,
+            )
+            .attach_printable("Error adding merchant connector id filter")
+            .switch()?;
+    }
+    if !filters.payment_method.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                ProcessingCostDimensions::PaymentMethod,
+                &filters.payment_method,
+            )
+            .attach_printable("Error adding payment method filter")
+            .switch()?;
+    }
+    if !filters.currency.is_empty() {
+        builder
+            .add_filter_in_range_clause("currency", &filters.currency)
+            .attach_printable("Error adding currency filter")
+            .switch()?;
+    }
+    Ok(())
+}
+
+async fn load_processing_cost(
+    pool: &ClickhouseClient,
+    auth: &AuthInfo,
+    req: &GetProcessingCostRequest,
+) -> MetricsResult<Vec<ProcessingCostBucketRow>> {
+    let mut query_builder = QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::Payment);
+
+    // All the attributes which the fees depend on are selected irrespective of the requested
+    // dimensions, the payments are aggregated over the requested dimensions by the caller once
+    // their cost is estimated
+    for (alias, expression) in FEE_ATTRIBUTES {
+        let column = if alias == expression {
+            alias.to_string()
+        } else {
+            format!("{expression} AS {alias}")
+        };
+        query_builder.add_select_column(column).switch()?;
+    }
+    query_builder
+        .add_select_column(Aggregate::<&str>::Count {
+            field: None,
+            alias: Some("payment_count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Sum {
+            field: "amount",
+            alias: Some("total_amount"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder
+        .add_filter_clause("status", AttemptStatus::Charged)
+        .switch()?;
+    set_processing_cost_filters(&req.filters, &mut query_builder)?;
+
+    for (_, expression) in FEE_ATTRIBUTES {
+        query_builder
+            .add_group_by_clause(expression)
+            .attach_printable("Error grouping by fee attributes")
+            .switch()?;
+    }
+    if let Some(granularity) = req.time_series {
+        granularity
+            .set_group_by_clause(&mut query_builder)
+            .attach_printable("Error adding granularity")
+            .switch()?;
+    }
+
+    query_builder
+        .execute_query::<ProcessingCostRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?
+        .into_iter()
+        .map(|row| {
+            Ok(ProcessingCostBucketRow {
+                time_range: TimeRange {
+                    start_time: match (req.time_series, row.start_bucket) {
+                        (Some(g), Some(st)) => g.clip_to_start(st)?,
+                        _ => req.time_range.start_time,
+                    },
+                    end_time: req.time_series.as_ref().map_or_else(
+                        || Ok(req.time_range.end_time),
+                        |g| row.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                    )?,
+                },
+                row,
+            })
+        })
+        .collect::<error_stack::Result<Vec<_>, crate::query::PostProcessingError>>()
+        .change_context(MetricsError::PostProcessingFailure)
+}
+
+/// Fetches the charged payment attempts grouped by the attributes which decide the fees charged
+/// by the connector, the cost of the payments is estimated by the caller from the fee schedules
+pub async fn get_processing_cost(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetProcessingCostRequest,
+) -> AnalyticsResult<Vec<ProcessingCostBucketRow>> {
+    let req = GetProcessingCostRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
+
+    match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Processing cost not implemented for SQLX",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => load_processing_cost(ckh_pool, auth, &req)
+            .await
+            .change_context(AnalyticsError::UnknownError),
+    }
+}
@@ -0,0 +1,245 @@
+use api_models::analytics::{
+    routing_rules::{RoutingRuleBucket, RoutingRuleDimensions, RoutingRuleFilters},
+    AnalyticsMetadata, GetRoutingRuleMetricsRequest, MetricsResponse, TimeRange,
+};
+use common_utils::errors::{ParsingError, ReportSwitchExt};
+use diesel_models::enums::{AttemptStatus, Currency};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{
+        normalize_time_range, Aggregate, FilterTypes, GroupByClause, Order, QueryBuilder,
+        QueryFilter, SeriesBucket,
+    },
+    types::{AnalyticsCollection, DBEnumWrapper, MetricsError, MetricsResult},
+    utils, AnalyticsProvider,
+};
+
+/// Payment attempts routed by a static routing rule, for a combination of dimensions and a time
+/// bucket
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RoutingRuleRow {
+    #[serde(default)]
+    pub routing_algorithm_id: Option<String>,
+    #[serde(default)]
+    pub routing_rule_name: Option<String>,
+    #[serde(default)]
+    pub connector: Option<String>,
+    #[serde(default)]
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub hit_count: u64,
+    pub success_count: u64,
+    pub processed_amount: Option<u64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+impl TryInto<RoutingRuleRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<RoutingRuleRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse RoutingRuleRow in clickhouse results",
+        ))
+    }
+}
+
+fn set_routing_rule_filters(
+    filters: &RoutingRuleFilters,
+    builder: &mut QueryBuilder<ClickhouseClient>,
+) -> MetricsResult<()> {
+    if !filters.routing_algorithm_id.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                RoutingRuleDimensions::RoutingAlgorithmId,
+                &filters.routing_algorithm_id,
+            )
+            .attach_printable("Error adding routing algorithm filter")
+            .switch()?;
+    }
+    if !filters.routing_rule_name.is_empty() {
+        builder
+            .add_filter_in_range_clause(
+                RoutingRuleDimensions::RoutingRuleName,
+                &filters.routing_rule_name,
+            )
+            .attach_printable("Error adding routing rule filter")
+            .switch()?;
+    }
+    if !filters.connector.is_empty() {
+        builder
+            .add_filter_in_range_clause(RoutingRuleDimensions::Connector, &filters.connector)
+            .attach_printable("Error adding connector filter")
+            .switch()?;
+    }
+    Ok(())
+}
+
+async fn load_routing_rule_metrics(
+    pool: &ClickhouseClient,
+    auth: &AuthInfo,
+    req: &GetRoutingRuleMetricsRequest,
+    dimensions: &[RoutingRuleDimensions],
+) -> MetricsResult<Vec<RoutingRuleBucket>> {
+    let mut query_builder = QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::Payment);
+
+    for dim in dimensions.iter() {
+        query_builder.add_select_column(dim).switch()?;
+    }
+    // Amounts can only be added up within a currency, so the currency is always selected
+    query_builder.add_select_column("currency").switch()?;
+    query_builder
+        .add_select_column(Aggregate::<&str>::Count {
+            field: None,
+            alias: Some("hit_count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(format!(
+            "countIf(status = '{}') AS success_count",
+            AttemptStatus::Charged
+        ))
+        .switch()?;
+    query_builder
+        .add_select_column(format!(
+            "sumIf(amount, status = '{}') AS processed_amount",
+            AttemptStatus::Charged
+        ))
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+    query_builder
+        .add_custom_filter_clause(
+            RoutingRuleDimensions::RoutingAlgorithmId,
+            "NULL",
+            FilterTypes::IsNotNull,
+        )
+        .switch()?;
+    set_routing_rule_filters(&req.filters, &mut query_builder)?;
+
+    for dim in dimensions.iter() {
+        query_builder
+            .add_group_by_clause(dim)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+    query_builder
+        .add_group_by_clause("currency")
+        .attach_printable("Error grouping by currency")
+        .switch()?;
+    if let Some(granularity) = req.time_series {
+        granularity
+            .set_group_by_clause(&mut query_builder)
+            .attach_printable("Error adding granularity")
+            .switch()?;
+    }
+
+    query_builder
+        .add_order_by_clause("hit_count", Order::Descending)
+        .attach_printable("Error adding order by clause")
+        .switch()?;
+
+    query_builder
+        .execute_query::<RoutingRuleRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?
+        .into_iter()
+        .map(|row| {
+            let time_range = TimeRange {
+                start_time: match (req.time_series, row.start_bucket) {
+                    (Some(g), Some(st)) => g.clip_to_start(st)?,
+                    _ => req.time_range.start_time,
+                },
+                end_time: req.time_series.as_ref().map_or_else(
+                    || Ok(req.time_range.end_time),
+                    |g| row.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                )?,
+            };
+            Ok(RoutingRuleBucket {
+                success_rate: utils::get_rate(row.success_count, row.hit_count),
+                routing_algorithm_id: row.routing_algorithm_id,
+                routing_rule_name: row.routing_rule_name,
+                connector: row.connector,
+                currency: row.currency.map(|currency| currency.0),
+                hit_count: row.hit_count,
+                success_count: row.success_count,
+                processed_amount: row.processed_amount.unwrap_or_default(),
+                start_time: time_range.start_time,
+                time_bucket: time_range,
+            })
+        })
+        .collect::<error_stack::Result<Vec<_>, crate::query::PostProcessingError>>()
+        .change_context(MetricsError::PostProcessingFailure)
+}
+
+/// Fetches the hit count, success rate and processed volume of the payment attempts routed by
+/// each static routing rule, payments routed through the default fallback are not included
+pub async fn get_routing_rule_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetRoutingRuleMetricsRequest,
+) -> AnalyticsResult<MetricsResponse<RoutingRuleBucket>> {
+    let req = GetRoutingRuleMetricsRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
+    let dimensions = if req.group_by_names.is_empty() {
+        vec![
+            RoutingRuleDimensions::RoutingAlgorithmId,
+            RoutingRuleDimensions::RoutingRuleName,
+        ]
+    } else {
+        req.group_by_names.clone()
+    };
+
+    let mut query_data = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Routing rule metrics not implemented for SQLX",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            load_routing_rule_metrics(ckh_pool, auth, &req, &dimensions)
+                .await
+                .change_context(AnalyticsError::UnknownError)?
+        }
+    };
+    query_data.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then(b.hit_count.cmp(&a.hit_count))
+    });
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+        }],
+    })
+}
@@ -0,0 +1,344 @@
+#![allow(dead_code)]
+use std::collections::{HashMap, HashSet};
+
+use api_models::analytics::{
+    payouts::{
+        PayoutDimensions, PayoutDistributions, PayoutMetrics, PayoutMetricsBucketIdentifier,
+        PayoutMetricsBucketResponse,
+    },
+    GetPayoutFilterRequest, GetPayoutMetricRequest, PayoutFilterValue, PayoutFiltersResponse,
+    PayoutsAnalyticsMetadata, PayoutsMetricsResponse,
+};
+use bigdecimal::ToPrimitive;
+use common_enums::Currency;
+use common_utils::errors::CustomResult;
+use currency_conversion::{conversion::convert, types::ExchangeRates};
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    distribution::PayoutDistributionRow,
+    filters::{get_payout_filter_for_dimension, PayoutFilterRow},
+    metrics::PayoutMetricRow,
+    PayoutMetricsAccumulator,
+};
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    payouts::{accumulator::PayoutDistributionAccumulator, PayoutMetricAccumulator},
+    query::normalize_time_range,
+    AnalyticsProvider,
+};
+
+#[derive(Debug)]
+pub enum TaskType {
+    MetricTask(
+        PayoutMetrics,
+        CustomResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>, AnalyticsError>,
+    ),
+    DistributionTask(
+        PayoutDistributions,
+        CustomResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>, AnalyticsError>,
+    ),
+}
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    ex_rates: &Option<ExchangeRates>,
+    auth: &AuthInfo,
+    req: GetPayoutMetricRequest,
+) -> AnalyticsResult<PayoutsMetricsResponse<PayoutMetricsBucketResponse>> {
+    let req = GetPayoutMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
+    let mut metrics_accumulator: HashMap<PayoutMetricsBucketIdentifier, PayoutMetricsAccumulator> =
+        HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payout_query",
+            payout_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payout_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        req.time_series,
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                TaskType::MetricTask(metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    if let Some(distribution) = req.clone().distribution {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payouts_distribution_query",
+            payout_distribution = distribution.distribution_for.as_ref()
+        );
+
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payout_distribution(
+                        &distribution,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        &req.time_series,
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                TaskType::DistributionTask(distribution.distribution_for, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some(task_type) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        match task_type {
+            TaskType::MetricTask(metric, data) => {
+                let data = data?;
+                let attributes = router_env::metric_attributes!(
+                    ("metric_type", metric.to_string()),
+                    ("source", pool.to_string()),
+                );
+
+                let value = u64::try_from(data.len());
+                if let Ok(val) = value {
+                    metrics::BUCKETS_FETCHED.record(val, attributes);
+                    logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+                }
+
+                for (id, value) in data {
+                    logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+                    let metrics_builder = metrics_accumulator.entry(id).or_default();
+                    match metric {
+                        PayoutMetrics::PayoutSuccessRate => metrics_builder
+                            .payout_success_rate
+                            .add_metrics_bucket(&value),
+                        PayoutMetrics::PayoutCount => {
+                            metrics_builder.payout_count.add_metrics_bucket(&value)
+                        }
+                        PayoutMetrics::PayoutProcessedAmount => {
+                            metrics_builder.processed_amount.add_metrics_bucket(&value)
+                        }
+                        PayoutMetrics::PayoutAvgTimeToFulfilment => metrics_builder
+                            .time_to_fulfilment
+                            .add_metrics_bucket(&value),
+                    }
+                }
+
+                logger::debug!(
+                    "Analytics Accumulated Results: metric: {}, results: {:#?}",
+                    metric,
+                    metrics_accumulator
+                );
+            }
+            TaskType::DistributionTask(distribution, data) => {
+                let data = data?;
+                let attributes = router_env::metric_attributes!(
+                    ("distribution_type", distribution.to_string()),
+                    ("source", pool.to_string()),
+                );
+                let value = u64::try_from(data.len());
+                if let Ok(val) = value {
+                    metrics::BUCKETS_FETCHED.record(val, attributes);
+                    logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+                }
+
+                for (id, value) in data {
+                    logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for distribution {distribution}");
+
+                    let metrics_builder = metrics_accumulator.entry(id).or_default();
+                    match distribution {
+                        PayoutDistributions::PayoutErrorMessage => metrics_builder
+                            .payout_error_message_distribution
+                            .add_distribution_bucket(&value),
+                    }
+                }
+                logger::debug!(
+                    "Analytics Accumulated Results: distribution: {}, results: {:#?}",
+                    distribution,
+                    metrics_accumulator
+                );
+            }
+        }
+    }
+
+    let mut success = 0;
+    let mut total = 0;
+    let mut total_payout_processed_amount = 0;
+    let mut total_payout_processed_amount_in_usd = 0;
+    let mut total_payout_processed_count = 0;
+    let query_data: Vec<PayoutMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| {
+            let mut collected_values = val.collect();
+            if let Some(success_count) = collected_values.successful_payouts {
+                success += success_count;
+            }
+            if let Some(total_count) = collected_values.total_payouts {
+                total += total_count;
+            }
+            if let Some(amount) = collected_values.payout_processed_amount {
+                let amount_in_usd = if let Some(ex_rates) = ex_rates {
+                    id.currency
+                        .and_then(|currency| {
+                            i64::try_from(amount)
+                                .inspect_err(|e| logger::error!("Amount conversion error: {:?}", e))
+                                .ok()
+                                .and_then(|amount_i64| {
+                                    convert(ex_rates, currency, Currency::USD, amount_i64)
+                                        .inspect_err(|e| {
+                                            logger::error!("Currency conversion error: {:?}", e)
+                                        })
+                                        .ok()
+                                })
+                        })
+                        .map(|amount| (amount * rust_decimal::Decimal::new(100, 0)).to_u64())
+                        .unwrap_or_default()
+                } else {
+                    None
+                };
+                collected_values.payout_processed_amount_in_usd = amount_in_usd;
+                total_payout_processed_amount += amount;
+                total_payout_processed_amount_in_usd += amount_in_usd.unwrap_or(0);
+            }
+            if let Some(count) = collected_values.payout_processed_count {
+                total_payout_processed_count += count;
+            }
+            PayoutMetricsBucketResponse {
+                values: collected_values,
+                dimensions: id,
+            }
+        })
+        .collect();
+    let total_payout_success_rate = match (success, total) {
+        (s, t) if t > 0 => Some(f64::from(s) * 100.0 / f64::from(t)),
+        _ => None,
+    };
+    Ok(PayoutsMetricsResponse {
+        query_data,
+        meta_data: [PayoutsAnalyticsMetadata {
+            total_payout_success_rate,
+            total_payout_processed_amount: Some(total_payout_processed_amount),
+            total_payout_processed_amount_in_usd: if ex_rates.is_some() {
+                Some(total_payout_processed_amount_in_usd)
+            } else {
+                None
+            },
+            total_payout_processed_count: Some(total_payout_processed_count),
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetPayoutFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<PayoutFiltersResponse> {
+    let mut res = PayoutFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+                        AnalyticsProvider::Sqlx(pool) => {
+                get_payout_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                        AnalyticsProvider::Clickhouse(pool) => {
+                get_payout_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                    AnalyticsProvider::CombinedCkh(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics filters")
+                    },
+                    _ => {}
+                };
+                ckh_result
+            }
+                    AnalyticsProvider::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics filters")
+                    },
+                    _ => {}
+                };
+                sqlx_result
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: PayoutFilterRow| match dim {
+            PayoutDimensions::Currency => fil.destination_currency.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::Status => fil.status.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::Connector => fil.connector,
+            PayoutDimensions::PayoutType => fil.payout_type.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::ProfileId => fil.profile_id,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(PayoutFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
@@ -0,0 +1,90 @@
+use api_models::analytics::{
+    payouts::{
+        PayoutDimensions, PayoutDistributions, PayoutFilters, PayoutMetricsBucketIdentifier,
+    },
+    PayoutDistributionBody, TimeRange, TimeSeries,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+mod payout_error_message;
+
+#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+pub struct PayoutDistributionRow {
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub profile_id: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutDistributionAnalytics: LoadRow<PayoutDistributionRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutDistribution<T>
+where
+    T: AnalyticsDataSource + PayoutDistributionAnalytics,
+{
+    #[allow(clippy::too_many_arguments)]
+    async fn load_distribution(
+        &self,
+        distribution: &PayoutDistributionBody,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<TimeSeries>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutDistribution<T> for PayoutDistributions
+where
+    T: AnalyticsDataSource + PayoutDistributionAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    TimeSeries: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_distribution(
+        &self,
+        distribution: &PayoutDistributionBody,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: &Option<TimeSeries>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(PayoutMetricsBucketIdentifier, PayoutDistributionRow)>> {
+        match self {
+            Self::PayoutErrorMessage => {
+                payout_error_message::PayoutErrorMessage
+                    .load_distribution(
+                        distribution,
+                        dimensions,
+                        auth,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use api_models::analytics::{payouts::PayoutDimensions, Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::{Currency, PayoutStatus, PayoutType};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
+        LoadRow,
+    },
+};
+pub trait PayoutFilterAnalytics: LoadRow<PayoutFilterRow> {}
+
+pub async fn get_payout_filter_for_dimension<T>(
+    dimension: PayoutDimensions,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<PayoutFilterRow>>
+where
+    T: AnalyticsDataSource + PayoutFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payout);
+
+    query_builder.add_select_column(dimension).switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder.set_distinct();
+
+    query_builder
+        .execute_query::<PayoutFilterRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct PayoutFilterRow {
+    pub destination_currency: Option<DBEnumWrapper<Currency>>,
+    pub status: Option<DBEnumWrapper<PayoutStatus>>,
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<PayoutType>>,
+    pub profile_id: Option<String>,
+}
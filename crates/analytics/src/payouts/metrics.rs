@@ -0,0 +1,113 @@
+use api_models::analytics::{
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
+    TimeRange, TimeSeries,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+mod payout_avg_time_to_fulfilment;
+mod payout_count;
+mod payout_processed_amount;
+mod payout_success_rate;
+use std::collections::HashSet;
+
+use payout_avg_time_to_fulfilment::PayoutAvgTimeToFulfilment;
+use payout_count::PayoutCount;
+use payout_processed_amount::PayoutProcessedAmount;
+use payout_success_rate::PayoutSuccessRate;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+
+/// Number of seconds a payout took to move from creation to its latest status.
+///
+/// The expression differs between data sources, hence each of them provides its own `ToSql`
+/// implementation.
+#[derive(Debug, Clone, Copy)]
+pub struct PayoutFulfilmentTime;
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PayoutMetricRow {
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub profile_id: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutMetricAnalytics: LoadRow<PayoutMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutMetric<T>
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    TimeSeries: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<PayoutFulfilmentTime>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<TimeSeries>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutMetric<T> for PayoutMetrics
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    TimeSeries: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Aggregate<PayoutFulfilmentTime>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<TimeSeries>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        match self {
+            Self::PayoutSuccessRate => {
+                PayoutSuccessRate::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutCount => {
+                PayoutCount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessedAmount => {
+                PayoutProcessedAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutAvgTimeToFulfilment => {
+                PayoutAvgTimeToFulfilment::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
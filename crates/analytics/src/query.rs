@@ -5,20 +5,26 @@ use api_models::{
         self as analytics_api,
         api_event::ApiEventDimensions,
         auth_events::{AuthEventDimensions, AuthEventFlows},
+        decline_reasons::DeclineReasonDimensions,
         disputes::DisputeDimensions,
         frm::{FrmDimensions, FrmTransactionType},
         payment_intents::PaymentIntentDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
+        payouts::{PayoutDimensions, PayoutDistributions},
+        processing_cost::ProcessingCostDimensions,
         refunds::{RefundDimensions, RefundDistributions, RefundType},
+        routing_rules::RoutingRuleDimensions,
         sdk_events::{SdkEventDimensions, SdkEventNames},
-        Granularity,
+        Granularity, TimeSeries,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType,
+        PaymentMethod, PaymentMethodType, PayoutStatus, PayoutType,
     },
     refunds::RefundStatus,
 };
+use chrono::{Offset, TimeZone};
+use chrono_tz::Tz;
 use common_enums::{
     AuthenticationConnectors, AuthenticationStatus, DecoupledAuthenticationType, TransactionStatus,
 };
@@ -73,7 +79,6 @@ where
     T: AnalyticsDataSource,
     time::PrimitiveDateTime: ToSql<T>,
     AnalyticsCollection: ToSql<T>,
-    Granularity: GroupByClause<T>,
 {
     fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
         builder.add_custom_filter_clause("created_at", self.start_time, FilterTypes::Gte)?;
@@ -85,27 +90,57 @@ where
 }
 
 impl GroupByClause<super::SqlxClient> for Granularity {
+    fn set_group_by_clause(
+        &self,
+        builder: &mut QueryBuilder<super::SqlxClient>,
+    ) -> QueryResult<()> {
+        TimeSeries::from(*self).set_group_by_clause(builder)
+    }
+}
+
+impl GroupByClause<super::ClickhouseClient> for Granularity {
+    fn set_group_by_clause(
+        &self,
+        builder: &mut QueryBuilder<super::ClickhouseClient>,
+    ) -> QueryResult<()> {
+        TimeSeries::from(*self).set_group_by_clause(builder)
+    }
+}
+
+impl GroupByClause<super::SqlxClient> for TimeSeries {
     fn set_group_by_clause(
         &self,
         builder: &mut QueryBuilder<super::SqlxClient>,
     ) -> QueryResult<()> {
         let trunc_scale = self.get_lowest_common_granularity_level();
 
-        let granularity_bucket_scale = match self {
-            Self::OneMin => None,
-            Self::FiveMin | Self::FifteenMin | Self::ThirtyMin => Some("minute"),
-            Self::OneHour | Self::OneDay => None,
+        let granularity_bucket_scale = match self.granularity {
+            Granularity::OneMin => None,
+            Granularity::FiveMin | Granularity::FifteenMin | Granularity::ThirtyMin => {
+                Some("minute")
+            }
+            Granularity::OneHour | Granularity::OneDay => None,
         };
 
         let granularity_divisor = self.get_bucket_size();
 
+        // `created_at` is stored as a UTC timestamp without zone, shift it to the
+        // wall clock time of the requested zone before truncating
+        let created_at = match self.time_zone {
+            Some(time_zone) => format!(
+                "(created_at AT TIME ZONE 'UTC' AT TIME ZONE '{}')",
+                time_zone.name()
+            ),
+            None => "created_at".to_string(),
+        };
+
         builder
-            .add_group_by_clause(format!("DATE_TRUNC('{trunc_scale}', created_at)"))
+            .add_group_by_clause(format!("DATE_TRUNC('{trunc_scale}', {created_at})"))
             .attach_printable("Error adding time prune group by")?;
         if let Some(scale) = granularity_bucket_scale {
             builder
                 .add_group_by_clause(format!(
-                    "FLOOR(DATE_PART('{scale}', created_at)/{granularity_divisor})"
+                    "FLOOR(DATE_PART('{scale}', {created_at})/{granularity_divisor})"
                 ))
                 .attach_printable("Error adding time binning group by")?;
         }
@@ -113,26 +148,36 @@ impl GroupByClause<super::SqlxClient> for Granularity {
     }
 }
 
-impl GroupByClause<super::ClickhouseClient> for Granularity {
+impl GroupByClause<super::ClickhouseClient> for TimeSeries {
     fn set_group_by_clause(
         &self,
         builder: &mut QueryBuilder<super::ClickhouseClient>,
     ) -> QueryResult<()> {
-        let interval = match self {
-            Self::OneMin => "toStartOfMinute(created_at)",
-            Self::FiveMin => "toStartOfFiveMinutes(created_at)",
-            Self::FifteenMin => "toStartOfFifteenMinutes(created_at)",
-            Self::ThirtyMin => "toStartOfInterval(created_at, INTERVAL 30 minute)",
-            Self::OneHour => "toStartOfHour(created_at)",
-            Self::OneDay => "toStartOfDay(created_at)",
-        };
-
         builder
-            .add_group_by_clause(interval)
+            .add_group_by_clause(clickhouse_time_bucket(self))
             .attach_printable("Error adding interval group by")
     }
 }
 
+/// Start of the bucket containing `created_at`, aligned in the time zone of the series
+fn clickhouse_time_bucket(time_series: &TimeSeries) -> String {
+    let time_zone = time_series
+        .time_zone
+        .map(|time_zone| format!(", '{}'", time_zone.name()))
+        .unwrap_or_default();
+
+    match time_series.granularity {
+        Granularity::OneMin => format!("toStartOfMinute(created_at{time_zone})"),
+        Granularity::FiveMin => format!("toStartOfFiveMinutes(created_at{time_zone})"),
+        Granularity::FifteenMin => format!("toStartOfFifteenMinutes(created_at{time_zone})"),
+        Granularity::ThirtyMin => {
+            format!("toStartOfInterval(created_at, INTERVAL 30 minute{time_zone})")
+        }
+        Granularity::OneHour => format!("toStartOfHour(created_at{time_zone})"),
+        Granularity::OneDay => format!("toStartOfDay(created_at{time_zone})"),
+    }
+}
+
 #[derive(strum::Display)]
 #[strum(serialize_all = "lowercase")]
 pub enum TimeGranularityLevel {
@@ -221,6 +266,96 @@ impl SeriesBucket for Granularity {
     }
 }
 
+impl SeriesBucket for TimeSeries {
+    type SeriesType = time::PrimitiveDateTime;
+
+    type GranularityLevel = TimeGranularityLevel;
+
+    fn get_lowest_common_granularity_level(&self) -> Self::GranularityLevel {
+        self.granularity.get_lowest_common_granularity_level()
+    }
+
+    fn get_bucket_size(&self) -> u8 {
+        self.granularity.get_bucket_size()
+    }
+
+    fn clip_to_start(
+        &self,
+        value: Self::SeriesType,
+    ) -> error_stack::Result<Self::SeriesType, PostProcessingError> {
+        match self.time_zone {
+            Some(time_zone) => self
+                .granularity
+                .clip_to_start(utc_to_local(value, time_zone))
+                .map(|clipped| local_to_utc(clipped, time_zone)),
+            None => self.granularity.clip_to_start(value),
+        }
+    }
+
+    fn clip_to_end(
+        &self,
+        value: Self::SeriesType,
+    ) -> error_stack::Result<Self::SeriesType, PostProcessingError> {
+        match self.time_zone {
+            Some(time_zone) => self
+                .granularity
+                .clip_to_end(utc_to_local(value, time_zone))
+                .map(|clipped| local_to_utc(clipped, time_zone)),
+            None => self.granularity.clip_to_end(value),
+        }
+    }
+}
+
+/// Interprets the bounds of a requested time range as wall clock times in the requested
+/// time zone (if any) and converts them to the UTC timestamps stored by the backends.
+pub fn normalize_time_range(
+    time_range: analytics_api::TimeRange,
+    time_zone: Option<Tz>,
+) -> analytics_api::TimeRange {
+    match time_zone {
+        Some(time_zone) => analytics_api::TimeRange {
+            start_time: local_to_utc(time_range.start_time, time_zone),
+            end_time: time_range
+                .end_time
+                .map(|end_time| local_to_utc(end_time, time_zone)),
+        },
+        None => time_range,
+    }
+}
+
+fn utc_to_local(value: time::PrimitiveDateTime, time_zone: Tz) -> time::PrimitiveDateTime {
+    let offset = to_naive_date_time(value)
+        .map(|naive| {
+            time_zone
+                .offset_from_utc_datetime(&naive)
+                .fix()
+                .local_minus_utc()
+        })
+        .unwrap_or_default();
+    value.saturating_add(time::Duration::seconds(offset.into()))
+}
+
+fn local_to_utc(value: time::PrimitiveDateTime, time_zone: Tz) -> time::PrimitiveDateTime {
+    // Wall clock times skipped by a DST transition do not map to any instant,
+    // fall back to the offset in effect at the same UTC time for those
+    let offset = to_naive_date_time(value)
+        .map(|naive| {
+            time_zone
+                .offset_from_local_datetime(&naive)
+                .earliest()
+                .unwrap_or_else(|| time_zone.offset_from_utc_datetime(&naive))
+                .fix()
+                .local_minus_utc()
+        })
+        .unwrap_or_default();
+    value.saturating_sub(time::Duration::seconds(offset.into()))
+}
+
+fn to_naive_date_time(value: time::PrimitiveDateTime) -> Option<chrono::NaiveDateTime> {
+    chrono::DateTime::from_timestamp(value.assume_utc().unix_timestamp(), value.nanosecond())
+        .map(|date_time| date_time.naive_utc())
+}
+
 #[derive(thiserror::Error, Debug)]
 pub enum QueryBuildingError {
     #[allow(dead_code)]
@@ -492,6 +627,9 @@ impl_to_sql_for_to_string!(
     &PaymentDistributions,
     RefundDimensions,
     &RefundDistributions,
+    &PayoutDimensions,
+    PayoutDimensions,
+    &PayoutDistributions,
     FrmDimensions,
     PaymentMethod,
     PaymentMethodType,
@@ -504,6 +642,8 @@ impl_to_sql_for_to_string!(
     storage_enums::RefundStatus,
     Currency,
     RefundType,
+    PayoutStatus,
+    PayoutType,
     FrmTransactionType,
     TransactionStatus,
     AuthenticationStatus,
@@ -528,7 +668,11 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    DeclineReasonDimensions,
+    &RoutingRuleDimensions,
+    RoutingRuleDimensions,
+    ProcessingCostDimensions
 );
 
 #[derive(Debug, Clone, Copy)]
@@ -539,6 +683,7 @@ pub enum FilterTypes {
     In,
     Gte,
     Lte,
+    Lt,
     Gt,
     Like,
     NotLike,
@@ -554,6 +699,7 @@ pub fn filter_type_to_sql(l: &str, op: FilterTypes, r: &str) -> String {
         FilterTypes::Gte => format!("{l} >= '{r}'"),
         FilterTypes::Gt => format!("{l} > {r}"),
         FilterTypes::Lte => format!("{l} <= '{r}'"),
+        FilterTypes::Lt => format!("{l} < '{r}'"),
         FilterTypes::Like => format!("{l} LIKE '%{r}%'"),
         FilterTypes::NotLike => format!("{l} NOT LIKE '%{r}%'"),
         FilterTypes::IsNotNull => format!("{l} IS NOT NULL"),
@@ -752,17 +898,12 @@ where
         Ok(())
     }
 
-    pub fn add_granularity_in_mins(&mut self, granularity: Granularity) -> QueryResult<()> {
-        let interval = match granularity {
-            Granularity::OneMin => "1",
-            Granularity::FiveMin => "5",
-            Granularity::FifteenMin => "15",
-            Granularity::ThirtyMin => "30",
-            Granularity::OneHour => "60",
-            Granularity::OneDay => "1440",
-        };
+    pub fn add_granularity_in_mins(&mut self, time_series: TimeSeries) -> QueryResult<()> {
+        // Buckets are aligned the same way as the other domains group by them, and reported
+        // as UTC timestamps like the bucket bounds of the other domains
         let _ = self.add_select_column(format!(
-            "toStartOfInterval(created_at, INTERVAL {interval} MINUTE) as time_bucket"
+            "toTimeZone({}, 'UTC') as time_bucket",
+            clickhouse_time_bucket(&time_series)
         ));
         Ok(())
     }
@@ -0,0 +1,334 @@
+use std::{collections::HashMap, io::Write};
+
+use api_models::analytics::{ReportType, TimeRange};
+use common_utils::errors::CustomResult;
+use error_stack::{report, ResultExt};
+use flate2::{write::GzEncoder, Compression};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    query::{Aggregate, FilterTypes, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, TableEngine},
+    AnalyticsProvider,
+};
+
+/// Rows of a report are fetched one chunk of the requested time range at a time, so that a
+/// report over a long time range does not have to be loaded from the database in a single query
+const REPORT_CHUNK_DURATION: time::Duration = time::Duration::days(1);
+
+pub trait ReportAnalytics: LoadRow<ReportRow> {
+    /// Select expression for a report column, aliased to the column name
+    fn report_column(column: &'static str) -> String;
+}
+
+/// A row of a report, mapping each report column to its value
+#[derive(Debug, Default, serde::Deserialize)]
+#[serde(transparent)]
+pub struct ReportRow(pub HashMap<String, serde_json::Value>);
+
+impl ReportRow {
+    fn get_value(&self, column: &str) -> String {
+        match self.0.get(column) {
+            None | Some(serde_json::Value::Null) => String::new(),
+            Some(serde_json::Value::String(value)) => value.clone(),
+            Some(value) => value.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFileFormat {
+    #[default]
+    Csv,
+    CsvGzip,
+}
+
+impl ReportFileFormat {
+    pub fn get_file_extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::CsvGzip => "csv.gz",
+        }
+    }
+
+    pub fn get_content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::CsvGzip => "application/gzip",
+        }
+    }
+}
+
+fn get_report_collection(report_type: ReportType) -> AnalyticsCollection {
+    match report_type {
+        ReportType::Payment => AnalyticsCollection::Payment,
+        ReportType::Refund => AnalyticsCollection::Refund,
+        ReportType::Dispute => AnalyticsCollection::Dispute,
+        ReportType::Authentication => AnalyticsCollection::Authentications,
+    }
+}
+
+fn get_report_columns(report_type: ReportType) -> &'static [&'static str] {
+    match report_type {
+        ReportType::Payment => &[
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "status",
+            "amount",
+            "currency",
+            "connector",
+            "payment_method",
+            "payment_method_type",
+            "authentication_type",
+            "capture_method",
+            "connector_transaction_id",
+            "error_code",
+            "error_message",
+            "created_at",
+            "modified_at",
+        ],
+        ReportType::Refund => &[
+            "refund_id",
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "refund_status",
+            "refund_type",
+            "total_amount",
+            "refund_amount",
+            "currency",
+            "connector",
+            "connector_refund_id",
+            "refund_reason",
+            "refund_error_code",
+            "refund_error_message",
+            "created_at",
+            "modified_at",
+        ],
+        ReportType::Dispute => &[
+            "dispute_id",
+            "payment_id",
+            "attempt_id",
+            "merchant_id",
+            "profile_id",
+            "dispute_stage",
+            "dispute_status",
+            "dispute_amount",
+            "currency",
+            "connector",
+            "connector_dispute_id",
+            "connector_reason",
+            "connector_reason_code",
+            "challenge_required_by",
+            "created_at",
+            "modified_at",
+        ],
+        ReportType::Authentication => &[
+            "authentication_id",
+            "payment_id",
+            "merchant_id",
+            "profile_id",
+            "authentication_connector",
+            "authentication_status",
+            "authentication_type",
+            "trans_status",
+            "eci",
+            "message_version",
+            "error_code",
+            "error_message",
+            "created_at",
+            "modified_at",
+        ],
+    }
+}
+
+async fn get_report_rows<T>(
+    report_type: ReportType,
+    auth: &AuthInfo,
+    start_time: PrimitiveDateTime,
+    end_time: PrimitiveDateTime,
+    is_last_chunk: bool,
+    pool: &T,
+) -> AnalyticsResult<Vec<ReportRow>>
+where
+    T: AnalyticsDataSource + ReportAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let collection = get_report_collection(report_type);
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
+
+    for column in get_report_columns(report_type) {
+        query_builder
+            .add_select_column(T::report_column(column))
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+
+    query_builder
+        .add_custom_filter_clause("created_at", start_time, FilterTypes::Gte)
+        .change_context(AnalyticsError::UnknownError)?;
+    // Chunks are half open, except for the last one which includes the end of the time range
+    query_builder
+        .add_custom_filter_clause(
+            "created_at",
+            end_time,
+            if is_last_chunk {
+                FilterTypes::Lte
+            } else {
+                FilterTypes::Lt
+            },
+        )
+        .change_context(AnalyticsError::UnknownError)?;
+    auth.set_filter_clause(&mut query_builder)
+        .change_context(AnalyticsError::UnknownError)?;
+
+    // Rows of collapsing tables are grouped so that only the latest version of each record,
+    // which has not been cancelled out by its sign, is included in the report
+    if let TableEngine::CollapsingMergeTree { sign } = T::get_table_engine(collection) {
+        for column in get_report_columns(report_type) {
+            query_builder
+                .add_group_by_clause(*column)
+                .change_context(AnalyticsError::UnknownError)?;
+        }
+        query_builder
+            .add_having_clause(
+                Aggregate::Sum {
+                    field: sign,
+                    alias: None,
+                },
+                FilterTypes::Gte,
+                "1",
+            )
+            .change_context(AnalyticsError::UnknownError)?;
+    }
+
+    query_builder
+        .add_order_by_clause("created_at", Order::Ascending)
+        .change_context(AnalyticsError::UnknownError)?;
+
+    query_builder
+        .execute_query::<ReportRow, _>(pool)
+        .await
+        .change_context(AnalyticsError::UnknownError)?
+        .change_context(AnalyticsError::UnknownError)
+}
+
+enum ReportWriter {
+    Csv(csv::Writer<Vec<u8>>),
+    CsvGzip(csv::Writer<GzEncoder<Vec<u8>>>),
+}
+
+impl ReportWriter {
+    fn new(file_format: ReportFileFormat) -> Self {
+        match file_format {
+            ReportFileFormat::Csv => Self::Csv(csv::Writer::from_writer(Vec::new())),
+            ReportFileFormat::CsvGzip => Self::CsvGzip(csv::Writer::from_writer(GzEncoder::new(
+                Vec::new(),
+                Compression::default(),
+            ))),
+        }
+    }
+
+    fn write_record<I>(&mut self, record: I) -> CustomResult<(), AnalyticsError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        match self {
+            Self::Csv(writer) => writer.write_record(record),
+            Self::CsvGzip(writer) => writer.write_record(record),
+        }
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to write report record")
+    }
+
+    fn finish(self) -> CustomResult<Vec<u8>, AnalyticsError> {
+        match self {
+            Self::Csv(writer) => writer.into_inner().map_err(|error| {
+                report!(AnalyticsError::UnknownError).attach_printable(error.to_string())
+            }),
+            Self::CsvGzip(writer) => writer
+                .into_inner()
+                .map_err(|error| {
+                    report!(AnalyticsError::UnknownError).attach_printable(error.to_string())
+                })
+                .and_then(|mut encoder| {
+                    encoder
+                        .flush()
+                        .and_then(|_| encoder.finish())
+                        .change_context(AnalyticsError::UnknownError)
+                }),
+        }
+        .attach_printable("Failed to finish writing report file")
+    }
+}
+
+/// Generates the report file for the time range, reading its rows from the analytics source
+/// configured for reports.
+pub async fn generate_report(
+    pool: &AnalyticsProvider,
+    report_type: ReportType,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    file_format: ReportFileFormat,
+) -> AnalyticsResult<Vec<u8>> {
+    let columns = get_report_columns(report_type);
+    let mut writer = ReportWriter::new(file_format);
+    writer.write_record(columns)?;
+
+    let end_time = time_range
+        .end_time
+        .unwrap_or_else(common_utils::date_time::now);
+    let mut chunk_start = time_range.start_time;
+
+    while chunk_start <= end_time {
+        let chunk_end = chunk_start
+            .saturating_add(REPORT_CHUNK_DURATION)
+            .min(end_time);
+        let is_last_chunk = chunk_end == end_time;
+
+        let rows = match pool {
+            AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+                get_report_rows(
+                    report_type,
+                    auth,
+                    chunk_start,
+                    chunk_end,
+                    is_last_chunk,
+                    pool,
+                )
+                .await
+            }
+            AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+                get_report_rows(
+                    report_type,
+                    auth,
+                    chunk_start,
+                    chunk_end,
+                    is_last_chunk,
+                    pool,
+                )
+                .await
+            }
+        }?;
+
+        for row in rows {
+            writer.write_record(columns.iter().map(|column| row.get_value(column)))?;
+        }
+
+        if is_last_chunk {
+            break;
+        }
+        chunk_start = chunk_end;
+    }
+
+    writer.finish()
+}
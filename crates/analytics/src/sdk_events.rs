@@ -2,8 +2,9 @@ pub mod accumulator;
 mod core;
 pub mod events;
 pub mod filters;
+pub mod funnel;
 pub mod metrics;
 pub mod types;
 pub use accumulator::{SdkEventMetricAccumulator, SdkEventMetricsAccumulator};
 
-pub use self::core::{get_filters, get_metrics, sdk_events_core};
+pub use self::core::{get_filters, get_metrics, get_payment_funnel, sdk_events_core};
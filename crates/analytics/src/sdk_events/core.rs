@@ -2,21 +2,25 @@ use std::collections::HashMap;
 
 use api_models::analytics::{
     sdk_events::{
-        MetricsBucketResponse, SdkEventMetrics, SdkEventMetricsBucketIdentifier, SdkEventsRequest,
+        MetricsBucketResponse, PaymentFunnelBucket, PaymentFunnelResponse, PaymentFunnelStep,
+        PaymentFunnelStepValue, SdkEventMetrics, SdkEventMetricsBucketIdentifier, SdkEventsRequest,
     },
-    AnalyticsMetadata, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, MetricsResponse,
-    SdkEventFiltersResponse,
+    AnalyticsMetadata, GetPaymentFunnelRequest, GetSdkEventFiltersRequest,
+    GetSdkEventMetricRequest, MetricsResponse, SdkEventFiltersResponse,
 };
 use common_utils::errors::ReportSwitchExt;
 use error_stack::ResultExt;
 use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
 
 use super::{
     events::{get_sdk_event, SdkEventsResult},
+    funnel::get_payment_funnel_data,
     SdkEventMetricsAccumulator,
 };
 use crate::{
     errors::{AnalyticsError, AnalyticsResult},
+    query::normalize_time_range,
     sdk_events::SdkEventMetricAccumulator,
     types::FiltersError,
     AnalyticsProvider,
@@ -48,6 +52,13 @@ pub async fn get_metrics(
     publishable_key: &String,
     req: GetSdkEventMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<MetricsBucketResponse>> {
+    let req = GetSdkEventMetricRequest {
+        time_range: normalize_time_range(req.time_range, req.time_zone),
+        time_series: req
+            .time_series
+            .map(|series| series.with_time_zone(req.time_zone)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         SdkEventMetricsBucketIdentifier,
         SdkEventMetricsAccumulator,
@@ -65,7 +76,7 @@ pub async fn get_metrics(
                     &req.group_by_names.clone(),
                     &publishable_key_scoped,
                     &req.filters,
-                    req.time_series.map(|t| t.granularity),
+                    req.time_series,
                     &req.time_range,
                 )
                 .await
@@ -180,3 +191,71 @@ pub async fn get_filters(
 
     Ok(res)
 }
+
+#[instrument(skip_all)]
+pub async fn get_payment_funnel(
+    pool: &AnalyticsProvider,
+    publishable_key: &str,
+    merchant_id: &common_utils::id_type::MerchantId,
+    req: GetPaymentFunnelRequest,
+) -> AnalyticsResult<PaymentFunnelResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Payment funnel not implemented for SQLX",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => get_payment_funnel_data(
+            ckh_pool,
+            publishable_key,
+            merchant_id,
+            &req.group_by_names,
+            &req.time_range,
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)?,
+    };
+
+    let get_rate = |count: u64, total: u64| -> Option<f64> {
+        if total == 0 {
+            return None;
+        }
+        Some(f64::from(u32::try_from(count).ok()?) * 100.0 / f64::from(u32::try_from(total).ok()?))
+    };
+
+    let query_data = rows
+        .into_iter()
+        .map(|row| {
+            let mut previous_count = None;
+            let steps = PaymentFunnelStep::iter()
+                .map(|step| {
+                    let count = row.get_step_count(step);
+                    let step_value = PaymentFunnelStepValue {
+                        step,
+                        count,
+                        conversion_rate: previous_count
+                            .and_then(|previous_count| get_rate(count, previous_count)),
+                        drop_off_count: previous_count
+                            .map(|previous_count: u64| previous_count.saturating_sub(count))
+                            .unwrap_or_default(),
+                    };
+                    previous_count = Some(count);
+                    step_value
+                })
+                .collect();
+
+            PaymentFunnelBucket {
+                overall_conversion_rate: get_rate(
+                    row.get_step_count(PaymentFunnelStep::PaymentAuthorized),
+                    row.get_step_count(PaymentFunnelStep::CheckoutRendered),
+                ),
+                payment_method: row.payment_method,
+                platform: row.platform,
+                browser_name: row.browser_name,
+                steps,
+            }
+        })
+        .collect();
+
+    Ok(PaymentFunnelResponse { query_data })
+}
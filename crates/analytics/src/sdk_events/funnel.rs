@@ -0,0 +1,202 @@
+use api_models::analytics::{
+    sdk_events::{PaymentFunnelDimensions, PaymentFunnelStep, SdkEventNames},
+    TimeRange,
+};
+use common_utils::{errors::ParsingError, id_type};
+use diesel_models::enums::AttemptStatus;
+use error_stack::ResultExt;
+use strum::IntoEnumIterator;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    query::{FilterTypes, QueryBuilder, QueryFilter},
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Number of payments which reached each step of the funnel, for a combination of dimensions
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PaymentFunnelRow {
+    #[serde(default)]
+    pub payment_method: Option<String>,
+    #[serde(default)]
+    pub platform: Option<String>,
+    #[serde(default)]
+    pub browser_name: Option<String>,
+    pub checkout_rendered: u64,
+    pub payment_method_selected: u64,
+    pub payment_data_filled: u64,
+    pub payment_submitted: u64,
+    pub payment_authorized: u64,
+}
+
+impl PaymentFunnelRow {
+    pub fn get_step_count(&self, step: PaymentFunnelStep) -> u64 {
+        match step {
+            PaymentFunnelStep::CheckoutRendered => self.checkout_rendered,
+            PaymentFunnelStep::PaymentMethodSelected => self.payment_method_selected,
+            PaymentFunnelStep::PaymentDataFilled => self.payment_data_filled,
+            PaymentFunnelStep::PaymentSubmitted => self.payment_submitted,
+            PaymentFunnelStep::PaymentAuthorized => self.payment_authorized,
+        }
+    }
+}
+
+impl TryInto<PaymentFunnelRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentFunnelRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentFunnelRow in clickhouse results",
+        ))
+    }
+}
+
+/// SDK events which show that a payment reached the step, a payment which reached a later step
+/// has gone through the earlier steps as well, even if their events were not recorded
+fn get_step_events(step: PaymentFunnelStep) -> &'static [SdkEventNames] {
+    match step {
+        PaymentFunnelStep::CheckoutRendered => &[
+            SdkEventNames::AppRendered,
+            SdkEventNames::PaymentMethodChanged,
+            SdkEventNames::PaymentDataFilled,
+            SdkEventNames::PaymentAttempt,
+        ],
+        PaymentFunnelStep::PaymentMethodSelected => &[
+            SdkEventNames::PaymentMethodChanged,
+            SdkEventNames::PaymentDataFilled,
+            SdkEventNames::PaymentAttempt,
+        ],
+        PaymentFunnelStep::PaymentDataFilled => &[
+            SdkEventNames::PaymentDataFilled,
+            SdkEventNames::PaymentAttempt,
+        ],
+        PaymentFunnelStep::PaymentSubmitted | PaymentFunnelStep::PaymentAuthorized => {
+            &[SdkEventNames::PaymentAttempt]
+        }
+    }
+}
+
+/// Expression attributing a dimension to a payment from all of its SDK events
+fn get_dimension_expression(dimension: PaymentFunnelDimensions) -> String {
+    match dimension {
+        // The payment method is only known once the customer selects it, so the latest one
+        // selected for the payment is used
+        PaymentFunnelDimensions::PaymentMethod => format!(
+            "argMaxIf(payment_method, created_at, payment_method IS NOT NULL AND payment_method != '') AS {dimension}"
+        ),
+        PaymentFunnelDimensions::Platform | PaymentFunnelDimensions::BrowserName => {
+            format!("any({dimension}) AS {dimension}")
+        }
+    }
+}
+
+pub async fn get_payment_funnel_data(
+    clickhouse_client: &ClickhouseClient,
+    publishable_key: &str,
+    merchant_id: &id_type::MerchantId,
+    dimensions: &[PaymentFunnelDimensions],
+    time_range: &TimeRange,
+) -> MetricsResult<Vec<PaymentFunnelRow>> {
+    // Payments with an attempt that was authorized, from the payment attempts of the merchant
+    let mut authorized_query_builder =
+        QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::Payment);
+    authorized_query_builder
+        .add_select_column("payment_id")
+        .change_context(MetricsError::QueryBuildingError)?;
+    authorized_query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .change_context(MetricsError::QueryBuildingError)?;
+    authorized_query_builder
+        .add_filter_in_range_clause(
+            "status",
+            &[
+                AttemptStatus::Authorized,
+                AttemptStatus::Charged,
+                AttemptStatus::PartialCharged,
+            ],
+        )
+        .change_context(MetricsError::QueryBuildingError)?;
+    time_range
+        .set_filter_clause(&mut authorized_query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+    authorized_query_builder
+        .add_group_by_clause("payment_id")
+        .change_context(MetricsError::QueryBuildingError)?;
+    let authorized_query = authorized_query_builder
+        .build_query()
+        .attach_printable("Error building authorized payments query")
+        .change_context(MetricsError::QueryBuildingError)?;
+
+    // Steps reached by each payment, along with the dimensions attributed to it
+    let mut payments_query_builder =
+        QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::SdkEventsAnalytics);
+    payments_query_builder
+        .add_select_column("payment_id")
+        .change_context(MetricsError::QueryBuildingError)?;
+    for dimension in dimensions {
+        payments_query_builder
+            .add_select_column(get_dimension_expression(*dimension))
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+    for step in [
+        PaymentFunnelStep::CheckoutRendered,
+        PaymentFunnelStep::PaymentMethodSelected,
+        PaymentFunnelStep::PaymentDataFilled,
+        PaymentFunnelStep::PaymentSubmitted,
+    ] {
+        let events = payments_query_builder
+            .transform_to_sql_values(get_step_events(step))
+            .change_context(MetricsError::QueryBuildingError)?;
+        payments_query_builder
+            .add_select_column(format!("max(event_name IN ({events})) AS {step}"))
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+    payments_query_builder
+        .add_select_column(format!(
+            "max(event_name = '{}' AND payment_id IN ({authorized_query})) AS {}",
+            SdkEventNames::PaymentAttempt,
+            PaymentFunnelStep::PaymentAuthorized
+        ))
+        .change_context(MetricsError::QueryBuildingError)?;
+    payments_query_builder
+        .add_filter_clause("merchant_id", publishable_key)
+        .change_context(MetricsError::QueryBuildingError)?;
+    payments_query_builder
+        .add_custom_filter_clause("payment_id", "NULL", FilterTypes::IsNotNull)
+        .change_context(MetricsError::QueryBuildingError)?;
+    time_range
+        .set_filter_clause(&mut payments_query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+    payments_query_builder
+        .add_group_by_clause("payment_id")
+        .change_context(MetricsError::QueryBuildingError)?;
+    let payments_query = payments_query_builder
+        .build_query()
+        .attach_printable("Error building funnel payments query")
+        .change_context(MetricsError::QueryBuildingError)?;
+
+    // The query builder does not support grouping over a subquery, so the funnel is aggregated
+    // over the payments by wrapping the payments query
+    let dimension_columns = dimensions
+        .iter()
+        .map(|dimension| dimension.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let step_columns = PaymentFunnelStep::iter()
+        .map(|step| format!("sum({step}) AS {step}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let query = if dimensions.is_empty() {
+        format!("SELECT {step_columns} FROM ({payments_query}) _")
+    } else {
+        format!(
+            "SELECT {dimension_columns}, {step_columns} FROM ({payments_query}) _ GROUP BY {dimension_columns}"
+        )
+    };
+
+    clickhouse_client
+        .load_results::<PaymentFunnelRow>(&query)
+        .await
+        .change_context(MetricsError::QueryExecutionFailure)
+}
@@ -7,11 +7,48 @@ use error_stack::ResultExt;
 use router_env::tracing;
 use serde_json::Value;
 
+pub mod postgres;
+
 use crate::{
     enums::AuthInfo,
     opensearch::{OpenSearchClient, OpenSearchError, OpenSearchQuery, OpenSearchQueryBuilder},
+    sqlx::SqlxClient,
 };
 
+/// Backend serving the global search
+pub enum SearchProvider<'a> {
+    OpenSearch(&'a OpenSearchClient),
+    /// Searches the Postgres tables directly, for deployments which do not run OpenSearch
+    Postgres(&'a SqlxClient),
+}
+
+impl SearchProvider<'_> {
+    pub async fn msearch_results(
+        &self,
+        req: GetGlobalSearchRequest,
+        search_params: Vec<AuthInfo>,
+        indexes: Vec<SearchIndex>,
+    ) -> CustomResult<Vec<GetSearchResponse>, OpenSearchError> {
+        match self {
+            Self::OpenSearch(client) => msearch_results(client, req, search_params, indexes).await,
+            Self::Postgres(client) => {
+                postgres::msearch_results(client, req, search_params, indexes).await
+            }
+        }
+    }
+
+    pub async fn search_results(
+        &self,
+        req: GetSearchRequestWithIndex,
+        search_params: Vec<AuthInfo>,
+    ) -> CustomResult<GetSearchResponse, OpenSearchError> {
+        match self {
+            Self::OpenSearch(client) => search_results(client, req, search_params).await,
+            Self::Postgres(client) => postgres::search_results(client, req, search_params).await,
+        }
+    }
+}
+
 pub fn convert_to_value<T: Into<Value>>(items: Vec<T>) -> Vec<Value> {
     items.into_iter().map(|item| item.into()).collect()
 }
@@ -168,6 +205,63 @@ pub async fn msearch_results(
                     .switch()?;
             }
         };
+        if let Some(customer_phone) = filters.customer_phone {
+            if !customer_phone.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "customer_phone.keyword".to_string(),
+                        convert_to_value(
+                            customer_phone
+                                .iter()
+                                .filter_map(|phone| {
+                                    // TODO: Add trait based inputs instead of converting this to strings
+                                    serde_json::to_value(phone)
+                                        .ok()
+                                        .and_then(|a| a.as_str().map(|a| a.to_string()))
+                                })
+                                .collect(),
+                        ),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(payout_id) = filters.payout_id {
+            if !payout_id.is_empty() {
+                query_builder
+                    .add_filter_clause("payout_id.keyword".to_string(), convert_to_value(payout_id))
+                    .switch()?;
+            }
+        };
+        if let Some(mandate_id) = filters.mandate_id {
+            if !mandate_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "mandate_id.keyword".to_string(),
+                        convert_to_value(mandate_id),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(payment_method_id) = filters.payment_method_id {
+            if !payment_method_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "payment_method_id.keyword".to_string(),
+                        convert_to_value(payment_method_id),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(fingerprint_id) = filters.fingerprint_id {
+            if !fingerprint_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "fingerprint_id.keyword".to_string(),
+                        convert_to_value(fingerprint_id),
+                    )
+                    .switch()?;
+            }
+        };
     };
 
     if let Some(time_range) = req.time_range {
@@ -374,6 +468,63 @@ pub async fn search_results(
                     .switch()?;
             }
         };
+        if let Some(customer_phone) = filters.customer_phone {
+            if !customer_phone.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "customer_phone.keyword".to_string(),
+                        convert_to_value(
+                            customer_phone
+                                .iter()
+                                .filter_map(|phone| {
+                                    // TODO: Add trait based inputs instead of converting this to strings
+                                    serde_json::to_value(phone)
+                                        .ok()
+                                        .and_then(|a| a.as_str().map(|a| a.to_string()))
+                                })
+                                .collect(),
+                        ),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(payout_id) = filters.payout_id {
+            if !payout_id.is_empty() {
+                query_builder
+                    .add_filter_clause("payout_id.keyword".to_string(), convert_to_value(payout_id))
+                    .switch()?;
+            }
+        };
+        if let Some(mandate_id) = filters.mandate_id {
+            if !mandate_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "mandate_id.keyword".to_string(),
+                        convert_to_value(mandate_id),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(payment_method_id) = filters.payment_method_id {
+            if !payment_method_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "payment_method_id.keyword".to_string(),
+                        convert_to_value(payment_method_id),
+                    )
+                    .switch()?;
+            }
+        };
+        if let Some(fingerprint_id) = filters.fingerprint_id {
+            if !fingerprint_id.is_empty() {
+                query_builder
+                    .add_filter_clause(
+                        "fingerprint_id.keyword".to_string(),
+                        convert_to_value(fingerprint_id),
+                    )
+                    .switch()?;
+            }
+        };
     };
 
     if let Some(time_range) = search_req.time_range {
@@ -0,0 +1,641 @@
+use api_models::analytics::search::{
+    GetGlobalSearchRequest, GetSearchRequestWithIndex, GetSearchResponse, SearchFilters,
+    SearchIndex, SearchStatus,
+};
+use common_utils::{errors::CustomResult, types::TimeRange};
+use error_stack::ResultExt;
+use futures::future::join_all;
+use router_env::tracing;
+use serde_json::Value;
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{enums::AuthInfo, opensearch::OpenSearchError, sqlx::SqlxClient};
+
+/// Number of hits returned for each index in a global search, same as the default size of an
+/// OpenSearch query
+const GLOBAL_SEARCH_HITS_PER_INDEX: i64 = 10;
+
+/// Number of hits up to which the total count is computed, same as the default number of hits
+/// tracked by OpenSearch, so that a broad query does not count every row of the table
+const MAX_SEARCH_HITS_COUNT: i64 = 10_000;
+
+/// Postgres table backing a search index
+struct SearchTable {
+    name: &'static str,
+    /// Columns returned in the hits, the encrypted and sensitive columns are left out
+    columns: &'static [&'static str],
+    /// Identifiers which are matched against the search query, all of which are covered by the
+    /// trigram index of the table
+    search_columns: &'static [&'static str],
+    has_organization_id: bool,
+    has_profile_id: bool,
+}
+
+/// Only the tables stored in Postgres can be searched, the sessionizer indexes are populated
+/// by the OpenSearch pipeline alone
+fn get_search_table(index: SearchIndex) -> Option<SearchTable> {
+    match index {
+        SearchIndex::PaymentAttempts => Some(SearchTable {
+            name: "payment_attempt",
+            columns: &[
+                "payment_id",
+                "attempt_id",
+                "merchant_id",
+                "profile_id",
+                "organization_id",
+                "status",
+                "amount",
+                "currency",
+                "connector",
+                "payment_method",
+                "payment_method_type",
+                "card_network",
+                "connector_transaction_id",
+                "error_code",
+                "error_message",
+                "created_at",
+                "modified_at",
+            ],
+            search_columns: &["payment_id", "attempt_id", "connector_transaction_id"],
+            has_organization_id: true,
+            has_profile_id: true,
+        }),
+        SearchIndex::PaymentIntents => Some(SearchTable {
+            name: "payment_intent",
+            columns: &[
+                "payment_id",
+                "merchant_id",
+                "profile_id",
+                "organization_id",
+                "status",
+                "amount",
+                "currency",
+                "amount_captured",
+                "customer_id",
+                "description",
+                "merchant_order_reference_id",
+                "active_attempt_id",
+                "created_at",
+                "modified_at",
+            ],
+            search_columns: &["payment_id", "customer_id", "merchant_order_reference_id"],
+            has_organization_id: true,
+            has_profile_id: true,
+        }),
+        SearchIndex::Refunds => Some(SearchTable {
+            name: "refund",
+            columns: &[
+                "refund_id",
+                "payment_id",
+                "attempt_id",
+                "merchant_id",
+                "profile_id",
+                "organization_id",
+                "refund_status",
+                "refund_amount",
+                "total_amount",
+                "currency",
+                "connector",
+                "connector_refund_id",
+                "refund_reason",
+                "created_at",
+                "modified_at",
+            ],
+            search_columns: &["refund_id", "payment_id", "connector_refund_id"],
+            has_organization_id: true,
+            has_profile_id: true,
+        }),
+        SearchIndex::Disputes => Some(SearchTable {
+            name: "dispute",
+            columns: &[
+                "dispute_id",
+                "payment_id",
+                "attempt_id",
+                "merchant_id",
+                "profile_id",
+                "organization_id",
+                "dispute_status",
+                "dispute_stage",
+                "dispute_amount",
+                "currency",
+                "connector",
+                "connector_dispute_id",
+                "connector_reason",
+                "created_at",
+                "modified_at",
+            ],
+            search_columns: &["dispute_id", "payment_id", "connector_dispute_id"],
+            has_organization_id: true,
+            has_profile_id: true,
+        }),
+        SearchIndex::Customers => Some(SearchTable {
+            name: "customers",
+            columns: &[
+                "customer_id",
+                "merchant_id",
+                "phone_country_code",
+                "default_payment_method_id",
+                "created_at",
+                "modified_at",
+            ],
+            search_columns: &["customer_id"],
+            has_organization_id: false,
+            has_profile_id: false,
+        }),
+        SearchIndex::Payouts => Some(SearchTable {
+            name: "payouts",
+            columns: &[
+                "payout_id",
+                "merchant_id",
+                "profile_id",
+                "customer_id",
+                "payout_type",
+                "status",
+                "amount",
+                "destination_currency",
+                "source_currency",
+                "created_at",
+                "last_modified_at",
+            ],
+            search_columns: &["payout_id", "customer_id"],
+            has_organization_id: false,
+            has_profile_id: true,
+        }),
+        SearchIndex::Mandates => Some(SearchTable {
+            name: "mandate",
+            columns: &[
+                "mandate_id",
+                "merchant_id",
+                "customer_id",
+                "payment_method_id",
+                "mandate_status",
+                "mandate_type",
+                "mandate_amount",
+                "mandate_currency",
+                "connector",
+                "connector_mandate_id",
+                "original_payment_id",
+                "created_at",
+            ],
+            search_columns: &["mandate_id", "customer_id", "connector_mandate_id"],
+            has_organization_id: false,
+            has_profile_id: false,
+        }),
+        SearchIndex::PaymentMethods => Some(SearchTable {
+            name: "payment_methods",
+            columns: &[
+                "payment_method_id",
+                "merchant_id",
+                "customer_id",
+                "payment_method",
+                "payment_method_type",
+                "status",
+                "created_at",
+                "last_modified",
+                "last_used_at",
+            ],
+            search_columns: &["payment_method_id", "customer_id"],
+            has_organization_id: false,
+            has_profile_id: false,
+        }),
+        SearchIndex::SessionizerPaymentAttempts
+        | SearchIndex::SessionizerPaymentIntents
+        | SearchIndex::SessionizerRefunds
+        | SearchIndex::SessionizerDisputes => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SearchField {
+    Currency,
+    Status,
+    PaymentMethod,
+    Connector,
+    PaymentMethodType,
+    CardNetwork,
+    CardLast4,
+    PaymentId,
+    Amount,
+    CustomerId,
+    CustomerEmail,
+    CustomerPhone,
+    SearchTags,
+    PayoutId,
+    MandateId,
+    PaymentMethodId,
+    FingerprintId,
+}
+
+impl SearchField {
+    /// Enums are matched case insensitively, as done for the keyword fields in OpenSearch
+    fn is_case_sensitive(self) -> bool {
+        !matches!(
+            self,
+            Self::Currency
+                | Self::Status
+                | Self::PaymentMethod
+                | Self::Connector
+                | Self::PaymentMethodType
+                | Self::CardNetwork
+        )
+    }
+
+    /// Column expression holding the field in the table of the index, customer emails and phone
+    /// numbers are encrypted in Postgres and cannot be filtered on
+    fn get_column(self, index: SearchIndex) -> Option<&'static str> {
+        match (self, index) {
+            (
+                Self::Currency,
+                SearchIndex::PaymentAttempts
+                | SearchIndex::PaymentIntents
+                | SearchIndex::Refunds
+                | SearchIndex::Disputes,
+            ) => Some("currency"),
+            (Self::Currency, SearchIndex::Payouts) => Some("destination_currency"),
+            (Self::Currency, SearchIndex::Mandates) => Some("mandate_currency"),
+            (
+                Self::Status,
+                SearchIndex::PaymentAttempts
+                | SearchIndex::PaymentIntents
+                | SearchIndex::Payouts
+                | SearchIndex::PaymentMethods,
+            ) => Some("status"),
+            (Self::Status, SearchIndex::Refunds) => Some("refund_status"),
+            (Self::Status, SearchIndex::Disputes) => Some("dispute_status"),
+            (Self::Status, SearchIndex::Mandates) => Some("mandate_status"),
+            (Self::PaymentMethod, SearchIndex::PaymentAttempts | SearchIndex::PaymentMethods) => {
+                Some("payment_method")
+            }
+            (
+                Self::Connector,
+                SearchIndex::PaymentAttempts
+                | SearchIndex::Refunds
+                | SearchIndex::Disputes
+                | SearchIndex::Mandates,
+            ) => Some("connector"),
+            (
+                Self::PaymentMethodType,
+                SearchIndex::PaymentAttempts | SearchIndex::PaymentMethods,
+            ) => Some("payment_method_type"),
+            (Self::CardNetwork, SearchIndex::PaymentAttempts) => Some("card_network"),
+            (Self::CardLast4, SearchIndex::PaymentAttempts) => {
+                Some("payment_method_data -> 'card' ->> 'last4'")
+            }
+            (
+                Self::PaymentId,
+                SearchIndex::PaymentAttempts
+                | SearchIndex::PaymentIntents
+                | SearchIndex::Refunds
+                | SearchIndex::Disputes,
+            ) => Some("payment_id"),
+            (Self::PaymentId, SearchIndex::Mandates) => Some("original_payment_id"),
+            (
+                Self::Amount,
+                SearchIndex::PaymentAttempts | SearchIndex::PaymentIntents | SearchIndex::Payouts,
+            ) => Some("amount"),
+            (Self::Amount, SearchIndex::Refunds) => Some("refund_amount"),
+            (Self::Amount, SearchIndex::Disputes) => Some("dispute_amount"),
+            (Self::Amount, SearchIndex::Mandates) => Some("mandate_amount"),
+            (
+                Self::CustomerId,
+                SearchIndex::PaymentIntents
+                | SearchIndex::Customers
+                | SearchIndex::Payouts
+                | SearchIndex::Mandates
+                | SearchIndex::PaymentMethods,
+            ) => Some("customer_id"),
+            (Self::SearchTags, SearchIndex::PaymentIntents) => {
+                Some("feature_metadata -> 'search_tags'")
+            }
+            (Self::PayoutId, SearchIndex::Payouts) => Some("payout_id"),
+            (Self::MandateId, SearchIndex::PaymentAttempts | SearchIndex::Mandates) => {
+                Some("mandate_id")
+            }
+            (
+                Self::PaymentMethodId,
+                SearchIndex::PaymentAttempts | SearchIndex::Mandates | SearchIndex::PaymentMethods,
+            ) => Some("payment_method_id"),
+            (Self::FingerprintId, SearchIndex::PaymentAttempts | SearchIndex::PaymentIntents) => {
+                Some("fingerprint_id")
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Hashed values are compared in their serialized form, as they are stored in the events
+fn get_hashed_values<T: serde::Serialize>(values: &[T]) -> Vec<String> {
+    values
+        .iter()
+        .filter_map(|value| {
+            serde_json::to_value(value)
+                .ok()
+                .and_then(|a| a.as_str().map(|a| a.to_string()))
+        })
+        .collect()
+}
+
+fn get_search_filters(filters: Option<SearchFilters>) -> Vec<(SearchField, Vec<String>)> {
+    let Some(filters) = filters else {
+        return Vec::new();
+    };
+    [
+        (SearchField::Currency, filters.currency),
+        (SearchField::Status, filters.status),
+        (SearchField::PaymentMethod, filters.payment_method),
+        (SearchField::Connector, filters.connector),
+        (SearchField::PaymentMethodType, filters.payment_method_type),
+        (SearchField::CardNetwork, filters.card_network),
+        (SearchField::CardLast4, filters.card_last_4),
+        (SearchField::PaymentId, filters.payment_id),
+        (
+            SearchField::Amount,
+            filters
+                .amount
+                .map(|amounts| amounts.iter().map(|amount| amount.to_string()).collect()),
+        ),
+        (SearchField::CustomerId, filters.customer_id),
+        (
+            SearchField::CustomerEmail,
+            filters.customer_email.as_deref().map(get_hashed_values),
+        ),
+        (
+            SearchField::CustomerPhone,
+            filters.customer_phone.as_deref().map(get_hashed_values),
+        ),
+        (
+            SearchField::SearchTags,
+            filters.search_tags.as_deref().map(get_hashed_values),
+        ),
+        (SearchField::PayoutId, filters.payout_id),
+        (SearchField::MandateId, filters.mandate_id),
+        (SearchField::PaymentMethodId, filters.payment_method_id),
+        (SearchField::FingerprintId, filters.fingerprint_id),
+    ]
+    .into_iter()
+    .filter_map(|(field, values)| {
+        values
+            .filter(|values| !values.is_empty())
+            .map(|values| (field, values))
+    })
+    .collect()
+}
+
+/// Escapes the wildcards of a `LIKE` pattern, so that the query is matched as is
+fn get_like_pattern(query: &str) -> String {
+    let escaped_query = query
+        .trim()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped_query}%")
+}
+
+struct PostgresSearchQuery<'a> {
+    query: &'a str,
+    filters: &'a [(SearchField, Vec<String>)],
+    time_range: Option<TimeRange>,
+    search_params: &'a [AuthInfo],
+}
+
+impl PostgresSearchQuery<'_> {
+    fn push_auth_conditions(&self, builder: &mut QueryBuilder<'_, Postgres>, table: &SearchTable) {
+        let mut is_first_condition = true;
+        builder.push("(");
+        for search_param in self.search_params {
+            let separator = if is_first_condition { "" } else { " OR " };
+            match search_param {
+                AuthInfo::OrgLevel { org_id } if table.has_organization_id => {
+                    builder
+                        .push(format!("{separator}organization_id = "))
+                        .push_bind(org_id.get_string_repr().to_owned());
+                }
+                AuthInfo::MerchantLevel { merchant_ids, .. } => {
+                    builder
+                        .push(format!("{separator}merchant_id = ANY("))
+                        .push_bind(
+                            merchant_ids
+                                .iter()
+                                .map(|merchant_id| merchant_id.get_string_repr().to_owned())
+                                .collect::<Vec<_>>(),
+                        )
+                        .push(")");
+                }
+                AuthInfo::ProfileLevel {
+                    merchant_id,
+                    profile_ids,
+                    ..
+                } if table.has_profile_id => {
+                    builder
+                        .push(format!("{separator}(merchant_id = "))
+                        .push_bind(merchant_id.get_string_repr().to_owned())
+                        .push(" AND profile_id = ANY(")
+                        .push_bind(
+                            profile_ids
+                                .iter()
+                                .map(|profile_id| profile_id.get_string_repr().to_owned())
+                                .collect::<Vec<_>>(),
+                        )
+                        .push("))");
+                }
+                // Organization and profile level access is expanded to the merchants by the
+                // caller for the tables without these columns
+                AuthInfo::OrgLevel { .. } | AuthInfo::ProfileLevel { .. } => continue,
+            }
+            is_first_condition = false;
+        }
+        if is_first_condition {
+            builder.push("FALSE");
+        }
+        builder.push(")");
+    }
+
+    fn push_conditions(
+        &self,
+        builder: &mut QueryBuilder<'_, Postgres>,
+        index: SearchIndex,
+        table: &SearchTable,
+    ) {
+        builder.push(" WHERE ");
+        self.push_auth_conditions(builder, table);
+
+        if !self.query.trim().is_empty() {
+            let pattern = get_like_pattern(self.query);
+            builder.push(" AND (");
+            for (position, column) in table.search_columns.iter().enumerate() {
+                if position > 0 {
+                    builder.push(" OR ");
+                }
+                builder
+                    .push(format!("{column} ILIKE "))
+                    .push_bind(pattern.clone());
+            }
+            builder.push(")");
+        }
+
+        for (field, values) in self.filters {
+            // Filtering on a field which the index does not have matches nothing, as in OpenSearch
+            let Some(column) = field.get_column(index) else {
+                builder.push(" AND FALSE");
+                continue;
+            };
+            if *field == SearchField::SearchTags {
+                builder
+                    .push(format!(" AND ({column}) ?| "))
+                    .push_bind(values.clone());
+            } else if field.is_case_sensitive() {
+                builder
+                    .push(format!(" AND CAST({column} AS TEXT) = ANY("))
+                    .push_bind(values.clone())
+                    .push(")");
+            } else {
+                builder
+                    .push(format!(" AND LOWER(CAST({column} AS TEXT)) = ANY("))
+                    .push_bind(
+                        values
+                            .iter()
+                            .map(|value| value.to_lowercase())
+                            .collect::<Vec<_>>(),
+                    )
+                    .push(")");
+            }
+        }
+
+        if let Some(time_range) = self.time_range {
+            builder
+                .push(" AND created_at >= ")
+                .push_bind(time_range.start_time);
+            if let Some(end_time) = time_range.end_time {
+                builder.push(" AND created_at <= ").push_bind(end_time);
+            }
+        }
+    }
+
+    async fn execute(
+        &self,
+        client: &SqlxClient,
+        index: SearchIndex,
+        offset: i64,
+        count: i64,
+    ) -> CustomResult<GetSearchResponse, OpenSearchError> {
+        let table = get_search_table(index).ok_or_else(|| {
+            OpenSearchError::BadRequestError(format!(
+                "Search on {index:?} is not supported without OpenSearch"
+            ))
+        })?;
+
+        let mut count_builder = QueryBuilder::<Postgres>::new(format!(
+            "SELECT COUNT(*) FROM (SELECT 1 FROM {}",
+            table.name
+        ));
+        self.push_conditions(&mut count_builder, index, &table);
+        count_builder
+            .push(" LIMIT ")
+            .push_bind(MAX_SEARCH_HITS_COUNT)
+            .push(") counted");
+        let total_count = count_builder
+            .build_query_scalar::<i64>()
+            .fetch_one(client.get_pool())
+            .await
+            .change_context(OpenSearchError::ResponseError)
+            .attach_printable_lazy(|| format!("Failed to count search hits for {index:?}"))?;
+
+        let mut hits_builder = QueryBuilder::<Postgres>::new(format!(
+            "SELECT to_jsonb(hit) FROM (SELECT {} FROM {}",
+            table.columns.join(", "),
+            table.name
+        ));
+        self.push_conditions(&mut hits_builder, index, &table);
+        hits_builder
+            .push(" ORDER BY created_at DESC LIMIT ")
+            .push_bind(count)
+            .push(" OFFSET ")
+            .push_bind(offset)
+            .push(") hit");
+        let hits = hits_builder
+            .build_query_scalar::<Value>()
+            .fetch_all(client.get_pool())
+            .await
+            .change_context(OpenSearchError::ResponseError)
+            .attach_printable_lazy(|| format!("Failed to fetch search hits for {index:?}"))?;
+
+        Ok(GetSearchResponse {
+            count: u64::try_from(total_count).unwrap_or_default(),
+            index,
+            hits,
+            status: SearchStatus::Success,
+        })
+    }
+}
+
+fn validate_search_request(
+    query: &str,
+    filters: Option<&SearchFilters>,
+) -> CustomResult<(), OpenSearchError> {
+    if query.trim().is_empty() && filters.map_or(true, |filters| filters.is_all_none()) {
+        return Err(OpenSearchError::BadRequestError(
+            "Both query and filters are empty".to_string(),
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Searches the indexes stored in Postgres, the indexes which are only available in OpenSearch
+/// are left out of the results
+pub async fn msearch_results(
+    client: &SqlxClient,
+    req: GetGlobalSearchRequest,
+    search_params: Vec<AuthInfo>,
+    indexes: Vec<SearchIndex>,
+) -> CustomResult<Vec<GetSearchResponse>, OpenSearchError> {
+    validate_search_request(&req.query, req.filters.as_ref())?;
+    let filters = get_search_filters(req.filters);
+    let search_query = &PostgresSearchQuery {
+        query: &req.query,
+        filters: &filters,
+        time_range: req.time_range,
+        search_params: &search_params,
+    };
+
+    let results = join_all(
+        indexes
+            .into_iter()
+            .filter(|index| get_search_table(*index).is_some())
+            .map(|index| async move {
+                search_query
+                    .execute(client, index, 0, GLOBAL_SEARCH_HITS_PER_INDEX)
+                    .await
+                    .unwrap_or_else(|error| {
+                        tracing::error!(index = ?index, ?error, "Search error");
+                        GetSearchResponse {
+                            count: 0,
+                            index,
+                            hits: Vec::new(),
+                            status: SearchStatus::Failure,
+                        }
+                    })
+            }),
+    )
+    .await;
+
+    Ok(results)
+}
+
+pub async fn search_results(
+    client: &SqlxClient,
+    req: GetSearchRequestWithIndex,
+    search_params: Vec<AuthInfo>,
+) -> CustomResult<GetSearchResponse, OpenSearchError> {
+    let search_req = req.search_req;
+    validate_search_request(&search_req.query, search_req.filters.as_ref())?;
+    let filters = get_search_filters(search_req.filters);
+    PostgresSearchQuery {
+        query: &search_req.query,
+        filters: &filters,
+        time_range: search_req.time_range,
+        search_params: &search_params,
+    }
+    .execute(client, req.index, search_req.offset, search_req.count)
+    .await
+}
@@ -13,12 +13,12 @@ use common_utils::{
 };
 use diesel_models::enums::{
     AttemptStatus, AuthenticationType, Currency, FraudCheckStatus, IntentStatus, PaymentMethod,
-    RefundStatus,
+    PayoutStatus, PayoutType, RefundStatus,
 };
 use error_stack::ResultExt;
 use sqlx::{
     postgres::{PgArgumentBuffer, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
-    Decode, Encode,
+    Column, Decode, Encode,
     Error::ColumnNotFound,
     FromRow, Pool, Postgres, Row,
 };
@@ -27,6 +27,7 @@ use time::PrimitiveDateTime;
 
 use super::{
     health_check::HealthCheck,
+    payouts::metrics::PayoutFulfilmentTime,
     query::{Aggregate, ToSql, Window},
     types::{
         AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, QueryExecutionError,
@@ -65,6 +66,10 @@ impl SqlxClient {
             .expect("SQLX Pool Creation failed");
         Self { pool }
     }
+
+    pub(crate) fn get_pool(&self) -> &Pool<Postgres> {
+        &self.pool
+    }
 }
 
 pub trait DbType {
@@ -95,6 +100,8 @@ db_type!(IntentStatus);
 db_type!(PaymentMethod, TEXT);
 db_type!(RefundStatus);
 db_type!(RefundType);
+db_type!(PayoutStatus);
+db_type!(PayoutType);
 db_type!(FraudCheckStatus);
 db_type!(FrmTransactionType);
 db_type!(DisputeStage);
@@ -162,12 +169,21 @@ impl super::payment_intents::metrics::PaymentIntentMetricAnalytics for SqlxClien
 impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::refunds::distribution::RefundDistributionAnalytics for SqlxClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for SqlxClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for SqlxClient {}
+impl super::payouts::distribution::PayoutDistributionAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
 impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::reports::ReportAnalytics for SqlxClient {
+    fn report_column(column: &'static str) -> String {
+        // Columns are read as text, since the report does not depend on their database types
+        format!("CAST({column} AS TEXT) AS {column}")
+    }
+}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -619,6 +635,154 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::payouts::metrics::PayoutMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            total,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::filters::PayoutFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::distribution::PayoutDistributionRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let error_message: Option<String> = row.try_get("error_message").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            destination_currency,
+            status,
+            connector,
+            payout_type,
+            profile_id,
+            total,
+            count,
+            error_message,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl<'a> FromRow<'a, PgRow> for super::frm::metrics::FrmMetricRow {
     fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
         let frm_name: Option<String> = row.try_get("frm_name").or_else(|e| match e {
@@ -1360,12 +1524,44 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::reports::ReportRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        row.columns()
+            .iter()
+            .map(|column| {
+                row.try_get::<Option<String>, _>(column.ordinal())
+                    .map(|value| {
+                        (
+                            column.name().to_string(),
+                            value.map_or(serde_json::Value::Null, serde_json::Value::String),
+                        )
+                    })
+            })
+            .collect::<sqlx::Result<_>>()
+            .map(Self)
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
     }
 }
 
+/// Payouts do not carry connector details in postgres, so they are joined with their latest
+/// attempt to expose the same columns as the clickhouse `payout` table.
+const PAYOUT_WITH_LATEST_ATTEMPT: &str = "(SELECT payouts.*, payout_attempt.connector, \
+    payout_attempt.error_message FROM payouts INNER JOIN payout_attempt \
+    ON payout_attempt.merchant_id = payouts.merchant_id \
+    AND payout_attempt.payout_attempt_id = payouts.payout_id || '_' || payouts.attempt_count) \
+    AS payout";
+
+impl ToSql<SqlxClient> for PayoutFulfilmentTime {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        Ok("EXTRACT(EPOCH FROM (last_modified_at - created_at))".to_string())
+    }
+}
+
 impl ToSql<SqlxClient> for AnalyticsCollection {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         match self {
@@ -1375,6 +1571,7 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::Refund => Ok("refund".to_string()),
             Self::RefundSessionized => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("RefundSessionized table is not implemented for Sqlx"))?,
+            Self::Payout => Ok(PAYOUT_WITH_LATEST_ATTEMPT.to_string()),
             Self::SdkEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("SdkEventsAudit table is not implemented for Sqlx"))?,
             Self::SdkEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
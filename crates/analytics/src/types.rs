@@ -21,6 +21,7 @@ pub enum AnalyticsDomain {
     SdkEvents,
     ApiEvents,
     Dispute,
+    Payouts,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -29,6 +30,7 @@ pub enum AnalyticsCollection {
     PaymentSessionized,
     Refund,
     RefundSessionized,
+    Payout,
     FraudCheck,
     SdkEvents,
     SdkEventsAnalytics,
@@ -5,12 +5,22 @@ use api_models::analytics::{
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
 };
 use strum::IntoEnumIterator;
 
+/// Percentage of `count` in `total`, which is not computed when there is nothing in `total` or
+/// the counts are too large to be represented exactly
+pub fn get_rate(count: u64, total: u64) -> Option<f64> {
+    if total == 0 {
+        return None;
+    }
+    Some(f64::from(u32::try_from(count).ok()?) * 100.0 / f64::from(u32::try_from(total).ok()?))
+}
+
 pub fn get_payment_dimensions() -> Vec<NameDescription> {
     vec![
         PaymentDimensions::Connector,
@@ -83,6 +93,10 @@ pub fn get_refund_dimensions() -> Vec<NameDescription> {
     RefundDimensions::iter().map(Into::into).collect()
 }
 
+pub fn get_payout_dimensions() -> Vec<NameDescription> {
+    PayoutDimensions::iter().map(Into::into).collect()
+}
+
 pub fn get_frm_dimensions() -> Vec<NameDescription> {
     FrmDimensions::iter().map(Into::into).collect()
 }
@@ -107,6 +121,10 @@ pub fn get_refund_metrics_info() -> Vec<NameDescription> {
     RefundMetrics::iter().map(Into::into).collect()
 }
 
+pub fn get_payout_metrics_info() -> Vec<NameDescription> {
+    PayoutMetrics::iter().map(Into::into).collect()
+}
+
 pub fn get_frm_metrics_info() -> Vec<NameDescription> {
     FrmMetrics::iter().map(Into::into).collect()
 }
@@ -130,3 +148,16 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_rate() {
+        assert_eq!(get_rate(1, 4), Some(25.0));
+        assert_eq!(get_rate(0, 4), Some(0.0));
+        assert_eq!(get_rate(1, 0), None);
+        assert_eq!(get_rate(u64::MAX, 1), None);
+    }
+}
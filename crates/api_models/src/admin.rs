@@ -2126,6 +2126,14 @@ pub struct ProfileCreate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Thresholds above which refunds and payouts are held for approval before execution
+    #[schema(value_type = Option<TransactionApprovalPolicy>)]
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+
+    /// Time zone and holiday calendars in which the time based keys of routing rules are evaluated
+    #[schema(value_type = Option<RoutingCalendarConfig>)]
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[nutype::nutype(
@@ -2447,6 +2455,14 @@ pub struct ProfileResponse {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Thresholds above which refunds and payouts are held for approval before execution
+    #[schema(value_type = Option<TransactionApprovalPolicy>)]
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+
+    /// Time zone and holiday calendars in which the time based keys of routing rules are evaluated
+    #[schema(value_type = Option<RoutingCalendarConfig>)]
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v2")]
@@ -2761,6 +2777,14 @@ pub struct ProfileUpdate {
     /// Four-digit code assigned based on business type to determine processing fees and risk level
     #[schema(value_type = Option<MerchantCategoryCode>, example = "5411")]
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+
+    /// Thresholds above which refunds and payouts are held for approval before execution
+    #[schema(value_type = Option<TransactionApprovalPolicy>)]
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+
+    /// Time zone and holiday calendars in which the time based keys of routing rules are evaluated
+    #[schema(value_type = Option<RoutingCalendarConfig>)]
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v2")]
@@ -8,24 +8,33 @@ use self::{
     active_payments::ActivePaymentsMetrics,
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::{AuthEventDimensions, AuthEventFilters, AuthEventMetrics},
+    decline_reasons::DeclineReasonDimensions,
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutDistributions, PayoutMetrics},
+    processing_cost::ProcessingCostDimensions,
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
+    routing_rules::RoutingRuleDimensions,
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
 pub mod active_payments;
+pub mod alerts;
 pub mod api_event;
 pub mod auth_events;
 pub mod connector_events;
+pub mod decline_reasons;
 pub mod disputes;
 pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
+pub mod payouts;
+pub mod processing_cost;
 pub mod refunds;
 pub mod routing_events;
+pub mod routing_rules;
 pub mod sdk_events;
 pub mod search;
 
@@ -46,6 +55,24 @@ pub struct GetInfoResponse {
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
 pub struct TimeSeries {
     pub granularity: Granularity,
+    /// Taken from the `time_zone` of the enclosing metrics request
+    #[serde(skip)]
+    pub time_zone: Option<chrono_tz::Tz>,
+}
+
+impl TimeSeries {
+    pub fn with_time_zone(self, time_zone: Option<chrono_tz::Tz>) -> Self {
+        Self { time_zone, ..self }
+    }
+}
+
+impl From<Granularity> for TimeSeries {
+    fn from(granularity: Granularity) -> Self {
+        Self {
+            granularity,
+            time_zone: None,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
@@ -74,6 +101,7 @@ pub struct AnalyticsRequest {
     pub payment_attempt: Option<GetPaymentMetricRequest>,
     pub refund: Option<GetRefundMetricRequest>,
     pub dispute: Option<GetDisputeMetricRequest>,
+    pub payout: Option<GetPayoutMetricRequest>,
 }
 
 impl AnalyticsRequest {
@@ -97,6 +125,11 @@ impl AnalyticsRequest {
                 .as_ref()
                 .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
                 .unwrap_or_default()
+            || self
+                .payout
+                .as_ref()
+                .map(|req| req.metrics.iter().any(|metric| metric.is_forex_metric()))
+                .unwrap_or_default()
     }
 }
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -104,6 +137,10 @@ impl AnalyticsRequest {
 pub struct GetPaymentMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<PaymentDimensions>,
     #[serde(default)]
@@ -146,6 +183,13 @@ pub struct RefundDistributionBody {
     pub distribution_cardinality: QueryLimit,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutDistributionBody {
+    pub distribution_for: PayoutDistributions,
+    pub distribution_cardinality: QueryLimit,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ReportRequest {
@@ -162,11 +206,33 @@ pub struct GenerateReportRequest {
     pub email: Secret<String, EmailStrategy>,
 }
 
+#[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    Payment,
+    Refund,
+    Dispute,
+    Authentication,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct GenerateReportResponse {
+    /// Identifier of the report, present when the report is generated by the scheduler and can
+    /// be downloaded once it is ready
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub report_id: Option<String>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentIntentMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<PaymentIntentDimensions>,
     #[serde(default)]
@@ -181,6 +247,10 @@ pub struct GetPaymentIntentMetricRequest {
 pub struct GetRefundMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<RefundDimensions>,
     #[serde(default)]
@@ -191,11 +261,80 @@ pub struct GetRefundMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetDeclineReasonsRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
+    #[serde(default)]
+    pub group_by_names: Vec<DeclineReasonDimensions>,
+    #[serde(default)]
+    pub filters: decline_reasons::DeclineReasonFilters,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRoutingRuleMetricsRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
+    /// Defaults to grouping by the routing algorithm and the routing rule
+    #[serde(default)]
+    pub group_by_names: Vec<RoutingRuleDimensions>,
+    #[serde(default)]
+    pub filters: routing_rules::RoutingRuleFilters,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetProcessingCostRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
+    #[serde(default)]
+    pub group_by_names: Vec<ProcessingCostDimensions>,
+    #[serde(default)]
+    pub filters: processing_cost::ProcessingCostFilters,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+    #[serde(default)]
+    pub filters: payouts::PayoutFilters,
+    pub metrics: HashSet<PayoutMetrics>,
+    pub distribution: Option<PayoutDistributionBody>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<FrmDimensions>,
     #[serde(default)]
@@ -212,6 +351,10 @@ impl ApiEventMetric for GetFrmMetricRequest {}
 pub struct GetSdkEventMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<SdkEventDimensions>,
     #[serde(default)]
@@ -221,11 +364,23 @@ pub struct GetSdkEventMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentFunnelRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<sdk_events::PaymentFunnelDimensions>,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAuthEventMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<AuthEventDimensions>,
     #[serde(default)]
@@ -286,6 +441,14 @@ pub struct RefundsAnalyticsMetadata {
     pub total_refund_reason_count: Option<u64>,
     pub total_refund_error_message_count: Option<u64>,
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutsAnalyticsMetadata {
+    pub total_payout_success_rate: Option<f64>,
+    pub total_payout_processed_amount: Option<u64>,
+    pub total_payout_processed_amount_in_usd: Option<u64>,
+    pub total_payout_processed_count: Option<u64>,
+}
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetPaymentFiltersRequest {
@@ -349,6 +512,27 @@ pub struct RefundFilterValue {
     pub values: Vec<String>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFiltersResponse {
+    pub query_data: Vec<PayoutFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFilterValue {
+    pub dimension: PayoutDimensions,
+    pub values: Vec<String>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetFrmFilterRequest {
@@ -429,6 +613,13 @@ pub struct RefundsMetricsResponse<T> {
     pub query_data: Vec<T>,
     pub meta_data: [RefundsAnalyticsMetadata; 1],
 }
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutsMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [PayoutsAnalyticsMetadata; 1],
+}
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DisputesMetricsResponse<T> {
@@ -461,6 +652,10 @@ pub struct ApiEventFilterValue {
 pub struct GetApiEventMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<ApiEventDimensions>,
     #[serde(default)]
@@ -496,6 +691,10 @@ pub struct DisputeFilterValue {
 pub struct GetDisputeMetricRequest {
     pub time_series: Option<TimeSeries>,
     pub time_range: TimeRange,
+    /// IANA time zone (e.g. `Asia/Kolkata`) in which the time range is interpreted and the
+    /// time series buckets are aligned, UTC when not provided
+    #[serde(default)]
+    pub time_zone: Option<chrono_tz::Tz>,
     #[serde(default)]
     pub group_by_names: Vec<DisputeDimensions>,
     #[serde(default)]
@@ -0,0 +1,239 @@
+use common_utils::{id_type, pii};
+
+use super::{
+    payments::{PaymentFilters, PaymentMetrics},
+    payouts::{PayoutFilters, PayoutMetrics},
+    refunds::{RefundFilters, RefundMetrics},
+};
+use crate::enums::AnalyticsAlertState;
+
+/// Metric that an alert rule watches, along with the filters applied while querying it
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum AlertMetric {
+    Payments {
+        metric: PaymentMetrics,
+        #[serde(default)]
+        filters: PaymentFilters,
+    },
+    Refunds {
+        metric: RefundMetrics,
+        #[serde(default)]
+        filters: RefundFilters,
+    },
+    Payouts {
+        metric: PayoutMetrics,
+        #[serde(default)]
+        filters: PayoutFilters,
+    },
+}
+
+impl AlertMetric {
+    /// Whether the metric evaluates to a single number which a condition can be checked against
+    pub fn is_supported(&self) -> bool {
+        match self {
+            Self::Payments { metric, .. } => matches!(
+                metric,
+                PaymentMetrics::PaymentSuccessRate
+                    | PaymentMetrics::PaymentCount
+                    | PaymentMetrics::PaymentSuccessCount
+                    | PaymentMetrics::PaymentProcessedAmount
+                    | PaymentMetrics::AvgTicketSize
+                    | PaymentMetrics::RetriesCount
+                    | PaymentMetrics::ConnectorSuccessRate
+            ),
+            Self::Refunds { metric, .. } => matches!(
+                metric,
+                RefundMetrics::RefundSuccessRate
+                    | RefundMetrics::RefundCount
+                    | RefundMetrics::RefundSuccessCount
+                    | RefundMetrics::RefundProcessedAmount
+            ),
+            Self::Payouts { .. } => true,
+        }
+    }
+
+    /// Whether the metric is a sum over the queried time range, as opposed to a rate or an
+    /// average. An additive metric with no data for the time range evaluates to zero, and its
+    /// baseline is scaled to the length of the evaluation window.
+    pub fn is_additive(&self) -> bool {
+        match self {
+            Self::Payments { metric, .. } => matches!(
+                metric,
+                PaymentMetrics::PaymentCount
+                    | PaymentMetrics::PaymentSuccessCount
+                    | PaymentMetrics::PaymentProcessedAmount
+                    | PaymentMetrics::RetriesCount
+            ),
+            Self::Refunds { metric, .. } => matches!(
+                metric,
+                RefundMetrics::RefundCount
+                    | RefundMetrics::RefundSuccessCount
+                    | RefundMetrics::RefundProcessedAmount
+            ),
+            Self::Payouts { metric, .. } => matches!(
+                metric,
+                PayoutMetrics::PayoutCount | PayoutMetrics::PayoutProcessedAmount
+            ),
+        }
+    }
+
+    pub fn get_metric_name(&self) -> String {
+        match self {
+            Self::Payments { metric, .. } => metric.to_string(),
+            Self::Refunds { metric, .. } => metric.to_string(),
+            Self::Payouts { metric, .. } => metric.to_string(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AlertComparisonOperator {
+    LessThan,
+    LessThanOrEqual,
+    GreaterThan,
+    GreaterThanOrEqual,
+}
+
+impl AlertComparisonOperator {
+    pub fn compare(&self, observed: f64, threshold: f64) -> bool {
+        match self {
+            Self::LessThan => observed < threshold,
+            Self::LessThanOrEqual => observed <= threshold,
+            Self::GreaterThan => observed > threshold,
+            Self::GreaterThanOrEqual => observed >= threshold,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, serde::Deserialize, serde::Serialize, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnomalyDirection {
+    /// The metric rose above the baseline multiplied by the factor
+    Spike,
+    /// The metric fell below the baseline divided by the factor
+    Drop,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertCondition {
+    /// Fires when the metric over the evaluation window compares true against a fixed value
+    Threshold {
+        operator: AlertComparisonOperator,
+        value: f64,
+    },
+    /// Fires when the metric over the evaluation window deviates from its baseline, computed over
+    /// the preceding `baseline_period_in_days`, by the given factor
+    Anomaly {
+        direction: AnomalyDirection,
+        factor: f64,
+        baseline_period_in_days: u16,
+    },
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AlertNotificationChannel {
+    /// The alert is posted to the URL, signed with the payment response hash key of the merchant
+    Webhook {
+        url: url::Url,
+    },
+    Email {
+        recipients: Vec<pii::Email>,
+    },
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRuleCreateRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// Restricts evaluation to the data of a single profile
+    pub profile_id: Option<id_type::ProfileId>,
+    pub metric: AlertMetric,
+    pub condition: AlertCondition,
+    /// Length of the trailing time range over which the metric is computed
+    pub evaluation_window_in_mins: u16,
+    /// How often the rule is evaluated, defaults to 5 minutes
+    pub evaluation_interval_in_mins: Option<u16>,
+    pub notification_channels: Vec<AlertNotificationChannel>,
+    #[serde(default = "alert_rule_enabled_default")]
+    pub enabled: bool,
+}
+
+fn alert_rule_enabled_default() -> bool {
+    true
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlertRuleUpdateRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub metric: Option<AlertMetric>,
+    pub condition: Option<AlertCondition>,
+    pub evaluation_window_in_mins: Option<u16>,
+    pub evaluation_interval_in_mins: Option<u16>,
+    pub notification_channels: Option<Vec<AlertNotificationChannel>>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct AlertRuleResponse {
+    pub alert_rule_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub description: Option<String>,
+    pub metric: AlertMetric,
+    pub condition: AlertCondition,
+    pub evaluation_window_in_mins: u16,
+    pub evaluation_interval_in_mins: u16,
+    pub notification_channels: Vec<AlertNotificationChannel>,
+    pub enabled: bool,
+    pub state: AnalyticsAlertState,
+    /// Value of the metric observed during the latest evaluation
+    pub last_observed_value: Option<f64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_evaluated_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_state_changed_at: Option<time::PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct AlertRuleListResponse {
+    pub count: usize,
+    pub data: Vec<AlertRuleResponse>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct AlertRuleDeleteResponse {
+    pub alert_rule_id: String,
+    pub deleted: bool,
+}
+
+/// Payload posted to webhook notification channels when an alert fires or resolves
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct AlertNotification {
+    pub alert_rule_id: String,
+    pub name: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub state: AnalyticsAlertState,
+    pub metric: String,
+    pub observed_value: Option<f64>,
+    /// Value the observed value was compared against, either the threshold or the baseline
+    /// adjusted by the anomaly factor
+    pub reference_value: Option<f64>,
+    pub evaluation_window_in_mins: u16,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub evaluated_at: time::PrimitiveDateTime,
+}
@@ -0,0 +1,65 @@
+use super::TimeRange;
+use crate::enums::{CardNetwork, Connector, ErrorCategory};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct DeclineReasonFilters {
+    #[serde(default)]
+    pub connector: Vec<Connector>,
+    #[serde(default)]
+    pub card_network: Vec<CardNetwork>,
+    #[serde(default)]
+    pub card_issuer: Vec<String>,
+    #[serde(default)]
+    pub card_bin: Vec<String>,
+    #[serde(default)]
+    pub unified_code: Vec<String>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    Hash,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DeclineReasonDimensions {
+    Connector,
+    CardNetwork,
+    CardIssuer,
+    /// First digits of the card number identifying the issuer of the card
+    CardBin,
+}
+
+/// Failures sharing a unified error code, for a combination of dimensions and a time bucket
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DeclineReasonBucket {
+    pub unified_code: Option<String>,
+    pub unified_message: Option<String>,
+    pub error_category: Option<ErrorCategory>,
+    pub connector: Option<String>,
+    pub card_network: Option<String>,
+    pub card_issuer: Option<String>,
+    pub card_bin: Option<String>,
+    pub failure_count: u64,
+    /// Number of the failures which GSM marked as retryable
+    pub retryable_failure_count: u64,
+    /// Share of the failures which GSM marked as retryable, in percent
+    pub retryable_failure_rate: Option<f64>,
+    /// Share of all the failures in the time bucket which had this unified error code, in percent
+    pub failure_share: Option<f64>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
@@ -0,0 +1,67 @@
+use super::TimeRange;
+use crate::enums::{Connector, Currency, PaymentMethod};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ProcessingCostFilters {
+    #[serde(default)]
+    pub connector: Vec<Connector>,
+    #[serde(default)]
+    pub merchant_connector_id: Vec<String>,
+    #[serde(default)]
+    pub payment_method: Vec<PaymentMethod>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    Hash,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ProcessingCostDimensions {
+    Connector,
+    MerchantConnectorId,
+    PaymentMethod,
+    PaymentMethodType,
+    CardNetwork,
+}
+
+/// Estimated cost of processing the charged payments, for a combination of dimensions, a
+/// currency and a time bucket
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ProcessingCostBucket {
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub currency: Option<Currency>,
+    pub payment_count: u64,
+    /// Amount charged in the payments, in the minor unit of the currency
+    pub processed_amount: i64,
+    /// Number of the payments which matched a rule in the fee schedule of their connector
+    pub costed_payment_count: u64,
+    /// Fees estimated for the payments which matched a rule in the fee schedule of their
+    /// connector, in the minor unit of the currency
+    pub estimated_cost: i64,
+    pub average_cost_per_payment: Option<f64>,
+    /// Estimated cost as a share of the amount of the payments which matched a fee rule, in percent
+    pub effective_rate: Option<f64>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
@@ -0,0 +1,59 @@
+use super::TimeRange;
+use crate::enums::{Connector, Currency};
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct RoutingRuleFilters {
+    #[serde(default)]
+    pub routing_algorithm_id: Vec<String>,
+    #[serde(default)]
+    pub routing_rule_name: Vec<String>,
+    #[serde(default)]
+    pub connector: Vec<Connector>,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    Hash,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingRuleDimensions {
+    /// Id of the static routing algorithm, a new id is created for every change to the rules
+    RoutingAlgorithmId,
+    /// Name of the rule of an advanced routing algorithm which matched the payment
+    RoutingRuleName,
+    Connector,
+}
+
+/// Payment attempts routed by a static routing rule, for a combination of dimensions and a time
+/// bucket
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct RoutingRuleBucket {
+    pub routing_algorithm_id: Option<String>,
+    pub routing_rule_name: Option<String>,
+    pub connector: Option<String>,
+    pub currency: Option<Currency>,
+    /// Number of payment attempts routed by the rule
+    pub hit_count: u64,
+    pub success_count: u64,
+    /// Share of the payment attempts routed by the rule which were charged, in percent
+    pub success_rate: Option<f64>,
+    /// Amount charged in the payment attempts routed by the rule, in the minor unit of the currency
+    pub processed_amount: u64,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
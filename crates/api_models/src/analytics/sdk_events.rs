@@ -223,3 +223,77 @@ pub struct MetricsBucketResponse {
     #[serde(flatten)]
     pub dimensions: SdkEventMetricsBucketIdentifier,
 }
+
+/// Dimensions by which the payment funnel can be broken down, attributed to each payment from
+/// the SDK events of the payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::AsRefStr,
+    strum::Display,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentFunnelDimensions {
+    PaymentMethod,
+    Platform,
+    BrowserName,
+}
+
+/// Steps of the payment funnel, in the order in which a customer goes through them. A payment
+/// which reached a step is counted as having reached all of the steps before it as well.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentFunnelStep {
+    CheckoutRendered,
+    PaymentMethodSelected,
+    PaymentDataFilled,
+    PaymentSubmitted,
+    PaymentAuthorized,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentFunnelStepValue {
+    pub step: PaymentFunnelStep,
+    /// Number of payments which reached the step
+    pub count: u64,
+    /// Share of the payments which reached the previous step that also reached this step
+    pub conversion_rate: Option<f64>,
+    /// Number of payments which reached the previous step but not this step
+    pub drop_off_count: u64,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentFunnelBucket {
+    pub payment_method: Option<String>,
+    pub platform: Option<String>,
+    pub browser_name: Option<String>,
+    pub steps: Vec<PaymentFunnelStepValue>,
+    /// Share of the payments which rendered the checkout that were authorized
+    pub overall_conversion_rate: Option<f64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentFunnelResponse {
+    pub query_data: Vec<PaymentFunnelBucket>,
+}
@@ -16,6 +16,11 @@ pub struct SearchFilters {
     pub payment_id: Option<Vec<String>>,
     pub amount: Option<Vec<u64>>,
     pub customer_id: Option<Vec<String>>,
+    pub customer_phone: Option<Vec<HashedString<WithType>>>,
+    pub payout_id: Option<Vec<String>>,
+    pub mandate_id: Option<Vec<String>>,
+    pub payment_method_id: Option<Vec<String>>,
+    pub fingerprint_id: Option<Vec<String>>,
 }
 impl SearchFilters {
     pub fn is_all_none(&self) -> bool {
@@ -31,6 +36,11 @@ impl SearchFilters {
             && self.payment_id.is_none()
             && self.amount.is_none()
             && self.customer_id.is_none()
+            && self.customer_phone.is_none()
+            && self.payout_id.is_none()
+            && self.mandate_id.is_none()
+            && self.payment_method_id.is_none()
+            && self.fingerprint_id.is_none()
     }
 }
 
@@ -76,6 +86,10 @@ pub enum SearchIndex {
     SessionizerPaymentIntents,
     SessionizerRefunds,
     SessionizerDisputes,
+    Customers,
+    Payouts,
+    Mandates,
+    PaymentMethods,
 }
 
 #[derive(Debug, strum::EnumIter, Clone, serde::Deserialize, serde::Serialize, Copy)]
@@ -0,0 +1,110 @@
+use common_enums::{ChangeRequestEntity, ChangeRequestStatus, TransactionType};
+use common_utils::id_type;
+use time::PrimitiveDateTime;
+
+use crate::{admin, blocklist, conditional_configs, routing, surcharge_decision_configs};
+
+/// Configuration change which is applied once the change request is approved
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+pub enum ChangeRequestPayload {
+    RoutingCreate {
+        config: routing::RoutingConfigRequest,
+        transaction_type: Option<TransactionType>,
+    },
+    RoutingActivate {
+        algorithm_id: id_type::RoutingId,
+        transaction_type: Option<TransactionType>,
+    },
+    RoutingDeactivate {
+        config: routing::RoutingConfigRequest,
+        transaction_type: Option<TransactionType>,
+    },
+    RoutingUpdateDefaultConfig {
+        connectors: Vec<routing::RoutableConnectorChoice>,
+        transaction_type: TransactionType,
+    },
+    MerchantConnectorCreate(Box<admin::MerchantConnectorCreate>),
+    MerchantConnectorUpdate {
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+        update: Box<admin::MerchantConnectorUpdate>,
+    },
+    MerchantConnectorDelete {
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+    },
+    SurchargeDecisionConfigUpsert(surcharge_decision_configs::SurchargeDecisionConfigReq),
+    SurchargeDecisionConfigDelete,
+    ThreeDsDecisionRuleUpsert(conditional_configs::DecisionManager),
+    ThreeDsDecisionRuleDelete,
+    BlocklistAdd(blocklist::AddToBlocklistRequest),
+    BlocklistRemove(blocklist::DeleteFromBlocklistRequest),
+    BlocklistToggle(blocklist::ToggleBlocklistQuery),
+}
+
+impl ChangeRequestPayload {
+    pub fn get_entity(&self) -> ChangeRequestEntity {
+        match self {
+            Self::RoutingCreate { .. }
+            | Self::RoutingActivate { .. }
+            | Self::RoutingDeactivate { .. }
+            | Self::RoutingUpdateDefaultConfig { .. } => ChangeRequestEntity::Routing,
+            Self::MerchantConnectorCreate(_)
+            | Self::MerchantConnectorUpdate { .. }
+            | Self::MerchantConnectorDelete { .. } => ChangeRequestEntity::MerchantConnectorAccount,
+            Self::SurchargeDecisionConfigUpsert(_) | Self::SurchargeDecisionConfigDelete => {
+                ChangeRequestEntity::SurchargeDecisionConfig
+            }
+            Self::ThreeDsDecisionRuleUpsert(_) | Self::ThreeDsDecisionRuleDelete => {
+                ChangeRequestEntity::ThreeDsDecisionRule
+            }
+            Self::BlocklistAdd(_) | Self::BlocklistRemove(_) | Self::BlocklistToggle(_) => {
+                ChangeRequestEntity::Blocklist
+            }
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct CreateChangeRequest {
+    pub change: ChangeRequestPayload,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ChangeRequestDecisionRequest {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ListChangeRequestsQuery {
+    pub status: Option<ChangeRequestStatus>,
+    pub limit: Option<u16>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ChangeRequestResponse {
+    pub change_request_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity: ChangeRequestEntity,
+    /// Requested change, with sensitive values masked
+    pub change: serde_json::Value,
+    pub description: Option<String>,
+    pub status: ChangeRequestStatus,
+    pub requested_by: String,
+    pub decided_by: Option<String>,
+    pub decision_reason: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub decided_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ChangeApprovalSettings {
+    pub is_enabled: bool,
+}
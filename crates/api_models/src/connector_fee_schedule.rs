@@ -0,0 +1,144 @@
+use common_utils::{
+    consts::FEE_PERCENTAGE_PRECISION_LENGTH,
+    errors::{CustomResult, PercentageError},
+    id_type,
+    types::{MinorUnit, Percentage},
+};
+use time::PrimitiveDateTime;
+
+use crate::enums;
+
+/// Fees charged by the connector of a merchant connector account for processing payments, a
+/// payment is charged the fees of the first rule which matches it
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeScheduleRequest {
+    pub fee_rules: Vec<ConnectorFeeRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFeeRule {
+    /// The rule matches all payment methods when not set
+    pub payment_method: Option<enums::PaymentMethod>,
+    /// The rule matches all payment method types when not set
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// The rule matches all card networks when not set
+    pub card_network: Option<enums::CardNetwork>,
+    /// Countries the card was issued in, as present in the card information of the payment
+    /// (e.g. `UNITED STATES OF AMERICA`), the rule matches cards issued in any country when not set
+    pub card_issuing_countries: Option<Vec<String>>,
+    /// Currency of the payments the rule applies to, fixed fees are charged in this currency
+    pub currency: enums::Currency,
+    pub fee: ConnectorFee,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectorFee {
+    /// A single rate covering the interchange, scheme and connector fees
+    Blended {
+        percentage: Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>,
+        fixed_amount: MinorUnit,
+    },
+    /// Interchange and scheme fees passed through at cost, with the connector markup on top
+    InterchangePlus {
+        interchange_percentage: Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>,
+        interchange_fixed_amount: MinorUnit,
+        scheme_fee_percentage: Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>,
+        markup_percentage: Percentage<FEE_PERCENTAGE_PRECISION_LENGTH>,
+        markup_fixed_amount: MinorUnit,
+    },
+}
+
+/// Attributes of a payment used to find the fee rule which applies to it
+#[derive(Debug, Clone, Default)]
+pub struct PaymentFeeAttributes {
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub card_network: Option<enums::CardNetwork>,
+    pub card_issuing_country: Option<String>,
+    pub currency: Option<enums::Currency>,
+}
+
+impl ConnectorFeeRule {
+    pub fn matches(&self, attributes: &PaymentFeeAttributes) -> bool {
+        fn matches_value<T: PartialEq>(expected: Option<&T>, actual: Option<&T>) -> bool {
+            expected.map_or(true, |expected| Some(expected) == actual)
+        }
+
+        attributes.currency == Some(self.currency)
+            && matches_value(
+                self.payment_method.as_ref(),
+                attributes.payment_method.as_ref(),
+            )
+            && matches_value(
+                self.payment_method_type.as_ref(),
+                attributes.payment_method_type.as_ref(),
+            )
+            && matches_value(self.card_network.as_ref(), attributes.card_network.as_ref())
+            && self
+                .card_issuing_countries
+                .as_ref()
+                .map_or(true, |countries| {
+                    attributes
+                        .card_issuing_country
+                        .as_ref()
+                        .is_some_and(|issuing_country| {
+                            countries
+                                .iter()
+                                .any(|country| country.eq_ignore_ascii_case(issuing_country))
+                        })
+                })
+    }
+}
+
+impl ConnectorFee {
+    pub fn get_fixed_amount(&self) -> MinorUnit {
+        match self {
+            Self::Blended { fixed_amount, .. } => *fixed_amount,
+            Self::InterchangePlus {
+                interchange_fixed_amount,
+                markup_fixed_amount,
+                ..
+            } => *interchange_fixed_amount + *markup_fixed_amount,
+        }
+    }
+
+    /// Estimates the fees charged for a number of payments adding up to the total amount, the
+    /// percentage fees are rounded up to the minor unit
+    pub fn estimate_cost(
+        &self,
+        total_amount: MinorUnit,
+        payment_count: i64,
+    ) -> CustomResult<MinorUnit, PercentageError> {
+        let percentage_fee = match self {
+            Self::Blended { percentage, .. } => percentage.apply_and_ceil_result(total_amount)?,
+            Self::InterchangePlus {
+                interchange_percentage,
+                scheme_fee_percentage,
+                markup_percentage,
+                ..
+            } => {
+                interchange_percentage.apply_and_ceil_result(total_amount)?
+                    + scheme_fee_percentage.apply_and_ceil_result(total_amount)?
+                    + markup_percentage.apply_and_ceil_result(total_amount)?
+            }
+        };
+        Ok(percentage_fee
+            + MinorUnit::new(self.get_fixed_amount().get_amount_as_i64() * payment_count))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ConnectorFeeScheduleResponse {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    pub profile_id: id_type::ProfileId,
+    pub connector_name: String,
+    pub fee_rules: Vec<ConnectorFeeRule>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
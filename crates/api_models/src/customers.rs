@@ -368,3 +368,96 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerDataExportStatus {
+    /// The export is being assembled in the background
+    Pending,
+    /// The export has been stored and can be downloaded
+    Completed,
+    /// The export could not be assembled
+    Failed,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerDataExportResponse {
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The file holding the exported data, which can be downloaded through the files API once the
+    /// export is completed
+    #[schema(example = "file_01926c58bc6e77c09e809964e72af8c8")]
+    pub file_id: String,
+    /// The status of the export
+    pub status: CustomerDataExportStatus,
+    /// The time at which the export was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone)]
+pub struct CustomerDataExportRetrieveRequest {
+    pub customer_id: id_type::CustomerId,
+    pub file_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DataRetentionConfigRequest {
+    /// Customers and payments older than these many days have their personal data redacted
+    #[schema(example = 365, minimum = 30, maximum = 3650)]
+    pub retention_period_in_days: u16,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DataRetentionConfigResponse {
+    /// The identifier for the merchant
+    #[schema(value_type = String, example = "merchant_1671528864")]
+    pub merchant_id: id_type::MerchantId,
+    /// Customers and payments older than these many days have their personal data redacted
+    #[schema(example = 365)]
+    pub retention_period_in_days: u16,
+    /// Whether the data retention job is scheduled to run for the merchant
+    pub enabled: bool,
+    /// The time at which the data retention job is scheduled to run next
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+    /// Payments created up to this time have had their personal data redacted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "custom_serde::iso8601::option")]
+    pub redacted_until: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The customer to be merged, which is deleted after the merge
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub source_customer_id: id_type::CustomerId,
+    /// The customer into which the source customer is merged
+    #[schema(value_type = String, max_length = 64, example = "cus_1ghbh5qr1x8xgzvyjtoyxh7zr3")]
+    pub target_customer_id: id_type::CustomerId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The customer that was merged, which now resolves to the target customer
+    #[schema(value_type = String, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub source_customer_id: id_type::CustomerId,
+    /// The customer after the merge
+    pub customer: CustomerResponse,
+    /// The time at which the customers were merged
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub merged_at: time::PrimitiveDateTime,
+}
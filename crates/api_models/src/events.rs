@@ -1,4 +1,7 @@
 pub mod apple_pay_certificates_migration;
+#[cfg(feature = "v1")]
+pub mod change_request;
+pub mod connector_fee_schedule;
 pub mod connector_onboarding;
 pub mod customer;
 pub mod dispute;
@@ -28,7 +31,7 @@ use crate::tokenization;
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
+        alerts::*, api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
         outgoing_webhook_event::OutgoingWebhookLogsRequest, routing_events::RoutingEventsRequest,
         sdk_events::*, search::*, *,
     },
@@ -116,12 +119,20 @@ impl_api_event_type!(
         GetRefundMetricRequest,
         GetActivePaymentsMetricRequest,
         GetSdkEventMetricRequest,
+        GetPaymentFunnelRequest,
+        PaymentFunnelResponse,
         GetAuthEventMetricRequest,
         GetAuthEventFilterRequest,
         GetPaymentFiltersRequest,
         PaymentFiltersResponse,
         GetRefundFilterRequest,
         RefundFiltersResponse,
+        GetPayoutMetricRequest,
+        GetDeclineReasonsRequest,
+        GetRoutingRuleMetricsRequest,
+        GetProcessingCostRequest,
+        GetPayoutFilterRequest,
+        PayoutFiltersResponse,
         AuthEventFiltersResponse,
         GetSdkEventFiltersRequest,
         SdkEventFiltersResponse,
@@ -129,6 +140,7 @@ impl_api_event_type!(
         GetApiEventMetricRequest,
         SdkEventsRequest,
         ReportRequest,
+        GenerateReportResponse,
         ConnectorEventsRequest,
         OutgoingWebhookLogsRequest,
         GetGlobalSearchRequest,
@@ -144,7 +156,12 @@ impl_api_event_type!(
         OrganizationUpdateRequest,
         OrganizationId,
         CustomerListRequest,
-        RoutingEventsRequest
+        RoutingEventsRequest,
+        AlertRuleCreateRequest,
+        AlertRuleUpdateRequest,
+        AlertRuleResponse,
+        AlertRuleListResponse,
+        AlertRuleDeleteResponse
     )
 );
 
@@ -182,6 +199,12 @@ impl<T> ApiEventMetric for RefundsMetricsResponse<T> {
     }
 }
 
+impl<T> ApiEventMetric for PayoutsMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl<T> ApiEventMetric for DisputesMetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
@@ -0,0 +1,17 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::change_request::{
+    ChangeApprovalSettings, ChangeRequestDecisionRequest, ChangeRequestResponse,
+    CreateChangeRequest, ListChangeRequestsQuery,
+};
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (
+        ChangeApprovalSettings,
+        ChangeRequestDecisionRequest,
+        ChangeRequestResponse,
+        CreateChangeRequest,
+        ListChangeRequestsQuery
+    )
+);
@@ -0,0 +1,8 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::connector_fee_schedule::{ConnectorFeeScheduleRequest, ConnectorFeeScheduleResponse};
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (ConnectorFeeScheduleRequest, ConnectorFeeScheduleResponse)
+);
@@ -1,5 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
+#[cfg(feature = "v1")]
+use crate::customers::{
+    CustomerDataExportResponse, CustomerDataExportRetrieveRequest, CustomerMergeRequest,
+    CustomerMergeResponse, DataRetentionConfigRequest, DataRetentionConfigResponse,
+};
 use crate::customers::{
     CustomerDeleteResponse, CustomerRequest, CustomerResponse, CustomerUpdateRequestInternal,
 };
@@ -73,3 +78,45 @@ impl ApiEventMetric for CustomerUpdateRequestInternal {
         })
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataExportRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerDataExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for DataRetentionConfigRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for DataRetentionConfigResponse {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.target_customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for CustomerMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer.customer_id.clone(),
+        })
+    }
+}
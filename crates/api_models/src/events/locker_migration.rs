@@ -1,9 +1,22 @@
-use common_utils::events::ApiEventMetric;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::locker_migration::MigrateCardResponse;
+use crate::locker_migration::{
+    MigrateCardResponse, VaultMigrationEntriesListConstraints, VaultMigrationEntriesResponse,
+    VaultMigrationRequest, VaultMigrationResponse,
+};
 
 impl ApiEventMetric for MigrateCardResponse {
     fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
         Some(common_utils::events::ApiEventsType::RustLocker)
     }
 }
+
+common_utils::impl_api_event_type!(
+    RustLocker,
+    (
+        VaultMigrationRequest,
+        VaultMigrationResponse,
+        VaultMigrationEntriesListConstraints,
+        VaultMigrationEntriesResponse
+    )
+);
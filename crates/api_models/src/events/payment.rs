@@ -308,6 +308,21 @@ impl ApiEventMetric for ListCountriesCurrenciesResponse {}
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentMethodListResponse {}
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::PaymentMethodEligibilityResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::CardUpdaterConfigRequest {}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for payment_methods::CardUpdaterConfigResponse {}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for payment_methods::CustomerDefaultPaymentMethodResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
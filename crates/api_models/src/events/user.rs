@@ -10,18 +10,26 @@ use crate::user::{
     dashboard_metadata::{
         GetMetaDataRequest, GetMetaDataResponse, GetMultipleMetaDataPayload, SetMetaDataRequest,
     },
+    scim::{
+        CreateScimTokenRequest, CreateScimTokenResponse, ScimGroupListResponse, ScimGroupRequest,
+        ScimGroupResponse, ScimListQuery, ScimPatchRequest, ScimServiceProviderConfigResponse,
+        ScimTokenResponse, ScimUserListResponse, ScimUserRequest, ScimUserResponse,
+    },
     AcceptInviteFromEmailRequest, AuthSelectRequest, AuthorizeResponse, BeginTotpResponse,
     ChangePasswordRequest, CloneConnectorRequest, ConnectAccountRequest, CreateInternalUserRequest,
     CreateTenantUserRequest, CreateUserAuthenticationMethodRequest, ForgotPasswordRequest,
     GetSsoAuthUrlRequest, GetUserAuthenticationMethodsRequest, GetUserDetailsResponse,
     GetUserRoleDetailsRequest, GetUserRoleDetailsResponseV2, InviteUserRequest,
-    PlatformAccountCreateRequest, PlatformAccountCreateResponse, ReInviteUserRequest,
-    RecoveryCodes, ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest,
-    SignUpRequest, SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantAccountResponse, UserMerchantCreate,
-    UserOrgMerchantCreateRequest, VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
+    PasskeyAssertionRequest, PasskeyAuthenticationOptionsResponse, PasskeyRegisterRequest,
+    PasskeyRegistrationOptionsResponse, PasskeyResponse, PasskeySignInBeginRequest,
+    PasskeySignInRequest, PlatformAccountCreateRequest, PlatformAccountCreateResponse,
+    ReInviteUserRequest, RecoveryCodes, ResetPasswordRequest, RotatePasswordRequest,
+    SendVerifyEmailRequest, SignUpRequest, SignUpWithMerchantIdRequest, SsoSignInRequest,
+    SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse,
+    TwoFactorAuthStatusResponse, TwoFactorStatus, UpdateUserAccountDetailsRequest,
+    UpdateUserAuthenticationMethodRequest, UserFromEmailRequest, UserMerchantAccountResponse,
+    UserMerchantCreate, UserOrgMerchantCreateRequest, VerifyEmailRequest,
+    VerifyRecoveryCodeRequest, VerifyTotpRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -72,7 +80,32 @@ common_utils::impl_api_event_type!(
         GetSsoAuthUrlRequest,
         SsoSignInRequest,
         AuthSelectRequest,
-        CloneConnectorRequest
+        CloneConnectorRequest,
+        PasskeyRegistrationOptionsResponse,
+        PasskeyRegisterRequest,
+        PasskeyAuthenticationOptionsResponse,
+        PasskeyAssertionRequest,
+        PasskeySignInBeginRequest,
+        PasskeySignInRequest,
+        PasskeyResponse
+    )
+);
+
+common_utils::impl_api_event_type!(
+    Miscellaneous,
+    (
+        CreateScimTokenRequest,
+        CreateScimTokenResponse,
+        ScimTokenResponse,
+        ScimListQuery,
+        ScimUserRequest,
+        ScimUserResponse,
+        ScimUserListResponse,
+        ScimGroupRequest,
+        ScimGroupResponse,
+        ScimGroupListResponse,
+        ScimPatchRequest,
+        ScimServiceProviderConfigResponse
     )
 );
 
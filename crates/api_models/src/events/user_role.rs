@@ -2,9 +2,9 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::user_role::{
     role::{
-        CreateRoleRequest, GetRoleRequest, GroupsAndResources, ListRolesAtEntityLevelRequest,
-        ListRolesRequest, RoleInfoResponseNew, RoleInfoWithGroupsResponse, RoleInfoWithParents,
-        UpdateRoleRequest,
+        CreateRoleRequest, GetRoleRequest, GroupsAndResources, ImportRoleTemplateRequest,
+        ListRolesAtEntityLevelRequest, ListRolesRequest, RoleInfoResponseNew,
+        RoleInfoWithGroupsResponse, RoleInfoWithParents, RoleTemplate, UpdateRoleRequest,
     },
     AuthorizationInfoResponse, DeleteUserRoleRequest, ListUsersInEntityRequest,
     UpdateUserRoleRequest,
@@ -25,6 +25,8 @@ common_utils::impl_api_event_type!(
         ListUsersInEntityRequest,
         ListRolesRequest,
         GroupsAndResources,
-        RoleInfoWithParents
+        RoleInfoWithParents,
+        RoleTemplate,
+        ImportRoleTemplateRequest
     )
 );
@@ -4,8 +4,11 @@ pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cards_info;
+#[cfg(feature = "v1")]
+pub mod change_request;
 pub mod conditional_configs;
 pub mod connector_enums;
+pub mod connector_fee_schedule;
 pub mod connector_onboarding;
 pub mod consts;
 pub mod currency;
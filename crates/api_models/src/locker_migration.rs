@@ -6,3 +6,111 @@ pub struct MigrateCardResponse {
     pub customers_moved: usize,
     pub cards_moved: usize,
 }
+
+#[derive(Debug, Default, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VaultMigrationRequest {
+    /// Read every card from the source vault without writing anything to the destination vault
+    pub dry_run: Option<bool>,
+    /// Number of payment methods migrated in a single run of the migration task
+    pub batch_size: Option<u16>,
+    /// Vault to which the cards are migrated, defaults to the configured migration locker
+    pub destination: Option<VaultMigrationDestination>,
+}
+
+/// Vault to which the saved cards of the merchant are migrated
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum VaultMigrationDestination {
+    /// The configured migration locker, which stores every card under the same card reference
+    /// as the source locker. The payment methods are not modified, and the migration is
+    /// completed by switching the locker configuration to the new deployment.
+    #[default]
+    LockerDeployment,
+    /// The configured locker, for cards stored in an external vault
+    Locker,
+    /// A vault processor connector of the merchant
+    ExternalVault {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultMigrationOperation {
+    Migrate,
+    Rollback,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VaultMigrationStatus {
+    InProgress,
+    Completed,
+    Failed,
+}
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VaultMigrationProgress {
+    /// Payment methods read from the source vault
+    pub processed: u32,
+    /// Cards copied to the destination vault and verified, with the payment method referring to
+    /// the destination vault
+    pub migrated: u32,
+    /// Payment methods that have no card to be migrated, or are already in the destination vault
+    pub skipped: u32,
+    /// Cards that could not be read from the source or written to the destination vault
+    pub failed: u32,
+    /// Cards whose fingerprint in the destination vault did not match the source vault
+    pub verification_failed: u32,
+    /// Cards whose payment method was pointed back to the source vault by a rollback
+    pub rolled_back: u32,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VaultMigrationResponse {
+    pub migration_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub operation: VaultMigrationOperation,
+    pub status: VaultMigrationStatus,
+    pub dry_run: bool,
+    pub batch_size: u16,
+    pub destination: VaultMigrationDestination,
+    pub progress: VaultMigrationProgress,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VaultMigrationEntriesListConstraints {
+    pub status: Option<common_enums::VaultMigrationEntryStatus>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VaultMigrationEntryResponse {
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub source_locker_id: String,
+    pub source_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub destination_locker_id: Option<String>,
+    pub destination_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub destination_reference_created: bool,
+    pub status: common_enums::VaultMigrationEntryStatus,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct VaultMigrationEntriesResponse {
+    pub migration_id: String,
+    pub count: usize,
+    pub data: Vec<VaultMigrationEntryResponse>,
+}
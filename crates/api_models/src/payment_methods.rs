@@ -796,7 +796,7 @@ pub enum PaymentMethodResponseData {
 }
 
 #[cfg(feature = "v1")]
-#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
 pub struct PaymentMethodResponse {
     /// Unique identifier for a merchant
     #[schema(example = "merchant_1671528864", value_type = String)]
@@ -1536,6 +1536,79 @@ impl ResponsePaymentMethodIntermediate {
     }
 }
 
+#[cfg(feature = "v1")]
+/// Eligibility of the payment methods enabled on the profile of a payment
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodEligibilityResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// Every payment method and connector pair enabled on the profile of the payment
+    pub payment_methods: Vec<PaymentMethodEligibility>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodEligibility {
+    /// The payment method
+    #[schema(value_type = PaymentMethod, example = "pay_later")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The payment method type
+    #[schema(value_type = PaymentMethodType, example = "klarna")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// The connector on which the payment method is enabled
+    #[schema(example = "stripe")]
+    pub connector: String,
+
+    /// The identifier of the merchant connector account on which the payment method is enabled
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Whether the payment method is listed for the payment
+    pub eligible: bool,
+
+    /// The reasons why the payment method is not listed for the payment, empty if it is eligible
+    pub reasons: Vec<PaymentMethodEligibilityReason>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodEligibilityReason {
+    /// The filter which excluded the payment method
+    pub code: PaymentMethodEligibilityReasonCode,
+
+    /// A description of the condition which was not satisfied
+    #[schema(example = "billing_country `IN` is not one of [`AT`, `BE`, `DE`]")]
+    pub message: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodEligibilityReasonCode {
+    /// The recurring support of the payment method does not match the request
+    RecurringNotEnabled,
+    /// The installment support of the payment method does not match the request
+    InstallmentNotEnabled,
+    /// The amount is outside the minimum and maximum amount configured for the payment method
+    AmountOutOfRange,
+    /// The payment method type is not in the allowed payment method types of the payment
+    PaymentMethodTypeNotAllowed,
+    /// The card networks requested are not enabled for the payment method
+    CardNetworkNotEnabled,
+    /// The payment method is not eligible to be saved through the SDK
+    SavedPaymentMethodNotSupported,
+    /// A constraint of the connector or the `pm_filters` configuration is not satisfied
+    ConstraintNotSatisfied,
+    /// The constraint graph could not be evaluated for the payment method
+    ConstraintGraphError,
+    /// Session flow routing chose another connector for the payment method type
+    NotChosenBySessionRouting,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq, Hash)]
 pub struct RequestPaymentMethodTypes {
     #[schema(value_type = PaymentMethodType)]
@@ -1983,6 +2056,32 @@ pub struct TotalPaymentMethodCountResponse {
 #[cfg(feature = "v2")]
 impl common_utils::events::ApiEventMetric for TotalPaymentMethodCountResponse {}
 
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct DuplicatePaymentMethodsMergeResponse {
+    /// The customer whose saved payment methods were merged
+    #[schema(value_type = String, example = "12345_cus_01926c58bc6e77c09e809964e72af8c8")]
+    pub customer_id: id_type::GlobalCustomerId,
+    /// The groups of duplicate payment methods that were merged
+    pub merged_payment_methods: Vec<DuplicatePaymentMethodsMergeResult>,
+}
+
+#[cfg(feature = "v2")]
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct DuplicatePaymentMethodsMergeResult {
+    /// The payment method that the duplicates were merged into
+    #[schema(value_type = String, example = "12345_pm_01926c58bc6e77c09e809964e72af8c8")]
+    pub payment_method_id: id_type::GlobalPaymentMethodId,
+    /// The duplicate payment methods that were deactivated
+    #[schema(value_type = Vec<String>, example = json!(["12345_pm_01926c58bc6e77c09e809964e72af8c9"]))]
+    pub duplicate_payment_method_ids: Vec<id_type::GlobalPaymentMethodId>,
+    /// The mandates that were moved to the surviving payment method
+    pub mandate_ids: Vec<String>,
+}
+
+#[cfg(feature = "v2")]
+impl common_utils::events::ApiEventMetric for DuplicatePaymentMethodsMergeResponse {}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, serde::Serialize, ToSchema)]
 pub struct PaymentMethodDeleteResponse {
@@ -3021,3 +3120,54 @@ pub struct AuthenticationDetails {
     #[schema(value_type = Option<ErrorDetails>)]
     pub error: Option<payments::ErrorDetails>,
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardUpdaterConfigRequest {
+    /// The profile whose webhook configuration is used to notify about updated and expiring cards
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Saved cards expiring within these many days are picked up by the card updater. Defaults to
+    /// 30 days.
+    #[schema(example = 30, maximum = 90)]
+    pub expiry_window_in_days: Option<u16>,
+
+    /// The interval between two runs of the card updater. Defaults to 24 hours.
+    #[schema(example = 24, maximum = 168)]
+    pub run_interval_in_hours: Option<u16>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CardUpdaterConfigResponse {
+    /// The identifier for the merchant
+    #[schema(value_type = String, example = "merchant_1671528864")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The profile whose webhook configuration is used to notify about updated and expiring cards
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Saved cards expiring within these many days are picked up by the card updater
+    #[schema(example = 30)]
+    pub expiry_window_in_days: u16,
+
+    /// The interval between two runs of the card updater
+    #[schema(example = 24)]
+    pub run_interval_in_hours: u16,
+
+    /// Whether the card updater is scheduled to run for the merchant
+    pub enabled: bool,
+
+    /// The time at which the card updater is scheduled to run next
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+
+    /// The time at which the card updater last ran
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<time::PrimitiveDateTime>,
+}
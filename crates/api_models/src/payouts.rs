@@ -677,6 +677,20 @@ pub struct PayoutActionRequest {
     pub payout_id: String,
 }
 
+/// Request to approve or reject a payout that is pending approval
+#[derive(Default, Debug, Deserialize, Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutApprovalRequest {
+    /// Unique identifier for the payout
+    #[schema(value_type = String, example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    #[serde(skip_deserializing)]
+    pub payout_id: String,
+
+    /// The reason for the decision, recorded as the error message when the payout is rejected
+    #[schema(max_length = 255, example = "Beneficiary details not verified")]
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize)]
 pub struct PayoutVendorAccountDetails {
     pub vendor_details: PayoutVendorDetails,
@@ -195,6 +195,20 @@ pub struct RefundManualUpdateRequest {
     pub error_message: Option<String>,
 }
 
+/// Request to approve or reject a refund that is pending approval
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundApprovalRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The reason for the decision, recorded as the error message when the refund is rejected
+    #[schema(
+        max_length = 255,
+        example = "Refund amount not verified with the customer"
+    )]
+    pub reason: Option<String>,
+}
+
 #[cfg(feature = "v1")]
 /// To indicate whether to refund needs to be instant or scheduled
 #[derive(
@@ -466,6 +480,7 @@ pub enum RefundStatus {
     #[default]
     Pending,
     Review,
+    PendingApproval,
 }
 
 impl From<enums::RefundStatus> for RefundStatus {
@@ -475,6 +490,7 @@ impl From<enums::RefundStatus> for RefundStatus {
             enums::RefundStatus::ManualReview => Self::Review,
             enums::RefundStatus::Pending => Self::Pending,
             enums::RefundStatus::Success => Self::Succeeded,
+            enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -486,6 +502,7 @@ impl From<RefundStatus> for enums::RefundStatus {
             RefundStatus::Review => Self::ManualReview,
             RefundStatus::Pending => Self::Pending,
             RefundStatus::Succeeded => Self::Success,
+            RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
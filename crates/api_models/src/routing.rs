@@ -155,6 +155,10 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CardRedirectType,
         DirKeyKind::BankTransferType,
         DirKeyKind::RealTimePaymentType,
+        DirKeyKind::LocalHourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::LocalDate,
+        DirKeyKind::HolidayCalendar,
     ];
 }
 
@@ -518,6 +522,9 @@ pub struct RoutingAlgorithmRef {
     pub timestamp: i64,
     pub config_algo_id: Option<String>,
     pub surcharge_config_algo_id: Option<String>,
+    /// Algorithm evaluated alongside the active algorithm, whose result is only recorded
+    #[serde(default)]
+    pub shadow_algorithm_id: Option<common_utils::id_type::RoutingId>,
 }
 
 impl RoutingAlgorithmRef {
@@ -526,6 +533,11 @@ impl RoutingAlgorithmRef {
         self.timestamp = common_utils::date_time::now_unix_timestamp();
     }
 
+    pub fn update_shadow_algorithm_id(&mut self, new_id: Option<common_utils::id_type::RoutingId>) {
+        self.shadow_algorithm_id = new_id;
+        self.timestamp = common_utils::date_time::now_unix_timestamp();
+    }
+
     pub fn update_conditional_config_id(&mut self, ids: String) {
         self.config_algo_id = Some(ids);
         self.timestamp = common_utils::date_time::now_unix_timestamp();
@@ -8,6 +8,7 @@ use crate::user_role::UserStatus;
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 #[cfg(feature = "control_center_theme")]
 pub mod theme;
 
@@ -242,6 +243,7 @@ pub struct TokenResponse {
 pub struct TwoFactorAuthStatusResponse {
     pub totp: bool,
     pub recovery_code: bool,
+    pub passkey: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -254,6 +256,7 @@ pub struct TwoFactorAuthAttempts {
 pub struct TwoFactorAuthStatusResponseWithAttempts {
     pub totp: TwoFactorAuthAttempts,
     pub recovery_code: TwoFactorAuthAttempts,
+    pub passkey: TwoFactorAuthAttempts,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -293,6 +296,101 @@ pub struct RecoveryCodes {
     pub recovery_codes: Vec<Secret<String>>,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyRelyingParty {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyUserEntity {
+    pub id: String,
+    pub name: pii::Email,
+    pub display_name: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyCredentialParameters {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub alg: i32,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyCredentialDescriptor {
+    #[serde(rename = "type")]
+    pub credential_type: String,
+    pub id: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyAuthenticatorSelection {
+    pub resident_key: String,
+    pub user_verification: String,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyRegistrationOptionsResponse {
+    pub challenge: String,
+    pub rp: PasskeyRelyingParty,
+    pub user: PasskeyUserEntity,
+    pub pub_key_cred_params: Vec<PasskeyCredentialParameters>,
+    pub exclude_credentials: Vec<PasskeyCredentialDescriptor>,
+    pub authenticator_selection: PasskeyAuthenticatorSelection,
+    pub attestation: String,
+    pub timeout: u64,
+}
+
+/// Registration response as returned by `AuthenticatorAttestationResponse`, all binary
+/// fields are base64url encoded.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyRegisterRequest {
+    pub name: String,
+    pub credential_id: String,
+    pub client_data_json: Secret<String>,
+    pub attestation_object: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyAuthenticationOptionsResponse {
+    pub challenge: String,
+    pub rp_id: String,
+    pub allow_credentials: Vec<PasskeyCredentialDescriptor>,
+    pub timeout: u64,
+}
+
+/// Assertion response as returned by `AuthenticatorAssertionResponse`, all binary
+/// fields are base64url encoded.
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyAssertionRequest {
+    pub credential_id: String,
+    pub client_data_json: Secret<String>,
+    pub authenticator_data: Secret<String>,
+    pub signature: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInBeginRequest {
+    pub email: pii::Email,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeySignInRequest {
+    pub email: pii::Email,
+    #[serde(flatten)]
+    pub assertion: PasskeyAssertionRequest,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct PasskeyResponse {
+    pub credential_id: String,
+    pub name: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_used_at: Option<time::PrimitiveDateTime>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(tag = "auth_type")]
 #[serde(rename_all = "snake_case")]
@@ -303,6 +401,7 @@ pub enum AuthConfig {
     },
     MagicLink,
     Password,
+    Passkey,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
@@ -0,0 +1,222 @@
+use common_enums::EntityType;
+use common_utils::{id_type, pii};
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateScimTokenRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreateScimTokenResponse {
+    pub token_id: String,
+    pub name: String,
+    pub token: Secret<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimTokenResponse {
+    pub token_id: String,
+    pub name: String,
+    pub prefix: String,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimListQuery {
+    pub filter: Option<String>,
+    pub start_index: Option<usize>,
+    pub count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimName {
+    pub formatted: Option<Secret<String>>,
+    pub given_name: Option<Secret<String>>,
+    pub family_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScimEmail {
+    pub value: pii::Email,
+    pub primary: Option<bool>,
+    #[serde(rename = "type")]
+    pub email_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimMeta {
+    pub resource_type: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified: PrimitiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    pub external_id: Option<String>,
+    pub user_name: Secret<String>,
+    pub name: Option<ScimName>,
+    pub display_name: Option<Secret<String>>,
+    pub active: Option<bool>,
+    pub emails: Option<Vec<ScimEmail>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub external_id: Option<String>,
+    pub user_name: pii::Email,
+    pub name: ScimName,
+    pub display_name: Secret<String>,
+    pub active: bool,
+    pub emails: Vec<ScimEmail>,
+    pub groups: Vec<ScimGroupMember>,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimUserListResponse {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<ScimUserResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ScimGroupMember {
+    pub value: String,
+    pub display: Option<String>,
+}
+
+/// Maps a SCIM group to a Hyperswitch role at organization, merchant or profile level
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupRoleMapping {
+    pub role_id: String,
+    pub entity_type: EntityType,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub members: Option<Vec<ScimGroupMember>>,
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group")]
+    pub role_mapping: Option<ScimGroupRoleMapping>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupResponse {
+    pub schemas: Vec<String>,
+    pub id: String,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub members: Vec<ScimGroupMember>,
+    #[serde(rename = "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group")]
+    pub role_mapping: ScimGroupRoleMapping,
+    pub meta: ScimMeta,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimGroupListResponse {
+    pub schemas: Vec<String>,
+    pub total_results: usize,
+    pub start_index: usize,
+    pub items_per_page: usize,
+    #[serde(rename = "Resources")]
+    pub resources: Vec<ScimGroupResponse>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScimPatchOperationType {
+    #[serde(alias = "Add")]
+    Add,
+    #[serde(alias = "Remove")]
+    Remove,
+    #[serde(alias = "Replace")]
+    Replace,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScimPatchOperation {
+    pub op: ScimPatchOperationType,
+    pub path: Option<String>,
+    pub value: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ScimPatchRequest {
+    #[serde(default)]
+    pub schemas: Vec<String>,
+    #[serde(rename = "Operations")]
+    pub operations: Vec<ScimPatchOperation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimSupported {
+    pub supported: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimBulkSupport {
+    pub supported: bool,
+    pub max_operations: usize,
+    pub max_payload_size: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimFilterSupport {
+    pub supported: bool,
+    pub max_results: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScimAuthenticationScheme {
+    #[serde(rename = "type")]
+    pub scheme_type: String,
+    pub name: String,
+    pub description: String,
+    pub primary: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScimServiceProviderConfigResponse {
+    pub schemas: Vec<String>,
+    pub patch: ScimSupported,
+    pub bulk: ScimBulkSupport,
+    pub filter: ScimFilterSupport,
+    pub change_password: ScimSupported,
+    pub sort: ScimSupported,
+    pub etag: ScimSupported,
+    pub authentication_schemes: Vec<ScimAuthenticationScheme>,
+}
@@ -1,19 +1,23 @@
 use common_enums::{
     EntityType, ParentGroup, PermissionGroup, PermissionScope, Resource, RoleScope,
 };
+use common_types::role::ResourcePermission;
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct CreateRoleRequest {
     pub role_name: String,
+    #[serde(default)]
     pub groups: Vec<PermissionGroup>,
     pub role_scope: RoleScope,
     pub entity_type: Option<EntityType>,
+    pub resource_permissions: Option<Vec<ResourcePermission>>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct UpdateRoleRequest {
     pub groups: Option<Vec<PermissionGroup>>,
     pub role_name: Option<String>,
+    pub resource_permissions: Option<Vec<ResourcePermission>>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -23,6 +27,27 @@ pub struct RoleInfoWithGroupsResponse {
     pub role_name: String,
     pub role_scope: RoleScope,
     pub entity_type: EntityType,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resource_permissions: Vec<ResourcePermission>,
+}
+
+/// Portable definition of a role, used to export a role and import it as a custom role
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct RoleTemplate {
+    pub role_name: String,
+    pub role_scope: RoleScope,
+    pub entity_type: EntityType,
+    #[serde(default)]
+    pub groups: Vec<PermissionGroup>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub resource_permissions: Vec<ResourcePermission>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImportRoleTemplateRequest {
+    pub template: RoleTemplate,
+    /// Overrides the role name present in the template
+    pub role_name: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -52,6 +77,8 @@ pub struct RoleInfoResponseNew {
     pub entity_type: EntityType,
     pub groups: Vec<PermissionGroup>,
     pub scope: RoleScope,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub resource_permissions: Vec<ResourcePermission>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
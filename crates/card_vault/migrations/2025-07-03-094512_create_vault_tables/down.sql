@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS vault_access_logs;
+
+DROP TABLE IF EXISTS vault_cards;
+
+DROP TABLE IF EXISTS vault_data_keys;
@@ -0,0 +1,39 @@
+CREATE TABLE IF NOT EXISTS vault_data_keys (
+    key_version INTEGER PRIMARY KEY,
+    wrapped_key BYTEA NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE TABLE IF NOT EXISTS vault_cards (
+    card_reference VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    merchant_customer_id VARCHAR(64) NOT NULL,
+    fingerprint VARCHAR(128) NOT NULL,
+    key_version INTEGER NOT NULL REFERENCES vault_data_keys (key_version),
+    encrypted_data BYTEA NOT NULL,
+    expires_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS vault_cards_merchant_customer_fingerprint_index ON vault_cards (
+    merchant_id,
+    merchant_customer_id,
+    fingerprint
+);
+
+CREATE INDEX IF NOT EXISTS vault_cards_key_version_index ON vault_cards (key_version);
+
+CREATE TABLE IF NOT EXISTS vault_access_logs (
+    id SERIAL PRIMARY KEY,
+    request_id VARCHAR(64),
+    merchant_id VARCHAR(64),
+    card_reference VARCHAR(64),
+    action VARCHAR(32) NOT NULL,
+    succeeded BOOLEAN NOT NULL,
+    error_code VARCHAR(32),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS vault_access_logs_card_reference_index ON vault_access_logs (card_reference);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS vault_key_rotations (
+    key_version INTEGER PRIMARY KEY REFERENCES vault_data_keys (key_version),
+    status VARCHAR(32) NOT NULL,
+    last_card_reference VARCHAR(64),
+    re_encrypted_cards BIGINT NOT NULL DEFAULT 0,
+    failed_cards BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
@@ -0,0 +1,36 @@
+use bb8::PooledConnection;
+use common_utils::DbConnectionParams;
+use diesel::PgConnection;
+use error_stack::ResultExt;
+
+use crate::{errors::StorageError, settings::Database};
+
+pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
+
+/// # Panics
+///
+/// Will panic if could not create a db pool
+#[allow(clippy::expect_used)]
+pub async fn diesel_make_pg_pool(database: &Database, schema: &str) -> PgPool {
+    let database_url = database.get_database_url(schema);
+    let manager = async_bb8_diesel::ConnectionManager::<PgConnection>::new(database_url);
+    let pool = bb8::Pool::builder()
+        .max_size(database.pool_size)
+        .connection_timeout(std::time::Duration::from_secs(database.connection_timeout));
+
+    pool.build(manager)
+        .await
+        .expect("Failed to create PostgreSQL connection pool")
+}
+
+pub async fn pg_connection(
+    pool: &PgPool,
+) -> error_stack::Result<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
+> {
+    pool.get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)
+        .attach_printable("Couldn't retrieve PostgreSQL connection")
+}
@@ -0,0 +1,508 @@
+use std::{str::FromStr, sync::Arc};
+
+use common_utils::{
+    ext_traits::{ByteSliceExt, Encode},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing, tracing::Instrument};
+
+use crate::{
+    crypto,
+    errors::{ApiError, ApiResult},
+    models::{
+        KeyRotation, KeyRotationNew, KeyRotationUpdate, VaultCard, VaultCardNew, VaultCardUpdate,
+    },
+    storage::Store,
+    types::{
+        CardReqBody, DataDuplicationCheck, KeyRotationResponse, KeyRotationStatus,
+        RetrieveCardRespPayload, StoreCardRespPayload, StoreLockerReq, StoredData,
+    },
+    AppState,
+};
+
+struct StoreRequestData {
+    merchant_id: String,
+    merchant_customer_id: String,
+    requestor_card_reference: Option<String>,
+    ttl: i64,
+    fingerprint_source: String,
+    data: StoredData,
+}
+
+impl From<StoreLockerReq> for StoreRequestData {
+    fn from(req: StoreLockerReq) -> Self {
+        match req {
+            StoreLockerReq::LockerCard(req) => Self {
+                merchant_id: req.merchant_id.get_string_repr().to_owned(),
+                merchant_customer_id: req.merchant_customer_id.get_string_repr().to_owned(),
+                requestor_card_reference: req.requestor_card_reference,
+                ttl: req.ttl,
+                fingerprint_source: req.card.card_number.get_card_no(),
+                data: StoredData::Card { card: req.card },
+            },
+            StoreLockerReq::LockerGeneric(req) => Self {
+                merchant_id: req.merchant_id.get_string_repr().to_owned(),
+                merchant_customer_id: req.merchant_customer_id.get_string_repr().to_owned(),
+                requestor_card_reference: None,
+                ttl: req.ttl,
+                fingerprint_source: req.enc_data.peek().to_owned(),
+                data: StoredData::Generic {
+                    enc_card_data: req.enc_data,
+                },
+            },
+        }
+    }
+}
+
+fn get_store<'a>(state: &'a AppState, tenant_id: &id_type::TenantId) -> ApiResult<&'a Store> {
+    state
+        .stores
+        .get(tenant_id)
+        .ok_or(ApiError::TenantNotFound.into())
+}
+
+fn get_expiry(ttl: i64, now: time::PrimitiveDateTime) -> Option<time::PrimitiveDateTime> {
+    (ttl > 0).then(|| now.saturating_add(time::Duration::seconds(ttl)))
+}
+
+async fn decrypt_stored_data(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    store: &Store,
+    card: VaultCard,
+) -> ApiResult<Vec<u8>> {
+    let key = state
+        .data_key_manager
+        .get_key(tenant_id, store, card.key_version)
+        .await?;
+    crypto::decrypt_data(&key, card.encrypted_data)
+}
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    req: StoreLockerReq,
+) -> ApiResult<StoreCardRespPayload> {
+    let store = get_store(state, tenant_id)?;
+    let request_data = StoreRequestData::from(req);
+    let now = common_utils::date_time::now();
+
+    let fingerprint = crypto::fingerprint(
+        &state.conf.vault.hash_key,
+        request_data.fingerprint_source.as_bytes(),
+    )?;
+    let serialized_data = request_data
+        .data
+        .encode_to_vec()
+        .change_context(ApiError::InvalidRequest)
+        .attach_printable("Failed to serialize data to be stored")?;
+
+    let (key_version, key) = state
+        .data_key_manager
+        .get_active_key(tenant_id, store)
+        .await?;
+
+    let existing_card = match store
+        .find_card_by_fingerprint(
+            &request_data.merchant_id,
+            &request_data.merchant_customer_id,
+            &fingerprint,
+        )
+        .await?
+    {
+        Some(existing_card) => existing_card,
+        None => {
+            let inserted_card = store
+                .insert_card_if_not_exists(VaultCardNew {
+                    card_reference: request_data
+                        .requestor_card_reference
+                        .clone()
+                        .unwrap_or_else(common_utils::generate_time_ordered_id_without_prefix),
+                    merchant_id: request_data.merchant_id.clone(),
+                    merchant_customer_id: request_data.merchant_customer_id.clone(),
+                    fingerprint: fingerprint.clone(),
+                    key_version,
+                    encrypted_data: crypto::encrypt_data(&key, &serialized_data)?,
+                    expires_at: get_expiry(request_data.ttl, now),
+                    created_at: now,
+                    modified_at: now,
+                })
+                .await?;
+
+            match inserted_card {
+                Some(card) => {
+                    return Ok(StoreCardRespPayload {
+                        card_reference: card.card_reference,
+                        duplication_check: None,
+                    })
+                }
+                // A concurrent request stored the same card in the meantime, it is handled like
+                // any existing card
+                None => store
+                    .find_card_by_fingerprint(
+                        &request_data.merchant_id,
+                        &request_data.merchant_customer_id,
+                        &fingerprint,
+                    )
+                    .await?
+                    .ok_or(ApiError::DatabaseError)
+                    .attach_printable("Card conflicting with the inserted card was not found")?,
+            }
+        }
+    };
+
+    let card_reference = existing_card.card_reference.clone();
+
+    // Expired data is replaced, as if it was not stored
+    let existing_data = if existing_card.is_expired(now) {
+        None
+    } else {
+        Some(decrypt_stored_data(state, tenant_id, store, existing_card).await?)
+    };
+
+    if get_duplication_check(existing_data.as_deref(), &serialized_data)
+        == DataDuplicationCheck::Duplicated
+    {
+        return Ok(StoreCardRespPayload {
+            card_reference,
+            duplication_check: Some(DataDuplicationCheck::Duplicated),
+        });
+    }
+
+    logger::debug!("Updating data of an existing card in the vault");
+    store
+        .update_card(
+            &card_reference,
+            VaultCardUpdate {
+                key_version,
+                encrypted_data: crypto::encrypt_data(&key, &serialized_data)?,
+                expires_at: Some(get_expiry(request_data.ttl, now)),
+                modified_at: now,
+            },
+        )
+        .await?;
+
+    Ok(StoreCardRespPayload {
+        card_reference,
+        duplication_check: Some(DataDuplicationCheck::MetaDataChanged),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    req: CardReqBody,
+) -> ApiResult<RetrieveCardRespPayload> {
+    let store = get_store(state, tenant_id)?;
+    let card = store
+        .find_card(
+            req.merchant_id.get_string_repr(),
+            req.merchant_customer_id.get_string_repr(),
+            &req.card_reference,
+        )
+        .await?;
+
+    if card.is_expired(common_utils::date_time::now()) {
+        return Err(ApiError::CardNotFound.into());
+    }
+
+    let stored_data: StoredData = decrypt_stored_data(state, tenant_id, store, card)
+        .await?
+        .parse_struct("StoredData")
+        .change_context(ApiError::DataEncryptionFailed)
+        .attach_printable("Failed to deserialize data stored in the vault")?;
+
+    Ok(match stored_data {
+        StoredData::Card { card } => RetrieveCardRespPayload {
+            card: Some(card),
+            enc_card_data: None,
+        },
+        StoredData::Generic { enc_card_data } => RetrieveCardRespPayload {
+            card: None,
+            enc_card_data: Some(enc_card_data),
+        },
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    req: CardReqBody,
+) -> ApiResult<()> {
+    let store = get_store(state, tenant_id)?;
+    let is_deleted = store
+        .delete_card(
+            req.merchant_id.get_string_repr(),
+            req.merchant_customer_id.get_string_repr(),
+            &req.card_reference,
+        )
+        .await?;
+
+    common_utils::fp_utils::when(!is_deleted, || Err(ApiError::CardNotFound.into()))
+}
+
+/// Determines whether data being stored for a fingerprint that already exists duplicates the
+/// stored data, which is `None` if it has expired.
+fn get_duplication_check(existing_data: Option<&[u8]>, data: &[u8]) -> DataDuplicationCheck {
+    if existing_data == Some(data) {
+        DataDuplicationCheck::Duplicated
+    } else {
+        DataDuplicationCheck::MetaDataChanged
+    }
+}
+
+impl TryFrom<KeyRotation> for KeyRotationResponse {
+    type Error = error_stack::Report<ApiError>;
+
+    fn try_from(key_rotation: KeyRotation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            key_version: key_rotation.key_version,
+            status: KeyRotationStatus::from_str(&key_rotation.status)
+                .change_context(ApiError::DatabaseError)
+                .attach_printable("Invalid key rotation status")?,
+            re_encrypted_cards: key_rotation.re_encrypted_cards,
+            failed_cards: key_rotation.failed_cards,
+        })
+    }
+}
+
+/// Generates a new data key for the tenant and starts re-encrypting every card with it in the
+/// background.
+#[instrument(skip_all)]
+pub async fn rotate_data_key(
+    state: Arc<AppState>,
+    tenant_id: &id_type::TenantId,
+) -> ApiResult<KeyRotationResponse> {
+    let store = get_store(&state, tenant_id)?;
+    let (key_version, _key) = state
+        .data_key_manager
+        .create_active_key(tenant_id, store)
+        .await?;
+
+    let now = common_utils::date_time::now();
+    let key_rotation = store
+        .insert_key_rotation(KeyRotationNew {
+            key_version,
+            status: KeyRotationStatus::InProgress.to_string(),
+            created_at: now,
+            modified_at: now,
+        })
+        .await?;
+    let response = KeyRotationResponse::try_from(key_rotation.clone())?;
+
+    spawn_key_rotation(state, tenant_id.clone(), key_rotation);
+    Ok(response)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_key_rotation(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+) -> ApiResult<KeyRotationResponse> {
+    get_store(state, tenant_id)?
+        .find_latest_key_rotation()
+        .await?
+        .ok_or(report!(ApiError::KeyRotationNotFound))
+        .and_then(KeyRotationResponse::try_from)
+}
+
+/// Resumes the key rotations of every tenant that were interrupted, from the last card they
+/// processed.
+pub async fn resume_key_rotations(state: Arc<AppState>) {
+    for (tenant_id, store) in &state.stores {
+        match store.list_key_rotations_in_progress().await {
+            Ok(key_rotations) => {
+                for key_rotation in key_rotations {
+                    logger::info!(
+                        key_version = key_rotation.key_version,
+                        "Resuming data key rotation"
+                    );
+                    spawn_key_rotation(state.clone(), tenant_id.clone(), key_rotation);
+                }
+            }
+            Err(error) => logger::error!(?error, "Failed to list key rotations in progress"),
+        }
+    }
+}
+
+fn spawn_key_rotation(
+    state: Arc<AppState>,
+    tenant_id: id_type::TenantId,
+    key_rotation: KeyRotation,
+) {
+    tokio::spawn(
+        async move {
+            re_encrypt_cards(&state, &tenant_id, key_rotation)
+                .await
+                .map_err(|error| logger::error!(?error, "Data key rotation was interrupted"))
+                .ok();
+        }
+        .in_current_span(),
+    );
+}
+
+/// Re-encrypts the cards of the tenant with the data key of the rotation in batches. The progress
+/// is persisted after every batch, so that an interrupted rotation can be resumed.
+async fn re_encrypt_cards(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    mut key_rotation: KeyRotation,
+) -> ApiResult<()> {
+    let store = get_store(state, tenant_id)?;
+    let key_version = key_rotation.key_version;
+    let key = state
+        .data_key_manager
+        .get_key(tenant_id, store, key_version)
+        .await?;
+
+    loop {
+        // The rotation to a newer data key re-encrypts every card again
+        let active_key_version = store
+            .find_active_data_key()
+            .await?
+            .map(|data_key| data_key.key_version);
+        if active_key_version != Some(key_version) {
+            store
+                .update_key_rotation(
+                    key_version,
+                    get_key_rotation_status_update(KeyRotationStatus::Superseded),
+                )
+                .await?;
+            logger::info!(key_version, "Data key rotation was superseded");
+            return Ok(());
+        }
+
+        let cards = store
+            .list_cards_not_encrypted_with(
+                key_version,
+                key_rotation.last_card_reference.as_deref(),
+                state.conf.vault.rotation_batch_size,
+            )
+            .await?;
+        let Some(last_card_reference) = cards.last().map(|card| card.card_reference.clone()) else {
+            store
+                .update_key_rotation(
+                    key_version,
+                    get_key_rotation_status_update(KeyRotationStatus::Completed),
+                )
+                .await?;
+            logger::info!(
+                key_version,
+                re_encrypted_cards = key_rotation.re_encrypted_cards,
+                failed_cards = key_rotation.failed_cards,
+                "Completed data key rotation"
+            );
+            return Ok(());
+        };
+
+        let (mut re_encrypted_cards, mut failed_cards) = (0, 0);
+        for card in cards {
+            let card_reference = card.card_reference.clone();
+            match re_encrypt_card(state, tenant_id, store, key_version, &key, card).await {
+                Ok(true) => re_encrypted_cards += 1,
+                // The card was rewritten with the active data key or deleted after it was listed
+                Ok(false) => {
+                    logger::info!(%card_reference, "Skipping card modified during re-encryption");
+                }
+                // Cards that could not be re-encrypted remain readable with their data key
+                Err(error) => {
+                    logger::error!(?error, %card_reference, "Failed to re-encrypt card");
+                    failed_cards += 1;
+                }
+            }
+        }
+
+        key_rotation = store
+            .update_key_rotation(
+                key_version,
+                KeyRotationUpdate {
+                    status: None,
+                    last_card_reference: Some(last_card_reference),
+                    re_encrypted_cards: Some(key_rotation.re_encrypted_cards + re_encrypted_cards),
+                    failed_cards: Some(key_rotation.failed_cards + failed_cards),
+                    modified_at: common_utils::date_time::now(),
+                },
+            )
+            .await?;
+    }
+}
+
+async fn re_encrypt_card(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    store: &Store,
+    key_version: i32,
+    key: &Secret<Vec<u8>>,
+    card: VaultCard,
+) -> ApiResult<bool> {
+    let current_key = state
+        .data_key_manager
+        .get_key(tenant_id, store, card.key_version)
+        .await?;
+
+    // The update only applies if the card was not modified since it was read, so that data
+    // stored concurrently is not overwritten with the re-encrypted stale data
+    store
+        .update_card_if_unmodified(
+            &card.card_reference,
+            card.key_version,
+            card.modified_at,
+            VaultCardUpdate {
+                key_version,
+                encrypted_data: crypto::re_encrypt_data(&current_key, key, card.encrypted_data)?,
+                expires_at: Some(card.expires_at),
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .map(|updated_card| updated_card.is_some())
+}
+
+fn get_key_rotation_status_update(status: KeyRotationStatus) -> KeyRotationUpdate {
+    KeyRotationUpdate {
+        status: Some(status.to_string()),
+        last_card_reference: None,
+        re_encrypted_cards: None,
+        failed_cards: None,
+        modified_at: common_utils::date_time::now(),
+    }
+}
+
+pub(crate) fn is_valid_admin_api_key(state: &AppState, api_key: &str) -> bool {
+    let expected = state.conf.vault.admin_api_key.clone().expose();
+    ring::constant_time::verify_slices_are_equal(expected.as_bytes(), api_key.as_bytes()).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_data_is_duplicated() {
+        assert_eq!(
+            get_duplication_check(Some(b"card".as_slice()), b"card"),
+            DataDuplicationCheck::Duplicated
+        );
+    }
+
+    #[test]
+    fn test_different_data_changes_metadata() {
+        assert_eq!(
+            get_duplication_check(Some(b"card".as_slice()), b"updated card"),
+            DataDuplicationCheck::MetaDataChanged
+        );
+    }
+
+    #[test]
+    fn test_expired_data_is_not_duplicated() {
+        assert_eq!(
+            get_duplication_check(None, b"card"),
+            DataDuplicationCheck::MetaDataChanged
+        );
+    }
+}
@@ -0,0 +1,334 @@
+use std::{collections::HashMap, sync::Arc};
+
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, SignMessage},
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_interfaces::encryption_interface::EncryptionManagementInterface;
+use josekit::{jwe, jws};
+use masking::{PeekInterface, Secret};
+use tokio::sync::RwLock;
+
+use crate::{
+    errors::{ApiError, ApiResult},
+    settings::{KeyEncryptionScheme, VaultSettings},
+    storage::Store,
+    types::{JweBody, JwsBody},
+};
+
+const DATA_KEY_LENGTH: usize = 32;
+
+/// Decrypts a request sent by the router, and verifies its signature using the tenant public key.
+pub fn decrypt_request(
+    jwe_body: JweBody,
+    vault_settings: &VaultSettings,
+    tenant_public_key: &Secret<String>,
+) -> ApiResult<String> {
+    let jwt = format!(
+        "{}.{}.{}.{}.{}",
+        jwe_body.header,
+        jwe_body.encrypted_key,
+        jwe_body.iv,
+        jwe_body.encrypted_payload,
+        jwe_body.tag
+    );
+
+    // The router always encrypts the content encryption key of requests using RSA-OAEP-256
+    let decrypter = jwe::RSA_OAEP_256
+        .decrypter_from_pem(vault_settings.private_key.peek())
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Error getting JweDecryptor")?;
+    let (jws_payload, _header) = jwe::deserialize_compact(&jwt, &decrypter)
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Error getting Decrypted jwe")?;
+
+    let jws_body: JwsBody = String::from_utf8(jws_payload)
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Could not decode JWE payload from UTF-8")?
+        .parse_struct("JwsBody")
+        .change_context(ApiError::RequestDecryptionFailed)?;
+    let jws = format!(
+        "{}.{}.{}",
+        jws_body.header, jws_body.payload, jws_body.signature
+    );
+
+    let verifier = jws::RS256
+        .verifier_from_pem(tenant_public_key.peek())
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Error getting verifier")?;
+    let (payload, _header) = jws::deserialize_compact(jws.as_bytes(), &verifier)
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Error verifying the request signature")?;
+
+    String::from_utf8(payload)
+        .change_context(ApiError::RequestDecryptionFailed)
+        .attach_printable("Could not decode JWS payload from UTF-8")
+}
+
+/// Signs a response using the vault private key, and encrypts it using the tenant public key.
+pub fn encrypt_response(
+    payload: &[u8],
+    vault_settings: &VaultSettings,
+    tenant_public_key: &Secret<String>,
+) -> ApiResult<JweBody> {
+    let mut jws_header = jws::JwsHeader::new();
+    if let Some(key_id) = &vault_settings.key_id {
+        jws_header.set_key_id(key_id);
+    }
+    let signer = jws::RS256
+        .signer_from_pem(vault_settings.private_key.peek())
+        .change_context(ApiError::ResponseEncryptionFailed)
+        .attach_printable("Error getting signer")?;
+    let jws = jws::serialize_compact(payload, &jws_header, &signer)
+        .change_context(ApiError::ResponseEncryptionFailed)
+        .attach_printable("Error getting signed jwt string")?;
+
+    let mut jws_parts = jws.split('.');
+    let jws_body = JwsBody {
+        header: jws_parts.next().unwrap_or_default().to_string(),
+        payload: jws_parts.next().unwrap_or_default().to_string(),
+        signature: jws_parts.next().unwrap_or_default().to_string(),
+    }
+    .encode_to_vec()
+    .change_context(ApiError::ResponseEncryptionFailed)?;
+
+    let mut jwe_header = jwe::JweHeader::new();
+    jwe_header.set_content_encryption("A256GCM");
+    jwe_header.set_token_type("JWT");
+    let encrypter = match vault_settings.response_encryption_scheme {
+        KeyEncryptionScheme::RsaOaep => jwe::RSA_OAEP.encrypter_from_pem(tenant_public_key.peek()),
+        KeyEncryptionScheme::RsaOaep256 => {
+            jwe::RSA_OAEP_256.encrypter_from_pem(tenant_public_key.peek())
+        }
+    }
+    .change_context(ApiError::ResponseEncryptionFailed)
+    .attach_printable("Error getting JweEncryptor")?;
+    let jwe = jwe::serialize_compact(&jws_body, &jwe_header, &encrypter)
+        .change_context(ApiError::ResponseEncryptionFailed)
+        .attach_printable("Error getting jwt string")?;
+
+    let jwe_parts: Vec<&str> = jwe.split('.').collect();
+    let generate_jwe_body = |parts: Vec<&str>| -> Option<JweBody> {
+        Some(JweBody {
+            header: parts.first()?.to_string(),
+            encrypted_key: parts.get(1)?.to_string(),
+            iv: parts.get(2)?.to_string(),
+            encrypted_payload: parts.get(3)?.to_string(),
+            tag: parts.get(4)?.to_string(),
+        })
+    };
+
+    generate_jwe_body(jwe_parts)
+        .ok_or(report!(ApiError::ResponseEncryptionFailed))
+        .attach_printable("Malformed JWE generated for the response")
+}
+
+/// Computes the keyed hash used to detect duplicate data of a customer.
+pub fn fingerprint(hash_key: &Secret<String>, data: &[u8]) -> ApiResult<String> {
+    crypto::HmacSha256
+        .sign_message(hash_key.peek().as_bytes(), data)
+        .map(hex::encode)
+        .change_context(ApiError::DataEncryptionFailed)
+        .attach_printable("Failed to compute data fingerprint")
+}
+
+/// Envelope encryption of the data stored in the vault.
+///
+/// Data is encrypted using data keys, which are persisted only after being wrapped by the
+/// configured encryption management client. Unwrapped data keys are cached in memory.
+pub struct DataKeyManager {
+    encryption_client: Arc<dyn EncryptionManagementInterface>,
+    unwrapped_keys: RwLock<HashMap<(id_type::TenantId, i32), Secret<Vec<u8>>>>,
+}
+
+impl DataKeyManager {
+    pub fn new(encryption_client: Arc<dyn EncryptionManagementInterface>) -> Self {
+        Self {
+            encryption_client,
+            unwrapped_keys: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the version and value of the active data key, creating one if none exists.
+    pub async fn get_active_key(
+        &self,
+        tenant_id: &id_type::TenantId,
+        store: &Store,
+    ) -> ApiResult<(i32, Secret<Vec<u8>>)> {
+        match store.find_active_data_key().await? {
+            Some(data_key) => {
+                let key = self
+                    .unwrap_key(tenant_id, data_key.key_version, &data_key.wrapped_key)
+                    .await?;
+                Ok((data_key.key_version, key))
+            }
+            None => self.create_active_key(tenant_id, store).await,
+        }
+    }
+
+    pub async fn get_key(
+        &self,
+        tenant_id: &id_type::TenantId,
+        store: &Store,
+        key_version: i32,
+    ) -> ApiResult<Secret<Vec<u8>>> {
+        if let Some(key) = self
+            .unwrapped_keys
+            .read()
+            .await
+            .get(&(tenant_id.clone(), key_version))
+        {
+            return Ok(key.clone());
+        }
+
+        let data_key = store.find_data_key(key_version).await?;
+        self.unwrap_key(tenant_id, key_version, &data_key.wrapped_key)
+            .await
+    }
+
+    /// Generates a new data key and marks it as the active key of the tenant.
+    pub async fn create_active_key(
+        &self,
+        tenant_id: &id_type::TenantId,
+        store: &Store,
+    ) -> ApiResult<(i32, Secret<Vec<u8>>)> {
+        let key = crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>();
+        let wrapped_key = self
+            .encryption_client
+            .encrypt(&key)
+            .await
+            .change_context(ApiError::DataEncryptionFailed)
+            .attach_printable("Failed to wrap data key")?;
+
+        let data_key = store
+            .insert_active_data_key(wrapped_key, common_utils::date_time::now())
+            .await?;
+        let key = Secret::new(key.to_vec());
+        self.unwrapped_keys
+            .write()
+            .await
+            .insert((tenant_id.clone(), data_key.key_version), key.clone());
+
+        Ok((data_key.key_version, key))
+    }
+
+    async fn unwrap_key(
+        &self,
+        tenant_id: &id_type::TenantId,
+        key_version: i32,
+        wrapped_key: &[u8],
+    ) -> ApiResult<Secret<Vec<u8>>> {
+        let cache_key = (tenant_id.clone(), key_version);
+        if let Some(key) = self.unwrapped_keys.read().await.get(&cache_key) {
+            return Ok(key.clone());
+        }
+
+        let key = Secret::new(
+            self.encryption_client
+                .decrypt(wrapped_key)
+                .await
+                .change_context(ApiError::DataEncryptionFailed)
+                .attach_printable("Failed to unwrap data key")?,
+        );
+        self.unwrapped_keys
+            .write()
+            .await
+            .insert(cache_key, key.clone());
+
+        Ok(key)
+    }
+}
+
+pub fn encrypt_data(key: &Secret<Vec<u8>>, data: &[u8]) -> ApiResult<Vec<u8>> {
+    crypto::GcmAes256
+        .encode_message(key.peek(), data)
+        .change_context(ApiError::DataEncryptionFailed)
+        .attach_printable("Failed to encrypt vault data")
+}
+
+pub fn decrypt_data(key: &Secret<Vec<u8>>, data: Vec<u8>) -> ApiResult<Vec<u8>> {
+    crypto::GcmAes256
+        .decode_message(key.peek(), data.into())
+        .change_context(ApiError::DataEncryptionFailed)
+        .attach_printable("Failed to decrypt vault data")
+}
+
+/// Decrypts data encrypted with the current data key and encrypts it with the new data key.
+pub fn re_encrypt_data(
+    current_key: &Secret<Vec<u8>>,
+    new_key: &Secret<Vec<u8>>,
+    data: Vec<u8>,
+) -> ApiResult<Vec<u8>> {
+    encrypt_data(new_key, &decrypt_data(current_key, data)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn generate_key() -> Secret<Vec<u8>> {
+        Secret::new(
+            crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>().to_vec(),
+        )
+    }
+
+    #[test]
+    fn test_encrypted_data_is_decrypted_with_the_same_key() {
+        let key = generate_key();
+        let encrypted_data = encrypt_data(&key, b"card data").unwrap();
+
+        assert_ne!(encrypted_data, b"card data");
+        assert_eq!(decrypt_data(&key, encrypted_data).unwrap(), b"card data");
+    }
+
+    #[test]
+    fn test_encrypted_data_is_not_decrypted_with_another_key() {
+        let encrypted_data = encrypt_data(&generate_key(), b"card data").unwrap();
+
+        assert!(decrypt_data(&generate_key(), encrypted_data).is_err());
+    }
+
+    #[test]
+    fn test_re_encrypted_data_is_decrypted_only_with_the_new_key() {
+        let current_key = generate_key();
+        let new_key = generate_key();
+        let encrypted_data = encrypt_data(&current_key, b"card data").unwrap();
+
+        let re_encrypted_data = re_encrypt_data(&current_key, &new_key, encrypted_data).unwrap();
+
+        assert!(decrypt_data(&current_key, re_encrypted_data.clone()).is_err());
+        assert_eq!(
+            decrypt_data(&new_key, re_encrypted_data).unwrap(),
+            b"card data"
+        );
+    }
+
+    #[test]
+    fn test_re_encryption_fails_with_a_wrong_current_key() {
+        let encrypted_data = encrypt_data(&generate_key(), b"card data").unwrap();
+
+        assert!(re_encrypt_data(&generate_key(), &generate_key(), encrypted_data).is_err());
+    }
+
+    #[test]
+    fn test_fingerprint_depends_only_on_data_and_hash_key() {
+        let hash_key = Secret::new("hash_key".to_string());
+        let fingerprint = fingerprint(&hash_key, b"4111111111111111").unwrap();
+
+        assert_eq!(
+            fingerprint,
+            super::fingerprint(&hash_key, b"4111111111111111").unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            super::fingerprint(&hash_key, b"4242424242424242").unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            super::fingerprint(&Secret::new("other_key".to_string()), b"4111111111111111").unwrap()
+        );
+    }
+}
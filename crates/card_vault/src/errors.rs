@@ -0,0 +1,93 @@
+use actix_web::http::StatusCode;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum VaultError {
+    #[error("Error in parsing config : {0}")]
+    ConfigParsingError(String),
+    #[error("Application configuration error: {0}")]
+    ConfigurationError(config::ConfigError),
+    #[error("Error while initializing encryption management client")]
+    EncryptionClientError,
+    #[error("I/O: {0}")]
+    IoError(std::io::Error),
+}
+
+pub type VaultResult<T> = error_stack::Result<T, VaultError>;
+
+impl From<std::io::Error> for VaultError {
+    fn from(err: std::io::Error) -> Self {
+        Self::IoError(err)
+    }
+}
+
+impl From<config::ConfigError> for VaultError {
+    fn from(err: config::ConfigError) -> Self {
+        Self::ConfigurationError(err)
+    }
+}
+
+/// Errors raised while talking to the database of a tenant.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum StorageError {
+    #[error("Failed to obtain a database connection")]
+    DatabaseConnectionError,
+}
+
+/// Errors surfaced to the callers of the locker API.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq)]
+pub enum ApiError {
+    #[error("Tenant is not configured in the vault")]
+    TenantNotFound,
+    #[error("Failed to decrypt or verify the request payload")]
+    RequestDecryptionFailed,
+    #[error("Failed to parse the request payload")]
+    InvalidRequest,
+    #[error("Invalid admin API key")]
+    Unauthorized,
+    #[error("Card not found in the vault")]
+    CardNotFound,
+    #[error("Failed to encrypt or decrypt the stored data")]
+    DataEncryptionFailed,
+    #[error("No active data key found in the vault")]
+    DataKeyNotFound,
+    #[error("Failed while performing a database operation")]
+    DatabaseError,
+    #[error("Failed to encrypt or sign the response payload")]
+    ResponseEncryptionFailed,
+    #[error("No key rotation found in the vault")]
+    KeyRotationNotFound,
+}
+
+pub type ApiResult<T> = error_stack::Result<T, ApiError>;
+
+impl ApiError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            Self::TenantNotFound | Self::RequestDecryptionFailed | Self::InvalidRequest => {
+                StatusCode::BAD_REQUEST
+            }
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::CardNotFound | Self::KeyRotationNotFound => StatusCode::NOT_FOUND,
+            Self::DataEncryptionFailed
+            | Self::DataKeyNotFound
+            | Self::DatabaseError
+            | Self::ResponseEncryptionFailed => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    pub fn error_code(&self) -> &'static str {
+        match self {
+            Self::TenantNotFound => "VA_01",
+            Self::RequestDecryptionFailed => "VA_02",
+            Self::InvalidRequest => "VA_03",
+            Self::Unauthorized => "VA_04",
+            Self::CardNotFound => "VA_05",
+            Self::DataEncryptionFailed => "VA_06",
+            Self::DataKeyNotFound => "VA_07",
+            Self::DatabaseError => "VA_08",
+            Self::ResponseEncryptionFailed => "VA_09",
+            Self::KeyRotationNotFound => "VA_10",
+        }
+    }
+}
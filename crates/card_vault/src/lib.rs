@@ -0,0 +1,66 @@
+mod connection;
+mod core;
+pub mod crypto;
+pub mod errors;
+pub mod logger;
+mod models;
+mod routes;
+mod schema;
+pub mod settings;
+pub mod storage;
+pub mod types;
+
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{dev::Server, web};
+use common_utils::id_type;
+use error_stack::ResultExt;
+
+use crate::{crypto::DataKeyManager, settings::Settings, storage::Store};
+
+pub struct AppState {
+    pub conf: Arc<Settings>,
+    pub data_key_manager: DataKeyManager,
+    pub stores: HashMap<id_type::TenantId, Store>,
+}
+
+impl AppState {
+    pub async fn new(conf: Settings) -> errors::VaultResult<Self> {
+        let encryption_client = conf
+            .encryption_management
+            .get_encryption_management_client()
+            .await
+            .change_context(errors::VaultError::EncryptionClientError)?;
+
+        let mut stores = HashMap::new();
+        for (tenant_id, tenant) in &conf.tenants {
+            let master_pool =
+                connection::diesel_make_pg_pool(&conf.master_database, &tenant.schema).await;
+            stores.insert(tenant_id.clone(), Store { master_pool });
+        }
+
+        Ok(Self {
+            conf: Arc::new(conf),
+            data_key_manager: DataKeyManager::new(encryption_client),
+            stores,
+        })
+    }
+}
+
+pub async fn start_server(state: AppState) -> Result<Server, errors::VaultError> {
+    let server = state.conf.server.clone();
+    let state = web::Data::new(state);
+    core::resume_key_rotations(state.clone().into_inner()).await;
+    let web_server = actix_web::HttpServer::new(move || {
+        actix_web::App::new()
+            .wrap(router_env::tracing_actix_web::TracingLogger::default())
+            .service(routes::Health::server())
+            .service(routes::Cards::server(state.clone()))
+            .service(routes::Keys::server(state.clone()))
+    })
+    .workers(server.workers)
+    .bind((server.host.as_str(), server.port))?
+    .run();
+
+    Ok(web_server)
+}
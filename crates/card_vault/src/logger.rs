@@ -0,0 +1,2 @@
+#[doc(inline)]
+pub use router_env::{debug, error, info, warn};
@@ -0,0 +1,37 @@
+use card_vault::{
+    errors::{self, VaultResult},
+    logger, settings, start_server, AppState,
+};
+
+#[tokio::main]
+async fn main() -> VaultResult<()> {
+    // Get configuration
+    let cmd_line = <settings::CmdLineConf as clap::Parser>::parse();
+
+    #[allow(clippy::expect_used)]
+    let conf = settings::Settings::with_config_path(cmd_line.config_path)
+        .expect("Unable to construct application configuration");
+    #[allow(clippy::expect_used)]
+    conf.validate()
+        .expect("Failed to validate card vault configuration");
+
+    #[allow(clippy::print_stdout)] // The logger has not yet been initialized
+    #[cfg(feature = "vergen")]
+    {
+        println!("Starting card vault (Version: {})", router_env::git_tag!());
+    }
+
+    let _guard = router_env::setup(
+        &conf.log,
+        router_env::service_name!(),
+        [router_env::service_name!()],
+    );
+
+    logger::info!("Card vault started [{:?}]", conf.server);
+
+    let state = AppState::new(conf).await?;
+    let server = start_server(state).await?;
+    server.await.map_err(errors::VaultError::from)?;
+
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::{vault_access_logs, vault_cards, vault_data_keys, vault_key_rotations};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = vault_data_keys, primary_key(key_version), check_for_backend(diesel::pg::Pg))]
+pub struct DataKey {
+    pub key_version: i32,
+    pub wrapped_key: Vec<u8>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_data_keys)]
+pub struct DataKeyNew {
+    pub key_version: i32,
+    pub wrapped_key: Vec<u8>,
+    pub is_active: bool,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = vault_cards, primary_key(card_reference), check_for_backend(diesel::pg::Pg))]
+pub struct VaultCard {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub merchant_customer_id: String,
+    pub fingerprint: String,
+    pub key_version: i32,
+    pub encrypted_data: Vec<u8>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl VaultCard {
+    pub fn is_expired(&self, now: PrimitiveDateTime) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_cards)]
+pub struct VaultCardNew {
+    pub card_reference: String,
+    pub merchant_id: String,
+    pub merchant_customer_id: String,
+    pub fingerprint: String,
+    pub key_version: i32,
+    pub encrypted_data: Vec<u8>,
+    pub expires_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = vault_cards)]
+pub struct VaultCardUpdate {
+    pub key_version: i32,
+    pub encrypted_data: Vec<u8>,
+    pub expires_at: Option<Option<PrimitiveDateTime>>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// Progress of the re-encryption of the cards of a tenant with a data key.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = vault_key_rotations, primary_key(key_version), check_for_backend(diesel::pg::Pg))]
+pub struct KeyRotation {
+    pub key_version: i32,
+    pub status: String,
+    /// Reference of the last card processed, the rotation resumes after this card
+    pub last_card_reference: Option<String>,
+    pub re_encrypted_cards: i64,
+    pub failed_cards: i64,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_key_rotations)]
+pub struct KeyRotationNew {
+    pub key_version: i32,
+    pub status: String,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset)]
+#[diesel(table_name = vault_key_rotations)]
+pub struct KeyRotationUpdate {
+    pub status: Option<String>,
+    pub last_card_reference: Option<String>,
+    pub re_encrypted_cards: Option<i64>,
+    pub failed_cards: Option<i64>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_access_logs)]
+pub struct AccessLogNew {
+    pub request_id: Option<String>,
+    pub merchant_id: Option<String>,
+    pub card_reference: Option<String>,
+    pub action: String,
+    pub succeeded: bool,
+    pub error_code: Option<String>,
+    pub created_at: PrimitiveDateTime,
+}
@@ -0,0 +1,385 @@
+use std::future::Future;
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    core, crypto,
+    errors::{ApiError, ApiResult},
+    models::AccessLogNew,
+    types::{
+        CardReqBody, JweBody, RetrieveCardResp, StatusResp, StoreCardResp, StoreLockerReq,
+        VaultAction, STATUS_ERROR, STATUS_OK,
+    },
+    AppState,
+};
+
+const X_TENANT_ID: &str = "x-tenant-id";
+const X_REQUEST_ID: &str = "x-request-id";
+const X_ADMIN_API_KEY: &str = "x-admin-api-key";
+
+pub struct Cards;
+
+impl Cards {
+    pub fn server(state: web::Data<AppState>) -> Scope {
+        web::scope("/cards")
+            .app_data(state)
+            .service(web::resource("/add").route(web::post().to(add_card)))
+            .service(web::resource("/retrieve").route(web::post().to(retrieve_card)))
+            .service(web::resource("/delete").route(web::post().to(delete_card)))
+    }
+}
+
+pub struct Keys;
+
+impl Keys {
+    pub fn server(state: web::Data<AppState>) -> Scope {
+        web::scope("/keys").app_data(state).service(
+            web::resource("/rotate")
+                .route(web::post().to(rotate_data_key))
+                .route(web::get().to(retrieve_key_rotation)),
+        )
+    }
+}
+
+pub struct Health;
+
+impl Health {
+    pub fn server() -> Scope {
+        web::scope("/health").service(web::resource("").route(web::get().to(health)))
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn health() -> impl actix_web::Responder {
+    logger::info!("Card vault health was called");
+    HttpResponse::Ok().body("Card vault health is good")
+}
+
+/// Details of a request that are recorded in the access log.
+trait AccessLogDetails {
+    fn get_merchant_id(&self) -> &id_type::MerchantId;
+    fn get_card_reference(&self) -> Option<&str>;
+}
+
+impl AccessLogDetails for StoreLockerReq {
+    fn get_merchant_id(&self) -> &id_type::MerchantId {
+        StoreLockerReq::get_merchant_id(self)
+    }
+
+    fn get_card_reference(&self) -> Option<&str> {
+        match self {
+            Self::LockerCard(req) => req.requestor_card_reference.as_deref(),
+            Self::LockerGeneric(_) => None,
+        }
+    }
+}
+
+impl AccessLogDetails for CardReqBody {
+    fn get_merchant_id(&self) -> &id_type::MerchantId {
+        &self.merchant_id
+    }
+
+    fn get_card_reference(&self) -> Option<&str> {
+        Some(&self.card_reference)
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn add_card(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<JweBody>,
+) -> HttpResponse {
+    handle_locker_request(
+        state,
+        http_req,
+        json_payload.into_inner(),
+        VaultAction::AddCard,
+        |state, tenant_id, req: StoreLockerReq| async move {
+            let payload = core::add_card(&state, &tenant_id, req).await?;
+            let card_reference = payload.card_reference.clone();
+            Ok((
+                StoreCardResp {
+                    status: STATUS_OK.to_string(),
+                    error_message: None,
+                    error_code: None,
+                    payload: Some(payload),
+                },
+                Some(card_reference),
+            ))
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<JweBody>,
+) -> HttpResponse {
+    handle_locker_request(
+        state,
+        http_req,
+        json_payload.into_inner(),
+        VaultAction::RetrieveCard,
+        |state, tenant_id, req: CardReqBody| async move {
+            let payload = core::retrieve_card(&state, &tenant_id, req).await?;
+            Ok((
+                RetrieveCardResp {
+                    status: STATUS_OK.to_string(),
+                    error_message: None,
+                    error_code: None,
+                    payload: Some(payload),
+                },
+                None,
+            ))
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn delete_card(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<JweBody>,
+) -> HttpResponse {
+    handle_locker_request(
+        state,
+        http_req,
+        json_payload.into_inner(),
+        VaultAction::DeleteCard,
+        |state, tenant_id, req: CardReqBody| async move {
+            core::delete_card(&state, &tenant_id, req).await?;
+            Ok((
+                StatusResp {
+                    status: STATUS_OK.to_string(),
+                    error_message: None,
+                    error_code: None,
+                },
+                None,
+            ))
+        },
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn rotate_data_key(state: web::Data<AppState>, http_req: HttpRequest) -> HttpResponse {
+    let request_id = get_header(&http_req, X_REQUEST_ID);
+
+    let result = async {
+        let tenant_id = authenticate_admin_request(&state, &http_req)?;
+
+        let response = core::rotate_data_key(state.clone().into_inner(), &tenant_id).await;
+        record_access(
+            &state,
+            &tenant_id,
+            VaultAction::RotateKey,
+            request_id.clone(),
+            None,
+            None,
+            response
+                .as_ref()
+                .err()
+                .map(|error| *error.current_context()),
+        )
+        .await
+        .map_err(|error| logger::error!(access_log_error=?error))
+        .ok();
+        response
+    }
+    .await;
+
+    match result {
+        // The cards are re-encrypted in the background
+        Ok(response) => HttpResponse::Accepted().json(response),
+        Err(error) => plain_error_response(error),
+    }
+}
+
+/// Returns the progress of the latest data key rotation of the tenant.
+#[instrument(skip_all)]
+pub async fn retrieve_key_rotation(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+) -> HttpResponse {
+    let result = async {
+        let tenant_id = authenticate_admin_request(&state, &http_req)?;
+        core::retrieve_key_rotation(&state, &tenant_id).await
+    }
+    .await;
+
+    match result {
+        Ok(response) => HttpResponse::Ok().json(response),
+        Err(error) => plain_error_response(error),
+    }
+}
+
+fn authenticate_admin_request(
+    state: &AppState,
+    http_req: &HttpRequest,
+) -> ApiResult<id_type::TenantId> {
+    let tenant_id = get_tenant_id(http_req)?;
+    let api_key = get_header(http_req, X_ADMIN_API_KEY).ok_or(ApiError::Unauthorized)?;
+    common_utils::fp_utils::when(!core::is_valid_admin_api_key(state, &api_key), || {
+        Err(report!(ApiError::Unauthorized))
+    })?;
+    Ok(tenant_id)
+}
+
+/// Decrypts the request, invokes the given handler and returns its response signed and encrypted
+/// for the tenant. Errors are returned in the same encrypted format, so that the router can read
+/// the error message and code.
+async fn handle_locker_request<Req, Res, F, Fut>(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    jwe_body: JweBody,
+    action: VaultAction,
+    handler: F,
+) -> HttpResponse
+where
+    Req: DeserializeOwned + AccessLogDetails,
+    Res: Serialize,
+    F: FnOnce(web::Data<AppState>, id_type::TenantId, Req) -> Fut,
+    Fut: Future<Output = ApiResult<(Res, Option<String>)>>,
+{
+    let request_id = get_header(&http_req, X_REQUEST_ID);
+    let tenant_id = match get_tenant_id(&http_req) {
+        Ok(tenant_id) => tenant_id,
+        Err(error) => return plain_error_response(error),
+    };
+    let Some(tenant) = state.conf.tenants.get(&tenant_id) else {
+        return plain_error_response(report!(ApiError::TenantNotFound));
+    };
+
+    let request = crypto::decrypt_request(jwe_body, &state.conf.vault, &tenant.public_key)
+        .and_then(|payload| -> ApiResult<Req> {
+            payload
+                .parse_struct(std::any::type_name::<Req>())
+                .change_context(ApiError::InvalidRequest)
+        });
+
+    let (merchant_id, request_card_reference, result) = match request {
+        Ok(req) => {
+            let merchant_id = req.get_merchant_id().get_string_repr().to_owned();
+            let card_reference = req.get_card_reference().map(ToOwned::to_owned);
+            let result = handler(state.clone(), tenant_id.clone(), req).await;
+            (Some(merchant_id), card_reference, result)
+        }
+        Err(error) => (None, None, Err(error)),
+    };
+
+    let (outcome, card_reference, response) = match result {
+        Ok((response, response_card_reference)) => (
+            None,
+            response_card_reference.or(request_card_reference),
+            response
+                .encode_to_vec()
+                .change_context(ApiError::ResponseEncryptionFailed)
+                .map(|payload| (actix_web::http::StatusCode::OK, payload)),
+        ),
+        Err(error) => {
+            logger::error!(?error);
+            let api_error = *error.current_context();
+            (
+                Some(api_error),
+                request_card_reference,
+                StatusResp {
+                    status: STATUS_ERROR.to_string(),
+                    error_message: Some(api_error.to_string()),
+                    error_code: Some(api_error.error_code().to_string()),
+                }
+                .encode_to_vec()
+                .change_context(ApiError::ResponseEncryptionFailed)
+                .map(|payload| (api_error.status_code(), payload)),
+            )
+        }
+    };
+
+    record_access(
+        &state,
+        &tenant_id,
+        action,
+        request_id,
+        merchant_id,
+        card_reference,
+        outcome,
+    )
+    .await
+    .map_err(|error| logger::error!(access_log_error=?error))
+    .ok();
+
+    match response.and_then(|(status_code, payload)| {
+        crypto::encrypt_response(&payload, &state.conf.vault, &tenant.public_key)
+            .map(|jwe_body| (status_code, jwe_body))
+    }) {
+        Ok((status_code, jwe_body)) => HttpResponse::build(status_code).json(jwe_body),
+        Err(error) => plain_error_response(error),
+    }
+}
+
+fn plain_error_response(error: error_stack::Report<ApiError>) -> HttpResponse {
+    logger::error!(?error);
+    let api_error = error.current_context();
+    HttpResponse::build(api_error.status_code()).json(StatusResp {
+        status: STATUS_ERROR.to_string(),
+        error_message: Some(api_error.to_string()),
+        error_code: Some(api_error.error_code().to_string()),
+    })
+}
+
+fn get_header(http_req: &HttpRequest, header_name: &str) -> Option<String> {
+    http_req
+        .headers()
+        .get(header_name)
+        .and_then(|value| value.to_str().ok())
+        .map(ToOwned::to_owned)
+}
+
+fn get_tenant_id(http_req: &HttpRequest) -> ApiResult<id_type::TenantId> {
+    get_header(http_req, X_TENANT_ID)
+        .ok_or(report!(ApiError::TenantNotFound))
+        .attach_printable("Missing tenant ID header")
+        .and_then(|tenant_id| {
+            id_type::TenantId::try_from_string(tenant_id)
+                .change_context(ApiError::TenantNotFound)
+                .attach_printable("Invalid tenant ID header")
+        })
+}
+
+/// Records the outcome of a vault operation in the access log of the tenant.
+async fn record_access(
+    state: &AppState,
+    tenant_id: &id_type::TenantId,
+    action: VaultAction,
+    request_id: Option<String>,
+    merchant_id: Option<String>,
+    card_reference: Option<String>,
+    error: Option<ApiError>,
+) -> ApiResult<()> {
+    let store = state
+        .stores
+        .get(tenant_id)
+        .ok_or(ApiError::TenantNotFound)?;
+
+    store
+        .insert_access_log(AccessLogNew {
+            request_id,
+            merchant_id,
+            card_reference,
+            action: action.to_string(),
+            succeeded: error.is_none(),
+            error_code: error.map(|error| error.error_code().to_string()),
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+}
@@ -0,0 +1,275 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use common_utils::{ext_traits::ConfigExt, id_type, DbConnectionParams};
+use config::{Environment, File};
+use external_services::managers::encryption_management::EncryptionManagementConfig;
+use masking::{PeekInterface, Secret};
+pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
+use router_env::{env, logger};
+use serde::Deserialize;
+
+use crate::errors;
+
+#[derive(clap::Parser, Default)]
+#[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
+pub struct CmdLineConf {
+    /// Config file.
+    /// Application will look for "config/config.toml" if this option isn't specified.
+    #[arg(short = 'f', long, value_name = "FILE")]
+    pub config_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Settings {
+    pub server: Server,
+    pub master_database: Database,
+    pub log: Log,
+    pub vault: VaultSettings,
+    pub encryption_management: EncryptionManagementConfig,
+    pub tenants: HashMap<id_type::TenantId, Tenant>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Server {
+    pub port: u16,
+    pub workers: usize,
+    pub host: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Database {
+    pub username: String,
+    pub password: Secret<String>,
+    pub host: String,
+    pub port: u16,
+    pub dbname: String,
+    pub pool_size: u32,
+    pub connection_timeout: u64,
+}
+
+impl DbConnectionParams for Database {
+    fn get_username(&self) -> &str {
+        &self.username
+    }
+    fn get_password(&self) -> Secret<String> {
+        self.password.clone()
+    }
+    fn get_host(&self) -> &str {
+        &self.host
+    }
+    fn get_port(&self) -> u16 {
+        self.port
+    }
+    fn get_dbname(&self) -> &str {
+        &self.dbname
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct VaultSettings {
+    /// Private key of the vault, used to decrypt requests and to sign responses
+    pub private_key: Secret<String>,
+    /// Key identifier sent in the JWE header of responses
+    pub key_id: Option<String>,
+    /// Algorithm used to encrypt the content encryption key of responses, must match the
+    /// `locker.decryption_scheme` configured in the router
+    pub response_encryption_scheme: KeyEncryptionScheme,
+    /// Secret used to compute the keyed hash used for duplicate detection
+    pub hash_key: Secret<String>,
+    /// API key required by the key rotation endpoint
+    pub admin_api_key: Secret<String>,
+    /// Number of cards re-encrypted in a single database round trip during key rotation
+    pub rotation_batch_size: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub enum KeyEncryptionScheme {
+    #[default]
+    #[serde(rename = "RSA-OAEP")]
+    RsaOaep,
+    #[serde(rename = "RSA-OAEP-256")]
+    RsaOaep256,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct Tenant {
+    /// Database schema holding the vault tables of the tenant
+    pub schema: String,
+    /// Public key of the tenant, used to verify requests and to encrypt responses
+    pub public_key: Secret<String>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            workers: 1,
+        }
+    }
+}
+
+impl Default for Database {
+    fn default() -> Self {
+        Self {
+            username: String::new(),
+            password: String::new().into(),
+            host: "localhost".into(),
+            port: 5432,
+            dbname: String::new(),
+            pool_size: 5,
+            connection_timeout: 10,
+        }
+    }
+}
+
+impl Default for VaultSettings {
+    fn default() -> Self {
+        Self {
+            private_key: String::new().into(),
+            key_id: None,
+            response_encryption_scheme: KeyEncryptionScheme::default(),
+            hash_key: String::new().into(),
+            admin_api_key: String::new().into(),
+            rotation_batch_size: 100,
+        }
+    }
+}
+
+impl Server {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "server host must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl Database {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        use common_utils::fp_utils::when;
+
+        when(self.host.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database host must not be empty".into(),
+            ))
+        })?;
+
+        when(self.dbname.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database name must not be empty".into(),
+            ))
+        })?;
+
+        when(self.username.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database user username must not be empty".into(),
+            ))
+        })?;
+
+        when(self.password.is_default_or_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "database user password must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl VaultSettings {
+    fn validate(&self) -> Result<(), errors::VaultError> {
+        use common_utils::fp_utils::when;
+
+        when(self.private_key.peek().is_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "vault private key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.hash_key.peek().is_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "vault hash key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.admin_api_key.peek().is_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "vault admin API key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.rotation_batch_size <= 0, || {
+            Err(errors::VaultError::ConfigParsingError(
+                "vault rotation batch size must be positive".into(),
+            ))
+        })
+    }
+}
+
+impl Settings {
+    pub fn new() -> Result<Self, errors::VaultError> {
+        Self::with_config_path(None)
+    }
+
+    pub fn with_config_path(config_path: Option<PathBuf>) -> Result<Self, errors::VaultError> {
+        // Configuration values are picked up in the following priority order (1 being least
+        // priority):
+        // 1. Defaults from the implementation of the `Default` trait.
+        // 2. Values from config file. The config file accessed depends on the environment
+        //    specified by the `RUN_ENV` environment variable. `RUN_ENV` can be one of
+        //    `development`, `sandbox` or `production`. If nothing is specified for `RUN_ENV`,
+        //    `/config/development.toml` file is read.
+        // 3. Environment variables prefixed with `CARD_VAULT` and each level separated by double
+        //    underscores.
+        //
+        // Values in config file override the defaults in `Default` trait, and the values set using
+        // environment variables override both the defaults and the config file values.
+
+        let environment = env::which();
+        let config_path = router_env::Config::config_path(&environment.to_string(), config_path);
+
+        let config = router_env::Config::builder(&environment.to_string())?
+            .add_source(File::from(config_path).required(false))
+            .add_source(
+                Environment::with_prefix("CARD_VAULT")
+                    .try_parsing(true)
+                    .separator("__"),
+            )
+            .build()?;
+
+        // The logger may not yet be initialized when constructing the application configuration
+        #[allow(clippy::print_stderr)]
+        serde_path_to_error::deserialize(config).map_err(|error| {
+            logger::error!(%error, "Unable to deserialize application configuration");
+            eprintln!("Unable to deserialize application configuration: {error}");
+            errors::VaultError::from(error.into_inner())
+        })
+    }
+
+    pub fn validate(&self) -> Result<(), errors::VaultError> {
+        self.server.validate()?;
+        self.master_database.validate()?;
+        self.vault.validate()?;
+
+        common_utils::fp_utils::when(self.tenants.is_empty(), || {
+            Err(errors::VaultError::ConfigParsingError(
+                "at least one tenant must be configured".into(),
+            ))
+        })?;
+
+        // The logger may not yet be initialized when validating the application configuration
+        #[allow(clippy::print_stderr)]
+        self.encryption_management.validate().map_err(|error| {
+            eprintln!("{error}");
+            errors::VaultError::ConfigParsingError(
+                "invalid encryption management configuration".into(),
+            )
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,334 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, OptionalExtension, QueryDsl, SelectableHelper,
+};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    connection::{pg_connection, PgPool},
+    errors::{ApiError, ApiResult},
+    models::{
+        AccessLogNew, DataKey, DataKeyNew, KeyRotation, KeyRotationNew, KeyRotationUpdate,
+        VaultCard, VaultCardNew, VaultCardUpdate,
+    },
+    schema::{vault_access_logs, vault_cards, vault_data_keys, vault_key_rotations},
+    types::KeyRotationStatus,
+};
+
+/// Database store of a single tenant.
+#[derive(Clone)]
+pub struct Store {
+    pub master_pool: PgPool,
+}
+
+impl Store {
+    pub async fn find_active_data_key(&self) -> ApiResult<Option<DataKey>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_data_keys::table
+            .filter(vault_data_keys::is_active.eq(true))
+            .select(DataKey::as_select())
+            .first_async(&*conn)
+            .await
+            .optional()
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while fetching the active data key")
+    }
+
+    pub async fn find_data_key(&self, key_version: i32) -> ApiResult<DataKey> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_data_keys::table
+            .find(key_version)
+            .select(DataKey::as_select())
+            .first_async(&*conn)
+            .await
+            .change_context(ApiError::DataKeyNotFound)
+            .attach_printable_lazy(|| format!("Failed while fetching data key {key_version}"))
+    }
+
+    /// Inserts a new data key with the next available version and marks it as the only active key.
+    pub async fn insert_active_data_key(
+        &self,
+        wrapped_key: Vec<u8>,
+        created_at: PrimitiveDateTime,
+    ) -> ApiResult<DataKey> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        conn.transaction_async(|conn| async move {
+            // Concurrent rotations would otherwise compute the same next version
+            diesel::sql_query("LOCK TABLE vault_data_keys IN EXCLUSIVE MODE")
+                .execute_async(&conn)
+                .await?;
+
+            let latest_version: Option<i32> = vault_data_keys::table
+                .select(diesel::dsl::max(vault_data_keys::key_version))
+                .first_async(&conn)
+                .await?;
+
+            diesel::update(vault_data_keys::table)
+                .set(vault_data_keys::is_active.eq(false))
+                .execute_async(&conn)
+                .await?;
+
+            diesel::insert_into(vault_data_keys::table)
+                .values(DataKeyNew {
+                    key_version: latest_version.unwrap_or_default() + 1,
+                    wrapped_key,
+                    is_active: true,
+                    created_at,
+                })
+                .returning(DataKey::as_returning())
+                .get_result_async(&conn)
+                .await
+        })
+        .await
+        .change_context(ApiError::DatabaseError)
+        .attach_printable("Failed while inserting a new data key")
+    }
+
+    /// Inserts the card, unless the customer already has a card with the same fingerprint, in
+    /// which case `None` is returned.
+    pub async fn insert_card_if_not_exists(
+        &self,
+        card: VaultCardNew,
+    ) -> ApiResult<Option<VaultCard>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::insert_into(vault_cards::table)
+            .values(card)
+            .on_conflict((
+                vault_cards::merchant_id,
+                vault_cards::merchant_customer_id,
+                vault_cards::fingerprint,
+            ))
+            .do_nothing()
+            .returning(VaultCard::as_returning())
+            .get_result_async(&*conn)
+            .await
+            .optional()
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while inserting card into the vault")
+    }
+
+    pub async fn find_card_by_fingerprint(
+        &self,
+        merchant_id: &str,
+        merchant_customer_id: &str,
+        fingerprint: &str,
+    ) -> ApiResult<Option<VaultCard>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_cards::table
+            .filter(
+                vault_cards::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(vault_cards::merchant_customer_id.eq(merchant_customer_id.to_owned()))
+                    .and(vault_cards::fingerprint.eq(fingerprint.to_owned())),
+            )
+            .select(VaultCard::as_select())
+            .first_async(&*conn)
+            .await
+            .optional()
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while fetching card by fingerprint")
+    }
+
+    pub async fn find_card(
+        &self,
+        merchant_id: &str,
+        merchant_customer_id: &str,
+        card_reference: &str,
+    ) -> ApiResult<VaultCard> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_cards::table
+            .filter(
+                vault_cards::card_reference
+                    .eq(card_reference.to_owned())
+                    .and(vault_cards::merchant_id.eq(merchant_id.to_owned()))
+                    .and(vault_cards::merchant_customer_id.eq(merchant_customer_id.to_owned())),
+            )
+            .select(VaultCard::as_select())
+            .first_async(&*conn)
+            .await
+            .optional()
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while fetching card from the vault")?
+            .ok_or(ApiError::CardNotFound.into())
+    }
+
+    pub async fn update_card(
+        &self,
+        card_reference: &str,
+        card_update: VaultCardUpdate,
+    ) -> ApiResult<VaultCard> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::update(vault_cards::table.find(card_reference.to_owned()))
+            .set(card_update)
+            .returning(VaultCard::as_returning())
+            .get_result_async(&*conn)
+            .await
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while updating card in the vault")
+    }
+
+    /// Updates the card only if it still has the given data key version and modification time,
+    /// returns `None` if the card was modified or deleted in the meantime.
+    pub async fn update_card_if_unmodified(
+        &self,
+        card_reference: &str,
+        key_version: i32,
+        modified_at: PrimitiveDateTime,
+        card_update: VaultCardUpdate,
+    ) -> ApiResult<Option<VaultCard>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::update(
+            vault_cards::table
+                .find(card_reference.to_owned())
+                .filter(vault_cards::key_version.eq(key_version))
+                .filter(vault_cards::modified_at.eq(modified_at)),
+        )
+        .set(card_update)
+        .returning(VaultCard::as_returning())
+        .get_result_async(&*conn)
+        .await
+        .optional()
+        .change_context(ApiError::DatabaseError)
+        .attach_printable("Failed while updating card in the vault")
+    }
+
+    pub async fn delete_card(
+        &self,
+        merchant_id: &str,
+        merchant_customer_id: &str,
+        card_reference: &str,
+    ) -> ApiResult<bool> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::delete(
+            vault_cards::table.filter(
+                vault_cards::card_reference
+                    .eq(card_reference.to_owned())
+                    .and(vault_cards::merchant_id.eq(merchant_id.to_owned()))
+                    .and(vault_cards::merchant_customer_id.eq(merchant_customer_id.to_owned())),
+            ),
+        )
+        .execute_async(&*conn)
+        .await
+        .map(|deleted_rows| deleted_rows > 0)
+        .change_context(ApiError::DatabaseError)
+        .attach_printable("Failed while deleting card from the vault")
+    }
+
+    /// Lists cards that are encrypted with a data key other than the given version, in the order
+    /// of their references, starting after the given card reference.
+    pub async fn list_cards_not_encrypted_with(
+        &self,
+        key_version: i32,
+        starting_after: Option<&str>,
+        limit: i64,
+    ) -> ApiResult<Vec<VaultCard>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_cards::table
+            .filter(
+                vault_cards::key_version.ne(key_version).and(
+                    vault_cards::card_reference.gt(starting_after.unwrap_or_default().to_owned()),
+                ),
+            )
+            .order(vault_cards::card_reference.asc())
+            .limit(limit)
+            .select(VaultCard::as_select())
+            .get_results_async(&*conn)
+            .await
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while listing cards for re-encryption")
+    }
+
+    pub async fn insert_key_rotation(
+        &self,
+        key_rotation: KeyRotationNew,
+    ) -> ApiResult<KeyRotation> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::insert_into(vault_key_rotations::table)
+            .values(key_rotation)
+            .returning(KeyRotation::as_returning())
+            .get_result_async(&*conn)
+            .await
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while inserting key rotation")
+    }
+
+    pub async fn find_latest_key_rotation(&self) -> ApiResult<Option<KeyRotation>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_key_rotations::table
+            .order(vault_key_rotations::key_version.desc())
+            .select(KeyRotation::as_select())
+            .first_async(&*conn)
+            .await
+            .optional()
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while fetching the latest key rotation")
+    }
+
+    pub async fn list_key_rotations_in_progress(&self) -> ApiResult<Vec<KeyRotation>> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        vault_key_rotations::table
+            .filter(vault_key_rotations::status.eq(KeyRotationStatus::InProgress.to_string()))
+            .select(KeyRotation::as_select())
+            .get_results_async(&*conn)
+            .await
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while listing key rotations in progress")
+    }
+
+    pub async fn update_key_rotation(
+        &self,
+        key_version: i32,
+        key_rotation_update: KeyRotationUpdate,
+    ) -> ApiResult<KeyRotation> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::update(vault_key_rotations::table.find(key_version))
+            .set(key_rotation_update)
+            .returning(KeyRotation::as_returning())
+            .get_result_async(&*conn)
+            .await
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while updating key rotation")
+    }
+
+    pub async fn insert_access_log(&self, access_log: AccessLogNew) -> ApiResult<()> {
+        let conn = pg_connection(&self.master_pool)
+            .await
+            .change_context(ApiError::DatabaseError)?;
+        diesel::insert_into(vault_access_logs::table)
+            .values(access_log)
+            .execute_async(&*conn)
+            .await
+            .map(|_| ())
+            .change_context(ApiError::DatabaseError)
+            .attach_printable("Failed while inserting access log")
+    }
+}
@@ -0,0 +1,151 @@
+//! Request and response types of the locker API.
+//!
+//! These mirror the types used by the router while talking to the locker, so that the wire format
+//! stays identical.
+
+use api_models::payment_methods::Card;
+use common_utils::id_type;
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+
+pub const STATUS_OK: &str = "Ok";
+pub const STATUS_ERROR: &str = "Error";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JweBody {
+    pub header: String,
+    pub iv: String,
+    pub encrypted_payload: String,
+    pub tag: String,
+    pub encrypted_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JwsBody {
+    pub header: String,
+    pub payload: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum StoreLockerReq {
+    LockerCard(StoreCardReq),
+    LockerGeneric(StoreGenericReq),
+}
+
+impl StoreLockerReq {
+    pub fn get_merchant_id(&self) -> &id_type::MerchantId {
+        match self {
+            Self::LockerCard(req) => &req.merchant_id,
+            Self::LockerGeneric(req) => &req.merchant_id,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreCardReq {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_customer_id: id_type::CustomerId,
+    pub requestor_card_reference: Option<String>,
+    pub card: Card,
+    pub ttl: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StoreGenericReq {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_customer_id: id_type::CustomerId,
+    #[serde(rename = "enc_card_data")]
+    pub enc_data: Secret<String>,
+    pub ttl: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CardReqBody {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_customer_id: id_type::CustomerId,
+    pub card_reference: String,
+}
+
+/// Data persisted in the vault, in its encrypted form.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum StoredData {
+    Card { card: Card },
+    Generic { enc_card_data: Secret<String> },
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum DataDuplicationCheck {
+    Duplicated,
+    MetaDataChanged,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StoreCardResp {
+    pub status: String,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub payload: Option<StoreCardRespPayload>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StoreCardRespPayload {
+    pub card_reference: String,
+    pub duplication_check: Option<DataDuplicationCheck>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetrieveCardResp {
+    pub status: String,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+    pub payload: Option<RetrieveCardRespPayload>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetrieveCardRespPayload {
+    pub card: Option<Card>,
+    pub enc_card_data: Option<Secret<String>>,
+}
+
+/// Response used both for successful deletions and for errors of every endpoint.
+#[derive(Debug, Serialize)]
+pub struct StatusResp {
+    pub status: String,
+    pub error_message: Option<String>,
+    pub error_code: Option<String>,
+}
+
+/// Status of the re-encryption of the cards of a tenant with a new data key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, strum::Display, strum::EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum KeyRotationStatus {
+    InProgress,
+    Completed,
+    /// A newer data key was created before the rotation completed, the rotation to the newer key
+    /// re-encrypts the remaining cards
+    Superseded,
+}
+
+#[derive(Debug, Serialize)]
+pub struct KeyRotationResponse {
+    pub key_version: i32,
+    pub status: KeyRotationStatus,
+    pub re_encrypted_cards: i64,
+    pub failed_cards: i64,
+}
+
+/// Actions recorded in the access log.
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultAction {
+    AddCard,
+    RetrieveCard,
+    DeleteCard,
+    RotateKey,
+}
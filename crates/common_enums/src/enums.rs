@@ -1424,6 +1424,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
 }
 
 impl EventClass {
@@ -1439,7 +1440,13 @@ impl EventClass {
                 EventType::PaymentCaptured,
                 EventType::ActionRequired,
             ]),
-            Self::Refunds => HashSet::from([EventType::RefundSucceeded, EventType::RefundFailed]),
+            Self::Refunds => HashSet::from([
+                EventType::RefundSucceeded,
+                EventType::RefundFailed,
+                EventType::RefundPendingApproval,
+                EventType::RefundApproved,
+                EventType::RefundRejected,
+            ]),
             Self::Disputes => HashSet::from([
                 EventType::DisputeOpened,
                 EventType::DisputeExpired,
@@ -1459,6 +1466,17 @@ impl EventClass {
                 EventType::PayoutCancelled,
                 EventType::PayoutExpired,
                 EventType::PayoutReversed,
+                EventType::PayoutPendingApproval,
+                EventType::PayoutApproved,
+                EventType::PayoutRejected,
+            ]),
+            Self::PaymentMethods => HashSet::from([
+                EventType::PaymentMethodUpdated,
+                EventType::PaymentMethodExpiring,
+                EventType::PaymentMethodExpired,
+                EventType::PaymentMethodNetworkTokenSuspended,
+                EventType::PaymentMethodNetworkTokenResumed,
+                EventType::PaymentMethodNetworkTokenDeleted,
             ]),
         }
     }
@@ -1493,6 +1511,9 @@ pub enum EventType {
     ActionRequired,
     RefundSucceeded,
     RefundFailed,
+    RefundPendingApproval,
+    RefundApproved,
+    RefundRejected,
     DisputeOpened,
     DisputeExpired,
     DisputeAccepted,
@@ -1516,6 +1537,25 @@ pub enum EventType {
     PayoutExpired,
     #[cfg(feature = "payouts")]
     PayoutReversed,
+    #[cfg(feature = "payouts")]
+    PayoutPendingApproval,
+    #[cfg(feature = "payouts")]
+    PayoutApproved,
+    #[cfg(feature = "payouts")]
+    PayoutRejected,
+    /// Saved card details or network token were refreshed by the card updater or the network
+    /// token service
+    PaymentMethodUpdated,
+    /// Saved card is nearing expiry and could not be updated automatically
+    PaymentMethodExpiring,
+    /// Saved card has expired and was marked inactive
+    PaymentMethodExpired,
+    /// Network token of the saved card was suspended by the token service
+    PaymentMethodNetworkTokenSuspended,
+    /// Network token of the saved card was resumed by the token service
+    PaymentMethodNetworkTokenResumed,
+    /// Network token of the saved card was deleted by the token service
+    PaymentMethodNetworkTokenDeleted,
 }
 
 #[derive(
@@ -1768,6 +1808,33 @@ impl From<AttemptStatus> for PaymentMethodStatus {
     }
 }
 
+/// Status of the network token stored for a payment method, as reported by the token service
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenStatus {
+    /// Indicates that the network token can be used for payments.
+    Active,
+    /// Indicates that the network token has been suspended by the token service and cannot be
+    /// used for payments until it is resumed.
+    Suspended,
+    /// Indicates that the network token has been deleted by the token service.
+    Deleted,
+}
+
 /// To indicate the type of payment experience that the customer would go through
 #[derive(
     Eq,
@@ -2200,6 +2267,8 @@ pub enum RefundStatus {
     Success,
     #[serde(alias = "TransactionFailure")]
     TransactionFailure,
+    /// Refund is held until it is approved or rejected by an approver
+    PendingApproval,
 }
 
 #[derive(
@@ -6969,6 +7038,8 @@ pub enum PayoutStatus {
     RequiresPayoutMethodData,
     RequiresFulfillment,
     RequiresVendorAccountCreation,
+    /// Payout is held until it is approved or rejected by an approver
+    PendingApproval,
 }
 
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
@@ -7486,6 +7557,7 @@ pub enum PermissionGroup {
     ReconOpsView,
     ReconOpsManage,
     InternalManage,
+    ApprovalsManage,
 }
 
 #[derive(Clone, Debug, serde::Serialize, PartialEq, Eq, Hash, strum::EnumIter)]
@@ -7499,9 +7571,10 @@ pub enum ParentGroup {
     ReconReports,
     Account,
     Internal,
+    Approvals,
 }
 
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Resource {
     Payment,
@@ -7529,6 +7602,7 @@ pub enum Resource {
     ReconConfig,
     RevenueRecovery,
     InternalConnector,
+    TransactionApproval,
 }
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, serde::Serialize, Hash)]
@@ -7538,6 +7612,78 @@ pub enum PermissionScope {
     Write = 1,
 }
 
+/// Action that a custom role can be granted on a resource
+#[derive(
+    Debug, Clone, Copy, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PermissionAction {
+    View,
+    Create,
+    Update,
+    Delete,
+    Accept,
+    SubmitEvidence,
+}
+
+/// Status of a change request raised for a configuration which requires approval
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ChangeRequestStatus {
+    /// Awaiting a decision from an approver
+    Pending,
+    /// Approved by an approver, the change is being applied to the configuration
+    Approving,
+    /// Approved and applied to the configuration
+    Approved,
+    /// Rejected by an approver
+    Rejected,
+    /// Not decided upon before the expiry
+    Expired,
+    /// Approved, but the change could not be applied
+    Failed,
+}
+
+/// Configuration which a change request modifies
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ChangeRequestEntity {
+    Routing,
+    MerchantConnectorAccount,
+    SurchargeDecisionConfig,
+    ThreeDsDecisionRule,
+    Blocklist,
+}
+
 /// Name of banks supported by Hyperswitch
 #[derive(
     Clone,
@@ -7805,6 +7951,7 @@ pub enum UserAuthType {
     MagicLink,
     #[default]
     Password,
+    Passkey,
 }
 
 #[derive(
@@ -8028,7 +8175,9 @@ impl From<RefundStatus> for RelayStatus {
     fn from(refund_status: RefundStatus) -> Self {
         match refund_status {
             RefundStatus::Failure | RefundStatus::TransactionFailure => Self::Failure,
-            RefundStatus::ManualReview | RefundStatus::Pending => Self::Pending,
+            RefundStatus::ManualReview | RefundStatus::Pending | RefundStatus::PendingApproval => {
+                Self::Pending
+            }
             RefundStatus::Success => Self::Success,
         }
     }
@@ -8143,6 +8292,7 @@ pub enum ConnectorTokenStatus {
     ToSchema,
     PartialOrd,
     Ord,
+    Hash,
 )]
 #[router_derive::diesel_enum(storage_type = "text")]
 #[serde(rename_all = "snake_case")]
@@ -8434,6 +8584,37 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    AnalyticsAlertWorkflow,
+    AnalyticsReportWorkflow,
+    CardUpdaterWorkflow,
+    VaultMigrationWorkflow,
+    DataRetentionWorkflow,
+    CustomerDataExportWorkflow,
+}
+
+/// State of an analytics alert rule as of its latest evaluation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AnalyticsAlertState {
+    /// The alert condition is not met
+    #[default]
+    Ok,
+    /// The alert condition is met and a notification has been sent
+    Firing,
 }
 
 #[derive(Debug)]
@@ -8476,3 +8657,31 @@ pub enum TokenDataType {
     /// Fetch network token for the given payment method
     NetworkToken,
 }
+
+/// The outcome of migrating a single payment method between vaults
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultMigrationEntryStatus {
+    /// The card was copied to the destination vault and its fingerprint matched the source
+    Migrated,
+    /// The card was copied to the destination vault, but its fingerprint did not match the source
+    VerificationFailed,
+    /// The card could not be read from the source vault or written to the destination vault
+    Failed,
+    /// The copy of the card in the destination vault was removed by a rollback
+    RolledBack,
+}
@@ -2,9 +2,11 @@
 
 use std::collections::HashMap;
 
+use chrono::{Datelike, Timelike};
 use common_enums::enums;
 use common_utils::{impl_to_sql_from_sql_json, types::MinorUnit};
 use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use euclid::{backend::inputs::TemporalDataInput, frontend::dir::enums::DayOfWeek};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -88,3 +90,166 @@ pub struct AcquirerConfig {
 pub struct AcquirerConfigMap(pub HashMap<common_utils::id_type::ProfileAcquirerId, AcquirerConfig>);
 
 impl_to_sql_from_sql_json!(AcquirerConfigMap);
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Policy deciding which refunds and payouts are held for approval before execution
+pub struct TransactionApprovalPolicy {
+    /// Thresholds applied to refunds
+    pub refund: Option<ApprovalThreshold>,
+    /// Thresholds applied to payouts
+    pub payout: Option<ApprovalThreshold>,
+}
+
+impl_to_sql_from_sql_json!(TransactionApprovalPolicy);
+
+#[derive(
+    Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+#[serde(deny_unknown_fields)]
+/// Time zone and holiday calendars in which the time based keys of routing rules are evaluated
+pub struct RoutingCalendarConfig {
+    /// IANA time zone of the local hour, day of week and date of a payment, UTC if not set
+    #[schema(value_type = Option<String>, example = "Europe/Berlin")]
+    pub time_zone: Option<chrono_tz::Tz>,
+    /// Holiday calendars of the merchant keyed by their name, each with its dates
+    #[schema(value_type = HashMap<String, Vec<String>>, example = json!({"bank_holidays": ["2025-12-25", "2025-12-26"]}))]
+    #[serde(default)]
+    pub holiday_calendars: HashMap<String, Vec<chrono::NaiveDate>>,
+}
+
+impl_to_sql_from_sql_json!(RoutingCalendarConfig);
+
+impl RoutingCalendarConfig {
+    /// Returns the local hour, day of week, date and holiday calendars of the given unix
+    /// timestamp in the configured time zone
+    pub fn get_temporal_data(&self, unix_timestamp: i64) -> Option<TemporalDataInput> {
+        let local_time = chrono::DateTime::from_timestamp(unix_timestamp, 0)?
+            .with_timezone(&self.time_zone.unwrap_or(chrono_tz::UTC));
+        let local_date = local_time.date_naive();
+
+        let day_of_week = match local_date.weekday() {
+            chrono::Weekday::Mon => DayOfWeek::Monday,
+            chrono::Weekday::Tue => DayOfWeek::Tuesday,
+            chrono::Weekday::Wed => DayOfWeek::Wednesday,
+            chrono::Weekday::Thu => DayOfWeek::Thursday,
+            chrono::Weekday::Fri => DayOfWeek::Friday,
+            chrono::Weekday::Sat => DayOfWeek::Saturday,
+            chrono::Weekday::Sun => DayOfWeek::Sunday,
+        };
+
+        let holiday_calendars = self
+            .holiday_calendars
+            .iter()
+            .filter(|(_, dates)| dates.contains(&local_date))
+            .map(|(name, _)| name.clone())
+            .collect();
+
+        Some(TemporalDataInput {
+            local_hour_of_day: u8::try_from(local_time.hour()).ok()?,
+            day_of_week,
+            local_date: u32::try_from(local_date.year()).ok()? * 10000
+                + local_date.month() * 100
+                + local_date.day(),
+            holiday_calendars,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Conditions under which a transaction requires approval
+pub struct ApprovalThreshold {
+    /// Transactions with an amount greater than or equal to the value configured for their
+    /// currency require approval, amounts in currencies which are not configured are not limited
+    #[schema(value_type = HashMap<Currency, i64>, example = json!({"USD": 100000, "EUR": 90000}))]
+    #[serde(default)]
+    pub amounts: HashMap<enums::Currency, MinorUnit>,
+    /// Number of transactions allowed within `bulk_window_in_secs` before further ones require approval
+    #[schema(example = 50)]
+    pub bulk_count: Option<u32>,
+    /// Window in seconds over which `bulk_count` is evaluated
+    #[schema(example = 3600)]
+    pub bulk_window_in_secs: Option<u32>,
+}
+
+impl ApprovalThreshold {
+    /// Returns whether the amount is at or above the threshold configured for its currency
+    pub fn is_amount_above_threshold(&self, amount: MinorUnit, currency: enums::Currency) -> bool {
+        self.amounts
+            .get(&currency)
+            .is_some_and(|threshold_amount| amount >= *threshold_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_temporal_data_follows_dst_transitions() {
+        let config = RoutingCalendarConfig {
+            time_zone: Some(chrono_tz::Europe::Berlin),
+            holiday_calendars: HashMap::new(),
+        };
+
+        // 2025-03-29T01:30:00Z, Berlin is on CET (UTC+1)
+        let before_dst = config.get_temporal_data(1743211800).unwrap();
+        assert_eq!(before_dst.local_hour_of_day, 2);
+        assert_eq!(before_dst.day_of_week, DayOfWeek::Saturday);
+        assert_eq!(before_dst.local_date, 20250329);
+
+        // 2025-03-30T01:30:00Z, Berlin is on CEST (UTC+2)
+        let after_dst = config.get_temporal_data(1743298200).unwrap();
+        assert_eq!(after_dst.local_hour_of_day, 3);
+        assert_eq!(after_dst.day_of_week, DayOfWeek::Sunday);
+        assert_eq!(after_dst.local_date, 20250330);
+    }
+
+    #[test]
+    fn test_temporal_data_uses_local_date() {
+        let holiday_calendars = HashMap::from([(
+            "new_year".to_string(),
+            vec![chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap()],
+        )]);
+        let kolkata = RoutingCalendarConfig {
+            time_zone: Some(chrono_tz::Asia::Kolkata),
+            holiday_calendars: holiday_calendars.clone(),
+        };
+        let utc = RoutingCalendarConfig {
+            time_zone: None,
+            holiday_calendars,
+        };
+
+        // 2025-12-31T23:30:00Z is already 2026-01-01 in Kolkata (UTC+5:30)
+        let local = kolkata.get_temporal_data(1767223800).unwrap();
+        assert_eq!(local.local_hour_of_day, 5);
+        assert_eq!(local.day_of_week, DayOfWeek::Thursday);
+        assert_eq!(local.local_date, 20260101);
+        assert_eq!(local.holiday_calendars, vec!["new_year".to_string()]);
+
+        let local = utc.get_temporal_data(1767223800).unwrap();
+        assert_eq!(local.local_hour_of_day, 23);
+        assert_eq!(local.day_of_week, DayOfWeek::Wednesday);
+        assert_eq!(local.local_date, 20251231);
+        assert!(local.holiday_calendars.is_empty());
+    }
+    #[test]
+    fn test_approval_threshold_compares_amount_in_its_currency() {
+        let threshold = ApprovalThreshold {
+            amounts: HashMap::from([(enums::Currency::USD, MinorUnit::new(100000))]),
+            bulk_count: None,
+            bulk_window_in_secs: None,
+        };
+
+        assert!(threshold.is_amount_above_threshold(MinorUnit::new(100000), enums::Currency::USD));
+        assert!(!threshold.is_amount_above_threshold(MinorUnit::new(99999), enums::Currency::USD));
+        // 100000 JPY is far below 100000 USD cents, and JPY has no configured threshold
+        assert!(!threshold.is_amount_above_threshold(MinorUnit::new(100000), enums::Currency::JPY));
+    }
+}
@@ -10,5 +10,6 @@ pub mod payments;
 /// types that are wrappers around primitive types
 pub mod primitive_wrappers;
 pub mod refunds;
+pub mod role;
 /// types for three ds decision rule engine
 pub mod three_ds_decision_rule_engine;
@@ -0,0 +1,38 @@
+//! Role related types
+
+use common_enums::{PermissionAction, Resource};
+use common_utils::{id_type, impl_to_sql_from_sql_json, types::MinorUnit};
+use diesel::{sql_types::Jsonb, AsExpression, FromSqlRow};
+use serde::{Deserialize, Serialize};
+
+/// Actions granted to a custom role on a single resource
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ResourcePermission {
+    /// Resource on which the actions are granted
+    pub resource: Resource,
+    /// Actions that are granted on the resource
+    pub actions: Vec<PermissionAction>,
+    /// Restrictions on the requests for which the actions are granted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraints: Option<ResourcePermissionConstraints>,
+}
+
+/// Restrictions applied to a resource permission
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ResourcePermissionConstraints {
+    /// Maximum amount, in minor units, up to which the create action is granted on payments and
+    /// refunds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_amount: Option<MinorUnit>,
+    /// Profiles to which the permission is restricted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile_ids: Option<Vec<id_type::ProfileId>>,
+}
+
+/// Resource permissions of a custom role, stored alongside its permission groups
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression)]
+#[diesel(sql_type = Jsonb)]
+pub struct ResourcePermissions(pub Vec<ResourcePermission>);
+impl_to_sql_from_sql_json!(ResourcePermissions);
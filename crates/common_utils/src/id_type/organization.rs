@@ -21,4 +21,9 @@ impl OrganizationId {
     pub fn try_from_string(org_id: String) -> CustomResult<Self, ValidationError> {
         Self::try_from(std::borrow::Cow::from(org_id))
     }
+
+    /// Get the key of the config which enables change approval for the organization
+    pub fn get_change_approval_enabled_key(&self) -> String {
+        format!("change_approval_enabled_{}", self.get_string_repr())
+    }
 }
@@ -0,0 +1,165 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::analytics_alert_rules};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = analytics_alert_rules,
+    primary_key(alert_rule_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct AnalyticsAlertRule {
+    pub alert_rule_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub description: Option<String>,
+    pub metric: serde_json::Value,
+    pub alert_condition: serde_json::Value,
+    pub evaluation_window_in_mins: i32,
+    pub evaluation_interval_in_mins: i32,
+    pub notification_channels: serde_json::Value,
+    pub enabled: bool,
+    pub state: storage_enums::AnalyticsAlertState,
+    pub last_observed_value: Option<f64>,
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    pub last_state_changed_at: Option<PrimitiveDateTime>,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = analytics_alert_rules)]
+pub struct AnalyticsAlertRuleNew {
+    pub alert_rule_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub name: String,
+    pub description: Option<String>,
+    pub metric: serde_json::Value,
+    pub alert_condition: serde_json::Value,
+    pub evaluation_window_in_mins: i32,
+    pub evaluation_interval_in_mins: i32,
+    pub notification_channels: serde_json::Value,
+    pub enabled: bool,
+    pub state: storage_enums::AnalyticsAlertState,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = analytics_alert_rules)]
+pub struct AnalyticsAlertRuleUpdateInternal {
+    name: Option<String>,
+    description: Option<String>,
+    metric: Option<serde_json::Value>,
+    alert_condition: Option<serde_json::Value>,
+    evaluation_window_in_mins: Option<i32>,
+    evaluation_interval_in_mins: Option<i32>,
+    notification_channels: Option<serde_json::Value>,
+    enabled: Option<bool>,
+    state: Option<storage_enums::AnalyticsAlertState>,
+    last_observed_value: Option<f64>,
+    last_evaluated_at: Option<PrimitiveDateTime>,
+    last_state_changed_at: Option<PrimitiveDateTime>,
+    last_modified_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum AnalyticsAlertRuleUpdate {
+    Update {
+        name: Option<String>,
+        description: Option<String>,
+        metric: Option<serde_json::Value>,
+        alert_condition: Option<serde_json::Value>,
+        evaluation_window_in_mins: Option<i32>,
+        evaluation_interval_in_mins: Option<i32>,
+        notification_channels: Option<serde_json::Value>,
+        enabled: Option<bool>,
+    },
+    Evaluation {
+        state: storage_enums::AnalyticsAlertState,
+        last_observed_value: Option<f64>,
+        state_changed: bool,
+    },
+}
+
+impl From<AnalyticsAlertRuleUpdate> for AnalyticsAlertRuleUpdateInternal {
+    fn from(value: AnalyticsAlertRuleUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            AnalyticsAlertRuleUpdate::Update {
+                name,
+                description,
+                metric,
+                alert_condition,
+                evaluation_window_in_mins,
+                evaluation_interval_in_mins,
+                notification_channels,
+                enabled,
+            } => Self {
+                name,
+                description,
+                metric,
+                alert_condition,
+                evaluation_window_in_mins,
+                evaluation_interval_in_mins,
+                notification_channels,
+                enabled,
+                last_modified_at: Some(now),
+                ..Default::default()
+            },
+            AnalyticsAlertRuleUpdate::Evaluation {
+                state,
+                last_observed_value,
+                state_changed,
+            } => Self {
+                state: Some(state),
+                last_observed_value,
+                last_evaluated_at: Some(now),
+                last_state_changed_at: state_changed.then_some(now),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+impl AnalyticsAlertRuleUpdateInternal {
+    pub fn apply_changeset(self, source: AnalyticsAlertRule) -> AnalyticsAlertRule {
+        AnalyticsAlertRule {
+            name: self.name.unwrap_or(source.name),
+            description: self.description.or(source.description),
+            metric: self.metric.unwrap_or(source.metric),
+            alert_condition: self.alert_condition.unwrap_or(source.alert_condition),
+            evaluation_window_in_mins: self
+                .evaluation_window_in_mins
+                .unwrap_or(source.evaluation_window_in_mins),
+            evaluation_interval_in_mins: self
+                .evaluation_interval_in_mins
+                .unwrap_or(source.evaluation_interval_in_mins),
+            notification_channels: self
+                .notification_channels
+                .unwrap_or(source.notification_channels),
+            enabled: self.enabled.unwrap_or(source.enabled),
+            state: self.state.unwrap_or(source.state),
+            last_observed_value: self.last_observed_value.or(source.last_observed_value),
+            last_evaluated_at: self.last_evaluated_at.or(source.last_evaluated_at),
+            last_state_changed_at: self.last_state_changed_at.or(source.last_state_changed_at),
+            last_modified_at: self.last_modified_at.unwrap_or(source.last_modified_at),
+            ..source
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AnalyticsAlertTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub alert_rule_id: String,
+}
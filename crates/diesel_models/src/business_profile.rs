@@ -76,6 +76,8 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -132,6 +134,8 @@ pub struct ProfileNew {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -188,6 +192,8 @@ pub struct ProfileUpdateInternal {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<common_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -241,6 +247,8 @@ impl ProfileUpdateInternal {
             three_ds_decision_rule_algorithm,
             acquirer_config_map,
             merchant_category_code,
+            transaction_approval_policy,
+            routing_calendar_config,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -325,6 +333,9 @@ impl ProfileUpdateInternal {
                 .or(source.three_ds_decision_rule_algorithm),
             acquirer_config_map: acquirer_config_map.or(source.acquirer_config_map),
             merchant_category_code: merchant_category_code.or(source.merchant_category_code),
+            transaction_approval_policy: transaction_approval_policy
+                .or(source.transaction_approval_policy),
+            routing_calendar_config: routing_calendar_config.or(source.routing_calendar_config),
         }
     }
 }
@@ -0,0 +1,114 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::change_requests};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = change_requests,
+    primary_key(change_request_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ChangeRequest {
+    pub change_request_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity: storage_enums::ChangeRequestEntity,
+    pub request_payload: Encryption,
+    pub description: Option<String>,
+    pub status: storage_enums::ChangeRequestStatus,
+    pub requested_by: String,
+    pub decided_by: Option<String>,
+    pub decision_reason: Option<String>,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+    pub decided_at: Option<PrimitiveDateTime>,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = change_requests)]
+pub struct ChangeRequestNew {
+    pub change_request_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entity: storage_enums::ChangeRequestEntity,
+    pub request_payload: Encryption,
+    pub description: Option<String>,
+    pub status: storage_enums::ChangeRequestStatus,
+    pub requested_by: String,
+    pub created_at: PrimitiveDateTime,
+    pub expires_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = change_requests)]
+pub struct ChangeRequestUpdateInternal {
+    status: storage_enums::ChangeRequestStatus,
+    decided_by: Option<String>,
+    decision_reason: Option<String>,
+    error_message: Option<String>,
+    decided_at: Option<PrimitiveDateTime>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum ChangeRequestUpdate {
+    Decision {
+        status: storage_enums::ChangeRequestStatus,
+        decided_by: String,
+        decision_reason: Option<String>,
+        error_message: Option<String>,
+    },
+    ApplyOutcome {
+        status: storage_enums::ChangeRequestStatus,
+        error_message: Option<String>,
+    },
+    Expire,
+}
+
+impl From<ChangeRequestUpdate> for ChangeRequestUpdateInternal {
+    fn from(value: ChangeRequestUpdate) -> Self {
+        let now = common_utils::date_time::now();
+        match value {
+            ChangeRequestUpdate::Decision {
+                status,
+                decided_by,
+                decision_reason,
+                error_message,
+            } => Self {
+                status,
+                decided_by: Some(decided_by),
+                decision_reason,
+                error_message,
+                decided_at: Some(now),
+                last_modified_at: now,
+            },
+            ChangeRequestUpdate::ApplyOutcome {
+                status,
+                error_message,
+            } => Self {
+                status,
+                decided_by: None,
+                decision_reason: None,
+                error_message,
+                decided_at: None,
+                last_modified_at: now,
+            },
+            ChangeRequestUpdate::Expire => Self {
+                status: storage_enums::ChangeRequestStatus::Expired,
+                decided_by: None,
+                decision_reason: None,
+                error_message: None,
+                decided_at: None,
+                last_modified_at: now,
+            },
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::schema::connector_fee_schedules;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = connector_fee_schedules,
+    primary_key(merchant_connector_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ConnectorFeeSchedule {
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub connector_name: String,
+    pub fee_rules: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = connector_fee_schedules)]
+pub struct ConnectorFeeScheduleNew {
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub connector_name: String,
+    pub fee_rules: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = connector_fee_schedules)]
+pub struct ConnectorFeeScheduleUpdateInternal {
+    fee_rules: serde_json::Value,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct ConnectorFeeScheduleUpdate {
+    pub fee_rules: serde_json::Value,
+}
+
+impl From<ConnectorFeeScheduleUpdate> for ConnectorFeeScheduleUpdateInternal {
+    fn from(value: ConnectorFeeScheduleUpdate) -> Self {
+        Self {
+            fee_rules: value.fee_rules,
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+impl ConnectorFeeScheduleUpdateInternal {
+    pub fn apply_changeset(self, source: ConnectorFeeSchedule) -> ConnectorFeeSchedule {
+        ConnectorFeeSchedule {
+            fee_rules: self.fee_rules,
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
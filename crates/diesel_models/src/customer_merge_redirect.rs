@@ -0,0 +1,27 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use time::PrimitiveDateTime;
+
+use crate::schema::customer_merge_redirects;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = customer_merge_redirects,
+    primary_key(merchant_id, source_customer_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct CustomerMergeRedirect {
+    pub merchant_id: id_type::MerchantId,
+    pub source_customer_id: id_type::CustomerId,
+    pub target_customer_id: id_type::CustomerId,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = customer_merge_redirects)]
+pub struct CustomerMergeRedirectNew {
+    pub merchant_id: id_type::MerchantId,
+    pub source_customer_id: id_type::CustomerId,
+    pub target_customer_id: id_type::CustomerId,
+    pub created_at: PrimitiveDateTime,
+}
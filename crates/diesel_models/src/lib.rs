@@ -1,16 +1,20 @@
 pub mod address;
+pub mod analytics_alert_rule;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
+pub mod change_request;
 pub mod configs;
+pub mod connector_fee_schedule;
 
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
+pub mod customer_merge_redirect;
 pub mod customers;
 pub mod dispute;
 pub mod dynamic_routing_stats;
@@ -59,6 +63,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod vault_migration_entry;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
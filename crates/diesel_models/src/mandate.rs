@@ -98,6 +98,9 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<common_utils::id_type::PaymentId>,
     },
+    PaymentMethodIdUpdate {
+        payment_method_id: String,
+    },
 }
 
 impl MandateUpdate {
@@ -176,6 +179,10 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::PaymentMethodIdUpdate { payment_method_id } => Self {
+                payment_method_id: Some(payment_method_id),
+                ..Default::default()
+            },
         }
     }
 }
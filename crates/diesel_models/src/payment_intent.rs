@@ -525,6 +525,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryption>,
     },
+    RedactionUpdate {
+        customer_details: Option<Encryption>,
+        billing_details: Option<Encryption>,
+        shipping_details: Option<Encryption>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -1497,6 +1503,52 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 is_iframe_redirection_enabled: None,
                 extended_return_url: None,
             },
+            PaymentIntentUpdate::RedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                amount: None,
+                currency: None,
+                status: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                updated_by,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details,
+                billing_details,
+                merchant_order_reference_id: None,
+                shipping_details,
+                is_payment_processor_token_flow: None,
+                tax_details: None,
+                force_3ds_challenge: None,
+                is_iframe_redirection_enabled: None,
+                extended_return_url: None,
+            },
         }
     }
 }
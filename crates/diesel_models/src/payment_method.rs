@@ -60,6 +60,9 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub card_expires_at: Option<PrimitiveDateTime>,
+    pub external_vault_source: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
 #[cfg(feature = "v2")]
@@ -143,6 +146,9 @@ pub struct PaymentMethodNew {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: Option<Encryption>,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub card_expires_at: Option<PrimitiveDateTime>,
+    pub external_vault_source: Option<common_utils::id_type::MerchantConnectorAccountId>,
 }
 
 #[cfg(feature = "v2")]
@@ -243,6 +249,18 @@ pub enum PaymentMethodUpdate {
         connector_mandate_details: Option<pii::SecretSerdeValue>,
         network_transaction_id: Option<Secret<String>>,
     },
+    NetworkTokenLifecycleUpdate {
+        network_token_requestor_reference_id: Option<String>,
+        network_token_payment_method_data: Option<Encryption>,
+        network_token_status: storage_enums::NetworkTokenStatus,
+    },
+    CardExpiryUpdate {
+        card_expires_at: PrimitiveDateTime,
+    },
+    VaultMigrationUpdate {
+        locker_id: String,
+        external_vault_source: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -282,6 +300,10 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<CommonMandateReference>,
     },
+    BillingAddressAndLastUsedUpdate {
+        payment_method_billing_address: Option<Encryption>,
+        last_used_at: PrimitiveDateTime,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -314,6 +336,7 @@ pub struct PaymentMethodUpdateInternal {
     network_token_payment_method_data: Option<Encryption>,
     locker_fingerprint_id: Option<String>,
     external_vault_source: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    payment_method_billing_address: Option<Encryption>,
 }
 
 #[cfg(feature = "v2")]
@@ -335,6 +358,7 @@ impl PaymentMethodUpdateInternal {
             network_token_payment_method_data,
             locker_fingerprint_id,
             external_vault_source,
+            payment_method_billing_address,
         } = self;
 
         PaymentMethod {
@@ -351,7 +375,8 @@ impl PaymentMethodUpdateInternal {
             status: status.unwrap_or(source.status),
             network_transaction_id: network_transaction_id.or(source.network_transaction_id),
             client_secret: source.client_secret,
-            payment_method_billing_address: source.payment_method_billing_address,
+            payment_method_billing_address: payment_method_billing_address
+                .or(source.payment_method_billing_address),
             updated_by: updated_by.or(source.updated_by),
             locker_fingerprint_id: locker_fingerprint_id.or(source.locker_fingerprint_id),
             payment_method_type_v2: payment_method_type_v2.or(source.payment_method_type_v2),
@@ -388,6 +413,9 @@ pub struct PaymentMethodUpdateInternal {
     network_token_locker_id: Option<String>,
     network_token_payment_method_data: Option<Encryption>,
     scheme: Option<String>,
+    network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    card_expires_at: Option<Option<PrimitiveDateTime>>,
+    external_vault_source: Option<Option<common_utils::id_type::MerchantConnectorAccountId>>,
 }
 
 #[cfg(feature = "v1")]
@@ -410,6 +438,9 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id,
             network_token_payment_method_data,
             scheme,
+            network_token_status,
+            card_expires_at,
+            external_vault_source,
         } = self;
 
         PaymentMethod {
@@ -450,6 +481,9 @@ impl PaymentMethodUpdateInternal {
             network_token_locker_id: network_token_locker_id.or(source.network_token_locker_id),
             network_token_payment_method_data: network_token_payment_method_data
                 .or(source.network_token_payment_method_data),
+            network_token_status: network_token_status.or(source.network_token_status),
+            card_expires_at: card_expires_at.unwrap_or(source.card_expires_at),
+            external_vault_source: external_vault_source.unwrap_or(source.external_vault_source),
         }
     }
 }
@@ -478,10 +512,15 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
             } => Self {
+                // The expiry is derived again from the updated card details
+                card_expires_at: payment_method_data.as_ref().map(|_| None),
                 metadata: None,
                 payment_method_data,
                 last_used_at: None,
@@ -498,6 +537,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -516,12 +557,17 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
                 scheme,
                 last_used_at,
             } => Self {
+                // The expiry is derived again from the updated card details
+                card_expires_at: payment_method_data.as_ref().map(|_| None),
                 metadata: None,
                 payment_method_data,
                 last_used_at: Some(last_used_at),
@@ -538,6 +584,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme,
+                network_token_status: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -559,6 +607,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -577,6 +628,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -589,6 +643,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id,
                 network_token_payment_method_data,
             } => Self {
+                // The expiry is derived again from the updated card details
+                card_expires_at: payment_method_data.as_ref().map(|_| None),
                 metadata: None,
                 payment_method_data,
                 last_used_at: None,
@@ -605,6 +661,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id,
                 network_token_payment_method_data,
                 scheme: None,
+                network_token_status: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -625,12 +683,19 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::NetworkTokenDataUpdate {
                 network_token_requestor_reference_id,
                 network_token_locker_id,
                 network_token_payment_method_data,
             } => Self {
+                // A network token that is stored again is active
+                network_token_status: network_token_requestor_reference_id
+                    .as_ref()
+                    .map(|_| storage_enums::NetworkTokenStatus::Active),
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
@@ -647,6 +712,8 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id,
                 network_token_payment_method_data,
                 scheme: None,
+                card_expires_at: None,
+                external_vault_source: None,
             },
             PaymentMethodUpdate::ConnectorNetworkTransactionIdAndMandateDetailsUpdate {
                 connector_mandate_details,
@@ -669,6 +736,79 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
                 scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
+            },
+            PaymentMethodUpdate::NetworkTokenLifecycleUpdate {
+                network_token_requestor_reference_id,
+                network_token_payment_method_data,
+                network_token_status,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_transaction_id: None,
+                network_token_requestor_reference_id,
+                network_token_locker_id: None,
+                network_token_payment_method_data,
+                scheme: None,
+                network_token_status: Some(network_token_status),
+                card_expires_at: None,
+                external_vault_source: None,
+            },
+            PaymentMethodUpdate::CardExpiryUpdate { card_expires_at } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+                scheme: None,
+                network_token_status: None,
+                card_expires_at: Some(Some(card_expires_at)),
+                external_vault_source: None,
+            },
+            PaymentMethodUpdate::VaultMigrationUpdate {
+                locker_id,
+                external_vault_source,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: Some(locker_id),
+                network_token_requestor_reference_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_payment_method_data: None,
+                scheme: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: Some(external_vault_source),
             },
         }
     }
@@ -696,6 +836,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 payment_method_data: None,
@@ -713,6 +854,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -734,6 +876,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -754,6 +897,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 payment_method_data: None,
@@ -771,6 +915,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::GenericUpdate {
                 payment_method_data,
@@ -800,6 +945,7 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data,
                 locker_fingerprint_id,
                 external_vault_source,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -819,6 +965,28 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 network_token_payment_method_data: None,
                 locker_fingerprint_id: None,
                 external_vault_source: None,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::BillingAddressAndLastUsedUpdate {
+                payment_method_billing_address,
+                last_used_at,
+            } => Self {
+                payment_method_data: None,
+                last_used_at: Some(last_used_at),
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method_type_v2: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_subtype: None,
+                last_modified: common_utils::date_time::now(),
+                network_token_locker_id: None,
+                network_token_requestor_reference_id: None,
+                network_token_payment_method_data: None,
+                locker_fingerprint_id: None,
+                external_vault_source: None,
+                payment_method_billing_address,
             },
         }
     }
@@ -868,6 +1036,9 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             network_token_payment_method_data: payment_method_new
                 .network_token_payment_method_data
                 .clone(),
+            network_token_status: payment_method_new.network_token_status,
+            card_expires_at: payment_method_new.card_expires_at,
+            external_vault_source: payment_method_new.external_vault_source.clone(),
         }
     }
 }
@@ -1,16 +1,20 @@
 pub mod address;
+pub mod analytics_alert_rule;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
 pub mod cards_info;
+pub mod change_request;
 pub mod configs;
+pub mod connector_fee_schedule;
 
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
 pub mod callback_mapper;
+pub mod customer_merge_redirect;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -47,3 +51,4 @@ pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
 mod utils;
+pub mod vault_migration_entry;
@@ -101,6 +101,22 @@ impl Address {
         .await
     }
 
+    pub async fn update_customer_id_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            dsl::customer_id.eq(new_customer_id.to_owned()),
+        )
+        .await
+    }
+
     pub async fn find_by_merchant_id_payment_id_address_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -0,0 +1,83 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    analytics_alert_rule::{
+        AnalyticsAlertRule, AnalyticsAlertRuleNew, AnalyticsAlertRuleUpdate,
+        AnalyticsAlertRuleUpdateInternal,
+    },
+    query::generics,
+    schema::analytics_alert_rules::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AnalyticsAlertRuleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AnalyticsAlertRule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AnalyticsAlertRule {
+    pub async fn find_by_merchant_id_alert_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_rule_id.eq(alert_rule_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_alert_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+        alert_rule_update: AnalyticsAlertRuleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_rule_id.eq(alert_rule_id.to_owned())),
+            AnalyticsAlertRuleUpdateInternal::from(alert_rule_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_alert_rule_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_rule_id.eq(alert_rule_id.to_owned())),
+        )
+        .await
+    }
+}
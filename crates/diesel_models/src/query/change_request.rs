@@ -0,0 +1,108 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    change_request::{
+        ChangeRequest, ChangeRequestNew, ChangeRequestUpdate, ChangeRequestUpdateInternal,
+    },
+    enums::ChangeRequestStatus,
+    query::generics,
+    schema::change_requests::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ChangeRequestNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ChangeRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ChangeRequest {
+    pub async fn find_by_merchant_id_change_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::change_request_id.eq(change_request_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        status: Option<ChangeRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(dsl::status.eq(status)),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::merchant_id.eq(merchant_id.to_owned()),
+                    limit,
+                    offset,
+                    Some(dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Updates the change request only while it has the given status, so that concurrent
+    /// decisions cannot both move a change request out of the same status
+    pub async fn update_by_merchant_id_change_request_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+        status: ChangeRequestStatus,
+        change_request_update: ChangeRequestUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::change_request_id.eq(change_request_id.to_owned()))
+                .and(dsl::status.eq(status)),
+            ChangeRequestUpdateInternal::from(change_request_update),
+        )
+        .await
+    }
+
+    pub async fn expire_pending_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(ChangeRequestStatus::Pending))
+                .and(dsl::expires_at.le(expired_before)),
+            ChangeRequestUpdateInternal::from(ChangeRequestUpdate::Expire),
+        )
+        .await
+    }
+}
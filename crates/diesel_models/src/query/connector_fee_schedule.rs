@@ -0,0 +1,83 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    connector_fee_schedule::{
+        ConnectorFeeSchedule, ConnectorFeeScheduleNew, ConnectorFeeScheduleUpdate,
+        ConnectorFeeScheduleUpdateInternal,
+    },
+    query::generics,
+    schema::connector_fee_schedules::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ConnectorFeeScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ConnectorFeeSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ConnectorFeeSchedule {
+    pub async fn find_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule_update: ConnectorFeeScheduleUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+            ConnectorFeeScheduleUpdateInternal::from(fee_schedule_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_merchant_connector_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::merchant_connector_id.eq(merchant_connector_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,63 @@
+use common_utils::id_type;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    customer_merge_redirect::{CustomerMergeRedirect, CustomerMergeRedirectNew},
+    query::generics,
+    schema::customer_merge_redirects::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerMergeRedirectNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerMergeRedirect> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerMergeRedirect {
+    pub async fn find_optional_by_merchant_id_source_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::source_customer_id.eq(source_customer_id.to_owned())),
+        )
+        .await
+    }
+
+    /// Points the redirects of customers previously merged into `target_customer_id` to
+    /// `new_target_customer_id`, so that a redirect never resolves to a merged customer.
+    pub async fn update_target_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        target_customer_id: &id_type::CustomerId,
+        new_target_customer_id: &id_type::CustomerId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::target_customer_id.eq(target_customer_id.to_owned())),
+            dsl::target_customer_id.eq(new_target_customer_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_merchant_id_source_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::source_customer_id.eq(source_customer_id.to_owned())),
+        )
+        .await
+    }
+}
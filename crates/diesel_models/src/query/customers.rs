@@ -1,5 +1,11 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::id_type;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+#[cfg(feature = "v1")]
+use diesel::{debug_query, pg::Pg, QueryDsl};
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
 
 use super::generics;
 #[cfg(feature = "v1")]
@@ -69,6 +75,46 @@ impl Customer {
         .await
     }
 
+    /// Lists customers of the merchant that were last modified before the given time and have not
+    /// been redacted, in the order of their ids, starting after the given customer id.
+    #[cfg(feature = "v1")]
+    pub async fn list_unredacted_by_merchant_id_modified_before(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        modified_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        // Redaction replaces the phone country code with the redaction marker, which is never a
+        // valid country code, so it identifies redacted customers without decrypting them
+        let mut query = <Self as HasTable>::table()
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::modified_at.lt(modified_before))
+                    .and(
+                        dsl::phone_country_code
+                            .is_null()
+                            .or(dsl::phone_country_code.ne(common_utils::pii::REDACTED)),
+                    ),
+            )
+            .into_boxed();
+        if let Some(starting_after_id) = starting_after_id {
+            query = query.filter(dsl::customer_id.gt(starting_after_id.to_owned()));
+        }
+        let query = query.order(dsl::customer_id.asc()).limit(limit);
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list unredacted customers")
+    }
+
     #[cfg(feature = "v2")]
     pub async fn find_optional_by_merchant_id_merchant_reference_id(
         conn: &PgPooledConn,
@@ -32,6 +32,25 @@ impl GatewayStatusMap {
         .await
     }
 
+    pub async fn find_by_connectors_flow_sub_flow(
+        conn: &PgPooledConn,
+        connectors: Vec<String>,
+        flow: String,
+        sub_flow: String,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::connector
+                .eq_any(connectors)
+                .and(dsl::flow.eq(flow))
+                .and(dsl::sub_flow.eq(sub_flow)),
+            None,
+            None,
+            Some(dsl::connector.asc()),
+        )
+        .await
+    }
+
     pub async fn retrieve_decision(
         conn: &PgPooledConn,
         connector: String,
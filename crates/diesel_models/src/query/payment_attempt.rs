@@ -81,6 +81,29 @@ impl PaymentAttempt {
         }
     }
 
+    /// Clears the personal data captured on the attempts of a payment, which is the browser
+    /// information, the payment method data and the customer acceptance
+    #[cfg(feature = "v1")]
+    pub async fn redact_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            (
+                dsl::browser_info.eq(None::<serde_json::Value>),
+                dsl::payment_method_data.eq(None::<serde_json::Value>),
+                dsl::customer_acceptance.eq(None::<serde_json::Value>),
+                dsl::modified_at.eq(common_utils::date_time::now()),
+            ),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
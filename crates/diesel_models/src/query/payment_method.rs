@@ -175,6 +175,92 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_merchant_id_payment_method_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: storage_enums::PaymentMethod,
+        status: storage_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(payment_method))
+                .and(dsl::status.eq(status)),
+            Some(limit),
+            Some(offset),
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_network_token_requestor_reference_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                dsl::network_token_requestor_reference_id
+                    .eq(network_token_requestor_reference_id.to_owned()),
+            ),
+        )
+        .await
+    }
+
+    /// Lists active cards of the merchant whose expiry is unknown or before the given time, in
+    /// the order of their ids, starting after the given payment method id.
+    pub async fn find_cards_by_merchant_id_expiring_before(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        expires_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(storage_enums::PaymentMethod::Card))
+                .and(dsl::status.eq(storage_enums::PaymentMethodStatus::Active))
+                .and(
+                    dsl::card_expires_at
+                        .is_null()
+                        .or(dsl::card_expires_at.lt(expires_before)),
+                )
+                .and(dsl::payment_method_id.gt(starting_after_id.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
+    /// Lists active cards of the merchant in the order of their ids, starting after the given
+    /// payment method id.
+    pub async fn find_cards_by_merchant_id_starting_after(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(storage_enums::PaymentMethod::Card))
+                .and(dsl::status.eq(storage_enums::PaymentMethodStatus::Active))
+                .and(dsl::payment_method_id.gt(starting_after_id.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
@@ -199,6 +285,48 @@ impl PaymentMethod {
             result => result,
         }
     }
+
+    pub async fn update_customer_id_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        new_customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            dsl::customer_id.eq(new_customer_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_locker_id_by_payment_method_id(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+        locker_id: &str,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+            dsl::locker_id.eq(locker_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn update_network_token_locker_id_by_payment_method_id(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+        network_token_locker_id: &str,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+            dsl::network_token_locker_id.eq(network_token_locker_id.to_owned()),
+        )
+        .await
+    }
 }
 
 #[cfg(feature = "v2")]
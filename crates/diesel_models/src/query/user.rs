@@ -2,7 +2,9 @@ use common_utils::pii;
 use diesel::{associations::HasTable, ExpressionMethods};
 
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
+pub mod webauthn_credential;
 
 use crate::{
     query::generics, schema::users::dsl as users_dsl, user::*, PgPooledConn, StorageResult,
@@ -0,0 +1,257 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+
+use crate::{
+    errors,
+    query::generics::{
+        self,
+        db_metrics::{track_database_call, DatabaseOperation},
+    },
+    schema::{
+        scim_groups::dsl as scim_groups_dsl, scim_tokens::dsl as scim_tokens_dsl,
+        scim_users::dsl as scim_users_dsl,
+    },
+    user::scim::{
+        ScimGroup, ScimGroupNew, ScimGroupUpdate, ScimGroupUpdateInternal, ScimToken, ScimTokenNew,
+        ScimUser, ScimUserListConstraints, ScimUserNew, ScimUserUpdate, ScimUserUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl ScimTokenNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ScimToken> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ScimToken {
+    pub async fn find_by_hashed_token(
+        conn: &PgPooledConn,
+        hashed_token: String,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_tokens_dsl::hashed_token.eq(hashed_token),
+        )
+        .await
+    }
+
+    pub async fn list_by_org_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            scim_tokens_dsl::org_id.eq(org_id),
+            None,
+            None,
+            Some(scim_tokens_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn delete_by_org_id_token_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        token_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_tokens_dsl::org_id
+                .eq(org_id)
+                .and(scim_tokens_dsl::token_id.eq(token_id)),
+        )
+        .await
+    }
+}
+
+impl ScimUserNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ScimUser> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ScimUser {
+    pub async fn find_by_org_id_user_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        user_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_users_dsl::org_id
+                .eq(org_id)
+                .and(scim_users_dsl::user_id.eq(user_id)),
+        )
+        .await
+    }
+
+    pub async fn list_by_org_id_constraints(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        constraints: ScimUserListConstraints,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(scim_users_dsl::org_id.eq(org_id))
+            .into_boxed();
+        if let Some(user_id) = constraints.user_id {
+            query = query.filter(scim_users_dsl::user_id.eq(user_id));
+        }
+        if let Some(external_id) = constraints.external_id {
+            query = query.filter(scim_users_dsl::external_id.eq(external_id));
+        }
+        let query = query
+            .order(scim_users_dsl::created_at.asc())
+            .limit(limit)
+            .offset(offset);
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async(conn),
+            DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to list SCIM users")
+    }
+
+    pub async fn count_by_org_id_constraints(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        constraints: ScimUserListConstraints,
+    ) -> StorageResult<i64> {
+        let mut query = <Self as HasTable>::table()
+            .count()
+            .filter(scim_users_dsl::org_id.eq(org_id))
+            .into_boxed();
+        if let Some(user_id) = constraints.user_id {
+            query = query.filter(scim_users_dsl::user_id.eq(user_id));
+        }
+        if let Some(external_id) = constraints.external_id {
+            query = query.filter(scim_users_dsl::external_id.eq(external_id));
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to count SCIM users")
+    }
+
+    pub async fn update_by_org_id_user_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        user_id: String,
+        scim_user_update: ScimUserUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            scim_users_dsl::org_id
+                .eq(org_id)
+                .and(scim_users_dsl::user_id.eq(user_id)),
+            ScimUserUpdateInternal::from(scim_user_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_org_id_user_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        user_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_users_dsl::org_id
+                .eq(org_id)
+                .and(scim_users_dsl::user_id.eq(user_id)),
+        )
+        .await
+    }
+}
+
+impl ScimGroupNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ScimGroup> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ScimGroup {
+    pub async fn find_by_org_id_group_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        group_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_groups_dsl::org_id
+                .eq(org_id)
+                .and(scim_groups_dsl::group_id.eq(group_id)),
+        )
+        .await
+    }
+
+    pub async fn list_by_org_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            scim_groups_dsl::org_id.eq(org_id),
+            None,
+            None,
+            Some(scim_groups_dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_org_id_group_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        group_id: String,
+        scim_group_update: ScimGroupUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            scim_groups_dsl::org_id
+                .eq(org_id)
+                .and(scim_groups_dsl::group_id.eq(group_id)),
+            ScimGroupUpdateInternal::from(scim_group_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_org_id_group_id(
+        conn: &PgPooledConn,
+        org_id: id_type::OrganizationId,
+        group_id: String,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            scim_groups_dsl::org_id
+                .eq(org_id)
+                .and(scim_groups_dsl::group_id.eq(group_id)),
+        )
+        .await
+    }
+}
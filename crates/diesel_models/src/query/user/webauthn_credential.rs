@@ -0,0 +1,84 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics,
+    schema::user_webauthn_credentials::dsl,
+    user::webauthn_credential::{
+        UserWebauthnCredential, UserWebauthnCredentialNew, UserWebauthnCredentialUpdate,
+        UserWebauthnCredentialUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl UserWebauthnCredentialNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<UserWebauthnCredential> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl UserWebauthnCredential {
+    pub async fn find_by_credential_id(
+        conn: &PgPooledConn,
+        credential_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::credential_id.eq(credential_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn list_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_user_id_credential_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        credential_id: &str,
+        credential_update: UserWebauthnCredentialUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::credential_id.eq(credential_id.to_owned())),
+            UserWebauthnCredentialUpdateInternal::from(credential_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_user_id_credential_id(
+        conn: &PgPooledConn,
+        user_id: &str,
+        credential_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::user_id
+                .eq(user_id.to_owned())
+                .and(dsl::credential_id.eq(credential_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_user_id(conn: &PgPooledConn, user_id: &str) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::user_id.eq(user_id.to_owned()),
+        )
+        .await
+    }
+}
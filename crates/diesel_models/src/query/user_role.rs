@@ -179,6 +179,30 @@ impl UserRole {
             .await
     }
 
+    pub async fn delete_by_user_id_tenant_id_org_id(
+        conn: &PgPooledConn,
+        user_id: String,
+        tenant_id: id_type::TenantId,
+        org_id: id_type::OrganizationId,
+        entity_id: Option<String>,
+    ) -> StorageResult<bool> {
+        let predicate = dsl::user_id
+            .eq(user_id)
+            .and(dsl::tenant_id.eq(tenant_id))
+            .and(dsl::org_id.eq(org_id));
+
+        match entity_id {
+            Some(entity_id) => {
+                generics::generic_delete::<<Self as HasTable>::Table, _>(
+                    conn,
+                    predicate.and(dsl::entity_id.eq(entity_id)),
+                )
+                .await
+            }
+            None => generics::generic_delete::<<Self as HasTable>::Table, _>(conn, predicate).await,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn generic_user_roles_list_for_user(
         conn: &PgPooledConn,
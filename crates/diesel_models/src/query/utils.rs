@@ -46,6 +46,24 @@ mod composite_key {
             self.1
         }
     }
+    impl CompositeKey for <schema::scim_users::table as diesel::Table>::PrimaryKey {
+        type UK = schema::scim_users::dsl::user_id;
+        fn get_local_unique_key(&self) -> Self::UK {
+            self.1
+        }
+    }
+    impl CompositeKey for <schema::customer_merge_redirects::table as diesel::Table>::PrimaryKey {
+        type UK = schema::customer_merge_redirects::dsl::source_customer_id;
+        fn get_local_unique_key(&self) -> Self::UK {
+            self.1
+        }
+    }
+    impl CompositeKey for <schema::vault_migration_entries::table as diesel::Table>::PrimaryKey {
+        type UK = schema::vault_migration_entries::dsl::payment_method_id;
+        fn get_local_unique_key(&self) -> Self::UK {
+            self.1
+        }
+    }
     impl CompositeKey for <schema::incremental_authorization::table as diesel::Table>::PrimaryKey {
         type UK = schema::incremental_authorization::dsl::authorization_id;
         fn get_local_unique_key(&self) -> Self::UK {
@@ -97,6 +115,12 @@ impl_get_primary_key!(
     schema::events::table,
     schema::merchant_account::table,
     schema::process_tracker::table,
+    schema::analytics_alert_rules::table,
+    schema::change_requests::table,
+    schema::connector_fee_schedules::table,
+    schema::scim_tokens::table,
+    schema::scim_groups::table,
+    schema::user_webauthn_credentials::table,
     // v2 tables
     schema_v2::dashboard_metadata::table,
     schema_v2::merchant_connector_account::table,
@@ -138,6 +162,9 @@ impl_get_primary_key_for_composite!(
     schema::refund::table,
     schema::customers::table,
     schema::blocklist::table,
+    schema::scim_users::table,
+    schema::vault_migration_entries::table,
+    schema::customer_merge_redirects::table,
     schema::incremental_authorization::table,
     schema_v2::incremental_authorization::table,
     schema_v2::blocklist::table
@@ -0,0 +1,110 @@
+use common_enums::VaultMigrationEntryStatus;
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use crate::{
+    query::generics,
+    schema::vault_migration_entries::dsl,
+    vault_migration_entry::{
+        VaultMigrationEntry, VaultMigrationEntryNew, VaultMigrationEntryUpdate,
+        VaultMigrationEntryUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl VaultMigrationEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultMigrationEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultMigrationEntry {
+    pub async fn find_optional_by_migration_id_payment_method_id(
+        conn: &PgPooledConn,
+        migration_id: &str,
+        payment_method_id: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::migration_id
+                .eq(migration_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_migration_id(
+        conn: &PgPooledConn,
+        migration_id: &str,
+        status: Option<VaultMigrationEntryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match status {
+            Some(status) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::migration_id
+                        .eq(migration_id.to_owned())
+                        .and(dsl::status.eq(status)),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    dsl::migration_id.eq(migration_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(dsl::created_at.asc()),
+                )
+                .await
+            }
+        }
+    }
+
+    /// Lists the entries of the migration with the given status in the order of their payment
+    /// method ids, starting after the given payment method id.
+    pub async fn list_by_migration_id_status_starting_after(
+        conn: &PgPooledConn,
+        migration_id: &str,
+        status: VaultMigrationEntryStatus,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::migration_id
+                .eq(migration_id.to_owned())
+                .and(dsl::status.eq(status))
+                .and(dsl::payment_method_id.gt(starting_after_id.unwrap_or_default().to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_migration_id_payment_method_id(
+        conn: &PgPooledConn,
+        migration_id: &str,
+        payment_method_id: &str,
+        entry_update: VaultMigrationEntryUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::migration_id
+                .eq(migration_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+            VaultMigrationEntryUpdateInternal::from(entry_update),
+        )
+        .await
+    }
+}
@@ -1,3 +1,4 @@
+use common_types::role::ResourcePermissions;
 use common_utils::id_type;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use time::PrimitiveDateTime;
@@ -21,6 +22,7 @@ pub struct Role {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    pub resource_permissions: Option<ResourcePermissions>,
 }
 
 #[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -40,6 +42,7 @@ pub struct RoleNew {
     pub entity_type: enums::EntityType,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: id_type::TenantId,
+    pub resource_permissions: Option<ResourcePermissions>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -47,6 +50,7 @@ pub struct RoleNew {
 pub struct RoleUpdateInternal {
     groups: Option<Vec<enums::PermissionGroup>>,
     role_name: Option<String>,
+    resource_permissions: Option<ResourcePermissions>,
     last_modified_by: String,
     last_modified_at: PrimitiveDateTime,
 }
@@ -55,6 +59,7 @@ pub enum RoleUpdate {
     UpdateDetails {
         groups: Option<Vec<enums::PermissionGroup>>,
         role_name: Option<String>,
+        resource_permissions: Option<ResourcePermissions>,
         last_modified_at: PrimitiveDateTime,
         last_modified_by: String,
     },
@@ -66,11 +71,13 @@ impl From<RoleUpdate> for RoleUpdateInternal {
             RoleUpdate::UpdateDetails {
                 groups,
                 role_name,
+                resource_permissions,
                 last_modified_by,
                 last_modified_at,
             } => Self {
                 groups,
                 role_name,
+                resource_permissions,
                 last_modified_at,
                 last_modified_by,
             },
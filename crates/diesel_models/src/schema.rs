@@ -34,6 +34,41 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    analytics_alert_rules (alert_rule_id) {
+        #[max_length = 64]
+        alert_rule_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metric -> Jsonb,
+        alert_condition -> Jsonb,
+        evaluation_window_in_mins -> Int4,
+        evaluation_interval_in_mins -> Int4,
+        notification_channels -> Jsonb,
+        enabled -> Bool,
+        #[max_length = 32]
+        state -> Varchar,
+        last_observed_value -> Nullable<Float8>,
+        last_evaluated_at -> Nullable<Timestamp>,
+        last_state_changed_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -231,6 +266,8 @@ diesel::table! {
         acquirer_config_map -> Nullable<Jsonb>,
         #[max_length = 16]
         merchant_category_code -> Nullable<Varchar>,
+        transaction_approval_policy -> Nullable<Jsonb>,
+        routing_calendar_config -> Nullable<Jsonb>,
     }
 }
 
@@ -312,6 +349,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    change_requests (change_request_id) {
+        #[max_length = 64]
+        change_request_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entity -> Varchar,
+        request_payload -> Bytea,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        requested_by -> Varchar,
+        #[max_length = 64]
+        decided_by -> Nullable<Varchar>,
+        decision_reason -> Nullable<Text>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        expires_at -> Timestamp,
+        decided_at -> Nullable<Timestamp>,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -323,6 +393,40 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    connector_fee_schedules (merchant_connector_id) {
+        #[max_length = 64]
+        merchant_connector_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        connector_name -> Varchar,
+        fee_rules -> Jsonb,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_merge_redirects (merchant_id, source_customer_id) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        source_customer_id -> Varchar,
+        #[max_length = 64]
+        target_customer_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1135,6 +1239,11 @@ diesel::table! {
         #[max_length = 64]
         network_token_locker_id -> Nullable<Varchar>,
         network_token_payment_method_data -> Nullable<Bytea>,
+        #[max_length = 32]
+        network_token_status -> Nullable<Varchar>,
+        card_expires_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        external_vault_source -> Nullable<Varchar>,
     }
 }
 
@@ -1393,6 +1502,7 @@ diesel::table! {
         profile_id -> Nullable<Varchar>,
         #[max_length = 64]
         tenant_id -> Varchar,
+        resource_permissions -> Nullable<Jsonb>,
     }
 }
 
@@ -1421,6 +1531,71 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_groups (group_id) {
+        #[max_length = 64]
+        group_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 255]
+        display_name -> Varchar,
+        #[max_length = 255]
+        external_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        role_id -> Varchar,
+        #[max_length = 64]
+        entity_type -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        members -> Array<Nullable<Text>>,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_tokens (token_id) {
+        #[max_length = 64]
+        token_id -> Varchar,
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        #[max_length = 16]
+        prefix -> Varchar,
+        #[max_length = 128]
+        hashed_token -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    scim_users (org_id, user_id) {
+        #[max_length = 32]
+        org_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 255]
+        external_id -> Nullable<Varchar>,
+        active -> Bool,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1542,6 +1717,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    user_webauthn_credentials (credential_id) {
+        #[max_length = 255]
+        credential_id -> Varchar,
+        #[max_length = 64]
+        user_id -> Varchar,
+        #[max_length = 64]
+        name -> Varchar,
+        public_key -> Bytea,
+        algorithm -> Int4,
+        sign_count -> Int8,
+        created_at -> Timestamp,
+        last_used_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1566,8 +1760,39 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    vault_migration_entries (migration_id, payment_method_id) {
+        #[max_length = 64]
+        migration_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 255]
+        source_locker_id -> Varchar,
+        #[max_length = 255]
+        destination_locker_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        #[max_length = 64]
+        source_connector_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        destination_connector_id -> Nullable<Varchar>,
+        destination_reference_created -> Bool,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
+    analytics_alert_rules,
     api_keys,
     authentication,
     blocklist,
@@ -1577,7 +1802,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     callback_mapper,
     captures,
     cards_info,
+    change_requests,
     configs,
+    connector_fee_schedules,
+    customer_merge_redirects,
     customers,
     dashboard_metadata,
     dispute,
@@ -1606,10 +1834,15 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    scim_groups,
+    scim_tokens,
+    scim_users,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
+    user_webauthn_credentials,
     users,
+    vault_migration_entries,
 );
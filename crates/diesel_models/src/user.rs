@@ -7,7 +7,9 @@ use crate::{diesel_impl::OptionalDieselArray, enums::TotpStatus, schema::users};
 
 pub mod dashboard_metadata;
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
+pub mod webauthn_credential;
 
 #[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
 #[diesel(table_name = users, primary_key(user_id), check_for_backend(diesel::pg::Pg))]
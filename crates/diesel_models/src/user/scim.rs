@@ -0,0 +1,172 @@
+use common_enums::EntityType;
+use common_utils::{date_time, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::{
+    diesel_impl::DieselArray,
+    schema::{scim_groups, scim_tokens, scim_users},
+};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = scim_tokens, primary_key(token_id), check_for_backend(diesel::pg::Pg))]
+pub struct ScimToken {
+    pub token_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub name: String,
+    pub prefix: String,
+    pub hashed_token: String,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, DebugAsDisplay)]
+#[diesel(table_name = scim_tokens)]
+pub struct ScimTokenNew {
+    pub token_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub name: String,
+    pub prefix: String,
+    pub hashed_token: String,
+    pub created_by: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = scim_users,
+    primary_key(org_id, user_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct ScimUser {
+    pub org_id: id_type::OrganizationId,
+    pub user_id: String,
+    pub external_id: Option<String>,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+/// Filters applied while listing the SCIM users of an organization
+#[derive(Clone, Debug, Default)]
+pub struct ScimUserListConstraints {
+    pub user_id: Option<String>,
+    pub external_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Insertable, DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserNew {
+    pub org_id: id_type::OrganizationId,
+    pub user_id: String,
+    pub external_id: Option<String>,
+    pub active: bool,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = scim_users)]
+pub struct ScimUserUpdateInternal {
+    external_id: Option<String>,
+    active: Option<bool>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+pub enum ScimUserUpdate {
+    DetailsUpdate {
+        external_id: Option<String>,
+        active: Option<bool>,
+    },
+}
+
+impl From<ScimUserUpdate> for ScimUserUpdateInternal {
+    fn from(value: ScimUserUpdate) -> Self {
+        match value {
+            ScimUserUpdate::DetailsUpdate {
+                external_id,
+                active,
+            } => Self {
+                external_id,
+                active,
+                last_modified_at: date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = scim_groups, primary_key(group_id), check_for_backend(diesel::pg::Pg))]
+pub struct ScimGroup {
+    pub group_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub role_id: String,
+    pub entity_type: EntityType,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+    #[diesel(deserialize_as = DieselArray<String>)]
+    pub members: Vec<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, DebugAsDisplay)]
+#[diesel(table_name = scim_groups)]
+pub struct ScimGroupNew {
+    pub group_id: String,
+    pub org_id: id_type::OrganizationId,
+    pub display_name: String,
+    pub external_id: Option<String>,
+    pub role_id: String,
+    pub entity_type: EntityType,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub members: Vec<String>,
+    pub created_at: PrimitiveDateTime,
+    pub last_modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = scim_groups)]
+pub struct ScimGroupUpdateInternal {
+    display_name: Option<String>,
+    external_id: Option<String>,
+    members: Option<Vec<String>>,
+    last_modified_at: PrimitiveDateTime,
+}
+
+pub enum ScimGroupUpdate {
+    DetailsUpdate {
+        display_name: Option<String>,
+        external_id: Option<String>,
+    },
+    MembersUpdate {
+        members: Vec<String>,
+    },
+}
+
+impl From<ScimGroupUpdate> for ScimGroupUpdateInternal {
+    fn from(value: ScimGroupUpdate) -> Self {
+        let last_modified_at = date_time::now();
+        match value {
+            ScimGroupUpdate::DetailsUpdate {
+                display_name,
+                external_id,
+            } => Self {
+                display_name,
+                external_id,
+                members: None,
+                last_modified_at,
+            },
+            ScimGroupUpdate::MembersUpdate { members } => Self {
+                display_name: None,
+                external_id: None,
+                members: Some(members),
+                last_modified_at,
+            },
+        }
+    }
+}
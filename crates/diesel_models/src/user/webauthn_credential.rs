@@ -0,0 +1,66 @@
+use common_utils::date_time;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::schema::user_webauthn_credentials;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = user_webauthn_credentials,
+    primary_key(credential_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct UserWebauthnCredential {
+    pub credential_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub public_key: Vec<u8>,
+    pub algorithm: i32,
+    pub sign_count: i64,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, DebugAsDisplay)]
+#[diesel(table_name = user_webauthn_credentials)]
+pub struct UserWebauthnCredentialNew {
+    pub credential_id: String,
+    pub user_id: String,
+    pub name: String,
+    pub public_key: Vec<u8>,
+    pub algorithm: i32,
+    pub sign_count: i64,
+    pub created_at: PrimitiveDateTime,
+    pub last_used_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = user_webauthn_credentials)]
+pub struct UserWebauthnCredentialUpdateInternal {
+    sign_count: Option<i64>,
+    name: Option<String>,
+    last_used_at: Option<PrimitiveDateTime>,
+}
+
+pub enum UserWebauthnCredentialUpdate {
+    AssertionUpdate { sign_count: i64 },
+    NameUpdate { name: String },
+}
+
+impl From<UserWebauthnCredentialUpdate> for UserWebauthnCredentialUpdateInternal {
+    fn from(value: UserWebauthnCredentialUpdate) -> Self {
+        match value {
+            UserWebauthnCredentialUpdate::AssertionUpdate { sign_count } => Self {
+                sign_count: Some(sign_count),
+                name: None,
+                last_used_at: Some(date_time::now()),
+            },
+            UserWebauthnCredentialUpdate::NameUpdate { name } => Self {
+                sign_count: None,
+                name: Some(name),
+                last_used_at: None,
+            },
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use common_enums::VaultMigrationEntryStatus;
+use common_utils::id_type;
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use router_derive::DebugAsDisplay;
+use time::PrimitiveDateTime;
+
+use crate::schema::vault_migration_entries;
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = vault_migration_entries,
+    primary_key(migration_id, payment_method_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct VaultMigrationEntry {
+    pub migration_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub source_locker_id: String,
+    pub destination_locker_id: Option<String>,
+    pub status: VaultMigrationEntryStatus,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// External vault connector the card was read from, if it was not stored in the locker
+    pub source_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// External vault connector the card was copied to, if it was not copied to a locker
+    pub destination_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Whether the destination reference was created by the migration, rather than already
+    /// being present in the destination vault
+    pub destination_reference_created: bool,
+}
+
+#[derive(Clone, Debug, Insertable)]
+#[diesel(table_name = vault_migration_entries)]
+pub struct VaultMigrationEntryNew {
+    pub migration_id: String,
+    pub payment_method_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub source_locker_id: String,
+    pub destination_locker_id: Option<String>,
+    pub status: VaultMigrationEntryStatus,
+    pub error_message: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+    /// External vault connector the card was read from, if it was not stored in the locker
+    pub source_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// External vault connector the card was copied to, if it was not copied to a locker
+    pub destination_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Whether the destination reference was created by the migration, rather than already
+    /// being present in the destination vault
+    pub destination_reference_created: bool,
+}
+
+#[derive(Clone, Debug, AsChangeset, DebugAsDisplay)]
+#[diesel(table_name = vault_migration_entries)]
+pub struct VaultMigrationEntryUpdateInternal {
+    status: VaultMigrationEntryStatus,
+    error_message: Option<String>,
+    modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub struct VaultMigrationEntryUpdate {
+    pub status: VaultMigrationEntryStatus,
+    pub error_message: Option<String>,
+}
+
+impl From<VaultMigrationEntryUpdate> for VaultMigrationEntryUpdateInternal {
+    fn from(value: VaultMigrationEntryUpdate) -> Self {
+        Self {
+            status: value.status,
+            error_message: value.error_message,
+            modified_at: common_utils::date_time::now(),
+        }
+    }
+}
+
+impl VaultMigrationEntryUpdateInternal {
+    pub fn apply_changeset(self, source: VaultMigrationEntry) -> VaultMigrationEntry {
+        VaultMigrationEntry {
+            status: self.status,
+            error_message: self.error_message,
+            modified_at: self.modified_at,
+            ..source
+        }
+    }
+}
@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     enums,
-    frontend::dir::enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+    frontend::dir::enums::{
+        CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+    },
 };
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +54,15 @@ pub struct IssuerDataInput {
     pub country: Option<enums::Country>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalDataInput {
+    pub local_hour_of_day: u8,
+    pub day_of_week: DayOfWeek,
+    /// Local date as a number in the YYYYMMDD format
+    pub local_date: u32,
+    pub holiday_calendars: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BackendInput {
     pub metadata: Option<FxHashMap<String, String>>,
@@ -60,5 +71,6 @@ pub struct BackendInput {
     pub acquirer_data: Option<AcquirerDataInput>,
     pub customer_device_data: Option<CustomerDeviceDataInput>,
     pub issuer_data: Option<IssuerDataInput>,
+    pub temporal_data: Option<TemporalDataInput>,
     pub mandate: MandateData,
 }
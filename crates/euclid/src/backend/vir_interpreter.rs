@@ -156,6 +156,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -199,6 +200,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -243,6 +245,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -287,6 +290,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -331,6 +335,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -375,6 +380,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -419,6 +425,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -463,6 +470,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -507,6 +515,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -553,6 +562,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
 
         let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
@@ -597,6 +607,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
         let mut inp_equal = inp_greater.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -650,6 +661,7 @@ mod test {
             acquirer_data: None,
             customer_device_data: None,
             issuer_data: None,
+            temporal_data: None,
         };
         let mut inp_equal = inp_lower.clone();
         inp_equal.payment.amount = MinorUnit::new(123);
@@ -665,4 +677,130 @@ mod test {
             "rule_1"
         );
     }
+
+    fn get_temporal_input(
+        temporal_data: Option<inputs::TemporalDataInput>,
+    ) -> inputs::BackendInput {
+        inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                card_bin: None,
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::PayLater),
+                payment_method_type: Some(enums::PaymentMethodType::Affirm),
+                card_network: None,
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+            acquirer_data: None,
+            customer_device_data: None,
+            issuer_data: None,
+            temporal_data,
+        }
+    }
+
+    #[test]
+    fn test_hour_of_day_and_day_of_week() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           local_hour_of_day >= 9 & local_hour_of_day <= 17 & day_of_week = (monday, tuesday, wednesday, thursday, friday)
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let temporal_data = inputs::TemporalDataInput {
+            local_hour_of_day: 9,
+            day_of_week: dir::enums::DayOfWeek::Wednesday,
+            local_date: 20251231,
+            holiday_calendars: Vec::new(),
+        };
+
+        let result = backend
+            .execute(get_temporal_input(Some(temporal_data.clone())))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+
+        let result = backend
+            .execute(get_temporal_input(Some(inputs::TemporalDataInput {
+                local_hour_of_day: 18,
+                ..temporal_data.clone()
+            })))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+
+        let result = backend
+            .execute(get_temporal_input(Some(inputs::TemporalDataInput {
+                day_of_week: dir::enums::DayOfWeek::Saturday,
+                ..temporal_data
+            })))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+
+        let result = backend
+            .execute(get_temporal_input(None))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_holiday_calendar_and_local_date() {
+        let program_str = r#"
+        default: ["stripe"]
+
+        rule_1: ["adyen"]
+        {
+           holiday_calendar = "bank_holidays"
+        }
+
+        rule_2: ["checkout"]
+        {
+           local_date >= 20251224 & local_date <= 20251231
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let temporal_data = inputs::TemporalDataInput {
+            local_hour_of_day: 12,
+            day_of_week: dir::enums::DayOfWeek::Thursday,
+            local_date: 20260101,
+            holiday_calendars: vec!["bank_holidays".to_string()],
+        };
+
+        let result = backend
+            .execute(get_temporal_input(Some(temporal_data.clone())))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_1");
+
+        let result = backend
+            .execute(get_temporal_input(Some(inputs::TemporalDataInput {
+                local_date: 20251227,
+                holiday_calendars: vec!["regional_holidays".to_string()],
+                ..temporal_data.clone()
+            })))
+            .expect("Execution");
+        assert_eq!(result.rule_name.expect("Rule Name").as_str(), "rule_2");
+
+        let result = backend
+            .execute(get_temporal_input(Some(inputs::TemporalDataInput {
+                holiday_calendars: Vec::new(),
+                ..temporal_data
+            })))
+            .expect("Execution");
+        assert!(result.rule_name.is_none());
+    }
 }
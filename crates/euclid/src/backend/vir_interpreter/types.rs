@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -57,6 +58,7 @@ impl Context {
         let acquirer_data = input.acquirer_data;
         let customer_device_data = input.customer_device_data;
         let issuer_data = input.issuer_data;
+        let temporal_data = input.temporal_data;
         let payment_mandate = input.mandate;
 
         let mut enum_values: FxHashSet<EuclidValue> =
@@ -143,7 +145,7 @@ impl Context {
             }
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
@@ -151,6 +153,28 @@ impl Context {
             }),
         )]);
 
+        // Handle temporal data
+        if let Some(temporal) = temporal_data {
+            enum_values.insert(EuclidValue::DayOfWeek(temporal.day_of_week));
+            for calendar in temporal.holiday_calendars {
+                enum_values.insert(EuclidValue::HolidayCalendar(StrValue { value: calendar }));
+            }
+            numeric_values.insert(
+                EuclidKey::LocalHourOfDay,
+                EuclidValue::LocalHourOfDay(types::NumValue {
+                    number: MinorUnit::new(i64::from(temporal.local_hour_of_day)),
+                    refinement: None,
+                }),
+            );
+            numeric_values.insert(
+                EuclidKey::LocalDate,
+                EuclidValue::LocalDate(types::NumValue {
+                    number: MinorUnit::new(i64::from(temporal.local_date)),
+                    refinement: None,
+                }),
+            );
+        }
+
         Self {
             atomic_values: enum_values,
             numeric_values,
@@ -82,6 +82,10 @@ impl cgraph::NodeViz for dir::DirValue {
             }
             Self::AcquirerCountry(acquirer_country) => acquirer_country.to_string(),
             Self::AcquirerFraudRate(acquirer_fraud_rate) => acquirer_fraud_rate.number.to_string(),
+            Self::LocalHourOfDay(local_hour_of_day) => local_hour_of_day.number.to_string(),
+            Self::DayOfWeek(day_of_week) => day_of_week.to_string(),
+            Self::LocalDate(local_date) => local_date.number.to_string(),
+            Self::HolidayCalendar(holiday_calendar) => holiday_calendar.value.clone(),
         }
     }
 }
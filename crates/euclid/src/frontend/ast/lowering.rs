@@ -275,6 +275,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
         dir::DirKeyKind::CustomerDeviceDisplaySize => lower_enum!(CustomerDeviceDisplaySize, value),
         dir::DirKeyKind::AcquirerCountry => lower_enum!(AcquirerCountry, value),
         dir::DirKeyKind::AcquirerFraudRate => lower_number!(AcquirerFraudRate, value, comparison),
+        dir::DirKeyKind::LocalHourOfDay => lower_number!(LocalHourOfDay, value, comparison),
+        dir::DirKeyKind::DayOfWeek => lower_enum!(DayOfWeek, value),
+        dir::DirKeyKind::LocalDate => lower_number!(LocalDate, value, comparison),
+        dir::DirKeyKind::HolidayCalendar => lower_str!(HolidayCalendar, value),
     }
 }
 
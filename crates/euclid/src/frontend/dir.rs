@@ -334,6 +334,34 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate,
+    #[strum(
+        serialize = "local_hour_of_day",
+        detailed_message = "Hour of the day (0-23) in the time zone configured for the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "local_hour_of_day")]
+    LocalHourOfDay,
+    #[strum(
+        serialize = "day_of_week",
+        detailed_message = "Day of the week in the time zone configured for the profile",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "day_of_week")]
+    DayOfWeek,
+    #[strum(
+        serialize = "local_date",
+        detailed_message = "Date in the time zone configured for the profile, as a number in the YYYYMMDD format",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "local_date")]
+    LocalDate,
+    #[strum(
+        serialize = "holiday_calendar",
+        detailed_message = "Name of a holiday calendar of the profile containing the local date",
+        props(Category = "Time")
+    )]
+    #[serde(rename = "holiday_calendar")]
+    HolidayCalendar,
 }
 
 pub trait EuclidDirFilter: Sized
@@ -391,6 +419,10 @@ impl DirKeyKind {
             Self::CustomerDeviceDisplaySize => types::DataType::EnumVariant,
             Self::AcquirerCountry => types::DataType::EnumVariant,
             Self::AcquirerFraudRate => types::DataType::Number,
+            Self::LocalHourOfDay => types::DataType::Number,
+            Self::DayOfWeek => types::DataType::EnumVariant,
+            Self::LocalDate => types::DataType::Number,
+            Self::HolidayCalendar => types::DataType::StrValue,
         }
     }
     pub fn get_value_set(&self) -> Option<Vec<DirValue>> {
@@ -555,6 +587,10 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::AcquirerFraudRate => None,
+            Self::LocalHourOfDay => None,
+            Self::DayOfWeek => Some(enums::DayOfWeek::iter().map(DirValue::DayOfWeek).collect()),
+            Self::LocalDate => None,
+            Self::HolidayCalendar => None,
         }
     }
 }
@@ -640,6 +676,14 @@ pub enum DirValue {
     AcquirerCountry(enums::Country),
     #[serde(rename = "acquirer_fraud_rate")]
     AcquirerFraudRate(types::NumValue),
+    #[serde(rename = "local_hour_of_day")]
+    LocalHourOfDay(types::NumValue),
+    #[serde(rename = "day_of_week")]
+    DayOfWeek(enums::DayOfWeek),
+    #[serde(rename = "local_date")]
+    LocalDate(types::NumValue),
+    #[serde(rename = "holiday_calendar")]
+    HolidayCalendar(types::StrValue),
 }
 
 impl DirValue {
@@ -683,6 +727,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => (DirKeyKind::CustomerDeviceDisplaySize, None),
             Self::AcquirerCountry(_) => (DirKeyKind::AcquirerCountry, None),
             Self::AcquirerFraudRate(_) => (DirKeyKind::AcquirerFraudRate, None),
+            Self::LocalHourOfDay(_) => (DirKeyKind::LocalHourOfDay, None),
+            Self::DayOfWeek(_) => (DirKeyKind::DayOfWeek, None),
+            Self::LocalDate(_) => (DirKeyKind::LocalDate, None),
+            Self::HolidayCalendar(_) => (DirKeyKind::HolidayCalendar, None),
         };
 
         DirKey::new(kind, data)
@@ -727,6 +775,10 @@ impl DirValue {
             Self::CustomerDeviceDisplaySize(_) => None,
             Self::AcquirerCountry(_) => None,
             Self::AcquirerFraudRate(_) => None,
+            Self::LocalHourOfDay(_) => None,
+            Self::DayOfWeek(_) => None,
+            Self::LocalDate(_) => None,
+            Self::HolidayCalendar(_) => None,
         }
     }
 
@@ -734,6 +786,7 @@ impl DirValue {
         match self {
             Self::CardBin(val) => Some(val.clone()),
             Self::IssuerName(val) => Some(val.clone()),
+            Self::HolidayCalendar(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -742,6 +795,8 @@ impl DirValue {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
             Self::AcquirerFraudRate(val) => Some(val.clone()),
+            Self::LocalHourOfDay(val) => Some(val.clone()),
+            Self::LocalDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -782,6 +837,10 @@ impl DirValue {
             (Self::CustomerDeviceDisplaySize(s1), Self::CustomerDeviceDisplaySize(s2)) => s1 == s2,
             (Self::AcquirerCountry(c1), Self::AcquirerCountry(c2)) => c1 == c2,
             (Self::AcquirerFraudRate(r1), Self::AcquirerFraudRate(r2)) => r1 == r2,
+            (Self::LocalHourOfDay(h1), Self::LocalHourOfDay(h2)) => h1 == h2,
+            (Self::DayOfWeek(d1), Self::DayOfWeek(d2)) => d1 == d2,
+            (Self::LocalDate(d1), Self::LocalDate(d2)) => d1 == d2,
+            (Self::HolidayCalendar(c1), Self::HolidayCalendar(c2)) => c1 == c2,
             _ => false,
         }
     }
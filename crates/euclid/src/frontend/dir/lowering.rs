@@ -278,6 +278,10 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         }
         dir::DirValue::AcquirerCountry(country) => EuclidValue::AcquirerCountry(country),
         dir::DirValue::AcquirerFraudRate(num_value) => EuclidValue::AcquirerFraudRate(num_value),
+        dir::DirValue::LocalHourOfDay(num_value) => EuclidValue::LocalHourOfDay(num_value),
+        dir::DirValue::DayOfWeek(day_of_week) => EuclidValue::DayOfWeek(day_of_week),
+        dir::DirValue::LocalDate(num_value) => EuclidValue::LocalDate(num_value),
+        dir::DirValue::HolidayCalendar(str_value) => EuclidValue::HolidayCalendar(str_value),
     })
 }
 
@@ -11,7 +11,9 @@ use crate::{
     frontend::{
         ast,
         dir::{
-            enums::{CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType},
+            enums::{
+                CustomerDeviceDisplaySize, CustomerDevicePlatform, CustomerDeviceType, DayOfWeek,
+            },
             DirKeyKind, DirValue, EuclidDirFilter,
         },
     },
@@ -77,6 +79,14 @@ pub enum EuclidKey {
     CustomerDeviceDisplaySize,
     #[strum(serialize = "customer_device_platform")]
     CustomerDevicePlatform,
+    #[strum(serialize = "local_hour_of_day")]
+    LocalHourOfDay,
+    #[strum(serialize = "day_of_week")]
+    DayOfWeek,
+    #[strum(serialize = "local_date")]
+    LocalDate,
+    #[strum(serialize = "holiday_calendar")]
+    HolidayCalendar,
 }
 
 impl EuclidDirFilter for DummyOutput {
@@ -95,6 +105,10 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::MandateType,
         DirKeyKind::PaymentType,
         DirKeyKind::SetupFutureUsage,
+        DirKeyKind::LocalHourOfDay,
+        DirKeyKind::DayOfWeek,
+        DirKeyKind::LocalDate,
+        DirKeyKind::HolidayCalendar,
     ];
 }
 impl EuclidAnalysable for DummyOutput {
@@ -163,6 +177,10 @@ impl EuclidKey {
             Self::CustomerDeviceType => DataType::EnumVariant,
             Self::CustomerDeviceDisplaySize => DataType::EnumVariant,
             Self::CustomerDevicePlatform => DataType::EnumVariant,
+            Self::LocalHourOfDay => DataType::Number,
+            Self::DayOfWeek => DataType::EnumVariant,
+            Self::LocalDate => DataType::Number,
+            Self::HolidayCalendar => DataType::StrValue,
         }
     }
 }
@@ -285,12 +303,18 @@ pub enum EuclidValue {
     CustomerDeviceType(CustomerDeviceType),
     CustomerDeviceDisplaySize(CustomerDeviceDisplaySize),
     CustomerDevicePlatform(CustomerDevicePlatform),
+    LocalHourOfDay(NumValue),
+    DayOfWeek(DayOfWeek),
+    LocalDate(NumValue),
+    HolidayCalendar(StrValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
             Self::PaymentAmount(val) => Some(val.clone()),
+            Self::LocalHourOfDay(val) => Some(val.clone()),
+            Self::LocalDate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -320,6 +344,10 @@ impl EuclidValue {
             Self::CustomerDeviceType(_) => EuclidKey::CustomerDeviceType,
             Self::CustomerDeviceDisplaySize(_) => EuclidKey::CustomerDeviceDisplaySize,
             Self::CustomerDevicePlatform(_) => EuclidKey::CustomerDevicePlatform,
+            Self::LocalHourOfDay(_) => EuclidKey::LocalHourOfDay,
+            Self::DayOfWeek(_) => EuclidKey::DayOfWeek,
+            Self::LocalDate(_) => EuclidKey::LocalDate,
+            Self::HolidayCalendar(_) => EuclidKey::HolidayCalendar,
         }
     }
 }
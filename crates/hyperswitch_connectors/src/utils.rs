@@ -452,7 +452,8 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
         }
         common_enums::RefundStatus::ManualReview
         | common_enums::RefundStatus::Pending
-        | common_enums::RefundStatus::Success => false,
+        | common_enums::RefundStatus::Success
+        | common_enums::RefundStatus::PendingApproval => false,
     }
 }
 // TODO: Make all traits as `pub(crate) trait` once all connectors are moved.
@@ -78,6 +78,8 @@ pub struct Profile {
     pub three_ds_decision_rule_algorithm: Option<serde_json::Value>,
     pub acquirer_config_map: Option<common_types::domain::AcquirerConfigMap>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -132,6 +134,8 @@ pub struct ProfileSetter {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: bool,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -193,6 +197,8 @@ impl From<ProfileSetter> for Profile {
             three_ds_decision_rule_algorithm: None, // three_ds_decision_rule_algorithm is not yet created during profile creation
             acquirer_config_map: None,
             merchant_category_code: value.merchant_category_code,
+            transaction_approval_policy: value.transaction_approval_policy,
+            routing_calendar_config: value.routing_calendar_config,
         }
     }
 }
@@ -254,6 +260,8 @@ pub struct ProfileGeneralUpdate {
     pub is_iframe_redirection_enabled: Option<bool>,
     pub is_pre_network_tokenization_enabled: Option<bool>,
     pub merchant_category_code: Option<api_enums::MerchantCategoryCode>,
+    pub transaction_approval_policy: Option<common_types::domain::TransactionApprovalPolicy>,
+    pub routing_calendar_config: Option<common_types::domain::RoutingCalendarConfig>,
 }
 
 #[cfg(feature = "v1")]
@@ -334,6 +342,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     is_iframe_redirection_enabled,
                     is_pre_network_tokenization_enabled,
                     merchant_category_code,
+                    transaction_approval_policy,
+                    routing_calendar_config,
                 } = *update;
 
                 Self {
@@ -385,6 +395,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     three_ds_decision_rule_algorithm: None,
                     acquirer_config_map: None,
                     merchant_category_code,
+                    transaction_approval_policy,
+                    routing_calendar_config,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -439,6 +451,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -490,6 +504,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -541,6 +557,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -592,6 +610,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -643,6 +663,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -694,6 +716,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map: None,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
             ProfileUpdate::AcquirerConfigMapUpdate {
                 acquirer_config_map,
@@ -745,6 +769,8 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 three_ds_decision_rule_algorithm: None,
                 acquirer_config_map,
                 merchant_category_code: None,
+                transaction_approval_policy: None,
+                routing_calendar_config: None,
             },
         }
     }
@@ -816,6 +842,8 @@ impl super::behaviour::Conversion for Profile {
             three_ds_decision_rule_algorithm: self.three_ds_decision_rule_algorithm,
             acquirer_config_map: self.acquirer_config_map,
             merchant_category_code: self.merchant_category_code,
+            transaction_approval_policy: self.transaction_approval_policy,
+            routing_calendar_config: self.routing_calendar_config,
         })
     }
 
@@ -913,6 +941,8 @@ impl super::behaviour::Conversion for Profile {
                 three_ds_decision_rule_algorithm: item.three_ds_decision_rule_algorithm,
                 acquirer_config_map: item.acquirer_config_map,
                 merchant_category_code: item.merchant_category_code,
+                transaction_approval_policy: item.transaction_approval_policy,
+                routing_calendar_config: item.routing_calendar_config,
             })
         }
         .await
@@ -977,6 +1007,8 @@ impl super::behaviour::Conversion for Profile {
             is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
             is_pre_network_tokenization_enabled: Some(self.is_pre_network_tokenization_enabled),
             merchant_category_code: self.merchant_category_code,
+            transaction_approval_policy: self.transaction_approval_policy,
+            routing_calendar_config: self.routing_calendar_config,
         })
     }
 }
@@ -614,6 +614,17 @@ where
         constraints: CustomerListConstraints,
     ) -> CustomResult<Vec<Customer>, Self::Error>;
 
+    #[cfg(feature = "v1")]
+    async fn list_unredacted_customers_by_merchant_id_modified_before(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
+        modified_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> CustomResult<Vec<Customer>, Self::Error>;
+
     async fn insert_customer(
         &self,
         customer_data: Customer,
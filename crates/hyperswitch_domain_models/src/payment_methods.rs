@@ -83,6 +83,9 @@ pub struct PaymentMethod {
     pub network_token_requestor_reference_id: Option<String>,
     pub network_token_locker_id: Option<String>,
     pub network_token_payment_method_data: OptionalEncryptableValue,
+    pub network_token_status: Option<storage_enums::NetworkTokenStatus>,
+    pub card_expires_at: Option<PrimitiveDateTime>,
+    pub external_vault_source: Option<id_type::MerchantConnectorAccountId>,
 }
 
 #[cfg(feature = "v2")]
@@ -291,6 +294,9 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            network_token_status: self.network_token_status,
+            card_expires_at: self.card_expires_at,
+            external_vault_source: self.external_vault_source,
         })
     }
 
@@ -378,6 +384,9 @@ impl super::behaviour::Conversion for PaymentMethod {
                         .and_then(|val| val.try_into_optionaloperation())
                     })
                     .await?,
+                network_token_status: item.network_token_status,
+                card_expires_at: item.card_expires_at,
+                external_vault_source: item.external_vault_source,
             })
         }
         .await
@@ -426,6 +435,9 @@ impl super::behaviour::Conversion for PaymentMethod {
             network_token_payment_method_data: self
                 .network_token_payment_method_data
                 .map(|val| val.into()),
+            network_token_status: self.network_token_status,
+            card_expires_at: self.card_expires_at,
+            external_vault_source: self.external_vault_source,
         })
     }
 }
@@ -774,6 +786,55 @@ pub trait PaymentMethodInterface {
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
 
+    /// Lists payment methods of the merchant having the given payment method and status, in the
+    /// order of their creation.
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_list_by_merchant_id_payment_method_status(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
+    /// Lists active cards of the merchant whose expiry is not known yet or is before the given
+    /// time, in the order of their ids, starting after the given payment method id.
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_expiring_before(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        expires_before: PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
+    /// Lists active cards of the merchant in the order of their ids, starting after the given
+    /// payment method id.
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_starting_after(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, Self::Error>;
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &keymanager::KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> CustomResult<PaymentMethod, Self::Error>;
+
     #[cfg(feature = "v2")]
     #[allow(clippy::too_many_arguments)]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
@@ -959,6 +1020,9 @@ mod tests {
             network_token_requestor_reference_id: None,
             network_token_locker_id: None,
             network_token_payment_method_data: None,
+            network_token_status: None,
+            card_expires_at: None,
+            external_vault_source: None,
         };
         payment_method.clone()
     }
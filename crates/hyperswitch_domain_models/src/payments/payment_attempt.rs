@@ -197,6 +197,14 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, Self::Error>;
 
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<(), Self::Error>;
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     async fn get_filters_for_payments(
         &self,
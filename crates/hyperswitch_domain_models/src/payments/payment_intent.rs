@@ -326,6 +326,12 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     },
+    RedactionUpdate {
+        customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        updated_by: String,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -981,6 +987,19 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details,
                 ..Default::default()
             },
+            PaymentIntentUpdate::RedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                customer_details,
+                billing_details,
+                shipping_details,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -1173,6 +1192,17 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 updated_by,
                 shipping_details: shipping_details.map(Encryption::from),
             },
+            PaymentIntentUpdate::RedactionUpdate {
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self::RedactionUpdate {
+                customer_details: customer_details.map(Encryption::from),
+                billing_details: billing_details.map(Encryption::from),
+                shipping_details: shipping_details.map(Encryption::from),
+                updated_by,
+            },
         }
     }
 }
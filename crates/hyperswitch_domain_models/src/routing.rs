@@ -33,6 +33,16 @@ pub struct PaymentRoutingInfo {
     pub algorithm: Option<routing::StraightThroughAlgorithm>,
     pub pre_routing_results:
         Option<HashMap<api_enums::PaymentMethodType, PreRoutingConnectorChoice>>,
+    pub static_routing_decision: Option<StaticRoutingDecision>,
+}
+
+/// The static routing algorithm which decided the connectors for a payment. Routing algorithms
+/// are not modified once created, so the algorithm id identifies the version of the rules used
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
+pub struct StaticRoutingDecision {
+    pub algorithm_id: id_type::RoutingId,
+    /// Name of the rule of an advanced algorithm which matched the payment
+    pub rule_name: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, PartialEq)]
@@ -47,6 +57,8 @@ pub struct PaymentRoutingInfoInner {
     pub algorithm: Option<routing::StraightThroughAlgorithm>,
     pub pre_routing_results:
         Option<HashMap<api_enums::PaymentMethodType, PreRoutingConnectorChoice>>,
+    #[serde(default)]
+    pub static_routing_decision: Option<StaticRoutingDecision>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -62,10 +74,12 @@ impl From<PaymentRoutingInfoSerde> for PaymentRoutingInfo {
             PaymentRoutingInfoSerde::OnlyAlgorithm(algo) => Self {
                 algorithm: Some(*algo),
                 pre_routing_results: None,
+                static_routing_decision: None,
             },
             PaymentRoutingInfoSerde::WithDetails(details) => Self {
                 algorithm: details.algorithm,
                 pre_routing_results: details.pre_routing_results,
+                static_routing_decision: details.static_routing_decision,
             },
         }
     }
@@ -76,6 +90,7 @@ impl From<PaymentRoutingInfo> for PaymentRoutingInfoSerde {
         Self::WithDetails(Box::new(PaymentRoutingInfoInner {
             algorithm: value.algorithm,
             pre_routing_results: value.pre_routing_results,
+            static_routing_decision: value.static_routing_decision,
         }))
     }
 }
@@ -1,4 +1,5 @@
 use api_models::enums as api_enums;
+use common_utils::types::MinorUnit;
 use euclid::{
     backend::BackendInput,
     dirval,
@@ -72,6 +73,23 @@ impl IntoContext for BackendInput {
         if let Some(payment_type) = self.mandate.payment_type {
             ctx.push(dir::DirValue::PaymentType(payment_type));
         }
+        if let Some(temporal_data) = self.temporal_data {
+            ctx.push(dir::DirValue::LocalHourOfDay(NumValue {
+                number: MinorUnit::new(i64::from(temporal_data.local_hour_of_day)),
+                refinement: None,
+            }));
+            ctx.push(dir::DirValue::DayOfWeek(temporal_data.day_of_week));
+            ctx.push(dir::DirValue::LocalDate(NumValue {
+                number: MinorUnit::new(i64::from(temporal_data.local_date)),
+                refinement: None,
+            }));
+            ctx.extend(
+                temporal_data
+                    .holiday_calendars
+                    .into_iter()
+                    .map(|value| dir::DirValue::HolidayCalendar(StrValue { value })),
+            );
+        }
 
         Ok(ctx)
     }
@@ -159,6 +159,8 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_config,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
+        routes::routing::routing_link_shadow_config,
+        routes::routing::routing_unlink_shadow_config,
         routes::routing::routing_update_default_config,
         routes::routing::routing_retrieve_default_config,
         routes::routing::routing_retrieve_linked_config,
@@ -280,6 +282,9 @@ Never share your secret api keys. Keep them guarded and secure.
         common_types::three_ds_decision_rule_engine::ThreeDSDecisionRule,
         common_types::domain::AcquirerConfigMap,
         common_types::domain::AcquirerConfig,
+        common_types::domain::TransactionApprovalPolicy,
+        common_types::domain::RoutingCalendarConfig,
+        common_types::domain::ApprovalThreshold,
         api_models::payment_methods::PaymentMethodListResponse,
         api_models::payment_methods::ResponsePaymentMethodsEnabled,
         api_models::payment_methods::ResponsePaymentMethodTypes,
@@ -152,6 +152,49 @@ pub async fn list_routing_configs() {}
 )]
 pub async fn routing_unlink_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Activate config in shadow mode
+///
+/// Activate a payment routing config in shadow mode. The config is evaluated alongside the active config for every payment of the profile and the divergence between both is recorded, while only the result of the active config is used
+#[utoipa::path(
+    post,
+    path = "/routing/{routing_algorithm_id}/shadow/activate",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Routing config activated in shadow mode", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Routing",
+   operation_id = "Activate a routing config in shadow mode",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_shadow_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Deactivate shadow config
+///
+/// Deactivates the routing config in shadow mode of a profile
+#[utoipa::path(
+    post,
+    path = "/routing/shadow/deactivate",
+    request_body = RoutingConfigRequest,
+    responses(
+        (status = 200, description = "Successfully deactivated shadow routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Routing",
+   operation_id = "Deactivate the shadow routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_shadow_config() {}
+
 #[cfg(feature = "v1")]
 /// Routing - Update Default Config
 ///
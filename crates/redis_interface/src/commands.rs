@@ -195,6 +195,19 @@ impl super::RedisConnectionPool {
         }
     }
 
+    /// Atomically gets the value of the key and deletes the key, so that the value can be read
+    /// only once even by concurrent callers
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_and_delete_key<V>(&self, key: &RedisKey) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .getdel(key.tenant_aware_key(self))
+            .await
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn exists<V>(&self, key: &RedisKey) -> CustomResult<bool, errors::RedisError>
     where
@@ -444,6 +457,43 @@ impl super::RedisConnectionPool {
         Ok(hsetnx)
     }
 
+    /// Atomically increments the integer value of the key by one and returns the incremented
+    /// value. A key that does not exist is set to zero before it is incremented.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key(&self, key: &RedisKey) -> CustomResult<i64, errors::RedisError> {
+        self.pool
+            .incr(key.tenant_aware_key(self))
+            .await
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
+    /// Atomically increments the integer value of the key by one and sets the expiry of the key
+    /// if it does not have one, which is the case when the key was created by this increment.
+    /// The expiry of an existing key is not modified.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &RedisKey,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        let lua_script = r#"
+        local count = redis.call("INCR", KEYS[1])
+        if redis.call("TTL", KEYS[1]) == -1 then
+            redis.call("EXPIRE", KEYS[1], ARGV[1])
+        end
+        return count
+        "#;
+
+        self.pool
+            .eval(
+                lua_script,
+                vec![key.tenant_aware_key(self)],
+                vec![seconds.to_string()],
+            )
+            .await
+            .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn increment_fields_in_hash<T>(
         &self,
@@ -1031,6 +1081,60 @@ mod tests {
 
         assert!(is_success);
     }
+    #[tokio::test]
+    async fn test_increment_key_returns_incremented_value() {
+        let values = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let _ = pool.delete_key(&"increment_key".into()).await;
+
+                // Act
+                let first = pool.increment_key(&"increment_key".into()).await;
+                let second = pool.increment_key(&"increment_key".into()).await;
+                let _ = pool.delete_key(&"increment_key".into()).await;
+
+                // Assert Setup
+                (first.ok(), second.ok())
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert_eq!(values, (Some(1), Some(2)));
+    }
+
+    #[tokio::test]
+    async fn test_increment_key_with_expiry_returns_incremented_value() {
+        let values = tokio::task::spawn_blocking(move || {
+            futures::executor::block_on(async {
+                // Arrange
+                let pool = RedisConnectionPool::new(&RedisSettings::default())
+                    .await
+                    .expect("failed to create redis connection pool");
+                let _ = pool.delete_key(&"increment_key_with_expiry".into()).await;
+
+                // Act
+                let first = pool
+                    .increment_key_with_expiry(&"increment_key_with_expiry".into(), 60)
+                    .await;
+                let second = pool
+                    .increment_key_with_expiry(&"increment_key_with_expiry".into(), 60)
+                    .await;
+                let _ = pool.delete_key(&"increment_key_with_expiry".into()).await;
+
+                // Assert Setup
+                (first.ok(), second.ok())
+            })
+        })
+        .await
+        .expect("Spawn block failure");
+
+        assert_eq!(values, (Some(1), Some(2)));
+    }
+
     #[tokio::test]
     async fn test_delete_non_existing_key_success() {
         let is_success = tokio::task::spawn_blocking(move || {
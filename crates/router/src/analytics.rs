@@ -9,10 +9,12 @@ pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
         api_event::api_events_core, connector_events::connector_events_core, enums::AuthInfo,
-        errors::AnalyticsError, lambda_utils::invoke_lambda, opensearch::OpenSearchError,
+        errors::AnalyticsError, opensearch::OpenSearchError,
         outgoing_webhook_event::outgoing_webhook_events_core, routing_events::routing_events_core,
-        sdk_events::sdk_events_core, AnalyticsFlow,
+        sdk_events::sdk_events_core, search::SearchProvider, AnalyticsFlow,
     };
+    #[cfg(feature = "v1")]
+    use api_models::analytics::alerts::{AlertRuleCreateRequest, AlertRuleUpdateRequest};
     use api_models::analytics::{
         api_event::QueryType,
         search::{
@@ -20,16 +22,23 @@ pub mod routes {
         },
         AnalyticsRequest, GenerateReportRequest, GetActivePaymentsMetricRequest,
         GetApiEventFiltersRequest, GetApiEventMetricRequest, GetAuthEventFilterRequest,
-        GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
-        GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
-        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetAuthEventMetricRequest, GetDeclineReasonsRequest, GetDisputeMetricRequest,
+        GetFrmFilterRequest, GetFrmMetricRequest, GetPaymentFiltersRequest,
+        GetPaymentFunnelRequest, GetPaymentIntentFiltersRequest, GetPaymentIntentMetricRequest,
+        GetPaymentMetricRequest, GetPayoutFilterRequest, GetPayoutMetricRequest,
+        GetProcessingCostRequest, GetRefundFilterRequest, GetRefundMetricRequest,
+        GetRoutingRuleMetricsRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest,
+        ReportRequest, ReportType,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
     use error_stack::{report, ResultExt};
     use futures::{stream::FuturesUnordered, StreamExt};
 
+    #[cfg(feature = "v1")]
+    use crate::core::{
+        analytics_alert, analytics_decline_reasons, analytics_processing_cost, analytics_report,
+    };
     use crate::{
         analytics_validator::request_validator,
         consts::opensearch::SEARCH_INDEXES,
@@ -77,6 +86,14 @@ pub mod routes {
                             web::resource("filters/refunds")
                                 .route(web::post().to(get_merchant_refund_filters)),
                         )
+                        .service(
+                            web::resource("metrics/payouts")
+                                .route(web::post().to(get_merchant_payout_metrics)),
+                        )
+                        .service(
+                            web::resource("filters/payouts")
+                                .route(web::post().to(get_merchant_payout_filters)),
+                        )
                         .service(web::resource("{domain}/info").route(web::get().to(get_info)))
                         .service(
                             web::resource("report/dispute")
@@ -94,10 +111,18 @@ pub mod routes {
                             web::resource("report/authentications")
                                 .route(web::post().to(generate_merchant_authentication_report)),
                         )
+                        .service(
+                            web::resource("report/{report_id}/download")
+                                .route(web::get().to(download_report)),
+                        )
                         .service(
                             web::resource("metrics/sdk_events")
                                 .route(web::post().to(get_sdk_event_metrics)),
                         )
+                        .service(
+                            web::resource("metrics/payment_funnel")
+                                .route(web::post().to(get_payment_funnel)),
+                        )
                         .service(
                             web::resource("metrics/active_payments")
                                 .route(web::post().to(get_active_payments_metrics)),
@@ -175,6 +200,18 @@ pub mod routes {
                                     web::resource("metrics/payments")
                                         .route(web::post().to(get_merchant_payment_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/decline_reasons")
+                                        .route(web::post().to(get_merchant_decline_reasons)),
+                                )
+                                .service(
+                                    web::resource("metrics/processing_cost")
+                                        .route(web::post().to(get_merchant_processing_cost)),
+                                )
+                                .service(
+                                    web::resource("metrics/routing_rules")
+                                        .route(web::post().to(get_merchant_routing_rule_metrics)),
+                                )
                                 .service(
                                     web::resource("metrics/refunds")
                                         .route(web::post().to(get_merchant_refund_metrics)),
@@ -187,6 +224,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_merchant_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_merchant_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_merchant_payout_filters)),
+                                )
                                 .service(
                                     web::resource("{domain}/info").route(web::get().to(get_info)),
                                 )
@@ -226,6 +271,17 @@ pub mod routes {
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_merchant_sankey)),
+                                )
+                                .service(
+                                    web::resource("alerts")
+                                        .route(web::post().to(create_alert_rule))
+                                        .route(web::get().to(list_alert_rules)),
+                                )
+                                .service(
+                                    web::resource("alerts/{alert_rule_id}")
+                                        .route(web::get().to(retrieve_alert_rule))
+                                        .route(web::post().to(update_alert_rule))
+                                        .route(web::delete().to(delete_alert_rule)),
                                 ),
                         )
                         .service(
@@ -237,6 +293,18 @@ pub mod routes {
                                     web::resource("metrics/payments")
                                         .route(web::post().to(get_org_payment_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/decline_reasons")
+                                        .route(web::post().to(get_org_decline_reasons)),
+                                )
+                                .service(
+                                    web::resource("metrics/processing_cost")
+                                        .route(web::post().to(get_org_processing_cost)),
+                                )
+                                .service(
+                                    web::resource("metrics/routing_rules")
+                                        .route(web::post().to(get_org_routing_rule_metrics)),
+                                )
                                 .service(
                                     web::resource("filters/payments")
                                         .route(web::post().to(get_org_payment_filters)),
@@ -249,6 +317,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_org_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_org_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_org_payout_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_org_dispute_metrics)),
@@ -287,6 +363,18 @@ pub mod routes {
                                     web::resource("metrics/payments")
                                         .route(web::post().to(get_profile_payment_metrics)),
                                 )
+                                .service(
+                                    web::resource("metrics/decline_reasons")
+                                        .route(web::post().to(get_profile_decline_reasons)),
+                                )
+                                .service(
+                                    web::resource("metrics/processing_cost")
+                                        .route(web::post().to(get_profile_processing_cost)),
+                                )
+                                .service(
+                                    web::resource("metrics/routing_rules")
+                                        .route(web::post().to(get_profile_routing_rule_metrics)),
+                                )
                                 .service(
                                     web::resource("filters/payments")
                                         .route(web::post().to(get_profile_payment_filters)),
@@ -299,6 +387,14 @@ pub mod routes {
                                     web::resource("filters/refunds")
                                         .route(web::post().to(get_profile_refund_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_profile_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_profile_payout_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/disputes")
                                         .route(web::post().to(get_profile_dispute_metrics)),
@@ -570,27 +666,18 @@ pub mod routes {
         .await
     }
 
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
-    pub async fn get_merchant_payment_intent_metrics(
+    #[cfg(feature = "v1")]
+    pub async fn get_merchant_decline_reasons(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+        json_payload: web::Json<GetDeclineReasonsRequest>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
-        #[allow(clippy::expect_used)]
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetPaymentIntentMetricRequest");
-        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        let flow = AnalyticsFlow::GetDeclineReasons;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
@@ -598,19 +685,7 @@ pub mod routes {
                     org_id: org_id.clone(),
                     merchant_ids: vec![merchant_id.clone()],
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        payment_intent: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_decline_reasons::get_decline_reasons(&state, &auth, req).await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantAnalyticsRead,
@@ -621,45 +696,23 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
-    pub async fn get_org_payment_intent_metrics(
+    pub async fn get_org_decline_reasons(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+        json_payload: web::Json<GetDeclineReasonsRequest>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
-        #[allow(clippy::expect_used)]
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetPaymentIntentMetricRequest");
-        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        let flow = AnalyticsFlow::GetDeclineReasons;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        payment_intent: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_decline_reasons::get_decline_reasons(&state, &auth, req).await
             },
             auth::auth_type(
                 &auth::PlatformOrgAdminAuth {
@@ -677,27 +730,17 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
-    pub async fn get_profile_payment_intent_metrics(
+    pub async fn get_profile_decline_reasons(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+        json_payload: web::Json<GetDeclineReasonsRequest>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
-        #[allow(clippy::expect_used)]
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetPaymentIntentMetricRequest");
-        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        let flow = AnalyticsFlow::GetDeclineReasons;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
@@ -710,19 +753,7 @@ pub mod routes {
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        payment_intent: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_decline_reasons::get_decline_reasons(&state, &auth, req).await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileAnalyticsRead,
@@ -732,27 +763,18 @@ pub mod routes {
         .await
     }
 
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
-    pub async fn get_merchant_refund_metrics(
+    #[cfg(feature = "v1")]
+    pub async fn get_merchant_processing_cost(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+        json_payload: web::Json<GetProcessingCostRequest>,
     ) -> impl Responder {
-        #[allow(clippy::expect_used)]
-        // safety: This shouldn't panic owing to the data type
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetRefundMetricRequest");
-        let flow = AnalyticsFlow::GetRefundsMetrics;
+        let flow = AnalyticsFlow::GetProcessingCost;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
@@ -760,19 +782,7 @@ pub mod routes {
                     org_id: org_id.clone(),
                     merchant_ids: vec![merchant_id.clone()],
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        refund: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_processing_cost::get_processing_cost(&state, &auth, req).await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantAnalyticsRead,
@@ -783,45 +793,23 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
-    pub async fn get_org_refund_metrics(
+    pub async fn get_org_processing_cost(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+        json_payload: web::Json<GetProcessingCostRequest>,
     ) -> impl Responder {
-        #[allow(clippy::expect_used)]
-        // safety: This shouldn't panic owing to the data type
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetRefundMetricRequest");
-        let flow = AnalyticsFlow::GetRefundsMetrics;
+        let flow = AnalyticsFlow::GetProcessingCost;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        refund: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_processing_cost::get_processing_cost(&state, &auth, req).await
             },
             auth::auth_type(
                 &auth::PlatformOrgAdminAuth {
@@ -839,27 +827,17 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
-    pub async fn get_profile_refund_metrics(
+    pub async fn get_profile_processing_cost(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+        json_payload: web::Json<GetProcessingCostRequest>,
     ) -> impl Responder {
-        #[allow(clippy::expect_used)]
-        // safety: This shouldn't panic owing to the data type
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetRefundMetricRequest");
-        let flow = AnalyticsFlow::GetRefundsMetrics;
+        let flow = AnalyticsFlow::GetProcessingCost;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
@@ -872,19 +850,7 @@ pub mod routes {
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
-
-                let validator_response = request_validator(
-                    AnalyticsRequest {
-                        refund: Some(req.clone()),
-                        ..Default::default()
-                    },
-                    &state,
-                )
-                .await?;
-                let ex_rates = validator_response;
-                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                analytics_processing_cost::get_processing_cost(&state, &auth, req).await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileAnalyticsRead,
@@ -894,31 +860,32 @@ pub mod routes {
         .await
     }
 
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetFrmMetricRequest` element.
-    pub async fn get_frm_metrics(
+    #[cfg(feature = "v1")]
+    pub async fn get_merchant_routing_rule_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetFrmMetricRequest; 1]>,
+        json_payload: web::Json<GetRoutingRuleMetricsRequest>,
     ) -> impl Responder {
-        #[allow(clippy::expect_used)]
-        // safety: This shouldn't panic owing to the data type
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
-            .pop()
-            .expect("Couldn't get GetFrmMetricRequest");
-        let flow = AnalyticsFlow::GetFrmMetrics;
+        let flow = AnalyticsFlow::GetRoutingRuleMetrics;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
-            payload,
+            json_payload.into_inner(),
             |state, auth: AuthenticationData, req, _| async move {
-                analytics::frm::get_metrics(&state.pool, auth.merchant_account.get_id(), req)
-                    .await
-                    .map(ApplicationResponse::Json)
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payments::routing_rules::get_routing_rule_metrics(
+                    &state.pool,
+                    &auth,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantAnalyticsRead,
@@ -929,19 +896,619 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    /// # Panics
-    ///
-    /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
-    pub async fn get_sdk_event_metrics(
+    pub async fn get_org_routing_rule_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<[GetSdkEventMetricRequest; 1]>,
+        json_payload: web::Json<GetRoutingRuleMetricsRequest>,
     ) -> impl Responder {
-        // safety: This shouldn't panic owing to the data type
-        #[allow(clippy::expect_used)]
-        let payload = json_payload
-            .into_inner()
-            .to_vec()
+        let flow = AnalyticsFlow::GetRoutingRuleMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payments::routing_rules::get_routing_rule_metrics(
+                    &state.pool,
+                    &auth,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_routing_rule_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRoutingRuleMetricsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRoutingRuleMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payments::routing_rules::get_routing_rule_metrics(
+                    &state.pool,
+                    &auth,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
+    pub async fn get_merchant_payment_intent_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentIntentMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payment_intent: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
+    pub async fn get_org_payment_intent_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentIntentMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payment_intent: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentIntentMetricRequest` element.
+    pub async fn get_profile_payment_intent_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPaymentIntentMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentIntentMetricRequest");
+        let flow = AnalyticsFlow::GetPaymentIntentMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payment_intent: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payment_intents::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
+    pub async fn get_merchant_refund_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetRefundMetricRequest");
+        let flow = AnalyticsFlow::GetRefundsMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        refund: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
+    pub async fn get_org_refund_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetRefundMetricRequest");
+        let flow = AnalyticsFlow::GetRefundsMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        refund: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
+    pub async fn get_profile_refund_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetRefundMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetRefundMetricRequest");
+        let flow = AnalyticsFlow::GetRefundsMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        refund: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::refunds::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_merchant_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_org_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_profile_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+
+                let validator_response = request_validator(
+                    AnalyticsRequest {
+                        payout: Some(req.clone()),
+                        ..Default::default()
+                    },
+                    &state,
+                )
+                .await?;
+                let ex_rates = validator_response;
+                analytics::payouts::get_metrics(&state.pool, &ex_rates, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetFrmMetricRequest` element.
+    pub async fn get_frm_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetFrmMetricRequest; 1]>,
+    ) -> impl Responder {
+        #[allow(clippy::expect_used)]
+        // safety: This shouldn't panic owing to the data type
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetFrmMetricRequest");
+        let flow = AnalyticsFlow::GetFrmMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::frm::get_metrics(&state.pool, auth.merchant_account.get_id(), req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetSdkEventMetricRequest` element.
+    pub async fn get_sdk_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetSdkEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
             .pop()
             .expect("Couldn't get GetSdkEventMetricRequest");
         let flow = AnalyticsFlow::GetSdkMetrics;
@@ -1105,23 +1672,161 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_org_payment_filters(
+    pub async fn get_org_payment_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payments::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::OrganizationAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payment_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payments::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_payment_intents_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentIntentFiltersRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentIntentFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::payment_intents::get_filters(
+                    &state.pool,
+                    req,
+                    auth.merchant_account.get_id(),
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::PlatformOrgAdminAuth {
+                    is_admin_auth_allowed: false,
+                    organization_id: None,
+                },
+                &auth::JWTAuth {
+                    permission: Permission::MerchantAnalyticsRead,
+                },
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_refund_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::refunds::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_org_refund_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentFiltersRequest>,
+        json_payload: web::Json<GetRefundFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentFilters;
+        let flow = AnalyticsFlow::GetRefundFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-                analytics::payments::get_filters(&state.pool, req, &auth)
+                analytics::refunds::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1141,18 +1846,18 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_profile_payment_filters(
+    pub async fn get_profile_refund_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentFiltersRequest>,
+        json_payload: web::Json<GetRefundFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentFilters;
+        let flow = AnalyticsFlow::GetRefundFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
+            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let profile_id = auth
@@ -1164,7 +1869,7 @@ pub mod routes {
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
-                analytics::payments::get_filters(&state.pool, req, &auth)
+                analytics::refunds::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1176,61 +1881,25 @@ pub mod routes {
         .await
     }
 
-    #[cfg(feature = "v1")]
-    pub async fn get_payment_intents_filters(
-        state: web::Data<AppState>,
-        req: actix_web::HttpRequest,
-        json_payload: web::Json<GetPaymentIntentFiltersRequest>,
-    ) -> impl Responder {
-        let flow = AnalyticsFlow::GetPaymentIntentFilters;
-        Box::pin(api::server_wrap(
-            flow,
-            state,
-            &req,
-            json_payload.into_inner(),
-            |state, auth: AuthenticationData, req, _| async move {
-                analytics::payment_intents::get_filters(
-                    &state.pool,
-                    req,
-                    auth.merchant_account.get_id(),
-                )
-                .await
-                .map(ApplicationResponse::Json)
-            },
-            auth::auth_type(
-                &auth::PlatformOrgAdminAuth {
-                    is_admin_auth_allowed: false,
-                    organization_id: None,
-                },
-                &auth::JWTAuth {
-                    permission: Permission::MerchantAnalyticsRead,
-                },
-                req.headers(),
-            ),
-            api_locking::LockAction::NotApplicable,
-        ))
-        .await
-    }
-
-    pub async fn get_merchant_refund_filters(
+    pub async fn get_merchant_payout_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
+        json_payload: web::Json<GetPayoutFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
+        let flow = AnalyticsFlow::GetPayoutFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let auth: AuthInfo = AuthInfo::MerchantLevel {
                     org_id: org_id.clone(),
                     merchant_ids: vec![merchant_id.clone()],
                 };
-                analytics::refunds::get_filters(&state.pool, req, &auth)
+                analytics::payouts::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1243,23 +1912,23 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_org_refund_filters(
+    pub async fn get_org_payout_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
+        json_payload: web::Json<GetPayoutFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
+        let flow = AnalyticsFlow::GetPayoutFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let auth: AuthInfo = AuthInfo::OrgLevel {
                     org_id: org_id.clone(),
                 };
-                analytics::refunds::get_filters(&state.pool, req, &auth)
+                analytics::payouts::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1279,18 +1948,18 @@ pub mod routes {
     }
 
     #[cfg(feature = "v1")]
-    pub async fn get_profile_refund_filters(
+    pub async fn get_profile_payout_filters(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
-        json_payload: web::Json<GetRefundFilterRequest>,
+        json_payload: web::Json<GetPayoutFilterRequest>,
     ) -> impl Responder {
-        let flow = AnalyticsFlow::GetRefundFilters;
+        let flow = AnalyticsFlow::GetPayoutFilters;
         Box::pin(api::server_wrap(
             flow,
             state,
             &req,
             json_payload.into_inner(),
-            |state, auth: AuthenticationData, req: GetRefundFilterRequest, _| async move {
+            |state, auth: AuthenticationData, req: GetPayoutFilterRequest, _| async move {
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
                 let profile_id = auth
@@ -1302,7 +1971,7 @@ pub mod routes {
                     merchant_id: merchant_id.clone(),
                     profile_ids: vec![profile_id.clone()],
                 };
-                analytics::refunds::get_filters(&state.pool, req, &auth)
+                analytics::payouts::get_filters(&state.pool, req, &auth)
                     .await
                     .map(ApplicationResponse::Json)
             },
@@ -1338,6 +2007,36 @@ pub mod routes {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    pub async fn get_payment_funnel(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetPaymentFunnelRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentFunnel;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                analytics::sdk_events::get_payment_funnel(
+                    &state.pool,
+                    &auth.merchant_account.publishable_key,
+                    auth.merchant_account.get_id(),
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     #[cfg(feature = "v1")]
     pub async fn get_sdk_event_filters(
         state: web::Data<AppState>,
@@ -1487,7 +2186,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1497,15 +2196,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Refund, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1537,7 +2228,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1546,15 +2237,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Refund, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1591,7 +2274,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1602,15 +2285,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.refund_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Refund, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1642,7 +2317,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1652,15 +2327,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Dispute, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1691,7 +2358,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1700,15 +2367,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Dispute, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1745,7 +2404,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1756,15 +2415,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.dispute_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Dispute, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1797,7 +2448,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1807,15 +2458,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Payment, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -1847,7 +2490,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -1856,15 +2499,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Payment, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -1900,7 +2535,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -1911,15 +2546,7 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.payment_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Payment, report_req).await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -1952,7 +2579,7 @@ pub mod routes {
 
                 let org_id = auth.merchant_account.get_org_id();
                 let merchant_id = auth.merchant_account.get_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::MerchantLevel {
@@ -1962,15 +2589,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Authentication, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::MerchantReportRead,
@@ -2002,7 +2622,7 @@ pub mod routes {
                     .get_secret();
 
                 let org_id = auth.merchant_account.get_org_id();
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: None,
                     auth: AuthInfo::OrgLevel {
@@ -2011,15 +2631,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Authentication, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::OrganizationReportRead,
@@ -2055,7 +2668,7 @@ pub mod routes {
                     .profile_id
                     .ok_or(report!(UserErrors::JwtProfileIdMissing))
                     .change_context(AnalyticsError::AccessForbiddenError)?;
-                let lambda_req = GenerateReportRequest {
+                let report_req = GenerateReportRequest {
                     request: payload,
                     merchant_id: Some(merchant_id.clone()),
                     auth: AuthInfo::ProfileLevel {
@@ -2066,15 +2679,8 @@ pub mod routes {
                     email: user_email,
                 };
 
-                let json_bytes =
-                    serde_json::to_vec(&lambda_req).map_err(|_| AnalyticsError::UnknownError)?;
-                invoke_lambda(
-                    &state.conf.report_download_config.authentication_function,
-                    &state.conf.report_download_config.region,
-                    &json_bytes,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                analytics_report::generate_report(&state, ReportType::Authentication, report_req)
+                    .await
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileReportRead,
@@ -2317,7 +2923,7 @@ pub mod routes {
                     })
                     .collect();
 
-                let search_params: Vec<AuthInfo> = filtered_user_roles
+                let mut search_params: Vec<AuthInfo> = filtered_user_roles
                     .iter()
                     .filter_map(|user_role| {
                         user_role
@@ -2341,18 +2947,12 @@ pub mod routes {
                             })
                     })
                     .collect();
+                add_org_merchants_to_search_params(&state, &mut search_params).await?;
 
-                analytics::search::msearch_results(
-                    state
-                        .opensearch_client
-                        .as_ref()
-                        .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
-                    req,
-                    search_params,
-                    SEARCH_INDEXES.to_vec(),
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                get_search_provider(&state)?
+                    .msearch_results(req, search_params, SEARCH_INDEXES.to_vec())
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileAnalyticsRead,
@@ -2469,7 +3069,7 @@ pub mod routes {
                     })
                     .collect();
 
-                let search_params: Vec<AuthInfo> = filtered_user_roles
+                let mut search_params: Vec<AuthInfo> = filtered_user_roles
                     .iter()
                     .filter_map(|user_role| {
                         user_role
@@ -2493,16 +3093,11 @@ pub mod routes {
                             })
                     })
                     .collect();
-                analytics::search::search_results(
-                    state
-                        .opensearch_client
-                        .as_ref()
-                        .ok_or_else(|| error_stack::report!(OpenSearchError::NotEnabled))?,
-                    req,
-                    search_params,
-                )
-                .await
-                .map(ApplicationResponse::Json)
+                add_org_merchants_to_search_params(&state, &mut search_params).await?;
+                get_search_provider(&state)?
+                    .search_results(req, search_params)
+                    .await
+                    .map(ApplicationResponse::Json)
             },
             &auth::JWTAuth {
                 permission: Permission::ProfileAnalyticsRead,
@@ -2881,4 +3476,191 @@ pub mod routes {
         ))
         .await
     }
+
+    #[cfg(feature = "v1")]
+    pub async fn create_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<AlertRuleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: UserFromToken, req, _| {
+                analytics_alert::create_alert_rule(state, auth, req)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn list_alert_rules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListAlertRules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: UserFromToken, _, _| analytics_alert::list_alert_rules(state, auth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn retrieve_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: UserFromToken, alert_rule_id, _| {
+                analytics_alert::retrieve_alert_rule(state, auth, alert_rule_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn update_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+        json_payload: web::Json<AlertRuleUpdateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::UpdateAlertRule;
+        let alert_rule_id = path.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: UserFromToken, req, _| {
+                analytics_alert::update_alert_rule(state, auth, alert_rule_id.clone(), req)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn delete_alert_rule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteAlertRule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: UserFromToken, alert_rule_id, _| {
+                analytics_alert::delete_alert_rule(state, auth, alert_rule_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn download_report(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DownloadReport;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            path.into_inner(),
+            |state, auth: UserFromToken, report_id, _| {
+                analytics_report::download_report(state, auth, report_id)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// Global search is served from OpenSearch when it is enabled, and falls back to searching the
+    /// Postgres tables otherwise
+    fn get_search_provider(
+        state: &crate::routes::SessionState,
+    ) -> common_utils::errors::CustomResult<SearchProvider<'_>, OpenSearchError> {
+        match state.opensearch_client.as_deref() {
+            Some(client) => Ok(SearchProvider::OpenSearch(client)),
+            None => state
+                .pool
+                .get_sqlx_client()
+                .map(SearchProvider::Postgres)
+                .ok_or_else(|| report!(OpenSearchError::NotEnabled)),
+        }
+    }
+
+    /// Customers, payouts, mandates and payment methods are not tagged with the organization in
+    /// their search indexes, so organization level access is also granted for each merchant of the
+    /// organization to search them
+    async fn add_org_merchants_to_search_params(
+        state: &crate::routes::SessionState,
+        search_params: &mut Vec<AuthInfo>,
+    ) -> common_utils::errors::CustomResult<(), OpenSearchError> {
+        let org_ids: HashSet<_> = search_params
+            .iter()
+            .filter_map(|search_param| match search_param {
+                AuthInfo::OrgLevel { org_id } => Some(org_id.clone()),
+                AuthInfo::MerchantLevel { .. } | AuthInfo::ProfileLevel { .. } => None,
+            })
+            .collect();
+
+        for org_id in org_ids {
+            let merchant_ids = state
+                .store
+                .list_merchant_accounts_by_organization_id(&state.into(), &org_id)
+                .await
+                .change_context(OpenSearchError::UnknownError)
+                .attach_printable("Failed to list the merchant accounts of the organization")?
+                .iter()
+                .map(|merchant_account| merchant_account.get_id().to_owned())
+                .collect::<Vec<_>>();
+            if !merchant_ids.is_empty() {
+                search_params.push(AuthInfo::MerchantLevel {
+                    org_id,
+                    merchant_ids,
+                });
+            }
+        }
+        Ok(())
+    }
 }
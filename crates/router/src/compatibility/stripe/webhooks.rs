@@ -87,6 +87,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(StripePaymentMethodResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +108,25 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub customer: Option<common_utils::id_type::CustomerId>,
+    #[serde(rename = "type")]
+    pub method_type: Option<String>,
+    pub card: Option<StripePaymentMethodCard>,
+    pub created: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodCard {
+    pub brand: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -156,7 +176,8 @@ impl From<common_enums::PayoutStatus> for StripePayoutStatus {
             | common_enums::PayoutStatus::RequiresFulfillment
             | common_enums::PayoutStatus::RequiresPayoutMethodData
             | common_enums::PayoutStatus::RequiresVendorAccountCreation
-            | common_enums::PayoutStatus::RequiresConfirmation => Self::PayoutProcessing,
+            | common_enums::PayoutStatus::RequiresConfirmation
+            | common_enums::PayoutStatus::PendingApproval => Self::PayoutProcessing,
         }
     }
 }
@@ -193,6 +214,26 @@ impl From<payout_models::PayoutCreateResponse> for StripePayoutResponse {
     }
 }
 
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            customer: res.customer_id,
+            method_type: res
+                .payment_method
+                .map(|payment_method| payment_method.to_string()),
+            card: res.card.map(|card| StripePaymentMethodCard {
+                brand: card.scheme,
+                last4: card.last4_digits,
+                exp_month: card.expiry_month,
+                exp_year: card.expiry_year,
+            }),
+            created: res.created.map(|t| t.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
 #[derive(Serialize, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeMandateStatus {
@@ -273,6 +314,9 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::ActionRequired => "action.required",
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
+        api_models::enums::EventType::RefundPendingApproval => "refund.pending_approval",
+        api_models::enums::EventType::RefundApproved => "refund.approved",
+        api_models::enums::EventType::RefundRejected => "refund.rejected",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
         api_models::enums::EventType::DisputeExpired => "dispute.expired",
         api_models::enums::EventType::DisputeAccepted => "dispute.accepted",
@@ -296,6 +340,17 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PayoutPendingApproval => "payout.pending_approval",
+        api_models::enums::EventType::PayoutApproved => "payout.approved",
+        api_models::enums::EventType::PayoutRejected => "payout.rejected",
+        api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
+        api_models::enums::EventType::PaymentMethodExpiring => "payment_method.expiring",
+        api_models::enums::EventType::PaymentMethodExpired => "payment_method.expired",
+        api_models::enums::EventType::PaymentMethodNetworkTokenSuspended
+        | api_models::enums::EventType::PaymentMethodNetworkTokenResumed
+        | api_models::enums::EventType::PaymentMethodNetworkTokenDeleted => {
+            "payment_method.updated"
+        }
     }
 }
 
@@ -338,6 +393,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
         }
     }
 }
@@ -463,6 +463,7 @@ pub(crate) async fn fetch_raw_secrets(
         secrets,
         fallback_merchant_ids_api_key_auth: conf.fallback_merchant_ids_api_key_auth,
         locker: conf.locker,
+        vault_migration_locker: conf.vault_migration_locker,
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
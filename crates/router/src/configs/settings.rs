@@ -78,6 +78,7 @@ pub struct Settings<S: SecretState> {
     pub secrets: SecretStateContainer<Secrets, S>,
     pub fallback_merchant_ids_api_key_auth: Option<FallbackMerchantIds>,
     pub locker: Locker,
+    pub vault_migration_locker: Option<Locker>,
     pub key_manager: SecretStateContainer<KeyManagerConfig, S>,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
@@ -712,6 +713,10 @@ pub struct UserSettings {
     pub base_url: String,
     pub force_two_factor_auth: bool,
     pub force_cookies: bool,
+    pub passkey_rp_id: String,
+    pub passkey_rp_name: String,
+    pub force_passkey_for_admins: bool,
+    pub passkey_satisfies_two_factor_auth: bool,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -1013,6 +1018,10 @@ impl Settings<SecuredSecret> {
         }
         self.secrets.get_inner().validate()?;
         self.locker.validate()?;
+        self.vault_migration_locker
+            .as_ref()
+            .map(Locker::validate)
+            .transpose()?;
         self.connectors.validate("connectors")?;
 
         self.cors.validate()?;
@@ -72,6 +72,61 @@ pub const USER_BLACKLIST_PREFIX: &str = "BU_";
 
 pub const ROLE_BLACKLIST_PREFIX: &str = "BR_";
 
+/// Time after which a pending change request can no longer be approved
+pub const CHANGE_REQUEST_EXPIRY_IN_SECS: i64 = 60 * 60 * 24 * 3; // 3 days
+
+/// Interval at which analytics alert rules are evaluated when not specified
+pub const DEFAULT_ANALYTICS_ALERT_EVALUATION_INTERVAL_IN_MINS: u16 = 5;
+
+/// Longest window over which an analytics alert rule computes its metric
+pub const MAX_ANALYTICS_ALERT_EVALUATION_WINDOW_IN_MINS: u16 = 60 * 24; // 1 day
+
+/// Longest period over which the baseline of an anomaly alert rule is computed
+pub const MAX_ANALYTICS_ALERT_BASELINE_PERIOD_IN_DAYS: u16 = 30;
+
+/// Window before expiry within which saved cards are picked up by the card updater, when not specified
+pub const DEFAULT_CARD_UPDATER_EXPIRY_WINDOW_IN_DAYS: u16 = 30;
+
+/// Longest window before expiry within which saved cards can be picked up by the card updater
+pub const MAX_CARD_UPDATER_EXPIRY_WINDOW_IN_DAYS: u16 = 90;
+
+/// Interval between two runs of the card updater, when not specified
+pub const DEFAULT_CARD_UPDATER_RUN_INTERVAL_IN_HOURS: u16 = 24;
+
+/// Longest interval between two runs of the card updater
+pub const MAX_CARD_UPDATER_RUN_INTERVAL_IN_HOURS: u16 = 24 * 7; // 1 week
+
+/// Number of payment methods fetched at once by the card updater
+pub const CARD_UPDATER_BATCH_SIZE: i64 = 100;
+
+/// Number of payment methods migrated in a single run of a vault migration, when not specified
+pub const DEFAULT_VAULT_MIGRATION_BATCH_SIZE: u16 = 100;
+
+/// Largest number of payment methods that can be migrated in a single run of a vault migration
+pub const MAX_VAULT_MIGRATION_BATCH_SIZE: u16 = 1000;
+
+/// Number of vault migration entries listed at once, when not specified
+pub const DEFAULT_VAULT_MIGRATION_ENTRIES_LIST_LIMIT: u32 = 100;
+
+/// Shortest period for which customer and payment data is retained before it is redacted
+pub const MIN_DATA_RETENTION_PERIOD_IN_DAYS: u16 = 30;
+
+/// Longest period for which customer and payment data can be retained before it is redacted
+pub const MAX_DATA_RETENTION_PERIOD_IN_DAYS: u16 = 365 * 10; // 10 years
+
+/// Interval between two runs of the data retention job
+pub const DATA_RETENTION_RUN_INTERVAL_IN_HOURS: u16 = 24;
+
+/// Number of customers or payments fetched at once by the data retention job
+pub const DATA_RETENTION_BATCH_SIZE: u16 = 100;
+
+/// Error message recorded on refunds rejected without a reason
+pub const REFUND_REJECTED_BY_APPROVER: &str = "Refund rejected by approver";
+
+/// Error message recorded on payouts rejected without a reason
+#[cfg(feature = "payouts")]
+pub const PAYOUT_REJECTED_BY_APPROVER: &str = "Payout rejected by approver";
+
 #[cfg(feature = "email")]
 pub const EMAIL_TOKEN_TIME_IN_SECS: u64 = 60 * 60 * 24; // 1 day
 
@@ -82,6 +137,8 @@ pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
+pub const EMAIL_SUBJECT_ANALYTICS_ALERT: &str = "Analytics Alert";
+pub const EMAIL_SUBJECT_ANALYTICS_REPORT: &str = "Your Report is Ready";
 
 pub const ROLE_INFO_CACHE_PREFIX: &str = "CR_INFO_";
 
@@ -1,6 +1,6 @@
 use api_models::analytics::search::SearchIndex;
 
-pub const fn get_search_indexes() -> [SearchIndex; 8] {
+pub const fn get_search_indexes() -> [SearchIndex; 12] {
     [
         SearchIndex::PaymentAttempts,
         SearchIndex::PaymentIntents,
@@ -10,7 +10,11 @@ pub const fn get_search_indexes() -> [SearchIndex; 8] {
         SearchIndex::SessionizerPaymentIntents,
         SearchIndex::SessionizerRefunds,
         SearchIndex::SessionizerDisputes,
+        SearchIndex::Customers,
+        SearchIndex::Payouts,
+        SearchIndex::Mandates,
+        SearchIndex::PaymentMethods,
     ]
 }
 
-pub const SEARCH_INDEXES: [SearchIndex; 8] = get_search_indexes();
+pub const SEARCH_INDEXES: [SearchIndex; 12] = get_search_indexes();
@@ -20,9 +20,43 @@ pub const TOTP_TOLERANCE: u8 = 1;
 pub const TOTP_MAX_ATTEMPTS: u8 = 4;
 /// Number of maximum attempts user has for recovery code
 pub const RECOVERY_CODE_MAX_ATTEMPTS: u8 = 4;
+/// Number of maximum attempts user has for passkey assertion
+pub const PASSKEY_MAX_ATTEMPTS: u8 = 4;
+/// Number of random bytes in a WebAuthn challenge
+pub const PASSKEY_CHALLENGE_LENGTH: usize = 32;
+/// Time in milliseconds the browser is allowed to wait for the authenticator
+pub const PASSKEY_CEREMONY_TIMEOUT_IN_MILLIS: u64 = 5 * 60 * 1000;
+/// Credential type of every WebAuthn public key credential
+pub const PASSKEY_CREDENTIAL_TYPE: &str = "public-key";
+pub const MAX_PASSKEY_NAME_LENGTH: usize = 64;
+/// COSE algorithm identifier for ECDSA with P-256 and SHA-256
+pub const PASSKEY_ALGORITHM_ES256: i32 = -7;
+/// COSE algorithm identifier for RSASSA-PKCS1-v1_5 with SHA-256
+pub const PASSKEY_ALGORITHM_RS256: i32 = -257;
+/// Passkeys are discoverable credentials, so that sign in does not need to list the credentials
+/// of the user
+pub const PASSKEY_RESIDENT_KEY_REQUIREMENT: &str = "required";
+pub const PASSKEY_USER_VERIFICATION_REQUIREMENT: &str = "required";
+/// Attestation conveyance preference, authenticators are not restricted to certified models
+pub const PASSKEY_ATTESTATION_CONVEYANCE: &str = "none";
 /// The default number of organizations to fetch for a tenant-level user
 pub const ORG_LIST_LIMIT_FOR_TENANT: u32 = 20;
 
+pub const SCIM_TOKEN_LENGTH: usize = 64;
+/// Prefix of SCIM token ids, which are recorded as the creator of the roles SCIM grants
+pub const SCIM_TOKEN_ID_PREFIX: &str = "scim";
+pub const MAX_SCIM_TOKEN_NAME_LENGTH: usize = 64;
+/// Page size used when the identity provider does not send `count` in a SCIM list request
+pub const SCIM_DEFAULT_PAGE_SIZE: usize = 100;
+pub const SCIM_MAX_PAGE_SIZE: usize = 500;
+pub const SCIM_USER_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:User";
+pub const SCIM_GROUP_SCHEMA: &str = "urn:ietf:params:scim:schemas:core:2.0:Group";
+pub const SCIM_GROUP_ROLE_MAPPING_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:extension:hyperswitch:2.0:Group";
+pub const SCIM_LIST_RESPONSE_SCHEMA: &str = "urn:ietf:params:scim:api:messages:2.0:ListResponse";
+pub const SCIM_SERVICE_PROVIDER_CONFIG_SCHEMA: &str =
+    "urn:ietf:params:scim:schemas:core:2.0:ServiceProviderConfig";
+
 pub const MAX_PASSWORD_LENGTH: usize = 70;
 pub const MIN_PASSWORD_LENGTH: usize = 8;
 
@@ -35,6 +69,15 @@ pub const REDIS_RECOVERY_CODE_ATTEMPTS_PREFIX: &str = "RC_ATTEMPTS_";
 pub const REDIS_TOTP_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
 
+pub const REDIS_PASSKEY_PREFIX: &str = "PASSKEY_";
+pub const REDIS_PASSKEY_ATTEMPTS_PREFIX: &str = "PASSKEY_ATTEMPTS_";
+pub const REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
+pub const REDIS_PASSKEY_SIGNIN_ATTEMPTS_PREFIX: &str = "PASSKEY_SIGNIN_ATTEMPTS_";
+pub const REDIS_PASSKEY_REGISTRATION_CHALLENGE_PREFIX: &str = "PASSKEY_REG_CHALLENGE_";
+pub const REDIS_PASSKEY_AUTHENTICATION_CHALLENGE_PREFIX: &str = "PASSKEY_AUTH_CHALLENGE_";
+pub const REDIS_PASSKEY_SIGNIN_CHALLENGE_PREFIX: &str = "PASSKEY_SIGNIN_CHALLENGE_";
+pub const REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
+
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
 
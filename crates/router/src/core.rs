@@ -1,4 +1,12 @@
 pub mod admin;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_alert;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_decline_reasons;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_processing_cost;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_report;
 pub mod api_keys;
 pub mod api_locking;
 #[cfg(feature = "v1")]
@@ -9,8 +17,12 @@ pub mod blocklist;
 pub mod cache;
 pub mod card_testing_guard;
 pub mod cards_info;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod change_request;
 pub mod conditional_config;
 pub mod configs;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_fee_schedule;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -50,6 +62,8 @@ pub mod debit_routing;
 pub mod routing;
 pub mod surcharge_decision_config;
 pub mod three_ds_decision_rule;
+#[cfg(feature = "v1")]
+pub mod transaction_approval;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
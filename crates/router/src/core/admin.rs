@@ -1368,7 +1368,7 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             api_enums::Connector::Demopay => {
                 demopay::transformers::DemopayAuthType::try_from(self.auth_type)?;
                 Ok(())
-            },
+            }
 
             api_enums::Connector::Vgs => {
                 vgs::transformers::VgsAuthType::try_from(self.auth_type)?;
@@ -4058,6 +4058,8 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 .is_pre_network_tokenization_enabled
                 .unwrap_or_default(),
             merchant_category_code: self.merchant_category_code,
+            transaction_approval_policy: self.transaction_approval_policy,
+            routing_calendar_config: self.routing_calendar_config,
         }))
     }
 
@@ -4541,6 +4543,8 @@ impl ProfileUpdateBridge for api::ProfileUpdate {
                 is_iframe_redirection_enabled: self.is_iframe_redirection_enabled,
                 is_pre_network_tokenization_enabled: self.is_pre_network_tokenization_enabled,
                 merchant_category_code: self.merchant_category_code,
+                transaction_approval_policy: self.transaction_approval_policy,
+                routing_calendar_config: self.routing_calendar_config,
             },
         )))
     }
@@ -0,0 +1,728 @@
+use analytics::enums::AuthInfo;
+use api_models::analytics::{
+    alerts::{self as alerts_api, AlertCondition, AlertMetric, AlertNotificationChannel},
+    TimeRange,
+};
+use common_enums::AnalyticsAlertState;
+use common_utils::{
+    crypto::SignMessage,
+    ext_traits::{Encode, ValueExt},
+    generate_id_with_default_len, id_type,
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    headers,
+    routes::{metrics, SessionState},
+    services::{self, authentication::UserFromToken, ApplicationResponse},
+    types::{domain, storage},
+};
+
+const ANALYTICS_ALERT_TAG: &str = "ANALYTICS_ALERT";
+const ANALYTICS_ALERT_NAME: &str = "ANALYTICS_ALERT_EVALUATION";
+const ANALYTICS_ALERT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::AnalyticsAlertWorkflow;
+const ANALYTICS_ALERT_WEBHOOK_TIMEOUT_SECS: u64 = 5;
+
+pub async fn create_alert_rule(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: alerts_api::AlertRuleCreateRequest,
+) -> RouterResponse<alerts_api::AlertRuleResponse> {
+    let evaluation_interval_in_mins = req
+        .evaluation_interval_in_mins
+        .unwrap_or(consts::DEFAULT_ANALYTICS_ALERT_EVALUATION_INTERVAL_IN_MINS);
+    validate_alert_rule(
+        &req.name,
+        &req.metric,
+        &req.condition,
+        req.evaluation_window_in_mins,
+        evaluation_interval_in_mins,
+        &req.notification_channels,
+    )?;
+
+    if let Some(profile_id) = &req.profile_id {
+        let key_store = get_merchant_key_store(&state, &user_from_token.merchant_id).await?;
+        state
+            .store
+            .find_business_profile_by_merchant_id_profile_id(
+                &(&state).into(),
+                &key_store,
+                &user_from_token.merchant_id,
+                profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+    }
+
+    let now = common_utils::date_time::now();
+    let alert_rule = state
+        .store
+        .insert_analytics_alert_rule(storage::AnalyticsAlertRuleNew {
+            alert_rule_id: generate_id_with_default_len("alert_rule"),
+            org_id: user_from_token.org_id,
+            merchant_id: user_from_token.merchant_id,
+            profile_id: req.profile_id,
+            name: req.name,
+            description: req.description,
+            metric: encode_value(&req.metric)?,
+            alert_condition: encode_value(&req.condition)?,
+            evaluation_window_in_mins: i32::from(req.evaluation_window_in_mins),
+            evaluation_interval_in_mins: i32::from(evaluation_interval_in_mins),
+            notification_channels: encode_value(&req.notification_channels)?,
+            enabled: req.enabled,
+            state: AnalyticsAlertState::Ok,
+            created_by: user_from_token.user_id,
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Alert rule already exists".to_string(),
+        })?;
+
+    add_alert_evaluation_task(&state, &alert_rule).await?;
+
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn list_alert_rules(
+    state: SessionState,
+    user_from_token: UserFromToken,
+) -> RouterResponse<alerts_api::AlertRuleListResponse> {
+    let alert_rules = state
+        .store
+        .list_analytics_alert_rules_by_merchant_id(&user_from_token.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list alert rules")?
+        .into_iter()
+        .map(get_alert_rule_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(
+        alerts_api::AlertRuleListResponse {
+            count: alert_rules.len(),
+            data: alert_rules,
+        },
+    ))
+}
+
+pub async fn retrieve_alert_rule(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    alert_rule_id: String,
+) -> RouterResponse<alerts_api::AlertRuleResponse> {
+    let alert_rule = find_alert_rule(&state, &user_from_token.merchant_id, &alert_rule_id).await?;
+
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn update_alert_rule(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    alert_rule_id: String,
+    req: alerts_api::AlertRuleUpdateRequest,
+) -> RouterResponse<alerts_api::AlertRuleResponse> {
+    let existing_rule =
+        find_alert_rule(&state, &user_from_token.merchant_id, &alert_rule_id).await?;
+    let existing_response = get_alert_rule_response(existing_rule)?;
+
+    // The updated rule is validated as a whole, since limits such as the evaluation interval
+    // depend on other fields of the rule
+    validate_alert_rule(
+        req.name.as_ref().unwrap_or(&existing_response.name),
+        req.metric.as_ref().unwrap_or(&existing_response.metric),
+        req.condition
+            .as_ref()
+            .unwrap_or(&existing_response.condition),
+        req.evaluation_window_in_mins
+            .unwrap_or(existing_response.evaluation_window_in_mins),
+        req.evaluation_interval_in_mins
+            .unwrap_or(existing_response.evaluation_interval_in_mins),
+        req.notification_channels
+            .as_ref()
+            .unwrap_or(&existing_response.notification_channels),
+    )?;
+
+    let alert_rule_update = storage::AnalyticsAlertRuleUpdate::Update {
+        name: req.name,
+        description: req.description,
+        metric: req.metric.as_ref().map(encode_value).transpose()?,
+        alert_condition: req.condition.as_ref().map(encode_value).transpose()?,
+        evaluation_window_in_mins: req.evaluation_window_in_mins.map(i32::from),
+        evaluation_interval_in_mins: req.evaluation_interval_in_mins.map(i32::from),
+        notification_channels: req
+            .notification_channels
+            .as_ref()
+            .map(encode_value)
+            .transpose()?,
+        enabled: req.enabled,
+    };
+
+    let alert_rule = state
+        .store
+        .update_analytics_alert_rule(
+            &user_from_token.merchant_id,
+            &alert_rule_id,
+            alert_rule_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    get_alert_rule_response(alert_rule).map(ApplicationResponse::Json)
+}
+
+pub async fn delete_alert_rule(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    alert_rule_id: String,
+) -> RouterResponse<alerts_api::AlertRuleDeleteResponse> {
+    let deleted = state
+        .store
+        .delete_analytics_alert_rule(&user_from_token.merchant_id, &alert_rule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })?;
+
+    // The evaluation task would also finish by itself on its next run, once it finds the rule
+    // missing
+    let task_id = get_alert_evaluation_task_id(&alert_rule_id);
+    match state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&task_id)
+        .await
+    {
+        Ok(Some(process)) => {
+            let _ = state
+                .store
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    diesel_models::process_tracker::business_status::COMPLETED_BY_PT,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to finish alert evaluation task");
+                });
+        }
+        Ok(None) => {}
+        Err(error) => logger::error!(?error, "Failed to fetch alert evaluation task"),
+    }
+
+    Ok(ApplicationResponse::Json(
+        alerts_api::AlertRuleDeleteResponse {
+            alert_rule_id,
+            deleted,
+        },
+    ))
+}
+
+/// Evaluates the alert rule against the analytics data of its evaluation window, and notifies the
+/// configured channels when the rule starts or stops firing
+pub async fn evaluate_alert_rule(
+    state: &SessionState,
+    alert_rule: storage::AnalyticsAlertRule,
+) -> RouterResult<storage::AnalyticsAlertRule> {
+    let rule = get_alert_rule_response(alert_rule)?;
+
+    let auth = match &rule.profile_id {
+        Some(profile_id) => AuthInfo::ProfileLevel {
+            org_id: get_org_id(state, &rule.merchant_id).await?,
+            merchant_id: rule.merchant_id.clone(),
+            profile_ids: vec![profile_id.clone()],
+        },
+        None => AuthInfo::MerchantLevel {
+            org_id: get_org_id(state, &rule.merchant_id).await?,
+            merchant_ids: vec![rule.merchant_id.clone()],
+        },
+    };
+
+    let now = common_utils::date_time::now();
+    let window_start = now.saturating_sub(time::Duration::minutes(
+        rule.evaluation_window_in_mins.into(),
+    ));
+    let observed_value = get_metric_value(
+        state,
+        &auth,
+        &rule.metric,
+        TimeRange {
+            start_time: window_start,
+            end_time: Some(now),
+        },
+    )
+    .await?;
+
+    let (reference_value, is_firing) = match &rule.condition {
+        AlertCondition::Threshold { operator, value } => (
+            Some(*value),
+            observed_value.is_some_and(|observed| operator.compare(observed, *value)),
+        ),
+        AlertCondition::Anomaly {
+            direction,
+            factor,
+            baseline_period_in_days,
+        } => {
+            let baseline_value = get_metric_value(
+                state,
+                &auth,
+                &rule.metric,
+                TimeRange {
+                    start_time: window_start
+                        .saturating_sub(time::Duration::days((*baseline_period_in_days).into())),
+                    end_time: Some(window_start),
+                },
+            )
+            .await?
+            .map(|baseline| {
+                // Sums over the baseline period are scaled down to the length of the window, so
+                // that they are comparable with the observed value
+                if rule.metric.is_additive() {
+                    baseline * f64::from(rule.evaluation_window_in_mins)
+                        / (f64::from(*baseline_period_in_days) * 24.0 * 60.0)
+                } else {
+                    baseline
+                }
+            })
+            // An anomaly cannot be detected without a baseline to compare against
+            .filter(|baseline| *baseline > 0.0);
+
+            let reference_value = baseline_value.map(|baseline| match direction {
+                alerts_api::AnomalyDirection::Spike => baseline * factor,
+                alerts_api::AnomalyDirection::Drop => baseline / factor,
+            });
+            let is_firing =
+                observed_value
+                    .zip(reference_value)
+                    .is_some_and(|(observed, reference)| match direction {
+                        alerts_api::AnomalyDirection::Spike => observed >= reference,
+                        alerts_api::AnomalyDirection::Drop => observed <= reference,
+                    });
+            (reference_value, is_firing)
+        }
+    };
+
+    let alert_state = if is_firing {
+        AnalyticsAlertState::Firing
+    } else {
+        AnalyticsAlertState::Ok
+    };
+    let state_changed = alert_state != rule.state;
+
+    if state_changed {
+        let notification = alerts_api::AlertNotification {
+            alert_rule_id: rule.alert_rule_id.clone(),
+            name: rule.name.clone(),
+            merchant_id: rule.merchant_id.clone(),
+            profile_id: rule.profile_id.clone(),
+            state: alert_state,
+            metric: rule.metric.get_metric_name(),
+            observed_value,
+            reference_value,
+            evaluation_window_in_mins: rule.evaluation_window_in_mins,
+            evaluated_at: now,
+        };
+        notify_alert_channels(state, &rule.notification_channels, &notification).await;
+    }
+
+    state
+        .store
+        .update_analytics_alert_rule(
+            &rule.merchant_id,
+            &rule.alert_rule_id,
+            storage::AnalyticsAlertRuleUpdate::Evaluation {
+                state: alert_state,
+                last_observed_value: observed_value,
+                state_changed,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update alert rule after evaluation")
+}
+
+async fn get_metric_value(
+    state: &SessionState,
+    auth: &AuthInfo,
+    metric: &AlertMetric,
+    time_range: TimeRange,
+) -> RouterResult<Option<f64>> {
+    analytics::alerts::get_alert_metric_value(&state.pool, auth, metric, time_range)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the alert metric")
+}
+
+/// Failing to notify a channel does not stop the remaining channels from being notified, nor the
+/// state of the alert rule from being updated
+async fn notify_alert_channels(
+    state: &SessionState,
+    notification_channels: &[AlertNotificationChannel],
+    notification: &alerts_api::AlertNotification,
+) {
+    for channel in notification_channels {
+        let result = match channel {
+            AlertNotificationChannel::Webhook { url } => {
+                send_alert_webhook(state, url, notification).await
+            }
+            AlertNotificationChannel::Email { recipients } => {
+                send_alert_email(state, recipients, notification).await
+            }
+        };
+
+        if let Err(error) = result {
+            logger::error!(
+                ?error,
+                alert_rule_id = notification.alert_rule_id,
+                "Failed to send alert notification"
+            );
+        }
+    }
+}
+
+async fn send_alert_webhook(
+    state: &SessionState,
+    url: &url::Url,
+    notification: &alerts_api::AlertNotification,
+) -> RouterResult<()> {
+    let payload = notification
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode alert notification")?;
+
+    let key_store = get_merchant_key_store(state, &notification.merchant_id).await?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(&state.into(), &notification.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut request_headers = vec![(
+        headers::CONTENT_TYPE.to_string(),
+        mime::APPLICATION_JSON.essence_str().to_string().into(),
+    )];
+    if let Some(key) = merchant_account.payment_response_hash_key.as_ref() {
+        let signature = common_utils::crypto::HmacSha512
+            .sign_message(key.as_bytes(), payload.as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to sign alert notification")?;
+        request_headers.push((
+            headers::X_WEBHOOK_SIGNATURE.to_string(),
+            hex::encode(signature).into(),
+        ));
+    }
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(url.as_str())
+        .attach_default_headers()
+        .headers(request_headers)
+        .set_body(RequestContent::RawBytes(payload.into_bytes()))
+        .build();
+
+    let response = state
+        .api_client
+        .send_request(
+            state,
+            request,
+            Some(ANALYTICS_ALERT_WEBHOOK_TIMEOUT_SECS),
+            false,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send alert webhook")?;
+
+    if !response.status().is_success() {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+            format!("Alert webhook responded with status {}", response.status()),
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_alert_email(
+    state: &SessionState,
+    recipients: &[common_utils::pii::Email],
+    notification: &alerts_api::AlertNotification,
+) -> RouterResult<()> {
+    use crate::{
+        services::email::types::AnalyticsAlertNotification, types::domain::UserEmail,
+        utils::user as user_utils,
+    };
+
+    for recipient in recipients {
+        let email_contents = AnalyticsAlertNotification {
+            recipient_email: UserEmail::from_pii_email(recipient.clone())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to convert recipient's email to UserEmail")?,
+            subject: consts::EMAIL_SUBJECT_ANALYTICS_ALERT,
+            notification: notification.clone(),
+        };
+
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send alert email")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_alert_email(
+    _state: &SessionState,
+    _recipients: &[common_utils::pii::Email],
+    _notification: &alerts_api::AlertNotification,
+) -> RouterResult<()> {
+    Err(report!(errors::ApiErrorResponse::NotSupported {
+        message: "Email notifications are not supported when the email feature is disabled"
+            .to_string(),
+    }))
+}
+
+fn validate_alert_rule(
+    name: &str,
+    metric: &AlertMetric,
+    condition: &AlertCondition,
+    evaluation_window_in_mins: u16,
+    evaluation_interval_in_mins: u16,
+    notification_channels: &[AlertNotificationChannel],
+) -> RouterResult<()> {
+    let invalid_request = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    };
+
+    if name.trim().is_empty() || name.len() > 64 {
+        return invalid_request("`name` must be between 1 and 64 characters long");
+    }
+
+    if !metric.is_supported() {
+        return invalid_request(&format!(
+            "Metric `{}` cannot be used in an alert rule",
+            metric.get_metric_name()
+        ));
+    }
+
+    if !(1..=consts::MAX_ANALYTICS_ALERT_EVALUATION_WINDOW_IN_MINS)
+        .contains(&evaluation_window_in_mins)
+    {
+        return invalid_request(&format!(
+            "`evaluation_window_in_mins` must be between 1 and {}",
+            consts::MAX_ANALYTICS_ALERT_EVALUATION_WINDOW_IN_MINS
+        ));
+    }
+
+    if !(1..=consts::MAX_ANALYTICS_ALERT_EVALUATION_WINDOW_IN_MINS)
+        .contains(&evaluation_interval_in_mins)
+    {
+        return invalid_request(&format!(
+            "`evaluation_interval_in_mins` must be between 1 and {}",
+            consts::MAX_ANALYTICS_ALERT_EVALUATION_WINDOW_IN_MINS
+        ));
+    }
+
+    match condition {
+        AlertCondition::Threshold { value, .. } => {
+            if !value.is_finite() {
+                return invalid_request("Threshold `value` must be a finite number");
+            }
+        }
+        AlertCondition::Anomaly {
+            factor,
+            baseline_period_in_days,
+            ..
+        } => {
+            if !factor.is_finite() || *factor <= 1.0 {
+                return invalid_request("Anomaly `factor` must be a number greater than 1");
+            }
+            if !(1..=consts::MAX_ANALYTICS_ALERT_BASELINE_PERIOD_IN_DAYS)
+                .contains(baseline_period_in_days)
+            {
+                return invalid_request(&format!(
+                    "`baseline_period_in_days` must be between 1 and {}",
+                    consts::MAX_ANALYTICS_ALERT_BASELINE_PERIOD_IN_DAYS
+                ));
+            }
+        }
+    }
+
+    if notification_channels.is_empty() {
+        return invalid_request("At least one notification channel must be provided");
+    }
+    for channel in notification_channels {
+        match channel {
+            AlertNotificationChannel::Webhook { url } => {
+                if url.scheme() != "https" {
+                    return invalid_request("Webhook notification channels must use HTTPS");
+                }
+            }
+            AlertNotificationChannel::Email { recipients } => {
+                if recipients.is_empty() {
+                    return invalid_request(
+                        "Email notification channels must have at least one recipient",
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn add_alert_evaluation_task(
+    state: &SessionState,
+    alert_rule: &storage::AnalyticsAlertRule,
+) -> RouterResult<()> {
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::minutes(
+        alert_rule.evaluation_interval_in_mins.into(),
+    ));
+    let tracking_data = storage::AnalyticsAlertTrackingData {
+        merchant_id: alert_rule.merchant_id.clone(),
+        alert_rule_id: alert_rule.alert_rule_id.clone(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_alert_evaluation_task_id(&alert_rule.alert_rule_id),
+        ANALYTICS_ALERT_NAME,
+        ANALYTICS_ALERT_RUNNER,
+        [ANALYTICS_ALERT_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct alert evaluation process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting alert evaluation task to process_tracker: {}",
+                alert_rule.alert_rule_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "AnalyticsAlert")),
+    );
+
+    Ok(())
+}
+
+fn get_alert_evaluation_task_id(alert_rule_id: &str) -> String {
+    format!("{ANALYTICS_ALERT_RUNNER}_{ANALYTICS_ALERT_NAME}_{alert_rule_id}")
+}
+
+async fn find_alert_rule(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    alert_rule_id: &str,
+) -> RouterResult<storage::AnalyticsAlertRule> {
+    state
+        .store
+        .find_analytics_alert_rule_by_merchant_id_alert_rule_id(merchant_id, alert_rule_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Alert rule not found".to_string(),
+        })
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<domain::MerchantKeyStore> {
+    state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+async fn get_org_id(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<id_type::OrganizationId> {
+    let key_store = get_merchant_key_store(state, merchant_id).await?;
+    state
+        .store
+        .find_merchant_account_by_merchant_id(&state.into(), merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .map(|merchant_account| merchant_account.get_org_id().clone())
+}
+
+fn encode_value<T: serde::Serialize>(value: &T) -> RouterResult<serde_json::Value> {
+    value
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode alert rule")
+}
+
+fn get_alert_rule_response(
+    alert_rule: storage::AnalyticsAlertRule,
+) -> RouterResult<alerts_api::AlertRuleResponse> {
+    let parse_error = || {
+        report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse stored alert rule")
+    };
+
+    Ok(alerts_api::AlertRuleResponse {
+        metric: alert_rule
+            .metric
+            .parse_value("AlertMetric")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        condition: alert_rule
+            .alert_condition
+            .parse_value("AlertCondition")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        notification_channels: alert_rule
+            .notification_channels
+            .parse_value("AlertNotificationChannels")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        evaluation_window_in_mins: u16::try_from(alert_rule.evaluation_window_in_mins)
+            .map_err(|_| parse_error())?,
+        evaluation_interval_in_mins: u16::try_from(alert_rule.evaluation_interval_in_mins)
+            .map_err(|_| parse_error())?,
+        alert_rule_id: alert_rule.alert_rule_id,
+        merchant_id: alert_rule.merchant_id,
+        profile_id: alert_rule.profile_id,
+        name: alert_rule.name,
+        description: alert_rule.description,
+        enabled: alert_rule.enabled,
+        state: alert_rule.state,
+        last_observed_value: alert_rule.last_observed_value,
+        last_evaluated_at: alert_rule.last_evaluated_at,
+        last_state_changed_at: alert_rule.last_state_changed_at,
+        created_at: alert_rule.created_at,
+        last_modified_at: alert_rule.last_modified_at,
+    })
+}
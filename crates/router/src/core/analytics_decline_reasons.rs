@@ -0,0 +1,197 @@
+use std::collections::{HashMap, HashSet};
+
+use analytics::{
+    enums::AuthInfo, errors::AnalyticsError, payments::decline_reasons::DeclineReasonBucketRow,
+    utils,
+};
+use api_models::{
+    analytics::{
+        decline_reasons::{DeclineReasonBucket, DeclineReasonDimensions},
+        AnalyticsMetadata, GetDeclineReasonsRequest, MetricsResponse,
+    },
+    gsm::GsmDecision,
+};
+use common_enums::ErrorCategory;
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{consts, routes::SessionState, services::ApplicationResponse, types::storage};
+
+const GSM_SUB_FLOW: &str = "sub_flow";
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct GsmKey {
+    connector: String,
+    code: String,
+    message: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct DeclineReasonKey {
+    unified_code: Option<String>,
+    unified_message: Option<String>,
+    error_category: Option<ErrorCategory>,
+    connector: Option<String>,
+    card_network: Option<String>,
+    card_issuer: Option<String>,
+    card_bin: Option<String>,
+    start_time: PrimitiveDateTime,
+}
+
+/// Aggregates the failed payment attempts by the unified error their connector error maps to,
+/// using the GSM rules configured for the connector errors to categorise the failures and to
+/// find the failures which are retryable.
+///
+/// This is not a dimension of the payment metrics, since the GSM rules are stored in Postgres
+/// while the payment attempts are aggregated in Clickhouse, so the unified error of attempts
+/// which were not assigned one at the time of the payment can only be found after the query.
+pub async fn get_decline_reasons(
+    state: &SessionState,
+    auth: &AuthInfo,
+    req: GetDeclineReasonsRequest,
+) -> CustomResult<ApplicationResponse<MetricsResponse<DeclineReasonBucket>>, AnalyticsError> {
+    let current_time_range = req.time_range;
+    let group_by_names = req.group_by_names.clone();
+    let rows =
+        analytics::payments::decline_reasons::get_decline_reasons(&state.pool, auth, req).await?;
+
+    let gsm_rules = find_gsm_rules(state, &rows).await?;
+    let mut buckets: HashMap<DeclineReasonKey, DeclineReasonBucket> = HashMap::new();
+    let mut failures_per_time_bucket: HashMap<PrimitiveDateTime, u64> = HashMap::new();
+
+    for DeclineReasonBucketRow { row, time_range } in rows {
+        let gsm_rule = row.connector.as_ref().and_then(|connector| {
+            gsm_rules.get(&GsmKey {
+                connector: connector.clone(),
+                code: row.error_code.clone().unwrap_or_default(),
+                message: row.error_message.clone().unwrap_or_default(),
+            })
+        });
+
+        let is_retryable = gsm_rule.is_some_and(|gsm_rule| {
+            gsm_rule
+                .decision
+                .parse::<GsmDecision>()
+                .is_ok_and(|decision| decision == GsmDecision::Retry)
+        });
+        let get_dimension = |dimension: DeclineReasonDimensions, value: &Option<String>| {
+            group_by_names
+                .contains(&dimension)
+                .then(|| value.clone())
+                .flatten()
+        };
+
+        let key = DeclineReasonKey {
+            unified_code: row.unified_code.clone().or_else(|| {
+                gsm_rule
+                    .as_ref()
+                    .and_then(|gsm_rule| gsm_rule.unified_code.clone())
+            }),
+            unified_message: row.unified_message.clone().or_else(|| {
+                gsm_rule
+                    .as_ref()
+                    .and_then(|gsm_rule| gsm_rule.unified_message.clone())
+            }),
+            error_category: gsm_rule.and_then(|gsm_rule| gsm_rule.error_category),
+            connector: get_dimension(DeclineReasonDimensions::Connector, &row.connector),
+            card_network: get_dimension(DeclineReasonDimensions::CardNetwork, &row.card_network),
+            card_issuer: get_dimension(DeclineReasonDimensions::CardIssuer, &row.card_issuer),
+            card_bin: get_dimension(DeclineReasonDimensions::CardBin, &row.card_bin),
+            start_time: time_range.start_time,
+        };
+
+        *failures_per_time_bucket
+            .entry(time_range.start_time)
+            .or_default() += row.count;
+
+        let bucket = buckets
+            .entry(key.clone())
+            .or_insert_with(|| DeclineReasonBucket {
+                unified_code: key.unified_code,
+                unified_message: key.unified_message,
+                error_category: key.error_category,
+                connector: key.connector,
+                card_network: key.card_network,
+                card_issuer: key.card_issuer,
+                card_bin: key.card_bin,
+                failure_count: 0,
+                retryable_failure_count: 0,
+                retryable_failure_rate: None,
+                failure_share: None,
+                start_time: key.start_time,
+                time_bucket: time_range,
+            });
+        bucket.failure_count += row.count;
+        if is_retryable {
+            bucket.retryable_failure_count += row.count;
+        }
+    }
+
+    let mut query_data: Vec<DeclineReasonBucket> = buckets
+        .into_values()
+        .map(|mut bucket| {
+            bucket.retryable_failure_rate =
+                utils::get_rate(bucket.retryable_failure_count, bucket.failure_count);
+            bucket.failure_share = failures_per_time_bucket
+                .get(&bucket.start_time)
+                .and_then(|total| utils::get_rate(bucket.failure_count, *total));
+            bucket
+        })
+        .collect();
+    query_data.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then(b.failure_count.cmp(&a.failure_count))
+    });
+
+    Ok(ApplicationResponse::Json(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata { current_time_range }],
+    }))
+}
+
+/// Loads the GSM rules of all the connectors present in the rows in a single query, keyed by the
+/// connector error they map
+async fn find_gsm_rules(
+    state: &SessionState,
+    rows: &[DeclineReasonBucketRow],
+) -> CustomResult<HashMap<GsmKey, storage::gsm::GatewayStatusMap>, AnalyticsError> {
+    let connectors = rows
+        .iter()
+        .filter_map(|bucket_row| bucket_row.row.connector.clone())
+        .collect::<HashSet<_>>();
+    if connectors.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let gsm_rules = state
+        .store
+        .find_gsm_rules_by_connectors(
+            connectors.into_iter().collect(),
+            consts::AUTHORIZE_FLOW_STR.to_string(),
+            GSM_SUB_FLOW.to_string(),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch GSM rules for decline reasons")?;
+    logger::debug!(
+        gsm_rules_count = gsm_rules.len(),
+        "Fetched GSM rules for decline reasons"
+    );
+
+    Ok(gsm_rules
+        .into_iter()
+        .map(|gsm_rule| {
+            (
+                GsmKey {
+                    connector: gsm_rule.connector.clone(),
+                    code: gsm_rule.code.clone(),
+                    message: gsm_rule.message.clone(),
+                },
+                gsm_rule,
+            )
+        })
+        .collect())
+}
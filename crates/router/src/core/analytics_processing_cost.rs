@@ -0,0 +1,196 @@
+use std::{collections::HashMap, str::FromStr};
+
+use analytics::{
+    enums::AuthInfo, errors::AnalyticsError, payments::processing_cost::ProcessingCostBucketRow,
+};
+use api_models::{
+    analytics::{
+        processing_cost::{ProcessingCostBucket, ProcessingCostDimensions},
+        AnalyticsMetadata, GetProcessingCostRequest, MetricsResponse,
+    },
+    connector_fee_schedule::{ConnectorFeeRule, PaymentFeeAttributes},
+};
+use common_enums::{CardNetwork, Currency, PaymentMethod, PaymentMethodType};
+use common_utils::{errors::CustomResult, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{core::connector_fee_schedule, routes::SessionState, services::ApplicationResponse};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct ProcessingCostKey {
+    connector: Option<String>,
+    merchant_connector_id: Option<String>,
+    payment_method: Option<String>,
+    payment_method_type: Option<String>,
+    card_network: Option<String>,
+    currency: Option<Currency>,
+    start_time: PrimitiveDateTime,
+}
+
+/// Estimates the cost of processing the charged payments from the fee schedules of the merchant
+/// connector accounts they were processed through, and aggregates the cost over the requested
+/// dimensions. Payments processed through a connector without a fee schedule, or which do not
+/// match any of its fee rules, are counted but not costed.
+pub async fn get_processing_cost(
+    state: &SessionState,
+    auth: &AuthInfo,
+    req: GetProcessingCostRequest,
+) -> CustomResult<ApplicationResponse<MetricsResponse<ProcessingCostBucket>>, AnalyticsError> {
+    let current_time_range = req.time_range;
+    let group_by_names = req.group_by_names.clone();
+    let rows =
+        analytics::payments::processing_cost::get_processing_cost(&state.pool, auth, req).await?;
+
+    let mut fee_rules_by_merchant: HashMap<
+        String,
+        HashMap<id_type::MerchantConnectorAccountId, Vec<ConnectorFeeRule>>,
+    > = HashMap::new();
+    let mut buckets: HashMap<ProcessingCostKey, ProcessingCostBucket> = HashMap::new();
+    let mut costed_amounts: HashMap<ProcessingCostKey, i64> = HashMap::new();
+
+    for ProcessingCostBucketRow { row, time_range } in rows {
+        if !fee_rules_by_merchant.contains_key(&row.merchant_id) {
+            let merchant_id = id_type::MerchantId::wrap(row.merchant_id.clone())
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Invalid merchant id found in payment attempts")?;
+            let fee_rules =
+                connector_fee_schedule::get_fee_rules_by_merchant_connector_id(state, &merchant_id)
+                    .await
+                    .change_context(AnalyticsError::UnknownError)?;
+            fee_rules_by_merchant.insert(row.merchant_id.clone(), fee_rules);
+        }
+
+        let currency = row
+            .currency
+            .as_deref()
+            .and_then(|currency| Currency::from_str(currency).ok());
+        let card_network = row
+            .card_network
+            .as_deref()
+            .map(|card_network| card_network.trim_matches('"').to_string());
+        let attributes = PaymentFeeAttributes {
+            payment_method: row
+                .payment_method
+                .as_deref()
+                .and_then(|payment_method| PaymentMethod::from_str(payment_method).ok()),
+            payment_method_type: row.payment_method_type.as_deref().and_then(
+                |payment_method_type| PaymentMethodType::from_str(payment_method_type).ok(),
+            ),
+            card_network: card_network.clone().and_then(|card_network| {
+                serde_json::from_value::<CardNetwork>(serde_json::Value::String(card_network)).ok()
+            }),
+            card_issuing_country: row.card_issuing_country.clone(),
+            currency,
+        };
+        let total_amount = row.total_amount.unwrap_or_default();
+        let payment_count = i64::try_from(row.payment_count)
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Payment count does not fit in i64")?;
+
+        let fee_rules = row
+            .merchant_connector_id
+            .as_ref()
+            .and_then(|merchant_connector_id| {
+                id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.clone()).ok()
+            })
+            .and_then(|merchant_connector_id| {
+                fee_rules_by_merchant
+                    .get(&row.merchant_id)
+                    .and_then(|fee_rules| fee_rules.get(&merchant_connector_id))
+            });
+        let estimated_cost = fee_rules
+            .map(|fee_rules| {
+                connector_fee_schedule::estimate_processing_cost(
+                    fee_rules,
+                    &attributes,
+                    MinorUnit::new(total_amount),
+                    payment_count,
+                )
+            })
+            .transpose()
+            .change_context(AnalyticsError::UnknownError)?
+            .flatten();
+
+        let get_dimension = |dimension: ProcessingCostDimensions, value: Option<String>| {
+            group_by_names
+                .contains(&dimension)
+                .then_some(value)
+                .flatten()
+        };
+        let key = ProcessingCostKey {
+            connector: get_dimension(ProcessingCostDimensions::Connector, row.connector),
+            merchant_connector_id: get_dimension(
+                ProcessingCostDimensions::MerchantConnectorId,
+                row.merchant_connector_id,
+            ),
+            payment_method: get_dimension(
+                ProcessingCostDimensions::PaymentMethod,
+                row.payment_method,
+            ),
+            payment_method_type: get_dimension(
+                ProcessingCostDimensions::PaymentMethodType,
+                row.payment_method_type,
+            ),
+            card_network: get_dimension(ProcessingCostDimensions::CardNetwork, card_network),
+            currency,
+            start_time: time_range.start_time,
+        };
+
+        let bucket = buckets.entry(key.clone()).or_insert_with(|| {
+            let key = key.clone();
+            ProcessingCostBucket {
+                connector: key.connector,
+                merchant_connector_id: key.merchant_connector_id,
+                payment_method: key.payment_method,
+                payment_method_type: key.payment_method_type,
+                card_network: key.card_network,
+                currency: key.currency,
+                payment_count: 0,
+                processed_amount: 0,
+                costed_payment_count: 0,
+                estimated_cost: 0,
+                average_cost_per_payment: None,
+                effective_rate: None,
+                start_time: key.start_time,
+                time_bucket: time_range,
+            }
+        });
+        bucket.payment_count += row.payment_count;
+        bucket.processed_amount += total_amount;
+        if let Some(estimated_cost) = estimated_cost {
+            bucket.costed_payment_count += row.payment_count;
+            bucket.estimated_cost += estimated_cost.get_amount_as_i64();
+            *costed_amounts.entry(key).or_default() += total_amount;
+        }
+    }
+
+    let mut query_data: Vec<ProcessingCostBucket> = buckets
+        .into_iter()
+        .map(|(key, mut bucket)| {
+            bucket.average_cost_per_payment = i64::try_from(bucket.costed_payment_count)
+                .ok()
+                .and_then(|count| get_ratio(bucket.estimated_cost, count));
+            bucket.effective_rate = costed_amounts
+                .get(&key)
+                .and_then(|costed_amount| get_ratio(bucket.estimated_cost, *costed_amount))
+                .map(|ratio| ratio * 100.0);
+            bucket
+        })
+        .collect();
+    query_data.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then(b.estimated_cost.cmp(&a.estimated_cost))
+    });
+
+    Ok(ApplicationResponse::Json(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata { current_time_range }],
+    }))
+}
+
+#[allow(clippy::as_conversions)]
+fn get_ratio(numerator: i64, denominator: i64) -> Option<f64> {
+    (denominator != 0).then(|| numerator as f64 / denominator as f64)
+}
@@ -0,0 +1,288 @@
+use analytics::{
+    errors::AnalyticsError, lambda_utils::invoke_lambda, reports::ReportFileFormat, ReportGenerator,
+};
+use api_models::analytics::{GenerateReportRequest, GenerateReportResponse, ReportType};
+use common_enums::EntityType;
+use common_utils::{
+    errors::CustomResult, ext_traits::ValueExt, generate_id_with_default_len,
+    types::authentication::AuthInfo,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::{metrics, SessionState},
+    services::{
+        authentication::UserFromToken, authorization::roles::RoleInfo, ApplicationResponse,
+    },
+    types::storage,
+};
+
+const ANALYTICS_REPORT_TAG: &str = "ANALYTICS_REPORT";
+const ANALYTICS_REPORT_NAME: &str = "ANALYTICS_REPORT_GENERATION";
+const ANALYTICS_REPORT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::AnalyticsReportWorkflow;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct AnalyticsReportTrackingData {
+    pub report_id: String,
+    pub report_type: ReportType,
+    pub file_format: ReportFileFormat,
+    pub request: GenerateReportRequest,
+}
+
+/// Triggers the generation of a report, either through the configured AWS Lambda function, or
+/// by adding a report generation task for the scheduler
+pub async fn generate_report(
+    state: &SessionState,
+    report_type: ReportType,
+    req: GenerateReportRequest,
+) -> CustomResult<ApplicationResponse<GenerateReportResponse>, AnalyticsError> {
+    let report_config = &state.conf.report_download_config;
+
+    match report_config.generator {
+        ReportGenerator::Lambda => {
+            let function_name = match report_type {
+                ReportType::Payment => &report_config.payment_function,
+                ReportType::Refund => &report_config.refund_function,
+                ReportType::Dispute => &report_config.dispute_function,
+                ReportType::Authentication => &report_config.authentication_function,
+            };
+            let json_bytes = serde_json::to_vec(&req).map_err(|_| AnalyticsError::UnknownError)?;
+            invoke_lambda(function_name, &report_config.region, &json_bytes).await?;
+
+            Ok(ApplicationResponse::Json(GenerateReportResponse {
+                report_id: None,
+            }))
+        }
+        ReportGenerator::Scheduler => {
+            let report_id = generate_id_with_default_len("report");
+            let tracking_data = AnalyticsReportTrackingData {
+                report_id: report_id.clone(),
+                report_type,
+                file_format: report_config.file_format,
+                request: req,
+            };
+
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                get_report_task_id(&report_id),
+                ANALYTICS_REPORT_NAME,
+                ANALYTICS_REPORT_RUNNER,
+                [ANALYTICS_REPORT_TAG],
+                tracking_data,
+                None,
+                common_utils::date_time::now(),
+                common_types::consts::API_VERSION,
+            )
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable("Failed to construct report generation process tracker task")?;
+
+            state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to insert report generation task to process_tracker")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "AnalyticsReport")),
+            );
+
+            Ok(ApplicationResponse::Json(GenerateReportResponse {
+                report_id: Some(report_id),
+            }))
+        }
+    }
+}
+
+/// Generates the report file, stores it in the file storage and notifies the recipients of the
+/// report that it can be downloaded
+pub async fn generate_and_store_report(
+    state: &SessionState,
+    tracking_data: &AnalyticsReportTrackingData,
+) -> RouterResult<()> {
+    let report_file = analytics::reports::generate_report(
+        &state.pool,
+        tracking_data.report_type,
+        &tracking_data.request.auth,
+        &tracking_data.request.request.time_range,
+        tracking_data.file_format,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to generate report")?;
+
+    state
+        .file_storage_client
+        .upload_file(
+            &get_report_file_key(&tracking_data.report_id, tracking_data.file_format),
+            report_file,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store report file")?;
+
+    #[cfg(feature = "email")]
+    send_report_ready_emails(state, tracking_data).await;
+
+    Ok(())
+}
+
+#[cfg(feature = "email")]
+async fn send_report_ready_emails(
+    state: &SessionState,
+    tracking_data: &AnalyticsReportTrackingData,
+) {
+    use router_env::logger;
+
+    use crate::{
+        consts, services::email::types::AnalyticsReportReady, types::domain::UserEmail,
+        utils::user as user_utils,
+    };
+
+    let download_url = format!(
+        "{}/analytics/v1/report/{}/download",
+        state.base_url, tracking_data.report_id
+    );
+    let time_range = &tracking_data.request.request.time_range;
+    let time_range = format!(
+        "{} - {}",
+        time_range.start_time,
+        time_range
+            .end_time
+            .map(|end_time| end_time.to_string())
+            .unwrap_or_else(|| "now".to_string())
+    );
+
+    let recipients = std::iter::once(&tracking_data.request.email)
+        .chain(tracking_data.request.request.emails.iter().flatten());
+
+    for recipient in recipients {
+        let recipient_email = match UserEmail::new(recipient.clone()) {
+            Ok(recipient_email) => recipient_email,
+            Err(error) => {
+                logger::error!(?error, "Invalid report recipient email");
+                continue;
+            }
+        };
+        let email_contents = AnalyticsReportReady {
+            recipient_email,
+            subject: consts::EMAIL_SUBJECT_ANALYTICS_REPORT,
+            report_type: tracking_data.report_type,
+            time_range: time_range.clone(),
+            download_url: download_url.clone(),
+        };
+
+        let _ = state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                user_utils::get_base_url(state),
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to send report ready email"));
+    }
+}
+
+pub async fn download_report(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    report_id: String,
+) -> RouterResponse<()> {
+    let report_not_found = || errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Report not found".to_string(),
+    };
+
+    let process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_report_task_id(&report_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch report generation task")?
+        .ok_or_else(|| report!(report_not_found()))?;
+    let tracking_data: AnalyticsReportTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("AnalyticsReportTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let role_info = RoleInfo::from_role_id_org_id_tenant_id(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.org_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch role info")?;
+
+    // The report can only be downloaded by users who have access to all of the data in it
+    let entity_type = role_info.get_entity_type();
+    let has_access = match &tracking_data.request.auth {
+        AuthInfo::OrgLevel { org_id } => {
+            *org_id == user_from_token.org_id && entity_type >= EntityType::Organization
+        }
+        AuthInfo::MerchantLevel {
+            org_id,
+            merchant_ids,
+        } => {
+            *org_id == user_from_token.org_id
+                && merchant_ids.contains(&user_from_token.merchant_id)
+                && entity_type >= EntityType::Merchant
+        }
+        AuthInfo::ProfileLevel {
+            org_id,
+            merchant_id,
+            profile_ids,
+        } => {
+            *org_id == user_from_token.org_id
+                && *merchant_id == user_from_token.merchant_id
+                && profile_ids.contains(&user_from_token.profile_id)
+        }
+    };
+    if !has_access {
+        return Err(report!(report_not_found()));
+    }
+
+    if process.business_status != business_status::COMPLETED_BY_PT {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Report is not ready yet".to_string(),
+        }));
+    }
+
+    let report_file = state
+        .file_storage_client
+        .retrieve_file(&get_report_file_key(
+            &tracking_data.report_id,
+            tracking_data.file_format,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Failed to retrieve report file")?;
+    let content_type = tracking_data
+        .file_format
+        .get_content_type()
+        .parse::<mime::Mime>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse report content type")?;
+
+    Ok(ApplicationResponse::FileData((report_file, content_type)))
+}
+
+fn get_report_task_id(report_id: &str) -> String {
+    format!("{ANALYTICS_REPORT_RUNNER}_{ANALYTICS_REPORT_NAME}_{report_id}")
+}
+
+fn get_report_file_key(report_id: &str, file_format: ReportFileFormat) -> String {
+    format!(
+        "analytics_reports/{report_id}.{}",
+        file_format.get_file_extension()
+    )
+}
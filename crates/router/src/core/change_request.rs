@@ -0,0 +1,694 @@
+use api_models::change_request::{self as change_request_api, ChangeRequestPayload};
+use common_enums::{ChangeRequestStatus, EntityType, TransactionType};
+use common_utils::{
+    ext_traits::ValueExt, generate_id_with_default_len, id_type, type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts,
+    core::{
+        admin, blocklist, conditional_config,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        routing, surcharge_decision_config,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::{
+        authentication::UserFromToken,
+        authorization::{self, permissions::Permission, roles::RoleInfo},
+        ApplicationResponse,
+    },
+    types::{domain, storage},
+};
+
+pub async fn get_change_approval_settings(
+    state: SessionState,
+    user_from_token: UserFromToken,
+) -> RouterResponse<change_request_api::ChangeApprovalSettings> {
+    let is_enabled =
+        authorization::is_change_approval_enabled(&*state.store, &user_from_token.org_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        change_request_api::ChangeApprovalSettings { is_enabled },
+    ))
+}
+
+pub async fn update_change_approval_settings(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: change_request_api::ChangeApprovalSettings,
+    req_state: ReqState,
+) -> RouterResponse<change_request_api::ChangeApprovalSettings> {
+    // Disabling change approval would let an organization admin bypass the review of their own
+    // changes, and therefore requires a permission above the organization
+    if !req.is_enabled {
+        let role_info = get_role_info(&state, &user_from_token).await?;
+        authorization::check_permission(
+            Permission::TenantAccountWrite,
+            &role_info,
+            &user_from_token.profile_id,
+        )?;
+    }
+
+    let key = user_from_token.org_id.get_change_approval_enabled_key();
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(_) => {
+            state
+                .store
+                .update_config_by_key(
+                    &key,
+                    configs::ConfigUpdate::Update {
+                        config: Some(req.is_enabled.to_string()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update change approval config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            state
+                .store
+                .insert_config(configs::ConfigNew {
+                    key,
+                    config: req.is_enabled.to_string(),
+                })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert change approval config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch change approval config");
+        }
+    }
+
+    // Disabling change approval lifts the review of every sensitive change of the organization,
+    // and the settings are therefore audited like the change requests themselves
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ChangeApprovalSettingsUpdated {
+                org_id: user_from_token.org_id,
+                is_enabled: req.is_enabled,
+                updated_by: user_from_token.user_id,
+            },
+        ))
+        .emit();
+
+    Ok(ApplicationResponse::Json(
+        change_request_api::ChangeApprovalSettings {
+            is_enabled: req.is_enabled,
+        },
+    ))
+}
+
+pub async fn create_change_request(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: change_request_api::CreateChangeRequest,
+    req_state: ReqState,
+) -> RouterResponse<change_request_api::ChangeRequestResponse> {
+    let role_info = get_role_info(&state, &user_from_token).await?;
+    authorization::check_permission(
+        get_required_permission(&req.change),
+        &role_info,
+        &user_from_token.profile_id,
+    )?;
+
+    let key_store = get_merchant_key_store(&state, &user_from_token.merchant_id).await?;
+    let request_payload = encrypt_change(&state, &key_store, &req.change).await?;
+
+    let now = common_utils::date_time::now();
+    let change_request = state
+        .store
+        .insert_change_request(storage::ChangeRequestNew {
+            change_request_id: generate_id_with_default_len("change_req"),
+            org_id: user_from_token.org_id,
+            merchant_id: user_from_token.merchant_id,
+            profile_id: Some(user_from_token.profile_id),
+            entity: req.change.get_entity(),
+            request_payload,
+            description: req.description,
+            status: ChangeRequestStatus::Pending,
+            requested_by: user_from_token.user_id,
+            created_at: now,
+            expires_at: now.saturating_add(time::Duration::seconds(
+                consts::CHANGE_REQUEST_EXPIRY_IN_SECS,
+            )),
+            last_modified_at: now,
+        })
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "Change request already exists".to_string(),
+        })?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ChangeRequestCreated {
+            change_request_id: change_request.change_request_id.clone(),
+            entity: change_request.entity,
+            requested_by: change_request.requested_by.clone(),
+        }))
+        .emit();
+
+    Ok(ApplicationResponse::Json(get_change_request_response(
+        change_request,
+        &req.change,
+    )?))
+}
+
+pub async fn list_change_requests(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    query: change_request_api::ListChangeRequestsQuery,
+) -> RouterResponse<Vec<change_request_api::ChangeRequestResponse>> {
+    expire_pending_change_requests(&state, &user_from_token.merchant_id).await?;
+
+    let role_info = get_role_info(&state, &user_from_token).await?;
+    let key_store = get_merchant_key_store(&state, &user_from_token.merchant_id).await?;
+    let change_requests = state
+        .store
+        .list_change_requests_by_merchant_id(
+            &user_from_token.merchant_id,
+            query.status,
+            query.limit.map(i64::from),
+            query.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list change requests")?;
+
+    let mut response = Vec::with_capacity(change_requests.len());
+    for change_request in change_requests
+        .into_iter()
+        .filter(|change_request| is_visible_to_user(change_request, &role_info, &user_from_token))
+    {
+        let change = decrypt_change(&state, &key_store, &change_request).await?;
+        response.push(get_change_request_response(change_request, &change)?);
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn get_change_request(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    change_request_id: String,
+) -> RouterResponse<change_request_api::ChangeRequestResponse> {
+    expire_pending_change_requests(&state, &user_from_token.merchant_id).await?;
+
+    let role_info = get_role_info(&state, &user_from_token).await?;
+    let change_request = find_change_request(&state, &user_from_token, &change_request_id).await?;
+    if !is_visible_to_user(&change_request, &role_info, &user_from_token) {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Change request not found".to_string(),
+        }));
+    }
+
+    let key_store = get_merchant_key_store(&state, &user_from_token.merchant_id).await?;
+    let change = decrypt_change(&state, &key_store, &change_request).await?;
+
+    Ok(ApplicationResponse::Json(get_change_request_response(
+        change_request,
+        &change,
+    )?))
+}
+
+pub async fn approve_change_request(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    change_request_id: String,
+    req: change_request_api::ChangeRequestDecisionRequest,
+    req_state: ReqState,
+) -> RouterResponse<change_request_api::ChangeRequestResponse> {
+    let (change_request, change) =
+        get_change_request_for_decision(&state, &user_from_token, &change_request_id).await?;
+
+    // Claim the change request before applying it, so that only one of several concurrent
+    // approvals applies the change
+    let change_request = update_change_request_with_status(
+        &state,
+        &change_request,
+        ChangeRequestStatus::Pending,
+        storage::ChangeRequestUpdate::Decision {
+            status: ChangeRequestStatus::Approving,
+            decided_by: user_from_token.user_id,
+            decision_reason: req.reason,
+            error_message: None,
+        },
+    )
+    .await?;
+
+    let error_message = apply_change(&state, &change_request, change.clone())
+        .await
+        .err()
+        .map(|error| {
+            logger::error!(?error, "Failed to apply approved change request");
+            error.current_context().to_string()
+        });
+    let status = if error_message.is_some() {
+        ChangeRequestStatus::Failed
+    } else {
+        ChangeRequestStatus::Approved
+    };
+
+    let change_request = update_change_request_with_status(
+        &state,
+        &change_request,
+        ChangeRequestStatus::Approving,
+        storage::ChangeRequestUpdate::ApplyOutcome {
+            status,
+            error_message,
+        },
+    )
+    .await?;
+
+    emit_decision_event(&req_state, &change_request);
+
+    Ok(ApplicationResponse::Json(get_change_request_response(
+        change_request,
+        &change,
+    )?))
+}
+
+pub async fn reject_change_request(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    change_request_id: String,
+    req: change_request_api::ChangeRequestDecisionRequest,
+    req_state: ReqState,
+) -> RouterResponse<change_request_api::ChangeRequestResponse> {
+    let (change_request, change) =
+        get_change_request_for_decision(&state, &user_from_token, &change_request_id).await?;
+
+    let change_request = update_change_request_with_status(
+        &state,
+        &change_request,
+        ChangeRequestStatus::Pending,
+        storage::ChangeRequestUpdate::Decision {
+            status: ChangeRequestStatus::Rejected,
+            decided_by: user_from_token.user_id,
+            decision_reason: req.reason,
+            error_message: None,
+        },
+    )
+    .await?;
+
+    emit_decision_event(&req_state, &change_request);
+
+    Ok(ApplicationResponse::Json(get_change_request_response(
+        change_request,
+        &change,
+    )?))
+}
+
+/// Updates the change request only if it still has the given status, failing if another
+/// decision has moved it to a different status in the meantime
+async fn update_change_request_with_status(
+    state: &SessionState,
+    change_request: &storage::ChangeRequest,
+    status: ChangeRequestStatus,
+    change_request_update: storage::ChangeRequestUpdate,
+) -> RouterResult<storage::ChangeRequest> {
+    state
+        .store
+        .update_change_request_with_status(
+            &change_request.merchant_id,
+            &change_request.change_request_id,
+            status,
+            change_request_update,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Change request is no longer {status}"),
+        })
+        .attach_printable("Failed to update change request")
+}
+
+/// Validates that the user can decide upon the change request, which requires the change request
+/// to be pending, the user to be different from the requester and to be authorized for the change
+async fn get_change_request_for_decision(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    change_request_id: &str,
+) -> RouterResult<(storage::ChangeRequest, ChangeRequestPayload)> {
+    expire_pending_change_requests(state, &user_from_token.merchant_id).await?;
+
+    let role_info = get_role_info(state, user_from_token).await?;
+    let change_request = find_change_request(state, user_from_token, change_request_id).await?;
+    if !is_visible_to_user(&change_request, &role_info, user_from_token) {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Change request not found".to_string(),
+        }));
+    }
+
+    if change_request.status != ChangeRequestStatus::Pending {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("Change request is already {}", change_request.status),
+        }));
+    }
+
+    if change_request.requested_by == user_from_token.user_id {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Change request cannot be decided by the user who raised it".to_string(),
+        }));
+    }
+
+    let key_store = get_merchant_key_store(state, &change_request.merchant_id).await?;
+    let change = decrypt_change(state, &key_store, &change_request).await?;
+    authorization::check_permission(
+        get_required_permission(&change),
+        &role_info,
+        &user_from_token.profile_id,
+    )?;
+
+    Ok((change_request, change))
+}
+
+async fn apply_change(
+    state: &SessionState,
+    change_request: &storage::ChangeRequest,
+    change: ChangeRequestPayload,
+) -> RouterResult<()> {
+    let key_store = get_merchant_key_store(state, &change_request.merchant_id).await?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            &state.into(),
+            &change_request.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store,
+    )));
+    let state = state.clone();
+    let merchant_id = change_request.merchant_id.clone();
+    let profile_id = change_request.profile_id.clone();
+
+    match change {
+        ChangeRequestPayload::RoutingCreate {
+            config,
+            transaction_type,
+        } => {
+            let transaction_type = transaction_type
+                .or(config.transaction_type)
+                .unwrap_or(TransactionType::Payment);
+            routing::create_routing_algorithm_under_profile(
+                state,
+                merchant_context,
+                profile_id,
+                config,
+                transaction_type,
+            )
+            .await
+            .map(|_| ())
+        }
+        ChangeRequestPayload::RoutingActivate {
+            algorithm_id,
+            transaction_type,
+        } => routing::link_routing_config(
+            state,
+            merchant_context,
+            profile_id,
+            algorithm_id,
+            transaction_type.unwrap_or(TransactionType::Payment),
+        )
+        .await
+        .map(|_| ()),
+        ChangeRequestPayload::RoutingDeactivate {
+            config,
+            transaction_type,
+        } => {
+            let transaction_type = transaction_type
+                .or(config.transaction_type)
+                .unwrap_or(TransactionType::Payment);
+            routing::unlink_routing_config(
+                state,
+                merchant_context,
+                config,
+                profile_id,
+                transaction_type,
+            )
+            .await
+            .map(|_| ())
+        }
+        ChangeRequestPayload::RoutingUpdateDefaultConfig {
+            connectors,
+            transaction_type,
+        } => routing::update_default_routing_config(
+            state,
+            merchant_context,
+            connectors,
+            &transaction_type,
+        )
+        .await
+        .map(|_| ()),
+        ChangeRequestPayload::MerchantConnectorCreate(request) => {
+            admin::create_connector(state, *request, merchant_context, profile_id)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::MerchantConnectorUpdate {
+            merchant_connector_id,
+            update,
+        } => admin::update_connector(
+            state,
+            &merchant_id,
+            profile_id,
+            &merchant_connector_id,
+            *update,
+        )
+        .await
+        .map(|_| ()),
+        ChangeRequestPayload::MerchantConnectorDelete {
+            merchant_connector_id,
+        } => admin::delete_connector(state, merchant_id, merchant_connector_id)
+            .await
+            .map(|_| ()),
+        ChangeRequestPayload::SurchargeDecisionConfigUpsert(request) => {
+            surcharge_decision_config::upsert_surcharge_decision_config(
+                state,
+                merchant_context,
+                request,
+            )
+            .await
+            .map(|_| ())
+        }
+        ChangeRequestPayload::SurchargeDecisionConfigDelete => {
+            surcharge_decision_config::delete_surcharge_decision_config(state, merchant_context)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::ThreeDsDecisionRuleUpsert(request) => {
+            conditional_config::upsert_conditional_config(state, merchant_context, request)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::ThreeDsDecisionRuleDelete => {
+            conditional_config::delete_conditional_config(state, merchant_context)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::BlocklistAdd(request) => {
+            blocklist::add_entry_to_blocklist(state, merchant_context, request)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::BlocklistRemove(request) => {
+            blocklist::remove_entry_from_blocklist(state, merchant_context, request)
+                .await
+                .map(|_| ())
+        }
+        ChangeRequestPayload::BlocklistToggle(query) => {
+            blocklist::toggle_blocklist_guard(state, merchant_context, query)
+                .await
+                .map(|_| ())
+        }
+    }
+}
+
+/// Permission required to raise or decide upon a change, same as the one required by the API
+/// which applies the change directly
+fn get_required_permission(change: &ChangeRequestPayload) -> Permission {
+    match change {
+        ChangeRequestPayload::RoutingCreate { .. }
+        | ChangeRequestPayload::RoutingActivate { .. }
+        | ChangeRequestPayload::RoutingDeactivate { .. } => Permission::ProfileRoutingWrite,
+        ChangeRequestPayload::RoutingUpdateDefaultConfig { .. } => Permission::MerchantRoutingWrite,
+        ChangeRequestPayload::MerchantConnectorCreate(_)
+        | ChangeRequestPayload::MerchantConnectorUpdate { .. }
+        | ChangeRequestPayload::MerchantConnectorDelete { .. } => Permission::ProfileConnectorWrite,
+        ChangeRequestPayload::SurchargeDecisionConfigUpsert(_)
+        | ChangeRequestPayload::SurchargeDecisionConfigDelete => {
+            Permission::MerchantSurchargeDecisionManagerWrite
+        }
+        ChangeRequestPayload::ThreeDsDecisionRuleUpsert(_)
+        | ChangeRequestPayload::ThreeDsDecisionRuleDelete => {
+            Permission::MerchantThreeDsDecisionManagerWrite
+        }
+        ChangeRequestPayload::BlocklistAdd(_)
+        | ChangeRequestPayload::BlocklistRemove(_)
+        | ChangeRequestPayload::BlocklistToggle(_) => Permission::MerchantAccountWrite,
+    }
+}
+
+/// Profile level users can only access the change requests raised in their profile
+fn is_visible_to_user(
+    change_request: &storage::ChangeRequest,
+    role_info: &RoleInfo,
+    user_from_token: &UserFromToken,
+) -> bool {
+    role_info.get_entity_type() != EntityType::Profile
+        || change_request.profile_id.as_ref() == Some(&user_from_token.profile_id)
+}
+
+async fn expire_pending_change_requests(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    state
+        .store
+        .expire_pending_change_requests(merchant_id, common_utils::date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to expire pending change requests")?;
+
+    Ok(())
+}
+
+async fn find_change_request(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+    change_request_id: &str,
+) -> RouterResult<storage::ChangeRequest> {
+    state
+        .store
+        .find_change_request_by_merchant_id_change_request_id(
+            &user_from_token.merchant_id,
+            change_request_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Change request not found".to_string(),
+        })
+}
+
+async fn get_role_info(
+    state: &SessionState,
+    user_from_token: &UserFromToken,
+) -> RouterResult<RoleInfo> {
+    user_from_token
+        .get_role_info_from_db(state)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch role info of the user")
+}
+
+async fn get_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<domain::MerchantKeyStore> {
+    state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+}
+
+/// Change requests can contain connector credentials and card details, so the requested change is
+/// stored encrypted with the merchant key
+async fn encrypt_change(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    change: &ChangeRequestPayload,
+) -> RouterResult<common_utils::encryption::Encryption> {
+    let change_value = serde_json::to_value(change)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize change request payload")?;
+
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::ChangeRequest),
+        domain::types::CryptoOperation::Encrypt(Secret::new(change_value)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Into::into)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt change request payload")
+}
+
+async fn decrypt_change(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    change_request: &storage::ChangeRequest,
+) -> RouterResult<ChangeRequestPayload> {
+    domain::types::crypto_operation::<serde_json::Value, masking::WithType>(
+        &state.into(),
+        type_name!(storage::ChangeRequest),
+        domain::types::CryptoOperation::Decrypt(change_request.request_payload.clone()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt change request payload")?
+    .into_inner()
+    .expose()
+    .parse_value("ChangeRequestPayload")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_change_request_response(
+    change_request: storage::ChangeRequest,
+    change: &ChangeRequestPayload,
+) -> RouterResult<change_request_api::ChangeRequestResponse> {
+    Ok(change_request_api::ChangeRequestResponse {
+        change_request_id: change_request.change_request_id,
+        merchant_id: change_request.merchant_id,
+        profile_id: change_request.profile_id,
+        entity: change_request.entity,
+        change: masking::masked_serialize(change)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to mask change request payload")?,
+        description: change_request.description,
+        status: change_request.status,
+        requested_by: change_request.requested_by,
+        decided_by: change_request.decided_by,
+        decision_reason: change_request.decision_reason,
+        error_message: change_request.error_message,
+        created_at: change_request.created_at,
+        expires_at: change_request.expires_at,
+        decided_at: change_request.decided_at,
+    })
+}
+
+fn emit_decision_event(req_state: &ReqState, change_request: &storage::ChangeRequest) {
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ChangeRequestDecided {
+            change_request_id: change_request.change_request_id.clone(),
+            entity: change_request.entity,
+            status: change_request.status,
+            decided_by: change_request.decided_by.clone().unwrap_or_default(),
+            reason: change_request.decision_reason.clone(),
+        }))
+        .emit();
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use api_models::connector_fee_schedule::{
+    ConnectorFeeRule, ConnectorFeeScheduleRequest, ConnectorFeeScheduleResponse,
+    PaymentFeeAttributes,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Creates the fee schedule of the merchant connector account, or replaces the fee rules of the
+/// existing fee schedule
+pub async fn upsert_connector_fee_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    request: ConnectorFeeScheduleRequest,
+) -> RouterResponse<ConnectorFeeScheduleResponse> {
+    validate_fee_rules(&request.fee_rules)?;
+    let db = state.store.as_ref();
+    let mca = find_merchant_connector_account(
+        &state,
+        &merchant_id,
+        &key_store,
+        profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let fee_rules = request
+        .fee_rules
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize connector fee rules")?;
+
+    let fee_schedule = match db
+        .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+        )
+        .await
+    {
+        Ok(_) => db
+            .update_connector_fee_schedule(
+                &merchant_id,
+                &merchant_connector_id,
+                storage::ConnectorFeeScheduleUpdate { fee_rules },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update connector fee schedule")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            db.insert_connector_fee_schedule(storage::ConnectorFeeScheduleNew {
+                merchant_connector_id: merchant_connector_id.clone(),
+                merchant_id: merchant_id.clone(),
+                profile_id: mca.profile_id.clone(),
+                connector_name: mca.connector_name.clone(),
+                fee_rules,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .to_duplicate_response(
+                errors::ApiErrorResponse::GenericDuplicateError {
+                    message: format!(
+                        "Fee schedule already exists for the merchant connector account {}",
+                        merchant_connector_id.get_string_repr()
+                    ),
+                },
+            )?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch connector fee schedule")?,
+    };
+
+    Ok(ApplicationResponse::Json(get_fee_schedule_response(
+        fee_schedule,
+    )?))
+}
+
+pub async fn retrieve_connector_fee_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorFeeScheduleResponse> {
+    find_merchant_connector_account(
+        &state,
+        &merchant_id,
+        &key_store,
+        profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let fee_schedule = state
+        .store
+        .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "Fee schedule does not exist for the merchant connector account {}",
+                merchant_connector_id.get_string_repr()
+            ),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_fee_schedule_response(
+        fee_schedule,
+    )?))
+}
+
+pub async fn list_connector_fee_schedules(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<Vec<ConnectorFeeScheduleResponse>> {
+    let fee_schedules = state
+        .store
+        .list_connector_fee_schedules_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list connector fee schedules")?;
+
+    fee_schedules
+        .into_iter()
+        .map(get_fee_schedule_response)
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+pub async fn delete_connector_fee_schedule(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorFeeScheduleResponse> {
+    find_merchant_connector_account(
+        &state,
+        &merchant_id,
+        &key_store,
+        profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let db = state.store.as_ref();
+    let not_found_error = errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!(
+            "Fee schedule does not exist for the merchant connector account {}",
+            merchant_connector_id.get_string_repr()
+        ),
+    };
+    let fee_schedule = db
+        .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+            &merchant_id,
+            &merchant_connector_id,
+        )
+        .await
+        .to_not_found_response(not_found_error.clone())?;
+    db.delete_connector_fee_schedule(&merchant_id, &merchant_connector_id)
+        .await
+        .to_not_found_response(not_found_error)?;
+
+    Ok(ApplicationResponse::Json(get_fee_schedule_response(
+        fee_schedule,
+    )?))
+}
+
+/// Fee rules of all the merchant connector accounts of the merchant which have a fee schedule
+pub async fn get_fee_rules_by_merchant_connector_id(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<HashMap<id_type::MerchantConnectorAccountId, Vec<ConnectorFeeRule>>> {
+    state
+        .store
+        .list_connector_fee_schedules_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list connector fee schedules")?
+        .into_iter()
+        .map(|fee_schedule| {
+            Ok((
+                fee_schedule.merchant_connector_id,
+                parse_fee_rules(fee_schedule.fee_rules)?,
+            ))
+        })
+        .collect()
+}
+
+/// Estimates the fees charged by the connector for a number of payments with the same
+/// attributes, using the first fee rule which matches the payments. Returns `None` when none of
+/// the fee rules match the payments.
+pub fn estimate_processing_cost(
+    fee_rules: &[ConnectorFeeRule],
+    attributes: &PaymentFeeAttributes,
+    total_amount: MinorUnit,
+    payment_count: i64,
+) -> RouterResult<Option<MinorUnit>> {
+    fee_rules
+        .iter()
+        .find(|fee_rule| fee_rule.matches(attributes))
+        .map(|fee_rule| {
+            fee_rule
+                .fee
+                .estimate_cost(total_amount, payment_count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to estimate processing cost")
+        })
+        .transpose()
+}
+
+fn validate_fee_rules(fee_rules: &[ConnectorFeeRule]) -> RouterResult<()> {
+    if fee_rules.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one fee rule is required in the fee schedule".to_string(),
+        }));
+    }
+    if fee_rules
+        .iter()
+        .any(|fee_rule| fee_rule.fee.get_fixed_amount().get_amount_as_i64() < 0)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Fixed fees cannot be negative".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let mca = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_id,
+            merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+    Ok(mca)
+}
+
+fn parse_fee_rules(fee_rules: serde_json::Value) -> RouterResult<Vec<ConnectorFeeRule>> {
+    fee_rules
+        .parse_value("Vec<ConnectorFeeRule>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize connector fee rules")
+}
+
+fn get_fee_schedule_response(
+    fee_schedule: storage::ConnectorFeeSchedule,
+) -> RouterResult<ConnectorFeeScheduleResponse> {
+    Ok(ConnectorFeeScheduleResponse {
+        fee_rules: parse_fee_rules(fee_schedule.fee_rules)?,
+        merchant_id: fee_schedule.merchant_id,
+        merchant_connector_id: fee_schedule.merchant_connector_id,
+        profile_id: fee_schedule.profile_id,
+        connector_name: fee_schedule.connector_name,
+        created_at: fee_schedule.created_at,
+        modified_at: fee_schedule.modified_at,
+    })
+}
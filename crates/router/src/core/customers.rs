@@ -1,3 +1,8 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod data_subject;
+#[cfg(feature = "v1")]
+pub mod merge;
+
 use common_utils::{
     crypto::Encryptable,
     errors::ReportSwitchExt,
@@ -504,7 +509,7 @@ pub async fn retrieve_customer(
     let db = state.store.as_ref();
     let key_manager_state = &(&state).into();
 
-    let response = db
+    let response = match db
         .find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
             key_manager_state,
             &customer_id,
@@ -514,7 +519,30 @@ pub async fn retrieve_customer(
         )
         .await
         .switch()?
-        .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?;
+    {
+        Some(customer) => customer,
+        // A customer merged into another customer resolves to the customer it was merged into
+        None => {
+            let redirect = db
+                .find_optional_customer_merge_redirect(
+                    merchant_context.get_merchant_account().get_id(),
+                    &customer_id,
+                )
+                .await
+                .switch()?
+                .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?;
+            db.find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
+                key_manager_state,
+                &redirect.target_customer_id,
+                merchant_context.get_merchant_account().get_id(),
+                merchant_context.get_merchant_key_store(),
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .switch()?
+            .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?
+        }
+    };
 
     let address = match &response.address_id {
         Some(address_id) => Some(api_models::payments::AddressDetails::from(
@@ -909,44 +937,13 @@ impl CustomerDeleteBridge for id_type::CustomerId {
                 .merchant_id
                 .clone(),
         );
-        let redacted_encrypted_value: Encryptable<Secret<_>> = types::crypto_operation(
-            key_manager_state,
-            type_name!(storage::Address),
-            types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
-            identifier.clone(),
-            key,
-        )
-        .await
-        .and_then(|val| val.try_into_operation())
-        .switch()?;
-
-        let redacted_encrypted_email = Encryptable::new(
-            redacted_encrypted_value
-                .clone()
-                .into_inner()
-                .switch_strategy(),
-            redacted_encrypted_value.clone().into_encrypted(),
+        let redacted_encrypted_value =
+            get_redacted_encrypted_value(key_manager_state, merchant_context).await?;
+        let update_address = get_redacted_address_update(
+            &redacted_encrypted_value,
+            merchant_context.get_merchant_account().storage_scheme,
         );
 
-        let update_address = storage::AddressUpdate::Update {
-            city: Some(REDACTED.to_string()),
-            country: None,
-            line1: Some(redacted_encrypted_value.clone()),
-            line2: Some(redacted_encrypted_value.clone()),
-            line3: Some(redacted_encrypted_value.clone()),
-            state: Some(redacted_encrypted_value.clone()),
-            zip: Some(redacted_encrypted_value.clone()),
-            first_name: Some(redacted_encrypted_value.clone()),
-            last_name: Some(redacted_encrypted_value.clone()),
-            phone_number: Some(redacted_encrypted_value.clone()),
-            country_code: Some(REDACTED.to_string()),
-            updated_by: merchant_context
-                .get_merchant_account()
-                .storage_scheme
-                .to_string(),
-            email: Some(redacted_encrypted_email),
-        };
-
         match db
             .update_address_by_merchant_id_customer_id(
                 key_manager_state,
@@ -1014,6 +1011,63 @@ impl CustomerDeleteBridge for id_type::CustomerId {
     }
 }
 
+/// Encrypts the placeholder that redacted personal data is replaced with
+#[cfg(feature = "v1")]
+async fn get_redacted_encrypted_value(
+    key_manager_state: &KeyManagerState,
+    merchant_context: &domain::MerchantContext,
+) -> errors::CustomResult<Encryptable<Secret<String>>, errors::CustomersErrorResponse> {
+    types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::Address),
+        types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
+        Identifier::Merchant(
+            merchant_context
+                .get_merchant_key_store()
+                .merchant_id
+                .clone(),
+        ),
+        merchant_context
+            .get_merchant_key_store()
+            .key
+            .get_inner()
+            .peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .switch()
+}
+
+#[cfg(feature = "v1")]
+fn get_redacted_address_update(
+    redacted_encrypted_value: &Encryptable<Secret<String>>,
+    storage_scheme: common_enums::MerchantStorageScheme,
+) -> storage::AddressUpdate {
+    let redacted_encrypted_email = Encryptable::new(
+        redacted_encrypted_value
+            .clone()
+            .into_inner()
+            .switch_strategy(),
+        redacted_encrypted_value.clone().into_encrypted(),
+    );
+
+    storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value.clone()),
+        country_code: Some(REDACTED.to_string()),
+        updated_by: storage_scheme.to_string(),
+        email: Some(redacted_encrypted_email),
+    }
+}
+
 #[instrument(skip(state))]
 pub async fn update_customer(
     state: SessionState,
@@ -0,0 +1,1004 @@
+use std::collections::HashSet;
+
+use api_models::customers::{
+    CustomerDataExportResponse, CustomerDataExportRetrieveRequest, CustomerDataExportStatus,
+    DataRetentionConfigRequest, DataRetentionConfigResponse,
+};
+use common_utils::{
+    crypto::Encryptable,
+    errors::ReportSwitchExt,
+    ext_traits::{AsyncExt, Encode, ValueExt},
+    id_type, pii, type_name,
+    types::{keymanager::Identifier, MinorUnit},
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentUpdate,
+};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::{get_redacted_address_update, get_redacted_encrypted_value, CustomerDeleteBridge};
+use crate::{
+    consts,
+    core::errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api::{self, customers},
+        domain,
+        storage::{self, enums, CustomerDataExportTrackingData, DataRetentionTrackingData},
+        transformers::ForeignFrom,
+    },
+};
+
+const DATA_RETENTION_TAG: &str = "DATA_RETENTION";
+const DATA_RETENTION_NAME: &str = "DATA_RETENTION";
+const DATA_RETENTION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::DataRetentionWorkflow;
+const CUSTOMER_DATA_EXPORT_TAG: &str = "CUSTOMER_DATA_EXPORT";
+const CUSTOMER_DATA_EXPORT_NAME: &str = "CUSTOMER_DATA_EXPORT";
+const CUSTOMER_DATA_EXPORT_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::CustomerDataExportWorkflow;
+
+/// Everything held about a customer, as exported to the customer on request
+#[derive(Debug, serde::Serialize)]
+struct CustomerDataExport {
+    customer: customers::CustomerResponse,
+    payment_methods: Vec<PaymentMethodExport>,
+    mandates: Vec<MandateExport>,
+    payments: Vec<PaymentExport>,
+    refunds: Vec<api::RefundResponse>,
+    disputes: Vec<api_models::disputes::DisputeResponse>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct PaymentMethodExport {
+    payment_method_id: String,
+    payment_method: Option<enums::PaymentMethod>,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    status: enums::PaymentMethodStatus,
+    payment_method_data: Option<pii::SecretSerdeValue>,
+    billing_address: Option<pii::SecretSerdeValue>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    last_used_at: PrimitiveDateTime,
+}
+
+impl From<domain::PaymentMethod> for PaymentMethodExport {
+    fn from(payment_method: domain::PaymentMethod) -> Self {
+        Self {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            status: payment_method.status,
+            payment_method_data: payment_method
+                .payment_method_data
+                .map(Encryptable::into_inner),
+            billing_address: payment_method
+                .payment_method_billing_address
+                .map(Encryptable::into_inner),
+            created_at: payment_method.created_at,
+            last_used_at: payment_method.last_used_at,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct MandateExport {
+    mandate_id: String,
+    payment_method_id: String,
+    mandate_status: enums::MandateStatus,
+    mandate_type: enums::MandateType,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    customer_accepted_at: Option<PrimitiveDateTime>,
+    customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    customer_user_agent: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+}
+
+impl From<storage::Mandate> for MandateExport {
+    fn from(mandate: storage::Mandate) -> Self {
+        Self {
+            mandate_id: mandate.mandate_id,
+            payment_method_id: mandate.payment_method_id,
+            mandate_status: mandate.mandate_status,
+            mandate_type: mandate.mandate_type,
+            customer_accepted_at: mandate.customer_accepted_at,
+            customer_ip_address: mandate.customer_ip_address,
+            customer_user_agent: mandate.customer_user_agent,
+            created_at: mandate.created_at,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct PaymentExport {
+    payment_id: id_type::PaymentId,
+    status: enums::IntentStatus,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    description: Option<String>,
+    customer_details: Option<pii::SecretSerdeValue>,
+    billing_address: Option<api_models::payments::AddressDetails>,
+    shipping_address: Option<api_models::payments::AddressDetails>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+}
+
+/// Schedules the export of the personal data held about the customer. The export is assembled in
+/// the background, and its status can be retrieved using the returned file id.
+#[instrument(skip_all)]
+pub async fn export_customer_data(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &(&state).into(),
+            &customer_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        get_customer_data_export_task_id(&file_id),
+        CUSTOMER_DATA_EXPORT_NAME,
+        CUSTOMER_DATA_EXPORT_RUNNER,
+        [CUSTOMER_DATA_EXPORT_TAG],
+        CustomerDataExportTrackingData {
+            merchant_id: merchant_id.clone(),
+            customer_id,
+            file_id,
+        },
+        None,
+        common_utils::date_time::now(),
+        common_types::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct customer data export process tracker task")?;
+
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert customer data export task to process_tracker")?;
+    metrics::TASKS_ADDED_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "CustomerDataExport")),
+    );
+
+    get_customer_data_export_response(&process).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_customer_data_export(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: CustomerDataExportRetrieveRequest,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_customer_data_export_task_id(&req.file_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch customer data export task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Customer data export not found".to_string(),
+            })
+        })?;
+
+    let tracking_data = get_customer_data_export_tracking_data(&process)?;
+    if tracking_data.merchant_id != *merchant_context.get_merchant_account().get_id()
+        || tracking_data.customer_id != req.customer_id
+    {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Customer data export not found".to_string(),
+        }));
+    }
+
+    get_customer_data_export_response(&process).map(ApplicationResponse::Json)
+}
+
+fn get_customer_data_export_tracking_data(
+    process: &storage::ProcessTracker,
+) -> RouterResult<CustomerDataExportTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("CustomerDataExportTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_customer_data_export_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<CustomerDataExportResponse> {
+    let tracking_data = get_customer_data_export_tracking_data(process)?;
+
+    Ok(CustomerDataExportResponse {
+        customer_id: tracking_data.customer_id,
+        file_id: tracking_data.file_id,
+        status: get_customer_data_export_status(process),
+        created_at: process.created_at,
+    })
+}
+
+fn get_customer_data_export_status(process: &storage::ProcessTracker) -> CustomerDataExportStatus {
+    match process.status {
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == business_status::COMPLETED_BY_PT =>
+        {
+            CustomerDataExportStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => CustomerDataExportStatus::Failed,
+        _ => CustomerDataExportStatus::Pending,
+    }
+}
+
+fn get_customer_data_export_task_id(file_id: &str) -> String {
+    format!("{CUSTOMER_DATA_EXPORT_RUNNER}_{CUSTOMER_DATA_EXPORT_NAME}_{file_id}")
+}
+
+/// Assembles the personal data held about the customer, along with their payment methods,
+/// mandates, payments, refunds and disputes, into a single JSON document. The document is stored
+/// as a file, which can be downloaded through the files API.
+#[instrument(skip_all, fields(merchant_id = ?tracking_data.merchant_id))]
+pub async fn run_customer_data_export(
+    state: &SessionState,
+    tracking_data: &CustomerDataExportTrackingData,
+) -> RouterResult<()> {
+    let merchant_context = get_merchant_context(state, &tracking_data.merchant_id).await?;
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let key_store = merchant_context.get_merchant_key_store();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let customer_id = &tracking_data.customer_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let address = customer
+        .address_id
+        .as_ref()
+        .async_map(|address_id| {
+            db.find_address_by_address_id(key_manager_state, address_id, key_store)
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find address of the customer")?
+        .map(api_models::payments::AddressDetails::from);
+
+    let payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find payment methods of the customer"))
+        }
+    };
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find mandates of the customer")?;
+
+    let mut payments = Vec::new();
+    let mut refunds = Vec::new();
+    let mut disputes = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let payment_intents = db
+            .filter_payment_intent_by_constraints(
+                key_manager_state,
+                merchant_id,
+                &get_payment_list_constraints(Some(customer_id.clone()), None, None, offset),
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payments of the customer")?;
+        let batch_size = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            refunds.extend(
+                db.find_refund_by_payment_id_merchant_id(
+                    &payment_intent.payment_id,
+                    merchant_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find refunds of the payment")?
+                .into_iter()
+                .map(api::RefundResponse::foreign_from),
+            );
+            disputes.extend(
+                db.find_disputes_by_merchant_id_payment_id(merchant_id, &payment_intent.payment_id)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find disputes of the payment")?
+                    .into_iter()
+                    .map(api_models::disputes::DisputeResponse::foreign_from),
+            );
+
+            let billing_address = find_payment_address(
+                state,
+                &merchant_context,
+                &payment_intent,
+                payment_intent.billing_address_id.as_deref(),
+            )
+            .await?;
+            let shipping_address = find_payment_address(
+                state,
+                &merchant_context,
+                &payment_intent,
+                payment_intent.shipping_address_id.as_deref(),
+            )
+            .await?;
+
+            payments.push(PaymentExport {
+                payment_id: payment_intent.payment_id,
+                status: payment_intent.status,
+                amount: payment_intent.amount,
+                currency: payment_intent.currency,
+                description: payment_intent.description,
+                customer_details: payment_intent.customer_details.map(Encryptable::into_inner),
+                billing_address: billing_address
+                    .map(|address| api_models::payments::AddressDetails::from(address.address)),
+                shipping_address: shipping_address
+                    .map(|address| api_models::payments::AddressDetails::from(address.address)),
+                created_at: payment_intent.created_at,
+            });
+        }
+
+        if batch_size < usize::from(consts::DATA_RETENTION_BATCH_SIZE) {
+            break;
+        }
+        offset += u32::from(consts::DATA_RETENTION_BATCH_SIZE);
+    }
+
+    let export = CustomerDataExport {
+        customer: customers::CustomerResponse::foreign_from((customer, address)),
+        payment_methods: payment_methods
+            .into_iter()
+            .map(PaymentMethodExport::from)
+            .collect(),
+        mandates: mandates.into_iter().map(MandateExport::from).collect(),
+        payments,
+        refunds,
+        disputes,
+    };
+    let file = export
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize customer data export")?;
+
+    let file_id = tracking_data.file_id.clone();
+    let file_key = format!("{}/{}", merchant_id.get_string_repr(), file_id);
+    let file_size = i32::try_from(file.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Customer data export is too large to be stored")?;
+
+    state
+        .file_storage_client
+        .upload_file(&file_key, file)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload customer data export")?;
+
+    db.insert_file_metadata(diesel_models::FileMetadataNew {
+        file_id,
+        merchant_id: merchant_id.clone(),
+        file_name: Some(format!(
+            "{}_data_export.json",
+            customer_id.get_string_repr()
+        )),
+        file_size,
+        file_type: mime::APPLICATION_JSON.to_string(),
+        provider_file_id: Some(file_key),
+        file_upload_provider: Some(enums::FileUploadProvider::Router),
+        available: true,
+        connector_label: None,
+        profile_id: None,
+        merchant_connector_id: None,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert file metadata of customer data export")?;
+
+    Ok(())
+}
+
+async fn find_payment_address(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_intent: &hyperswitch_domain_models::payments::PaymentIntent,
+    address_id: Option<&str>,
+) -> RouterResult<Option<domain::PaymentAddress>> {
+    let Some(address_id) = address_id else {
+        return Ok(None);
+    };
+
+    match state
+        .store
+        .find_address_by_merchant_id_payment_id_address_id(
+            &state.into(),
+            merchant_context.get_merchant_account().get_id(),
+            &payment_intent.payment_id,
+            address_id,
+            merchant_context.get_merchant_key_store(),
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+    {
+        Ok(address) => Ok(Some(address)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find address of the payment")),
+    }
+}
+
+fn get_payment_list_constraints(
+    customer_id: Option<id_type::CustomerId>,
+    created_gte: Option<PrimitiveDateTime>,
+    created_lte: Option<PrimitiveDateTime>,
+    offset: u32,
+) -> PaymentIntentFetchConstraints {
+    let mut constraints =
+        PaymentIntentFetchConstraints::from(api_models::payments::PaymentListConstraints {
+            customer_id,
+            starting_after: None,
+            ending_before: None,
+            limit: u32::from(consts::DATA_RETENTION_BATCH_SIZE),
+            created: None,
+            created_lt: None,
+            created_gt: None,
+            created_lte,
+            created_gte,
+        });
+
+    if let PaymentIntentFetchConstraints::List(ref mut params) = constraints {
+        params.offset = offset;
+    }
+
+    constraints
+}
+
+/// Enables the data retention job for the merchant, or updates its retention period if it is
+/// already enabled. The first run is scheduled immediately.
+#[instrument(skip_all)]
+pub async fn configure_data_retention(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: DataRetentionConfigRequest,
+) -> RouterResponse<DataRetentionConfigResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+
+    if !(consts::MIN_DATA_RETENTION_PERIOD_IN_DAYS..=consts::MAX_DATA_RETENTION_PERIOD_IN_DAYS)
+        .contains(&req.retention_period_in_days)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`retention_period_in_days` must be between {} and {}",
+                consts::MIN_DATA_RETENTION_PERIOD_IN_DAYS,
+                consts::MAX_DATA_RETENTION_PERIOD_IN_DAYS
+            ),
+        }));
+    }
+
+    let task_id = get_data_retention_task_id(merchant_id);
+    let existing_process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch data retention task")?;
+
+    let now = common_utils::date_time::now();
+    let tracking_data = DataRetentionTrackingData {
+        merchant_id: merchant_id.clone(),
+        retention_period_in_days: req.retention_period_in_days,
+        redacted_until: existing_process
+            .as_ref()
+            .and_then(|process| get_tracking_data(process).ok())
+            .and_then(|tracking_data| tracking_data.redacted_until),
+    };
+
+    let process = match existing_process {
+        Some(process) => state
+            .store
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(now),
+                    tracking_data: Some(
+                        tracking_data
+                            .encode_to_value()
+                            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                    ),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update data retention task")?,
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                DATA_RETENTION_NAME,
+                DATA_RETENTION_RUNNER,
+                [DATA_RETENTION_TAG],
+                tracking_data,
+                None,
+                now,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct data retention process tracker task")?;
+
+            let process = state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert data retention task to process_tracker")?;
+            metrics::TASKS_ADDED_COUNT
+                .add(1, router_env::metric_attributes!(("flow", "DataRetention")));
+            process
+        }
+    };
+
+    get_data_retention_config_response(&process).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_data_retention_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<DataRetentionConfigResponse> {
+    let process = find_data_retention_process(&state, &merchant_context).await?;
+    get_data_retention_config_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Stops scheduling further runs of the data retention job for the merchant. Data that has
+/// already been redacted is not restored.
+#[instrument(skip_all)]
+pub async fn disable_data_retention(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<DataRetentionConfigResponse> {
+    let process = find_data_retention_process(&state, &merchant_context).await?;
+    let mut response = get_data_retention_config_response(&process)?;
+
+    if process.status != enums::ProcessTrackerStatus::Finish {
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to disable data retention task")?;
+    }
+
+    response.enabled = false;
+    response.next_run_at = None;
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn find_data_retention_process(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_data_retention_task_id(
+            merchant_context.get_merchant_account().get_id(),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch data retention task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Data retention is not configured for the merchant".to_string(),
+            })
+        })
+}
+
+fn get_data_retention_config_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<DataRetentionConfigResponse> {
+    let tracking_data = get_tracking_data(process)?;
+    let enabled = process.status != enums::ProcessTrackerStatus::Finish;
+
+    Ok(DataRetentionConfigResponse {
+        merchant_id: tracking_data.merchant_id,
+        retention_period_in_days: tracking_data.retention_period_in_days,
+        enabled,
+        next_run_at: process.schedule_time.filter(|_| enabled),
+        redacted_until: tracking_data.redacted_until,
+    })
+}
+
+fn get_tracking_data(process: &storage::ProcessTracker) -> RouterResult<DataRetentionTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("DataRetentionTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_data_retention_task_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{DATA_RETENTION_RUNNER}_{DATA_RETENTION_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+async fn get_merchant_context(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<domain::MerchantContext> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok(domain::MerchantContext::NormalMerchant(Box::new(
+        domain::Context(merchant_account, key_store),
+    )))
+}
+
+/// Redacts the personal data of payments and customers of the merchant that are older than the
+/// retention period, and returns the time up to which payments have been redacted.
+///
+/// Payments are redacted in the window since the previous run, so that each payment is processed
+/// once. This covers the addresses, customer, billing and shipping details of the payment, along
+/// with the browser information, payment method data, customer acceptance and billing address of
+/// its attempts. Customers are redacted once they have not been modified and have not made a
+/// payment within the retention period, unless they have an active mandate.
+#[instrument(skip_all, fields(merchant_id = ?tracking_data.merchant_id))]
+pub async fn run_data_retention(
+    state: &SessionState,
+    tracking_data: &DataRetentionTrackingData,
+) -> RouterResult<PrimitiveDateTime> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_context = get_merchant_context(state, &tracking_data.merchant_id).await?;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let merchant_id = &tracking_data.merchant_id;
+    let key_store = merchant_context.get_merchant_key_store();
+
+    let cutoff = common_utils::date_time::now().saturating_sub(time::Duration::days(
+        tracking_data.retention_period_in_days.into(),
+    ));
+
+    let redacted_encrypted_value =
+        get_redacted_encrypted_value(key_manager_state, &merchant_context)
+            .await
+            .switch()?;
+    let redacted_payment_details = domain::types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::PaymentIntent),
+        domain::types::CryptoOperation::Encrypt(Secret::new(serde_json::json!({}))),
+        Identifier::Merchant(merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt redacted payment details")?;
+
+    let mut redacted_payments_count = 0;
+    let mut offset = 0;
+
+    loop {
+        let payment_intents = db
+            .filter_payment_intent_by_constraints(
+                key_manager_state,
+                merchant_id,
+                &get_payment_list_constraints(
+                    None,
+                    tracking_data.redacted_until,
+                    Some(cutoff),
+                    offset,
+                ),
+                key_store,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list payments past the retention period")?;
+        let batch_size = payment_intents.len();
+
+        for payment_intent in payment_intents {
+            let payment_attempts = db
+                .find_attempts_by_merchant_id_payment_id(
+                    merchant_id,
+                    &payment_intent.payment_id,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list attempts of the payment")?;
+
+            for address_id in [
+                payment_intent.billing_address_id.as_deref(),
+                payment_intent.shipping_address_id.as_deref(),
+            ]
+            .into_iter()
+            .chain(
+                payment_attempts
+                    .iter()
+                    .map(|attempt| attempt.payment_method_billing_address_id.as_deref()),
+            )
+            .flatten()
+            .collect::<HashSet<_>>()
+            {
+                let Some(address) = find_payment_address(
+                    state,
+                    &merchant_context,
+                    &payment_intent,
+                    Some(address_id),
+                )
+                .await?
+                else {
+                    continue;
+                };
+
+                db.update_address_for_payments(
+                    key_manager_state,
+                    address,
+                    get_redacted_address_update(&redacted_encrypted_value, storage_scheme),
+                    payment_intent.payment_id.clone(),
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact address of the payment")?;
+            }
+
+            db.redact_payment_attempts_by_merchant_id_payment_id(
+                merchant_id,
+                &payment_intent.payment_id,
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact attempts of the payment")?;
+
+            if payment_intent.customer_details.is_some()
+                || payment_intent.billing_details.is_some()
+                || payment_intent.shipping_details.is_some()
+            {
+                let redact = |details: &Option<_>| {
+                    details.as_ref().map(|_| redacted_payment_details.clone())
+                };
+                let payment_intent_update = PaymentIntentUpdate::RedactionUpdate {
+                    customer_details: redact(&payment_intent.customer_details),
+                    billing_details: redact(&payment_intent.billing_details),
+                    shipping_details: redact(&payment_intent.shipping_details),
+                    updated_by: storage_scheme.to_string(),
+                };
+                db.update_payment_intent(
+                    key_manager_state,
+                    payment_intent,
+                    payment_intent_update,
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact details of the payment")?;
+            }
+
+            redacted_payments_count += 1;
+        }
+
+        if batch_size < usize::from(consts::DATA_RETENTION_BATCH_SIZE) {
+            break;
+        }
+        offset += u32::from(consts::DATA_RETENTION_BATCH_SIZE);
+    }
+
+    let mut redacted_customers_count = 0;
+    let mut starting_after_id = None;
+
+    loop {
+        let customers = db
+            .list_unredacted_customers_by_merchant_id_modified_before(
+                key_manager_state,
+                merchant_id,
+                key_store,
+                cutoff,
+                starting_after_id.as_ref(),
+                i64::from(consts::DATA_RETENTION_BATCH_SIZE),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list customers past the retention period")?;
+        let batch_size = customers.len();
+        starting_after_id = customers
+            .last()
+            .map(|customer| customer.customer_id.clone());
+
+        for customer in customers {
+            let recent_payments = db
+                .filter_payment_intent_by_constraints(
+                    key_manager_state,
+                    merchant_id,
+                    &get_payment_list_constraints(
+                        Some(customer.customer_id.clone()),
+                        Some(cutoff),
+                        None,
+                        0,
+                    ),
+                    key_store,
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list recent payments of the customer")?;
+            if !recent_payments.is_empty() {
+                continue;
+            }
+
+            match customer
+                .customer_id
+                .redact_customer_details_and_generate_response(
+                    db,
+                    &merchant_context,
+                    key_manager_state,
+                    state,
+                )
+                .await
+            {
+                Ok(_) => redacted_customers_count += 1,
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::CustomersErrorResponse::MandateActive
+                    ) =>
+                {
+                    logger::info!(
+                        customer_id = ?customer.customer_id,
+                        "Skipping redaction of customer with an active mandate"
+                    );
+                }
+                Err(error) => return Err(error).switch(),
+            }
+        }
+
+        if batch_size < usize::from(consts::DATA_RETENTION_BATCH_SIZE) {
+            break;
+        }
+    }
+
+    logger::info!(
+        redacted_payments_count,
+        redacted_customers_count,
+        "Completed data retention run"
+    );
+
+    Ok(cutoff)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_process(
+        status: enums::ProcessTrackerStatus,
+        business_status: &str,
+    ) -> storage::ProcessTracker {
+        let now = common_utils::date_time::now();
+        let tracking_data = CustomerDataExportTrackingData {
+            merchant_id: id_type::MerchantId::try_from(std::borrow::Cow::from("merchant")).unwrap(),
+            customer_id: id_type::CustomerId::try_from(std::borrow::Cow::from("customer")).unwrap(),
+            file_id: "file_id".to_string(),
+        };
+
+        storage::ProcessTracker {
+            id: get_customer_data_export_task_id(&tracking_data.file_id),
+            name: Some(CUSTOMER_DATA_EXPORT_NAME.to_string()),
+            tag: vec![CUSTOMER_DATA_EXPORT_TAG.to_string()],
+            runner: Some(CUSTOMER_DATA_EXPORT_RUNNER.to_string()),
+            retry_count: 0,
+            schedule_time: Some(now),
+            rule: String::new(),
+            tracking_data: tracking_data.encode_to_value().unwrap(),
+            business_status: business_status.to_string(),
+            status,
+            event: Vec::new(),
+            created_at: now,
+            updated_at: now,
+            version: common_enums::ApiVersion::V1,
+        }
+    }
+
+    #[test]
+    fn test_customer_data_export_status() {
+        let response = get_customer_data_export_response(&get_process(
+            enums::ProcessTrackerStatus::New,
+            business_status::PENDING,
+        ))
+        .unwrap();
+        assert_eq!(response.status, CustomerDataExportStatus::Pending);
+        assert_eq!(response.file_id, "file_id");
+
+        let process = get_process(
+            enums::ProcessTrackerStatus::Finish,
+            business_status::COMPLETED_BY_PT,
+        );
+        assert_eq!(
+            get_customer_data_export_status(&process),
+            CustomerDataExportStatus::Completed
+        );
+
+        let process = get_process(
+            enums::ProcessTrackerStatus::Finish,
+            business_status::GLOBAL_ERROR,
+        );
+        assert_eq!(
+            get_customer_data_export_status(&process),
+            CustomerDataExportStatus::Failed
+        );
+    }
+}
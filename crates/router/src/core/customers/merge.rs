@@ -0,0 +1,567 @@
+//! Merging of two customers of a merchant into one.
+//!
+//! The payment methods, mandates, payments and addresses of the source customer are moved to the
+//! target customer, and the source customer is deleted, in a single database transaction. A
+//! redirect from the source customer ID to the target customer ID is recorded in the same
+//! transaction, so that the source customer can still be retrieved by its ID after the merge.
+//!
+//! Saved payment methods are stored in the locker under the customer ID, and are therefore stored
+//! again under the target customer before the transaction, and removed from the source customer
+//! once the transaction is committed. Both customers are locked for the duration of the merge.
+
+use api_models::{
+    customers::{CustomerMergeRequest, CustomerMergeResponse},
+    enums as api_enums,
+};
+use common_utils::{ext_traits::AsyncExt, id_type};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, utils::StorageErrorExt, CustomResult, RouterResponse, RouterResult},
+        payment_methods::{cards, transformers as payment_methods},
+    },
+    routes::{lock_utils, SessionState},
+    services::ApplicationResponse,
+    types::{api::customers, domain, storage, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+/// A payment method entry stored in the locker under both customers while the customers are
+/// being merged
+struct MovedLockerEntry {
+    payment_method_id: String,
+    is_network_token: bool,
+    source_reference: String,
+    target_reference: String,
+    /// Whether the target entry was created by the merge. The locker returns the existing entry
+    /// of the target customer when the target customer has already saved the same card, and that
+    /// entry must be kept when the merge fails.
+    is_target_created: bool,
+}
+
+#[instrument(skip(state))]
+pub async fn merge_customers(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: CustomerMergeRequest,
+) -> RouterResponse<CustomerMergeResponse> {
+    let merchant_account = merchant_context.get_merchant_account();
+    let merchant_id = merchant_account.get_id();
+    let CustomerMergeRequest {
+        source_customer_id,
+        target_customer_id,
+    } = request;
+
+    if source_customer_id == target_customer_id {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "source_customer_id and target_customer_id must be different".to_string(),
+        }));
+    }
+
+    // Records stored in the key-value store are keyed by the customer ID, and cannot be moved in
+    // a database transaction
+    if merchant_account.storage_scheme == storage::enums::MerchantStorageScheme::RedisKv {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Customers cannot be merged for merchants using the key-value storage scheme"
+                .to_string(),
+        }));
+    }
+
+    let mut acquired_locks = Vec::new();
+    for lock_action in get_customer_lock_actions(&source_customer_id, &target_customer_id) {
+        if let Err(error) = lock_action
+            .clone()
+            .perform_locking_action(&state, merchant_id.to_owned())
+            .await
+        {
+            free_customer_locks(&state, merchant_id, acquired_locks).await;
+            return Err(error);
+        }
+        acquired_locks.push(lock_action);
+    }
+
+    let response = merge_locked_customers(
+        &state,
+        &merchant_context,
+        source_customer_id,
+        target_customer_id,
+    )
+    .await;
+    free_customer_locks(&state, merchant_id, acquired_locks).await;
+
+    response
+}
+
+/// Both customers are locked in the order of their IDs, so that two merges of the same customers
+/// never hold one lock each while waiting for the other
+fn get_customer_lock_actions(
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+) -> Vec<api_locking::LockAction> {
+    let mut customer_ids = vec![source_customer_id, target_customer_id];
+    customer_ids.sort_by_key(|customer_id| customer_id.get_string_repr());
+
+    customer_ids
+        .into_iter()
+        .map(|customer_id| api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: customer_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::Customers,
+                override_lock_retries: None,
+            },
+        })
+        .collect()
+}
+
+/// The locks expire on their own, and failing to free them is therefore only logged
+async fn free_customer_locks(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    lock_actions: Vec<api_locking::LockAction>,
+) {
+    for lock_action in lock_actions {
+        lock_action
+            .free_lock_action(state, merchant_id.to_owned())
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to free customer lock after merging customers"
+                );
+            })
+            .ok();
+    }
+}
+
+async fn merge_locked_customers(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    source_customer_id: id_type::CustomerId,
+    target_customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerMergeResponse> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let merchant_account = merchant_context.get_merchant_account();
+    let key_store = merchant_context.get_merchant_key_store();
+    let merchant_id = merchant_account.get_id();
+
+    let source_customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &source_customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let target_customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &target_customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let source_payment_methods = match db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            &source_customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find payment methods of the source customer"))
+        }
+    };
+
+    let moved_locker_entries = move_locker_entries(
+        state,
+        merchant_id,
+        &source_customer_id,
+        &target_customer_id,
+        &source_payment_methods,
+    )
+    .await?;
+
+    // The default payment method of the source customer becomes the default payment method of
+    // the target customer, if the target customer has none
+    let default_payment_method_update = target_customer
+        .default_payment_method_id
+        .is_none()
+        .then_some(source_customer.default_payment_method_id.clone())
+        .flatten()
+        .map(
+            |default_payment_method_id| storage::CustomerUpdate::UpdateDefaultPaymentMethod {
+                default_payment_method_id: Some(Some(default_payment_method_id)),
+            },
+        );
+    let customer_updates =
+        std::iter::once(get_customer_merge_update(source_customer, &target_customer))
+            .chain(default_payment_method_update)
+            .collect();
+
+    // The payment methods are pointed to the entries of the target customer in the transaction
+    let (network_token_entries, card_entries): (Vec<_>, Vec<_>) = moved_locker_entries
+        .iter()
+        .partition(|entry| entry.is_network_token);
+    let get_locker_ids = |entries: Vec<&MovedLockerEntry>| {
+        entries
+            .into_iter()
+            .map(|entry| {
+                (
+                    entry.payment_method_id.clone(),
+                    entry.target_reference.clone(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    let (redirect, customer) = match db
+        .merge_customer_references(
+            key_manager_state,
+            key_store,
+            storage::CustomerMergeRedirectNew {
+                merchant_id: merchant_id.to_owned(),
+                source_customer_id: source_customer_id.clone(),
+                target_customer_id: target_customer_id.clone(),
+                created_at: common_utils::date_time::now(),
+            },
+            customer_updates,
+            get_locker_ids(card_entries),
+            get_locker_ids(network_token_entries),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the records of the source customer")
+    {
+        Ok(merged) => merged,
+        Err(error) => {
+            remove_created_target_entries(
+                state,
+                merchant_id,
+                &target_customer_id,
+                &moved_locker_entries,
+            )
+            .await;
+            return Err(error);
+        }
+    };
+
+    remove_source_entries(
+        state,
+        merchant_id,
+        &source_customer_id,
+        &moved_locker_entries,
+    )
+    .await;
+
+    let address = customer
+        .address_id
+        .as_ref()
+        .async_map(|address_id| async {
+            db.find_address_by_address_id(key_manager_state, address_id, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find address of the customer")
+        })
+        .await
+        .transpose()?
+        .map(api_models::payments::AddressDetails::from);
+
+    Ok(ApplicationResponse::Json(CustomerMergeResponse {
+        source_customer_id: redirect.source_customer_id,
+        customer: customers::CustomerResponse::foreign_from((customer, address)),
+        merged_at: redirect.created_at,
+    }))
+}
+
+/// The details of the target customer are retained, and only the details missing for the target
+/// customer are taken from the source customer.
+fn get_customer_merge_update(
+    source_customer: domain::Customer,
+    target_customer: &domain::Customer,
+) -> storage::CustomerUpdate {
+    let (phone, phone_country_code) = match target_customer.phone {
+        Some(_) => (None, None),
+        None => (source_customer.phone, source_customer.phone_country_code),
+    };
+
+    storage::CustomerUpdate::Update {
+        name: target_customer
+            .name
+            .is_none()
+            .then_some(source_customer.name)
+            .flatten(),
+        email: target_customer
+            .email
+            .is_none()
+            .then_some(source_customer.email)
+            .flatten(),
+        phone: Box::new(phone),
+        description: target_customer
+            .description
+            .is_none()
+            .then_some(source_customer.description)
+            .flatten(),
+        phone_country_code,
+        metadata: target_customer
+            .metadata
+            .is_none()
+            .then_some(source_customer.metadata)
+            .flatten(),
+        connector_customer: Box::new(
+            target_customer
+                .connector_customer
+                .is_none()
+                .then_some(source_customer.connector_customer)
+                .flatten(),
+        ),
+        address_id: target_customer
+            .address_id
+            .is_none()
+            .then_some(source_customer.address_id)
+            .flatten(),
+    }
+}
+
+/// Stores the locker entries of the payment methods of the source customer under the target
+/// customer. If any entry cannot be stored, the entries already stored are removed.
+async fn move_locker_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+    payment_methods: &[domain::PaymentMethod],
+) -> RouterResult<Vec<MovedLockerEntry>> {
+    // The mock locker looks up entries by their reference alone
+    if state.conf.locker.mock_locker {
+        return Ok(Vec::new());
+    }
+
+    let mut moved_locker_entries = Vec::new();
+    for payment_method in payment_methods {
+        // Cards saved before the locker ID was stored are stored under the payment method ID
+        let locker_id = payment_method.locker_id.as_ref().or_else(|| {
+            (payment_method.get_payment_method_type() == Some(storage::enums::PaymentMethod::Card))
+                .then_some(&payment_method.payment_method_id)
+        });
+        let references = locker_id
+            .map(|reference| (reference, false))
+            .into_iter()
+            .chain(
+                payment_method
+                    .network_token_locker_id
+                    .as_ref()
+                    .map(|reference| (reference, true)),
+            );
+        for (reference, is_network_token) in references {
+            match move_locker_entry(
+                state,
+                merchant_id,
+                source_customer_id,
+                target_customer_id,
+                reference,
+            )
+            .await
+            {
+                Ok((target_reference, is_target_created)) => {
+                    moved_locker_entries.push(MovedLockerEntry {
+                        payment_method_id: payment_method.payment_method_id.clone(),
+                        is_network_token,
+                        source_reference: reference.clone(),
+                        target_reference,
+                        is_target_created,
+                    })
+                }
+                Err(error) => {
+                    remove_created_target_entries(
+                        state,
+                        merchant_id,
+                        target_customer_id,
+                        &moved_locker_entries,
+                    )
+                    .await;
+                    return Err(error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Failed to move payment method {} to the target customer",
+                                payment_method.payment_method_id
+                            )
+                        }));
+                }
+            }
+        }
+    }
+
+    Ok(moved_locker_entries)
+}
+
+/// Stores the locker entry as is under the target customer, and returns the reference of the entry
+/// of the target customer along with whether the entry was created. The locker assigns the
+/// reference, as card references are unique across customers, and returns the existing entry when
+/// the target customer has already saved the same card.
+async fn move_locker_entry(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    source_customer_id: &id_type::CustomerId,
+    target_customer_id: &id_type::CustomerId,
+    reference: &str,
+) -> CustomResult<(String, bool), errors::VaultError> {
+    let locker = &state.conf.locker;
+    let request = payment_methods::mk_get_card_request_hs(
+        state.conf.jwekey.get_inner(),
+        locker,
+        source_customer_id,
+        merchant_id,
+        reference,
+        Some(api_enums::LockerChoice::HyperswitchCardVault),
+        state.tenant.tenant_id.clone(),
+        state.request_id,
+    )
+    .await
+    .change_context(errors::VaultError::FetchPaymentMethodFailed)
+    .attach_printable("Making get payment method request failed")?;
+    let stored_entry = cards::call_locker_api::<payment_methods::RetrieveCardResp>(
+        state,
+        request,
+        "get_pm_from_locker_for_customer_merge",
+        Some(api_enums::LockerChoice::HyperswitchCardVault),
+    )
+    .await
+    .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+    .payload
+    .get_required_value("RetrieveCardRespPayload")
+    .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+
+    let payload = match (stored_entry.card, stored_entry.enc_card_data) {
+        (Some(card), _) => {
+            payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+                merchant_id: merchant_id.to_owned(),
+                merchant_customer_id: target_customer_id.to_owned(),
+                requestor_card_reference: None,
+                card,
+                ttl: locker.ttl_for_storage_in_secs,
+            })
+        }
+        (None, Some(enc_card_data)) => {
+            payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+                merchant_id: merchant_id.to_owned(),
+                merchant_customer_id: target_customer_id.to_owned(),
+                enc_data: enc_card_data.peek().to_owned(),
+                ttl: locker.ttl_for_storage_in_secs,
+            })
+        }
+        (None, None) => Err(report!(errors::VaultError::FetchPaymentMethodFailed)
+            .attach_printable("Locker returned neither card nor payment method data"))?,
+    };
+
+    let stored_card = cards::add_card_to_hs_locker(
+        state,
+        &payload,
+        target_customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await?;
+
+    Ok((
+        stored_card.card_reference,
+        stored_card.duplication_check.is_none(),
+    ))
+}
+
+/// Removes the entries stored under the target customer by a merge that did not complete. Entries
+/// that the target customer already had are kept, as they are referenced by payment methods of
+/// the target customer.
+async fn remove_created_target_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    target_customer_id: &id_type::CustomerId,
+    moved_locker_entries: &[MovedLockerEntry],
+) {
+    let created_entries = moved_locker_entries
+        .iter()
+        .filter(|entry| entry.is_target_created);
+    for entry in created_entries {
+        cards::delete_card_from_hs_locker(
+            state,
+            target_customer_id,
+            merchant_id,
+            &entry.target_reference,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, payment_method_id = %entry.payment_method_id, "Failed to remove locker entry after failing to merge customers");
+        })
+        .ok();
+    }
+}
+
+/// Removing a locker entry is not retried, as the entry is no longer referenced by any payment
+/// method of the source customer
+async fn remove_source_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    source_customer_id: &id_type::CustomerId,
+    moved_locker_entries: &[MovedLockerEntry],
+) {
+    for entry in moved_locker_entries {
+        cards::delete_card_from_hs_locker(
+            state,
+            source_customer_id,
+            merchant_id,
+            &entry.source_reference,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, payment_method_id = %entry.payment_method_id, "Failed to remove locker entry after merging customers");
+        })
+        .ok();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_locking_keys(lock_actions: Vec<api_locking::LockAction>) -> Vec<String> {
+        lock_actions
+            .into_iter()
+            .filter_map(|lock_action| match lock_action {
+                api_locking::LockAction::Hold { input } => Some(input.unique_locking_key),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_customers_are_locked_in_the_same_order_for_either_direction() {
+        let first = id_type::CustomerId::try_from(std::borrow::Cow::from("cus_a")).unwrap();
+        let second = id_type::CustomerId::try_from(std::borrow::Cow::from("cus_b")).unwrap();
+
+        let expected = vec!["cus_a".to_string(), "cus_b".to_string()];
+        assert_eq!(
+            get_locking_keys(get_customer_lock_actions(&first, &second)),
+            expected
+        );
+        assert_eq!(
+            get_locking_keys(get_customer_lock_actions(&second, &first)),
+            expected
+        );
+    }
+}
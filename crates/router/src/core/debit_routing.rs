@@ -206,6 +206,7 @@ pub async fn check_for_debit_routing_connector_in_profile<
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
+        None,
     );
 
     let fallback_config_optional = super::routing::helpers::get_merchant_default_config(
@@ -114,6 +114,22 @@ pub enum UserErrors {
     InvalidCloneConnectorOperation(String),
     #[error("Error cloning connector: {0}")]
     ErrorCloningConnector(String),
+    #[error("InvalidPasskey")]
+    InvalidPasskey,
+    #[error("PasskeyNotSetup")]
+    PasskeyNotSetup,
+    #[error("PasskeyChallengeNotFound")]
+    PasskeyChallengeNotFound,
+    #[error("Maximum attempts reached for Passkey")]
+    MaxPasskeyAttemptsReached,
+    #[error("PasskeyRequired")]
+    PasskeyRequired,
+    #[error("SCIM resource not found")]
+    ScimResourceNotFound,
+    #[error("SCIM resource already exists")]
+    ScimResourceConflict,
+    #[error("Invalid SCIM request: {0}")]
+    InvalidScimRequest(String),
 }
 
 impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorResponse> for UserErrors {
@@ -298,6 +314,30 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
                 self.get_error_message(),
                 None,
             )),
+            Self::InvalidPasskey => {
+                AER::BadRequest(ApiError::new(sub_code, 60, self.get_error_message(), None))
+            }
+            Self::PasskeyNotSetup => {
+                AER::BadRequest(ApiError::new(sub_code, 61, self.get_error_message(), None))
+            }
+            Self::PasskeyChallengeNotFound => {
+                AER::BadRequest(ApiError::new(sub_code, 62, self.get_error_message(), None))
+            }
+            Self::MaxPasskeyAttemptsReached => {
+                AER::BadRequest(ApiError::new(sub_code, 63, self.get_error_message(), None))
+            }
+            Self::PasskeyRequired => {
+                AER::BadRequest(ApiError::new(sub_code, 64, self.get_error_message(), None))
+            }
+            Self::ScimResourceNotFound => {
+                AER::NotFound(ApiError::new(sub_code, 65, self.get_error_message(), None))
+            }
+            Self::ScimResourceConflict => {
+                AER::Conflict(ApiError::new(sub_code, 66, self.get_error_message(), None))
+            }
+            Self::InvalidScimRequest(_) => {
+                AER::BadRequest(ApiError::new(sub_code, 67, self.get_error_message(), None))
+            }
         }
     }
 }
@@ -374,6 +414,16 @@ impl UserErrors {
             Self::ErrorCloningConnector(error_message) => {
                 format!("Error cloning connector: {}", error_message)
             }
+            Self::InvalidPasskey => "Invalid Passkey".to_string(),
+            Self::PasskeyNotSetup => "Passkey not setup".to_string(),
+            Self::PasskeyChallengeNotFound => "Passkey challenge expired or not found".to_string(),
+            Self::MaxPasskeyAttemptsReached => "Maximum attempts reached for Passkey".to_string(),
+            Self::PasskeyRequired => "Passkey required".to_string(),
+            Self::ScimResourceNotFound => "SCIM resource not found".to_string(),
+            Self::ScimResourceConflict => "SCIM resource already exists".to_string(),
+            Self::InvalidScimRequest(error_message) => {
+                format!("Invalid SCIM request: {}", error_message)
+            }
         }
     }
 }
@@ -17,6 +17,9 @@ use crate::{errors, routes::SessionState, services, types::domain};
 #[cfg(feature = "v1")]
 use crate::{services::logger, types::api};
 
+#[cfg(feature = "v1")]
+pub mod vault_migration;
+
 #[cfg(feature = "v2")]
 pub async fn rust_locker_migration(
     _state: SessionState,
@@ -0,0 +1,1280 @@
+//! Resumable migration of saved cards between the locker and the vaults of the merchant.
+//!
+//! Cards can be migrated to another deployment of the locker, from an external vault back to the
+//! locker, or from the locker to a vault processor connector of the merchant. A migration is run
+//! by the scheduler in batches of payment methods ordered by their identifier, and the last
+//! processed payment method is stored in the tracking data of the process tracker task after
+//! every batch, so that a failed or interrupted migration can be resumed from where it stopped.
+//!
+//! Every card copied to the destination vault is read back and compared with the source vault by
+//! its fingerprint, and the payment method is then pointed to the destination vault. A locker
+//! deployment is the exception, since the card is stored under the same card reference and the
+//! migration is completed by switching the locker configuration. The outcome for every card is
+//! recorded as a vault migration entry, which also serves as the list of payment methods to be
+//! pointed back to the source vault when the migration is rolled back. The cards are never
+//! removed from the source vault by a migration.
+
+use api_models::{
+    enums as api_enums,
+    locker_migration::{
+        VaultMigrationDestination, VaultMigrationEntriesListConstraints,
+        VaultMigrationEntriesResponse, VaultMigrationEntryResponse, VaultMigrationOperation,
+        VaultMigrationProgress, VaultMigrationRequest, VaultMigrationResponse,
+        VaultMigrationStatus,
+    },
+    payment_methods::Card,
+};
+use common_utils::{
+    crypto::{GenerateDigest, Sha512},
+    ext_traits::{Encode, ValueExt},
+    generate_id, id_type,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, utils::StorageErrorExt, CustomResult, RouterResponse, RouterResult},
+        payment_methods::{cards, transformers as payment_methods, vault},
+    },
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums, VaultMigrationTrackingData},
+    },
+    utils::OptionExt,
+};
+
+const VAULT_MIGRATION_TAG: &str = "VAULT_MIGRATION";
+const VAULT_MIGRATION_NAME: &str = "VAULT_MIGRATION";
+const VAULT_MIGRATION_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::VaultMigrationWorkflow;
+
+/// Vault to which the cards of a migration are written
+enum DestinationVault<'a> {
+    Locker(&'a settings::Locker),
+    External(Box<domain::MerchantConnectorAccount>),
+}
+
+impl DestinationVault<'_> {
+    fn get_connector_id(&self) -> Option<id_type::MerchantConnectorAccountId> {
+        match self {
+            Self::Locker(_) => None,
+            Self::External(merchant_connector_account) => Some(merchant_connector_account.get_id()),
+        }
+    }
+}
+
+/// Schedules a migration of the saved cards of the merchant to the destination vault.
+///
+/// If the previous migration of the merchant failed, and was started with the same `dry_run`
+/// value and destination, it is resumed from the last completed batch instead of starting over.
+#[instrument(skip_all)]
+pub async fn start_vault_migration(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+    req: VaultMigrationRequest,
+) -> RouterResponse<VaultMigrationResponse> {
+    let dry_run = req.dry_run.unwrap_or(false);
+    let destination = req.destination.unwrap_or_default();
+    let batch_size = req
+        .batch_size
+        .unwrap_or(consts::DEFAULT_VAULT_MIGRATION_BATCH_SIZE);
+
+    if !(1..=consts::MAX_VAULT_MIGRATION_BATCH_SIZE).contains(&batch_size) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`batch_size` must be between 1 and {}",
+                consts::MAX_VAULT_MIGRATION_BATCH_SIZE
+            ),
+        }));
+    }
+    get_merchant_context(&state, merchant_id).await?;
+    get_destination_vault(&state, merchant_id, &destination).await?;
+
+    let task_id = get_vault_migration_task_id(merchant_id);
+    let existing_process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch vault migration task")?;
+
+    let new_tracking_data = VaultMigrationTrackingData {
+        migration_id: generate_id(consts::ID_LENGTH, "vmg"),
+        merchant_id: merchant_id.clone(),
+        operation: VaultMigrationOperation::Migrate,
+        dry_run,
+        batch_size,
+        destination: destination.clone(),
+        starting_after_payment_method_id: None,
+        progress: VaultMigrationProgress::default(),
+    };
+
+    let process = match existing_process {
+        Some(process) => {
+            ensure_vault_migration_not_running(&process)?;
+            let previous_tracking_data = get_tracking_data(&process)?;
+            let tracking_data = if get_vault_migration_status(&process)
+                == VaultMigrationStatus::Failed
+                && previous_tracking_data.operation == VaultMigrationOperation::Migrate
+                && previous_tracking_data.dry_run == dry_run
+                && previous_tracking_data.destination == destination
+            {
+                VaultMigrationTrackingData {
+                    batch_size,
+                    ..previous_tracking_data
+                }
+            } else {
+                new_tracking_data
+            };
+            reschedule_vault_migration(&state, process, tracking_data).await?
+        }
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                VAULT_MIGRATION_NAME,
+                VAULT_MIGRATION_RUNNER,
+                [VAULT_MIGRATION_TAG],
+                new_tracking_data,
+                None,
+                common_utils::date_time::now(),
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct vault migration process tracker task")?;
+
+            let process = state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert vault migration task to process_tracker")?;
+            metrics::TASKS_ADDED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("flow", "VaultMigration")),
+            );
+            process
+        }
+    };
+
+    get_vault_migration_response(&process).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_vault_migration(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResponse<VaultMigrationResponse> {
+    let process = find_vault_migration_process(&state, merchant_id).await?;
+    get_vault_migration_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Lists the outcome for the cards of the latest migration of the merchant. The entries with the
+/// `migrated` status are the payment methods that are pointed back to the source vault on a
+/// rollback.
+#[instrument(skip_all)]
+pub async fn list_vault_migration_entries(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+    constraints: VaultMigrationEntriesListConstraints,
+) -> RouterResponse<VaultMigrationEntriesResponse> {
+    let process = find_vault_migration_process(&state, merchant_id).await?;
+    let tracking_data = get_tracking_data(&process)?;
+
+    let entries = state
+        .store
+        .list_vault_migration_entries_by_migration_id(
+            &tracking_data.migration_id,
+            constraints.status,
+            i64::from(
+                constraints
+                    .limit
+                    .unwrap_or(consts::DEFAULT_VAULT_MIGRATION_ENTRIES_LIST_LIMIT),
+            ),
+            i64::from(constraints.offset.unwrap_or_default()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list vault migration entries")?;
+
+    let data = entries
+        .into_iter()
+        .map(|entry| VaultMigrationEntryResponse {
+            payment_method_id: entry.payment_method_id,
+            customer_id: entry.customer_id,
+            source_locker_id: entry.source_locker_id,
+            source_connector_id: entry.source_connector_id,
+            destination_locker_id: entry.destination_locker_id,
+            destination_connector_id: entry.destination_connector_id,
+            destination_reference_created: entry.destination_reference_created,
+            status: entry.status,
+            error_message: entry.error_message,
+            created_at: entry.created_at,
+            modified_at: entry.modified_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(VaultMigrationEntriesResponse {
+        migration_id: tracking_data.migration_id,
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Schedules the rollback of the latest migration of the merchant. The payment methods that were
+/// migrated are pointed back to the card in the source vault, and the cards that were created in
+/// the destination vault by the migration are removed from it.
+#[instrument(skip_all)]
+pub async fn rollback_vault_migration(
+    state: SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResponse<VaultMigrationResponse> {
+    let process = find_vault_migration_process(&state, merchant_id).await?;
+    ensure_vault_migration_not_running(&process)?;
+    let previous_tracking_data = get_tracking_data(&process)?;
+
+    if previous_tracking_data.dry_run {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A dry run of the vault migration has no cards to be rolled back".to_string(),
+        }));
+    }
+    get_destination_vault(&state, merchant_id, &previous_tracking_data.destination).await?;
+
+    let tracking_data = if get_vault_migration_status(&process) == VaultMigrationStatus::Failed
+        && previous_tracking_data.operation == VaultMigrationOperation::Rollback
+    {
+        previous_tracking_data
+    } else {
+        VaultMigrationTrackingData {
+            operation: VaultMigrationOperation::Rollback,
+            starting_after_payment_method_id: None,
+            progress: VaultMigrationProgress::default(),
+            ..previous_tracking_data
+        }
+    };
+
+    let process = reschedule_vault_migration(&state, process, tracking_data).await?;
+    get_vault_migration_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Runs a single batch of the migration or rollback, and returns the tracking data to be stored
+/// for the next batch, along with whether all batches have been run.
+#[instrument(skip_all)]
+pub async fn run_vault_migration_batch(
+    state: &SessionState,
+    tracking_data: VaultMigrationTrackingData,
+) -> RouterResult<(VaultMigrationTrackingData, bool)> {
+    match tracking_data.operation {
+        VaultMigrationOperation::Migrate => migrate_batch(state, tracking_data).await,
+        VaultMigrationOperation::Rollback => rollback_batch(state, tracking_data).await,
+    }
+}
+
+async fn migrate_batch(
+    state: &SessionState,
+    mut tracking_data: VaultMigrationTrackingData,
+) -> RouterResult<(VaultMigrationTrackingData, bool)> {
+    let merchant_context = get_merchant_context(state, &tracking_data.merchant_id).await?;
+    let destination = get_destination_vault(
+        state,
+        &tracking_data.merchant_id,
+        &tracking_data.destination,
+    )
+    .await?;
+
+    let payment_methods = match state
+        .store
+        .find_card_payment_method_list_starting_after(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            &tracking_data.merchant_id,
+            tracking_data.starting_after_payment_method_id.as_deref(),
+            i64::from(tracking_data.batch_size),
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to list saved cards of the merchant"))
+        }
+    };
+
+    for payment_method in &payment_methods {
+        let outcome = migrate_payment_method(
+            state,
+            &merchant_context,
+            &destination,
+            &tracking_data,
+            payment_method,
+        )
+        .await?;
+        record_outcome(&mut tracking_data.progress, outcome);
+    }
+
+    let is_complete = payment_methods.len() < usize::from(tracking_data.batch_size);
+    if let Some(payment_method) = payment_methods.last() {
+        tracking_data.starting_after_payment_method_id =
+            Some(payment_method.payment_method_id.clone());
+    }
+
+    Ok((tracking_data, is_complete))
+}
+
+/// Copies the card of the payment method to the destination vault, verifies the copy and points
+/// the payment method to it. Returns `None` if the payment method does not have a card to be
+/// migrated to the destination.
+async fn migrate_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    destination: &DestinationVault<'_>,
+    tracking_data: &VaultMigrationTrackingData,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Option<enums::VaultMigrationEntryStatus>> {
+    let Some(locker_id) = payment_method.locker_id.as_deref() else {
+        return Ok(None);
+    };
+
+    // The card was already migrated before the batch was interrupted, in which case the payment
+    // method may not have been pointed to the destination vault yet
+    if let Some(entry) = state
+        .store
+        .find_optional_vault_migration_entry(
+            &tracking_data.migration_id,
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch vault migration entry")?
+    {
+        if entry.status == enums::VaultMigrationEntryStatus::Migrated
+            && is_migration_required(
+                &tracking_data.destination,
+                payment_method.external_vault_source.as_ref(),
+            )
+        {
+            if let Some(destination_locker_id) = entry.destination_locker_id {
+                repoint_payment_method(
+                    state,
+                    merchant_context,
+                    &tracking_data.destination,
+                    payment_method.clone(),
+                    destination_locker_id,
+                    entry.destination_connector_id,
+                )
+                .await?;
+            }
+        }
+        return Ok(Some(entry.status));
+    }
+
+    if !is_migration_required(
+        &tracking_data.destination,
+        payment_method.external_vault_source.as_ref(),
+    ) {
+        return Ok(None);
+    }
+
+    let source_card = match cards::get_card_from_vault(
+        state,
+        &payment_method.customer_id,
+        &tracking_data.merchant_id,
+        locker_id,
+        payment_method.external_vault_source.as_ref(),
+    )
+    .await
+    {
+        Ok(card) => card,
+        Err(error) => {
+            logger::error!(?error, payment_method_id = %payment_method.payment_method_id, "Failed to fetch card from source vault");
+            return insert_vault_migration_entry(
+                state,
+                tracking_data,
+                payment_method,
+                locker_id,
+                None,
+                enums::VaultMigrationEntryStatus::Failed,
+                Some("Failed to fetch card from source vault"),
+            )
+            .await;
+        }
+    };
+
+    if tracking_data.dry_run {
+        return Ok(Some(enums::VaultMigrationEntryStatus::Migrated));
+    }
+
+    // A locker deployment must store the card under the same reference, since the payment method
+    // is not updated for it
+    let requestor_card_reference = match tracking_data.destination {
+        VaultMigrationDestination::LockerDeployment => Some(locker_id),
+        VaultMigrationDestination::Locker | VaultMigrationDestination::ExternalVault { .. } => None,
+    };
+    let (destination_card_reference, is_reference_created) = match add_card_to_destination_vault(
+        state,
+        destination,
+        &tracking_data.merchant_id,
+        &payment_method.customer_id,
+        requestor_card_reference,
+        source_card.clone(),
+    )
+    .await
+    {
+        Ok(stored_card) => stored_card,
+        Err(error) => {
+            logger::error!(?error, payment_method_id = %payment_method.payment_method_id, "Failed to store card in destination vault");
+            return insert_vault_migration_entry(
+                state,
+                tracking_data,
+                payment_method,
+                locker_id,
+                None,
+                enums::VaultMigrationEntryStatus::Failed,
+                Some("Failed to store card in destination vault"),
+            )
+            .await;
+        }
+    };
+    let destination_reference = DestinationReference {
+        card_reference: destination_card_reference.clone(),
+        connector_id: destination.get_connector_id(),
+        is_created: is_reference_created,
+    };
+
+    let verification_error = if requestor_card_reference
+        .is_some_and(|card_reference| card_reference != destination_card_reference)
+    {
+        Some("Destination locker stored the card under a different card reference")
+    } else {
+        match get_card_from_destination_vault(
+            state,
+            destination,
+            &tracking_data.merchant_id,
+            &payment_method.customer_id,
+            &destination_card_reference,
+        )
+        .await
+        {
+            Ok(destination_card) => (get_card_fingerprint(&destination_card)?
+                != get_card_fingerprint(&source_card)?)
+            .then_some("Card fingerprint in destination vault does not match source vault"),
+            Err(error) => {
+                logger::error!(?error, payment_method_id = %payment_method.payment_method_id, "Failed to fetch card from destination vault");
+                Some("Failed to fetch card from destination vault")
+            }
+        }
+    };
+
+    if let Some(error_message) = verification_error {
+        // A card that was already stored in the destination vault before the migration is
+        // left untouched
+        if is_reference_created {
+            delete_card_from_destination_vault(
+                state,
+                destination,
+                &tracking_data.merchant_id,
+                &payment_method.customer_id,
+                &destination_card_reference,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, payment_method_id = %payment_method.payment_method_id, "Failed to delete unverified card from destination vault");
+            })
+            .ok();
+        }
+
+        return insert_vault_migration_entry(
+            state,
+            tracking_data,
+            payment_method,
+            locker_id,
+            Some(destination_reference),
+            enums::VaultMigrationEntryStatus::VerificationFailed,
+            Some(error_message),
+        )
+        .await;
+    }
+
+    // The entry is recorded before the payment method is updated, so that the payment method can
+    // be pointed to the destination vault when an interrupted batch is resumed
+    let status = insert_vault_migration_entry(
+        state,
+        tracking_data,
+        payment_method,
+        locker_id,
+        Some(destination_reference),
+        enums::VaultMigrationEntryStatus::Migrated,
+        None,
+    )
+    .await?;
+
+    repoint_payment_method(
+        state,
+        merchant_context,
+        &tracking_data.destination,
+        payment_method.clone(),
+        destination_card_reference,
+        destination.get_connector_id(),
+    )
+    .await?;
+
+    Ok(status)
+}
+
+/// Returns whether the card of a payment method stored in the given vault is to be migrated to
+/// the destination. Only cards in the locker can be migrated to another locker deployment, and
+/// cards that are already in the destination vault are skipped.
+fn is_migration_required(
+    destination: &VaultMigrationDestination,
+    external_vault_source: Option<&id_type::MerchantConnectorAccountId>,
+) -> bool {
+    match destination {
+        VaultMigrationDestination::LockerDeployment => external_vault_source.is_none(),
+        VaultMigrationDestination::Locker => external_vault_source.is_some(),
+        VaultMigrationDestination::ExternalVault {
+            merchant_connector_id,
+        } => external_vault_source != Some(merchant_connector_id),
+    }
+}
+
+/// Points the payment method to the card in the given vault. Payment methods are not updated for
+/// a locker deployment, since the card is stored under the same reference.
+async fn repoint_payment_method(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    destination: &VaultMigrationDestination,
+    payment_method: domain::PaymentMethod,
+    locker_id: String,
+    external_vault_source: Option<id_type::MerchantConnectorAccountId>,
+) -> RouterResult<()> {
+    if *destination == VaultMigrationDestination::LockerDeployment {
+        return Ok(());
+    }
+
+    state
+        .store
+        .update_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method,
+            storage::PaymentMethodUpdate::VaultMigrationUpdate {
+                locker_id,
+                external_vault_source,
+            },
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update vault of the payment method")?;
+
+    Ok(())
+}
+
+async fn rollback_batch(
+    state: &SessionState,
+    mut tracking_data: VaultMigrationTrackingData,
+) -> RouterResult<(VaultMigrationTrackingData, bool)> {
+    let merchant_context = get_merchant_context(state, &tracking_data.merchant_id).await?;
+    let destination = get_destination_vault(
+        state,
+        &tracking_data.merchant_id,
+        &tracking_data.destination,
+    )
+    .await?;
+
+    let entries = state
+        .store
+        .list_vault_migration_entries_starting_after(
+            &tracking_data.migration_id,
+            enums::VaultMigrationEntryStatus::Migrated,
+            tracking_data.starting_after_payment_method_id.as_deref(),
+            i64::from(tracking_data.batch_size),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list vault migration entries")?;
+
+    for entry in &entries {
+        let entry_update = match rollback_entry(
+            state,
+            &merchant_context,
+            &destination,
+            &tracking_data,
+            entry,
+        )
+        .await
+        {
+            Ok(()) => storage::VaultMigrationEntryUpdate {
+                status: enums::VaultMigrationEntryStatus::RolledBack,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::error!(?error, payment_method_id = %entry.payment_method_id, "Failed to roll back vault migration entry");
+                storage::VaultMigrationEntryUpdate {
+                    status: enums::VaultMigrationEntryStatus::Migrated,
+                    error_message: Some(
+                        "Failed to roll back the card from the destination vault".to_string(),
+                    ),
+                }
+            }
+        };
+
+        let status = entry_update.status;
+        state
+            .store
+            .update_vault_migration_entry(
+                &entry.migration_id,
+                &entry.payment_method_id,
+                entry_update,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update vault migration entry")?;
+
+        record_outcome(
+            &mut tracking_data.progress,
+            Some(match status {
+                enums::VaultMigrationEntryStatus::RolledBack => status,
+                _ => enums::VaultMigrationEntryStatus::Failed,
+            }),
+        );
+    }
+
+    let is_complete = entries.len() < usize::from(tracking_data.batch_size);
+    if let Some(entry) = entries.last() {
+        tracking_data.starting_after_payment_method_id = Some(entry.payment_method_id.clone());
+    }
+
+    Ok((tracking_data, is_complete))
+}
+
+/// Points the payment method of the entry back to the source vault, if it still refers to the
+/// destination vault, and then removes the card from the destination vault if it was created by
+/// the migration.
+async fn rollback_entry(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    destination: &DestinationVault<'_>,
+    tracking_data: &VaultMigrationTrackingData,
+    entry: &storage::VaultMigrationEntry,
+) -> RouterResult<()> {
+    let destination_card_reference = entry
+        .destination_locker_id
+        .as_deref()
+        .unwrap_or(&entry.source_locker_id);
+
+    if tracking_data.destination != VaultMigrationDestination::LockerDeployment {
+        match state
+            .store
+            .find_payment_method(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                &entry.payment_method_id,
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_method)
+                if payment_method.locker_id.as_deref() == Some(destination_card_reference)
+                    && payment_method.external_vault_source == entry.destination_connector_id =>
+            {
+                repoint_payment_method(
+                    state,
+                    merchant_context,
+                    &tracking_data.destination,
+                    payment_method,
+                    entry.source_locker_id.clone(),
+                    entry.source_connector_id.clone(),
+                )
+                .await?;
+            }
+            Ok(_) => (),
+            Err(error) if error.current_context().is_db_not_found() => (),
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch payment method of vault migration entry"))
+            }
+        }
+    }
+
+    if entry.destination_reference_created {
+        delete_card_from_destination_vault(
+            state,
+            destination,
+            &tracking_data.merchant_id,
+            &entry.customer_id,
+            destination_card_reference,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Card stored in the destination vault for a payment method
+struct DestinationReference {
+    card_reference: String,
+    connector_id: Option<id_type::MerchantConnectorAccountId>,
+    /// Whether the card was created in the destination vault by the migration, and is therefore
+    /// removed from it on a rollback
+    is_created: bool,
+}
+
+async fn insert_vault_migration_entry(
+    state: &SessionState,
+    tracking_data: &VaultMigrationTrackingData,
+    payment_method: &domain::PaymentMethod,
+    source_locker_id: &str,
+    destination_reference: Option<DestinationReference>,
+    status: enums::VaultMigrationEntryStatus,
+    error_message: Option<&str>,
+) -> RouterResult<Option<enums::VaultMigrationEntryStatus>> {
+    let now = common_utils::date_time::now();
+    let (destination_locker_id, destination_connector_id, destination_reference_created) =
+        destination_reference.map_or((None, None, false), |reference| {
+            (
+                Some(reference.card_reference),
+                reference.connector_id,
+                reference.is_created,
+            )
+        });
+
+    state
+        .store
+        .insert_vault_migration_entry(storage::VaultMigrationEntryNew {
+            migration_id: tracking_data.migration_id.clone(),
+            payment_method_id: payment_method.payment_method_id.clone(),
+            merchant_id: tracking_data.merchant_id.clone(),
+            customer_id: payment_method.customer_id.clone(),
+            source_locker_id: source_locker_id.to_owned(),
+            source_connector_id: payment_method.external_vault_source.clone(),
+            destination_locker_id,
+            destination_connector_id,
+            destination_reference_created,
+            status,
+            error_message: error_message.map(str::to_owned),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert vault migration entry")?;
+
+    Ok(Some(status))
+}
+
+fn record_outcome(
+    progress: &mut VaultMigrationProgress,
+    outcome: Option<enums::VaultMigrationEntryStatus>,
+) {
+    progress.processed = progress.processed.saturating_add(1);
+    let counter = match outcome {
+        None => &mut progress.skipped,
+        Some(enums::VaultMigrationEntryStatus::Migrated) => &mut progress.migrated,
+        Some(enums::VaultMigrationEntryStatus::VerificationFailed) => {
+            &mut progress.verification_failed
+        }
+        Some(enums::VaultMigrationEntryStatus::Failed) => &mut progress.failed,
+        Some(enums::VaultMigrationEntryStatus::RolledBack) => &mut progress.rolled_back,
+    };
+    *counter = counter.saturating_add(1);
+}
+
+/// The fingerprint is only compared in memory, and is never stored
+fn get_card_fingerprint(card: &Card) -> RouterResult<Vec<u8>> {
+    Sha512
+        .generate_digest(
+            format!(
+                "{}:{}:{}",
+                card.card_number.get_card_no(),
+                card.card_exp_month.peek(),
+                card.card_exp_year.peek()
+            )
+            .as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate card fingerprint")
+}
+
+/// Stores the card in the destination vault, and returns the reference of the card along with
+/// whether it was created by this call, rather than already being stored in the vault.
+async fn add_card_to_destination_vault(
+    state: &SessionState,
+    destination: &DestinationVault<'_>,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    requestor_card_reference: Option<&str>,
+    card: Card,
+) -> RouterResult<(String, bool)> {
+    match destination {
+        DestinationVault::Locker(locker) => add_card_to_destination_locker(
+            state,
+            locker,
+            merchant_id,
+            customer_id,
+            requestor_card_reference,
+            card,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError),
+        // The connector always creates a new reference for the card
+        DestinationVault::External(merchant_connector_account) => {
+            vault::add_card_to_vault_external(state, merchant_connector_account, card)
+                .await
+                .map(|connector_vault_id| (connector_vault_id, true))
+        }
+    }
+}
+
+async fn get_card_from_destination_vault(
+    state: &SessionState,
+    destination: &DestinationVault<'_>,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> RouterResult<Card> {
+    match destination {
+        DestinationVault::Locker(locker) => get_card_from_destination_locker(
+            state,
+            locker,
+            merchant_id,
+            customer_id,
+            card_reference,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError),
+        DestinationVault::External(merchant_connector_account) => {
+            vault::get_card_from_vault_external(state, merchant_connector_account, card_reference)
+                .await
+        }
+    }
+}
+
+async fn delete_card_from_destination_vault(
+    state: &SessionState,
+    destination: &DestinationVault<'_>,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> RouterResult<()> {
+    match destination {
+        DestinationVault::Locker(locker) => delete_card_from_destination_locker(
+            state,
+            locker,
+            merchant_id,
+            customer_id,
+            card_reference,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .map(|_| ()),
+        DestinationVault::External(merchant_connector_account) => {
+            vault::delete_card_from_vault_external(
+                state,
+                merchant_connector_account,
+                card_reference,
+            )
+            .await
+        }
+    }
+}
+
+async fn add_card_to_destination_locker(
+    state: &SessionState,
+    destination: &settings::Locker,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    requestor_card_reference: Option<&str>,
+    card: Card,
+) -> CustomResult<(String, bool), errors::VaultError> {
+    let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+        merchant_id: merchant_id.to_owned(),
+        merchant_customer_id: customer_id.to_owned(),
+        requestor_card_reference: requestor_card_reference.map(str::to_owned),
+        card,
+        ttl: destination.ttl_for_storage_in_secs,
+    });
+
+    let stored_card_response = if !destination.mock_locker {
+        let request = payment_methods::mk_add_locker_request_hs(
+            state.conf.jwekey.get_inner(),
+            destination,
+            &payload,
+            api_enums::LockerChoice::HyperswitchCardVault,
+            state.tenant.tenant_id.clone(),
+            state.request_id,
+        )
+        .await?;
+        cards::call_locker_api::<payment_methods::StoreCardResp>(
+            state,
+            request,
+            "add_card_to_destination_locker",
+            Some(api_enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?
+    } else {
+        let card_id = requestor_card_reference
+            .map(str::to_owned)
+            .unwrap_or_else(|| generate_id(consts::ID_LENGTH, "card"));
+        cards::mock_call_to_locker_hs(
+            &*state.store,
+            &card_id,
+            &payload,
+            None,
+            None,
+            Some(customer_id),
+        )
+        .await?
+    };
+
+    // The locker reports a duplication check only when the card was already stored in it
+    stored_card_response
+        .payload
+        .get_required_value("StoreCardRespPayload")
+        .change_context(errors::VaultError::SaveCardFailed)
+        .map(|stored_card| {
+            (
+                stored_card.card_reference,
+                stored_card.duplication_check.is_none(),
+            )
+        })
+}
+
+async fn get_card_from_destination_locker(
+    state: &SessionState,
+    destination: &settings::Locker,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> CustomResult<Card, errors::VaultError> {
+    if !destination.mock_locker {
+        let request = payment_methods::mk_get_card_request_hs(
+            state.conf.jwekey.get_inner(),
+            destination,
+            customer_id,
+            merchant_id,
+            card_reference,
+            Some(api_enums::LockerChoice::HyperswitchCardVault),
+            state.tenant.tenant_id.clone(),
+            state.request_id,
+        )
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Making get card request failed")?;
+        cards::call_locker_api::<payment_methods::RetrieveCardResp>(
+            state,
+            request,
+            "get_card_from_destination_locker",
+            Some(api_enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?
+        .payload
+        .and_then(|payload| payload.card)
+        .get_required_value("Card")
+        .change_context(errors::VaultError::FetchCardFailed)
+    } else {
+        let (get_card_resp, _) = cards::mock_get_card(&*state.store, card_reference).await?;
+        payment_methods::mk_get_card_response(get_card_resp)
+            .change_context(errors::VaultError::ResponseDeserializationFailed)
+    }
+}
+
+async fn delete_card_from_destination_locker(
+    state: &SessionState,
+    destination: &settings::Locker,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+    if !destination.mock_locker {
+        let request = payment_methods::mk_delete_card_request_hs(
+            state.conf.jwekey.get_inner(),
+            destination,
+            customer_id,
+            merchant_id,
+            card_reference,
+            state.tenant.tenant_id.clone(),
+            state.request_id,
+        )
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)
+        .attach_printable("Making delete card request failed")?;
+        cards::call_locker_api::<payment_methods::DeleteCardResp>(
+            state,
+            request,
+            "delete_card_from_destination_locker",
+            Some(api_enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)
+    } else {
+        cards::mock_delete_card_hs(&*state.store, card_reference)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed)
+    }
+}
+
+async fn get_destination_vault<'a>(
+    state: &'a SessionState,
+    merchant_id: &id_type::MerchantId,
+    destination: &VaultMigrationDestination,
+) -> RouterResult<DestinationVault<'a>> {
+    match destination {
+        VaultMigrationDestination::LockerDeployment => state
+            .conf
+            .vault_migration_locker
+            .as_ref()
+            .map(DestinationVault::Locker)
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Destination locker for vault migrations is not configured"
+                        .to_string(),
+                })
+            }),
+        VaultMigrationDestination::Locker => Ok(DestinationVault::Locker(&state.conf.locker)),
+        VaultMigrationDestination::ExternalVault {
+            merchant_connector_id,
+        } => vault::get_external_vault_connector_account(state, merchant_id, merchant_connector_id)
+            .await
+            .map(|merchant_connector_account| {
+                DestinationVault::External(Box::new(merchant_connector_account))
+            }),
+    }
+}
+
+async fn get_merchant_context(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<domain::MerchantContext> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok(domain::MerchantContext::NormalMerchant(Box::new(
+        domain::Context(merchant_account, key_store),
+    )))
+}
+
+async fn find_vault_migration_process(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_vault_migration_task_id(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch vault migration task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "No vault migration found for the merchant".to_string(),
+            })
+        })
+}
+
+async fn reschedule_vault_migration(
+    state: &SessionState,
+    process: storage::ProcessTracker,
+    tracking_data: VaultMigrationTrackingData,
+) -> RouterResult<storage::ProcessTracker> {
+    let now = common_utils::date_time::now();
+    let process = state
+        .store
+        .as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(now),
+                tracking_data: Some(
+                    tracking_data
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                ),
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update vault migration task")?;
+    metrics::TASKS_RESET_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "VaultMigration")),
+    );
+
+    Ok(process)
+}
+
+fn ensure_vault_migration_not_running(process: &storage::ProcessTracker) -> RouterResult<()> {
+    if get_vault_migration_status(process) == VaultMigrationStatus::InProgress {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A vault migration is already in progress for the merchant".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+fn get_vault_migration_status(process: &storage::ProcessTracker) -> VaultMigrationStatus {
+    match process.status {
+        enums::ProcessTrackerStatus::Finish
+            if process.business_status == business_status::COMPLETED_BY_PT =>
+        {
+            VaultMigrationStatus::Completed
+        }
+        enums::ProcessTrackerStatus::Finish => VaultMigrationStatus::Failed,
+        _ => VaultMigrationStatus::InProgress,
+    }
+}
+
+fn get_vault_migration_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<VaultMigrationResponse> {
+    let tracking_data = get_tracking_data(process)?;
+
+    Ok(VaultMigrationResponse {
+        migration_id: tracking_data.migration_id,
+        merchant_id: tracking_data.merchant_id,
+        operation: tracking_data.operation,
+        status: get_vault_migration_status(process),
+        dry_run: tracking_data.dry_run,
+        batch_size: tracking_data.batch_size,
+        destination: tracking_data.destination,
+        progress: tracking_data.progress,
+        created_at: process.created_at,
+        updated_at: process.updated_at,
+    })
+}
+
+fn get_tracking_data(
+    process: &storage::ProcessTracker,
+) -> RouterResult<VaultMigrationTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("VaultMigrationTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_vault_migration_task_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{VAULT_MIGRATION_RUNNER}_{VAULT_MIGRATION_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_is_migration_required() {
+        let connector_id =
+            id_type::MerchantConnectorAccountId::wrap("mca_source".to_string()).unwrap();
+        let other_connector_id =
+            id_type::MerchantConnectorAccountId::wrap("mca_destination".to_string()).unwrap();
+        let external_vault = VaultMigrationDestination::ExternalVault {
+            merchant_connector_id: connector_id.clone(),
+        };
+
+        assert!(is_migration_required(
+            &VaultMigrationDestination::LockerDeployment,
+            None
+        ));
+        assert!(!is_migration_required(
+            &VaultMigrationDestination::LockerDeployment,
+            Some(&connector_id)
+        ));
+        assert!(!is_migration_required(
+            &VaultMigrationDestination::Locker,
+            None
+        ));
+        assert!(is_migration_required(
+            &VaultMigrationDestination::Locker,
+            Some(&connector_id)
+        ));
+        assert!(is_migration_required(&external_vault, None));
+        assert!(!is_migration_required(&external_vault, Some(&connector_id)));
+        assert!(is_migration_required(
+            &external_vault,
+            Some(&other_connector_id)
+        ));
+    }
+
+    #[test]
+    fn test_record_outcome() {
+        let mut progress = VaultMigrationProgress::default();
+        record_outcome(&mut progress, None);
+        record_outcome(
+            &mut progress,
+            Some(enums::VaultMigrationEntryStatus::Migrated),
+        );
+        record_outcome(
+            &mut progress,
+            Some(enums::VaultMigrationEntryStatus::VerificationFailed),
+        );
+
+        assert_eq!(progress.processed, 3);
+        assert_eq!(progress.skipped, 1);
+        assert_eq!(progress.migrated, 1);
+        assert_eq!(progress.verification_failed, 1);
+        assert_eq!(progress.failed, 0);
+    }
+
+    #[test]
+    fn test_destination_defaults_for_existing_tracking_data() {
+        let tracking_data = serde_json::json!({
+            "migration_id": "vmg_123",
+            "merchant_id": "merchant_123",
+            "operation": "migrate",
+            "dry_run": false,
+            "batch_size": 100,
+            "starting_after_payment_method_id": null,
+            "progress": VaultMigrationProgress::default(),
+        })
+        .parse_value::<VaultMigrationTrackingData>("VaultMigrationTrackingData")
+        .unwrap();
+
+        assert_eq!(
+            tracking_data.destination,
+            VaultMigrationDestination::LockerDeployment
+        );
+
+        let destination = serde_json::from_value::<VaultMigrationDestination>(
+            serde_json::json!({ "type": "external_vault", "merchant_connector_id": "mca_123" }),
+        )
+        .unwrap();
+        assert!(matches!(
+            destination,
+            VaultMigrationDestination::ExternalVault { .. }
+        ));
+    }
+}
@@ -1,4 +1,10 @@
+#[cfg(feature = "v1")]
+pub mod card_updater;
 pub mod cards;
+#[cfg(feature = "v1")]
+pub mod eligibility;
+#[cfg(feature = "v1")]
+pub mod network_token_lifecycle;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
 #[cfg(feature = "v1")]
@@ -7,11 +13,9 @@ pub mod transformers;
 pub mod utils;
 mod validator;
 pub mod vault;
-use std::borrow::Cow;
-#[cfg(feature = "v1")]
-use std::collections::HashSet;
 #[cfg(feature = "v2")]
 use std::str::FromStr;
+use std::{borrow::Cow, collections::HashSet};
 
 #[cfg(feature = "v2")]
 pub use api_models::enums as api_enums;
@@ -942,6 +946,46 @@ pub async fn create_payment_method_core(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to parse Payment method billing address")?;
 
+    let payment_method_data = domain::PaymentMethodVaultingData::from(req.payment_method_data)
+        .populate_bin_details_for_payment_method(state)
+        .await;
+
+    // The fingerprint is looked up once and reused when vaulting the payment method
+    let fingerprint_id = if profile.is_external_vault_enabled() {
+        None
+    } else {
+        Some(get_vault_fingerprint_id(state, &payment_method_data, &customer_id).await?)
+    };
+
+    // Saving a payment method that the customer already has returns the existing payment method
+    if let Some(fingerprint_id) = fingerprint_id.as_deref() {
+        if let Some(existing_payment_method) =
+            find_duplicate_payment_method(state, fingerprint_id, merchant_context, &customer_id)
+                .await?
+        {
+            let pm_update = storage::PaymentMethodUpdate::BillingAddressAndLastUsedUpdate {
+                payment_method_billing_address: payment_method_billing_address.map(Into::into),
+                last_used_at: common_utils::date_time::now(),
+            };
+
+            let payment_method = db
+                .update_payment_method(
+                    key_manager_state,
+                    merchant_context.get_merchant_key_store(),
+                    existing_payment_method,
+                    pm_update,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update duplicate payment method in db")?;
+
+            let resp = pm_transforms::generate_payment_method_response(&payment_method, &None)?;
+
+            return Ok((resp, payment_method));
+        }
+    }
+
     let payment_method_id =
         id_type::GlobalPaymentMethodId::generate(&state.conf.cell_information.id)
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -960,10 +1004,6 @@ pub async fn create_payment_method_core(
     .await
     .attach_printable("failed to add payment method to db")?;
 
-    let payment_method_data = domain::PaymentMethodVaultingData::from(req.payment_method_data)
-        .populate_bin_details_for_payment_method(state)
-        .await;
-
     let vaulting_result = vault_payment_method(
         state,
         &payment_method_data,
@@ -971,6 +1011,7 @@ pub async fn create_payment_method_core(
         profile,
         None,
         &customer_id,
+        fingerprint_id,
     )
     .await;
 
@@ -1805,6 +1846,69 @@ pub async fn create_pm_additional_data_update(
     Ok(pm_update)
 }
 
+#[cfg(feature = "v2")]
+#[instrument(skip_all)]
+pub async fn find_duplicate_payment_method(
+    state: &SessionState,
+    fingerprint_id: &str,
+    merchant_context: &domain::MerchantContext,
+    customer_id: &id_type::GlobalCustomerId,
+) -> RouterResult<Option<domain::PaymentMethod>> {
+    let saved_payment_methods = state
+        .store
+        .find_payment_method_by_global_customer_id_merchant_id_status(
+            &(state.into()),
+            merchant_context.get_merchant_key_store(),
+            customer_id,
+            merchant_context.get_merchant_account().get_id(),
+            common_enums::PaymentMethodStatus::Active,
+            None,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods of the customer")?;
+
+    Ok(remove_duplicate_payment_methods(saved_payment_methods)
+        .into_iter()
+        .find(|payment_method| {
+            payment_method.locker_fingerprint_id.as_deref() == Some(fingerprint_id)
+        }))
+}
+
+#[cfg(feature = "v2")]
+async fn get_vault_fingerprint_id(
+    state: &SessionState,
+    pmd: &domain::PaymentMethodVaultingData,
+    customer_id: &id_type::GlobalCustomerId,
+) -> RouterResult<String> {
+    vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get fingerprint_id from vault")
+}
+
+/// Retain only the most recently used payment method among the payment methods that share a vault
+/// fingerprint
+#[cfg(feature = "v2")]
+fn remove_duplicate_payment_methods(
+    mut payment_methods: Vec<domain::PaymentMethod>,
+) -> Vec<domain::PaymentMethod> {
+    let mut fingerprint_ids = HashSet::new();
+
+    payment_methods.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+    payment_methods.retain(|payment_method| {
+        payment_method
+            .locker_fingerprint_id
+            .as_ref()
+            .map_or(true, |fingerprint_id| {
+                fingerprint_ids.insert(fingerprint_id.clone())
+            })
+    });
+
+    payment_methods
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all)]
 pub async fn vault_payment_method_internal(
@@ -1813,15 +1917,15 @@ pub async fn vault_payment_method_internal(
     merchant_context: &domain::MerchantContext,
     existing_vault_id: Option<domain::VaultId>,
     customer_id: &id_type::GlobalCustomerId,
+    fingerprint_id: Option<String>,
 ) -> RouterResult<pm_types::AddVaultResponse> {
     let db = &*state.store;
 
-    // get fingerprint_id from vault
-    let fingerprint_id_from_vault =
-        vault::get_fingerprint_id_from_vault(state, pmd, customer_id.get_string_repr().to_owned())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed to get fingerprint_id from vault")?;
+    // get fingerprint_id from vault, unless the caller already has it
+    let fingerprint_id_from_vault = match fingerprint_id {
+        Some(fingerprint_id) => fingerprint_id,
+        None => get_vault_fingerprint_id(state, pmd, customer_id).await?,
+    };
 
     // throw back error if payment method is duplicated
     when(
@@ -1949,6 +2053,7 @@ pub async fn vault_payment_method(
     profile: &domain::Profile,
     existing_vault_id: Option<domain::VaultId>,
     customer_id: &id_type::GlobalCustomerId,
+    fingerprint_id: Option<String>,
 ) -> RouterResult<pm_types::AddVaultResponse> {
     let is_external_vault_enabled = profile.is_external_vault_enabled();
 
@@ -1989,6 +2094,7 @@ pub async fn vault_payment_method(
                 merchant_context,
                 existing_vault_id,
                 customer_id,
+                fingerprint_id,
             )
             .await
         }
@@ -2122,7 +2228,7 @@ pub async fn list_payment_methods_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
-    let customer_payment_methods = saved_payment_methods
+    let customer_payment_methods = remove_duplicate_payment_methods(saved_payment_methods)
         .into_iter()
         .map(ForeignTryFrom::foreign_try_from)
         .collect::<Result<Vec<payment_methods::PaymentMethodResponseItem>, _>>()
@@ -2160,7 +2266,7 @@ pub async fn list_customer_payment_methods_core(
     let mut customer_payment_methods = Vec::new();
 
     let payment_method_results: Result<Vec<_>, error_stack::Report<errors::ApiErrorResponse>> =
-        saved_payment_methods
+        remove_duplicate_payment_methods(saved_payment_methods)
             .into_iter()
             .map(|pm| async move {
                 let parent_payment_method_token = generate_id(consts::ID_LENGTH, "token");
@@ -2203,6 +2309,163 @@ pub async fn list_customer_payment_methods_core(
     Ok(customer_payment_methods)
 }
 
+#[cfg(all(feature = "v2", feature = "olap"))]
+#[instrument(skip_all)]
+pub async fn merge_duplicate_payment_methods(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    customer_id: id_type::GlobalCustomerId,
+) -> RouterResponse<payment_methods::DuplicatePaymentMethodsMergeResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    db.find_customer_by_global_id(
+        key_manager_state,
+        &customer_id,
+        merchant_id,
+        merchant_context.get_merchant_key_store(),
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let saved_payment_methods = db
+        .find_payment_method_by_global_customer_id_merchant_id_status(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            &customer_id,
+            merchant_id,
+            common_enums::PaymentMethodStatus::Active,
+            None,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods of the customer")?;
+
+    let mut duplicate_groups: std::collections::HashMap<String, Vec<domain::PaymentMethod>> =
+        std::collections::HashMap::new();
+    for payment_method in saved_payment_methods {
+        if let Some(fingerprint_id) = payment_method.locker_fingerprint_id.clone() {
+            duplicate_groups
+                .entry(fingerprint_id)
+                .or_default()
+                .push(payment_method);
+        }
+    }
+
+    let mandates = db
+        .find_mandate_by_global_customer_id(&customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find mandates of the customer")?;
+
+    let mut merged_payment_methods = Vec::new();
+
+    for mut payment_methods in duplicate_groups.into_values() {
+        if payment_methods.len() < 2 {
+            continue;
+        }
+
+        // The most recently used payment method survives the merge
+        payment_methods.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+        let mut payment_methods = payment_methods.into_iter();
+        let Some(surviving_payment_method) = payment_methods.next() else {
+            continue;
+        };
+        let duplicate_payment_methods = payment_methods.collect::<Vec<_>>();
+
+        let duplicate_mandate_details = duplicate_payment_methods
+            .iter()
+            .filter_map(|payment_method| payment_method.connector_mandate_details.clone())
+            .collect::<Vec<_>>();
+
+        let surviving_payment_method_id = surviving_payment_method.id.clone();
+        if !duplicate_mandate_details.is_empty() {
+            let mut connector_mandate_details = surviving_payment_method
+                .connector_mandate_details
+                .clone()
+                .unwrap_or(CommonMandateReference {
+                    payments: None,
+                    payouts: None,
+                });
+            for mandate_details in duplicate_mandate_details {
+                connector_mandate_details.merge(mandate_details);
+            }
+
+            db.update_payment_method(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                surviving_payment_method,
+                storage::PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
+                    connector_mandate_details: Some(connector_mandate_details.into()),
+                },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update connector mandate details of payment method")?;
+        }
+
+        let mut mandate_ids = Vec::new();
+        let mut duplicate_payment_method_ids = Vec::new();
+
+        // Duplicates are deactivated only after their mandates have been moved, so that a failed
+        // merge can be retried
+        for duplicate_payment_method in duplicate_payment_methods {
+            let duplicate_payment_method_id = duplicate_payment_method.id.clone();
+
+            for mandate in mandates.iter().filter(|mandate| {
+                mandate.payment_method_id == *duplicate_payment_method_id.get_string_repr()
+            }) {
+                db.update_mandate_by_merchant_id_mandate_id(
+                    merchant_id,
+                    &mandate.mandate_id,
+                    storage::MandateUpdate::PaymentMethodIdUpdate {
+                        payment_method_id: surviving_payment_method_id.get_string_repr().to_owned(),
+                    },
+                    mandate.clone(),
+                    storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+
+                mandate_ids.push(mandate.mandate_id.clone());
+            }
+
+            db.update_payment_method(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                duplicate_payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(enums::PaymentMethodStatus::Inactive),
+                },
+                storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deactivate duplicate payment method")?;
+
+            duplicate_payment_method_ids.push(duplicate_payment_method_id);
+        }
+
+        merged_payment_methods.push(payment_methods::DuplicatePaymentMethodsMergeResult {
+            payment_method_id: surviving_payment_method_id,
+            duplicate_payment_method_ids,
+            mandate_ids,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods::DuplicatePaymentMethodsMergeResponse {
+            customer_id,
+            merged_payment_methods,
+        },
+    ))
+}
+
 #[cfg(all(feature = "v2", feature = "olap"))]
 pub async fn get_total_payment_method_count_core(
     state: &SessionState,
@@ -2376,6 +2639,7 @@ pub async fn update_payment_method_core(
                     // will have to refactor this to generate new one on each vaulting later on
                     current_vault_id,
                     &payment_method.customer_id,
+                    None,
                 )
                 .await
                 .attach_printable("Failed to add payment method in vault")?,
@@ -0,0 +1,724 @@
+use api_models::payment_methods::{
+    CardDetailsPaymentMethod, CardUpdaterConfigRequest, CardUpdaterConfigResponse,
+    PaymentMethodsData,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    id_type,
+};
+use diesel_models::process_tracker::business_status;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use super::{cards, network_tokenization};
+use crate::{
+    consts,
+    core::{
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+        webhooks as webhooks_core,
+    },
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+    types::{
+        api, domain,
+        storage::{self, enums, payment_method::CardUpdaterTrackingData},
+    },
+};
+
+const CARD_UPDATER_TAG: &str = "CARD_UPDATER";
+const CARD_UPDATER_NAME: &str = "CARD_UPDATER";
+const CARD_UPDATER_RUNNER: diesel_models::ProcessTrackerRunner =
+    diesel_models::ProcessTrackerRunner::CardUpdaterWorkflow;
+
+/// Enables the card updater for the merchant, or updates its configuration if it is already
+/// enabled. The first run is scheduled immediately.
+#[instrument(skip_all)]
+pub async fn configure_card_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    req: CardUpdaterConfigRequest,
+) -> RouterResponse<CardUpdaterConfigResponse> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let expiry_window_in_days = req
+        .expiry_window_in_days
+        .unwrap_or(consts::DEFAULT_CARD_UPDATER_EXPIRY_WINDOW_IN_DAYS);
+    let run_interval_in_hours = req
+        .run_interval_in_hours
+        .unwrap_or(consts::DEFAULT_CARD_UPDATER_RUN_INTERVAL_IN_HOURS);
+
+    if !(1..=consts::MAX_CARD_UPDATER_EXPIRY_WINDOW_IN_DAYS).contains(&expiry_window_in_days) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`expiry_window_in_days` must be between 1 and {}",
+                consts::MAX_CARD_UPDATER_EXPIRY_WINDOW_IN_DAYS
+            ),
+        }));
+    }
+    if !(1..=consts::MAX_CARD_UPDATER_RUN_INTERVAL_IN_HOURS).contains(&run_interval_in_hours) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`run_interval_in_hours` must be between 1 and {}",
+                consts::MAX_CARD_UPDATER_RUN_INTERVAL_IN_HOURS
+            ),
+        }));
+    }
+
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &(&state).into(),
+            merchant_context.get_merchant_key_store(),
+            merchant_id,
+            &req.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: req.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let task_id = get_card_updater_task_id(merchant_id);
+    let existing_process = state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card updater task")?;
+
+    let now = common_utils::date_time::now();
+    let tracking_data = CardUpdaterTrackingData {
+        merchant_id: merchant_id.clone(),
+        profile_id: req.profile_id,
+        expiry_window_in_days,
+        run_interval_in_hours,
+        last_run_at: existing_process
+            .as_ref()
+            .and_then(|process| get_tracking_data(process).ok())
+            .and_then(|tracking_data| tracking_data.last_run_at),
+    };
+
+    let process = match existing_process {
+        Some(process) => state
+            .store
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(now),
+                    tracking_data: Some(
+                        tracking_data
+                            .encode_to_value()
+                            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+                    ),
+                    business_status: Some(String::from(business_status::PENDING)),
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update card updater task")?,
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                task_id,
+                CARD_UPDATER_NAME,
+                CARD_UPDATER_RUNNER,
+                [CARD_UPDATER_TAG],
+                tracking_data,
+                None,
+                now,
+                common_types::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct card updater process tracker task")?;
+
+            let process = state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert card updater task to process_tracker")?;
+            metrics::TASKS_ADDED_COUNT
+                .add(1, router_env::metric_attributes!(("flow", "CardUpdater")));
+            process
+        }
+    };
+
+    get_card_updater_config_response(&process).map(ApplicationResponse::Json)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_card_updater_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<CardUpdaterConfigResponse> {
+    let process = find_card_updater_process(&state, &merchant_context).await?;
+    get_card_updater_config_response(&process).map(ApplicationResponse::Json)
+}
+
+/// Stops scheduling further runs of the card updater for the merchant. The configuration is
+/// retained, and can be enabled again by configuring the card updater.
+#[instrument(skip_all)]
+pub async fn disable_card_updater(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+) -> RouterResponse<CardUpdaterConfigResponse> {
+    let process = find_card_updater_process(&state, &merchant_context).await?;
+    let mut response = get_card_updater_config_response(&process)?;
+
+    if process.status != enums::ProcessTrackerStatus::Finish {
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to disable card updater task")?;
+    }
+
+    response.enabled = false;
+    response.next_run_at = None;
+    Ok(ApplicationResponse::Json(response))
+}
+
+async fn find_card_updater_process(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .as_scheduler()
+        .find_process_by_id(&get_card_updater_task_id(
+            merchant_context.get_merchant_account().get_id(),
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch card updater task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Card updater is not configured for the merchant".to_string(),
+            })
+        })
+}
+
+fn get_card_updater_config_response(
+    process: &storage::ProcessTracker,
+) -> RouterResult<CardUpdaterConfigResponse> {
+    let tracking_data = get_tracking_data(process)?;
+    let enabled = process.status != enums::ProcessTrackerStatus::Finish;
+
+    Ok(CardUpdaterConfigResponse {
+        merchant_id: tracking_data.merchant_id,
+        profile_id: tracking_data.profile_id,
+        expiry_window_in_days: tracking_data.expiry_window_in_days,
+        run_interval_in_hours: tracking_data.run_interval_in_hours,
+        enabled,
+        next_run_at: process.schedule_time.filter(|_| enabled),
+        last_run_at: tracking_data.last_run_at,
+    })
+}
+
+fn get_tracking_data(process: &storage::ProcessTracker) -> RouterResult<CardUpdaterTrackingData> {
+    process
+        .tracking_data
+        .clone()
+        .parse_value("CardUpdaterTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+fn get_card_updater_task_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{CARD_UPDATER_RUNNER}_{CARD_UPDATER_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Outcome of the card updater for a single saved card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CardUpdateOutcome {
+    /// The network token of the card was refreshed with a later expiry
+    Updated,
+    /// The card entered the expiry window and could not be updated
+    Expiring,
+    /// The card has expired and could not be updated, it has been marked inactive
+    Expired,
+    /// Nothing to be done for the card in this run
+    Skipped,
+}
+
+impl CardUpdateOutcome {
+    fn get_event_type(self) -> Option<enums::EventType> {
+        match self {
+            Self::Updated => Some(enums::EventType::PaymentMethodUpdated),
+            Self::Expiring => Some(enums::EventType::PaymentMethodExpiring),
+            Self::Expired => Some(enums::EventType::PaymentMethodExpired),
+            Self::Skipped => None,
+        }
+    }
+}
+
+/// Processes the saved cards of the merchant that expire within the configured window.
+///
+/// Cards with a network token have the token expiry refreshed through the network tokenization
+/// service. Cards that could not be updated are reported once when they enter the expiry window,
+/// and are marked inactive once expired. Each of these outcomes is notified to the merchant
+/// through a `payment_method` webhook.
+#[instrument(skip_all, fields(merchant_id = ?tracking_data.merchant_id))]
+pub async fn run_card_updater(
+    state: &SessionState,
+    tracking_data: &CardUpdaterTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let business_profile = db
+        .find_business_profile_by_merchant_id_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.merchant_id,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+    let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(domain::Context(
+        merchant_account,
+        key_store,
+    )));
+
+    let now = common_utils::date_time::now();
+    let window = time::Duration::days(tracking_data.expiry_window_in_days.into());
+    let mut starting_after_id = None;
+
+    loop {
+        // Only cards expiring within the window, or whose expiry is not known yet, are fetched
+        let payment_methods = match db
+            .find_card_payment_method_list_expiring_before(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                &tracking_data.merchant_id,
+                now.saturating_add(window),
+                starting_after_id.as_deref(),
+                consts::CARD_UPDATER_BATCH_SIZE,
+            )
+            .await
+        {
+            Ok(payment_methods) => payment_methods,
+            Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to list saved cards of the merchant"))
+            }
+        };
+        let fetched_count = i64::try_from(payment_methods.len()).unwrap_or_default();
+        starting_after_id = payment_methods
+            .last()
+            .map(|payment_method| payment_method.payment_method_id.clone());
+
+        for payment_method in payment_methods {
+            let payment_method_id = payment_method.payment_method_id.clone();
+            let outcome = match update_card(
+                state,
+                &merchant_context,
+                &payment_method,
+                now,
+                window,
+                tracking_data.last_run_at,
+            )
+            .await
+            {
+                Ok(outcome) => outcome,
+                Err(error) => {
+                    logger::error!(?error, %payment_method_id, "Failed to update saved card");
+                    continue;
+                }
+            };
+
+            if let Some(event_type) = outcome.get_event_type() {
+                trigger_payment_method_webhook(
+                    state,
+                    &merchant_context,
+                    &business_profile,
+                    event_type,
+                    &payment_method_id,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, %payment_method_id, "Failed to trigger payment method webhook")
+                })
+                .ok();
+            }
+        }
+
+        if fetched_count < consts::CARD_UPDATER_BATCH_SIZE {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn update_card(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+    now: time::PrimitiveDateTime,
+    window: time::Duration,
+    last_run_at: Option<time::PrimitiveDateTime>,
+) -> RouterResult<CardUpdateOutcome> {
+    let mut payment_method = payment_method.clone();
+    let card_expires_at = match payment_method.card_expires_at {
+        Some(card_expires_at) => card_expires_at,
+        None => {
+            let Some(card_expires_at) =
+                decrypt_card_details(payment_method.payment_method_data.clone()).and_then(|card| {
+                    get_expires_at(card.expiry_month.as_ref(), card.expiry_year.as_ref())
+                })
+            else {
+                return Ok(CardUpdateOutcome::Skipped);
+            };
+
+            // The expiry is stored so that the card is fetched by later runs only once it enters
+            // the expiry window
+            payment_method = state
+                .store
+                .update_payment_method(
+                    &state.into(),
+                    merchant_context.get_merchant_key_store(),
+                    payment_method,
+                    storage::PaymentMethodUpdate::CardExpiryUpdate { card_expires_at },
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to store the expiry of the saved card")?;
+            card_expires_at
+        }
+    };
+
+    if card_expires_at >= now.saturating_add(window) {
+        return Ok(CardUpdateOutcome::Skipped);
+    }
+
+    if refresh_network_token(state, merchant_context, &payment_method, now).await? {
+        return Ok(CardUpdateOutcome::Updated);
+    }
+
+    if card_expires_at <= now {
+        // Payments with the card can still be made with its network token while it is valid
+        if has_valid_network_token(&payment_method, now) {
+            return Ok(CardUpdateOutcome::Skipped);
+        }
+
+        state
+            .store
+            .update_payment_method(
+                &state.into(),
+                merchant_context.get_merchant_key_store(),
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(enums::PaymentMethodStatus::Inactive),
+                },
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to mark expired card as inactive")?;
+        return Ok(CardUpdateOutcome::Expired);
+    }
+
+    // The card is reported as expiring only in the run in which it enters the expiry window
+    let entered_window_at = card_expires_at.saturating_sub(window);
+    Ok(
+        if last_run_at.map_or(true, |last_run_at| entered_window_at > last_run_at) {
+            CardUpdateOutcome::Expiring
+        } else {
+            CardUpdateOutcome::Skipped
+        },
+    )
+}
+
+/// Fetches the current expiry of the network token of the card from the network tokenization
+/// service, and stores it if it is later than the stored one. Returns whether the network token
+/// details were updated.
+async fn refresh_network_token(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payment_method: &domain::PaymentMethod,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<bool> {
+    let (Some(network_token_requestor_reference_id), Some(network_tokenization_service)) = (
+        payment_method.network_token_requestor_reference_id.clone(),
+        state.conf.network_tokenization_service.as_ref(),
+    ) else {
+        return Ok(false);
+    };
+    // Network tokens suspended or deleted by the token service are not refreshed
+    if is_network_token_revoked(payment_method) {
+        return Ok(false);
+    }
+
+    let (token_exp_month, token_exp_year) =
+        match network_tokenization::check_token_status_with_tokenization_service(
+            state,
+            &payment_method.customer_id,
+            network_token_requestor_reference_id,
+            network_tokenization_service.get_inner(),
+        )
+        .await
+        {
+            Ok(token_expiry) => token_expiry,
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    "Failed to check network token status with the tokenization service"
+                );
+                return Ok(false);
+            }
+        };
+
+    let Some(token_expires_at) = get_expires_at(token_exp_month.as_ref(), token_exp_year.as_ref())
+    else {
+        return Ok(false);
+    };
+
+    let token_details =
+        decrypt_card_details(payment_method.network_token_payment_method_data.clone());
+    let stored_token_expires_at = token_details
+        .as_ref()
+        .and_then(|token| get_expires_at(token.expiry_month.as_ref(), token.expiry_year.as_ref()));
+    if token_expires_at <= now
+        || stored_token_expires_at.is_some_and(|expires_at| expires_at >= token_expires_at)
+    {
+        return Ok(false);
+    }
+
+    let Some(token_details) = token_details else {
+        return Ok(false);
+    };
+    let token_details = CardDetailsPaymentMethod {
+        expiry_month: token_exp_month,
+        expiry_year: token_exp_year,
+        ..token_details
+    };
+
+    let key_manager_state = &state.into();
+    let network_token_payment_method_data = cards::create_encrypted_data(
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        PaymentMethodsData::Card(token_details),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt network token data")?;
+
+    state
+        .store
+        .update_payment_method(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            payment_method.clone(),
+            storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_requestor_reference_id: payment_method
+                    .network_token_requestor_reference_id
+                    .clone(),
+                network_token_locker_id: payment_method.network_token_locker_id.clone(),
+                network_token_payment_method_data: Some(network_token_payment_method_data.into()),
+            },
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token data of the saved card")?;
+
+    Ok(true)
+}
+
+fn is_network_token_revoked(payment_method: &domain::PaymentMethod) -> bool {
+    matches!(
+        payment_method.network_token_status,
+        Some(enums::NetworkTokenStatus::Suspended | enums::NetworkTokenStatus::Deleted)
+    )
+}
+
+/// Returns whether the card has a network token that is neither revoked by the token service nor
+/// expired as per the stored network token details.
+fn has_valid_network_token(
+    payment_method: &domain::PaymentMethod,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    payment_method
+        .network_token_requestor_reference_id
+        .is_some()
+        && !is_network_token_revoked(payment_method)
+        && decrypt_card_details(payment_method.network_token_payment_method_data.clone())
+            .and_then(|token| {
+                get_expires_at(token.expiry_month.as_ref(), token.expiry_year.as_ref())
+            })
+            .is_some_and(|token_expires_at| token_expires_at > now)
+}
+
+pub(crate) fn decrypt_card_details(
+    data: common_utils::crypto::OptionalEncryptableValue,
+) -> Option<CardDetailsPaymentMethod> {
+    data.map(|value| value.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|payment_method_data| match payment_method_data {
+            PaymentMethodsData::Card(card) => Some(card),
+            _ => None,
+        })
+}
+
+/// Returns the time at which a card with the given expiry month and year expires, which is the
+/// start of the month following its expiry month.
+fn get_expires_at(
+    expiry_month: Option<&Secret<String>>,
+    expiry_year: Option<&Secret<String>>,
+) -> Option<time::PrimitiveDateTime> {
+    let month = expiry_month?.peek().trim().parse::<u8>().ok()?;
+    let year = expiry_year?.peek().trim().parse::<i32>().ok()?;
+    let year = if year < 100 { 2000 + year } else { year };
+
+    let (year, month) = if month == 12 {
+        (year + 1, time::Month::January)
+    } else {
+        (year, time::Month::try_from(month).ok()?.next())
+    };
+
+    time::Date::from_calendar_date(year, month, 1)
+        .ok()
+        .map(|date| date.midnight())
+}
+
+/// Builds the payment method response sent in `payment_method` webhooks. Card details are
+/// obtained from the payment methods table, without fetching them from the locker.
+pub(crate) fn get_payment_method_webhook_response(
+    payment_method: &domain::PaymentMethod,
+) -> api::PaymentMethodResponse {
+    let card = decrypt_card_details(payment_method.payment_method_data.clone()).map(|card| {
+        let mut card = api::CardDetailFromLocker::from(card);
+        card.scheme.clone_from(&payment_method.scheme);
+        card
+    });
+
+    api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        payment_method_id: payment_method.payment_method_id.clone(),
+        payment_method: payment_method.get_payment_method_type(),
+        payment_method_type: payment_method.get_payment_method_subtype(),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: payment_method.metadata.clone(),
+        created: Some(payment_method.created_at),
+        recurring_enabled: Some(false),
+        installment_payment_enabled: Some(false),
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(payment_method.last_used_at),
+        client_secret: None,
+    }
+}
+
+pub(crate) async fn trigger_payment_method_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &domain::Profile,
+    event_type: enums::EventType,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    // The payment method is fetched again so that the webhook contains its updated details
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            payment_method_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    let payment_method_response = get_payment_method_webhook_response(&payment_method);
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        business_profile.clone(),
+        event_type,
+        enums::EventClass::PaymentMethods,
+        payment_method.payment_method_id.clone(),
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+        Some(payment_method.created_at),
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_date_time(year: i32, month: time::Month) -> time::PrimitiveDateTime {
+        time::Date::from_calendar_date(year, month, 1)
+            .unwrap()
+            .midnight()
+    }
+
+    #[test]
+    fn test_card_expires_at_start_of_next_month() {
+        assert_eq!(
+            get_expires_at(
+                Some(&Secret::new("03".to_string())),
+                Some(&Secret::new("2030".to_string()))
+            ),
+            Some(get_date_time(2030, time::Month::April))
+        );
+    }
+
+    #[test]
+    fn test_card_expiring_in_december_expires_next_year() {
+        assert_eq!(
+            get_expires_at(
+                Some(&Secret::new("12".to_string())),
+                Some(&Secret::new("29".to_string()))
+            ),
+            Some(get_date_time(2030, time::Month::January))
+        );
+    }
+
+    #[test]
+    fn test_card_with_invalid_expiry_has_no_expiry() {
+        assert_eq!(
+            get_expires_at(
+                Some(&Secret::new("13".to_string())),
+                Some(&Secret::new("2030".to_string()))
+            ),
+            None
+        );
+        assert_eq!(
+            get_expires_at(None, Some(&Secret::new("2030".to_string()))),
+            None
+        );
+    }
+}
@@ -12,6 +12,10 @@ use ::payment_methods::{
 };
 #[cfg(feature = "v1")]
 use api_models::admin::PaymentMethodsEnabled;
+#[cfg(feature = "v1")]
+use api_models::payment_methods::{
+    PaymentMethodEligibility, PaymentMethodEligibilityReason, PaymentMethodEligibilityReasonCode,
+};
 use api_models::{
     enums as api_enums,
     payment_methods::{
@@ -53,6 +57,8 @@ use hyperswitch_interfaces::secrets_interface::secret_state::RawSecret;
 use kgraph_utils::transformers::IntoDirValue;
 use masking::Secret;
 use router_env::{instrument, tracing};
+#[cfg(feature = "v1")]
+use rustc_hash::FxHashMap;
 use scheduler::errors as sch_errors;
 use strum::IntoEnumIterator;
 
@@ -65,7 +71,9 @@ use super::surcharge_decision_configs::{
 use super::tokenize::NetworkTokenizationProcess;
 #[cfg(feature = "v1")]
 use crate::core::payment_methods::{
-    add_payment_method_status_update_task, tokenize,
+    add_payment_method_status_update_task,
+    eligibility::get_constraint_graph_reasons,
+    tokenize,
     utils::{get_merchant_pm_filter_graph, make_pm_graph, refresh_pm_filters_cache},
 };
 #[cfg(feature = "v1")]
@@ -189,6 +197,9 @@ impl PaymentMethodsController for PmCards<'_> {
                     network_token_requestor_reference_id,
                     network_token_locker_id,
                     network_token_payment_method_data,
+                    network_token_status: None,
+                    card_expires_at: None,
+                    external_vault_source: None,
                 },
                 self.merchant_context.get_merchant_account().storage_scheme,
             )
@@ -911,11 +922,12 @@ impl PaymentMethodsController for PmCards<'_> {
 
         let card = if pm.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
             let card_detail = if self.state.conf.locker.locker_enabled {
-                let card = get_card_from_locker(
+                let card = get_card_from_vault(
                     self.state,
                     &pm.customer_id,
                     &pm.merchant_id,
                     pm.locker_id.as_ref().unwrap_or(&pm.payment_method_id),
+                    pm.external_vault_source.as_ref(),
                 )
                 .await
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -982,13 +994,32 @@ impl PaymentMethodsController for PmCards<'_> {
             .attach_printable("Customer not found for the payment method")?;
 
         if key.get_payment_method_type() == Some(enums::PaymentMethod::Card) {
-            let response = self
-                .delete_card_from_locker(
-                    &key.customer_id,
-                    &key.merchant_id,
-                    key.locker_id.as_ref().unwrap_or(&key.payment_method_id),
-                )
-                .await?;
+            // Cards migrated to an external vault are deleted from the vault connector
+            let response = match key.external_vault_source.as_ref() {
+                Some(merchant_connector_id) => {
+                    let merchant_connector_account = vault::get_external_vault_connector_account(
+                        self.state,
+                        &key.merchant_id,
+                        merchant_connector_id,
+                    )
+                    .await?;
+                    vault::delete_card_from_vault_external(
+                        self.state,
+                        &merchant_connector_account,
+                        key.locker_id.as_ref().unwrap_or(&key.payment_method_id),
+                    )
+                    .await?;
+                    None
+                }
+                None => Some(
+                    self.delete_card_from_locker(
+                        &key.customer_id,
+                        &key.merchant_id,
+                        key.locker_id.as_ref().unwrap_or(&key.payment_method_id),
+                    )
+                    .await?,
+                ),
+            };
 
             if let Some(network_token_ref_id) = key.network_token_requestor_reference_id {
                 let resp =
@@ -1010,7 +1041,10 @@ impl PaymentMethodsController for PmCards<'_> {
                 }
             }
 
-            if response.status == "Ok" {
+            if response
+                .as_ref()
+                .map_or(true, |response| response.status == "Ok")
+            {
                 logger::info!("Card From locker deleted Successfully!");
             } else {
                 logger::error!("Error: Deleting Card From Locker!\n{:#?}", response);
@@ -1684,6 +1718,12 @@ pub async fn update_customer_payment_method(
             validate_payment_method_update(card_update.clone(), existing_card_data.clone());
 
         let response = if is_card_updation_required {
+            if pm.external_vault_source.is_some() {
+                return Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: "Card details of a payment method stored in an external vault cannot be updated".into()
+                }));
+            }
+
             // Fetch the existing card data from locker for getting card number
             let card_data_from_locker = get_card_from_locker(
                 &state,
@@ -1939,6 +1979,28 @@ pub async fn get_card_from_locker(
     Ok(get_card_from_rs_locker_resp)
 }
 
+/// Fetches the card from the external vault connector the payment method is stored in, or from
+/// the locker if the payment method is not stored in an external vault.
+#[cfg(feature = "v1")]
+pub async fn get_card_from_vault(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    card_reference: &str,
+    external_vault_source: Option<&id_type::MerchantConnectorAccountId>,
+) -> errors::RouterResult<Card> {
+    let Some(merchant_connector_id) = external_vault_source else {
+        return get_card_from_locker(state, customer_id, merchant_id, card_reference).await;
+    };
+
+    let merchant_connector_account =
+        vault::get_external_vault_connector_account(state, merchant_id, merchant_connector_id)
+            .await?;
+    vault::get_card_from_vault_external(state, &merchant_connector_account, card_reference)
+        .await
+        .attach_printable("Failed while getting card from external vault")
+}
+
 #[cfg(feature = "v2")]
 pub async fn delete_card_by_locker_id(
     state: &routes::SessionState,
@@ -2564,7 +2626,6 @@ pub async fn list_payment_methods(
     mut req: api::PaymentMethodListRequest,
 ) -> errors::RouterResponse<api::PaymentMethodListResponse> {
     let db = &*state.store;
-    let pm_config_mapping = &state.conf.pm_filters;
     let key_manager_state = &(&state).into();
     let payment_intent = if let Some(cs) = &req.client_secret {
         if cs.starts_with("pm_") {
@@ -2708,94 +2769,33 @@ pub async fn list_payment_methods(
     logger::debug!(mca_before_filtering=?filtered_mcas);
 
     let mut response: Vec<ResponsePaymentMethodIntermediate> = vec![];
-    // Key creation for storing PM_FILTER_CGRAPH
-    let key = {
-        format!(
-            "pm_filters_cgraph_{}_{}",
-            merchant_context
-                .get_merchant_account()
-                .get_id()
-                .get_string_repr(),
-            profile_id.get_string_repr()
-        )
-    };
-
-    if let Some(graph) = get_merchant_pm_filter_graph(&state, &key).await {
-        // Derivation of PM_FILTER_CGRAPH from MokaCache successful
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
-    } else {
-        // No PM_FILTER_CGRAPH Cache present in MokaCache
-        let mut builder = cgraph::ConstraintGraphBuilder::new();
-        for mca in &filtered_mcas {
-            let domain_id = builder.make_domain(
-                mca.get_id().get_string_repr().to_string(),
-                mca.connector_name.as_str(),
-            );
-
-            let Ok(domain_id) = domain_id else {
-                logger::error!("Failed to construct domain for list payment methods");
-                return Err(errors::ApiErrorResponse::InternalServerError.into());
-            };
-
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            if let Err(e) = make_pm_graph(
-                &mut builder,
-                domain_id,
-                payment_methods,
-                mca.connector_name.clone(),
-                pm_config_mapping,
-                &state.conf.mandates.supported_payment_methods,
-                &state.conf.mandates.update_mandate_supported,
-            ) {
-                logger::error!(
-                    "Failed to construct constraint graph for list payment methods {e:?}"
-                );
-            }
-        }
-
-        // Refreshing our CGraph cache
-        let graph = refresh_pm_filters_cache(&state, &key, builder.build()).await;
+    let graph = get_pm_filters_graph(
+        &state,
+        merchant_context.get_merchant_account().get_id(),
+        profile_id,
+        &filtered_mcas,
+    )
+    .await?;
 
-        for mca in &filtered_mcas {
-            let payment_methods = match &mca.payment_methods_enabled {
-                Some(pm) => pm,
-                None => continue,
-            };
-            filter_payment_methods(
-                &graph,
-                mca.get_id().clone(),
-                payment_methods,
-                &mut req,
-                &mut response,
-                payment_intent.as_ref(),
-                payment_attempt.as_ref(),
-                billing_address.as_ref(),
-                mca.connector_name.clone(),
-                &state.conf,
-            )
-            .await?;
-        }
+    for mca in &filtered_mcas {
+        let payment_methods = match &mca.payment_methods_enabled {
+            Some(pm) => pm,
+            None => continue,
+        };
+        filter_payment_methods(
+            &graph,
+            mca.get_id(),
+            payment_methods,
+            &mut req,
+            &mut response,
+            None,
+            payment_intent.as_ref(),
+            payment_attempt.as_ref(),
+            billing_address.as_ref(),
+            mca.connector_name.clone(),
+            &state.conf,
+        )
+        .await?;
     }
     logger::info!(
         "The Payment Methods available after Constraint Graph filtering are {:?}",
@@ -2810,80 +2810,18 @@ pub async fn list_payment_methods(
     if let Some((payment_attempt, payment_intent)) =
         payment_attempt.as_ref().zip(payment_intent.as_ref())
     {
-        let routing_enabled_pms = &router_consts::ROUTING_ENABLED_PAYMENT_METHODS;
-
-        let routing_enabled_pm_types = &router_consts::ROUTING_ENABLED_PAYMENT_METHOD_TYPES;
-
-        let mut chosen = api::SessionConnectorDatas::new(Vec::new());
-        for intermediate in &response {
-            if routing_enabled_pm_types.contains(&intermediate.payment_method_type)
-                || routing_enabled_pms.contains(&intermediate.payment_method)
-            {
-                let connector_data = api::ConnectorData::get_connector_by_name(
-                    &state.clone().conf.connectors,
-                    &intermediate.connector,
-                    api::GetToken::from(intermediate.payment_method_type),
-                    None,
-                )
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("invalid connector name received")?;
-
-                chosen.push(api::SessionConnectorData {
-                    payment_method_sub_type: intermediate.payment_method_type,
-                    payment_method_type: intermediate.payment_method,
-                    connector: connector_data,
-                    business_sub_label: None,
-                });
-            }
-        }
-        let sfr = SessionFlowRoutingInput {
-            state: &state,
-            country: billing_address.clone().and_then(|ad| ad.country),
-            key_store: merchant_context.get_merchant_key_store(),
-            merchant_account: merchant_context.get_merchant_account(),
+        let result = perform_session_routing_for_list(
+            &state,
+            &merchant_context,
+            &business_profile,
             payment_attempt,
             payment_intent,
-            chosen,
-        };
-        let result = routing::perform_session_flow_routing(
-            sfr,
-            &business_profile,
-            &enums::TransactionType::Payment,
+            billing_address.as_ref().and_then(|address| address.country),
+            &response,
         )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("error performing session flow routing")?;
-
-        response.retain(|intermediate| {
-            if !routing_enabled_pm_types.contains(&intermediate.payment_method_type)
-                && !routing_enabled_pms.contains(&intermediate.payment_method)
-            {
-                return true;
-            }
+        .await?;
 
-            if let Some(choice) = result.get(&intermediate.payment_method_type) {
-                if let Some(first_routable_connector) = choice.first() {
-                    intermediate.connector
-                        == first_routable_connector
-                            .connector
-                            .connector_name
-                            .to_string()
-                        && first_routable_connector
-                            .connector
-                            .merchant_connector_id
-                            .as_ref()
-                            .map(|merchant_connector_id| {
-                                *merchant_connector_id.get_string_repr()
-                                    == intermediate.merchant_connector_id
-                            })
-                            .unwrap_or_default()
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        });
+        response.retain(|intermediate| is_chosen_by_session_routing(intermediate, &result));
 
         let mut routing_info: storage::PaymentRoutingInfo = payment_attempt
             .straight_through_algorithm
@@ -2895,6 +2833,7 @@ pub async fn list_payment_methods(
             .unwrap_or(storage::PaymentRoutingInfo {
                 algorithm: None,
                 pre_routing_results: None,
+                static_routing_decision: None,
             });
 
         let mut pre_routing_results: HashMap<
@@ -3533,6 +3472,152 @@ pub async fn list_payment_methods(
     ))
 }
 
+/// Returns the constraint graph built from the `pm_filters` configuration and the payment methods
+/// enabled on the given connector accounts of the profile, building and caching it if required.
+#[cfg(feature = "v1")]
+pub async fn get_pm_filters_graph(
+    state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    mcas: &domain::MerchantConnectorAccounts,
+) -> errors::RouterResult<std::sync::Arc<cgraph::ConstraintGraph<dir::DirValue>>> {
+    // Key creation for storing PM_FILTER_CGRAPH
+    let key = format!(
+        "pm_filters_cgraph_{}_{}",
+        merchant_id.get_string_repr(),
+        profile_id.get_string_repr()
+    );
+
+    if let Some(graph) = get_merchant_pm_filter_graph(state, &key).await {
+        // Derivation of PM_FILTER_CGRAPH from MokaCache successful
+        return Ok(graph);
+    }
+
+    // No PM_FILTER_CGRAPH Cache present in MokaCache
+    let mut builder = cgraph::ConstraintGraphBuilder::new();
+    for mca in mcas {
+        let domain_id = builder.make_domain(
+            mca.get_id().get_string_repr().to_string(),
+            mca.connector_name.as_str(),
+        );
+
+        let Ok(domain_id) = domain_id else {
+            logger::error!("Failed to construct domain for list payment methods");
+            return Err(errors::ApiErrorResponse::InternalServerError.into());
+        };
+
+        let payment_methods = match &mca.payment_methods_enabled {
+            Some(pm) => pm,
+            None => continue,
+        };
+        if let Err(e) = make_pm_graph(
+            &mut builder,
+            domain_id,
+            payment_methods,
+            mca.connector_name.clone(),
+            &state.conf.pm_filters,
+            &state.conf.mandates.supported_payment_methods,
+            &state.conf.mandates.update_mandate_supported,
+        ) {
+            logger::error!("Failed to construct constraint graph for list payment methods {e:?}");
+        }
+    }
+
+    // Refreshing our CGraph cache
+    Ok(refresh_pm_filters_cache(state, &key, builder.build()).await)
+}
+
+/// Performs session flow routing for the routing enabled payment methods of the payment methods
+/// list, and returns the connectors chosen for each payment method type.
+#[cfg(feature = "v1")]
+pub async fn perform_session_routing_for_list(
+    state: &routes::SessionState,
+    merchant_context: &domain::MerchantContext,
+    business_profile: &Profile,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_country: Option<api_enums::CountryAlpha2>,
+    response: &[ResponsePaymentMethodIntermediate],
+) -> errors::RouterResult<
+    FxHashMap<api_enums::PaymentMethodType, Vec<routing_types::SessionRoutingChoice>>,
+> {
+    let mut chosen = api::SessionConnectorDatas::new(Vec::new());
+    for intermediate in response {
+        if is_routing_enabled_payment_method(intermediate) {
+            let connector_data = api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &intermediate.connector,
+                api::GetToken::from(intermediate.payment_method_type),
+                None,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("invalid connector name received")?;
+
+            chosen.push(api::SessionConnectorData {
+                payment_method_sub_type: intermediate.payment_method_type,
+                payment_method_type: intermediate.payment_method,
+                connector: connector_data,
+                business_sub_label: None,
+            });
+        }
+    }
+    let sfr = SessionFlowRoutingInput {
+        state,
+        country: billing_country,
+        key_store: merchant_context.get_merchant_key_store(),
+        merchant_account: merchant_context.get_merchant_account(),
+        payment_attempt,
+        payment_intent,
+        chosen,
+    };
+    routing::perform_session_flow_routing(sfr, business_profile, &enums::TransactionType::Payment)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("error performing session flow routing")
+}
+
+/// Returns whether the payment method is listed after session flow routing, which only retains
+/// the first routable connector of each routing enabled payment method type.
+#[cfg(feature = "v1")]
+pub fn is_chosen_by_session_routing(
+    intermediate: &ResponsePaymentMethodIntermediate,
+    session_routing_result: &FxHashMap<
+        api_enums::PaymentMethodType,
+        Vec<routing_types::SessionRoutingChoice>,
+    >,
+) -> bool {
+    if !is_routing_enabled_payment_method(intermediate) {
+        return true;
+    }
+
+    session_routing_result
+        .get(&intermediate.payment_method_type)
+        .and_then(|choice| choice.first())
+        .map(|first_routable_connector| {
+            intermediate.connector
+                == first_routable_connector
+                    .connector
+                    .connector_name
+                    .to_string()
+                && first_routable_connector
+                    .connector
+                    .merchant_connector_id
+                    .as_ref()
+                    .map(|merchant_connector_id| {
+                        *merchant_connector_id.get_string_repr()
+                            == intermediate.merchant_connector_id
+                    })
+                    .unwrap_or_default()
+        })
+        .unwrap_or(false)
+}
+
+#[cfg(feature = "v1")]
+fn is_routing_enabled_payment_method(intermediate: &ResponsePaymentMethodIntermediate) -> bool {
+    router_consts::ROUTING_ENABLED_PAYMENT_METHOD_TYPES.contains(&intermediate.payment_method_type)
+        || router_consts::ROUTING_ENABLED_PAYMENT_METHODS.contains(&intermediate.payment_method)
+}
+
 #[cfg(feature = "v1")]
 fn should_collect_shipping_or_billing_details_from_wallet_connector(
     payment_method: api_enums::PaymentMethod,
@@ -3733,6 +3818,7 @@ pub async fn filter_payment_methods(
     payment_methods: &[Secret<serde_json::Value>],
     req: &mut api::PaymentMethodListRequest,
     resp: &mut Vec<ResponsePaymentMethodIntermediate>,
+    mut eligibility: Option<&mut Vec<PaymentMethodEligibility>>,
     payment_intent: Option<&storage::PaymentIntent>,
     payment_attempt: Option<&storage::PaymentAttempt>,
     address: Option<&domain::Address>,
@@ -3765,13 +3851,61 @@ pub async fn filter_payment_methods(
                 .payment_method_types
                 .unwrap_or_default()
             {
-                if filter_recurring_based(&payment_method_type_info, req.recurring_enabled)
-                    && filter_installment_based(
-                        &payment_method_type_info,
-                        req.installment_payment_enabled,
-                    )
-                    && filter_amount_based(&payment_method_type_info, req.amount)
-                {
+                let recurring_filter =
+                    filter_recurring_based(&payment_method_type_info, req.recurring_enabled);
+                let installment_filter = filter_installment_based(
+                    &payment_method_type_info,
+                    req.installment_payment_enabled,
+                );
+                let amount_filter = filter_amount_based(&payment_method_type_info, req.amount);
+
+                if !(recurring_filter && installment_filter && amount_filter) {
+                    if let Some(eligibility) = eligibility.as_deref_mut() {
+                        let reasons = [
+                            (
+                                recurring_filter,
+                                PaymentMethodEligibilityReasonCode::RecurringNotEnabled,
+                                format!(
+                                    "recurring_enabled is {:?}, requested {:?}",
+                                    payment_method_type_info.recurring_enabled,
+                                    req.recurring_enabled
+                                ),
+                            ),
+                            (
+                                installment_filter,
+                                PaymentMethodEligibilityReasonCode::InstallmentNotEnabled,
+                                format!(
+                                    "installment_payment_enabled is {:?}, requested {:?}",
+                                    payment_method_type_info.installment_payment_enabled,
+                                    req.installment_payment_enabled
+                                ),
+                            ),
+                            (
+                                amount_filter,
+                                PaymentMethodEligibilityReasonCode::AmountOutOfRange,
+                                format!(
+                                    "amount {:?} is not within minimum_amount {:?} and maximum_amount {:?}",
+                                    req.amount,
+                                    payment_method_type_info.minimum_amount,
+                                    payment_method_type_info.maximum_amount
+                                ),
+                            ),
+                        ]
+                        .into_iter()
+                        .filter(|(is_satisfied, _, _)| !is_satisfied)
+                        .map(|(_, code, message)| PaymentMethodEligibilityReason { code, message })
+                        .collect();
+
+                        eligibility.push(PaymentMethodEligibility {
+                            payment_method,
+                            payment_method_type: payment_method_type_info.payment_method_type,
+                            connector: connector.clone(),
+                            merchant_connector_id: mca_id.clone(),
+                            eligible: false,
+                            reasons,
+                        });
+                    }
+                } else {
                     let payment_method_object = payment_method_type_info.clone();
 
                     let pm_dir_value: dir::DirValue =
@@ -3928,13 +4062,62 @@ pub async fn filter_payment_methods(
                     logger::info!("Context created for List Payment method is {:?}", context);
 
                     let domain_ident: &[String] = &[mca_id.clone().get_string_repr().to_string()];
+                    // The analysis trace of a failure refers to the memoization, hence it is kept
+                    // alive until the eligibility reasons are collected
+                    let mut memo = cgraph::Memoization::new();
                     let result = graph.key_value_analysis(
                         pm_dir_value.clone(),
                         &context,
-                        &mut cgraph::Memoization::new(),
+                        &mut memo,
                         &mut cgraph::CycleCheck::new(),
                         Some(domain_ident),
                     );
+
+                    if let Some(eligibility) = eligibility.as_deref_mut() {
+                        let mut reasons = Vec::new();
+                        if !filter_pm_based_on_allowed_types {
+                            reasons.push(PaymentMethodEligibilityReason {
+                                code:
+                                    PaymentMethodEligibilityReasonCode::PaymentMethodTypeNotAllowed,
+                                message: format!(
+                                    "{} is not one of the allowed_payment_method_types {:?}",
+                                    payment_method_object.payment_method_type,
+                                    allowed_payment_method_types.as_ref().unwrap_or(&Vec::new())
+                                ),
+                            });
+                        }
+                        if !filter_pm_card_network_based {
+                            reasons.push(PaymentMethodEligibilityReason {
+                                code: PaymentMethodEligibilityReasonCode::CardNetworkNotEnabled,
+                                message: format!(
+                                    "requested card_networks {:?} are not all enabled, enabled card_networks are {:?}",
+                                    req.card_networks.as_ref().unwrap_or(&Vec::new()),
+                                    payment_method_object.card_networks.as_ref().unwrap_or(&Vec::new())
+                                ),
+                            });
+                        }
+                        if !saved_payment_methods_filter {
+                            reasons.push(PaymentMethodEligibilityReason {
+                                code: PaymentMethodEligibilityReasonCode::SavedPaymentMethodNotSupported,
+                                message: format!(
+                                    "{payment_method} is not one of the sdk_eligible_payment_methods"
+                                ),
+                            });
+                        }
+                        if let Err(error) = &result {
+                            reasons.extend(get_constraint_graph_reasons(error));
+                        }
+
+                        eligibility.push(PaymentMethodEligibility {
+                            payment_method,
+                            payment_method_type: payment_method_object.payment_method_type,
+                            connector: connector.clone(),
+                            merchant_connector_id: mca_id.clone(),
+                            eligible: reasons.is_empty(),
+                            reasons,
+                        });
+                    }
+
                     if let Err(ref e) = result {
                         logger::error!(
                             "Error while performing Constraint graph's key value analysis
@@ -4598,11 +4781,12 @@ pub async fn get_card_details_from_locker(
     state: &routes::SessionState,
     pm: &domain::PaymentMethod,
 ) -> errors::RouterResult<api::CardDetailFromLocker> {
-    let card = get_card_from_locker(
+    let card = get_card_from_vault(
         state,
         &pm.customer_id,
         &pm.merchant_id,
         pm.locker_id.as_ref().unwrap_or(pm.get_id()),
+        pm.external_vault_source.as_ref(),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -5097,11 +5281,12 @@ pub async fn execute_payment_method_tokenization(
     let builder = builder.set_validate_result(&customer);
 
     // Fetch card from locker
-    let card_details = get_card_from_locker(
+    let card_details = get_card_from_vault(
         executor.state,
         &customer.id,
         executor.merchant_account.get_id(),
         &locker_id,
+        payment_method.external_vault_source.as_ref(),
     )
     .await?;
 
@@ -0,0 +1,299 @@
+use std::sync::Weak;
+
+use api_models::payment_methods::{
+    PaymentMethodEligibility, PaymentMethodEligibilityReason, PaymentMethodEligibilityReasonCode,
+    PaymentMethodEligibilityResponse, ResponsePaymentMethodIntermediate,
+};
+use common_enums::ConnectorType;
+use common_utils::id_type;
+use euclid::frontend::dir;
+use hyperswitch_constraint_graph::{
+    self as cgraph, error::ValueTracePredecessor, AnalysisTrace, NodeValue, NodeViz,
+};
+use router_env::{instrument, tracing};
+
+use super::cards;
+use crate::{
+    core::{
+        errors::{self, utils::StorageErrorExt, RouterResponse},
+        payments::helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain},
+};
+
+/// Returns every payment method and connector pair enabled on the profile of the payment, along
+/// with the reasons why each of them is or is not listed by the payment methods list of the
+/// payment.
+///
+/// The payment methods are evaluated exactly as the payment methods list of the SDK evaluates
+/// them, which is without any of the optional filters of the list request. Surcharge and 3DS
+/// decision rules only annotate the listed payment methods and never exclude them, hence they
+/// are not reported.
+#[instrument(skip_all)]
+pub async fn get_payment_method_eligibility(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<id_type::ProfileId>,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentMethodEligibilityResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &payment_id,
+            merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let billing_address = helpers::get_address_by_id(
+        &state,
+        payment_intent.billing_address_id.clone(),
+        merchant_context.get_merchant_key_store(),
+        &payment_intent.payment_id,
+        merchant_id,
+        storage_scheme,
+    )
+    .await?;
+
+    let profile_id = payment_intent.profile_id.as_ref().ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Profile id not found".to_string(),
+        },
+    )?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let filtered_mcas = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            merchant_id,
+            false,
+            merchant_context.get_merchant_key_store(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .filter_based_on_profile_and_connector_type(profile_id, ConnectorType::PaymentProcessor);
+
+    let graph =
+        cards::get_pm_filters_graph(&state, merchant_id, profile_id, &filtered_mcas).await?;
+
+    let mut req = api::PaymentMethodListRequest::default();
+    let mut response: Vec<ResponsePaymentMethodIntermediate> = Vec::new();
+    let mut payment_methods = Vec::new();
+    for mca in &filtered_mcas {
+        let Some(payment_methods_enabled) = &mca.payment_methods_enabled else {
+            continue;
+        };
+        cards::filter_payment_methods(
+            &graph,
+            mca.get_id(),
+            payment_methods_enabled,
+            &mut req,
+            &mut response,
+            Some(&mut payment_methods),
+            Some(&payment_intent),
+            Some(&payment_attempt),
+            billing_address.as_ref(),
+            mca.connector_name.clone(),
+            &state.conf,
+        )
+        .await?;
+    }
+
+    let session_routing_result = cards::perform_session_routing_for_list(
+        &state,
+        &merchant_context,
+        &business_profile,
+        &payment_attempt,
+        &payment_intent,
+        billing_address.as_ref().and_then(|address| address.country),
+        &response,
+    )
+    .await?;
+
+    for intermediate in response.iter().filter(|intermediate| {
+        !cards::is_chosen_by_session_routing(intermediate, &session_routing_result)
+    }) {
+        let chosen_connector = session_routing_result
+            .get(&intermediate.payment_method_type)
+            .and_then(|choice| choice.first())
+            .map(|choice| choice.connector.connector_name.to_string());
+
+        if let Some(eligibility) = payment_methods.iter_mut().find(|eligibility| {
+            eligibility.eligible
+                && eligibility.payment_method == intermediate.payment_method
+                && eligibility.payment_method_type == intermediate.payment_method_type
+                && *eligibility.merchant_connector_id.get_string_repr()
+                    == intermediate.merchant_connector_id
+        }) {
+            eligibility.eligible = false;
+            eligibility.reasons.push(PaymentMethodEligibilityReason {
+                code: PaymentMethodEligibilityReasonCode::NotChosenBySessionRouting,
+                message: match chosen_connector {
+                    Some(connector) => format!(
+                        "session flow routing chose {connector} for {}",
+                        intermediate.payment_method_type
+                    ),
+                    None => format!(
+                        "session flow routing chose no connector for {}",
+                        intermediate.payment_method_type
+                    ),
+                },
+            });
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        PaymentMethodEligibilityResponse {
+            payment_id,
+            payment_methods,
+        },
+    ))
+}
+
+/// Flattens the analysis trace of a failed constraint graph analysis into the constraints which
+/// were not satisfied.
+///
+/// The trace only holds weak references to the nodes of the memoization used for the analysis,
+/// hence this must be called before the memoization is dropped.
+pub(super) fn get_constraint_graph_reasons(
+    error: &cgraph::GraphError<dir::DirValue>,
+) -> Vec<PaymentMethodEligibilityReason> {
+    let cgraph::GraphError::AnalysisError(trace) = error else {
+        return vec![PaymentMethodEligibilityReason {
+            code: PaymentMethodEligibilityReasonCode::ConstraintGraphError,
+            message: error.to_string(),
+        }];
+    };
+
+    let mut messages = Vec::new();
+    collect_trace_messages(trace, &mut messages);
+    if messages.is_empty() {
+        messages.push("the constraints of the connector are not satisfied".to_string());
+    }
+    messages.dedup();
+
+    messages
+        .into_iter()
+        .map(|message| PaymentMethodEligibilityReason {
+            code: PaymentMethodEligibilityReasonCode::ConstraintNotSatisfied,
+            message,
+        })
+        .collect()
+}
+
+fn collect_trace_messages(trace: &Weak<AnalysisTrace<dir::DirValue>>, messages: &mut Vec<String>) {
+    let Some(trace) = trace.upgrade() else {
+        return;
+    };
+
+    match trace.as_ref() {
+        AnalysisTrace::Value {
+            value,
+            relation,
+            predecessors,
+            info,
+            ..
+        } => {
+            let collected = messages.len();
+            match predecessors {
+                Some(ValueTracePredecessor::Mandatory(predecessor)) => {
+                    collect_trace_messages(predecessor, messages)
+                }
+                Some(ValueTracePredecessor::OneOf(predecessors)) => predecessors
+                    .iter()
+                    .for_each(|predecessor| collect_trace_messages(predecessor, messages)),
+                None => (),
+            }
+
+            // Only the deepest unsatisfied constraints are reported
+            if messages.len() == collected {
+                let expectation = match relation {
+                    cgraph::Relation::Positive => "must be",
+                    cgraph::Relation::Negative => "must not be",
+                };
+                let message = match value {
+                    NodeValue::Key(key) => format!("{} {expectation} present", key.viz()),
+                    NodeValue::Value(value) => {
+                        format!("{} {expectation} `{}`", value.get_key().viz(), value.viz())
+                    }
+                };
+                messages.push(with_info(message, *info));
+            }
+        }
+        AnalysisTrace::AllAggregation { unsatisfied, .. }
+        | AnalysisTrace::AnyAggregation { unsatisfied, .. } => unsatisfied
+            .iter()
+            .for_each(|trace| collect_trace_messages(trace, messages)),
+        AnalysisTrace::InAggregation {
+            expected,
+            found,
+            relation,
+            info,
+            ..
+        } => {
+            let key = found
+                .as_ref()
+                .or(expected.first())
+                .map(|value| value.get_key().viz())
+                .unwrap_or_default();
+            let expected = expected
+                .iter()
+                .map(|value| format!("`{}`", value.viz()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            let found = found
+                .as_ref()
+                .map(|value| format!("`{}`", value.viz()))
+                .unwrap_or_else(|| "not present".to_string());
+            let message = match relation {
+                cgraph::Relation::Positive => {
+                    format!("{key} {found} is not one of [{expected}]")
+                }
+                cgraph::Relation::Negative => {
+                    format!("{key} {found} is one of the disallowed values [{expected}]")
+                }
+            };
+            messages.push(with_info(message, *info));
+        }
+        AnalysisTrace::Contradiction { .. } => {
+            messages.push("the constraints of the connector contradict each other".to_string())
+        }
+    }
+}
+
+fn with_info(message: String, info: Option<&'static str>) -> String {
+    match info {
+        Some(info) => format!("{message} ({info})"),
+        None => message,
+    }
+}
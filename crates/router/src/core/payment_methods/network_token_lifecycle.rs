@@ -0,0 +1,541 @@
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::ext_traits::BytesExt;
+use error_stack::ResultExt;
+use josekit::jwe;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use super::{card_updater, cards};
+use crate::{
+    core::{
+        api_locking,
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+    },
+    routes::{lock_utils, SessionState},
+    services::{self, encryption, ApplicationResponse},
+    settings,
+    types::{
+        domain, payment_methods as pm_types,
+        storage::{self, enums},
+    },
+};
+
+const NETWORK_TOKEN_EVENT_PREFIX: &str = "NETWORK_TOKEN_EVENT";
+
+/// How long received event ids and the time of the last applied event are remembered
+const NETWORK_TOKEN_EVENT_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60;
+
+/// Handles a lifecycle event sent by the network token service for one of the merchant's
+/// network tokens. The network token reference and status stored on the payment method are
+/// updated, and a `payment_method` event is sent to the merchant's default profile.
+///
+/// Events that were already received are ignored, and so are events older than the last event
+/// applied to the network token, since the service may retry or reorder deliveries.
+#[instrument(skip_all)]
+pub async fn receive_network_token_lifecycle_webhook(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    body: actix_web::web::Bytes,
+) -> RouterResponse<()> {
+    let network_tokenization_service = state
+        .conf
+        .network_tokenization_service
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::NotSupported {
+            message: "Network tokenization is not configured".to_string(),
+        })?
+        .get_inner();
+
+    let event = get_network_token_lifecycle_event(body, network_tokenization_service).await?;
+    logger::info!(
+        event_id = %event.event_id,
+        event_type = ?event.event_type,
+        "Received network token lifecycle event"
+    );
+
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    let event_id_key = get_event_id_key(merchant_id, &event.event_id);
+    if !set_key_if_not_exists(&state, &event_id_key, "received").await? {
+        logger::info!(
+            event_id = %event.event_id,
+            "Ignoring network token lifecycle event that was already received"
+        );
+        return Ok(ApplicationResponse::StatusOk);
+    }
+
+    // The network token is locked while the event is checked against the last applied event,
+    // applied and recorded as the last applied event, so that a concurrent older event cannot
+    // pass the check before this event is recorded
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: event.card_reference.clone(),
+            api_identifier: lock_utils::ApiIdentifier::PaymentMethods,
+            override_lock_retries: None,
+        },
+    };
+    let result = match lock_action
+        .clone()
+        .perform_locking_action(&state, merchant_id.to_owned())
+        .await
+    {
+        Ok(()) => {
+            let result =
+                process_network_token_lifecycle_event(&state, &merchant_context, &event).await;
+            lock_action
+                .free_lock_action(&state, merchant_id.to_owned())
+                .await
+                .map_err(|error| logger::error!(?error, "Failed to free the network token lock"))
+                .ok();
+            result
+        }
+        Err(error) => Err(error),
+    };
+    if result.is_err() {
+        // Forget the event so that it is applied when the network token service retries it
+        delete_key(&state, &event_id_key).await;
+    }
+    result
+}
+
+async fn process_network_token_lifecycle_event(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    event: &pm_types::NetworkTokenLifecycleEvent,
+) -> RouterResponse<()> {
+    let merchant_id = merchant_context.get_merchant_account().get_id();
+    if is_stale_network_token_lifecycle_event(state, merchant_id, event).await? {
+        logger::info!(
+            event_id = %event.event_id,
+            "Ignoring network token lifecycle event older than the last applied event"
+        );
+        return Ok(ApplicationResponse::StatusOk);
+    }
+
+    let key_manager_state = &state.into();
+    let payment_method = state
+        .store
+        .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            merchant_id,
+            &event.card_reference,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let (network_token_status, event_type) = match event.event_type {
+        pm_types::NetworkTokenLifecycleEventType::Suspended => (
+            Some(enums::NetworkTokenStatus::Suspended),
+            enums::EventType::PaymentMethodNetworkTokenSuspended,
+        ),
+        pm_types::NetworkTokenLifecycleEventType::Resumed => (
+            Some(enums::NetworkTokenStatus::Active),
+            enums::EventType::PaymentMethodNetworkTokenResumed,
+        ),
+        pm_types::NetworkTokenLifecycleEventType::Deleted => (
+            Some(enums::NetworkTokenStatus::Deleted),
+            enums::EventType::PaymentMethodNetworkTokenDeleted,
+        ),
+        pm_types::NetworkTokenLifecycleEventType::PanUpdated => (
+            Some(enums::NetworkTokenStatus::Active),
+            enums::EventType::PaymentMethodUpdated,
+        ),
+        // Card art is not stored, the merchant is only notified about the update
+        pm_types::NetworkTokenLifecycleEventType::ArtUpdated => {
+            (None, enums::EventType::PaymentMethodUpdated)
+        }
+    };
+
+    if let Some(network_token_status) = network_token_status {
+        let network_token_payment_method_data = match get_updated_network_token_details(
+            event,
+            payment_method.network_token_payment_method_data.clone(),
+        ) {
+            Some(token_details) => Some(
+                cards::create_encrypted_data(
+                    key_manager_state,
+                    merchant_context.get_merchant_key_store(),
+                    PaymentMethodsData::Card(token_details),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to encrypt network token data")?
+                .into(),
+            ),
+            None => None,
+        };
+
+        state
+            .store
+            .update_payment_method(
+                key_manager_state,
+                merchant_context.get_merchant_key_store(),
+                payment_method.clone(),
+                storage::PaymentMethodUpdate::NetworkTokenLifecycleUpdate {
+                    network_token_requestor_reference_id: event.new_card_reference.clone(),
+                    network_token_payment_method_data,
+                    network_token_status,
+                },
+                merchant_context.get_merchant_account().storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update network token details of the payment method")?;
+    }
+
+    if let Some(event_timestamp) = event.timestamp {
+        // Later events refer to the network token by its new reference, if it was replaced
+        let card_reference = event
+            .new_card_reference
+            .as_ref()
+            .unwrap_or(&event.card_reference);
+        set_key(
+            state,
+            &get_last_applied_event_key(merchant_id, card_reference),
+            event_timestamp.assume_utc().unix_timestamp().to_string(),
+        )
+        .await?;
+    }
+
+    let Some(profile_id) = merchant_context
+        .get_merchant_account()
+        .default_profile
+        .as_ref()
+    else {
+        logger::warn!(
+            payment_method_id = %payment_method.payment_method_id,
+            "Merchant has no default profile, skipping payment method webhook"
+        );
+        return Ok(ApplicationResponse::StatusOk);
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    card_updater::trigger_payment_method_webhook(
+        state,
+        merchant_context,
+        &business_profile,
+        event_type,
+        &payment_method.payment_method_id,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to trigger payment method webhook"))
+    .ok();
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+fn get_event_id_key(merchant_id: &common_utils::id_type::MerchantId, event_id: &str) -> String {
+    format!(
+        "{NETWORK_TOKEN_EVENT_PREFIX}_{}_{event_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_last_applied_event_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    card_reference: &str,
+) -> String {
+    format!(
+        "{NETWORK_TOKEN_EVENT_PREFIX}_{}_{card_reference}_last_applied_at",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Returns whether the event was created before the last event applied to the network token.
+/// Events without a timestamp are never considered stale.
+///
+/// The caller must hold the lock of the network token until the event is recorded as the last
+/// applied event.
+async fn is_stale_network_token_lifecycle_event(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    event: &pm_types::NetworkTokenLifecycleEvent,
+) -> RouterResult<bool> {
+    if event.timestamp.is_none() {
+        return Ok(false);
+    }
+
+    let last_applied_at = get_redis_conn(state)?
+        .get_key::<Option<String>>(
+            &get_last_applied_event_key(merchant_id, &event.card_reference)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get the last applied network token lifecycle event")?
+        .and_then(|last_applied_at| last_applied_at.parse::<i64>().ok());
+
+    Ok(is_event_older_than_last_applied(event, last_applied_at))
+}
+
+fn is_event_older_than_last_applied(
+    event: &pm_types::NetworkTokenLifecycleEvent,
+    last_applied_at: Option<i64>,
+) -> bool {
+    event
+        .timestamp
+        .zip(last_applied_at)
+        .is_some_and(|(event_timestamp, last_applied_at)| {
+            event_timestamp.assume_utc().unix_timestamp() < last_applied_at
+        })
+}
+
+fn get_redis_conn(
+    state: &SessionState,
+) -> RouterResult<std::sync::Arc<redis_interface::RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Returns `false` if the key already exists
+async fn set_key_if_not_exists(state: &SessionState, key: &str, value: &str) -> RouterResult<bool> {
+    get_redis_conn(state)?
+        .set_key_if_not_exists_with_expiry(
+            &key.into(),
+            value,
+            Some(NETWORK_TOKEN_EVENT_EXPIRY_SECONDS),
+        )
+        .await
+        .map(|reply| reply == redis_interface::SetnxReply::KeySet)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the network token lifecycle event")
+}
+
+async fn set_key(state: &SessionState, key: &str, value: String) -> RouterResult<()> {
+    get_redis_conn(state)?
+        .set_key_with_expiry(&key.into(), value, NETWORK_TOKEN_EVENT_EXPIRY_SECONDS)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the last applied network token lifecycle event")
+}
+
+async fn delete_key(state: &SessionState, key: &str) {
+    if let Ok(redis_conn) = get_redis_conn(state) {
+        redis_conn
+            .delete_key(&key.into())
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete {key} from redis"))
+            .ok();
+    }
+}
+
+/// Decrypts the payload sent by the network token service with the private key and verifies its
+/// signature with the public key of the service.
+async fn get_network_token_lifecycle_event(
+    body: actix_web::web::Bytes,
+    network_tokenization_service: &settings::NetworkTokenizationService,
+) -> RouterResult<pm_types::NetworkTokenLifecycleEvent> {
+    let webhook: pm_types::NetworkTokenLifecycleWebhook = body
+        .parse_struct("NetworkTokenLifecycleWebhook")
+        .change_context(errors::ApiErrorResponse::WebhookBadRequest)?;
+
+    let jws_body = services::decrypt_jwe(
+        webhook.payload.peek(),
+        services::KeyIdCheck::SkipKeyIdCheck,
+        network_tokenization_service.private_key.peek().clone(),
+        jwe::RSA_OAEP_256,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+    .attach_printable("Failed to decrypt the network token lifecycle webhook payload")?;
+
+    let event = encryption::verify_sign(
+        jws_body,
+        network_tokenization_service.public_key.peek().as_bytes(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookAuthenticationFailed)
+    .attach_printable("Failed to verify the signature of the network token lifecycle webhook")?;
+
+    serde_json::from_str(&event)
+        .change_context(errors::ApiErrorResponse::WebhookUnprocessableEntity)
+        .attach_printable("Failed to deserialize the network token lifecycle event")
+}
+
+/// Returns the stored network token details with the token expiry and last four digits sent in
+/// the event, if the event contains any of them.
+fn get_updated_network_token_details(
+    event: &pm_types::NetworkTokenLifecycleEvent,
+    network_token_payment_method_data: common_utils::crypto::OptionalEncryptableValue,
+) -> Option<CardDetailsPaymentMethod> {
+    if event.token_expiry_month.is_none()
+        && event.token_expiry_year.is_none()
+        && event.token_last_four.is_none()
+    {
+        return None;
+    }
+
+    card_updater::decrypt_card_details(network_token_payment_method_data).map(|token_details| {
+        CardDetailsPaymentMethod {
+            expiry_month: event
+                .token_expiry_month
+                .clone()
+                .or(token_details.expiry_month),
+            expiry_year: event
+                .token_expiry_year
+                .clone()
+                .or(token_details.expiry_year),
+            last4_digits: event.token_last_four.clone().or(token_details.last4_digits),
+            ..token_details
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_event(
+        timestamp: Option<time::PrimitiveDateTime>,
+    ) -> pm_types::NetworkTokenLifecycleEvent {
+        pm_types::NetworkTokenLifecycleEvent {
+            event_id: "event_1".to_string(),
+            event_type: pm_types::NetworkTokenLifecycleEventType::PanUpdated,
+            card_reference: "card_reference_1".to_string(),
+            new_card_reference: None,
+            token_expiry_month: None,
+            token_expiry_year: None,
+            token_last_four: None,
+            timestamp,
+        }
+    }
+
+    fn get_merchant_id(merchant_id: &'static str) -> common_utils::id_type::MerchantId {
+        common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from(merchant_id)).unwrap()
+    }
+
+    fn get_card_details() -> CardDetailsPaymentMethod {
+        CardDetailsPaymentMethod {
+            last4_digits: Some("1111".to_string()),
+            issuer_country: None,
+            expiry_month: Some(Secret::new("03".to_string())),
+            expiry_year: Some(Secret::new("2030".to_string())),
+            nick_name: None,
+            card_holder_name: None,
+            card_isin: None,
+            card_issuer: None,
+            card_network: None,
+            card_type: None,
+            saved_to_locker: true,
+            co_badged_card_data: None,
+        }
+    }
+
+    fn get_encrypted_card_details(
+        card_details: CardDetailsPaymentMethod,
+    ) -> common_utils::crypto::OptionalEncryptableValue {
+        Some(common_utils::crypto::Encryptable::new(
+            Secret::new(serde_json::to_value(PaymentMethodsData::Card(card_details)).unwrap()),
+            Secret::new(Vec::new()),
+        ))
+    }
+
+    #[test]
+    fn test_event_older_than_last_applied_event_is_stale() {
+        let event = get_event(Some(time::macros::datetime!(2025-01-01 10:00:00)));
+        let event_timestamp = time::macros::datetime!(2025-01-01 10:00:00)
+            .assume_utc()
+            .unix_timestamp();
+
+        assert!(is_event_older_than_last_applied(
+            &event,
+            Some(event_timestamp + 1)
+        ));
+        assert!(!is_event_older_than_last_applied(
+            &event,
+            Some(event_timestamp)
+        ));
+        assert!(!is_event_older_than_last_applied(
+            &event,
+            Some(event_timestamp - 1)
+        ));
+        assert!(!is_event_older_than_last_applied(&event, None));
+    }
+
+    #[test]
+    fn test_event_without_timestamp_is_never_stale() {
+        assert!(!is_event_older_than_last_applied(
+            &get_event(None),
+            Some(i64::MAX)
+        ));
+    }
+
+    #[test]
+    fn test_event_id_key_identifies_event_of_merchant() {
+        let merchant_id = get_merchant_id("merchant_1");
+
+        assert_eq!(
+            get_event_id_key(&merchant_id, "event_1"),
+            get_event_id_key(&merchant_id, "event_1")
+        );
+        assert_ne!(
+            get_event_id_key(&merchant_id, "event_1"),
+            get_event_id_key(&merchant_id, "event_2")
+        );
+        assert_ne!(
+            get_event_id_key(&merchant_id, "event_1"),
+            get_event_id_key(&get_merchant_id("merchant_2"), "event_1")
+        );
+    }
+
+    #[test]
+    fn test_updated_network_token_details_use_details_of_event() {
+        let event = pm_types::NetworkTokenLifecycleEvent {
+            token_expiry_month: Some(Secret::new("09".to_string())),
+            token_last_four: Some("4242".to_string()),
+            ..get_event(None)
+        };
+
+        let token_details = get_updated_network_token_details(
+            &event,
+            get_encrypted_card_details(get_card_details()),
+        )
+        .unwrap();
+
+        assert_eq!(
+            token_details,
+            CardDetailsPaymentMethod {
+                last4_digits: Some("4242".to_string()),
+                expiry_month: Some(Secret::new("09".to_string())),
+                ..get_card_details()
+            }
+        );
+    }
+
+    #[test]
+    fn test_network_token_details_are_not_updated_without_details_in_event() {
+        assert_eq!(
+            get_updated_network_token_details(
+                &get_event(None),
+                get_encrypted_card_details(get_card_details())
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_network_token_details_are_not_updated_without_stored_details() {
+        let event = pm_types::NetworkTokenLifecycleEvent {
+            token_last_four: Some("4242".to_string()),
+            ..get_event(None)
+        };
+
+        assert_eq!(get_updated_network_token_details(&event, None), None);
+    }
+}
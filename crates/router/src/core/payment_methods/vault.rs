@@ -1,3 +1,8 @@
+#[cfg(feature = "v1")]
+use std::str::FromStr;
+
+#[cfg(feature = "v1")]
+use api_models::{enums as api_enums, payment_methods as api_payment_methods};
 use common_enums::PaymentMethodType;
 #[cfg(feature = "v2")]
 use common_utils::request;
@@ -8,12 +13,23 @@ use common_utils::{
     pii::Email,
 };
 use error_stack::{report, ResultExt};
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::{
+    router_data_v2::flow_common_types::VaultConnectorFlowData,
+    router_flow_types::{
+        ExternalVaultDeleteFlow, ExternalVaultInsertFlow, ExternalVaultRetrieveFlow,
+    },
+    types::VaultRouterData,
+    vault::PaymentMethodVaultingData,
+};
 #[cfg(feature = "v2")]
 use hyperswitch_domain_models::{
     router_data_v2::flow_common_types::VaultConnectorFlowData,
     router_flow_types::{ExternalVaultDeleteFlow, ExternalVaultRetrieveFlow},
     types::VaultRouterData,
 };
+#[cfg(feature = "v1")]
+use masking::ExposeInterface;
 use masking::PeekInterface;
 use router_env::{instrument, tracing};
 use scheduler::{types::process_data, utils as process_tracker_utils};
@@ -46,6 +62,15 @@ use crate::{
     types::{self, payment_methods as pm_types},
     utils::{ext_traits::OptionExt, ConnectorResponseExt},
 };
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::{ConnectorErrorExt, StorageErrorExt},
+        payments as payments_core,
+    },
+    services::{self, connector_integration_interface::RouterDataConversion},
+    types,
+};
 
 const VAULT_SERVICE_NAME: &str = "CARD";
 
@@ -1763,6 +1788,262 @@ pub async fn delete_payment_method_data_from_vault(
 
 // ********************************************** PROCESS TRACKER **********************************************
 
+#[cfg(feature = "v1")]
+pub async fn get_external_vault_connector_account(
+    state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    if merchant_connector_account.connector_type != common_enums::ConnectorType::VaultProcessor
+        || api_enums::VaultConnectors::from_str(&merchant_connector_account.connector_name).is_err()
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Merchant connector account `{}` is not an external vault connector",
+                merchant_connector_id.get_string_repr()
+            ),
+        }));
+    }
+
+    Ok(merchant_connector_account)
+}
+
+#[cfg(feature = "v1")]
+fn get_external_vault_connector_data(
+    state: &routes::SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> RouterResult<api::ConnectorData> {
+    let connector_name = api_enums::Connector::from_str(&merchant_connector_account.connector_name)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the external vault connector name")?;
+
+    api::ConnectorData::get_external_vault_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to get the connector data")
+}
+
+#[cfg(feature = "v1")]
+fn construct_external_vault_router_data<F: Clone>(
+    state: &routes::SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method_vaulting_data: Option<PaymentMethodVaultingData>,
+    connector_vault_id: Option<String>,
+) -> RouterResult<VaultRouterData<F>> {
+    let connector_auth_type = merchant_connector_account
+        .get_connector_account_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the external vault connector account details")?;
+
+    let router_data = types::RouterDataV2 {
+        flow: std::marker::PhantomData,
+        resource_common_data: VaultConnectorFlowData {
+            merchant_id: merchant_connector_account.merchant_id.clone(),
+        },
+        tenant_id: state.tenant.tenant_id.clone(),
+        connector_auth_type,
+        request: types::VaultRequestData {
+            payment_method_vaulting_data,
+            connector_vault_id,
+            connector_customer_id: None,
+        },
+        response: Ok(types::VaultResponseData::default()),
+    };
+
+    VaultConnectorFlowData::to_old_router_data(router_data)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Cannot construct router data for making the external vault api call")
+}
+
+/// Stores the card in the external vault connector, and returns the reference to the card in
+/// the vault
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn add_card_to_vault_external(
+    state: &routes::SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    card: api_payment_methods::Card,
+) -> RouterResult<String> {
+    let connector_data = get_external_vault_connector_data(state, merchant_connector_account)?;
+    let card_detail = api_payment_methods::CardDetail {
+        card_number: card.card_number,
+        card_exp_month: card.card_exp_month,
+        card_exp_year: card.card_exp_year,
+        card_holder_name: card.name_on_card,
+        nick_name: card.nick_name.map(masking::Secret::new),
+        card_issuing_country: None,
+        card_network: card
+            .card_brand
+            .and_then(|card_brand| api_enums::CardNetwork::from_str(&card_brand).ok()),
+        card_issuer: None,
+        card_type: None,
+    };
+    let router_data = construct_external_vault_router_data::<ExternalVaultInsertFlow>(
+        state,
+        merchant_connector_account,
+        Some(PaymentMethodVaultingData::Card(card_detail)),
+        None,
+    )?;
+
+    let connector_integration: services::BoxedVaultConnectorIntegrationInterface<
+        ExternalVaultInsertFlow,
+        types::VaultRequestData,
+        types::VaultResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .to_vault_failed_response()?;
+
+    match router_data_resp.response {
+        Ok(types::VaultResponseData::ExternalVaultInsertResponse {
+            connector_vault_id, ..
+        }) => Ok(connector_vault_id),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid Vault Response")),
+        Err(err) => {
+            logger::error!("Error vaulting card in external vault: {:?}", err);
+            Err(report!(errors::ApiErrorResponse::ExternalVaultFailed)
+                .attach_printable("Failed to vault card in external vault"))
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn get_card_from_vault_external(
+    state: &routes::SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    connector_vault_id: &str,
+) -> RouterResult<api_payment_methods::Card> {
+    let connector_data = get_external_vault_connector_data(state, merchant_connector_account)?;
+    let router_data = construct_external_vault_router_data::<ExternalVaultRetrieveFlow>(
+        state,
+        merchant_connector_account,
+        None,
+        Some(connector_vault_id.to_owned()),
+    )?;
+
+    let connector_integration: services::BoxedVaultConnectorIntegrationInterface<
+        ExternalVaultRetrieveFlow,
+        types::VaultRequestData,
+        types::VaultResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .to_vault_failed_response()?;
+
+    match router_data_resp.response {
+        Ok(types::VaultResponseData::ExternalVaultRetrieveResponse {
+            vault_data: PaymentMethodVaultingData::Card(card),
+        }) => Ok(api_payment_methods::Card {
+            card_isin: Some(card.card_number.get_card_isin()),
+            card_number: card.card_number,
+            name_on_card: card.card_holder_name,
+            card_exp_month: card.card_exp_month,
+            card_exp_year: card.card_exp_year,
+            card_brand: card
+                .card_network
+                .map(|card_network| card_network.to_string()),
+            nick_name: card.nick_name.map(|nick_name| nick_name.expose()),
+        }),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid Vault Response")),
+        Err(err) => {
+            logger::error!("Error retrieving card from external vault: {:?}", err);
+            Err(report!(errors::ApiErrorResponse::ExternalVaultFailed)
+                .attach_printable("Failed to retrieve card from external vault"))
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn delete_card_from_vault_external(
+    state: &routes::SessionState,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    connector_vault_id: &str,
+) -> RouterResult<()> {
+    let connector_data = get_external_vault_connector_data(state, merchant_connector_account)?;
+    let router_data = construct_external_vault_router_data::<ExternalVaultDeleteFlow>(
+        state,
+        merchant_connector_account,
+        None,
+        Some(connector_vault_id.to_owned()),
+    )?;
+
+    let connector_integration: services::BoxedVaultConnectorIntegrationInterface<
+        ExternalVaultDeleteFlow,
+        types::VaultRequestData,
+        types::VaultResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data_resp = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        None,
+    )
+    .await
+    .to_vault_failed_response()?;
+
+    match router_data_resp.response {
+        Ok(types::VaultResponseData::ExternalVaultDeleteResponse { .. }) => Ok(()),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid Vault Response")),
+        Err(err) => {
+            logger::error!("Error deleting card from external vault: {:?}", err);
+            Err(report!(errors::ApiErrorResponse::ExternalVaultFailed)
+                .attach_printable("Failed to delete card from external vault"))
+        }
+    }
+}
+
 pub async fn add_delete_tokenized_data_task(
     db: &dyn db::StorageInterface,
     lookup_key: &str,
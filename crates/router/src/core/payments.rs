@@ -467,6 +467,7 @@ where
         .to_get_tracker()?
         .validate_request_with_state(state, &req, &mut payment_data, &business_profile)
         .await?;
+    let initial_intent_status = payment_data.get_payment_intent().status;
 
     core_utils::validate_profile_id_from_auth_layer(
         profile_id_from_auth_layer,
@@ -1027,6 +1028,18 @@ where
         )
         .await?;
 
+    // The outcome is recorded when the payment turns terminal in this operation, whether through
+    // the connector response, a sync or an incoming webhook, so that retrieving a completed
+    // payment does not record it again
+    let intent_status = payment_data.get_payment_intent().status;
+    if intent_status != initial_intent_status && intent_status.is_in_terminal_state() {
+        routing::log_shadow_routing_outcome_event(
+            state,
+            &business_profile,
+            payment_data.get_payment_attempt(),
+        );
+    }
+
     utils::trigger_payments_webhook(
         merchant_context.clone(),
         business_profile,
@@ -1432,6 +1445,7 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
+        None,
     );
     let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_context
         .get_merchant_account()
@@ -1479,6 +1493,7 @@ where
         payment_data.get_address(),
         None,
         payment_data.get_currency(),
+        None,
     );
 
     let output = perform_decision_management(record, &payment_dsl_data)
@@ -6848,6 +6863,7 @@ where
             .unwrap_or(storage::PaymentRoutingInfo {
                 algorithm: None,
                 pre_routing_results: None,
+                static_routing_decision: None,
             }),
     };
 
@@ -6919,6 +6935,7 @@ where
         payment_data.get_address(),
         None,
         payment_data.get_currency(),
+        None,
     );
 
     let decided_connector = decide_connector(
@@ -7206,6 +7223,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                business_profile.routing_calendar_config.as_ref(),
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -7265,6 +7283,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                business_profile.routing_calendar_config.as_ref(),
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -7316,6 +7335,7 @@ where
         new_pd.get_address(),
         new_pd.get_recurring_details(),
         new_pd.get_currency(),
+        business_profile.routing_calendar_config.as_ref(),
     );
 
     route_connector_v1_for_payments(
@@ -7874,7 +7894,7 @@ pub async fn route_connector_v2_for_payments(
         .as_ref()
         .or(business_profile.routing_algorithm_id.as_ref());
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, _) = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
         routing_algorithm_id,
@@ -7941,7 +7961,7 @@ where
         algorithm_ref.algorithm_id
     };
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, static_routing_decision) = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
         routing_algorithm_id.as_ref(),
@@ -7950,6 +7970,7 @@ where
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    routing_data.routing_info.static_routing_decision = static_routing_decision;
 
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
     let payment_attempt = transaction_data.payment_attempt.clone();
@@ -8118,7 +8139,7 @@ pub async fn route_connector_v1_for_payouts(
         algorithm_ref.algorithm_id
     };
 
-    let connectors = routing::perform_static_routing_v1(
+    let (connectors, _) = routing::perform_static_routing_v1(
         state,
         merchant_context.get_merchant_account().get_id(),
         routing_algorithm_id.as_ref(),
@@ -2134,9 +2134,19 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         connector_variant,
         network_tokenization_supported_connectors,
         should_retry_with_pan,
+        // Network tokens suspended or deleted by the token service are not used for payments
         payment_method_info
             .network_token_requestor_reference_id
-            .clone(),
+            .clone()
+            .filter(|_| {
+                !matches!(
+                    payment_method_info.network_token_status,
+                    Some(
+                        storage_enums::NetworkTokenStatus::Suspended
+                            | storage_enums::NetworkTokenStatus::Deleted
+                    )
+                )
+            }),
     );
 
     let co_badged_card_data = payment_method_info
@@ -2154,6 +2164,7 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
                         customer_id,
                         &payment_intent.merchant_id,
                         locker_id,
+                        payment_method_info.external_vault_source.as_ref(),
                         card_token_data,
                         co_badged_card_data,
                     )
@@ -2169,6 +2180,7 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
                 customer_id,
                 &payment_intent.merchant_id,
                 locker_id,
+                payment_method_info.external_vault_source.as_ref(),
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -2205,6 +2217,7 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
                                     customer_id,
                                     &payment_intent.merchant_id,
                                     locker_id,
+                                    payment_method_info.external_vault_source.as_ref(),
                                     card_token_data,
                                     co_badged_card_data,
                                 )
@@ -2249,10 +2262,11 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
 pub async fn retrieve_card_with_permanent_token_for_external_authentication(
     state: &SessionState,
     locker_id: &str,
+    payment_method_id: Option<&str>,
     payment_intent: &PaymentIntent,
     card_token_data: Option<&domain::CardToken>,
-    _merchant_key_store: &domain::MerchantKeyStore,
-    _storage_scheme: enums::MerchantStorageScheme,
+    merchant_key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
 ) -> RouterResult<domain::PaymentMethodData> {
     let customer_id = payment_intent
         .customer_id
@@ -2261,12 +2275,29 @@ pub async fn retrieve_card_with_permanent_token_for_external_authentication(
         .change_context(errors::ApiErrorResponse::UnprocessableEntity {
             message: "no customer id provided for the payment".to_string(),
         })?;
+    let external_vault_source = match payment_method_id {
+        Some(payment_method_id) => {
+            state
+                .store
+                .find_payment_method(
+                    &state.into(),
+                    merchant_key_store,
+                    payment_method_id,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?
+                .external_vault_source
+        }
+        None => None,
+    };
     Ok(domain::PaymentMethodData::Card(
         fetch_card_details_from_locker(
             state,
             customer_id,
             &payment_intent.merchant_id,
             locker_id,
+            external_vault_source.as_ref(),
             card_token_data,
             None,
         )
@@ -2282,14 +2313,21 @@ pub async fn fetch_card_details_from_locker(
     customer_id: &id_type::CustomerId,
     merchant_id: &id_type::MerchantId,
     locker_id: &str,
+    external_vault_source: Option<&id_type::MerchantConnectorAccountId>,
     card_token_data: Option<&domain::CardToken>,
     co_badged_card_data: Option<api_models::payment_methods::CoBadgedCardData>,
 ) -> RouterResult<domain::Card> {
     logger::debug!("Fetching card details from locker");
-    let card = cards::get_card_from_locker(state, customer_id, merchant_id, locker_id)
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("failed to fetch card information from the permanent locker")?;
+    let card = cards::get_card_from_vault(
+        state,
+        customer_id,
+        merchant_id,
+        locker_id,
+        external_vault_source,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to fetch card information from the permanent locker")?;
 
     // The card_holder_name from locker retrieved card is considered if it is a non-empty string or else card_holder_name is picked
     // from payment_method_data.card_token object
@@ -2388,12 +2426,18 @@ pub async fn fetch_card_details_for_network_transaction_flow_from_locker(
     customer_id: &id_type::CustomerId,
     merchant_id: &id_type::MerchantId,
     locker_id: &str,
+    external_vault_source: Option<&id_type::MerchantConnectorAccountId>,
 ) -> RouterResult<domain::PaymentMethodData> {
-    let card_details_from_locker =
-        cards::get_card_from_locker(state, customer_id, merchant_id, locker_id)
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("failed to fetch card details from locker")?;
+    let card_details_from_locker = cards::get_card_from_vault(
+        state,
+        customer_id,
+        merchant_id,
+        locker_id,
+        external_vault_source,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to fetch card details from locker")?;
 
     let card_network = card_details_from_locker
         .card_brand
@@ -6557,6 +6601,7 @@ pub async fn get_payment_method_details_from_payment_token(
             retrieve_card_with_permanent_token_for_external_authentication(
                 state,
                 &card_token.token,
+                card_token.payment_method_id.as_deref(),
                 payment_intent,
                 None,
                 key_store,
@@ -6570,6 +6615,7 @@ pub async fn get_payment_method_details_from_payment_token(
             retrieve_card_with_permanent_token_for_external_authentication(
                 state,
                 &card_token.token,
+                card_token.payment_method_id.as_deref(),
                 payment_intent,
                 None,
                 key_store,
@@ -365,6 +365,7 @@ impl<F: Clone + Send + Sync> Domain<F, PaymentsSessionRequest, payments::Payment
                 }
                 Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(pre_routing_results)
             })()?),
+            static_routing_decision: None,
         };
 
         // Store the routing results in payment intent
@@ -32,6 +32,8 @@ use external_services::grpc_client::dynamic_routing::{
     success_rate_client::SuccessBasedDynamicRouting, DynamicRoutingError,
 };
 use hyperswitch_domain_models::address::Address;
+#[cfg(feature = "v1")]
+use hyperswitch_interfaces::events::routing_api_logs as routing_events;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use hyperswitch_interfaces::events::routing_api_logs::{ApiMethod, RoutingEngine};
 use kgraph_utils::{
@@ -47,6 +49,8 @@ use rand::SeedableRng;
 use router_env::{instrument, tracing};
 use rustc_hash::FxHashMap;
 use storage_impl::redis::cache::{CacheKey, CGRAPH_CACHE, ROUTING_CACHE};
+#[cfg(feature = "v1")]
+use tracing_futures::Instrument;
 use utils::perform_decision_euclid_routing;
 
 #[cfg(feature = "v2")]
@@ -55,7 +59,7 @@ use crate::core::admin;
 use crate::core::payouts;
 #[cfg(feature = "v1")]
 use crate::core::routing::transformers::OpenRouterDecideGatewayRequestExt;
-#[cfg(all(feature = "v1", feature = "dynamic_routing"))]
+#[cfg(feature = "v1")]
 use crate::routes::app::SessionStateInfo;
 use crate::{
     core::{
@@ -194,6 +198,16 @@ pub fn make_dsl_input_for_payouts(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        #[cfg(feature = "v1")]
+        temporal_data: get_temporal_data(
+            payout_data
+                .business_profile
+                .routing_calendar_config
+                .as_ref(),
+            payout_data.payouts.created_at,
+        ),
+        #[cfg(feature = "v2")]
+        temporal_data: get_temporal_data(None, payout_data.payouts.created_at),
     })
 }
 
@@ -309,6 +323,7 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: get_temporal_data(None, payments_dsl_input.payment_intent.created_at),
     })
 }
 
@@ -423,16 +438,41 @@ pub fn make_dsl_input(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: get_temporal_data(
+            payments_dsl_input.routing_calendar_config,
+            payments_dsl_input.payment_intent.created_at,
+        ),
     })
 }
 
+/// Returns the local hour, day of week, date and holiday calendars at which the payment or payout
+/// was created, in the time zone of the routing calendar of the profile, or in UTC if the profile
+/// has none. The creation time is used so that every routing decision of a transaction, including
+/// retries, is made for the same time.
+fn get_temporal_data(
+    routing_calendar_config: Option<&common_types::domain::RoutingCalendarConfig>,
+    created_at: time::PrimitiveDateTime,
+) -> Option<dsl_inputs::TemporalDataInput> {
+    let unix_timestamp = created_at.assume_utc().unix_timestamp();
+
+    match routing_calendar_config {
+        Some(routing_calendar_config) => routing_calendar_config.get_temporal_data(unix_timestamp),
+        None => {
+            common_types::domain::RoutingCalendarConfig::default().get_temporal_data(unix_timestamp)
+        }
+    }
+}
+
 pub async fn perform_static_routing_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     algorithm_id: Option<&common_utils::id_type::RoutingId>,
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+) -> RoutingResult<(
+    Vec<routing_types::RoutableConnectorChoice>,
+    Option<oss_storage::StaticRoutingDecision>,
+)> {
     let algorithm_id = if let Some(id) = algorithm_id {
         id
     } else {
@@ -449,7 +489,17 @@ pub async fn perform_static_routing_v1(
             .get_default_fallback_list_of_connector_under_profile()
             .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
-        return Ok(fallback_config);
+        #[cfg(feature = "v1")]
+        perform_shadow_routing_v1(
+            state,
+            merchant_id,
+            None,
+            business_profile,
+            transaction_data,
+            &fallback_config,
+        );
+
+        return Ok((fallback_config, None));
     };
     let cached_algorithm = ensure_algorithm_cached_v1(
         state,
@@ -460,13 +510,16 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    Ok(match cached_algorithm.as_ref() {
-        CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
+    let (connectors, rule_name) = match cached_algorithm.as_ref() {
+        CachedAlgorithm::Single(conn) => (vec![(**conn).clone()], None),
 
-        CachedAlgorithm::Priority(plist) => plist.clone(),
+        CachedAlgorithm::Priority(plist) => (plist.clone(), None),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => (
+            perform_volume_split(splits.to_vec())
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+            None,
+        ),
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
@@ -513,7 +566,8 @@ pub async fn perform_static_routing_v1(
                 // errors are ignored as this is just for diff checking as of now (optional flow).
                 logger::error!(decision_engine_euclid_evaluate_error=?e, "decision_engine_euclid: error in evaluation of rule")
             ).unwrap_or_default();
-            let routable_connectors = execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
+            let (routable_connectors, rule_name) =
+                execute_dsl_and_get_connector_v1(backend_input, interpreter)?;
             let connectors = routable_connectors
                 .iter()
                 .map(|c| c.connector.to_string())
@@ -527,11 +581,247 @@ pub async fn perform_static_routing_v1(
                 connectors,
                 "evaluate_routing".to_string(),
             );
-            routable_connectors
+            (routable_connectors, rule_name)
+        }
+    };
+
+    #[cfg(feature = "v1")]
+    perform_shadow_routing_v1(
+        state,
+        merchant_id,
+        Some(algorithm_id),
+        business_profile,
+        transaction_data,
+        &connectors,
+    );
+
+    Ok((
+        connectors,
+        Some(oss_storage::StaticRoutingDecision {
+            algorithm_id: algorithm_id.clone(),
+            rule_name,
+        }),
+    ))
+}
+
+/// Evaluates the algorithm in shadow mode of the profile for a payment in the background, and
+/// records how its result diverges from the connectors chosen by the active algorithm as a
+/// routing event. The result of the shadow algorithm is never used for the payment, hence it does
+/// not add to the latency of the payment, and failures are only logged.
+#[cfg(feature = "v1")]
+fn perform_shadow_routing_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    active_algorithm_id: Option<&common_utils::id_type::RoutingId>,
+    business_profile: &domain::Profile,
+    transaction_data: &routing::TransactionData<'_>,
+    active_connectors: &[routing_types::RoutableConnectorChoice],
+) {
+    let payment_data = match transaction_data {
+        routing::TransactionData::Payment(payment_data) => payment_data,
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(_) => return,
+    };
+
+    let Some(shadow_algorithm_id) = get_shadow_algorithm_id(business_profile) else {
+        return;
+    };
+
+    let backend_input = match make_dsl_input(payment_data) {
+        Ok(backend_input) => backend_input,
+        Err(error) => {
+            logger::error!(?error, "Failed to construct the shadow routing input");
+            return;
+        }
+    };
+
+    let state = state.clone();
+    let merchant_id = merchant_id.clone();
+    let business_profile = business_profile.clone();
+    let payment_id = payment_data.payment_attempt.payment_id.clone();
+    let request = utils::ShadowRoutingEventRequest {
+        active_algorithm_id: active_algorithm_id.cloned(),
+        shadow_algorithm_id,
+    };
+    let active_connectors = active_connectors.to_vec();
+
+    tokio::spawn(
+        async move {
+            let shadow_result = match evaluate_shadow_algorithm_v1(
+                &state,
+                &merchant_id,
+                &request.shadow_algorithm_id,
+                &business_profile,
+                backend_input,
+            )
+            .await
+            {
+                Ok(shadow_result) => shadow_result,
+                Err(error) => {
+                    logger::error!(?error, "Failed to evaluate the shadow routing algorithm");
+                    return;
+                }
+            };
+
+            let response = utils::ShadowRoutingEventResponse::new(active_connectors, shadow_result);
+            logger::debug!(shadow_routing_divergence=?response.divergence);
+
+            let Some(mut routing_event) = build_shadow_routing_event(
+                &state,
+                &business_profile,
+                payment_id.get_string_repr(),
+                "Shadow Routing",
+                "ShadowRouting.Evaluate",
+                &request,
+            ) else {
+                return;
+            };
+            routing_event.set_routable_connectors(response.shadow_connectors.clone());
+            routing_event.set_response_body(&response);
+            routing_event.set_status_code(200);
+            state.event_handler().log_event(&routing_event);
+        }
+        .in_current_span(),
+    );
+}
+
+/// Records the outcome of a payment of a profile with an algorithm in shadow mode as a routing
+/// event, to be compared with the divergence recorded when the payment was routed.
+#[cfg(feature = "v1")]
+pub fn log_shadow_routing_outcome_event(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_attempt: &oss_storage::PaymentAttempt,
+) {
+    let Some(shadow_algorithm_id) = get_shadow_algorithm_id(business_profile) else {
+        return;
+    };
+
+    let request = utils::ShadowRoutingOutcomeEventRequest {
+        shadow_algorithm_id,
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+    let response = utils::ShadowRoutingOutcomeEventResponse {
+        status: payment_attempt.status,
+        connector: payment_attempt.connector.clone(),
+        merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+    };
+
+    let Some(mut routing_event) = build_shadow_routing_event(
+        state,
+        business_profile,
+        payment_attempt.payment_id.get_string_repr(),
+        "Shadow Routing: Payment Outcome",
+        "ShadowRouting.RecordOutcome",
+        &request,
+    ) else {
+        return;
+    };
+    if let Some(connector) = payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector| api_enums::RoutableConnectors::from_str(connector).ok())
+    {
+        routing_event.set_payment_connector(routing_types::RoutableConnectorChoice {
+            choice_kind: routing_types::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+        });
+    }
+    routing_event.set_response_body(&response);
+    routing_event.set_status_code(200);
+    state.event_handler().log_event(&routing_event);
+}
+
+#[cfg(feature = "v1")]
+fn get_shadow_algorithm_id(
+    business_profile: &domain::Profile,
+) -> Option<common_utils::id_type::RoutingId> {
+    business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+        .transpose()
+        .map_err(|error| logger::error!(?error, "Failed to parse the routing algorithm ref"))
+        .ok()
+        .flatten()
+        .and_then(|routing_ref| routing_ref.shadow_algorithm_id)
+}
+
+#[cfg(feature = "v1")]
+async fn evaluate_shadow_algorithm_v1(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    algorithm_id: &common_utils::id_type::RoutingId,
+    business_profile: &domain::Profile,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<utils::ShadowRoutingResult> {
+    let key = format!(
+        "routing_config_shadow_{}_{}",
+        merchant_id.get_string_repr(),
+        business_profile.get_id().get_string_repr(),
+    );
+
+    let cached_algorithm = ROUTING_CACHE
+        .get_val::<Arc<CachedAlgorithm>>(CacheKey {
+            key: key.clone(),
+            prefix: state.tenant.redis_key_prefix.clone(),
+        })
+        .await;
+
+    let algorithm = if let Some(algo) = cached_algorithm {
+        algo
+    } else {
+        refresh_routing_cache_v1(state, key, algorithm_id, business_profile.get_id()).await?
+    };
+
+    // A volume split picks a connector at random for every payment, so its split is recorded
+    // instead of a single pick, which would diverge from the active algorithm by chance
+    Ok(match algorithm.as_ref() {
+        CachedAlgorithm::Single(conn) => {
+            utils::ShadowRoutingResult::Connectors(vec![(**conn).clone()])
+        }
+
+        CachedAlgorithm::Priority(plist) => utils::ShadowRoutingResult::Connectors(plist.clone()),
+
+        CachedAlgorithm::VolumeSplit(splits) => {
+            utils::ShadowRoutingResult::VolumeSplit(splits.clone())
         }
+
+        CachedAlgorithm::Advanced(interpreter) => utils::ShadowRoutingResult::Connectors(
+            execute_dsl_and_get_connector_v1(backend_input, interpreter)?.0,
+        ),
     })
 }
 
+#[cfg(feature = "v1")]
+fn build_shadow_routing_event<Req: serde::Serialize>(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_id: &str,
+    flow: &str,
+    url: &str,
+    request: &Req,
+) -> Option<routing_events::RoutingEvent> {
+    let request = serde_json::to_value(request)
+        .map_err(|error| logger::error!(?error, "Failed to serialize the shadow routing request"))
+        .ok()?;
+
+    Some(routing_events::RoutingEvent::new(
+        state.tenant.tenant_id.clone(),
+        "".to_string(),
+        flow,
+        request,
+        url.to_string(),
+        routing_events::ApiMethod::Internal,
+        payment_id.to_string(),
+        business_profile.get_id().to_owned(),
+        business_profile.merchant_id.to_owned(),
+        state.request_id,
+        routing_events::RoutingEngine::Router,
+    ))
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -614,16 +904,19 @@ pub fn perform_routing_for_single_straight_through_algorithm(
     })
 }
 
+/// Returns the connectors selected by the DSL along with the name of the rule which matched,
+/// the rule name is absent when none of the rules matched and the default selection was used
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
-) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::StaticRoutingAlgorithm = interpreter
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    let output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
+    let routing_output: routing_types::StaticRoutingAlgorithm =
+        output.connector_selection.foreign_into();
 
-    Ok(match routing_output {
+    let connectors = match routing_output {
         routing_types::StaticRoutingAlgorithm::Priority(plist) => plist,
 
         routing_types::StaticRoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits)
@@ -631,7 +924,9 @@ fn execute_dsl_and_get_connector_v1(
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .attach_printable("Unsupported algorithm received as a result of static routing")?,
-    })
+    };
+
+    Ok((connectors, output.rule_name))
 }
 
 pub async fn refresh_routing_cache_v1(
@@ -1120,6 +1415,7 @@ pub async fn perform_session_flow_routing<'a>(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: get_temporal_data(None, session_input.payment_intent.created_at),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1267,6 +1563,10 @@ pub async fn perform_session_flow_routing(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: get_temporal_data(
+            business_profile.routing_calendar_config.as_ref(),
+            session_input.payment_intent.created_at,
+        ),
     };
 
     for connector_data in session_input.chosen.iter() {
@@ -1364,10 +1664,13 @@ async fn perform_session_routing_for_pm_type(
             CachedAlgorithm::Priority(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                let (connectors, _) = execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?;
+                connectors
+            }
         }
     } else {
         routing::helpers::get_merchant_default_config(
@@ -1445,10 +1748,13 @@ async fn get_chosen_connectors<'a>(
             CachedAlgorithm::Priority(plist) => plist.clone(),
             CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec())
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
-            CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
-                session_pm_input.backend_input.clone(),
-                interpreter,
-            )?,
+            CachedAlgorithm::Advanced(interpreter) => {
+                let (connectors, _) = execute_dsl_and_get_connector_v1(
+                    session_pm_input.backend_input.clone(),
+                    interpreter,
+                )?;
+                connectors
+            }
         }
     } else {
         profile_wrapper
@@ -1572,6 +1878,7 @@ pub fn make_dsl_input_for_surcharge(
         acquirer_data: None,
         customer_device_data: None,
         issuer_data: None,
+        temporal_data: None,
     };
     Ok(backend_input)
 }
@@ -5,7 +5,7 @@ use std::{
 
 use api_models::{
     open_router as or_types, routing as api_routing,
-    routing::{ConnectorSelection, RoutableConnectorChoice},
+    routing::{ConnectorSelection, ConnectorVolumeSplit, RoutableConnectorChoice},
 };
 use async_trait::async_trait;
 use common_utils::{ext_traits::BytesExt, id_type};
@@ -1786,3 +1786,177 @@ pub enum ContractUpdationStatusEventResponse {
     ContractUpdationSucceeded,
     ContractUpdationFailed,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShadowRoutingEventRequest {
+    pub active_algorithm_id: Option<id_type::RoutingId>,
+    pub shadow_algorithm_id: id_type::RoutingId,
+}
+
+/// Result of evaluating the algorithm in shadow mode for a payment
+#[derive(Clone, Debug)]
+pub enum ShadowRoutingResult {
+    /// Connectors in the order in which they would be tried
+    Connectors(Vec<RoutableConnectorChoice>),
+    /// Split of the volume split algorithm, from which a connector is picked at random
+    VolumeSplit(Vec<ConnectorVolumeSplit>),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShadowRoutingEventResponse {
+    pub active_connectors: Vec<RoutableConnectorChoice>,
+    pub shadow_connectors: Vec<RoutableConnectorChoice>,
+    /// Split of the shadow algorithm, if it is a volume split, for which no divergence is
+    /// recorded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shadow_volume_split: Option<Vec<ConnectorVolumeSplit>>,
+    pub divergence: Option<ShadowRoutingDivergence>,
+}
+
+impl ShadowRoutingEventResponse {
+    pub fn new(
+        active_connectors: Vec<RoutableConnectorChoice>,
+        shadow_result: ShadowRoutingResult,
+    ) -> Self {
+        match shadow_result {
+            ShadowRoutingResult::Connectors(shadow_connectors) => Self {
+                divergence: Some(ShadowRoutingDivergence::new(
+                    &active_connectors,
+                    &shadow_connectors,
+                )),
+                active_connectors,
+                shadow_connectors,
+                shadow_volume_split: None,
+            },
+            ShadowRoutingResult::VolumeSplit(splits) => Self {
+                active_connectors,
+                shadow_connectors: splits.iter().map(|split| split.connector.clone()).collect(),
+                shadow_volume_split: Some(splits),
+                divergence: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShadowRoutingDivergence {
+    /// Both algorithms chose the same connectors in the same order
+    None,
+    /// The algorithms chose different connectors to be tried first
+    DifferentConnector,
+    /// The same connector is tried first, but the remaining connectors or their order differ
+    DifferentOrder,
+}
+
+impl ShadowRoutingDivergence {
+    pub fn new(
+        active_connectors: &[RoutableConnectorChoice],
+        shadow_connectors: &[RoutableConnectorChoice],
+    ) -> Self {
+        let is_same_choice = |active: &RoutableConnectorChoice,
+                              shadow: &RoutableConnectorChoice| {
+            active.connector == shadow.connector
+                && active.merchant_connector_id == shadow.merchant_connector_id
+        };
+
+        match (active_connectors.first(), shadow_connectors.first()) {
+            (Some(active), Some(shadow)) if !is_same_choice(active, shadow) => {
+                Self::DifferentConnector
+            }
+            (Some(_), None) | (None, Some(_)) => Self::DifferentConnector,
+            _ if active_connectors.len() != shadow_connectors.len()
+                || active_connectors
+                    .iter()
+                    .zip(shadow_connectors)
+                    .any(|(active, shadow)| !is_same_choice(active, shadow)) =>
+            {
+                Self::DifferentOrder
+            }
+            _ => Self::None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShadowRoutingOutcomeEventRequest {
+    pub shadow_algorithm_id: id_type::RoutingId,
+    pub attempt_id: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ShadowRoutingOutcomeEventResponse {
+    pub status: enums::AttemptStatus,
+    pub connector: Option<String>,
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::{enums::RoutableConnectors, routing::RoutableChoiceKind};
+
+    use super::*;
+
+    fn get_connector_choice(connector: RoutableConnectors) -> RoutableConnectorChoice {
+        RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: None,
+        }
+    }
+
+    #[test]
+    fn test_shadow_routing_divergence() {
+        let stripe = get_connector_choice(RoutableConnectors::Stripe);
+        let adyen = get_connector_choice(RoutableConnectors::Adyen);
+
+        assert_eq!(
+            ShadowRoutingDivergence::new(
+                &[stripe.clone(), adyen.clone()],
+                &[stripe.clone(), adyen.clone()]
+            ),
+            ShadowRoutingDivergence::None
+        );
+        assert_eq!(
+            ShadowRoutingDivergence::new(&[stripe.clone(), adyen.clone()], &[stripe.clone()]),
+            ShadowRoutingDivergence::DifferentOrder
+        );
+        assert_eq!(
+            ShadowRoutingDivergence::new(&[stripe], &[adyen]),
+            ShadowRoutingDivergence::DifferentConnector
+        );
+    }
+
+    #[test]
+    fn test_shadow_routing_volume_split_has_no_divergence() {
+        let stripe = get_connector_choice(RoutableConnectors::Stripe);
+        let adyen = get_connector_choice(RoutableConnectors::Adyen);
+
+        let response = ShadowRoutingEventResponse::new(
+            vec![stripe.clone()],
+            ShadowRoutingResult::VolumeSplit(vec![
+                ConnectorVolumeSplit {
+                    connector: stripe,
+                    split: 70,
+                },
+                ConnectorVolumeSplit {
+                    connector: adyen,
+                    split: 30,
+                },
+            ]),
+        );
+
+        assert!(response.divergence.is_none());
+        assert_eq!(response.shadow_connectors.len(), 2);
+        assert_eq!(
+            response
+                .shadow_volume_split
+                .map(|splits| splits.iter().map(|split| split.split).collect::<Vec<_>>()),
+            Some(vec![70, 30])
+        );
+    }
+}
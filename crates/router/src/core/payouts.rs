@@ -38,6 +38,8 @@ use scheduler::utils as pt_utils;
 use serde_json;
 use time::Duration;
 
+#[cfg(feature = "v1")]
+use crate::core::{transaction_approval, webhooks as webhooks_core};
 #[cfg(feature = "olap")]
 use crate::types::domain::behaviour::Conversion;
 #[cfg(feature = "olap")]
@@ -126,6 +128,7 @@ pub async fn get_connector_choice(
                 routing_info: PaymentRoutingInfo {
                     algorithm: None,
                     pre_routing_results: None,
+                    static_routing_decision: None,
                 },
             };
             helpers::decide_payout_connector(
@@ -147,6 +150,7 @@ pub async fn get_connector_choice(
                 routing_info: PaymentRoutingInfo {
                     algorithm: None,
                     pre_routing_results: None,
+                    static_routing_decision: None,
                 },
             };
             helpers::decide_payout_connector(
@@ -304,6 +308,69 @@ pub async fn payouts_core(
     todo!()
 }
 
+/// Holds the payout for approval if the profile's transaction approval policy requires it,
+/// otherwise proceeds with the connector calls
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payouts_core_with_approval_check(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_data: &mut PayoutData,
+    routing_algorithm: Option<serde_json::Value>,
+    eligible_connectors: Option<Vec<api_enums::PayoutConnectors>>,
+) -> RouterResult<()> {
+    let is_approval_required = transaction_approval::is_approval_required(
+        state,
+        &payout_data.business_profile,
+        transaction_approval::ApprovalFlow::Payout,
+        payout_data.payouts.amount,
+        payout_data.payouts.destination_currency,
+    )
+    .await?;
+
+    if is_approval_required {
+        update_payout_status(
+            state,
+            merchant_context,
+            payout_data,
+            storage_enums::PayoutStatus::PendingApproval,
+            None,
+        )
+        .await?;
+        trigger_payout_outgoing_webhook(
+            state,
+            merchant_context,
+            payout_data,
+            storage_enums::EventType::PayoutPendingApproval,
+        )
+        .await
+        .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+        .ok();
+        return Ok(());
+    }
+
+    payouts_core(
+        state,
+        merchant_context,
+        payout_data,
+        routing_algorithm,
+        eligible_connectors,
+    )
+    .await
+}
+
+#[cfg(feature = "v2")]
+#[instrument(skip_all)]
+pub async fn payouts_core_with_approval_check(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_data: &mut PayoutData,
+    routing_algorithm: Option<serde_json::Value>,
+    eligible_connectors: Option<Vec<api_enums::PayoutConnectors>>,
+) -> RouterResult<()> {
+    todo!()
+}
+
 #[instrument(skip_all)]
 pub async fn payouts_create_core(
     state: SessionState,
@@ -353,7 +420,7 @@ pub async fn payouts_create_core(
     }
 
     if let Some(true) = payout_data.payouts.confirm {
-        payouts_core(
+        payouts_core_with_approval_check(
             &state,
             &merchant_context,
             &mut payout_data,
@@ -393,6 +460,7 @@ pub async fn payouts_confirm_core(
             storage_enums::PayoutStatus::Ineligible,
             storage_enums::PayoutStatus::RequiresFulfillment,
             storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+            storage_enums::PayoutStatus::PendingApproval,
         ],
         "confirm",
     )?;
@@ -417,7 +485,7 @@ pub async fn payouts_confirm_core(
         .await
         .transpose()?;
 
-    payouts_core(
+    payouts_core_with_approval_check(
         &state,
         &merchant_context,
         &mut payout_data,
@@ -447,8 +515,11 @@ pub async fn payouts_update_core(
     let payout_attempt = payout_data.payout_attempt.to_owned();
     let status = payout_attempt.status;
 
-    // Verify update feasibility
-    if helpers::is_payout_terminal_state(status) || helpers::is_payout_initiated(status) {
+    // Verify update feasibility, payouts held for approval can only be approved or rejected
+    if helpers::is_payout_terminal_state(status)
+        || helpers::is_payout_initiated(status)
+        || status == storage_enums::PayoutStatus::PendingApproval
+    {
         return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
             message: format!(
                 "Payout {} cannot be updated for status {}",
@@ -488,7 +559,7 @@ pub async fn payouts_update_core(
     }
 
     if let Some(true) = payout_data.payouts.confirm {
-        payouts_core(
+        payouts_core_with_approval_check(
             &state,
             &merchant_context,
             &mut payout_data,
@@ -635,6 +706,194 @@ pub async fn payouts_cancel_core(
     response_handler(&state, &merchant_context, &payout_data).await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payouts_approve_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: payouts::PayoutApprovalRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let mut payout_data =
+        make_payout_data_pending_approval(&state, &merchant_context, profile_id, &req).await?;
+
+    update_payout_status(
+        &state,
+        &merchant_context,
+        &mut payout_data,
+        storage_enums::PayoutStatus::RequiresConfirmation,
+        None,
+    )
+    .await?;
+    trigger_payout_outgoing_webhook(
+        &state,
+        &merchant_context,
+        &payout_data,
+        storage_enums::EventType::PayoutApproved,
+    )
+    .await
+    .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+    .ok();
+
+    let routing_algorithm = payout_data.payout_attempt.routing_info.clone();
+    payouts_core(
+        &state,
+        &merchant_context,
+        &mut payout_data,
+        routing_algorithm,
+        None,
+    )
+    .await?;
+
+    response_handler(&state, &merchant_context, &payout_data).await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payouts_reject_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: payouts::PayoutApprovalRequest,
+) -> RouterResponse<payouts::PayoutCreateResponse> {
+    let mut payout_data =
+        make_payout_data_pending_approval(&state, &merchant_context, profile_id, &req).await?;
+
+    update_payout_status(
+        &state,
+        &merchant_context,
+        &mut payout_data,
+        storage_enums::PayoutStatus::Cancelled,
+        Some(
+            req.reason
+                .unwrap_or_else(|| crate::consts::PAYOUT_REJECTED_BY_APPROVER.to_string()),
+        ),
+    )
+    .await?;
+    trigger_payout_outgoing_webhook(
+        &state,
+        &merchant_context,
+        &payout_data,
+        storage_enums::EventType::PayoutRejected,
+    )
+    .await
+    .map_err(|error| logger::warn!(payouts_outgoing_webhook_error=?error))
+    .ok();
+
+    response_handler(&state, &merchant_context, &payout_data).await
+}
+
+#[cfg(feature = "v1")]
+async fn make_payout_data_pending_approval(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: &payouts::PayoutApprovalRequest,
+) -> RouterResult<PayoutData> {
+    let payout_data = Box::pin(make_payout_data(
+        state,
+        merchant_context,
+        profile_id,
+        &payouts::PayoutRequest::PayoutActionRequest(payouts::PayoutActionRequest {
+            payout_id: req.payout_id.clone(),
+        }),
+        &state.locale,
+    ))
+    .await?;
+
+    helpers::validate_payout_status_against_not_allowed_statuses(
+        payout_data.payout_attempt.status,
+        &[
+            storage_enums::PayoutStatus::Success,
+            storage_enums::PayoutStatus::Failed,
+            storage_enums::PayoutStatus::Cancelled,
+            storage_enums::PayoutStatus::Initiated,
+            storage_enums::PayoutStatus::Expired,
+            storage_enums::PayoutStatus::Reversed,
+            storage_enums::PayoutStatus::Pending,
+            storage_enums::PayoutStatus::Ineligible,
+            storage_enums::PayoutStatus::RequiresCreation,
+            storage_enums::PayoutStatus::RequiresConfirmation,
+            storage_enums::PayoutStatus::RequiresPayoutMethodData,
+            storage_enums::PayoutStatus::RequiresFulfillment,
+            storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+        ],
+        "approve or reject",
+    )?;
+
+    Ok(payout_data)
+}
+
+#[cfg(feature = "v1")]
+async fn update_payout_status(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_data: &mut PayoutData,
+    status: storage_enums::PayoutStatus,
+    error_message: Option<String>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_data.payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_message,
+        error_code: None,
+        is_eligible: None,
+        unified_code: None,
+        unified_message: None,
+    };
+    payout_data.payout_attempt = db
+        .update_payout_attempt(
+            &payout_data.payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = db
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+async fn trigger_payout_outgoing_webhook(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    payout_data: &PayoutData,
+    event_type: storage_enums::EventType,
+) -> RouterResult<()> {
+    let payout_create_response =
+        match response_handler(state, merchant_context, payout_data).await? {
+            services::ApplicationResponse::Json(response) => response,
+            _ => Err(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payout create response")?,
+        };
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.clone(),
+        payout_data.business_profile.clone(),
+        event_type,
+        storage_enums::EventClass::Payouts,
+        payout_data.payout_attempt.payout_id.clone(),
+        storage_enums::EventObjectType::PayoutDetails,
+        api::OutgoingWebhookContent::PayoutDetails(Box::new(payout_create_response)),
+        Some(payout_data.payout_attempt.created_at),
+    ))
+    .await
+}
+
 #[instrument(skip_all)]
 pub async fn payouts_fulfill_core(
     state: SessionState,
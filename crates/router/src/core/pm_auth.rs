@@ -529,6 +529,9 @@ async fn store_bank_details_in_payment_methods(
                 network_token_requestor_reference_id: None,
                 network_token_locker_id: None,
                 network_token_payment_method_data: None,
+                network_token_status: None,
+                card_expires_at: None,
+                external_vault_source: None,
             };
 
             new_entries.push(pm_new);
@@ -24,6 +24,7 @@ use crate::{
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token, helpers},
         refunds::transformers::SplitRefundInput,
+        transaction_approval,
         utils::{
             self as core_utils, refunds_transformers as transformers,
             refunds_validator as validator,
@@ -803,6 +804,180 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND APPROVAL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_approve_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let refund =
+        find_refund_pending_approval(&state, &merchant_context, profile_id, &req.refund_id).await?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+            &refund.connector_transaction_id,
+            &refund.payment_id,
+            &refund.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_attempt.payment_id,
+            &refund.merchant_id,
+            merchant_context.get_merchant_key_store(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let approved_refund = db
+        .update_refund(
+            refund.clone(),
+            storage::RefundUpdate::ManualUpdate {
+                refund_status: Some(enums::RefundStatus::Pending),
+                refund_error_message: None,
+                refund_error_code: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while approving refund: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+
+    utils::trigger_refund_outgoing_webhook_with_event_type(
+        &state,
+        &merchant_context,
+        &approved_refund,
+        payment_attempt.profile_id.clone(),
+        enums::EventType::RefundApproved,
+    )
+    .await
+    .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+    .ok();
+
+    let split_refunds = core_utils::get_split_refunds(SplitRefundInput {
+        split_payment_request: payment_intent.split_payments.clone(),
+        payment_charges: payment_attempt.charges.clone(),
+        charge_id: payment_attempt.charge_id.clone(),
+        refund_request: approved_refund.split_refunds.clone(),
+    })?;
+
+    let refund = Box::pin(schedule_refund_execution(
+        &state,
+        approved_refund,
+        api_models::refunds::RefundType::Instant,
+        &merchant_context,
+        &payment_attempt,
+        &payment_intent,
+        None,
+        split_refunds,
+    ))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(refund.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_reject_core(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_context.get_merchant_account().storage_scheme;
+    let refund =
+        find_refund_pending_approval(&state, &merchant_context, profile_id, &req.refund_id).await?;
+
+    let rejected_refund = db
+        .update_refund(
+            refund.clone(),
+            storage::RefundUpdate::ManualUpdate {
+                refund_status: Some(enums::RefundStatus::Failure),
+                refund_error_message: Some(
+                    req.reason
+                        .unwrap_or_else(|| consts::REFUND_REJECTED_BY_APPROVER.to_string()),
+                ),
+                refund_error_code: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while rejecting refund: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+
+    if let Some(profile_id) = rejected_refund.profile_id.clone() {
+        utils::trigger_refund_outgoing_webhook_with_event_type(
+            &state,
+            &merchant_context,
+            &rejected_refund,
+            profile_id,
+            enums::EventType::RefundRejected,
+        )
+        .await
+        .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+        .ok();
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        rejected_refund.foreign_into(),
+    ))
+}
+
+async fn find_refund_pending_approval(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    refund_id: &str,
+) -> RouterResult<storage::Refund> {
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_context.get_merchant_account().get_id(),
+            refund_id,
+            merchant_context.get_merchant_account().storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::PendingApproval,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Refund {} cannot be approved or rejected for status {}",
+                    refund.refund_id, refund.refund_status
+                ),
+            }))
+        },
+    )?;
+
+    Ok(refund)
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
@@ -894,6 +1069,21 @@ pub async fn validate_and_create_refund(
         .attach_printable("No connector populated in payment attempt")?;
     let (connector_transaction_id, processor_transaction_data) =
         ConnectorTransactionId::form_id_and_data(connector_transaction_id);
+
+    let refund_status = if is_refund_approval_required(
+        state,
+        merchant_context,
+        &payment_attempt.profile_id,
+        refund_amount,
+        currency,
+    )
+    .await?
+    {
+        enums::RefundStatus::PendingApproval
+    } else {
+        enums::RefundStatus::Pending
+    };
+
     let refund_create_req = storage::RefundNew {
         refund_id: refund_id.to_string(),
         internal_reference_id: utils::generate_id(consts::ID_LENGTH, "refid"),
@@ -908,7 +1098,7 @@ pub async fn validate_and_create_refund(
         currency,
         created_at: common_utils::date_time::now(),
         modified_at: common_utils::date_time::now(),
-        refund_status: enums::RefundStatus::Pending,
+        refund_status,
         metadata: req.metadata,
         description: req.reason.clone(),
         attempt_id: payment_attempt.attempt_id.clone(),
@@ -936,6 +1126,18 @@ pub async fn validate_and_create_refund(
         )
         .await
     {
+        Ok(refund) if refund.refund_status == enums::RefundStatus::PendingApproval => {
+            utils::trigger_refund_outgoing_webhook(
+                state,
+                merchant_context,
+                &refund,
+                payment_attempt.profile_id.clone(),
+            )
+            .await
+            .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+            .ok();
+            refund
+        }
         Ok(refund) => {
             Box::pin(schedule_refund_execution(
                 state,
@@ -982,6 +1184,35 @@ pub async fn validate_and_create_refund(
     Ok(refund.foreign_into())
 }
 
+async fn is_refund_approval_required(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    profile_id: &common_utils::id_type::ProfileId,
+    refund_amount: MinorUnit,
+    currency: enums::Currency,
+) -> RouterResult<bool> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            &state.into(),
+            merchant_context.get_merchant_key_store(),
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    transaction_approval::is_approval_required(
+        state,
+        &business_profile,
+        transaction_approval::ApprovalFlow::Refund,
+        refund_amount,
+        currency,
+    )
+    .await
+}
+
 // ********************************************** Refund list **********************************************
 
 ///   If payment-id is provided, lists all the refunds associated with that particular payment-id
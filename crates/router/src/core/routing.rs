@@ -78,6 +78,7 @@ pub struct PaymentsDslInput<'a> {
     pub address: &'a payment_address::PaymentAddress,
     pub recurring_details: Option<&'a mandates_api::RecurringDetails>,
     pub currency: storage_enums::Currency,
+    pub routing_calendar_config: Option<&'a common_types::domain::RoutingCalendarConfig>,
 }
 
 impl<'a> PaymentsDslInput<'a> {
@@ -89,6 +90,7 @@ impl<'a> PaymentsDslInput<'a> {
         address: &'a payment_address::PaymentAddress,
         recurring_details: Option<&'a mandates_api::RecurringDetails>,
         currency: storage_enums::Currency,
+        routing_calendar_config: Option<&'a common_types::domain::RoutingCalendarConfig>,
     ) -> Self {
         Self {
             setup_mandate,
@@ -98,6 +100,7 @@ impl<'a> PaymentsDslInput<'a> {
             address,
             recurring_details,
             currency,
+            routing_calendar_config,
         }
     }
 }
@@ -706,6 +709,10 @@ pub async fn link_routing_config(
                     })
                 },
             )?;
+            // An algorithm promoted from shadow mode is no longer evaluated as the shadow
+            if routing_ref.shadow_algorithm_id.as_ref() == Some(&algorithm_id) {
+                routing_ref.update_shadow_algorithm_id(None);
+            }
             routing_ref.update_algorithm_id(algorithm_id);
             helpers::update_profile_active_algorithm_ref(
                 db,
@@ -941,6 +948,7 @@ pub async fn unlink_routing_config(
                             timestamp,
                             config_algo_id: routing_algo_ref.config_algo_id.clone(),
                             surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                            shadow_algorithm_id: routing_algo_ref.shadow_algorithm_id,
                         };
 
                     let record = db
@@ -976,6 +984,180 @@ pub async fn unlink_routing_config(
     }
 }
 
+#[cfg(feature = "v1")]
+pub async fn link_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_LINK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_context.get_merchant_account().get_id(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&routing_algorithm.profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ProfileNotFound {
+        id: routing_algorithm.profile_id.get_string_repr().to_owned(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    utils::when(
+        routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Cannot use {}'s routing algorithm in shadow mode, only payment routing algorithms are supported",
+                    routing_algorithm.algorithm_for
+                ),
+            })
+        },
+    )?;
+
+    utils::when(
+        !matches!(
+            routing_algorithm.kind,
+            diesel_models::enums::RoutingAlgorithmKind::Single
+                | diesel_models::enums::RoutingAlgorithmKind::Priority
+                | diesel_models::enums::RoutingAlgorithmKind::Advanced
+                | diesel_models::enums::RoutingAlgorithmKind::VolumeSplit
+        ),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only static routing algorithms can be used in shadow mode".to_string(),
+            })
+        },
+    )?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    utils::when(
+        routing_ref.algorithm_id == Some(algorithm_id.clone()),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already active".to_string(),
+            })
+        },
+    )?;
+    utils::when(
+        routing_ref.shadow_algorithm_id == Some(algorithm_id.clone()),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Algorithm is already in shadow mode".to_string(),
+            })
+        },
+    )?;
+
+    routing_ref.update_shadow_algorithm_id(Some(algorithm_id));
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        routing_algorithm.foreign_into(),
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn unlink_shadow_routing_config(
+    state: SessionState,
+    merchant_context: domain::MerchantContext,
+    request: routing_types::RoutingConfigRequest,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_UNLINK_CONFIG.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let profile_id = request
+        .profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })
+        .attach_printable("Profile_id not provided")?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        Some(&profile_id),
+        merchant_context.get_merchant_account().get_id(),
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+        message: "The business_profile is not present".to_string(),
+    })?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    let algorithm_id = routing_ref.shadow_algorithm_id.clone().ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "No algorithm is in shadow mode".to_string(),
+        },
+    )?;
+
+    let record = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    routing_ref.update_shadow_algorithm_id(None);
+    helpers::update_profile_active_algorithm_ref(
+        db,
+        key_manager_state,
+        merchant_context.get_merchant_key_store(),
+        business_profile,
+        routing_ref,
+        &enums::TransactionType::Payment,
+    )
+    .await?;
+
+    metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn update_default_fallback_routing(
     state: SessionState,
@@ -2278,6 +2460,7 @@ impl RoutableConnectors {
             payment_data.get_address(),
             payment_data.get_recurring_details(),
             payment_data.get_currency(),
+            None,
         );
 
         let routable_connector_choice = self.0.clone();
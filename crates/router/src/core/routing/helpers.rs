@@ -271,16 +271,28 @@ pub async fn update_profile_active_algorithm_ref(
 
     // Invalidate the routing cache for Payments and Payouts transaction types
     if !transaction_type.is_three_ds_authentication() {
-        let routing_cache_key = cache::CacheKind::Routing(
+        let mut routing_cache_keys = vec![cache::CacheKind::Routing(
             format!(
                 "routing_config_{}_{}",
                 merchant_id.get_string_repr(),
                 profile_id.get_string_repr(),
             )
             .into(),
-        );
+        )];
+
+        // Shadow algorithms are only evaluated for payments
+        if *transaction_type == storage::enums::TransactionType::Payment {
+            routing_cache_keys.push(cache::CacheKind::Routing(
+                format!(
+                    "routing_config_shadow_{}_{}",
+                    merchant_id.get_string_repr(),
+                    profile_id.get_string_repr(),
+                )
+                .into(),
+            ));
+        }
 
-        cache::redact_from_redis_and_publish(db.get_cache_store().as_ref(), [routing_cache_key])
+        cache::redact_from_redis_and_publish(db.get_cache_store().as_ref(), routing_cache_keys)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to invalidate routing cache")?;
@@ -0,0 +1,100 @@
+use common_types::domain::ApprovalThreshold;
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::domain,
+};
+
+/// Money movement flows that can be held for approval
+#[derive(Debug, Clone, Copy, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+pub enum ApprovalFlow {
+    Refund,
+    Payout,
+}
+
+impl ApprovalFlow {
+    fn get_threshold(self, business_profile: &domain::Profile) -> Option<&ApprovalThreshold> {
+        let policy = business_profile.transaction_approval_policy.as_ref()?;
+        match self {
+            Self::Refund => policy.refund.as_ref(),
+            Self::Payout => policy.payout.as_ref(),
+        }
+    }
+}
+
+fn get_bulk_counter_key(
+    flow: ApprovalFlow,
+    profile_id: &common_utils::id_type::ProfileId,
+) -> String {
+    format!(
+        "transaction_approval_{flow}_count_{}",
+        profile_id.get_string_repr()
+    )
+}
+
+/// Decides whether a refund or payout of `amount` in `currency` must be held for approval, based
+/// on the profile's transaction approval policy.
+///
+/// Every call counts towards the bulk window of the profile, so this must be invoked exactly once
+/// per transaction.
+pub async fn is_approval_required(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    flow: ApprovalFlow,
+    amount: MinorUnit,
+    currency: common_enums::Currency,
+) -> RouterResult<bool> {
+    let Some(threshold) = flow.get_threshold(business_profile) else {
+        return Ok(false);
+    };
+
+    if threshold.is_amount_above_threshold(amount, currency) {
+        return Ok(true);
+    }
+
+    match (threshold.bulk_count, threshold.bulk_window_in_secs) {
+        (Some(bulk_count), Some(bulk_window_in_secs)) => {
+            let count = increment_bulk_counter(
+                state,
+                &get_bulk_counter_key(flow, business_profile.get_id()),
+                i64::from(bulk_window_in_secs),
+            )
+            .await?;
+            Ok(count > i64::from(bulk_count))
+        }
+        (Some(_), None) | (None, Some(_)) => {
+            logger::warn!(
+                "Incomplete bulk approval threshold configured for {flow}, skipping bulk check"
+            );
+            Ok(false)
+        }
+        (None, None) => Ok(false),
+    }
+}
+
+async fn increment_bulk_counter(
+    state: &SessionState,
+    cache_key: &str,
+    window_in_secs: i64,
+) -> RouterResult<i64> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The counter is incremented and its expiry is set in a single script, so concurrent
+    // transactions never observe the same count, and a failure after the increment cannot leave
+    // the counter without an expiry. The expiry of an existing counter is not modified, so the
+    // window is not extended by subsequent transactions.
+    redis_conn
+        .increment_key_with_expiry(&cache_key.into(), window_in_secs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment transaction approval counter")
+}
@@ -46,7 +46,7 @@ use crate::{
     types::{domain, transformers::ForeignInto},
     utils::{
         self,
-        user::{theme as theme_utils, two_factor_auth as tfa_utils},
+        user::{passkey as passkey_utils, theme as theme_utils, two_factor_auth as tfa_utils},
     },
 };
 #[cfg(feature = "email")]
@@ -55,6 +55,7 @@ use crate::{services::email::types as email_types, utils::user as user_utils};
 pub mod dashboard_metadata;
 #[cfg(feature = "dummy_connector")]
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
 
 #[cfg(feature = "email")]
@@ -421,6 +422,7 @@ pub async fn signout(
     tfa_utils::delete_totp_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_recovery_code_from_redis(&state, &user_from_token.user_id).await?;
     tfa_utils::delete_totp_secret_from_redis(&state, &user_from_token.user_id).await?;
+    passkey_utils::delete_passkey_from_redis(&state, &user_from_token.user_id).await?;
 
     auth::blacklist::insert_user_in_blacklist(&state, &user_from_token.user_id).await?;
     auth::cookies::remove_cookie_response()
@@ -2103,6 +2105,7 @@ pub async fn reset_totp(
 
     if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
         && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
+        && !passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?
     {
         return Err(UserErrors::TwoFactorAuthRequired.into());
     }
@@ -2255,7 +2258,9 @@ pub async fn generate_recovery_codes(
     state: SessionState,
     user_token: auth::UserIdFromAuth,
 ) -> UserResponse<user_api::RecoveryCodes> {
-    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await? {
+    if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
+        && !passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?
+    {
         return Err(UserErrors::TotpRequired.into());
     }
 
@@ -2320,7 +2325,11 @@ pub async fn verify_recovery_code(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    if user_from_db.get_totp_status() != TotpStatus::Set {
+    if user_from_db.get_totp_status() != TotpStatus::Set
+        && passkey_utils::list_passkeys_for_user(&state, &user_token.user_id)
+            .await?
+            .is_empty()
+    {
         return Err(UserErrors::TwoFactorAuthNotSetup.into());
     }
 
@@ -2381,8 +2390,24 @@ pub async fn terminate_two_factor_auth(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
-        if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
+    let is_passkey_required = passkey_utils::is_passkey_required_for_user(
+        &state,
+        &user_token.user_id,
+        user_token.tenant_id.as_ref(),
+    )
+    .await?;
+
+    if state.conf.user.force_two_factor_auth || is_passkey_required || !skip_two_factor_auth {
+        let is_totp_verified = tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?;
+        let is_passkey_verified =
+            passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?;
+
+        if is_passkey_required && !is_passkey_verified {
+            return Err(UserErrors::PasskeyRequired.into());
+        }
+
+        if !is_totp_verified
+            && !is_passkey_verified
             && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
         {
             return Err(UserErrors::TwoFactorAuthRequired.into());
@@ -2392,7 +2417,8 @@ pub async fn terminate_two_factor_auth(
             return Err(UserErrors::TwoFactorAuthNotSetup.into());
         }
 
-        if user_from_db.get_totp_status() != TotpStatus::Set {
+        // Users who only registered a passkey do not have a TOTP secret to verify against
+        if is_totp_verified && user_from_db.get_totp_status() != TotpStatus::Set {
             state
                 .global_store
                 .update_user_by_user_id(
@@ -2418,6 +2444,9 @@ pub async fn terminate_two_factor_auth(
     let _ = tfa_utils::delete_recovery_code_attempts_from_redis(&state, &user_token.user_id)
         .await
         .inspect_err(|error| logger::error!(?error));
+    let _ = passkey_utils::delete_passkey_attempts_from_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
 
     auth::cookies::set_cookie_response(
         user_api::TokenResponse {
@@ -2437,6 +2466,7 @@ pub async fn check_two_factor_auth_status(
             totp: tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?,
             recovery_code: tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id)
                 .await?,
+            passkey: passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
         },
     ))
 }
@@ -2452,8 +2482,16 @@ pub async fn check_two_factor_auth_status_with_attempts(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    let is_skippable = state.conf.user.force_two_factor_auth.not();
-    if user_from_db.get_totp_status() == TotpStatus::NotSet {
+    let is_skippable = state.conf.user.force_two_factor_auth.not()
+        && passkey_utils::is_passkey_required_for_user(
+            &state,
+            &user_token.user_id,
+            user_token.tenant_id.as_ref(),
+        )
+        .await?
+        .not();
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+    if user_from_db.get_totp_status() == TotpStatus::NotSet && passkeys.is_empty() {
         return Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
             status: None,
             is_skippable,
@@ -2470,15 +2508,493 @@ pub async fn check_two_factor_auth_status_with_attempts(
         remaining_attempts: consts::user::RECOVERY_CODE_MAX_ATTEMPTS
             - tfa_utils::get_recovery_code_attempts_from_redis(&state, &user_token.user_id).await?,
     };
+    let passkey = user_api::TwoFactorAuthAttempts {
+        is_completed: passkey_utils::check_passkey_in_redis(&state, &user_token.user_id).await?,
+        remaining_attempts: consts::user::PASSKEY_MAX_ATTEMPTS
+            - passkey_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?,
+    };
     Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
         status: Some(user_api::TwoFactorAuthStatusResponseWithAttempts {
             totp,
             recovery_code,
+            passkey,
         }),
         is_skippable,
     }))
 }
 
+fn get_passkey_credential_descriptors(
+    passkeys: &[storage_user::webauthn_credential::UserWebauthnCredential],
+) -> Vec<user_api::PasskeyCredentialDescriptor> {
+    passkeys
+        .iter()
+        .map(|passkey| user_api::PasskeyCredentialDescriptor {
+            credential_type: consts::user::PASSKEY_CREDENTIAL_TYPE.to_string(),
+            id: passkey.credential_id.clone(),
+        })
+        .collect()
+}
+
+async fn validate_second_factor_for_passkey_change(
+    state: &SessionState,
+    user_from_db: &domain::UserFromStorage,
+    passkeys: &[storage_user::webauthn_credential::UserWebauthnCredential],
+) -> UserResult<()> {
+    // A user setting up their first second factor has nothing to verify against yet
+    if user_from_db.get_totp_status() != TotpStatus::Set && passkeys.is_empty() {
+        return Ok(());
+    }
+
+    let user_id = user_from_db.get_user_id();
+    if !tfa_utils::check_totp_in_redis(state, user_id).await?
+        && !tfa_utils::check_recovery_code_in_redis(state, user_id).await?
+        && !passkey_utils::check_passkey_in_redis(state, user_id).await?
+    {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+
+    Ok(())
+}
+
+async fn verify_passkey_assertion(
+    state: &SessionState,
+    user_id: &str,
+    expected_challenge: &str,
+    assertion: &user_api::PasskeyAssertionRequest,
+    require_user_verification: bool,
+) -> UserResult<()> {
+    let passkey = state
+        .global_store
+        .find_user_webauthn_credential_by_credential_id(&assertion.credential_id)
+        .await
+        .to_not_found_response(UserErrors::InvalidPasskey)?;
+
+    if passkey.user_id != user_id {
+        return Err(UserErrors::InvalidPasskey)
+            .attach_printable("Passkey does not belong to the user");
+    }
+
+    let client_data_json = passkey_utils::decode_secret(&assertion.client_data_json)?;
+    let authenticator_data = passkey_utils::decode_secret(&assertion.authenticator_data)?;
+    let signature = passkey_utils::decode_secret(&assertion.signature)?;
+
+    passkey_utils::verify_client_data(
+        state,
+        &client_data_json,
+        passkey_utils::PasskeyCeremony::Authentication,
+        expected_challenge,
+    )?;
+    let parsed_authenticator_data = passkey_utils::parse_authenticator_data(
+        &passkey_utils::get_rp_id(state),
+        &authenticator_data,
+    )?;
+    if require_user_verification && !parsed_authenticator_data.user_verified {
+        return Err(UserErrors::InvalidPasskey).attach_printable("User verification flag not set");
+    }
+
+    passkey_utils::verify_assertion_signature(
+        &passkey.public_key,
+        &authenticator_data,
+        &client_data_json,
+        &signature,
+    )?;
+    passkey_utils::validate_sign_count(passkey.sign_count, parsed_authenticator_data.sign_count)?;
+
+    state
+        .global_store
+        .update_user_webauthn_credential(
+            user_id,
+            &passkey.credential_id,
+            storage_user::webauthn_credential::UserWebauthnCredentialUpdate::AssertionUpdate {
+                sign_count: parsed_authenticator_data.sign_count.into(),
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(())
+}
+
+pub async fn begin_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyRegistrationOptionsResponse> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+    validate_second_factor_for_passkey_change(&state, &user_from_db, &passkeys).await?;
+
+    let challenge = passkey_utils::generate_challenge();
+    passkey_utils::insert_challenge_in_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_REGISTRATION_CHALLENGE_PREFIX,
+        &user_token.user_id,
+        &challenge,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyRegistrationOptionsResponse {
+            challenge,
+            rp: user_api::PasskeyRelyingParty {
+                id: passkey_utils::get_rp_id(&state),
+                name: state.conf.user.passkey_rp_name.clone(),
+            },
+            user: user_api::PasskeyUserEntity {
+                id: passkey_utils::get_user_handle(user_from_db.get_user_id()),
+                name: user_from_db.get_email(),
+                display_name: user_from_db.get_name(),
+            },
+            pub_key_cred_params: [
+                consts::user::PASSKEY_ALGORITHM_ES256,
+                consts::user::PASSKEY_ALGORITHM_RS256,
+            ]
+            .into_iter()
+            .map(|alg| user_api::PasskeyCredentialParameters {
+                credential_type: consts::user::PASSKEY_CREDENTIAL_TYPE.to_string(),
+                alg,
+            })
+            .collect(),
+            exclude_credentials: get_passkey_credential_descriptors(&passkeys),
+            authenticator_selection: user_api::PasskeyAuthenticatorSelection {
+                resident_key: consts::user::PASSKEY_RESIDENT_KEY_REQUIREMENT.to_string(),
+                user_verification: consts::user::PASSKEY_USER_VERIFICATION_REQUIREMENT.to_string(),
+            },
+            attestation: consts::user::PASSKEY_ATTESTATION_CONVEYANCE.to_string(),
+            timeout: consts::user::PASSKEY_CEREMONY_TIMEOUT_IN_MILLIS,
+        },
+    ))
+}
+
+pub async fn finish_passkey_registration(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::PasskeyRegisterRequest,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+    validate_second_factor_for_passkey_change(&state, &user_from_db, &passkeys).await?;
+
+    let challenge = passkey_utils::get_and_delete_challenge_from_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_REGISTRATION_CHALLENGE_PREFIX,
+        &user_token.user_id,
+    )
+    .await?;
+
+    let client_data_json = passkey_utils::decode_secret(&req.client_data_json)?;
+    let attestation_object = passkey_utils::parse_attestation_object(
+        &passkey_utils::decode_secret(&req.attestation_object)?,
+    )?;
+
+    passkey_utils::verify_client_data(
+        &state,
+        &client_data_json,
+        passkey_utils::PasskeyCeremony::Registration,
+        &challenge,
+    )?;
+    let parsed_authenticator_data = passkey_utils::parse_authenticator_data(
+        &passkey_utils::get_rp_id(&state),
+        &attestation_object.authenticator_data,
+    )?;
+    if !parsed_authenticator_data.user_verified {
+        return Err(UserErrors::InvalidPasskey).attach_printable("User verification flag not set");
+    }
+    let attested_credential_data = parsed_authenticator_data
+        .attested_credential_data
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Attested credential data missing from registration")?;
+    if passkey_utils::encode(&attested_credential_data.credential_id) != req.credential_id {
+        return Err(UserErrors::InvalidPasskey).attach_printable("Credential id mismatch");
+    }
+    passkey_utils::verify_attestation(
+        &attestation_object,
+        &attested_credential_data,
+        &client_data_json,
+    )?;
+
+    let name = req.name.trim();
+    if name.is_empty() || name.len() > consts::user::MAX_PASSKEY_NAME_LENGTH {
+        return Err(UserErrors::InvalidPasskey).attach_printable("Invalid passkey name");
+    }
+
+    state
+        .global_store
+        .insert_user_webauthn_credential(
+            storage_user::webauthn_credential::UserWebauthnCredentialNew {
+                credential_id: req.credential_id,
+                user_id: user_token.user_id.clone(),
+                name: name.to_string(),
+                public_key: attested_credential_data.public_key,
+                algorithm: attested_credential_data.algorithm,
+                sign_count: parsed_authenticator_data.sign_count.into(),
+                created_at: common_utils::date_time::now(),
+                last_used_at: None,
+            },
+        )
+        .await
+        .to_duplicate_response(UserErrors::InvalidPasskey)?;
+
+    // The user has just proven possession of the passkey, so they need not be asked again
+    let _ = passkey_utils::insert_passkey_in_redis(&state, &user_token.user_id)
+        .await
+        .inspect_err(|error| logger::error!(?error));
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_authentication(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+) -> UserResponse<user_api::PasskeyAuthenticationOptionsResponse> {
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+    if passkeys.is_empty() {
+        return Err(UserErrors::PasskeyNotSetup.into());
+    }
+
+    let challenge = passkey_utils::generate_challenge();
+    passkey_utils::insert_challenge_in_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_AUTHENTICATION_CHALLENGE_PREFIX,
+        &user_token.user_id,
+        &challenge,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyAuthenticationOptionsResponse {
+            challenge,
+            rp_id: passkey_utils::get_rp_id(&state),
+            allow_credentials: get_passkey_credential_descriptors(&passkeys),
+            timeout: consts::user::PASSKEY_CEREMONY_TIMEOUT_IN_MILLIS,
+        },
+    ))
+}
+
+pub async fn verify_passkey(
+    state: SessionState,
+    user_token: auth::UserIdFromAuth,
+    req: user_api::PasskeyAssertionRequest,
+) -> UserResponse<()> {
+    let user_passkey_attempts =
+        passkey_utils::get_passkey_attempts_from_redis(&state, &user_token.user_id).await?;
+
+    if user_passkey_attempts >= consts::user::PASSKEY_MAX_ATTEMPTS {
+        return Err(UserErrors::MaxPasskeyAttemptsReached.into());
+    }
+
+    let challenge = passkey_utils::get_and_delete_challenge_from_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_AUTHENTICATION_CHALLENGE_PREFIX,
+        &user_token.user_id,
+    )
+    .await?;
+
+    if let Err(error) =
+        verify_passkey_assertion(&state, &user_token.user_id, &challenge, &req, false).await
+    {
+        let _ = passkey_utils::insert_passkey_attempts_in_redis(
+            &state,
+            &user_token.user_id,
+            user_passkey_attempts + 1,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error));
+        return Err(error);
+    }
+
+    passkey_utils::insert_passkey_in_redis(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_passkeys(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+) -> UserResponse<Vec<user_api::PasskeyResponse>> {
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        passkeys
+            .into_iter()
+            .map(|passkey| user_api::PasskeyResponse {
+                credential_id: passkey.credential_id,
+                name: passkey.name,
+                created_at: passkey.created_at,
+                last_used_at: passkey.last_used_at,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn delete_passkey(
+    state: SessionState,
+    user_token: auth::UserFromToken,
+    credential_id: String,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_token.user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into();
+
+    let passkeys = passkey_utils::list_passkeys_for_user(&state, &user_token.user_id).await?;
+    validate_second_factor_for_passkey_change(&state, &user_from_db, &passkeys).await?;
+
+    // Admins who are mandated to use passkeys must keep at least one of them
+    if passkeys.len() == 1
+        && passkey_utils::is_passkey_required_for_user(
+            &state,
+            &user_token.user_id,
+            user_token.tenant_id.as_ref(),
+        )
+        .await?
+    {
+        return Err(UserErrors::PasskeyRequired.into());
+    }
+
+    state
+        .global_store
+        .delete_user_webauthn_credential(&user_token.user_id, &credential_id)
+        .await
+        .to_not_found_response(UserErrors::PasskeyNotSetup)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn begin_passkey_signin(
+    state: SessionState,
+    request: user_api::PasskeySignInBeginRequest,
+) -> UserResponse<user_api::PasskeyAuthenticationOptionsResponse> {
+    let user_email = domain::UserEmail::from_pii_email(request.email)?;
+
+    utils::user::validate_email_domain_auth_type_using_db(
+        &state,
+        &user_email,
+        UserAuthType::Passkey,
+    )
+    .await?;
+
+    // Passkeys are discoverable credentials, so the authenticator offers the passkeys of the
+    // site itself. The user is not looked up, so that the response does not reveal whether an
+    // account or its credentials exist.
+    let challenge = passkey_utils::generate_challenge();
+    passkey_utils::insert_challenge_in_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_SIGNIN_CHALLENGE_PREFIX,
+        user_email.peek(),
+        &challenge,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        user_api::PasskeyAuthenticationOptionsResponse {
+            challenge,
+            rp_id: passkey_utils::get_rp_id(&state),
+            allow_credentials: Vec::new(),
+            timeout: consts::user::PASSKEY_CEREMONY_TIMEOUT_IN_MILLIS,
+        },
+    ))
+}
+
+pub async fn passkey_signin_token_only_flow(
+    state: SessionState,
+    request: user_api::PasskeySignInRequest,
+    client_ip: Option<String>,
+) -> UserResponse<user_api::TokenResponse> {
+    let user_email = domain::UserEmail::from_pii_email(request.email)?;
+
+    utils::user::validate_email_domain_auth_type_using_db(
+        &state,
+        &user_email,
+        UserAuthType::Passkey,
+    )
+    .await?;
+
+    let challenge = passkey_utils::get_and_delete_challenge_from_redis(
+        &state,
+        consts::user::REDIS_PASSKEY_SIGNIN_CHALLENGE_PREFIX,
+        user_email.peek(),
+    )
+    .await?;
+
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&user_email)
+        .await
+        .to_not_found_response(UserErrors::InvalidCredentials)?
+        .into();
+
+    let user_passkey_attempts = passkey_utils::get_passkey_signin_attempts_from_redis(
+        &state,
+        user_from_db.get_user_id(),
+        client_ip.as_deref(),
+    )
+    .await?;
+
+    if user_passkey_attempts >= consts::user::PASSKEY_MAX_ATTEMPTS {
+        return Err(UserErrors::MaxPasskeyAttemptsReached.into());
+    }
+
+    if let Err(error) = verify_passkey_assertion(
+        &state,
+        user_from_db.get_user_id(),
+        &challenge,
+        &request.assertion,
+        true,
+    )
+    .await
+    {
+        let _ = passkey_utils::insert_passkey_signin_attempts_in_redis(
+            &state,
+            user_from_db.get_user_id(),
+            client_ip.as_deref(),
+            user_passkey_attempts + 1,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error));
+        return Err(error.change_context(UserErrors::InvalidCredentials));
+    }
+
+    let _ = passkey_utils::delete_passkey_signin_attempts_from_redis(
+        &state,
+        user_from_db.get_user_id(),
+        client_ip.as_deref(),
+    )
+    .await
+    .inspect_err(|error| logger::error!(?error));
+
+    // A user verified passkey satisfies the second factor only when the deployment allows it,
+    // the user is asked for TOTP like any other sign in otherwise
+    let origin = if state.conf.user.passkey_satisfies_two_factor_auth {
+        passkey_utils::insert_passkey_in_redis(&state, user_from_db.get_user_id()).await?;
+        domain::Origin::SignInWithPasskeyAsSecondFactor
+    } else {
+        domain::Origin::SignInWithPasskey
+    };
+    let next_flow = domain::NextFlow::from_origin(origin, user_from_db.clone(), &state).await?;
+
+    let token = next_flow.get_token(&state).await?;
+
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    auth::cookies::set_cookie_response(response, token)
+}
+
 pub async fn create_user_authentication_method(
     state: SessionState,
     req: user_api::CreateUserAuthenticationMethodRequest,
@@ -2552,7 +3068,9 @@ pub async fn create_user_authentication_method(
                 let req_auth_name = public_config.name;
                 db_auth_name.is_some_and(|name| name == req_auth_name)
             }
-            user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => true,
+            user_api::AuthConfig::Password
+            | user_api::AuthConfig::MagicLink
+            | user_api::AuthConfig::Passkey => true,
         };
         if is_type_same && is_extra_identifier_same {
             return Err(report!(UserErrors::UserAuthMethodAlreadyExists));
@@ -0,0 +1,928 @@
+use std::collections::HashSet;
+
+use api_models::user::scim as scim_api;
+use common_utils::{date_time, id_type};
+use diesel_models::user::{
+    self as storage_user,
+    scim::{
+        ScimGroup, ScimGroupNew, ScimGroupUpdate, ScimTokenNew, ScimUser, ScimUserListConstraints,
+        ScimUserNew, ScimUserUpdate,
+    },
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::ApplicationResponse;
+use masking::{ExposeInterface, PeekInterface, Secret};
+
+use crate::{
+    consts::user as user_consts,
+    core::{
+        api_keys,
+        errors::{StorageErrorExt, UserErrors, UserResponse, UserResult},
+    },
+    routes::SessionState,
+    services::authentication as auth,
+    types::{domain, storage},
+    utils::user::scim as scim_utils,
+};
+
+pub async fn create_scim_token(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: scim_api::CreateScimTokenRequest,
+) -> UserResponse<scim_api::CreateScimTokenResponse> {
+    let name = request.name.trim().to_owned();
+    if name.is_empty() || name.len() > user_consts::MAX_SCIM_TOKEN_NAME_LENGTH {
+        return Err(UserErrors::InvalidScimRequest(format!(
+            "Token name must be between 1 and {} characters",
+            user_consts::MAX_SCIM_TOKEN_NAME_LENGTH
+        ))
+        .into());
+    }
+
+    let hash_key = state
+        .conf
+        .api_keys
+        .get_inner()
+        .get_hash_key()
+        .change_context(UserErrors::InternalServerError)?;
+    let plaintext_token = api_keys::PlaintextApiKey::new(user_consts::SCIM_TOKEN_LENGTH);
+    let hashed_token =
+        storage::HashedApiKey::from(plaintext_token.keyed_hash(hash_key.peek())).into_inner();
+
+    let scim_token = state
+        .store
+        .insert_scim_token(ScimTokenNew {
+            token_id: common_utils::generate_id(
+                user_consts::SCIM_TOKEN_LENGTH / 2,
+                user_consts::SCIM_TOKEN_ID_PREFIX,
+            ),
+            org_id: user_from_token.org_id,
+            name,
+            prefix: plaintext_token.prefix(),
+            hashed_token,
+            created_by: user_from_token.user_id,
+            created_at: date_time::now(),
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to insert SCIM token")?;
+
+    Ok(ApplicationResponse::Json(
+        scim_api::CreateScimTokenResponse {
+            token_id: scim_token.token_id,
+            name: scim_token.name,
+            token: Secret::new(plaintext_token.peek().to_owned()),
+            created_at: scim_token.created_at,
+        },
+    ))
+}
+
+pub async fn list_scim_tokens(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<Vec<scim_api::ScimTokenResponse>> {
+    let scim_tokens = state
+        .store
+        .list_scim_tokens_by_org_id(&user_from_token.org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(
+        scim_tokens
+            .into_iter()
+            .map(|scim_token| scim_api::ScimTokenResponse {
+                token_id: scim_token.token_id,
+                name: scim_token.name,
+                prefix: scim_token.prefix,
+                created_by: scim_token.created_by,
+                created_at: scim_token.created_at,
+            })
+            .collect(),
+    ))
+}
+
+pub async fn delete_scim_token(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    token_id: String,
+) -> UserResponse<()> {
+    state
+        .store
+        .delete_scim_token(&user_from_token.org_id, &token_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn get_service_provider_config(
+    _state: SessionState,
+) -> UserResponse<scim_api::ScimServiceProviderConfigResponse> {
+    Ok(ApplicationResponse::Json(
+        scim_api::ScimServiceProviderConfigResponse {
+            schemas: vec![user_consts::SCIM_SERVICE_PROVIDER_CONFIG_SCHEMA.to_string()],
+            patch: scim_api::ScimSupported { supported: true },
+            bulk: scim_api::ScimBulkSupport {
+                supported: false,
+                max_operations: 0,
+                max_payload_size: 0,
+            },
+            filter: scim_api::ScimFilterSupport {
+                supported: true,
+                max_results: user_consts::SCIM_MAX_PAGE_SIZE,
+            },
+            change_password: scim_api::ScimSupported { supported: false },
+            sort: scim_api::ScimSupported { supported: false },
+            etag: scim_api::ScimSupported { supported: false },
+            authentication_schemes: vec![scim_api::ScimAuthenticationScheme {
+                scheme_type: "oauthbearertoken".to_string(),
+                name: "OAuth Bearer Token".to_string(),
+                description: "Authentication using an organization SCIM token".to_string(),
+                primary: true,
+            }],
+        },
+    ))
+}
+
+pub async fn list_users(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimUserListResponse> {
+    let pagination = scim_utils::ScimPagination::from(&query);
+    let filter = query
+        .filter
+        .as_deref()
+        .map(scim_utils::parse_eq_filter)
+        .transpose()?;
+
+    let constraints = match filter {
+        None => Some(ScimUserListConstraints::default()),
+        Some((attribute, value)) => match attribute.as_str() {
+            "userName" | "emails.value" => scim_utils::find_user_id_by_email(&state, value)
+                .await?
+                .map(|user_id| ScimUserListConstraints {
+                    user_id: Some(user_id),
+                    external_id: None,
+                }),
+            "externalId" => Some(ScimUserListConstraints {
+                user_id: None,
+                external_id: Some(value),
+            }),
+            _ => {
+                return Err(UserErrors::InvalidScimRequest(format!(
+                    "Filtering users by {attribute} is not supported"
+                ))
+                .into());
+            }
+        },
+    };
+
+    let (total_results, scim_users) = match constraints {
+        Some(constraints) => {
+            let (limit, offset) = pagination.get_limit_and_offset()?;
+            let total_results = state
+                .store
+                .count_scim_users_by_org_id(&org_id, constraints.clone())
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            let scim_users = state
+                .store
+                .list_scim_users_by_org_id(&org_id, constraints, limit, offset)
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            (
+                usize::try_from(total_results).change_context(UserErrors::InternalServerError)?,
+                scim_users,
+            )
+        }
+        None => (0, Vec::new()),
+    };
+
+    let users = scim_utils::get_users_by_user_ids(
+        &state,
+        scim_users
+            .iter()
+            .map(|scim_user| scim_user.user_id.clone())
+            .collect(),
+    )
+    .await?;
+    let groups = if scim_users.is_empty() {
+        Vec::new()
+    } else {
+        state
+            .store
+            .list_scim_groups_by_org_id(&org_id)
+            .await
+            .change_context(UserErrors::InternalServerError)?
+    };
+
+    let resources = scim_users
+        .iter()
+        .filter_map(|scim_user| {
+            users
+                .get(&scim_user.user_id)
+                .map(|user| scim_utils::get_user_response(user, scim_user, &groups))
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(scim_api::ScimUserListResponse {
+        schemas: vec![user_consts::SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+        total_results,
+        start_index: pagination.start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn create_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let email = scim_utils::get_email_from_request(&request)?;
+    let name = scim_utils::get_name_from_request(&request, &email)?;
+
+    let user = match state.global_store.find_user_by_email(&email).await {
+        Ok(user) => domain::UserFromStorage::from(user),
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = date_time::now();
+            state
+                .global_store
+                .insert_user(storage_user::UserNew {
+                    user_id: uuid::Uuid::new_v4().to_string(),
+                    email: email.into_inner(),
+                    name: name.get_secret(),
+                    password: None,
+                    is_verified: false,
+                    created_at: Some(now),
+                    last_modified_at: Some(now),
+                    ..Default::default()
+                })
+                .await
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Failed to insert SCIM provisioned user")?
+                .into()
+        }
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
+
+    let now = date_time::now();
+    let scim_user = state
+        .store
+        .insert_scim_user(ScimUserNew {
+            org_id,
+            user_id: user.get_user_id().to_owned(),
+            external_id: request.external_id,
+            active: request.active.unwrap_or(true),
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(UserErrors::ScimResourceConflict)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    Ok(ApplicationResponse::Json(scim_utils::get_user_response(
+        &user,
+        &scim_user,
+        &[],
+    )))
+}
+
+pub async fn get_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let scim_user = state
+        .store
+        .find_scim_user(&org_id, &user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+    let user: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?
+        .into();
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(scim_utils::get_user_response(
+        &user, &scim_user, &groups,
+    )))
+}
+
+#[derive(Default)]
+struct ScimUserChanges {
+    name: Option<Secret<String>>,
+    external_id: Option<String>,
+    active: Option<bool>,
+}
+
+pub async fn replace_user(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    user_id: String,
+    request: scim_api::ScimUserRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let scim_user = state
+        .store
+        .find_scim_user(&scim_client.org_id, &user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    let user: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_id(&user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?
+        .into();
+
+    let email = scim_utils::get_email_from_request(&request)?;
+    if !scim_utils::matches_email(&user.get_email(), email.peek()) {
+        return Err(UserErrors::InvalidScimRequest(
+            "Email of a user cannot be changed".to_string(),
+        )
+        .into());
+    }
+
+    let changes = ScimUserChanges {
+        name: Some(scim_utils::get_name_from_request(&request, &email)?.get_secret()),
+        external_id: request.external_id,
+        active: request.active,
+    };
+
+    update_user(state, scim_client, scim_user, changes).await
+}
+
+pub async fn patch_user(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    user_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let scim_user = state
+        .store
+        .find_scim_user(&scim_client.org_id, &user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    let mut changes = ScimUserChanges::default();
+
+    for operation in request.operations {
+        if operation.op == scim_api::ScimPatchOperationType::Remove {
+            return Err(UserErrors::InvalidScimRequest(
+                "Remove operation is not supported for users".to_string(),
+            )
+            .into());
+        }
+        let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+            "Patch operation is missing value".to_string(),
+        ))?;
+
+        let attributes = match operation.path {
+            Some(path) => vec![(path, value)],
+            None => value
+                .as_object()
+                .ok_or(UserErrors::InvalidScimRequest(
+                    "Patch operation without path must have an object value".to_string(),
+                ))?
+                .clone()
+                .into_iter()
+                .collect(),
+        };
+
+        for (attribute, value) in attributes {
+            match attribute.as_str() {
+                "active" => changes.active = Some(scim_utils::parse_bool_value(&value)?),
+                "externalId" => changes.external_id = Some(scim_utils::parse_string_value(&value)?),
+                "displayName" | "name.formatted" => {
+                    changes.name = Some(Secret::new(scim_utils::parse_string_value(&value)?))
+                }
+                _ => {
+                    return Err(UserErrors::InvalidScimRequest(format!(
+                        "Patching {attribute} is not supported"
+                    ))
+                    .into());
+                }
+            }
+        }
+    }
+
+    update_user(state, scim_client, scim_user, changes).await
+}
+
+async fn update_user(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    scim_user: ScimUser,
+    changes: ScimUserChanges,
+) -> UserResponse<scim_api::ScimUserResponse> {
+    let org_id = scim_client.org_id;
+    let user_id = scim_user.user_id;
+
+    let user: domain::UserFromStorage = match changes.name {
+        Some(name) => state
+            .global_store
+            .update_user_by_user_id(
+                &user_id,
+                storage_user::UserUpdate::AccountUpdate {
+                    name: Some(domain::UserName::new(name)?.get_secret().expose()),
+                    is_verified: None,
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?,
+        None => state
+            .global_store
+            .find_user_by_id(&user_id)
+            .await
+            .to_not_found_response(UserErrors::ScimResourceNotFound)?,
+    }
+    .into();
+
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    match (scim_user.active, changes.active) {
+        (true, Some(false)) => {
+            scim_utils::deactivate_user_in_org(&state, &org_id, &user_id).await?
+        }
+        (false, Some(true)) => {
+            scim_utils::reactivate_user_in_org(&state, &groups, &user_id, &scim_client.token_id)
+                .await?
+        }
+        _ => {}
+    }
+
+    let scim_user = state
+        .store
+        .update_scim_user(
+            &org_id,
+            &user_id,
+            ScimUserUpdate::DetailsUpdate {
+                external_id: changes.external_id,
+                active: changes.active,
+            },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::Json(scim_utils::get_user_response(
+        &user, &scim_user, &groups,
+    )))
+}
+
+pub async fn delete_user(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    user_id: String,
+) -> UserResponse<()> {
+    state
+        .store
+        .find_scim_user(&org_id, &user_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    scim_utils::deactivate_user_in_org(&state, &org_id, &user_id).await?;
+
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+    for group in groups
+        .into_iter()
+        .filter(|group| group.members.contains(&user_id))
+    {
+        state
+            .store
+            .update_scim_group(
+                &org_id,
+                &group.group_id,
+                ScimGroupUpdate::MembersUpdate {
+                    members: group
+                        .members
+                        .into_iter()
+                        .filter(|member| member != &user_id)
+                        .collect(),
+                },
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)?;
+    }
+
+    state
+        .store
+        .delete_scim_user(&org_id, &user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn list_groups(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    query: scim_api::ScimListQuery,
+) -> UserResponse<scim_api::ScimGroupListResponse> {
+    let pagination = scim_utils::ScimPagination::from(&query);
+    let filter = query
+        .filter
+        .as_deref()
+        .map(scim_utils::parse_eq_filter)
+        .transpose()?;
+
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let groups = match filter {
+        None => groups,
+        Some((attribute, value)) => match attribute.as_str() {
+            "displayName" => groups
+                .into_iter()
+                .filter(|group| group.display_name == value)
+                .collect(),
+            "externalId" => groups
+                .into_iter()
+                .filter(|group| group.external_id.as_deref() == Some(value.as_str()))
+                .collect(),
+            _ => {
+                return Err(UserErrors::InvalidScimRequest(format!(
+                    "Filtering groups by {attribute} is not supported"
+                ))
+                .into());
+            }
+        },
+    };
+
+    let total_results = groups.len();
+    let groups = pagination.paginate(groups);
+    let users = scim_utils::get_users_by_user_ids(
+        &state,
+        groups
+            .iter()
+            .flat_map(|group| group.members.iter().cloned())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect(),
+    )
+    .await?;
+
+    let resources = groups
+        .into_iter()
+        .map(|group| scim_utils::get_group_response(group, &users))
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(scim_api::ScimGroupListResponse {
+        schemas: vec![user_consts::SCIM_LIST_RESPONSE_SCHEMA.to_string()],
+        total_results,
+        start_index: pagination.start_index,
+        items_per_page: resources.len(),
+        resources,
+    }))
+}
+
+pub async fn create_group(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    request: scim_api::ScimGroupRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let org_id = scim_client.org_id;
+    let role_mapping = request
+        .role_mapping
+        .ok_or(UserErrors::InvalidScimRequest(format!(
+            "Role mapping is required under {}",
+            user_consts::SCIM_GROUP_ROLE_MAPPING_SCHEMA
+        )))?;
+    scim_utils::validate_group_role_mapping(&state, &org_id, &role_mapping).await?;
+
+    let now = date_time::now();
+    let group = state
+        .store
+        .insert_scim_group(ScimGroupNew {
+            group_id: common_utils::generate_id_with_default_len("scim_grp"),
+            org_id: org_id.clone(),
+            display_name: request.display_name,
+            external_id: request.external_id,
+            role_id: role_mapping.role_id,
+            entity_type: role_mapping.entity_type,
+            merchant_id: role_mapping.merchant_id,
+            profile_id: role_mapping.profile_id,
+            members: Vec::new(),
+            created_at: now,
+            last_modified_at: now,
+        })
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(UserErrors::ScimResourceConflict)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })?;
+
+    let members = get_member_ids(request.members);
+    let group = update_group_members(&state, group, members, &scim_client.token_id).await?;
+
+    get_group_response(&state, group).await
+}
+
+pub async fn get_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let group = state
+        .store
+        .find_scim_group(&org_id, &group_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    get_group_response(&state, group).await
+}
+
+pub async fn replace_group(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    group_id: String,
+    request: scim_api::ScimGroupRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let group = state
+        .store
+        .find_scim_group(&scim_client.org_id, &group_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    if let Some(role_mapping) = request.role_mapping.as_ref() {
+        if role_mapping.role_id != group.role_id
+            || role_mapping.entity_type != group.entity_type
+            || role_mapping.merchant_id != group.merchant_id
+            || role_mapping.profile_id != group.profile_id
+        {
+            return Err(UserErrors::InvalidScimRequest(
+                "Role mapping of a group cannot be changed".to_string(),
+            )
+            .into());
+        }
+    }
+
+    let group = update_group_details(
+        &state,
+        group,
+        Some(request.display_name),
+        request.external_id,
+    )
+    .await?;
+    let members = get_member_ids(request.members);
+    let group = update_group_members(&state, group, members, &scim_client.token_id).await?;
+
+    get_group_response(&state, group).await
+}
+
+pub async fn patch_group(
+    state: SessionState,
+    scim_client: auth::ScimClient,
+    group_id: String,
+    request: scim_api::ScimPatchRequest,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let group = state
+        .store
+        .find_scim_group(&scim_client.org_id, &group_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+
+    let mut members = group.members.clone();
+    let mut display_name = None;
+    let mut external_id = None;
+
+    for operation in request.operations {
+        let path = operation.path.as_deref().map(str::trim);
+        match (operation.op, path) {
+            (scim_api::ScimPatchOperationType::Remove, Some(path)) => {
+                match scim_utils::parse_member_path_filter(path)? {
+                    Some(member_id) => members.retain(|member| member != &member_id),
+                    None if path == "members" => match operation.value.as_ref() {
+                        Some(value) => {
+                            let removed = scim_utils::parse_member_ids(value)?;
+                            members.retain(|member| !removed.contains(member));
+                        }
+                        None => members.clear(),
+                    },
+                    None => {
+                        return Err(UserErrors::InvalidScimRequest(format!(
+                            "Removing {path} is not supported"
+                        ))
+                        .into());
+                    }
+                }
+            }
+            (scim_api::ScimPatchOperationType::Remove, None) => {
+                return Err(UserErrors::InvalidScimRequest(
+                    "Remove operation requires a path".to_string(),
+                )
+                .into());
+            }
+            (op, path) => {
+                let value = operation.value.ok_or(UserErrors::InvalidScimRequest(
+                    "Patch operation is missing value".to_string(),
+                ))?;
+                let attributes = match path {
+                    Some(path) => vec![(path.to_owned(), value)],
+                    None => value
+                        .as_object()
+                        .ok_or(UserErrors::InvalidScimRequest(
+                            "Patch operation without path must have an object value".to_string(),
+                        ))?
+                        .clone()
+                        .into_iter()
+                        .collect(),
+                };
+
+                for (attribute, value) in attributes {
+                    match attribute.as_str() {
+                        "members" => {
+                            let member_ids = scim_utils::parse_member_ids(&value)?;
+                            if op == scim_api::ScimPatchOperationType::Replace {
+                                members = member_ids;
+                            } else {
+                                members.extend(member_ids);
+                            }
+                        }
+                        "displayName" => {
+                            display_name = Some(scim_utils::parse_string_value(&value)?)
+                        }
+                        "externalId" => external_id = Some(scim_utils::parse_string_value(&value)?),
+                        _ => {
+                            return Err(UserErrors::InvalidScimRequest(format!(
+                                "Patching {attribute} is not supported"
+                            ))
+                            .into());
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let group = update_group_details(&state, group, display_name, external_id).await?;
+    let group = update_group_members(&state, group, members, &scim_client.token_id).await?;
+
+    get_group_response(&state, group).await
+}
+
+pub async fn delete_group(
+    state: SessionState,
+    org_id: id_type::OrganizationId,
+    group_id: String,
+) -> UserResponse<()> {
+    let group = state
+        .store
+        .find_scim_group(&org_id, &group_id)
+        .await
+        .to_not_found_response(UserErrors::ScimResourceNotFound)?;
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    for member in group.members.iter() {
+        scim_utils::revoke_group_role(&state, &group, member, &groups).await?;
+    }
+
+    state
+        .store
+        .delete_scim_group(&org_id, &group_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+fn get_member_ids(members: Option<Vec<scim_api::ScimGroupMember>>) -> Vec<String> {
+    members
+        .unwrap_or_default()
+        .into_iter()
+        .map(|member| member.value)
+        .collect()
+}
+
+async fn get_group_response(
+    state: &SessionState,
+    group: ScimGroup,
+) -> UserResponse<scim_api::ScimGroupResponse> {
+    let users = scim_utils::get_users_by_user_ids(state, group.members.clone()).await?;
+
+    Ok(ApplicationResponse::Json(scim_utils::get_group_response(
+        group, &users,
+    )))
+}
+
+async fn update_group_details(
+    state: &SessionState,
+    group: ScimGroup,
+    display_name: Option<String>,
+    external_id: Option<String>,
+) -> UserResult<ScimGroup> {
+    if display_name.is_none() && external_id.is_none() {
+        return Ok(group);
+    }
+
+    state
+        .store
+        .update_scim_group(
+            &group.org_id,
+            &group.group_id,
+            ScimGroupUpdate::DetailsUpdate {
+                display_name,
+                external_id,
+            },
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_unique_violation() {
+                error.change_context(UserErrors::ScimResourceConflict)
+            } else {
+                error.change_context(UserErrors::InternalServerError)
+            }
+        })
+}
+
+/// Sets the members of the group, granting the mapped role to added members and revoking it
+/// from removed ones
+async fn update_group_members(
+    state: &SessionState,
+    group: ScimGroup,
+    members: Vec<String>,
+    token_id: &str,
+) -> UserResult<ScimGroup> {
+    let mut seen = HashSet::new();
+    let members = members
+        .into_iter()
+        .filter(|member| seen.insert(member.clone()))
+        .collect::<Vec<_>>();
+
+    let added = members
+        .iter()
+        .filter(|member| !group.members.contains(member))
+        .collect::<Vec<_>>();
+    let removed = group
+        .members
+        .iter()
+        .filter(|member| !members.contains(member))
+        .collect::<Vec<_>>();
+
+    if added.is_empty() && removed.is_empty() {
+        return Ok(group);
+    }
+
+    let groups = state
+        .store
+        .list_scim_groups_by_org_id(&group.org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    for member in added {
+        let scim_user = state
+            .store
+            .find_scim_user(&group.org_id, member)
+            .await
+            .to_not_found_response(UserErrors::InvalidScimRequest(format!(
+                "User {member} is not provisioned in the organization"
+            )))?;
+        if scim_user.active {
+            scim_utils::assign_group_role(state, &group, member, token_id).await?;
+        }
+    }
+
+    for member in removed {
+        scim_utils::revoke_group_role(state, &group, member, &groups).await?;
+    }
+
+    state
+        .store
+        .update_scim_group(
+            &group.org_id,
+            &group.group_id,
+            ScimGroupUpdate::MembersUpdate { members },
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to update SCIM group members")
+}
@@ -712,6 +712,13 @@ pub async fn delete_user_role(
             .await
             .change_context(UserErrors::InternalServerError)
             .attach_printable("Error while deleting user entry")?;
+
+        state
+            .global_store
+            .delete_user_webauthn_credentials_by_user_id(user_from_db.get_user_id())
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Error while deleting user passkeys")?;
     }
 
     auth::blacklist::insert_user_in_blacklist(&state, user_from_db.get_user_id()).await?;
@@ -2,6 +2,7 @@ use std::{cmp, collections::HashSet};
 
 use api_models::user_role::role as role_api;
 use common_enums::{EntityType, ParentGroup, PermissionGroup};
+use common_types::role::ResourcePermissions;
 use common_utils::generate_id_with_default_len;
 use diesel_models::role::{ListRolesByEntityPayload, RoleNew, RoleUpdate};
 use error_stack::{report, ResultExt};
@@ -45,12 +46,7 @@ pub async fn get_groups_and_resources_for_role_from_token(
         .get_permission_groups()
         .into_iter()
         .collect::<Vec<_>>();
-    let resources = groups
-        .iter()
-        .flat_map(|group| group.resources())
-        .collect::<HashSet<_>>()
-        .into_iter()
-        .collect();
+    let resources = role_info.get_resources_set().into_iter().collect();
 
     Ok(ApplicationResponse::Json(role_api::GroupsAndResources {
         groups,
@@ -97,7 +93,16 @@ pub async fn create_role(
 
     let role_name = RoleName::new(req.role_name)?;
 
+    let resource_permissions = req.resource_permissions.unwrap_or_default();
     utils::user_role::validate_role_groups(&req.groups)?;
+    utils::user_role::validate_role_has_permissions(&req.groups, &resource_permissions)?;
+    utils::user_role::validate_resource_permissions(
+        &state,
+        &resource_permissions,
+        role_entity_type,
+        &user_from_token.merchant_id,
+    )
+    .await?;
     utils::user_role::validate_role_name(
         &state,
         &role_name,
@@ -142,6 +147,8 @@ pub async fn create_role(
             last_modified_at: now,
             profile_id,
             tenant_id: user_from_token.tenant_id.unwrap_or(state.tenant.tenant_id),
+            resource_permissions: (!resource_permissions.is_empty())
+                .then_some(ResourcePermissions(resource_permissions)),
         })
         .await
         .to_duplicate_response(UserErrors::RoleNameAlreadyExists)?;
@@ -153,6 +160,10 @@ pub async fn create_role(
             role_name: role.role_name,
             role_scope: role.scope,
             entity_type: role.entity_type,
+            resource_permissions: role
+                .resource_permissions
+                .map(|resource_permissions| resource_permissions.0)
+                .unwrap_or_default(),
         },
     ))
 }
@@ -185,10 +196,64 @@ pub async fn get_role_with_groups(
             role_name: role_info.get_role_name().to_string(),
             role_scope: role_info.get_scope(),
             entity_type: role_info.get_entity_type(),
+            resource_permissions: role_info.get_resource_permissions().to_vec(),
         },
     ))
 }
 
+pub async fn export_role_template(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    role: role_api::GetRoleRequest,
+) -> UserResponse<role_api::RoleTemplate> {
+    let role_info = roles::RoleInfo::from_role_id_org_id_tenant_id(
+        &state,
+        &role.role_id,
+        &user_from_token.org_id,
+        user_from_token
+            .tenant_id
+            .as_ref()
+            .unwrap_or(&state.tenant.tenant_id),
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if role_info.is_internal() {
+        return Err(UserErrors::InvalidRoleId.into());
+    }
+
+    Ok(ApplicationResponse::Json(role_api::RoleTemplate {
+        role_name: role_info.get_role_name().to_string(),
+        role_scope: role_info.get_scope(),
+        entity_type: role_info.get_entity_type(),
+        groups: role_info.get_permission_groups().to_vec(),
+        resource_permissions: role_info.get_resource_permissions().to_vec(),
+    }))
+}
+
+pub async fn import_role_template(
+    state: SessionState,
+    user_from_token: UserFromToken,
+    req: role_api::ImportRoleTemplateRequest,
+    req_state: ReqState,
+) -> UserResponse<role_api::RoleInfoWithGroupsResponse> {
+    let template = req.template;
+
+    Box::pin(create_role(
+        state,
+        user_from_token,
+        role_api::CreateRoleRequest {
+            role_name: req.role_name.unwrap_or(template.role_name),
+            groups: template.groups,
+            role_scope: template.role_scope,
+            entity_type: Some(template.entity_type),
+            resource_permissions: Some(template.resource_permissions),
+        },
+        req_state,
+    ))
+    .await
+}
+
 pub async fn get_parent_info_for_role(
     state: SessionState,
     user_from_token: UserFromToken,
@@ -301,6 +366,25 @@ pub async fn update_role(
         utils::user_role::validate_role_groups(groups)?;
     }
 
+    if let Some(ref resource_permissions) = req.resource_permissions {
+        utils::user_role::validate_resource_permissions(
+            &state,
+            resource_permissions,
+            role_info.get_entity_type(),
+            &user_from_token.merchant_id,
+        )
+        .await?;
+    }
+
+    utils::user_role::validate_role_has_permissions(
+        req.groups
+            .as_deref()
+            .unwrap_or(role_info.get_permission_groups().as_slice()),
+        req.resource_permissions
+            .as_deref()
+            .unwrap_or(role_info.get_resource_permissions()),
+    )?;
+
     let updated_role = state
         .global_store
         .update_role_by_role_id(
@@ -308,6 +392,7 @@ pub async fn update_role(
             RoleUpdate::UpdateDetails {
                 groups: req.groups,
                 role_name: role_name.map(RoleName::get_role_name),
+                resource_permissions: req.resource_permissions.map(ResourcePermissions),
                 last_modified_at: common_utils::date_time::now(),
                 last_modified_by: user_from_token.user_id,
             },
@@ -324,6 +409,10 @@ pub async fn update_role(
             role_name: updated_role.role_name,
             role_scope: updated_role.scope,
             entity_type: updated_role.entity_type,
+            resource_permissions: updated_role
+                .resource_permissions
+                .map(|resource_permissions| resource_permissions.0)
+                .unwrap_or_default(),
         },
     ))
 }
@@ -414,6 +503,7 @@ pub async fn list_roles_with_info(
                 groups: role_info.get_permission_groups().to_vec(),
                 entity_type: role_info.get_entity_type(),
                 scope: role_info.get_scope(),
+                resource_permissions: role_info.get_resource_permissions().to_vec(),
             })
         })
         .collect::<Vec<_>>();
@@ -662,6 +662,12 @@ impl ForeignFrom<storage::EventMetadata> for outgoing_webhook_logs::OutgoingWebh
                 mandate_id,
                 content: serde_json::Value::Null,
             },
+            diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+                Self::PaymentMethod {
+                    payment_method_id,
+                    content: serde_json::Value::Null,
+                }
+            }
         }
     }
 }
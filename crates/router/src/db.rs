@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_alert_rule;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -8,7 +9,10 @@ pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
+pub mod change_request;
 pub mod configs;
+pub mod connector_fee_schedule;
+pub mod customer_merge_redirect;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -39,6 +43,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod vault_migration_entry;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -97,7 +102,12 @@ pub trait StorageInterface:
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
+    + connector_fee_schedule::ConnectorFeeScheduleInterface
+    + vault_migration_entry::VaultMigrationEntryInterface
     + capture::CaptureInterface
+    + change_request::ChangeRequestInterface
+    + analytics_alert_rule::AnalyticsAlertRuleInterface
+    + customer_merge_redirect::CustomerMergeRedirectInterface
     + customers::CustomerInterface<Error = StorageError>
     + dashboard_metadata::DashboardMetadataInterface
     + dispute::DisputeInterface
@@ -140,6 +150,7 @@ pub trait StorageInterface:
     + generic_link::GenericLinkInterface
     + relay::RelayInterface
     + user::theme::ThemeInterface
+    + user::scim::ScimInterface
     + payment_method_session::PaymentMethodsSessionInterface
     + tokenization::TokenizationInterface
     + 'static
@@ -155,6 +166,7 @@ pub trait GlobalStorageInterface:
     + Sync
     + dyn_clone::DynClone
     + user::UserInterface
+    + user::webauthn_credential::UserWebauthnCredentialInterface
     + user_role::UserRoleInterface
     + user_key_store::UserKeyStoreInterface
     + role::RoleInterface
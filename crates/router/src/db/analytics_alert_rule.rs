@@ -0,0 +1,294 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AnalyticsAlertRuleInterface {
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError>;
+
+    async fn update_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError>;
+
+    async fn delete_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertRuleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        alert_rule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::find_by_merchant_id_alert_rule_id(
+            &conn,
+            merchant_id,
+            alert_rule_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AnalyticsAlertRule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertRule::update_by_merchant_id_alert_rule_id(
+            &conn,
+            merchant_id,
+            alert_rule_id,
+            alert_rule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AnalyticsAlertRule::delete_by_merchant_id_alert_rule_id(
+            &conn,
+            merchant_id,
+            alert_rule_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertRuleInterface for MockDb {
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let mut alert_rules = self.analytics_alert_rules.lock().await;
+        if alert_rules
+            .iter()
+            .any(|existing| existing.alert_rule_id == alert_rule.alert_rule_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "alert_rule_id",
+                key: Some(alert_rule.alert_rule_id.clone()),
+            })?
+        }
+
+        let alert_rule = storage::AnalyticsAlertRule {
+            alert_rule_id: alert_rule.alert_rule_id,
+            org_id: alert_rule.org_id,
+            merchant_id: alert_rule.merchant_id,
+            profile_id: alert_rule.profile_id,
+            name: alert_rule.name,
+            description: alert_rule.description,
+            metric: alert_rule.metric,
+            alert_condition: alert_rule.alert_condition,
+            evaluation_window_in_mins: alert_rule.evaluation_window_in_mins,
+            evaluation_interval_in_mins: alert_rule.evaluation_interval_in_mins,
+            notification_channels: alert_rule.notification_channels,
+            enabled: alert_rule.enabled,
+            state: alert_rule.state,
+            last_observed_value: None,
+            last_evaluated_at: None,
+            last_state_changed_at: None,
+            created_by: alert_rule.created_by,
+            created_at: alert_rule.created_at,
+            last_modified_at: alert_rule.last_modified_at,
+        };
+        alert_rules.push(alert_rule.clone());
+        Ok(alert_rule)
+    }
+
+    async fn find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let alert_rules = self.analytics_alert_rules.lock().await;
+        alert_rules
+            .iter()
+            .find(|alert_rule| {
+                alert_rule.merchant_id == *merchant_id && alert_rule.alert_rule_id == alert_rule_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No alert rule found for alert_rule_id = {alert_rule_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        let alert_rules = self.analytics_alert_rules.lock().await;
+        let mut filtered_alert_rules = alert_rules
+            .iter()
+            .filter(|alert_rule| alert_rule.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        filtered_alert_rules.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(filtered_alert_rules)
+    }
+
+    async fn update_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        let mut alert_rules = self.analytics_alert_rules.lock().await;
+        let alert_rule = alert_rules
+            .iter_mut()
+            .find(|alert_rule| {
+                alert_rule.merchant_id == *merchant_id && alert_rule.alert_rule_id == alert_rule_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No alert rule found for alert_rule_id = {alert_rule_id}"
+            )))?;
+
+        *alert_rule = storage::AnalyticsAlertRuleUpdateInternal::from(alert_rule_update)
+            .apply_changeset(alert_rule.clone());
+
+        Ok(alert_rule.clone())
+    }
+
+    async fn delete_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut alert_rules = self.analytics_alert_rules.lock().await;
+        let initial_len = alert_rules.len();
+        alert_rules.retain(|alert_rule| {
+            !(alert_rule.merchant_id == *merchant_id && alert_rule.alert_rule_id == alert_rule_id)
+        });
+        if alert_rules.len() == initial_len {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No alert rule found for alert_rule_id = {alert_rule_id}"
+            )))?
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl AnalyticsAlertRuleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_analytics_alert_rule(
+        &self,
+        alert_rule: storage::AnalyticsAlertRuleNew,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .insert_analytics_alert_rule(alert_rule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .find_analytics_alert_rule_by_merchant_id_alert_rule_id(merchant_id, alert_rule_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_analytics_alert_rules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::AnalyticsAlertRule>, errors::StorageError> {
+        self.diesel_store
+            .list_analytics_alert_rules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+        alert_rule_update: storage::AnalyticsAlertRuleUpdate,
+    ) -> CustomResult<storage::AnalyticsAlertRule, errors::StorageError> {
+        self.diesel_store
+            .update_analytics_alert_rule(merchant_id, alert_rule_id, alert_rule_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_analytics_alert_rule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        alert_rule_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_analytics_alert_rule(merchant_id, alert_rule_id)
+            .await
+    }
+}
@@ -0,0 +1,355 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ChangeRequestInterface {
+    async fn insert_change_request(
+        &self,
+        change_request: storage::ChangeRequestNew,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError>;
+
+    async fn find_change_request_by_merchant_id_change_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError>;
+
+    async fn list_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<common_enums::ChangeRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ChangeRequest>, errors::StorageError>;
+
+    async fn update_change_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+        status: common_enums::ChangeRequestStatus,
+        change_request_update: storage::ChangeRequestUpdate,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError>;
+
+    async fn expire_pending_change_requests(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ChangeRequestInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_change_request(
+        &self,
+        change_request: storage::ChangeRequestNew,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        change_request
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_change_request_by_merchant_id_change_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ChangeRequest::find_by_merchant_id_change_request_id(
+            &conn,
+            merchant_id,
+            change_request_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<common_enums::ChangeRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ChangeRequest>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ChangeRequest::list_by_merchant_id(&conn, merchant_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_change_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+        status: common_enums::ChangeRequestStatus,
+        change_request_update: storage::ChangeRequestUpdate,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ChangeRequest::update_by_merchant_id_change_request_id_status(
+            &conn,
+            merchant_id,
+            change_request_id,
+            status,
+            change_request_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn expire_pending_change_requests(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ChangeRequest::expire_pending_by_merchant_id(&conn, merchant_id, expired_before)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ChangeRequestInterface for MockDb {
+    async fn insert_change_request(
+        &self,
+        change_request: storage::ChangeRequestNew,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let mut change_requests = self.change_requests.lock().await;
+        if change_requests
+            .iter()
+            .any(|existing| existing.change_request_id == change_request.change_request_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "change_request_id",
+                key: Some(change_request.change_request_id.clone()),
+            })?
+        }
+
+        let change_request = storage::ChangeRequest {
+            change_request_id: change_request.change_request_id,
+            org_id: change_request.org_id,
+            merchant_id: change_request.merchant_id,
+            profile_id: change_request.profile_id,
+            entity: change_request.entity,
+            request_payload: change_request.request_payload,
+            description: change_request.description,
+            status: change_request.status,
+            requested_by: change_request.requested_by,
+            decided_by: None,
+            decision_reason: None,
+            error_message: None,
+            created_at: change_request.created_at,
+            expires_at: change_request.expires_at,
+            decided_at: None,
+            last_modified_at: change_request.last_modified_at,
+        };
+        change_requests.push(change_request.clone());
+        Ok(change_request)
+    }
+
+    async fn find_change_request_by_merchant_id_change_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let change_requests = self.change_requests.lock().await;
+        change_requests
+            .iter()
+            .find(|change_request| {
+                change_request.merchant_id == *merchant_id
+                    && change_request.change_request_id == change_request_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No change request found for change_request_id = {change_request_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<common_enums::ChangeRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ChangeRequest>, errors::StorageError> {
+        let change_requests = self.change_requests.lock().await;
+        let mut filtered_change_requests = change_requests
+            .iter()
+            .filter(|change_request| {
+                change_request.merchant_id == *merchant_id
+                    && status.map_or(true, |status| change_request.status == status)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        filtered_change_requests.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or_default();
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(filtered_change_requests
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+
+    async fn update_change_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+        status: common_enums::ChangeRequestStatus,
+        change_request_update: storage::ChangeRequestUpdate,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        let mut change_requests = self.change_requests.lock().await;
+        let change_request = change_requests
+            .iter_mut()
+            .find(|change_request| {
+                change_request.merchant_id == *merchant_id
+                    && change_request.change_request_id == change_request_id
+                    && change_request.status == status
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No {status} change request found for change_request_id = {change_request_id}"
+            )))?;
+
+        match change_request_update {
+            storage::ChangeRequestUpdate::Decision {
+                status,
+                decided_by,
+                decision_reason,
+                error_message,
+            } => {
+                let now = common_utils::date_time::now();
+                change_request.status = status;
+                change_request.decided_by = Some(decided_by);
+                change_request.decision_reason = decision_reason;
+                change_request.error_message = error_message;
+                change_request.decided_at = Some(now);
+                change_request.last_modified_at = now;
+            }
+            storage::ChangeRequestUpdate::ApplyOutcome {
+                status,
+                error_message,
+            } => {
+                change_request.status = status;
+                change_request.error_message = error_message;
+                change_request.last_modified_at = common_utils::date_time::now();
+            }
+            storage::ChangeRequestUpdate::Expire => {
+                change_request.status = common_enums::ChangeRequestStatus::Expired;
+                change_request.last_modified_at = common_utils::date_time::now();
+            }
+        }
+
+        Ok(change_request.clone())
+    }
+
+    async fn expire_pending_change_requests(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut change_requests = self.change_requests.lock().await;
+        let now = common_utils::date_time::now();
+        let mut expired_count = 0;
+        for change_request in change_requests.iter_mut().filter(|change_request| {
+            change_request.merchant_id == *merchant_id
+                && change_request.status == common_enums::ChangeRequestStatus::Pending
+                && change_request.expires_at <= expired_before
+        }) {
+            change_request.status = common_enums::ChangeRequestStatus::Expired;
+            change_request.last_modified_at = now;
+            expired_count += 1;
+        }
+        Ok(expired_count)
+    }
+}
+
+#[async_trait::async_trait]
+impl ChangeRequestInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_change_request(
+        &self,
+        change_request: storage::ChangeRequestNew,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_change_request(change_request)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_change_request_by_merchant_id_change_request_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        self.diesel_store
+            .find_change_request_by_merchant_id_change_request_id(merchant_id, change_request_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_change_requests_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<common_enums::ChangeRequestStatus>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::ChangeRequest>, errors::StorageError> {
+        self.diesel_store
+            .list_change_requests_by_merchant_id(merchant_id, status, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_change_request_with_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        change_request_id: &str,
+        status: common_enums::ChangeRequestStatus,
+        change_request_update: storage::ChangeRequestUpdate,
+    ) -> CustomResult<storage::ChangeRequest, errors::StorageError> {
+        self.diesel_store
+            .update_change_request_with_status(
+                merchant_id,
+                change_request_id,
+                status,
+                change_request_update,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn expire_pending_change_requests(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        expired_before: time::PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .expire_pending_change_requests(merchant_id, expired_before)
+            .await
+    }
+}
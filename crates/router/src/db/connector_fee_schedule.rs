@@ -0,0 +1,296 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ConnectorFeeScheduleInterface {
+    async fn insert_connector_fee_schedule(
+        &self,
+        fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn list_connector_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError>;
+
+    async fn update_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError>;
+
+    async fn delete_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_connector_fee_schedule(
+        &self,
+        fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fee_schedule
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorFeeSchedule::find_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ConnectorFeeSchedule::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorFeeSchedule::update_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+            fee_schedule_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ConnectorFeeSchedule::delete_by_merchant_id_merchant_connector_id(
+            &conn,
+            merchant_id,
+            merchant_connector_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for MockDb {
+    async fn insert_connector_fee_schedule(
+        &self,
+        fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let mut fee_schedules = self.connector_fee_schedules.lock().await;
+        if fee_schedules
+            .iter()
+            .any(|existing| existing.merchant_connector_id == fee_schedule.merchant_connector_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "merchant_connector_id",
+                key: Some(
+                    fee_schedule
+                        .merchant_connector_id
+                        .get_string_repr()
+                        .to_string(),
+                ),
+            })?
+        }
+
+        let fee_schedule = storage::ConnectorFeeSchedule {
+            merchant_connector_id: fee_schedule.merchant_connector_id,
+            merchant_id: fee_schedule.merchant_id,
+            profile_id: fee_schedule.profile_id,
+            connector_name: fee_schedule.connector_name,
+            fee_rules: fee_schedule.fee_rules,
+            created_at: fee_schedule.created_at,
+            modified_at: fee_schedule.modified_at,
+        };
+        fee_schedules.push(fee_schedule.clone());
+        Ok(fee_schedule)
+    }
+
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let fee_schedules = self.connector_fee_schedules.lock().await;
+        fee_schedules
+            .iter()
+            .find(|fee_schedule| {
+                fee_schedule.merchant_id == *merchant_id
+                    && fee_schedule.merchant_connector_id == *merchant_connector_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No fee schedule found for merchant_connector_id = {}",
+                    merchant_connector_id.get_string_repr()
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_connector_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        let fee_schedules = self.connector_fee_schedules.lock().await;
+        let mut filtered_fee_schedules = fee_schedules
+            .iter()
+            .filter(|fee_schedule| fee_schedule.merchant_id == *merchant_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        filtered_fee_schedules.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(filtered_fee_schedules)
+    }
+
+    async fn update_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        let mut fee_schedules = self.connector_fee_schedules.lock().await;
+        let fee_schedule = fee_schedules
+            .iter_mut()
+            .find(|fee_schedule| {
+                fee_schedule.merchant_id == *merchant_id
+                    && fee_schedule.merchant_connector_id == *merchant_connector_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No fee schedule found for merchant_connector_id = {}",
+                merchant_connector_id.get_string_repr()
+            )))?;
+
+        *fee_schedule = storage::ConnectorFeeScheduleUpdateInternal::from(fee_schedule_update)
+            .apply_changeset(fee_schedule.clone());
+
+        Ok(fee_schedule.clone())
+    }
+
+    async fn delete_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut fee_schedules = self.connector_fee_schedules.lock().await;
+        let initial_len = fee_schedules.len();
+        fee_schedules.retain(|fee_schedule| {
+            !(fee_schedule.merchant_id == *merchant_id
+                && fee_schedule.merchant_connector_id == *merchant_connector_id)
+        });
+        if fee_schedules.len() == initial_len {
+            Err(errors::StorageError::ValueNotFound(format!(
+                "No fee schedule found for merchant_connector_id = {}",
+                merchant_connector_id.get_string_repr()
+            )))?
+        }
+        Ok(true)
+    }
+}
+
+#[async_trait::async_trait]
+impl ConnectorFeeScheduleInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_connector_fee_schedule(
+        &self,
+        fee_schedule: storage::ConnectorFeeScheduleNew,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_connector_fee_schedule(fee_schedule)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .find_connector_fee_schedule_by_merchant_id_merchant_connector_id(
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_connector_fee_schedules_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::ConnectorFeeSchedule>, errors::StorageError> {
+        self.diesel_store
+            .list_connector_fee_schedules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        fee_schedule_update: storage::ConnectorFeeScheduleUpdate,
+    ) -> CustomResult<storage::ConnectorFeeSchedule, errors::StorageError> {
+        self.diesel_store
+            .update_connector_fee_schedule(merchant_id, merchant_connector_id, fee_schedule_update)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_connector_fee_schedule(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_connector_fee_schedule(merchant_id, merchant_connector_id)
+            .await
+    }
+}
@@ -0,0 +1,391 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncConnection;
+use common_utils::id_type;
+#[cfg(feature = "v1")]
+use common_utils::types::keymanager::KeyManagerState;
+use error_stack::report;
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+#[cfg(feature = "v1")]
+use crate::types::domain::{self, behaviour::ReverseConversion};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerMergeRedirectInterface {
+    async fn find_optional_customer_merge_redirect(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMergeRedirect>, errors::StorageError>;
+
+    /// Applies `customer_updates` to the target customer, moves the payment methods, mandates,
+    /// payments and addresses of the source customer to the target customer, deletes the source
+    /// customer and records the redirect, in a single transaction.
+    ///
+    /// `payment_method_locker_ids` and `payment_method_network_token_locker_ids` hold the
+    /// references of the locker entries stored under the target customer for the payment methods
+    /// of the source customer.
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn merge_customer_references(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        redirect: storage::CustomerMergeRedirectNew,
+        customer_updates: Vec<storage::CustomerUpdate>,
+        payment_method_locker_ids: Vec<(String, String)>,
+        payment_method_network_token_locker_ids: Vec<(String, String)>,
+    ) -> CustomResult<(storage::CustomerMergeRedirect, domain::Customer), errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeRedirectInterface for Store {
+    #[instrument(skip_all)]
+    async fn find_optional_customer_merge_redirect(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMergeRedirect>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerMergeRedirect::find_optional_by_merchant_id_source_customer_id(
+            &conn,
+            merchant_id,
+            source_customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn merge_customer_references(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        redirect: storage::CustomerMergeRedirectNew,
+        customer_updates: Vec<storage::CustomerUpdate>,
+        payment_method_locker_ids: Vec<(String, String)>,
+        payment_method_network_token_locker_ids: Vec<(String, String)>,
+    ) -> CustomResult<(storage::CustomerMergeRedirect, domain::Customer), errors::StorageError>
+    {
+        let conn = connection::pg_connection_write(self).await?;
+
+        let (redirect, customer) = conn
+            .transaction_async(|conn| async move {
+                let merchant_id = &redirect.merchant_id;
+                let source_customer_id = &redirect.source_customer_id;
+                let target_customer_id = &redirect.target_customer_id;
+
+                for (payment_method_id, locker_id) in payment_method_locker_ids {
+                    storage::PaymentMethod::update_locker_id_by_payment_method_id(
+                        &conn,
+                        &payment_method_id,
+                        &locker_id,
+                    )
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                }
+                for (payment_method_id, network_token_locker_id) in
+                    payment_method_network_token_locker_ids
+                {
+                    storage::PaymentMethod::update_network_token_locker_id_by_payment_method_id(
+                        &conn,
+                        &payment_method_id,
+                        &network_token_locker_id,
+                    )
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                }
+                storage::PaymentMethod::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                storage::Mandate::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                diesel_models::PaymentIntent::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                storage::Address::update_customer_id_by_merchant_id_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                let mut customer = storage::Customer::find_by_customer_id_merchant_id(
+                    &conn,
+                    target_customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                for customer_update in customer_updates {
+                    customer = storage::Customer::update_by_customer_id_merchant_id(
+                        &conn,
+                        target_customer_id.clone(),
+                        merchant_id.clone(),
+                        customer_update.into(),
+                    )
+                    .await
+                    .map_err(errors::StorageError::from)?;
+                }
+                storage::Customer::delete_by_customer_id_merchant_id(
+                    &conn,
+                    source_customer_id,
+                    merchant_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+                // Customers merged into the source customer earlier now resolve to the target
+                // customer, and a redirect left behind by an earlier merge of a customer with the
+                // same ID is replaced
+                storage::CustomerMergeRedirect::update_target_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                    target_customer_id,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                storage::CustomerMergeRedirect::delete_by_merchant_id_source_customer_id(
+                    &conn,
+                    merchant_id,
+                    source_customer_id,
+                )
+                .await
+                .or_else(|error| match error.current_context() {
+                    diesel_models::errors::DatabaseError::NotFound => Ok(false),
+                    _ => Err(error),
+                })
+                .map_err(errors::StorageError::from)?;
+
+                let redirect = redirect
+                    .insert(&conn)
+                    .await
+                    .map_err(errors::StorageError::from)?;
+
+                Ok::<_, errors::StorageError>((redirect, customer))
+            })
+            .await
+            .map_err(|error| report!(error))?;
+
+        let customer: domain::Customer = customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((redirect, customer))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeRedirectInterface for MockDb {
+    async fn find_optional_customer_merge_redirect(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMergeRedirect>, errors::StorageError> {
+        let redirects = self.customer_merge_redirects.lock().await;
+        Ok(redirects
+            .iter()
+            .find(|redirect| {
+                redirect.merchant_id == *merchant_id
+                    && redirect.source_customer_id == *source_customer_id
+            })
+            .cloned())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn merge_customer_references(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        redirect: storage::CustomerMergeRedirectNew,
+        customer_updates: Vec<storage::CustomerUpdate>,
+        payment_method_locker_ids: Vec<(String, String)>,
+        payment_method_network_token_locker_ids: Vec<(String, String)>,
+    ) -> CustomResult<(storage::CustomerMergeRedirect, domain::Customer), errors::StorageError>
+    {
+        let merchant_id = &redirect.merchant_id;
+        let source_customer_id = &redirect.source_customer_id;
+        let target_customer_id = &redirect.target_customer_id;
+
+        let mut customers = self.customers.lock().await;
+        let target_customer = customers
+            .iter_mut()
+            .find(|customer| {
+                customer.merchant_id == *merchant_id && customer.customer_id == *target_customer_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer found for customer_id = {target_customer_id:?}"
+            )))?;
+        for customer_update in customer_updates {
+            *target_customer = storage::CustomerUpdateInternal::from(customer_update)
+                .apply_changeset(target_customer.clone());
+        }
+        let customer = target_customer.clone();
+        let customer_index = customers
+            .iter()
+            .position(|customer| {
+                customer.merchant_id == *merchant_id && customer.customer_id == *source_customer_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No customer found for customer_id = {source_customer_id:?}"
+            )))?;
+        customers.remove(customer_index);
+
+        let find_locker_id = |locker_ids: &[(String, String)], payment_method_id: &str| {
+            locker_ids
+                .iter()
+                .find(|(id, _)| id == payment_method_id)
+                .map(|(_, locker_id)| locker_id.clone())
+        };
+        for payment_method in self.payment_methods.lock().await.iter_mut() {
+            if let Some(locker_id) = find_locker_id(
+                &payment_method_locker_ids,
+                &payment_method.payment_method_id,
+            ) {
+                payment_method.locker_id = Some(locker_id);
+            }
+            if let Some(network_token_locker_id) = find_locker_id(
+                &payment_method_network_token_locker_ids,
+                &payment_method.payment_method_id,
+            ) {
+                payment_method.network_token_locker_id = Some(network_token_locker_id);
+            }
+            if payment_method.merchant_id == *merchant_id
+                && payment_method.customer_id == *source_customer_id
+            {
+                payment_method.customer_id = target_customer_id.clone();
+            }
+        }
+        self.mandates
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|mandate| {
+                mandate.merchant_id == *merchant_id && mandate.customer_id == *source_customer_id
+            })
+            .for_each(|mandate| mandate.customer_id = target_customer_id.clone());
+        self.payment_intents
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && payment_intent.customer_id.as_ref() == Some(source_customer_id)
+            })
+            .for_each(|payment_intent| {
+                payment_intent.customer_id = Some(target_customer_id.clone())
+            });
+        self.addresses
+            .lock()
+            .await
+            .iter_mut()
+            .filter(|address| {
+                address.merchant_id == *merchant_id
+                    && address.customer_id.as_ref() == Some(source_customer_id)
+            })
+            .for_each(|address| address.customer_id = Some(target_customer_id.clone()));
+
+        let mut redirects = self.customer_merge_redirects.lock().await;
+        redirects.retain(|existing| {
+            existing.merchant_id != *merchant_id
+                || existing.source_customer_id != *source_customer_id
+        });
+        redirects
+            .iter_mut()
+            .filter(|existing| {
+                existing.merchant_id == *merchant_id
+                    && existing.target_customer_id == *source_customer_id
+            })
+            .for_each(|existing| existing.target_customer_id = target_customer_id.clone());
+
+        let redirect = storage::CustomerMergeRedirect {
+            merchant_id: redirect.merchant_id,
+            source_customer_id: redirect.source_customer_id,
+            target_customer_id: redirect.target_customer_id,
+            created_at: redirect.created_at,
+        };
+        redirects.push(redirect.clone());
+
+        let customer: domain::Customer = customer
+            .convert(
+                state,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+            .await
+            .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok((redirect, customer))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerMergeRedirectInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn find_optional_customer_merge_redirect(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        source_customer_id: &id_type::CustomerId,
+    ) -> CustomResult<Option<storage::CustomerMergeRedirect>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_customer_merge_redirect(merchant_id, source_customer_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn merge_customer_references(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        redirect: storage::CustomerMergeRedirectNew,
+        customer_updates: Vec<storage::CustomerUpdate>,
+        payment_method_locker_ids: Vec<(String, String)>,
+        payment_method_network_token_locker_ids: Vec<(String, String)>,
+    ) -> CustomResult<(storage::CustomerMergeRedirect, domain::Customer), errors::StorageError>
+    {
+        self.diesel_store
+            .merge_customer_references(
+                state,
+                key_store,
+                redirect,
+                customer_updates,
+                payment_method_locker_ids,
+                payment_method_network_token_locker_ids,
+            )
+            .await
+    }
+}
@@ -31,6 +31,12 @@ pub trait GsmInterface {
         code: String,
         message: String,
     ) -> CustomResult<storage::GatewayStatusMap, errors::StorageError>;
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError>;
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -96,6 +102,21 @@ impl GsmInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GatewayStatusMap::find_by_connectors_flow_sub_flow(
+            &conn, connectors, flow, sub_flow,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_gsm_rule(
         &self,
@@ -159,6 +180,15 @@ impl GsmInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        _connectors: Vec<String>,
+        _flow: String,
+        _sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_gsm_rule(
         &self,
         _connector: String,
@@ -43,7 +43,10 @@ use super::{
     dashboard_metadata::DashboardMetadataInterface,
     ephemeral_key::ClientSecretInterface,
     role::RoleInterface,
-    user::{sample_data::BatchSampleDataInterface, theme::ThemeInterface, UserInterface},
+    user::{
+        sample_data::BatchSampleDataInterface, scim::ScimInterface, theme::ThemeInterface,
+        webauthn_credential::UserWebauthnCredentialInterface, UserInterface,
+    },
     user_authentication_method::UserAuthenticationMethodInterface,
     user_key_store::UserKeyStoreInterface,
     user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload, UserRoleInterface},
@@ -84,7 +87,10 @@ use crate::{
         AccountsStorageInterface, CommonStorageInterface, GlobalStorageInterface,
         MasterKeyInterface, StorageInterface,
     },
-    services::{kafka::KafkaProducer, Store},
+    services::{
+        kafka::{KafkaCustomer, KafkaMandate, KafkaPaymentMethod, KafkaProducer},
+        Store,
+    },
     types::{domain, storage, AccessToken},
 };
 #[derive(Debug, Clone, Serialize)]
@@ -455,7 +461,9 @@ impl CustomerInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        self.diesel_store
+        let old_customer = customer.clone();
+        let updated_customer = self
+            .diesel_store
             .update_customer_by_customer_id_merchant_id(
                 state,
                 customer_id,
@@ -465,7 +473,21 @@ impl CustomerInterface for KafkaStore {
                 key_store,
                 storage_scheme,
             )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_customer(
+                &KafkaCustomer::from_domain(&updated_customer),
+                Some(KafkaCustomer::from_domain(&old_customer)),
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Customer {updated_customer:?}", error_message=?er);
+        };
+
+        Ok(updated_customer)
     }
 
     #[cfg(feature = "v2")]
@@ -479,7 +501,9 @@ impl CustomerInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        self.diesel_store
+        let old_customer = customer.clone();
+        let updated_customer = self
+            .diesel_store
             .update_customer_by_global_id(
                 state,
                 id,
@@ -489,7 +513,21 @@ impl CustomerInterface for KafkaStore {
                 key_store,
                 storage_scheme,
             )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_customer(
+                &KafkaCustomer::from_domain(&updated_customer),
+                Some(KafkaCustomer::from_domain(&old_customer)),
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Customer {updated_customer:?}", error_message=?er);
+        };
+
+        Ok(updated_customer)
     }
 
     async fn list_customers_by_merchant_id(
@@ -504,6 +542,28 @@ impl CustomerInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn list_unredacted_customers_by_merchant_id_modified_before(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        modified_before: PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, errors::StorageError> {
+        self.diesel_store
+            .list_unredacted_customers_by_merchant_id_modified_before(
+                state,
+                merchant_id,
+                key_store,
+                modified_before,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
     #[cfg(feature = "v1")]
     async fn find_customer_by_customer_id_merchant_id(
         &self,
@@ -565,9 +625,24 @@ impl CustomerInterface for KafkaStore {
         key_store: &domain::MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::Customer, errors::StorageError> {
-        self.diesel_store
+        let customer = self
+            .diesel_store
             .insert_customer(customer_data, state, key_store, storage_scheme)
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_customer(
+                &KafkaCustomer::from_domain(&customer),
+                None,
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Customer {customer:?}", error_message=?er);
+        };
+
+        Ok(customer)
     }
 }
 
@@ -974,7 +1049,9 @@ impl MandateInterface for KafkaStore {
         mandate: storage::Mandate,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        self.diesel_store
+        let old_mandate = mandate.clone();
+        let updated_mandate = self
+            .diesel_store
             .update_mandate_by_merchant_id_mandate_id(
                 merchant_id,
                 mandate_id,
@@ -982,7 +1059,21 @@ impl MandateInterface for KafkaStore {
                 mandate,
                 storage_scheme,
             )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_mandate(
+                &KafkaMandate::from_storage(&updated_mandate),
+                Some(KafkaMandate::from_storage(&old_mandate)),
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Mandate {updated_mandate:?}", error_message=?er);
+        };
+
+        Ok(updated_mandate)
     }
 
     async fn find_mandates_by_merchant_id(
@@ -1000,9 +1091,24 @@ impl MandateInterface for KafkaStore {
         mandate: storage::MandateNew,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Mandate, errors::StorageError> {
-        self.diesel_store
+        let mandate = self
+            .diesel_store
             .insert_mandate(mandate, storage_scheme)
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_mandate(
+                &KafkaMandate::from_storage(&mandate),
+                None,
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Mandate {mandate:?}", error_message=?er);
+        };
+
+        Ok(mandate)
     }
 }
 
@@ -1839,6 +1945,22 @@ impl PaymentAttemptInterface for KafkaStore {
             .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
+
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .redact_payment_attempts_by_merchant_id_payment_id(
+                merchant_id,
+                payment_id,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2183,6 +2305,91 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_list_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_list_by_merchant_id_payment_method_status(
+                state,
+                key_store,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_expiring_before(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        expires_before: PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_card_payment_method_list_expiring_before(
+                state,
+                key_store,
+                merchant_id,
+                expires_before,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_starting_after(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_card_payment_method_list_starting_after(
+                state,
+                key_store,
+                merchant_id,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+            )
+            .await
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
         &self,
@@ -2253,9 +2460,24 @@ impl PaymentMethodInterface for KafkaStore {
         m: domain::PaymentMethod,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
-        self.diesel_store
+        let payment_method = self
+            .diesel_store
             .insert_payment_method(state, key_store, m, storage_scheme)
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_method(
+                &KafkaPaymentMethod::from_domain(&payment_method),
+                None,
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Payment Method {payment_method:?}", error_message=?er);
+        };
+
+        Ok(payment_method)
     }
 
     async fn update_payment_method(
@@ -2266,7 +2488,9 @@ impl PaymentMethodInterface for KafkaStore {
         payment_method_update: storage::PaymentMethodUpdate,
         storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<domain::PaymentMethod, errors::StorageError> {
-        self.diesel_store
+        let old_payment_method = payment_method.clone();
+        let updated_payment_method = self
+            .diesel_store
             .update_payment_method(
                 state,
                 key_store,
@@ -2274,7 +2498,21 @@ impl PaymentMethodInterface for KafkaStore {
                 payment_method_update,
                 storage_scheme,
             )
+            .await?;
+
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_method(
+                &KafkaPaymentMethod::from_domain(&updated_payment_method),
+                Some(KafkaPaymentMethod::from_domain(&old_payment_method)),
+                self.tenant_id.clone(),
+            )
             .await
+        {
+            logger::error!(message="Failed to add analytics entry for Payment Method {updated_payment_method:?}", error_message=?er);
+        };
+
+        Ok(updated_payment_method)
     }
 
     #[cfg(feature = "v1")]
@@ -3211,6 +3449,17 @@ impl GsmInterface for KafkaStore {
             .await
     }
 
+    async fn find_gsm_rules_by_connectors(
+        &self,
+        connectors: Vec<String>,
+        flow: String,
+        sub_flow: String,
+    ) -> CustomResult<Vec<storage::GatewayStatusMap>, errors::StorageError> {
+        self.diesel_store
+            .find_gsm_rules_by_connectors(connectors, flow, sub_flow)
+            .await
+    }
+
     async fn update_gsm_rule(
         &self,
         connector: String,
@@ -3385,6 +3634,71 @@ impl UserInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for KafkaStore {
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::webauthn_credential::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .insert_user_webauthn_credential(credential)
+            .await
+    }
+
+    async fn find_user_webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .find_user_webauthn_credential_by_credential_id(credential_id)
+            .await
+    }
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::webauthn_credential::UserWebauthnCredential>, errors::StorageError>
+    {
+        self.diesel_store
+            .list_user_webauthn_credentials_by_user_id(user_id)
+            .await
+    }
+
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+        credential_update: storage::webauthn_credential::UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .update_user_webauthn_credential(user_id, credential_id, credential_update)
+            .await
+    }
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+    ) -> CustomResult<storage::webauthn_credential::UserWebauthnCredential, errors::StorageError>
+    {
+        self.diesel_store
+            .delete_user_webauthn_credential(user_id, credential_id)
+            .await
+    }
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_webauthn_credentials_by_user_id(user_id)
+            .await
+    }
+}
+
 impl RedisConnInterface for KafkaStore {
     fn get_redis_conn(&self) -> CustomResult<Arc<RedisConnectionPool>, RedisError> {
         self.diesel_store.get_redis_conn()
@@ -3465,6 +3779,18 @@ impl UserRoleInterface for KafkaStore {
             .await
     }
 
+    async fn delete_user_roles_by_user_id_and_org_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        entity_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_user_roles_by_user_id_and_org_id(user_id, tenant_id, org_id, entity_id)
+            .await
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -4158,6 +4484,137 @@ impl ThemeInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ScimInterface for KafkaStore {
+    async fn insert_scim_token(
+        &self,
+        scim_token: storage::scim::ScimTokenNew,
+    ) -> CustomResult<storage::scim::ScimToken, errors::StorageError> {
+        self.diesel_store.insert_scim_token(scim_token).await
+    }
+
+    async fn find_scim_token_by_hashed_token_optional(
+        &self,
+        hashed_token: String,
+    ) -> CustomResult<Option<storage::scim::ScimToken>, errors::StorageError> {
+        self.diesel_store
+            .find_scim_token_by_hashed_token_optional(hashed_token)
+            .await
+    }
+
+    async fn list_scim_tokens_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::scim::ScimToken>, errors::StorageError> {
+        self.diesel_store.list_scim_tokens_by_org_id(org_id).await
+    }
+
+    async fn delete_scim_token(
+        &self,
+        org_id: &id_type::OrganizationId,
+        token_id: &str,
+    ) -> CustomResult<storage::scim::ScimToken, errors::StorageError> {
+        self.diesel_store.delete_scim_token(org_id, token_id).await
+    }
+
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::scim::ScimUserNew,
+    ) -> CustomResult<storage::scim::ScimUser, errors::StorageError> {
+        self.diesel_store.insert_scim_user(scim_user).await
+    }
+
+    async fn find_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::scim::ScimUser, errors::StorageError> {
+        self.diesel_store.find_scim_user(org_id, user_id).await
+    }
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::scim::ScimUserListConstraints,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::scim::ScimUser>, errors::StorageError> {
+        self.diesel_store
+            .list_scim_users_by_org_id(org_id, constraints, limit, offset)
+            .await
+    }
+
+    async fn count_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::scim::ScimUserListConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_scim_users_by_org_id(org_id, constraints)
+            .await
+    }
+
+    async fn update_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: storage::scim::ScimUserUpdate,
+    ) -> CustomResult<storage::scim::ScimUser, errors::StorageError> {
+        self.diesel_store
+            .update_scim_user(org_id, user_id, scim_user_update)
+            .await
+    }
+
+    async fn delete_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::scim::ScimUser, errors::StorageError> {
+        self.diesel_store.delete_scim_user(org_id, user_id).await
+    }
+
+    async fn insert_scim_group(
+        &self,
+        scim_group: storage::scim::ScimGroupNew,
+    ) -> CustomResult<storage::scim::ScimGroup, errors::StorageError> {
+        self.diesel_store.insert_scim_group(scim_group).await
+    }
+
+    async fn find_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::scim::ScimGroup, errors::StorageError> {
+        self.diesel_store.find_scim_group(org_id, group_id).await
+    }
+
+    async fn list_scim_groups_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::scim::ScimGroup>, errors::StorageError> {
+        self.diesel_store.list_scim_groups_by_org_id(org_id).await
+    }
+
+    async fn update_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+        scim_group_update: storage::scim::ScimGroupUpdate,
+    ) -> CustomResult<storage::scim::ScimGroup, errors::StorageError> {
+        self.diesel_store
+            .update_scim_group(org_id, group_id, scim_group_update)
+            .await
+    }
+
+    async fn delete_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::scim::ScimGroup, errors::StorageError> {
+        self.diesel_store.delete_scim_group(org_id, group_id).await
+    }
+}
+
 #[async_trait::async_trait]
 #[cfg(feature = "v2")]
 impl db::payment_method_session::PaymentMethodsSessionInterface for KafkaStore {
@@ -227,6 +227,7 @@ impl RoleInterface for MockDb {
             last_modified_by: role.last_modified_by,
             profile_id: role.profile_id,
             tenant_id: role.tenant_id,
+            resource_permissions: role.resource_permissions,
         };
         roles.push(role.clone());
         Ok(role)
@@ -325,11 +326,14 @@ impl RoleInterface for MockDb {
                     storage::RoleUpdate::UpdateDetails {
                         groups,
                         role_name,
+                        resource_permissions,
                         last_modified_at,
                         last_modified_by,
                     } => storage::Role {
                         groups: groups.unwrap_or(role.groups.to_owned()),
                         role_name: role_name.unwrap_or(role.role_name.to_owned()),
+                        resource_permissions: resource_permissions
+                            .or(role.resource_permissions.to_owned()),
                         last_modified_by,
                         last_modified_at,
                         ..role.to_owned()
@@ -10,7 +10,9 @@ use crate::{
     services::Store,
 };
 pub mod sample_data;
+pub mod scim;
 pub mod theme;
+pub mod webauthn_credential;
 
 #[async_trait::async_trait]
 pub trait UserInterface {
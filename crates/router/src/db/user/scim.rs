@@ -0,0 +1,625 @@
+use common_utils::{date_time, id_type};
+use diesel_models::user::scim::{self as storage, ScimGroupUpdate, ScimUserUpdate};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait ScimInterface {
+    async fn insert_scim_token(
+        &self,
+        scim_token: storage::ScimTokenNew,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError>;
+
+    async fn find_scim_token_by_hashed_token_optional(
+        &self,
+        hashed_token: String,
+    ) -> CustomResult<Option<storage::ScimToken>, errors::StorageError>;
+
+    async fn list_scim_tokens_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimToken>, errors::StorageError>;
+
+    async fn delete_scim_token(
+        &self,
+        org_id: &id_type::OrganizationId,
+        token_id: &str,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError>;
+
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn find_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError>;
+
+    async fn count_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+    ) -> CustomResult<i64, errors::StorageError>;
+
+    async fn update_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn delete_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError>;
+
+    async fn insert_scim_group(
+        &self,
+        scim_group: storage::ScimGroupNew,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError>;
+
+    async fn find_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError>;
+
+    async fn list_scim_groups_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimGroup>, errors::StorageError>;
+
+    async fn update_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+        scim_group_update: ScimGroupUpdate,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError>;
+
+    async fn delete_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ScimInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_scim_token(
+        &self,
+        scim_token: storage::ScimTokenNew,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_token
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_token_by_hashed_token_optional(
+        &self,
+        hashed_token: String,
+    ) -> CustomResult<Option<storage::ScimToken>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimToken::find_by_hashed_token(&conn, hashed_token)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_scim_tokens_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimToken>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimToken::list_by_org_id(&conn, org_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_scim_token(
+        &self,
+        org_id: &id_type::OrganizationId,
+        token_id: &str,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimToken::delete_by_org_id_token_id(&conn, org_id.to_owned(), token_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_user
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::find_by_org_id_user_id(&conn, org_id.to_owned(), user_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::list_by_org_id_constraints(
+            &conn,
+            org_id.to_owned(),
+            constraints,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimUser::count_by_org_id_constraints(&conn, org_id.to_owned(), constraints)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::update_by_org_id_user_id(
+            &conn,
+            org_id.to_owned(),
+            user_id.to_owned(),
+            scim_user_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimUser::delete_by_org_id_user_id(&conn, org_id.to_owned(), user_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_scim_group(
+        &self,
+        scim_group: storage::ScimGroupNew,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        scim_group
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimGroup::find_by_org_id_group_id(&conn, org_id.to_owned(), group_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_scim_groups_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimGroup>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ScimGroup::list_by_org_id(&conn, org_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+        scim_group_update: ScimGroupUpdate,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimGroup::update_by_org_id_group_id(
+            &conn,
+            org_id.to_owned(),
+            group_id.to_owned(),
+            scim_group_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ScimGroup::delete_by_org_id_group_id(&conn, org_id.to_owned(), group_id.to_owned())
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl ScimInterface for MockDb {
+    async fn insert_scim_token(
+        &self,
+        scim_token: storage::ScimTokenNew,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError> {
+        let mut scim_tokens = self.scim_tokens.lock().await;
+        if scim_tokens.iter().any(|token| {
+            token.token_id == scim_token.token_id || token.hashed_token == scim_token.hashed_token
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "scim_token",
+                key: None,
+            })?
+        }
+
+        let scim_token = storage::ScimToken {
+            token_id: scim_token.token_id,
+            org_id: scim_token.org_id,
+            name: scim_token.name,
+            prefix: scim_token.prefix,
+            hashed_token: scim_token.hashed_token,
+            created_by: scim_token.created_by,
+            created_at: scim_token.created_at,
+        };
+        scim_tokens.push(scim_token.clone());
+        Ok(scim_token)
+    }
+
+    async fn find_scim_token_by_hashed_token_optional(
+        &self,
+        hashed_token: String,
+    ) -> CustomResult<Option<storage::ScimToken>, errors::StorageError> {
+        let scim_tokens = self.scim_tokens.lock().await;
+        Ok(scim_tokens
+            .iter()
+            .find(|token| token.hashed_token == hashed_token)
+            .cloned())
+    }
+
+    async fn list_scim_tokens_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimToken>, errors::StorageError> {
+        let scim_tokens = self.scim_tokens.lock().await;
+        Ok(scim_tokens
+            .iter()
+            .filter(|token| token.org_id == *org_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_scim_token(
+        &self,
+        org_id: &id_type::OrganizationId,
+        token_id: &str,
+    ) -> CustomResult<storage::ScimToken, errors::StorageError> {
+        let mut scim_tokens = self.scim_tokens.lock().await;
+        let index = scim_tokens
+            .iter()
+            .position(|token| token.org_id == *org_id && token.token_id == token_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No scim token found for org_id = {org_id:?} and token_id = {token_id}"
+            )))?;
+        Ok(scim_tokens.remove(index))
+    }
+
+    async fn insert_scim_user(
+        &self,
+        scim_user: storage::ScimUserNew,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        if scim_users
+            .iter()
+            .any(|user| user.org_id == scim_user.org_id && user.user_id == scim_user.user_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "scim_user",
+                key: None,
+            })?
+        }
+
+        let scim_user = storage::ScimUser {
+            org_id: scim_user.org_id,
+            user_id: scim_user.user_id,
+            external_id: scim_user.external_id,
+            active: scim_user.active,
+            created_at: scim_user.created_at,
+            last_modified_at: scim_user.last_modified_at,
+        };
+        scim_users.push(scim_user.clone());
+        Ok(scim_user)
+    }
+
+    async fn find_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let scim_users = self.scim_users.lock().await;
+        scim_users
+            .iter()
+            .find(|user| user.org_id == *org_id && user.user_id == user_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim user found for org_id = {org_id:?} and user_id = {user_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ScimUser>, errors::StorageError> {
+        let scim_users = self.scim_users.lock().await;
+        Ok(scim_users
+            .iter()
+            .filter(|user| user.org_id == *org_id && matches_constraints(user, &constraints))
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .cloned()
+            .collect())
+    }
+
+    async fn count_scim_users_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+        constraints: storage::ScimUserListConstraints,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let scim_users = self.scim_users.lock().await;
+        Ok(i64::try_from(
+            scim_users
+                .iter()
+                .filter(|user| user.org_id == *org_id && matches_constraints(user, &constraints))
+                .count(),
+        )
+        .unwrap_or_default())
+    }
+
+    async fn update_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+        scim_user_update: ScimUserUpdate,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        scim_users
+            .iter_mut()
+            .find(|user| user.org_id == *org_id && user.user_id == user_id)
+            .map(|user| {
+                *user = match &scim_user_update {
+                    ScimUserUpdate::DetailsUpdate {
+                        external_id,
+                        active,
+                    } => storage::ScimUser {
+                        external_id: external_id.clone().or(user.external_id.clone()),
+                        active: active.unwrap_or(user.active),
+                        last_modified_at: date_time::now(),
+                        ..user.to_owned()
+                    },
+                };
+                user.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim user found for org_id = {org_id:?} and user_id = {user_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_scim_user(
+        &self,
+        org_id: &id_type::OrganizationId,
+        user_id: &str,
+    ) -> CustomResult<storage::ScimUser, errors::StorageError> {
+        let mut scim_users = self.scim_users.lock().await;
+        let index = scim_users
+            .iter()
+            .position(|user| user.org_id == *org_id && user.user_id == user_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No scim user found for org_id = {org_id:?} and user_id = {user_id}"
+            )))?;
+        Ok(scim_users.remove(index))
+    }
+
+    async fn insert_scim_group(
+        &self,
+        scim_group: storage::ScimGroupNew,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let mut scim_groups = self.scim_groups.lock().await;
+        if scim_groups.iter().any(|group| {
+            group.group_id == scim_group.group_id
+                || (group.org_id == scim_group.org_id
+                    && group.display_name == scim_group.display_name)
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "scim_group",
+                key: None,
+            })?
+        }
+
+        let scim_group = storage::ScimGroup {
+            group_id: scim_group.group_id,
+            org_id: scim_group.org_id,
+            display_name: scim_group.display_name,
+            external_id: scim_group.external_id,
+            role_id: scim_group.role_id,
+            entity_type: scim_group.entity_type,
+            merchant_id: scim_group.merchant_id,
+            profile_id: scim_group.profile_id,
+            members: scim_group.members,
+            created_at: scim_group.created_at,
+            last_modified_at: scim_group.last_modified_at,
+        };
+        scim_groups.push(scim_group.clone());
+        Ok(scim_group)
+    }
+
+    async fn find_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let scim_groups = self.scim_groups.lock().await;
+        scim_groups
+            .iter()
+            .find(|group| group.org_id == *org_id && group.group_id == group_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim group found for org_id = {org_id:?} and group_id = {group_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_scim_groups_by_org_id(
+        &self,
+        org_id: &id_type::OrganizationId,
+    ) -> CustomResult<Vec<storage::ScimGroup>, errors::StorageError> {
+        let scim_groups = self.scim_groups.lock().await;
+        Ok(scim_groups
+            .iter()
+            .filter(|group| group.org_id == *org_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+        scim_group_update: ScimGroupUpdate,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let mut scim_groups = self.scim_groups.lock().await;
+        scim_groups
+            .iter_mut()
+            .find(|group| group.org_id == *org_id && group.group_id == group_id)
+            .map(|group| {
+                *group = match &scim_group_update {
+                    ScimGroupUpdate::DetailsUpdate {
+                        display_name,
+                        external_id,
+                    } => storage::ScimGroup {
+                        display_name: display_name.clone().unwrap_or(group.display_name.clone()),
+                        external_id: external_id.clone().or(group.external_id.clone()),
+                        last_modified_at: date_time::now(),
+                        ..group.to_owned()
+                    },
+                    ScimGroupUpdate::MembersUpdate { members } => storage::ScimGroup {
+                        members: members.clone(),
+                        last_modified_at: date_time::now(),
+                        ..group.to_owned()
+                    },
+                };
+                group.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No scim group found for org_id = {org_id:?} and group_id = {group_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_scim_group(
+        &self,
+        org_id: &id_type::OrganizationId,
+        group_id: &str,
+    ) -> CustomResult<storage::ScimGroup, errors::StorageError> {
+        let mut scim_groups = self.scim_groups.lock().await;
+        let index = scim_groups
+            .iter()
+            .position(|group| group.org_id == *org_id && group.group_id == group_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No scim group found for org_id = {org_id:?} and group_id = {group_id}"
+            )))?;
+        Ok(scim_groups.remove(index))
+    }
+}
+
+fn matches_constraints(
+    scim_user: &storage::ScimUser,
+    constraints: &storage::ScimUserListConstraints,
+) -> bool {
+    constraints
+        .user_id
+        .as_ref()
+        .map_or(true, |user_id| scim_user.user_id == *user_id)
+        && constraints
+            .external_id
+            .as_ref()
+            .map_or(true, |external_id| {
+                scim_user.external_id.as_ref() == Some(external_id)
+            })
+}
@@ -0,0 +1,254 @@
+use diesel_models::user::webauthn_credential::{self as storage, UserWebauthnCredentialUpdate};
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::MockDb;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    services::Store,
+};
+
+#[async_trait::async_trait]
+pub trait UserWebauthnCredentialInterface {
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn find_user_webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError>;
+
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+        credential_update: UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError>;
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        credential
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_user_webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserWebauthnCredential::find_by_credential_id(&conn, credential_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::UserWebauthnCredential::list_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+        credential_update: UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::update_by_user_id_credential_id(
+            &conn,
+            user_id,
+            credential_id,
+            credential_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::delete_by_user_id_credential_id(
+            &conn,
+            user_id,
+            credential_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserWebauthnCredential::delete_by_user_id(&conn, user_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl UserWebauthnCredentialInterface for MockDb {
+    async fn insert_user_webauthn_credential(
+        &self,
+        credential: storage::UserWebauthnCredentialNew,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let mut credentials = self.user_webauthn_credentials.lock().await;
+        if credentials
+            .iter()
+            .any(|existing| existing.credential_id == credential.credential_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "credential_id",
+                key: None,
+            })?
+        }
+
+        let credential = storage::UserWebauthnCredential {
+            credential_id: credential.credential_id,
+            user_id: credential.user_id,
+            name: credential.name,
+            public_key: credential.public_key,
+            algorithm: credential.algorithm,
+            sign_count: credential.sign_count,
+            created_at: credential.created_at,
+            last_used_at: credential.last_used_at,
+        };
+        credentials.push(credential.clone());
+        Ok(credential)
+    }
+
+    async fn find_user_webauthn_credential_by_credential_id(
+        &self,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let credentials = self.user_webauthn_credentials.lock().await;
+        credentials
+            .iter()
+            .find(|credential| credential.credential_id == credential_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webauthn credential found for credential_id = {credential_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<Vec<storage::UserWebauthnCredential>, errors::StorageError> {
+        let credentials = self.user_webauthn_credentials.lock().await;
+        Ok(credentials
+            .iter()
+            .filter(|credential| credential.user_id == user_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+        credential_update: UserWebauthnCredentialUpdate,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let mut credentials = self.user_webauthn_credentials.lock().await;
+        credentials
+            .iter_mut()
+            .find(|credential| {
+                credential.user_id == user_id && credential.credential_id == credential_id
+            })
+            .map(|credential| {
+                *credential = match &credential_update {
+                    UserWebauthnCredentialUpdate::AssertionUpdate { sign_count } => {
+                        storage::UserWebauthnCredential {
+                            sign_count: *sign_count,
+                            last_used_at: Some(common_utils::date_time::now()),
+                            ..credential.to_owned()
+                        }
+                    }
+                    UserWebauthnCredentialUpdate::NameUpdate { name } => {
+                        storage::UserWebauthnCredential {
+                            name: name.to_owned(),
+                            ..credential.to_owned()
+                        }
+                    }
+                };
+                credential.to_owned()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webauthn credential found for user_id = {user_id} and credential_id = {credential_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_user_webauthn_credential(
+        &self,
+        user_id: &str,
+        credential_id: &str,
+    ) -> CustomResult<storage::UserWebauthnCredential, errors::StorageError> {
+        let mut credentials = self.user_webauthn_credentials.lock().await;
+        let index = credentials
+            .iter()
+            .position(|credential| {
+                credential.user_id == user_id && credential.credential_id == credential_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webauthn credential found for user_id = {user_id} and credential_id = {credential_id}"
+            )))?;
+        Ok(credentials.remove(index))
+    }
+
+    async fn delete_user_webauthn_credentials_by_user_id(
+        &self,
+        user_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut credentials = self.user_webauthn_credentials.lock().await;
+        let initial_len = credentials.len();
+        credentials.retain(|credential| credential.user_id != user_id);
+        Ok(credentials.len() != initial_len)
+    }
+}
@@ -74,6 +74,14 @@ pub trait UserRoleInterface {
         version: enums::UserRoleVersion,
     ) -> CustomResult<storage::UserRole, errors::StorageError>;
 
+    async fn delete_user_roles_by_user_id_and_org_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        entity_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError>;
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -180,6 +188,26 @@ impl UserRoleInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn delete_user_roles_by_user_id_and_org_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        entity_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::UserRole::delete_by_user_id_tenant_id_org_id(
+            &conn,
+            user_id.to_owned(),
+            tenant_id.to_owned(),
+            org_id.to_owned(),
+            entity_id.map(ToOwned::to_owned),
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -444,6 +472,33 @@ impl UserRoleInterface for MockDb {
         }
     }
 
+    async fn delete_user_roles_by_user_id_and_org_id(
+        &self,
+        user_id: &str,
+        tenant_id: &id_type::TenantId,
+        org_id: &id_type::OrganizationId,
+        entity_id: Option<&str>,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut user_roles = self.user_roles.lock().await;
+        let initial_len = user_roles.len();
+        user_roles.retain(|role| {
+            !(role.user_id == user_id
+                && role.tenant_id == *tenant_id
+                && role.org_id.as_ref() == Some(org_id)
+                && entity_id.map_or(true, |entity_id| {
+                    role.entity_id.as_deref() == Some(entity_id)
+                }))
+        });
+
+        if user_roles.len() == initial_len {
+            return Err(errors::StorageError::ValueNotFound(
+                "Cannot find user role to delete".to_string(),
+            )
+            .into());
+        }
+        Ok(true)
+    }
+
     async fn list_user_roles_by_user_id<'a>(
         &self,
         payload: ListUserRolesByUserIdPayload<'a>,
@@ -0,0 +1,327 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait VaultMigrationEntryInterface {
+    async fn insert_vault_migration_entry(
+        &self,
+        entry: storage::VaultMigrationEntryNew,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError>;
+
+    async fn find_optional_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::VaultMigrationEntry>, errors::StorageError>;
+
+    async fn list_vault_migration_entries_by_migration_id(
+        &self,
+        migration_id: &str,
+        status: Option<enums::VaultMigrationEntryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError>;
+
+    /// Lists the entries of the migration with the given status in the order of their payment
+    /// method ids, starting after the given payment method id.
+    async fn list_vault_migration_entries_starting_after(
+        &self,
+        migration_id: &str,
+        status: enums::VaultMigrationEntryStatus,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError>;
+
+    async fn update_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+        entry_update: storage::VaultMigrationEntryUpdate,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl VaultMigrationEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_vault_migration_entry(
+        &self,
+        entry: storage::VaultMigrationEntryNew,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        entry
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::VaultMigrationEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultMigrationEntry::find_optional_by_migration_id_payment_method_id(
+            &conn,
+            migration_id,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_migration_entries_by_migration_id(
+        &self,
+        migration_id: &str,
+        status: Option<enums::VaultMigrationEntryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultMigrationEntry::list_by_migration_id(
+            &conn,
+            migration_id,
+            status,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_migration_entries_starting_after(
+        &self,
+        migration_id: &str,
+        status: enums::VaultMigrationEntryStatus,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultMigrationEntry::list_by_migration_id_status_starting_after(
+            &conn,
+            migration_id,
+            status,
+            starting_after_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+        entry_update: storage::VaultMigrationEntryUpdate,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultMigrationEntry::update_by_migration_id_payment_method_id(
+            &conn,
+            migration_id,
+            payment_method_id,
+            entry_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultMigrationEntryInterface for MockDb {
+    async fn insert_vault_migration_entry(
+        &self,
+        entry: storage::VaultMigrationEntryNew,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        let mut entries = self.vault_migration_entries.lock().await;
+        if entries.iter().any(|existing| {
+            existing.migration_id == entry.migration_id
+                && existing.payment_method_id == entry.payment_method_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "payment_method_id",
+                key: Some(entry.payment_method_id.clone()),
+            })?
+        }
+
+        let entry = storage::VaultMigrationEntry {
+            migration_id: entry.migration_id,
+            payment_method_id: entry.payment_method_id,
+            merchant_id: entry.merchant_id,
+            customer_id: entry.customer_id,
+            source_locker_id: entry.source_locker_id,
+            destination_locker_id: entry.destination_locker_id,
+            status: entry.status,
+            error_message: entry.error_message,
+            created_at: entry.created_at,
+            modified_at: entry.modified_at,
+            source_connector_id: entry.source_connector_id,
+            destination_connector_id: entry.destination_connector_id,
+            destination_reference_created: entry.destination_reference_created,
+        };
+        entries.push(entry.clone());
+        Ok(entry)
+    }
+
+    async fn find_optional_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::VaultMigrationEntry>, errors::StorageError> {
+        let entries = self.vault_migration_entries.lock().await;
+        Ok(entries
+            .iter()
+            .find(|entry| {
+                entry.migration_id == migration_id && entry.payment_method_id == payment_method_id
+            })
+            .cloned())
+    }
+
+    async fn list_vault_migration_entries_by_migration_id(
+        &self,
+        migration_id: &str,
+        status: Option<enums::VaultMigrationEntryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        let entries = self.vault_migration_entries.lock().await;
+        let mut filtered_entries = entries
+            .iter()
+            .filter(|entry| {
+                entry.migration_id == migration_id
+                    && status.map_or(true, |status| entry.status == status)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        filtered_entries.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+        Ok(filtered_entries
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    async fn list_vault_migration_entries_starting_after(
+        &self,
+        migration_id: &str,
+        status: enums::VaultMigrationEntryStatus,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        let entries = self.vault_migration_entries.lock().await;
+        let mut filtered_entries = entries
+            .iter()
+            .filter(|entry| {
+                entry.migration_id == migration_id
+                    && entry.status == status
+                    && starting_after_id.map_or(true, |starting_after_id| {
+                        entry.payment_method_id.as_str() > starting_after_id
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        filtered_entries.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        Ok(filtered_entries
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    async fn update_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+        entry_update: storage::VaultMigrationEntryUpdate,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        let mut entries = self.vault_migration_entries.lock().await;
+        let entry = entries
+            .iter_mut()
+            .find(|entry| {
+                entry.migration_id == migration_id && entry.payment_method_id == payment_method_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No vault migration entry found for payment_method_id = {payment_method_id}"
+            )))?;
+
+        *entry = storage::VaultMigrationEntryUpdateInternal::from(entry_update)
+            .apply_changeset(entry.clone());
+
+        Ok(entry.clone())
+    }
+}
+
+#[async_trait::async_trait]
+impl VaultMigrationEntryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_vault_migration_entry(
+        &self,
+        entry: storage::VaultMigrationEntryNew,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        self.diesel_store.insert_vault_migration_entry(entry).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_optional_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+    ) -> CustomResult<Option<storage::VaultMigrationEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_optional_vault_migration_entry(migration_id, payment_method_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_migration_entries_by_migration_id(
+        &self,
+        migration_id: &str,
+        status: Option<enums::VaultMigrationEntryStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        self.diesel_store
+            .list_vault_migration_entries_by_migration_id(migration_id, status, limit, offset)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_migration_entries_starting_after(
+        &self,
+        migration_id: &str,
+        status: enums::VaultMigrationEntryStatus,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultMigrationEntry>, errors::StorageError> {
+        self.diesel_store
+            .list_vault_migration_entries_starting_after(
+                migration_id,
+                status,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn update_vault_migration_entry(
+        &self,
+        migration_id: &str,
+        payment_method_id: &str,
+        entry_update: storage::VaultMigrationEntryUpdate,
+    ) -> CustomResult<storage::VaultMigrationEntry, errors::StorageError> {
+        self.diesel_store
+            .update_vault_migration_entry(migration_id, payment_method_id, entry_update)
+            .await
+    }
+}
@@ -39,6 +39,9 @@ pub enum EventType {
     Consolidated,
     Authentication,
     RoutingApiLogs,
+    Customer,
+    Mandate,
+    PaymentMethod,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
@@ -39,6 +39,23 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    ChangeRequestCreated {
+        change_request_id: String,
+        entity: common_enums::ChangeRequestEntity,
+        requested_by: String,
+    },
+    ChangeRequestDecided {
+        change_request_id: String,
+        entity: common_enums::ChangeRequestEntity,
+        status: common_enums::ChangeRequestStatus,
+        decided_by: String,
+        reason: Option<String>,
+    },
+    ChangeApprovalSettingsUpdated {
+        org_id: common_utils::id_type::OrganizationId,
+        is_enabled: bool,
+        updated_by: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -83,6 +100,11 @@ impl Event for AuditEvent {
             AuditEventType::PaymentStatus => "payment_status",
             AuditEventType::PaymentCompleteAuthorize => "payment_complete_authorize",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::ChangeRequestCreated { .. } => "change_request_created",
+            AuditEventType::ChangeRequestDecided { .. } => "change_request_decided",
+            AuditEventType::ChangeApprovalSettingsUpdated { .. } => {
+                "change_approval_settings_updated"
+            }
         };
         format!(
             "{event_type}-{}",
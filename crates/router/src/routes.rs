@@ -7,6 +7,8 @@ pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod change_request;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -89,10 +91,10 @@ pub use self::app::{
     Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense,
     Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
     Poll, ProcessTracker, Profile, ProfileAcquirer, ProfileNew, Refunds, Relay, RelayWebhooks,
-    SessionState, ThreeDsDecisionRule, User, Webhooks,
+    Scim, SessionState, ThreeDsDecisionRule, User, VaultMigration, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{Blocklist, ChangeRequests, Organization, Routing, Verify, WebhookEvents};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(feature = "stripe", feature = "v1"))]
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::connector_fee_schedule;
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -514,11 +516,13 @@ pub async fn connector_create(
             create_connector(state, req, merchant_context, auth_data.profile_id)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+            ))),
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -550,9 +554,9 @@ pub async fn connector_create(
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
-            &auth::JWTAuthMerchantFromHeader {
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromHeader {
                 required_permission: Permission::MerchantConnectorWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -847,11 +851,13 @@ pub async fn connector_update(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(
+                merchant_id.clone(),
+            ))),
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::ProfileConnectorWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -899,10 +905,10 @@ pub async fn connector_update(
         |state, _, req, _| update_connector(state, &merchant_id, None, &id, req),
         auth::auth_type(
             &auth::V2AdminApiAuth,
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromRoute {
                 merchant_id: merchant_id.clone(),
                 required_permission: Permission::MerchantConnectorWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -954,9 +960,182 @@ pub async fn connector_delete(
         |state, _, req, _| delete_connector(state, req.merchant_id, req.merchant_connector_id),
         auth::auth_type(
             &auth::AdminApiAuth,
-            &auth::JWTAuthMerchantFromRoute {
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromRoute {
                 merchant_id,
                 required_permission: Permission::MerchantConnectorWrite,
+            }),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Fee Schedule Create or Update
+///
+/// Create the fee schedule of a Merchant Connector, or replace the fee rules of its existing fee schedule
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleUpsert))]
+pub async fn connector_fee_schedule_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<api_models::connector_fee_schedule::ConnectorFeeScheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorFeeScheduleUpsert;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            connector_fee_schedule::upsert_connector_fee_schedule(
+                state,
+                merchant_id.clone(),
+                auth.key_store,
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Fee Schedule Retrieve
+///
+/// Retrieve the fee schedule of a Merchant Connector
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleRetrieve))]
+pub async fn connector_fee_schedule_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorFeeScheduleRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            connector_fee_schedule::retrieve_connector_fee_schedule(
+                state,
+                req.merchant_id,
+                auth.key_store,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Fee Schedule List
+///
+/// List the fee schedules of all the Merchant Connectors of the merchant
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleList))]
+pub async fn connector_fee_schedule_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorFeeScheduleList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.to_owned(),
+        |state, _auth, merchant_id, _| {
+            connector_fee_schedule::list_connector_fee_schedules(state, merchant_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Fee Schedule Delete
+///
+/// Delete the fee schedule of a Merchant Connector
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorFeeScheduleDelete))]
+pub async fn connector_fee_schedule_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorFeeScheduleDelete;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            connector_fee_schedule::delete_connector_fee_schedule(
+                state,
+                req.merchant_id,
+                auth.key_store,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuthWithMerchantIdFromRoute(merchant_id.clone())),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorWrite,
             },
             req.headers(),
         ),
@@ -998,9 +1177,9 @@ pub async fn connector_delete(
         },
         auth::auth_type(
             &auth::AdminApiAuthWithMerchantIdFromHeader,
-            &auth::JWTAuthMerchantFromHeader {
+            &auth::ChangeApprovalGuard(auth::JWTAuthMerchantFromHeader {
                 required_permission: Permission::MerchantConnectorWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -28,6 +28,8 @@ use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::change_request;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
 #[cfg(feature = "dummy_connector")]
@@ -63,7 +65,9 @@ use super::{
     relay, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, blocklist, locker_migration, payment_link, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
@@ -825,6 +829,9 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/extended_card_info").route(web::get().to(payments::retrieve_extended_card_info)),
                 )
+                .service(
+                    web::resource("/{payment_id}/payment_methods/eligibility").route(web::get().to(payment_methods::payment_method_eligibility_api)),
+                )
                 .service(
                 web::resource("{payment_id}/calculate_tax")
                     .route(web::post().to(payments::payments_dynamic_tax_calculation)),
@@ -920,6 +927,10 @@ impl Routing {
                     routing::routing_unlink_config(state, req, payload, None)
                 })),
             )
+            .service(
+                web::resource("/shadow/deactivate")
+                    .route(web::post().to(routing::routing_unlink_shadow_config)),
+            )
             .service(
                 web::resource("/decision")
                     .route(web::put().to(routing::upsert_decision_manager_config))
@@ -1071,6 +1082,10 @@ impl Routing {
                         routing::routing_link_config(state, req, path, payload, None)
                     },
                 )),
+            )
+            .service(
+                web::resource("/{algorithm_id}/shadow/activate")
+                    .route(web::post().to(routing::routing_link_shadow_config)),
             );
         route
     }
@@ -1105,6 +1120,11 @@ impl Customers {
                     web::resource("/{id}/saved-payment-methods")
                         .route(web::get().to(payment_methods::list_customer_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{id}/saved-payment-methods/merge-duplicates").route(
+                        web::post().to(payment_methods::merge_duplicate_payment_methods_api),
+                    ),
+                )
         }
         route
     }
@@ -1122,6 +1142,20 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/data_export")
+                        .route(web::post().to(customers::customers_data_export)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_export/{file_id}")
+                        .route(web::get().to(customers::customers_data_export_retrieve)),
+                )
+                .service(
+                    web::resource("/data_retention")
+                        .route(web::post().to(customers::data_retention_configure))
+                        .route(web::get().to(customers::data_retention_retrieve))
+                        .route(web::delete().to(customers::data_retention_disable)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
@@ -1134,6 +1168,7 @@ impl Customers {
                         web::get().to(payment_methods::list_customer_payment_method_api_client),
                     ),
                 )
+                .service(web::resource("/merge").route(web::post().to(customers::customers_merge)))
                 .service(
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(payment_methods::list_customer_payment_method_api)),
@@ -1179,7 +1214,9 @@ impl Refunds {
                 .service(
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
-                );
+                )
+                .service(web::resource("/{id}/approve").route(web::post().to(refunds_approve)))
+                .service(web::resource("/{id}/reject").route(web::post().to(refunds_reject)));
         }
         #[cfg(feature = "oltp")]
         {
@@ -1254,6 +1291,12 @@ impl Payouts {
                 .service(
                     web::resource("/profile/filter")
                         .route(web::post().to(payouts_list_available_filters_for_profile)),
+                )
+                .service(
+                    web::resource("/{payout_id}/approve").route(web::post().to(payouts_approve)),
+                )
+                .service(
+                    web::resource("/{payout_id}/reject").route(web::post().to(payouts_reject)),
                 );
         }
         route = route
@@ -1314,11 +1357,16 @@ impl PaymentMethods {
         #[cfg(feature = "olap")]
         {
             route =
-                route.service(web::resource("/filter").route(
-                    web::get().to(
+                route
+                    .service(web::resource("/filter").route(web::get().to(
                         payment_methods::list_countries_currencies_for_connector_payment_method,
-                    ),
-                ));
+                    )))
+                    .service(
+                        web::resource("/card_updater")
+                            .route(web::post().to(payment_methods::card_updater_configure))
+                            .route(web::get().to(payment_methods::card_updater_retrieve))
+                            .route(web::delete().to(payment_methods::card_updater_disable)),
+                    );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1499,6 +1547,39 @@ impl Blocklist {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct ChangeRequests;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ChangeRequests {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/change_requests")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(change_request::list_change_requests))
+                    .route(web::post().to(change_request::create_change_request)),
+            )
+            .service(
+                web::resource("/settings")
+                    .route(web::get().to(change_request::get_change_approval_settings))
+                    .route(web::post().to(change_request::update_change_approval_settings)),
+            )
+            .service(
+                web::resource("/{change_request_id}")
+                    .route(web::get().to(change_request::get_change_request)),
+            )
+            .service(
+                web::resource("/{change_request_id}/approve")
+                    .route(web::post().to(change_request::approve_change_request)),
+            )
+            .service(
+                web::resource("/{change_request_id}/reject")
+                    .route(web::post().to(change_request::reject_change_request)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Organization;
 
@@ -1636,11 +1717,21 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(connector_create))
                         .route(web::get().to(connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/fee_schedules")
+                        .route(web::get().to(connector_fee_schedule_list)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/fee_schedule")
+                        .route(web::post().to(connector_fee_schedule_upsert))
+                        .route(web::get().to(connector_fee_schedule_retrieve))
+                        .route(web::delete().to(connector_fee_schedule_delete)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1708,6 +1799,12 @@ impl Webhooks {
         #[allow(unused_mut)]
         let mut route = web::scope("/webhooks")
             .app_data(web::Data::new(config))
+            // This is a more specific route as compared to `/{merchant_id}/{connector_id_or_name}`
+            // so it is registered before it.
+            .service(
+                web::resource("/network_token/{merchant_id}")
+                    .route(web::post().to(receive_network_token_lifecycle_webhook)),
+            )
             .service(
                 web::resource("/{merchant_id}/{connector_id_or_name}")
                     .route(
@@ -1808,6 +1905,29 @@ impl ApplePayCertificatesMigration {
     }
 }
 
+pub struct VaultMigration;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl VaultMigration {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/vault_migration/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(locker_migration::vault_migration_start))
+                    .route(web::get().to(locker_migration::vault_migration_retrieve)),
+            )
+            .service(
+                web::resource("/entries")
+                    .route(web::get().to(locker_migration::vault_migration_list_entries)),
+            )
+            .service(
+                web::resource("/rollback")
+                    .route(web::post().to(locker_migration::vault_migration_rollback)),
+            )
+    }
+}
+
 pub struct Poll;
 
 #[cfg(all(feature = "oltp", feature = "v1"))]
@@ -2257,6 +2377,11 @@ impl User {
             .service(web::resource("").route(web::get().to(user::get_user_details)))
             .service(web::resource("/signin").route(web::post().to(user::user_signin)))
             .service(web::resource("/v2/signin").route(web::post().to(user::user_signin)))
+            .service(
+                web::resource("/signin/passkey/begin")
+                    .route(web::post().to(user::passkey_signin_begin)),
+            )
+            .service(web::resource("/signin/passkey").route(web::post().to(user::passkey_signin)))
             // signin/signup with sso using openidconnect
             .service(web::resource("/oidc").route(web::post().to(user::sso_sign)))
             .service(web::resource("/signout").route(web::post().to(user::signout)))
@@ -2355,6 +2480,29 @@ impl User {
                                 .route(web::put().to(user::totp_update)),
                         ),
                 )
+                .service(
+                    web::scope("/passkey")
+                        .service(web::resource("").route(web::get().to(user::list_passkeys)))
+                        .service(
+                            web::resource("/register/begin")
+                                .route(web::get().to(user::passkey_register_begin)),
+                        )
+                        .service(
+                            web::resource("/register")
+                                .route(web::post().to(user::passkey_register)),
+                        )
+                        .service(
+                            web::resource("/verify/begin")
+                                .route(web::get().to(user::passkey_verify_begin)),
+                        )
+                        .service(
+                            web::resource("/verify").route(web::post().to(user::passkey_verify)),
+                        )
+                        .service(
+                            web::resource("/{credential_id}")
+                                .route(web::delete().to(user::delete_passkey)),
+                        ),
+                )
                 .service(
                     web::scope("/recovery_code")
                         .service(
@@ -2514,6 +2662,10 @@ impl User {
                                 web::get().to(user_role::list_updatable_roles_at_entity_level),
                             )),
                     )
+                    .service(
+                        web::resource("/template")
+                            .route(web::post().to(user_role::import_role_template)),
+                    )
                     .service(
                         web::resource("/{role_id}")
                             .route(web::get().to(user_role::get_role))
@@ -2522,6 +2674,10 @@ impl User {
                     .service(
                         web::resource("/{role_id}/v2")
                             .route(web::get().to(user_role::get_parent_info_for_role)),
+                    )
+                    .service(
+                        web::resource("/{role_id}/template")
+                            .route(web::get().to(user_role::export_role_template)),
                     ),
             );
 
@@ -2534,6 +2690,19 @@ impl User {
             )
         }
 
+        route = route.service(
+            web::scope("/scim/token")
+                .service(
+                    web::resource("")
+                        .route(web::get().to(user::scim::list_scim_tokens))
+                        .route(web::post().to(user::scim::create_scim_token)),
+                )
+                .service(
+                    web::resource("/{token_id}")
+                        .route(web::delete().to(user::scim::delete_scim_token)),
+                ),
+        );
+
         route = route.service(
             web::scope("/theme")
                 .service(
@@ -2554,6 +2723,44 @@ impl User {
     }
 }
 
+pub struct Scim;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl Scim {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/scim/v2")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/ServiceProviderConfig")
+                    .route(web::get().to(user::scim::get_service_provider_config)),
+            )
+            .service(
+                web::resource("/Users")
+                    .route(web::get().to(user::scim::list_users))
+                    .route(web::post().to(user::scim::create_user)),
+            )
+            .service(
+                web::resource("/Users/{user_id}")
+                    .route(web::get().to(user::scim::get_user))
+                    .route(web::put().to(user::scim::replace_user))
+                    .route(web::patch().to(user::scim::patch_user))
+                    .route(web::delete().to(user::scim::delete_user)),
+            )
+            .service(
+                web::resource("/Groups")
+                    .route(web::get().to(user::scim::list_groups))
+                    .route(web::post().to(user::scim::create_group)),
+            )
+            .service(
+                web::resource("/Groups/{group_id}")
+                    .route(web::get().to(user::scim::get_group))
+                    .route(web::put().to(user::scim::replace_group))
+                    .route(web::patch().to(user::scim::patch_group))
+                    .route(web::delete().to(user::scim::delete_group)),
+            )
+    }
+}
+
 pub struct ConnectorOnboarding;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
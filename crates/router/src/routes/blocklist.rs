@@ -39,13 +39,13 @@ pub async fn add_entry_to_blocklist(
             blocklist::add_entry_to_blocklist(state, merchant_context, body)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -83,13 +83,13 @@ pub async fn remove_entry_from_blocklist(
             blocklist::remove_entry_from_blocklist(state, merchant_context, body)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -175,13 +175,13 @@ pub async fn toggle_blocklist_guard(
             blocklist::toggle_blocklist_guard(state, merchant_context, query)
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantAccountWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
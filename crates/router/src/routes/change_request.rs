@@ -0,0 +1,185 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::change_request as change_request_api;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, change_request as change_request_core},
+    routes::{lock_utils, AppState},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all)]
+pub async fn get_change_approval_settings(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::GetChangeApprovalSettings;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| change_request_core::get_change_approval_settings(state, user),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn update_change_approval_settings(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<change_request_api::ChangeApprovalSettings>,
+) -> HttpResponse {
+    let flow = Flow::UpdateChangeApprovalSettings;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, req_state| {
+            change_request_core::update_change_approval_settings(state, user, payload, req_state)
+        },
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn create_change_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<change_request_api::CreateChangeRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateChangeRequest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, req_state| {
+            change_request_core::create_change_request(state, user, payload, req_state)
+        },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn list_change_requests(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<change_request_api::ListChangeRequestsQuery>,
+) -> HttpResponse {
+    let flow = Flow::ListChangeRequests;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, user, query, _| change_request_core::list_change_requests(state, user, query),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn get_change_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::GetChangeRequest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user, change_request_id, _| {
+            change_request_core::get_change_request(state, user, change_request_id)
+        },
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn approve_change_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<change_request_api::ChangeRequestDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::ApproveChangeRequest;
+    let change_request_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: change_request_id.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, req_state| {
+            change_request_core::approve_change_request(
+                state,
+                user,
+                change_request_id.clone(),
+                payload,
+                req_state,
+            )
+        },
+        &auth::DashboardNoPermissionAuth,
+        lock_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn reject_change_request(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<change_request_api::ChangeRequestDecisionRequest>,
+) -> HttpResponse {
+    let flow = Flow::RejectChangeRequest;
+    let change_request_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: change_request_id.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, req_state| {
+            change_request_core::reject_change_request(
+                state,
+                user,
+                change_request_id.clone(),
+                payload,
+                req_state,
+            )
+        },
+        &auth::DashboardNoPermissionAuth,
+        lock_action,
+    ))
+    .await
+}
@@ -317,8 +317,10 @@ pub async fn accept_dispute(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
+            &auth::JWTAuthWithResourceAction {
                 permission: Permission::ProfileDisputeWrite,
+                action: common_enums::PermissionAction::Accept,
+                amount: None,
             },
             req.headers(),
         ),
@@ -364,8 +366,10 @@ pub async fn submit_dispute_evidence(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
+            &auth::JWTAuthWithResourceAction {
                 permission: Permission::ProfileDisputeWrite,
+                action: common_enums::PermissionAction::SubmitEvidence,
+                amount: None,
             },
             req.headers(),
         ),
@@ -418,8 +422,10 @@ pub async fn attach_dispute_evidence(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
+            &auth::JWTAuthWithResourceAction {
                 permission: Permission::ProfileDisputeWrite,
+                action: common_enums::PermissionAction::SubmitEvidence,
+                amount: None,
             },
             req.headers(),
         ),
@@ -518,8 +524,10 @@ pub async fn delete_dispute_evidence(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
+            &auth::JWTAuthWithResourceAction {
                 permission: Permission::ProfileDisputeWrite,
+                action: common_enums::PermissionAction::SubmitEvidence,
+                amount: None,
             },
             req.headers(),
         ),
@@ -47,6 +47,7 @@ pub enum ApiIdentifier {
     ProfileAcquirer,
     ThreeDsDecisionRule,
     GenericTokenization,
+    ChangeRequest,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -67,6 +68,8 @@ impl From<Flow> for ApiIdentifier {
             Flow::RoutingCreateConfig
             | Flow::RoutingLinkConfig
             | Flow::RoutingUnlinkConfig
+            | Flow::RoutingLinkShadowConfig
+            | Flow::RoutingUnlinkShadowConfig
             | Flow::RoutingRetrieveConfig
             | Flow::RoutingRetrieveActiveConfig
             | Flow::RoutingRetrieveDefaultConfig
@@ -93,7 +96,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::ConnectorFeeScheduleUpsert
+            | Flow::ConnectorFeeScheduleRetrieve
+            | Flow::ConnectorFeeScheduleList
+            | Flow::ConnectorFeeScheduleDelete => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -106,6 +113,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersDataExport
+            | Flow::CustomersDataExportRetrieve
+            | Flow::CustomersMerge
+            | Flow::DataRetentionConfigure
+            | Flow::DataRetentionRetrieve
+            | Flow::DataRetentionDisable
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -116,7 +129,9 @@ impl From<Flow> for ApiIdentifier {
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
             | Flow::PaymentMethodsList
+            | Flow::PaymentMethodEligibility
             | Flow::CustomerPaymentMethodsList
+            | Flow::DuplicatePaymentMethodsMerge
             | Flow::GetPaymentMethodTokenData
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
@@ -125,6 +140,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
+            | Flow::CardUpdaterConfigure
+            | Flow::CardUpdaterRetrieve
+            | Flow::CardUpdaterDisable
             | Flow::PaymentMethodSave
             | Flow::TotalPaymentMethodCount => Self::PaymentMethods,
 
@@ -172,7 +190,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
             | Flow::PayoutsConfirm
-            | Flow::PayoutLinkInitiate => Self::Payouts,
+            | Flow::PayoutLinkInitiate
+            | Flow::PayoutsApprove
+            | Flow::PayoutsReject => Self::Payouts,
 
             Flow::RefundsCreate
             | Flow::RefundsRetrieve
@@ -181,12 +201,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundsApprove
+            | Flow::RefundsReject => Self::Refunds,
             Flow::Relay | Flow::RelayRetrieve => Self::Relay,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::IncomingRelayWebhookReceive
+            | Flow::IncomingNetworkTokenWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
@@ -232,7 +255,11 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::Verification => Self::Verification,
 
-            Flow::RustLockerMigration => Self::RustLockerMigration,
+            Flow::RustLockerMigration
+            | Flow::VaultMigrationStart
+            | Flow::VaultMigrationRetrieve
+            | Flow::VaultMigrationEntriesList
+            | Flow::VaultMigrationRollback => Self::RustLockerMigration,
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
@@ -278,6 +305,30 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
             | Flow::TwoFactorAuthStatus
+            | Flow::PasskeyRegisterBegin
+            | Flow::PasskeyRegister
+            | Flow::PasskeyVerifyBegin
+            | Flow::PasskeyVerify
+            | Flow::ListPasskeys
+            | Flow::DeletePasskey
+            | Flow::PasskeySignInBegin
+            | Flow::PasskeySignIn
+            | Flow::CreateScimToken
+            | Flow::ListScimTokens
+            | Flow::DeleteScimToken
+            | Flow::ScimServiceProviderConfig
+            | Flow::ScimListUsers
+            | Flow::ScimCreateUser
+            | Flow::ScimGetUser
+            | Flow::ScimReplaceUser
+            | Flow::ScimPatchUser
+            | Flow::ScimDeleteUser
+            | Flow::ScimListGroups
+            | Flow::ScimCreateGroup
+            | Flow::ScimGetGroup
+            | Flow::ScimReplaceGroup
+            | Flow::ScimPatchGroup
+            | Flow::ScimDeleteGroup
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
             | Flow::ListUserAuthenticationMethods
@@ -302,6 +353,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ListUpdatableRolesAtEntityLevel
             | Flow::GetRole
             | Flow::GetRoleV2
+            | Flow::ExportRoleTemplate
+            | Flow::ImportRoleTemplate
             | Flow::GetRoleFromToken
             | Flow::GetRoleFromTokenV2
             | Flow::UpdateUserRole
@@ -350,6 +403,14 @@ impl From<Flow> for ApiIdentifier {
             Flow::ProfileAcquirerCreate | Flow::ProfileAcquirerUpdate => Self::ProfileAcquirer,
             Flow::ThreeDsDecisionRuleExecute => Self::ThreeDsDecisionRule,
             Flow::TokenizationCreate | Flow::TokenizationRetrieve => Self::GenericTokenization,
+
+            Flow::GetChangeApprovalSettings
+            | Flow::UpdateChangeApprovalSettings
+            | Flow::CreateChangeRequest
+            | Flow::ListChangeRequests
+            | Flow::GetChangeRequest
+            | Flow::ApproveChangeRequest
+            | Flow::RejectChangeRequest => Self::ChangeRequest,
         }
     }
 }
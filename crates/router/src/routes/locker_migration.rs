@@ -25,3 +25,97 @@ pub async fn rust_locker_migration(
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn vault_migration_start(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::locker_migration::VaultMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::VaultMigrationStart;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            locker_migration::vault_migration::start_vault_migration(state, &merchant_id, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn vault_migration_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::VaultMigrationRetrieve;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &merchant_id,
+        |state, _, merchant_id, _| {
+            locker_migration::vault_migration::retrieve_vault_migration(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn vault_migration_list_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<api_models::locker_migration::VaultMigrationEntriesListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::VaultMigrationEntriesList;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            locker_migration::vault_migration::list_vault_migration_entries(
+                state,
+                &merchant_id,
+                constraints,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn vault_migration_rollback(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::VaultMigrationRollback;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        &merchant_id,
+        |state, _, merchant_id, _| {
+            locker_migration::vault_migration::rollback_vault_migration(state, merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -73,6 +73,7 @@ pub async fn payments_create(
     );
 
     let locking_action = payload.get_locking_input(flow.clone());
+    let amount = payload.amount.map(common_utils::types::MinorUnit::from);
 
     Box::pin(api::server_wrap(
         flow,
@@ -104,8 +105,10 @@ pub async fn payments_create(
                     is_connected_allowed: false,
                     is_platform_allowed: true,
                 }),
-                &auth::JWTAuth {
+                &auth::JWTAuthWithResourceAction {
                     permission: Permission::ProfilePaymentWrite,
+                    action: common_enums::PermissionAction::Create,
+                    amount,
                 },
                 req.headers(),
             ),
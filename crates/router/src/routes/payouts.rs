@@ -186,6 +186,84 @@ pub async fn payouts_cancel(
     ))
     .await
 }
+/// Payouts - Approve
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsApprove))]
+pub async fn payouts_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsApprove;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.payout_id.clone(),
+            api_identifier: super::lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payouts_approve_core(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileTransactionApprovalWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
+/// Payouts - Reject
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsReject))]
+pub async fn payouts_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsReject;
+    let mut payload = json_payload.into_inner();
+    payload.payout_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.payout_id.clone(),
+            api_identifier: super::lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            payouts_reject_core(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileTransactionApprovalWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
 /// Payouts - Fulfill
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsFulfill))]
 pub async fn payouts_fulfill(
@@ -68,11 +68,17 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let jwt_auth = auth::JWTAuthWithResourceAction {
+        permission: Permission::ProfileRefundWrite,
+        action: common_enums::PermissionAction::Create,
+        amount: payload.amount,
+    };
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
             let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
                 domain::Context(auth.merchant_account, auth.key_store),
@@ -84,9 +90,7 @@ pub async fn refunds_create(
                 is_connected_allowed: false,
                 is_platform_allowed: false,
             }),
-            &auth::JWTAuth {
-                permission: Permission::ProfileRefundWrite,
-            },
+            &jwt_auth,
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -769,6 +773,88 @@ pub async fn refunds_manual_update(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "refunds_v2"),
+    feature = "olap"
+))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsApprove))]
+pub async fn refunds_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsApprove;
+    let mut refund_approval_req = payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: refund_approval_req.refund_id.clone(),
+            api_identifier: super::lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_approve_core(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileTransactionApprovalWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "refunds_v2"),
+    feature = "olap"
+))]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsReject))]
+pub async fn refunds_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsReject;
+    let mut refund_approval_req = payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: refund_approval_req.refund_id.clone(),
+            api_identifier: super::lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            refund_reject_core(state, merchant_context, auth.profile_id, req)
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileTransactionApprovalWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "refunds_v2"),
@@ -51,13 +51,13 @@ pub async fn routing_create_config(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -93,19 +93,19 @@ pub async fn routing_create_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
-            &auth::JWTAuth {
+            }),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::ProfileRoutingWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -141,13 +141,13 @@ pub async fn routing_link_config(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -189,21 +189,21 @@ pub async fn routing_link_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
-            &auth::JWTAuthProfileFromRoute {
+            }),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::MerchantRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuthProfileFromRoute {
+        &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
             profile_id: wrapper.profile_id,
             required_permission: Permission::MerchantRoutingWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -408,21 +408,21 @@ pub async fn routing_unlink_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
-            &auth::JWTAuthProfileFromRoute {
+            }),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: path,
                 required_permission: Permission::MerchantRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuthProfileFromRoute {
+        &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
             profile_id: path,
             required_permission: Permission::MerchantRoutingWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -457,13 +457,86 @@ pub async fn routing_unlink_config(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
             }),
-            &auth::JWTAuth {
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_link_shadow_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::RoutingId>,
+) -> impl Responder {
+    let flow = Flow::RoutingLinkShadowConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, algorithm, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::link_shadow_routing_config(state, merchant_context, auth.profile_id, algorithm)
+        },
+        auth::auth_type(
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::ProfileRoutingWrite,
-            },
+            }),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn routing_unlink_shadow_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<routing_types::RoutingConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::RoutingUnlinkShadowConfig;
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, payload_req, _| {
+            let merchant_context = domain::MerchantContext::NormalMerchant(Box::new(
+                domain::Context(auth.merchant_account, auth.key_store),
+            ));
+            routing::unlink_shadow_routing_config(
+                state,
+                merchant_context,
+                payload_req,
+                auth.profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
+                is_connected_allowed: false,
+                is_platform_allowed: false,
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
+                permission: Permission::ProfileRoutingWrite,
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -501,19 +574,19 @@ pub async fn routing_update_default_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
-            &auth::JWTAuth {
+            }),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::MerchantRoutingWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -544,13 +617,13 @@ pub async fn routing_update_default_config(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -666,19 +739,19 @@ pub async fn upsert_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::MerchantSurchargeDecisionManagerWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -703,19 +776,19 @@ pub async fn delete_surcharge_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantSurchargeDecisionManagerWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::MerchantSurchargeDecisionManagerWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -780,19 +853,19 @@ pub async fn upsert_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::MerchantThreeDsDecisionManagerWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -821,19 +894,19 @@ pub async fn upsert_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::V2ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::V2ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            },
-            &auth::JWTAuth {
+            }),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::ProfileThreeDsDecisionManagerWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::ProfileThreeDsDecisionManagerWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -859,19 +932,19 @@ pub async fn delete_decision_manager_config(
         },
         #[cfg(not(feature = "release"))]
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuth {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuth {
                 permission: Permission::MerchantThreeDsDecisionManagerWrite,
-            },
+            }),
             req.headers(),
         ),
         #[cfg(feature = "release")]
-        &auth::JWTAuth {
+        &auth::ChangeApprovalGuard(auth::JWTAuth {
             permission: Permission::MerchantThreeDsDecisionManagerWrite,
-        },
+        }),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -1167,14 +1240,14 @@ pub async fn routing_update_default_config_for_profile(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1216,14 +1289,14 @@ pub async fn toggle_success_based_routing(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1260,14 +1333,14 @@ pub async fn success_based_routing_update_configs(
             .await
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1304,14 +1377,14 @@ pub async fn elimination_routing_update_configs(
             .await
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1356,14 +1429,14 @@ pub async fn contract_based_routing_setup_config(
             .await
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1407,14 +1480,14 @@ pub async fn contract_based_routing_update_configs(
             .await
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: routing_payload_wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1456,14 +1529,14 @@ pub async fn toggle_elimination_routing(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: wrapper.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1509,14 +1582,14 @@ pub async fn set_dynamic_routing_volume_split(
             )
         },
         auth::auth_type(
-            &auth::HeaderAuth(auth::ApiKeyAuth {
+            &auth::ChangeApprovalGuard(auth::HeaderAuth(auth::ApiKeyAuth {
                 is_connected_allowed: false,
                 is_platform_allowed: false,
-            }),
-            &auth::JWTAuthProfileFromRoute {
+            })),
+            &auth::ChangeApprovalGuard(auth::JWTAuthProfileFromRoute {
                 profile_id: payload.profile_id,
                 required_permission: Permission::ProfileRoutingWrite,
-            },
+            }),
             req.headers(),
         ),
         api_locking::LockAction::NotApplicable,
@@ -1,3 +1,4 @@
+pub mod scim;
 pub mod theme;
 
 use actix_web::{web, HttpRequest, HttpResponse};
@@ -764,6 +765,144 @@ pub async fn check_two_factor_auth_status_with_attempts(
     .await
 }
 
+pub async fn passkey_register_begin(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::PasskeyRegisterBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::begin_passkey_registration(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_register(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::PasskeyRegisterRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyRegister;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| user_core::finish_passkey_registration(state, user, req_body),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_verify_begin(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::PasskeyVerifyBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::begin_passkey_authentication(state, user),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_verify(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::PasskeyAssertionRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeyVerify;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| user_core::verify_passkey(state, user, req_body),
+        &auth::SinglePurposeOrLoginTokenAuth(TokenPurpose::TOTP),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_passkeys(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ListPasskeys;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::list_passkeys(state, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_passkey(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DeletePasskey;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        path.into_inner(),
+        |state, user, credential_id, _| user_core::delete_passkey(state, user, credential_id),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_signin_begin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::PasskeySignInBeginRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeySignInBegin;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, _: (), req_body, _| user_core::begin_passkey_signin(state, req_body),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn passkey_signin(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::PasskeySignInRequest>,
+) -> HttpResponse {
+    let flow = Flow::PasskeySignIn;
+    let client_ip = req
+        .connection_info()
+        .realip_remote_addr()
+        .map(ToOwned::to_owned);
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, _: (), req_body, _| {
+            user_core::passkey_signin_token_only_flow(state, req_body, client_ip.clone())
+        },
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 pub async fn get_sso_auth_url(
     state: web::Data<AppState>,
@@ -0,0 +1,330 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::user::scim as scim_api;
+use router_env::Flow;
+
+use crate::{
+    core::{api_locking, user::scim as scim_core},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+pub async fn create_scim_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::CreateScimTokenRequest>,
+) -> HttpResponse {
+    let flow = Flow::CreateScimToken;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user, payload, _| scim_core::create_scim_token(state, user, payload),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_scim_tokens(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::ListScimTokens;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, user, _, _| scim_core::list_scim_tokens(state, user),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_scim_token(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DeleteScimToken;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user, token_id, _| scim_core::delete_scim_token(state, user, token_id),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_service_provider_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::ScimServiceProviderConfig;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _: auth::ScimClient, _, _| scim_core::get_service_provider_config(state),
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_users(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimListUsers;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, scim_client: auth::ScimClient, query, _| {
+            scim_core::list_users(state, scim_client.org_id, query)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimCreateUser;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::create_user(state, scim_client.org_id, payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimGetUser;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, scim_client: auth::ScimClient, user_id, _| {
+            scim_core::get_user(state, scim_client.org_id, user_id)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimUserRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimReplaceUser;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::replace_user(state, scim_client, user_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimPatchUser;
+    let user_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::patch_user(state, scim_client, user_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_user(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimDeleteUser;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, scim_client: auth::ScimClient, user_id, _| {
+            scim_core::delete_user(state, scim_client.org_id, user_id)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_groups(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<scim_api::ScimListQuery>,
+) -> HttpResponse {
+    let flow = Flow::ScimListGroups;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, scim_client: auth::ScimClient, query, _| {
+            scim_core::list_groups(state, scim_client.org_id, query)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn create_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<scim_api::ScimGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimCreateGroup;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::create_group(state, scim_client, payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn get_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimGetGroup;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, scim_client: auth::ScimClient, group_id, _| {
+            scim_core::get_group(state, scim_client.org_id, group_id)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn replace_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimGroupRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimReplaceGroup;
+    let group_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::replace_group(state, scim_client, group_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn patch_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<scim_api::ScimPatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::ScimPatchGroup;
+    let group_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, scim_client: auth::ScimClient, payload, _| {
+            scim_core::patch_group(state, scim_client, group_id.clone(), payload)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn delete_group(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ScimDeleteGroup;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, scim_client: auth::ScimClient, group_id, _| {
+            scim_core::delete_group(state, scim_client.org_id, group_id)
+        },
+        &auth::ScimTokenAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
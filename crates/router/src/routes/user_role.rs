@@ -120,6 +120,51 @@ pub async fn get_role(
     .await
 }
 
+pub async fn export_role_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ExportRoleTemplate;
+    let request_payload = user_role_api::role::GetRoleRequest {
+        role_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        request_payload,
+        |state, user, payload, _| async move {
+            role_core::export_role_template(state, user, payload).await
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn import_role_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<role_api::ImportRoleTemplateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ImportRoleTemplate;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        role_core::import_role_template,
+        &auth::JWTAuth {
+            permission: Permission::MerchantUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn get_parent_info_for_role(
     state: web::Data<AppState>,
     req: HttpRequest,
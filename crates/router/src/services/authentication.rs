@@ -100,6 +100,12 @@ pub struct AuthenticationDataWithOrg {
     pub organization_id: id_type::OrganizationId,
 }
 
+#[derive(Clone, Debug)]
+pub struct ScimClient {
+    pub org_id: id_type::OrganizationId,
+    pub token_id: String,
+}
+
 #[derive(Clone)]
 pub struct UserFromTokenWithRoleInfo {
     pub user: UserFromToken,
@@ -155,6 +161,10 @@ pub enum AuthenticationType {
     WebhookAuth {
         merchant_id: id_type::MerchantId,
     },
+    ScimToken {
+        org_id: id_type::OrganizationId,
+        token_id: String,
+    },
     NoAuth,
 }
 
@@ -190,6 +200,7 @@ impl AuthenticationType {
             | Self::UserJwt { .. }
             | Self::SinglePurposeJwt { .. }
             | Self::SinglePurposeOrLoginJwt { .. }
+            | Self::ScimToken { .. }
             | Self::NoAuth => None,
         }
     }
@@ -1462,6 +1473,65 @@ where
     }
 }
 
+/// Authenticates SCIM requests sent by an identity provider using an organization level token
+#[derive(Debug)]
+pub struct ScimTokenAuth;
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<ScimClient, A> for ScimTokenAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(ScimClient, AuthenticationType)> {
+        let scim_token = request_headers
+            .get(headers::AUTHORIZATION)
+            .get_required_value(headers::AUTHORIZATION)
+            .change_context(errors::ApiErrorResponse::Unauthorized)?
+            .to_str()
+            .change_context(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("Failed to convert SCIM token to string")?
+            .strip_prefix("Bearer ")
+            .ok_or(errors::ApiErrorResponse::Unauthorized)?
+            .trim();
+        if scim_token.is_empty() {
+            return Err(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("SCIM token is empty");
+        }
+
+        let hash_key = {
+            let config = state.conf();
+            config.api_keys.get_inner().get_hash_key()?
+        };
+        let hashed_token = storage::HashedApiKey::from(
+            api_keys::PlaintextApiKey::from(scim_token).keyed_hash(hash_key.peek()),
+        );
+
+        let stored_token = state
+            .store()
+            .find_scim_token_by_hashed_token_optional(hashed_token.into_inner())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve SCIM token")?
+            .ok_or(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Organization not authenticated")?;
+
+        Ok((
+            ScimClient {
+                org_id: stored_token.org_id.clone(),
+                token_id: stored_token.token_id.clone(),
+            },
+            AuthenticationType::ScimToken {
+                org_id: stored_token.org_id,
+                token_id: stored_token.token_id,
+            },
+        ))
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct V2AdminApiAuth;
 
@@ -2819,7 +2889,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         Ok((
             (),
@@ -2852,7 +2922,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         Ok((
             UserFromToken {
@@ -2892,7 +2962,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -2956,7 +3026,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         // Check if token has access to Organization that has been requested in the route
         if payload.org_id != self.organization_id {
@@ -2995,7 +3065,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         // Check if token has access to Organization that has been requested in the route
         if payload.org_id != self.organization_id {
@@ -3040,7 +3110,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let merchant_id_from_header = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
@@ -3079,7 +3149,7 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let merchant_id_from_header = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
@@ -3150,7 +3220,7 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let merchant_id_from_header = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
@@ -3194,7 +3264,7 @@ where
                 .get_required_value(headers::X_PROFILE_ID)?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let merchant_id_from_header = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
@@ -3272,7 +3342,7 @@ where
         }
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let merchant_id_from_header = HeaderMapStruct::new(request_headers)
             .get_id_type_from_header::<id_type::MerchantId>(headers::X_MERCHANT_ID)?;
@@ -3341,7 +3411,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         // Check if token has access to MerchantId that has been requested through query param
         if payload.merchant_id != self.merchant_id {
@@ -3382,7 +3452,7 @@ where
         }
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3447,7 +3517,7 @@ where
         }
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3519,7 +3589,7 @@ where
         }
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3593,7 +3663,7 @@ where
         }
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3661,7 +3731,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3727,7 +3797,7 @@ where
                 .get_required_value(headers::X_PROFILE_ID)?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.required_permission, &role_info)?;
+        authorization::check_permission(self.required_permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3828,7 +3898,86 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
+
+        let key_manager_state = &(&state.session_state()).into();
+        let key_store = state
+            .store()
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &state.store().get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+        let merchant = state
+            .store()
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant account for the merchant id")?;
+        let merchant_id = merchant.get_id().clone();
+        let auth = AuthenticationData {
+            merchant_account: merchant,
+            platform_merchant_account: None,
+            key_store,
+            profile_id: Some(payload.profile_id),
+        };
+        Ok((
+            auth,
+            AuthenticationType::MerchantJwt {
+                merchant_id,
+                user_id: Some(payload.user_id),
+            },
+        ))
+    }
+}
+
+/// JWT authentication for APIs which require a specific action on the resource, allowing custom
+/// roles to be granted only that action, optionally up to an amount
+#[derive(Debug)]
+pub(crate) struct JWTAuthWithResourceAction {
+    pub permission: Permission,
+    pub action: common_enums::PermissionAction,
+    pub amount: Option<common_utils::types::MinorUnit>,
+}
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for JWTAuthWithResourceAction
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        if payload.check_in_blacklist(state).await? {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+        authorization::check_tenant(
+            payload.tenant_id.clone(),
+            &state.session_state().tenant.tenant_id,
+        )?;
+
+        let role_info = authorization::get_role_info(state, &payload).await?;
+        authorization::check_permission_for_action(
+            self.permission,
+            self.action,
+            &role_info,
+            &authorization::roles::ResourceAccessContext {
+                profile_id: &payload.profile_id,
+                amount: self.amount,
+            },
+        )?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3893,7 +4042,7 @@ where
             .get_id_type_from_header::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -3944,6 +4093,169 @@ where
     }
 }
 
+#[cfg(feature = "v2")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for JWTAuthWithResourceAction
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        if payload.check_in_blacklist(state).await? {
+            return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
+        }
+        authorization::check_tenant(
+            payload.tenant_id.clone(),
+            &state.session_state().tenant.tenant_id,
+        )?;
+
+        let profile_id = HeaderMapStruct::new(request_headers)
+            .get_id_type_from_header::<id_type::ProfileId>(headers::X_PROFILE_ID)?;
+
+        let role_info = authorization::get_role_info(state, &payload).await?;
+        authorization::check_permission_for_action(
+            self.permission,
+            self.action,
+            &role_info,
+            &authorization::roles::ResourceAccessContext {
+                profile_id: &profile_id,
+                amount: self.amount,
+            },
+        )?;
+
+        let key_manager_state = &(&state.session_state()).into();
+        let key_store = state
+            .store()
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &state.store().get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+        let profile = state
+            .store()
+            .find_business_profile_by_merchant_id_profile_id(
+                key_manager_state,
+                &key_store,
+                &payload.merchant_id,
+                &profile_id,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+        let merchant = state
+            .store()
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &payload.merchant_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Failed to fetch merchant account for the merchant id")?;
+        let merchant_id = merchant.get_id().clone();
+        let auth = AuthenticationData {
+            merchant_account: merchant,
+            key_store,
+            profile,
+            platform_merchant_account: None,
+        };
+        Ok((
+            auth,
+            AuthenticationType::MerchantJwt {
+                merchant_id,
+                user_id: Some(payload.user_id),
+            },
+        ))
+    }
+}
+
+/// Wrapper over authentication for APIs which modify sensitive configuration, rejecting direct
+/// changes when change approval is enabled for the organization of the caller. Both dashboard users
+/// and API keys of the merchant are rejected, while the admin API key is not subject to change
+/// approval.
+#[derive(Debug)]
+pub(crate) struct ChangeApprovalGuard<I>(pub I);
+
+#[async_trait]
+impl<A, I, T> AuthenticateAndFetch<T, A> for ChangeApprovalGuard<I>
+where
+    A: SessionStateInfo + Sync,
+    I: AuthenticateAndFetch<T, A> + Sync + Send,
+    T: Send,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(T, AuthenticationType)> {
+        let (auth, auth_type) = self
+            .0
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+
+        let org_id = match &auth_type {
+            AuthenticationType::ApiKey { merchant_id, .. } => {
+                Some(get_organization_id_of_merchant(state, merchant_id).await?)
+            }
+            AuthenticationType::OrganizationJwt { .. }
+            | AuthenticationType::MerchantJwt { .. }
+            | AuthenticationType::MerchantJwtWithProfileId { .. } => Some(
+                parse_jwt_payload::<A, AuthToken>(request_headers, state)
+                    .await?
+                    .org_id,
+            ),
+            _ => None,
+        };
+
+        if let Some(org_id) = org_id {
+            if authorization::is_change_approval_enabled(&*state.session_state().store, &org_id)
+                .await?
+            {
+                return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Change approval is enabled for the organization, submit a change request instead".to_string(),
+                }));
+            }
+        }
+
+        Ok((auth, auth_type))
+    }
+}
+
+async fn get_organization_id_of_merchant<A>(
+    state: &A,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<id_type::OrganizationId>
+where
+    A: SessionStateInfo + Sync,
+{
+    let key_manager_state = &(&state.session_state()).into();
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    state
+        .store()
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant account for the merchant id")
+        .map(|merchant_account| merchant_account.get_org_id().to_owned())
+}
+
 pub type AuthenticationDataWithUserId = (AuthenticationData, String);
 
 #[cfg(feature = "v1")]
@@ -3967,7 +4279,7 @@ where
         )?;
 
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -4417,7 +4729,7 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         let key_manager_state = &(&state.session_state()).into();
         let key_store = state
@@ -4577,7 +4889,7 @@ where
             &state.session_state().tenant.tenant_id,
         )?;
         let role_info = authorization::get_role_info(state, &payload).await?;
-        authorization::check_permission(self.permission, &role_info)?;
+        authorization::check_permission(self.permission, &role_info, &payload.profile_id)?;
 
         let user = UserFromToken {
             user_id: payload.user_id.clone(),
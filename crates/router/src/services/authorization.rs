@@ -1,5 +1,6 @@
 use std::sync::Arc;
 
+use common_enums::PermissionAction;
 use common_utils::id_type;
 use error_stack::ResultExt;
 use redis_interface::RedisConnectionPool;
@@ -9,6 +10,7 @@ use super::authentication::AuthToken;
 use crate::{
     consts,
     core::errors::{ApiErrorResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
     routes::app::SessionStateInfo,
 };
 
@@ -115,16 +117,36 @@ where
 pub fn check_permission(
     required_permission: permissions::Permission,
     role_info: &roles::RoleInfo,
+    profile_id: &id_type::ProfileId,
 ) -> RouterResult<()> {
-    role_info
-        .check_permission_exists(required_permission)
-        .then_some(())
-        .ok_or(
-            ApiErrorResponse::AccessForbidden {
-                resource: required_permission.to_string(),
-            }
-            .into(),
-        )
+    check_permission_for_action(
+        required_permission,
+        permissions::get_default_action_for_scope(required_permission.scope()),
+        role_info,
+        &roles::ResourceAccessContext {
+            profile_id,
+            amount: None,
+        },
+    )
+}
+
+/// Checks the required permission against the permission groups of the role, falling back to
+/// the action specific resource permissions of custom roles
+pub fn check_permission_for_action(
+    required_permission: permissions::Permission,
+    action: PermissionAction,
+    role_info: &roles::RoleInfo,
+    context: &roles::ResourceAccessContext<'_>,
+) -> RouterResult<()> {
+    (role_info.check_permission_exists(required_permission)
+        || role_info.check_resource_action_exists(required_permission, action, context))
+    .then_some(())
+    .ok_or(
+        ApiErrorResponse::AccessForbidden {
+            resource: required_permission.to_string(),
+        }
+        .into(),
+    )
 }
 
 pub fn check_tenant(
@@ -143,6 +165,22 @@ pub fn check_tenant(
     Ok(())
 }
 
+pub async fn is_change_approval_enabled(
+    db: &dyn StorageInterface,
+    org_id: &id_type::OrganizationId,
+) -> RouterResult<bool> {
+    match db
+        .find_config_by_key(&org_id.get_change_approval_enabled_key())
+        .await
+    {
+        Ok(config) => Ok(config.config.parse::<bool>().unwrap_or(false)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error)
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch change approval config"),
+    }
+}
+
 fn get_redis_connection_for_global_tenant<A: SessionStateInfo>(
     state: &A,
 ) -> RouterResult<Arc<RedisConnectionPool>> {
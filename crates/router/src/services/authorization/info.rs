@@ -49,6 +49,7 @@ fn get_group_description(group: PermissionGroup) -> Option<&'static str> {
         PermissionGroup::ReconOpsView => Some("View and access all reconciliation operations including reports and analytics"),
         PermissionGroup::ReconOpsManage => Some("Manage all reconciliation operations including reports and analytics"),
         PermissionGroup::InternalManage => None, // Internal group, no user-facing description
+        PermissionGroup::ApprovalsManage => Some("Approve or reject Refunds and Payouts pending approval"),
     }
 }
 
@@ -63,5 +64,6 @@ pub fn get_parent_group_description(group: ParentGroup) -> Option<&'static str>
         ParentGroup::ReconOps => Some("View, manage reconciliation operations like upload and process files, run reconciliation etc"),
         ParentGroup::ReconReports => Some("View, manage reconciliation reports and analytics"),
         ParentGroup::Internal => None, // Internal group, no user-facing description
+        ParentGroup::Approvals => Some("Approve or reject Refunds and Payouts pending approval"),
     }
 }
@@ -34,7 +34,8 @@ impl PermissionGroupExt for PermissionGroup {
             | Self::AccountManage
             | Self::ReconOpsManage
             | Self::ReconReportsManage
-            | Self::InternalManage => PermissionScope::Write,
+            | Self::InternalManage
+            | Self::ApprovalsManage => PermissionScope::Write,
         }
     }
 
@@ -53,6 +54,7 @@ impl PermissionGroupExt for PermissionGroup {
             Self::ReconOpsView | Self::ReconOpsManage => ParentGroup::ReconOps,
             Self::ReconReportsView | Self::ReconReportsManage => ParentGroup::ReconReports,
             Self::InternalManage => ParentGroup::Internal,
+            Self::ApprovalsManage => ParentGroup::Approvals,
         }
     }
 
@@ -103,6 +105,8 @@ impl PermissionGroupExt for PermissionGroup {
             Self::AccountManage => vec![Self::AccountView, Self::AccountManage],
 
             Self::InternalManage => vec![Self::InternalManage],
+
+            Self::ApprovalsManage => vec![Self::OperationsView, Self::ApprovalsManage],
         }
     }
 }
@@ -127,6 +131,7 @@ impl ParentGroupExt for ParentGroup {
             Self::ReconOps => RECON_OPS.to_vec(),
             Self::ReconReports => RECON_REPORTS.to_vec(),
             Self::Internal => INTERNAL.to_vec(),
+            Self::Approvals => APPROVALS.to_vec(),
         }
     }
 
@@ -204,6 +209,8 @@ pub static RECON_OPS: [Resource; 8] = [
 
 pub static INTERNAL: [Resource; 1] = [Resource::InternalConnector];
 
+pub static APPROVALS: [Resource; 2] = [Resource::TransactionApproval, Resource::Account];
+
 pub static RECON_REPORTS: [Resource; 4] = [
     Resource::ReconToken,
     Resource::ReconAndSettlementAnalytics,
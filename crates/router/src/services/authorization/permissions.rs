@@ -1,4 +1,4 @@
-use common_enums::{EntityType, PermissionScope, Resource};
+use common_enums::{EntityType, PermissionAction, PermissionScope, Resource};
 use router_derive::generate_permissions;
 
 generate_permissions! {
@@ -102,6 +102,10 @@ generate_permissions! {
         InternalConnector: {
             scopes: [Write],
             entities: [Merchant]
+        },
+        TransactionApproval: {
+            scopes: [Write],
+            entities: [Profile, Merchant]
         }
     ]
 }
@@ -138,6 +142,7 @@ pub fn get_resource_name(resource: Resource, entity_type: EntityType) -> Option<
         (Resource::Account, EntityType::Organization) => Some("Organization Account"),
         (Resource::Account, EntityType::Tenant) => Some("Tenant Account"),
         (Resource::InternalConnector, _) => None,
+        (Resource::TransactionApproval, _) => Some("Refund & Payout Approvals"),
     }
 }
 
@@ -147,3 +152,57 @@ pub fn get_scope_name(scope: PermissionScope) -> &'static str {
         PermissionScope::Write => "View and Manage",
     }
 }
+
+pub trait PermissionActionExt {
+    fn scope(&self) -> PermissionScope;
+    fn is_applicable_to(&self, resource: Resource) -> bool;
+}
+
+impl PermissionActionExt for PermissionAction {
+    fn scope(&self) -> PermissionScope {
+        match self {
+            Self::View => PermissionScope::Read,
+            Self::Create | Self::Update | Self::Delete | Self::Accept | Self::SubmitEvidence => {
+                PermissionScope::Write
+            }
+        }
+    }
+
+    /// Actions other than viewing and updating are only applicable to the resources whose APIs
+    /// check them, since granting them on any other resource would have no effect
+    fn is_applicable_to(&self, resource: Resource) -> bool {
+        match self {
+            Self::Accept | Self::SubmitEvidence => resource == Resource::Dispute,
+            Self::Create => matches!(resource, Resource::Payment | Resource::Refund),
+            Self::Delete => false,
+            Self::View | Self::Update => resource.scopes().contains(&self.scope()),
+        }
+    }
+}
+
+/// Action checked against resource permissions of a role when an API does not require a
+/// specific action
+pub fn get_default_action_for_scope(scope: PermissionScope) -> PermissionAction {
+    match scope {
+        PermissionScope::Read => PermissionAction::View,
+        PermissionScope::Write => PermissionAction::Update,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_action_is_applicable_to_resource() {
+        assert!(PermissionAction::Create.is_applicable_to(Resource::Payment));
+        assert!(PermissionAction::Create.is_applicable_to(Resource::Refund));
+        assert!(!PermissionAction::Create.is_applicable_to(Resource::Payout));
+        assert!(!PermissionAction::Delete.is_applicable_to(Resource::Payment));
+        assert!(!PermissionAction::Delete.is_applicable_to(Resource::Payout));
+        assert!(PermissionAction::Accept.is_applicable_to(Resource::Dispute));
+        assert!(!PermissionAction::Accept.is_applicable_to(Resource::Payment));
+        assert!(PermissionAction::Update.is_applicable_to(Resource::Payout));
+        assert!(PermissionAction::View.is_applicable_to(Resource::Payout));
+    }
+}
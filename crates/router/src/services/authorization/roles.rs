@@ -4,12 +4,16 @@ use std::collections::HashSet;
 
 #[cfg(feature = "recon")]
 use api_models::enums::ReconPermissionScope;
-use common_enums::{EntityType, PermissionGroup, Resource, RoleScope};
-use common_utils::{errors::CustomResult, id_type};
+use common_enums::{EntityType, PermissionAction, PermissionGroup, Resource, RoleScope};
+use common_types::role::{ResourcePermission, ResourcePermissionConstraints};
+use common_utils::{errors::CustomResult, id_type, types::MinorUnit};
 
 #[cfg(feature = "recon")]
 use super::permission_groups::{RECON_OPS, RECON_REPORTS};
-use super::{permission_groups::PermissionGroupExt, permissions::Permission};
+use super::{
+    permission_groups::PermissionGroupExt,
+    permissions::{Permission, PermissionActionExt},
+};
 use crate::{core::errors, routes::SessionState};
 
 pub mod predefined_roles;
@@ -25,6 +29,14 @@ pub struct RoleInfo {
     is_deletable: bool,
     is_updatable: bool,
     is_internal: bool,
+    #[serde(default)]
+    resource_permissions: Vec<ResourcePermission>,
+}
+
+/// Request details against which the constraints of a resource permission are evaluated
+pub struct ResourceAccessContext<'a> {
+    pub profile_id: &'a id_type::ProfileId,
+    pub amount: Option<MinorUnit>,
 }
 
 impl RoleInfo {
@@ -69,10 +81,19 @@ impl RoleInfo {
         self.is_updatable
     }
 
+    pub fn get_resource_permissions(&self) -> &[ResourcePermission] {
+        &self.resource_permissions
+    }
+
     pub fn get_resources_set(&self) -> HashSet<Resource> {
         self.get_permission_groups()
             .iter()
             .flat_map(|group| group.resources())
+            .chain(
+                self.resource_permissions
+                    .iter()
+                    .map(|resource_permission| resource_permission.resource),
+            )
             .collect()
     }
 
@@ -84,6 +105,30 @@ impl RoleInfo {
             })
     }
 
+    /// Checks whether the resource permissions of the role grant the action on the resource of
+    /// the required permission. Any granted action on a resource also grants viewing it.
+    pub fn check_resource_action_exists(
+        &self,
+        required_permission: Permission,
+        action: PermissionAction,
+        context: &ResourceAccessContext<'_>,
+    ) -> bool {
+        required_permission.entity_type() <= self.entity_type
+            && required_permission.scope() <= action.scope()
+            && self.resource_permissions.iter().any(|resource_permission| {
+                resource_permission.resource == required_permission.resource()
+                    && (resource_permission.actions.contains(&action)
+                        || (action == PermissionAction::View
+                            && !resource_permission.actions.is_empty()))
+                    && resource_permission
+                        .constraints
+                        .as_ref()
+                        .map_or(true, |constraints| {
+                            is_allowed_by_constraints(constraints, action, context)
+                        })
+            })
+    }
+
     #[cfg(feature = "recon")]
     pub fn get_recon_acl(&self) -> HashMap<Resource, ReconPermissionScope> {
         let mut acl: HashMap<Resource, ReconPermissionScope> = HashMap::new();
@@ -164,6 +209,27 @@ impl RoleInfo {
     }
 }
 
+fn is_allowed_by_constraints(
+    constraints: &ResourcePermissionConstraints,
+    action: PermissionAction,
+    context: &ResourceAccessContext<'_>,
+) -> bool {
+    let is_profile_allowed = constraints
+        .profile_ids
+        .as_ref()
+        .map_or(true, |profile_ids| profile_ids.contains(context.profile_id));
+
+    // Amount limits apply only to creation, requests without an amount cannot be verified
+    let is_amount_allowed = match (action, constraints.max_amount) {
+        (PermissionAction::Create, Some(max_amount)) => {
+            context.amount.is_some_and(|amount| amount <= max_amount)
+        }
+        _ => true,
+    };
+
+    is_profile_allowed && is_amount_allowed
+}
+
 impl From<diesel_models::role::Role> for RoleInfo {
     fn from(role: diesel_models::role::Role) -> Self {
         Self {
@@ -176,6 +242,10 @@ impl From<diesel_models::role::Role> for RoleInfo {
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: role
+                .resource_permissions
+                .map(|resource_permissions| resource_permissions.0)
+                .unwrap_or_default(),
         }
     }
 }
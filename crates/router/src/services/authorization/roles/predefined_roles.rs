@@ -31,6 +31,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::ApprovalsManage,
             ],
             role_id: common_utils::consts::ROLE_ID_INTERNAL_ADMIN.to_string(),
             role_name: "internal_admin".to_string(),
@@ -40,6 +41,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -64,6 +66,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -89,6 +92,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: true,
+            resource_permissions: Vec::new(),
         },
     );
 
@@ -115,6 +119,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::ApprovalsManage,
             ],
             role_id: common_utils::consts::ROLE_ID_TENANT_ADMIN.to_string(),
             role_name: "tenant_admin".to_string(),
@@ -124,6 +129,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: false,
             is_updatable: false,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
 
@@ -150,6 +156,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::ApprovalsManage,
             ],
             role_id: common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN.to_string(),
             role_name: "organization_admin".to_string(),
@@ -159,6 +166,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
 
@@ -184,6 +192,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::ReconOpsManage,
                 PermissionGroup::ReconReportsView,
                 PermissionGroup::ReconReportsManage,
+                PermissionGroup::ApprovalsManage,
             ],
             role_id: consts::user_role::ROLE_ID_MERCHANT_ADMIN.to_string(),
             role_name: "merchant_admin".to_string(),
@@ -193,6 +202,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -217,6 +227,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -238,6 +249,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -263,6 +275,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -289,6 +302,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -311,6 +325,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
 
@@ -332,6 +347,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
                 PermissionGroup::AccountView,
                 PermissionGroup::MerchantDetailsManage,
                 PermissionGroup::AccountManage,
+                PermissionGroup::ApprovalsManage,
             ],
             role_id: consts::user_role::ROLE_ID_PROFILE_ADMIN.to_string(),
             role_name: "profile_admin".to_string(),
@@ -341,6 +357,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -363,6 +380,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -384,6 +402,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -407,6 +426,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -430,6 +450,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles.insert(
@@ -450,6 +471,7 @@ pub static PREDEFINED_ROLES: LazyLock<HashMap<&'static str, RoleInfo>> = LazyLoc
             is_deletable: true,
             is_updatable: true,
             is_internal: false,
+            resource_permissions: Vec::new(),
         },
     );
     roles
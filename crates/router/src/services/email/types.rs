@@ -81,6 +81,20 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    AnalyticsAlert {
+        alert_name: String,
+        merchant_id: common_utils::id_type::MerchantId,
+        state: String,
+        metric: String,
+        observed_value: String,
+        reference_value: String,
+        evaluation_window_in_mins: u16,
+    },
+    AnalyticsReport {
+        report_type: String,
+        time_range: String,
+        download_url: String,
+    },
 }
 
 pub mod html {
@@ -242,6 +256,34 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::AnalyticsAlert {
+                alert_name,
+                merchant_id,
+                state,
+                metric,
+                observed_value,
+                reference_value,
+                evaluation_window_in_mins,
+            } => format!(
+                include_str!("assets/analytics_alert.html"),
+                alert_name = alert_name,
+                merchant_id = merchant_id.get_string_repr(),
+                state = state,
+                metric = metric,
+                observed_value = observed_value,
+                reference_value = reference_value,
+                evaluation_window_in_mins = evaluation_window_in_mins,
+            ),
+            EmailBody::AnalyticsReport {
+                report_type,
+                time_range,
+                download_url,
+            } => format!(
+                include_str!("assets/analytics_report.html"),
+                report_type = report_type,
+                time_range = time_range,
+                download_url = download_url,
+            ),
         }
     }
 }
@@ -678,3 +720,64 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct AnalyticsAlertNotification {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub notification: api_models::analytics::alerts::AlertNotification,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsAlertNotification {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let format_value = |value: Option<f64>| {
+            value
+                .map(|value| format!("{value:.2}"))
+                .unwrap_or_else(|| "-".to_string())
+        };
+
+        let body = html::get_html_body(EmailBody::AnalyticsAlert {
+            alert_name: self.notification.name.clone(),
+            merchant_id: self.notification.merchant_id.clone(),
+            state: self.notification.state.to_string(),
+            metric: self.notification.metric.clone(),
+            observed_value: format_value(self.notification.observed_value),
+            reference_value: format_value(self.notification.reference_value),
+            evaluation_window_in_mins: self.notification.evaluation_window_in_mins,
+        });
+
+        Ok(EmailContents {
+            subject: format!(
+                "{} - {} is {}",
+                self.subject, self.notification.name, self.notification.state
+            ),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
+
+pub struct AnalyticsReportReady {
+    pub recipient_email: domain::UserEmail,
+    pub subject: &'static str,
+    pub report_type: api_models::analytics::ReportType,
+    pub time_range: String,
+    pub download_url: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for AnalyticsReportReady {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::AnalyticsReport {
+            report_type: self.report_type.to_string(),
+            time_range: self.time_range.clone(),
+            download_url: self.download_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone().into_inner(),
+        })
+    }
+}
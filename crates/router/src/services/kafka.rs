@@ -18,14 +18,17 @@ use diesel_models::fraud_check::FraudCheck;
 use crate::{events::EventType, services::kafka::fraud_check_event::KafkaFraudCheckEvent};
 mod authentication;
 mod authentication_event;
+mod customer;
 mod dispute;
 mod dispute_event;
 mod fraud_check;
 mod fraud_check_event;
+mod mandate;
 mod payment_attempt;
 mod payment_attempt_event;
 mod payment_intent;
 mod payment_intent_event;
+mod payment_method;
 mod refund;
 mod refund_event;
 use diesel_models::{authentication::Authentication, refund::Refund};
@@ -42,6 +45,9 @@ use self::{
     payment_intent_event::KafkaPaymentIntentEvent, refund::KafkaRefund,
     refund_event::KafkaRefundEvent,
 };
+pub use self::{
+    customer::KafkaCustomer, mandate::KafkaMandate, payment_method::KafkaPaymentMethod,
+};
 use crate::{services::kafka::fraud_check::KafkaFraudCheck, types::storage::Dispute};
 
 // Using message queue result here to avoid confusion with Kafka result provided by library
@@ -162,6 +168,9 @@ pub struct KafkaSettings {
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
     routing_logs_topic: String,
+    customer_analytics_topic: String,
+    mandate_analytics_topic: String,
+    payment_method_analytics_topic: String,
 }
 
 impl KafkaSettings {
@@ -255,6 +264,27 @@ impl KafkaSettings {
             ))
         })?;
 
+        common_utils::fp_utils::when(self.customer_analytics_topic.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka Customer Analytics topic must not be empty".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(self.mandate_analytics_topic.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka Mandate Analytics topic must not be empty".into(),
+            ))
+        })?;
+
+        common_utils::fp_utils::when(
+            self.payment_method_analytics_topic.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka Payment Method Analytics topic must not be empty".into(),
+                ))
+            },
+        )?;
+
         Ok(())
     }
 }
@@ -277,6 +307,9 @@ pub struct KafkaProducer {
     authentication_analytics_topic: String,
     ckh_database_name: Option<String>,
     routing_logs_topic: String,
+    customer_analytics_topic: String,
+    mandate_analytics_topic: String,
+    payment_method_analytics_topic: String,
 }
 
 struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
@@ -327,6 +360,9 @@ impl KafkaProducer {
             authentication_analytics_topic: conf.authentication_analytics_topic.clone(),
             ckh_database_name: None,
             routing_logs_topic: conf.routing_logs_topic.clone(),
+            customer_analytics_topic: conf.customer_analytics_topic.clone(),
+            mandate_analytics_topic: conf.mandate_analytics_topic.clone(),
+            payment_method_analytics_topic: conf.payment_method_analytics_topic.clone(),
         })
     }
 
@@ -649,6 +685,80 @@ impl KafkaProducer {
         })
     }
 
+    pub async fn log_customer(
+        &self,
+        customer: &KafkaCustomer<'_>,
+        old_customer: Option<KafkaCustomer<'_>>,
+        tenant_id: TenantID,
+    ) -> MQResult<()> {
+        if let Some(negative_event) = old_customer {
+            self.log_event(&KafkaEvent::old(
+                &negative_event,
+                tenant_id.clone(),
+                self.ckh_database_name.clone(),
+            ))
+            .attach_printable_lazy(|| {
+                format!("Failed to add negative customer event {negative_event:?}")
+            })?;
+        };
+        self.log_event(&KafkaEvent::new(
+            customer,
+            tenant_id.clone(),
+            self.ckh_database_name.clone(),
+        ))
+        .attach_printable_lazy(|| format!("Failed to add positive customer event {customer:?}"))
+    }
+
+    pub async fn log_mandate(
+        &self,
+        mandate: &KafkaMandate<'_>,
+        old_mandate: Option<KafkaMandate<'_>>,
+        tenant_id: TenantID,
+    ) -> MQResult<()> {
+        if let Some(negative_event) = old_mandate {
+            self.log_event(&KafkaEvent::old(
+                &negative_event,
+                tenant_id.clone(),
+                self.ckh_database_name.clone(),
+            ))
+            .attach_printable_lazy(|| {
+                format!("Failed to add negative mandate event {negative_event:?}")
+            })?;
+        };
+        self.log_event(&KafkaEvent::new(
+            mandate,
+            tenant_id.clone(),
+            self.ckh_database_name.clone(),
+        ))
+        .attach_printable_lazy(|| format!("Failed to add positive mandate event {mandate:?}"))
+    }
+
+    pub async fn log_payment_method(
+        &self,
+        payment_method: &KafkaPaymentMethod<'_>,
+        old_payment_method: Option<KafkaPaymentMethod<'_>>,
+        tenant_id: TenantID,
+    ) -> MQResult<()> {
+        if let Some(negative_event) = old_payment_method {
+            self.log_event(&KafkaEvent::old(
+                &negative_event,
+                tenant_id.clone(),
+                self.ckh_database_name.clone(),
+            ))
+            .attach_printable_lazy(|| {
+                format!("Failed to add negative payment method event {negative_event:?}")
+            })?;
+        };
+        self.log_event(&KafkaEvent::new(
+            payment_method,
+            tenant_id.clone(),
+            self.ckh_database_name.clone(),
+        ))
+        .attach_printable_lazy(|| {
+            format!("Failed to add positive payment method event {payment_method:?}")
+        })
+    }
+
     pub fn get_topic(&self, event: EventType) -> &str {
         match event {
             EventType::FraudCheck => &self.fraud_check_analytics_topic,
@@ -665,6 +775,9 @@ impl KafkaProducer {
             EventType::Consolidated => &self.consolidated_events_topic,
             EventType::Authentication => &self.authentication_analytics_topic,
             EventType::RoutingApiLogs => &self.routing_logs_topic,
+            EventType::Customer => &self.customer_analytics_topic,
+            EventType::Mandate => &self.mandate_analytics_topic,
+            EventType::PaymentMethod => &self.payment_method_analytics_topic,
         }
     }
 }
@@ -0,0 +1,55 @@
+use common_utils::{hashing::HashedString, id_type, pii};
+use masking::WithType;
+use time::OffsetDateTime;
+
+use crate::types::domain;
+
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaCustomer<'a> {
+    pub customer_id: &'a str,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub customer_email: Option<HashedString<pii::EmailStrategy>>,
+    pub customer_phone: Option<HashedString<WithType>>,
+    pub phone_country_code: Option<&'a String>,
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp")]
+    pub modified_at: OffsetDateTime,
+}
+
+impl<'a> KafkaCustomer<'a> {
+    pub fn from_domain(customer: &'a domain::Customer) -> Self {
+        Self {
+            #[cfg(feature = "v1")]
+            customer_id: customer.customer_id.get_string_repr(),
+            #[cfg(feature = "v2")]
+            customer_id: customer.id.get_string_repr(),
+            merchant_id: &customer.merchant_id,
+            customer_email: customer
+                .email
+                .as_ref()
+                .map(|email| HashedString::from(email.clone().into_inner())),
+            customer_phone: customer
+                .phone
+                .as_ref()
+                .map(|phone| HashedString::from(phone.clone().into_inner())),
+            phone_country_code: customer.phone_country_code.as_ref(),
+            created_at: customer.created_at.assume_utc(),
+            modified_at: customer.modified_at.assume_utc(),
+        }
+    }
+}
+
+impl super::KafkaMessage for KafkaCustomer<'_> {
+    fn key(&self) -> String {
+        format!(
+            "{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.customer_id
+        )
+    }
+
+    fn event_type(&self) -> crate::events::EventType {
+        crate::events::EventType::Customer
+    }
+}
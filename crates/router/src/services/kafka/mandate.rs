@@ -0,0 +1,53 @@
+use common_utils::id_type;
+use diesel_models::enums as storage_enums;
+use time::OffsetDateTime;
+
+use crate::types::storage::Mandate;
+
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaMandate<'a> {
+    pub mandate_id: &'a String,
+    pub customer_id: &'a id_type::CustomerId,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub payment_method_id: &'a String,
+    pub mandate_status: storage_enums::MandateStatus,
+    pub mandate_type: storage_enums::MandateType,
+    pub mandate_amount: Option<i64>,
+    pub mandate_currency: Option<storage_enums::Currency>,
+    pub connector: &'a String,
+    pub connector_mandate_id: Option<&'a String>,
+    pub merchant_connector_id: Option<&'a id_type::MerchantConnectorAccountId>,
+    pub original_payment_id: Option<&'a id_type::PaymentId>,
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+}
+
+impl<'a> KafkaMandate<'a> {
+    pub fn from_storage(mandate: &'a Mandate) -> Self {
+        Self {
+            mandate_id: &mandate.mandate_id,
+            customer_id: &mandate.customer_id,
+            merchant_id: &mandate.merchant_id,
+            payment_method_id: &mandate.payment_method_id,
+            mandate_status: mandate.mandate_status,
+            mandate_type: mandate.mandate_type,
+            mandate_amount: mandate.mandate_amount,
+            mandate_currency: mandate.mandate_currency,
+            connector: &mandate.connector,
+            connector_mandate_id: mandate.connector_mandate_id.as_ref(),
+            merchant_connector_id: mandate.merchant_connector_id.as_ref(),
+            original_payment_id: mandate.original_payment_id.as_ref(),
+            created_at: mandate.created_at.assume_utc(),
+        }
+    }
+}
+
+impl super::KafkaMessage for KafkaMandate<'_> {
+    fn key(&self) -> String {
+        format!("{}_{}", self.merchant_id.get_string_repr(), self.mandate_id)
+    }
+
+    fn event_type(&self) -> crate::events::EventType {
+        crate::events::EventType::Mandate
+    }
+}
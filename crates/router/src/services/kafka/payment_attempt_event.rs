@@ -1,6 +1,8 @@
 // use diesel_models::enums::MandateDetails;
 use common_utils::{id_type, types::MinorUnit};
 use diesel_models::enums as storage_enums;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::routing::PaymentRoutingInfo;
 use hyperswitch_domain_models::{
     mandates::MandateDetails, payments::payment_attempt::PaymentAttempt,
 };
@@ -59,11 +61,18 @@ pub struct KafkaPaymentAttemptEvent<'a> {
     pub organization_id: &'a id_type::OrganizationId,
     pub card_network: Option<String>,
     pub card_discovery: Option<String>,
+    pub routing_algorithm_id: Option<id_type::RoutingId>,
+    pub routing_rule_name: Option<String>,
 }
 
 #[cfg(feature = "v1")]
 impl<'a> KafkaPaymentAttemptEvent<'a> {
     pub fn from_storage(attempt: &'a PaymentAttempt) -> Self {
+        let static_routing_decision = attempt
+            .straight_through_algorithm
+            .clone()
+            .and_then(|info| serde_json::from_value::<PaymentRoutingInfo>(info).ok())
+            .and_then(|info| info.static_routing_decision);
         Self {
             payment_id: &attempt.payment_id,
             merchant_id: &attempt.merchant_id,
@@ -120,6 +129,10 @@ impl<'a> KafkaPaymentAttemptEvent<'a> {
             card_discovery: attempt
                 .card_discovery
                 .map(|discovery| discovery.to_string()),
+            routing_algorithm_id: static_routing_decision
+                .as_ref()
+                .map(|decision| decision.algorithm_id.clone()),
+            routing_rule_name: static_routing_decision.and_then(|decision| decision.rule_name),
         }
     }
 }
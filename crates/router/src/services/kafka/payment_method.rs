@@ -0,0 +1,95 @@
+use common_utils::id_type;
+use diesel_models::enums as storage_enums;
+use hyperswitch_domain_models::payment_method_data::PaymentMethodsData;
+use time::OffsetDateTime;
+
+use crate::types::domain;
+
+#[derive(serde::Serialize, Debug)]
+pub struct KafkaPaymentMethod<'a> {
+    pub payment_method_id: &'a str,
+    pub customer_id: &'a str,
+    pub merchant_id: &'a id_type::MerchantId,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub payment_method_type: Option<storage_enums::PaymentMethodType>,
+    pub status: storage_enums::PaymentMethodStatus,
+    pub card_last_4: Option<String>,
+    pub card_isin: Option<String>,
+    pub card_network: Option<storage_enums::CardNetwork>,
+    pub card_issuer: Option<String>,
+    pub fingerprint_id: Option<&'a String>,
+    #[serde(with = "time::serde::timestamp")]
+    pub created_at: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp")]
+    pub last_modified: OffsetDateTime,
+}
+
+impl<'a> KafkaPaymentMethod<'a> {
+    #[cfg(feature = "v1")]
+    pub fn from_domain(payment_method: &'a domain::PaymentMethod) -> Self {
+        Self::new(
+            payment_method,
+            payment_method.get_id(),
+            payment_method.customer_id.get_string_repr(),
+            payment_method.get_payment_methods_data(),
+            None,
+        )
+    }
+
+    #[cfg(feature = "v2")]
+    pub fn from_domain(payment_method: &'a domain::PaymentMethod) -> Self {
+        Self::new(
+            payment_method,
+            payment_method.get_id().get_string_repr(),
+            payment_method.customer_id.get_string_repr(),
+            payment_method
+                .payment_method_data
+                .as_ref()
+                .map(|payment_method_data| payment_method_data.get_inner().clone()),
+            payment_method.locker_fingerprint_id.as_ref(),
+        )
+    }
+
+    fn new(
+        payment_method: &'a domain::PaymentMethod,
+        payment_method_id: &'a str,
+        customer_id: &'a str,
+        payment_method_data: Option<PaymentMethodsData>,
+        fingerprint_id: Option<&'a String>,
+    ) -> Self {
+        // Only the non sensitive card details are published, to look up saved cards
+        let card = match payment_method_data {
+            Some(PaymentMethodsData::Card(card)) => Some(card),
+            _ => None,
+        };
+        Self {
+            payment_method_id,
+            customer_id,
+            merchant_id: &payment_method.merchant_id,
+            payment_method: payment_method.get_payment_method_type(),
+            payment_method_type: payment_method.get_payment_method_subtype(),
+            status: payment_method.status,
+            card_last_4: card.as_ref().and_then(|card| card.last4_digits.clone()),
+            card_isin: card.as_ref().and_then(|card| card.card_isin.clone()),
+            card_network: card.as_ref().and_then(|card| card.card_network.clone()),
+            card_issuer: card.and_then(|card| card.card_issuer),
+            fingerprint_id,
+            created_at: payment_method.created_at.assume_utc(),
+            last_modified: payment_method.last_modified.assume_utc(),
+        }
+    }
+}
+
+impl super::KafkaMessage for KafkaPaymentMethod<'_> {
+    fn key(&self) -> String {
+        format!(
+            "{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.payment_method_id
+        )
+    }
+
+    fn event_type(&self) -> crate::events::EventType {
+        crate::events::EventType::PaymentMethod
+    }
+}
@@ -298,7 +298,6 @@ impl ConnectorData {
         })
     }
 
-    #[cfg(feature = "v2")]
     pub fn get_external_vault_connector_by_name(
         _connectors: &Connectors,
         connector: &api_enums::Connector,
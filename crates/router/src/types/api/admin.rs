@@ -230,6 +230,8 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
             .into(),
             is_iframe_redirection_enabled: item.is_iframe_redirection_enabled,
             merchant_category_code: item.merchant_category_code,
+            transaction_approval_policy: item.transaction_approval_policy,
+            routing_calendar_config: item.routing_calendar_config,
         })
     }
 }
@@ -483,5 +485,7 @@ pub async fn create_profile_from_merchant_account(
             .is_pre_network_tokenization_enabled
             .unwrap_or_default(),
         merchant_category_code: request.merchant_category_code,
+        transaction_approval_policy: request.transaction_approval_policy,
+        routing_calendar_config: request.routing_calendar_config,
     }))
 }
@@ -62,7 +62,7 @@ impl MandateResponseExt for MandateResponse {
         let card = if pm == storage_enums::PaymentMethod::Card {
             // if locker is disabled , decrypt the payment method data
             let card_details = if state.conf.locker.locker_enabled {
-                let card = payment_methods::cards::get_card_from_locker(
+                let card = payment_methods::cards::get_card_from_vault(
                     state,
                     &payment_method.customer_id,
                     &payment_method.merchant_id,
@@ -70,6 +70,7 @@ impl MandateResponseExt for MandateResponse {
                         .locker_id
                         .as_ref()
                         .unwrap_or(payment_method.get_id()),
+                    payment_method.external_vault_source.as_ref(),
                 )
                 .await?;
 
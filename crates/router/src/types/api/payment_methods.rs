@@ -18,15 +18,15 @@ pub use api_models::payment_methods::{
 #[cfg(feature = "v1")]
 pub use api_models::payment_methods::{
     CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod, CardNetworkTokenizeRequest,
-    CardNetworkTokenizeResponse, ConnectorCustomerDetails, CustomerPaymentMethod,
-    CustomerPaymentMethodsListResponse, DefaultPaymentMethod, DeleteTokenizeByTokenRequest,
-    GetTokenizePayloadRequest, GetTokenizePayloadResponse, ListCountriesCurrenciesRequest,
-    MigrateCardDetail, PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
-    PaymentMethodCreate, PaymentMethodCreateData, PaymentMethodCustomerMigrate,
-    PaymentMethodDeleteResponse, PaymentMethodId, PaymentMethodListRequest,
-    PaymentMethodListResponse, PaymentMethodMigrate, PaymentMethodMigrateResponse,
-    PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData, TokenizeCardRequest,
-    TokenizeDataRequest, TokenizePayloadEncrypted, TokenizePayloadRequest,
+    CardNetworkTokenizeResponse, CardUpdaterConfigRequest, CardUpdaterConfigResponse,
+    ConnectorCustomerDetails, CustomerPaymentMethod, CustomerPaymentMethodsListResponse,
+    DefaultPaymentMethod, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
+    GetTokenizePayloadResponse, ListCountriesCurrenciesRequest, MigrateCardDetail,
+    PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest, PaymentMethodCreate,
+    PaymentMethodCreateData, PaymentMethodCustomerMigrate, PaymentMethodDeleteResponse,
+    PaymentMethodId, PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodMigrate,
+    PaymentMethodMigrateResponse, PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData,
+    TokenizeCardRequest, TokenizeDataRequest, TokenizePayloadEncrypted, TokenizePayloadRequest,
     TokenizePaymentMethodRequest, TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1,
     TokenizedWalletValue2,
 };
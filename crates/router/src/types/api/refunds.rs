@@ -19,6 +19,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             storage_enums::RefundStatus::ManualReview => Self::Review,
             storage_enums::RefundStatus::Pending => Self::Pending,
             storage_enums::RefundStatus::Success => Self::Succeeded,
+            storage_enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
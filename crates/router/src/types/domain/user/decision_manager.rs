@@ -237,6 +237,8 @@ impl JWTFlow {
 pub enum Origin {
     #[serde(rename = "sign_in_with_sso")]
     SignInWithSSO,
+    SignInWithPasskey,
+    SignInWithPasskeyAsSecondFactor,
     SignIn,
     SignUp,
     MagicLink,
@@ -249,6 +251,8 @@ impl Origin {
     fn get_flows(&self) -> &'static [UserFlow] {
         match self {
             Self::SignInWithSSO => &SIGNIN_WITH_SSO_FLOW,
+            Self::SignInWithPasskey => &SIGNIN_WITH_PASSKEY_FLOW,
+            Self::SignInWithPasskeyAsSecondFactor => &SIGNIN_WITH_PASSKEY_AS_SECOND_FACTOR_FLOW,
             Self::SignIn => &SIGNIN_FLOW,
             Self::SignUp => &SIGNUP_FLOW,
             Self::VerifyEmail => &VERIFY_EMAIL_FLOW,
@@ -264,6 +268,19 @@ const SIGNIN_WITH_SSO_FLOW: [UserFlow; 2] = [
     UserFlow::JWTFlow(JWTFlow::UserInfo),
 ];
 
+const SIGNIN_WITH_PASSKEY_FLOW: [UserFlow; 3] = [
+    UserFlow::SPTFlow(SPTFlow::TOTP),
+    UserFlow::SPTFlow(SPTFlow::MerchantSelect),
+    UserFlow::JWTFlow(JWTFlow::UserInfo),
+];
+
+// A user verified passkey is already phishing resistant multi factor authentication, this flow
+// is only used when the deployment allows passkeys to satisfy two factor authentication
+const SIGNIN_WITH_PASSKEY_AS_SECOND_FACTOR_FLOW: [UserFlow; 2] = [
+    UserFlow::SPTFlow(SPTFlow::MerchantSelect),
+    UserFlow::JWTFlow(JWTFlow::UserInfo),
+];
+
 const SIGNIN_FLOW: [UserFlow; 4] = [
     UserFlow::SPTFlow(SPTFlow::TOTP),
     UserFlow::SPTFlow(SPTFlow::ForceSetPassword),
@@ -357,3 +357,36 @@ pub struct CheckTokenStatusResponsePayload {
 pub struct CheckTokenStatusResponse {
     pub payload: CheckTokenStatusResponsePayload,
 }
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Deserialize)]
+pub struct NetworkTokenLifecycleWebhook {
+    pub payload: Secret<String>, //encrypted and signed payload
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NetworkTokenLifecycleEventType {
+    Suspended,
+    Resumed,
+    Deleted,
+    PanUpdated,
+    ArtUpdated,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkTokenLifecycleEvent {
+    pub event_id: String,
+    pub event_type: NetworkTokenLifecycleEventType,
+    pub card_reference: String, //network token requestor ref id
+    pub new_card_reference: Option<String>,
+    pub token_expiry_month: Option<Secret<String>>,
+    pub token_expiry_year: Option<Secret<String>>,
+    pub token_last_four: Option<String>,
+    /// Time at which the network token service created the event
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub timestamp: Option<time::PrimitiveDateTime>,
+}
@@ -1,4 +1,5 @@
 pub mod address;
+pub mod analytics_alert_rule;
 pub mod api_keys;
 pub mod authentication;
 pub mod authorization;
@@ -9,7 +10,9 @@ pub mod business_profile;
 pub mod callback_mapper;
 pub mod capture;
 pub mod cards_info;
+pub mod change_request;
 pub mod configs;
+pub mod connector_fee_schedule;
 pub mod customers;
 pub mod dashboard_metadata;
 pub mod dispute;
@@ -43,6 +46,7 @@ pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod vault_migration;
 
 pub use diesel_models::{
     process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
@@ -63,17 +67,20 @@ pub use hyperswitch_domain_models::{
     },
     routing::{
         PaymentRoutingInfo, PaymentRoutingInfoInner, PreRoutingConnectorChoice, RoutingData,
+        StaticRoutingDecision,
     },
 };
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
-    capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
+    address::*, analytics_alert_rule::*, api_keys::*, authentication::*, authorization::*,
+    blocklist::*, blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*,
+    callback_mapper::*, capture::*, cards_info::*, change_request::*, configs::*,
+    connector_fee_schedule::*, customers::*, dashboard_metadata::*, dispute::*,
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
     generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
     merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
     process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
     unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    vault_migration::*,
 };
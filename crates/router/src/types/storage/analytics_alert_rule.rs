@@ -0,0 +1,4 @@
+pub use diesel_models::analytics_alert_rule::{
+    AnalyticsAlertRule, AnalyticsAlertRuleNew, AnalyticsAlertRuleUpdate,
+    AnalyticsAlertRuleUpdateInternal, AnalyticsAlertTrackingData,
+};
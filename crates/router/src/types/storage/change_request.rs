@@ -0,0 +1,3 @@
+pub use diesel_models::change_request::{
+    ChangeRequest, ChangeRequestNew, ChangeRequestUpdate, ChangeRequestUpdateInternal,
+};
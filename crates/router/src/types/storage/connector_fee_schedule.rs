@@ -0,0 +1,4 @@
+pub use diesel_models::connector_fee_schedule::{
+    ConnectorFeeSchedule, ConnectorFeeScheduleNew, ConnectorFeeScheduleUpdate,
+    ConnectorFeeScheduleUpdateInternal,
+};
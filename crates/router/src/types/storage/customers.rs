@@ -1,5 +1,26 @@
-pub use diesel_models::customers::{Customer, CustomerNew, CustomerUpdateInternal};
+pub use diesel_models::{
+    customer_merge_redirect::{CustomerMergeRedirect, CustomerMergeRedirectNew},
+    customers::{Customer, CustomerNew, CustomerUpdateInternal},
+};
 
 #[cfg(feature = "v2")]
 pub use crate::types::domain::CustomerGeneralUpdate;
 pub use crate::types::domain::CustomerUpdate;
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DataRetentionTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub retention_period_in_days: u16,
+    /// Payments created up to this time have been redacted in an earlier run
+    pub redacted_until: Option<time::PrimitiveDateTime>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CustomerDataExportTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    /// The file the export is stored in once it is assembled
+    pub file_id: String,
+}
@@ -169,3 +169,12 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct CardUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub expiry_window_in_days: u16,
+    pub run_interval_in_hours: u16,
+    pub last_run_at: Option<time::PrimitiveDateTime>,
+}
@@ -0,0 +1,23 @@
+use api_models::locker_migration::{
+    VaultMigrationDestination, VaultMigrationOperation, VaultMigrationProgress,
+};
+use common_utils::id_type;
+pub use diesel_models::vault_migration_entry::{
+    VaultMigrationEntry, VaultMigrationEntryNew, VaultMigrationEntryUpdate,
+    VaultMigrationEntryUpdateInternal,
+};
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct VaultMigrationTrackingData {
+    pub migration_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub operation: VaultMigrationOperation,
+    pub dry_run: bool,
+    pub batch_size: u16,
+    #[serde(default)]
+    pub destination: VaultMigrationDestination,
+    /// Identifier of the last processed payment method (or entry, for a rollback), after which
+    /// the next batch is fetched
+    pub starting_after_payment_method_id: Option<String>,
+    pub progress: VaultMigrationProgress,
+}
@@ -240,7 +240,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             }
             api_enums::Connector::Cybersource => Self::Cybersource,
             api_enums::Connector::Datatrans => Self::Datatrans,
-			api_enums::Connector::Demopay => Self::Demopay,
+            api_enums::Connector::Demopay => Self::Demopay,
             api_enums::Connector::Deutschebank => Self::Deutschebank,
             api_enums::Connector::Digitalvirgo => Self::Digitalvirgo,
             api_enums::Connector::Dlocal => Self::Dlocal,
@@ -637,6 +637,9 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
         match value {
             storage_enums::RefundStatus::Success => Some(storage_enums::EventType::RefundSucceeded),
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
+            storage_enums::RefundStatus::PendingApproval => {
+                Some(storage_enums::EventType::RefundPendingApproval)
+            }
             api_enums::RefundStatus::ManualReview
             | api_enums::RefundStatus::Pending
             | api_enums::RefundStatus::TransactionFailure => None,
@@ -657,6 +660,9 @@ impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventTyp
             }
             storage_enums::PayoutStatus::Expired => Some(storage_enums::EventType::PayoutExpired),
             storage_enums::PayoutStatus::Reversed => Some(storage_enums::EventType::PayoutReversed),
+            storage_enums::PayoutStatus::PendingApproval => {
+                Some(storage_enums::EventType::PayoutPendingApproval)
+            }
             storage_enums::PayoutStatus::Ineligible
             | storage_enums::PayoutStatus::Pending
             | storage_enums::PayoutStatus::RequiresCreation
@@ -1253,43 +1253,18 @@ pub async fn trigger_refund_outgoing_webhook(
         enums::RefundStatus::Success
             | enums::RefundStatus::Failure
             | enums::RefundStatus::TransactionFailure
+            | enums::RefundStatus::PendingApproval
     ) {
-        let event_type = ForeignFrom::foreign_from(refund_status);
-        let refund_response: api_models::refunds::RefundResponse = refund.clone().foreign_into();
-        let key_manager_state = &(state).into();
-        let refund_id = refund_response.refund_id.clone();
-        let business_profile = state
-            .store
-            .find_business_profile_by_profile_id(
-                key_manager_state,
-                merchant_context.get_merchant_key_store(),
-                &profile_id,
-            )
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
-                id: profile_id.get_string_repr().to_owned(),
-            })?;
-        let cloned_state = state.clone();
-        let cloned_merchant_context = merchant_context.clone();
-        let primary_object_created_at = refund_response.created_at;
+        let event_type: Option<enums::EventType> = ForeignFrom::foreign_from(refund_status);
         if let Some(outgoing_event_type) = event_type {
-            tokio::spawn(
-                async move {
-                    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
-                        cloned_state,
-                        cloned_merchant_context,
-                        business_profile,
-                        outgoing_event_type,
-                        diesel_models::enums::EventClass::Refunds,
-                        refund_id.to_string(),
-                        diesel_models::enums::EventObjectType::RefundDetails,
-                        webhooks::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
-                        primary_object_created_at,
-                    ))
-                    .await
-                }
-                .in_current_span(),
-            );
+            trigger_refund_outgoing_webhook_with_event_type(
+                state,
+                merchant_context,
+                refund,
+                profile_id,
+                outgoing_event_type,
+            )
+            .await?;
         } else {
             logger::warn!("Outgoing webhook not sent because of missing event type status mapping");
         };
@@ -1297,6 +1272,51 @@ pub async fn trigger_refund_outgoing_webhook(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+pub async fn trigger_refund_outgoing_webhook_with_event_type(
+    state: &SessionState,
+    merchant_context: &domain::MerchantContext,
+    refund: &diesel_models::Refund,
+    profile_id: id_type::ProfileId,
+    event_type: enums::EventType,
+) -> RouterResult<()> {
+    let refund_response: api_models::refunds::RefundResponse = refund.clone().foreign_into();
+    let key_manager_state = &(state).into();
+    let refund_id = refund_response.refund_id.clone();
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            merchant_context.get_merchant_key_store(),
+            &profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+    let cloned_state = state.clone();
+    let cloned_merchant_context = merchant_context.clone();
+    let primary_object_created_at = refund_response.created_at;
+    tokio::spawn(
+        async move {
+            Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+                cloned_state,
+                cloned_merchant_context,
+                business_profile,
+                event_type,
+                diesel_models::enums::EventClass::Refunds,
+                refund_id.to_string(),
+                diesel_models::enums::EventObjectType::RefundDetails,
+                webhooks::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+                primary_object_created_at,
+            ))
+            .await
+        }
+        .in_current_span(),
+    );
+    Ok(())
+}
+
 #[cfg(feature = "v2")]
 pub async fn trigger_refund_outgoing_webhook(
     state: &SessionState,
@@ -0,0 +1,866 @@
+use base64::{
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use common_utils::crypto::generate_cryptographically_secure_random_bytes;
+use diesel_models::{enums::UserStatus, user::webauthn_credential::UserWebauthnCredential};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use openssl::{
+    bn::BigNum,
+    ec::{EcGroup, EcKey},
+    hash::MessageDigest,
+    nid::Nid,
+    pkey::{PKey, Public},
+    rsa::Rsa,
+    sign::Verifier,
+    x509::X509,
+};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    db::user_role::ListUserRolesByUserIdPayload,
+    routes::SessionState,
+};
+
+/// WebAuthn encodes binary values as base64url, browsers and libraries differ on padding.
+const PASSKEY_BASE64_ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &base64::alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+const CLIENT_DATA_TYPE_CREATE: &str = "webauthn.create";
+const CLIENT_DATA_TYPE_GET: &str = "webauthn.get";
+
+const AUTHENTICATOR_DATA_MIN_LENGTH: usize = 37;
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+const FLAG_ATTESTED_CREDENTIAL_DATA: u8 = 0x40;
+const AAGUID_LENGTH: usize = 16;
+
+const ATTESTATION_FORMAT_NONE: &str = "none";
+const ATTESTATION_FORMAT_PACKED: &str = "packed";
+
+// COSE key parameters and values, as registered with IANA
+const COSE_KEY_TYPE: i64 = 1;
+const COSE_KEY_ALGORITHM: i64 = 3;
+const COSE_KEY_TYPE_EC2: i64 = 2;
+const COSE_KEY_TYPE_RSA: i64 = 3;
+const COSE_EC2_CURVE: i64 = -1;
+const COSE_EC2_X: i64 = -2;
+const COSE_EC2_Y: i64 = -3;
+const COSE_EC2_CURVE_P256: i64 = 1;
+const COSE_RSA_N: i64 = -1;
+const COSE_RSA_E: i64 = -2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PasskeyCeremony {
+    Registration,
+    Authentication,
+}
+
+impl PasskeyCeremony {
+    fn client_data_type(self) -> &'static str {
+        match self {
+            Self::Registration => CLIENT_DATA_TYPE_CREATE,
+            Self::Authentication => CLIENT_DATA_TYPE_GET,
+        }
+    }
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CollectedClientData {
+    #[serde(rename = "type")]
+    ceremony_type: String,
+    challenge: String,
+    origin: String,
+    #[serde(default)]
+    cross_origin: bool,
+}
+
+pub struct AttestedCredentialData {
+    pub credential_id: Vec<u8>,
+    /// DER encoded SubjectPublicKeyInfo of the credential public key
+    pub public_key: Vec<u8>,
+    pub algorithm: i32,
+}
+
+pub struct AuthenticatorData {
+    pub sign_count: u32,
+    pub user_verified: bool,
+    pub attested_credential_data: Option<AttestedCredentialData>,
+}
+
+pub struct AttestationObject {
+    pub format: String,
+    pub statement: Vec<(ciborium::Value, ciborium::Value)>,
+    pub authenticator_data: Vec<u8>,
+}
+
+pub fn encode(data: &[u8]) -> String {
+    PASSKEY_BASE64_ENGINE.encode(data)
+}
+
+pub fn decode(data: &str) -> UserResult<Vec<u8>> {
+    PASSKEY_BASE64_ENGINE
+        .decode(data)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to decode base64url passkey field")
+}
+
+pub fn generate_challenge() -> String {
+    encode(&generate_cryptographically_secure_random_bytes::<
+        { consts::user::PASSKEY_CHALLENGE_LENGTH },
+    >())
+}
+
+/// The user handle is an opaque identifier the authenticator stores alongside the credential.
+pub fn get_user_handle(user_id: &str) -> String {
+    encode(user_id.as_bytes())
+}
+
+pub fn get_rp_id(state: &SessionState) -> String {
+    state.conf.user.passkey_rp_id.clone()
+}
+
+pub fn verify_client_data(
+    state: &SessionState,
+    client_data_json: &[u8],
+    ceremony: PasskeyCeremony,
+    expected_challenge: &str,
+) -> UserResult<()> {
+    let client_data: CollectedClientData = serde_json::from_slice(client_data_json)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to parse client data json")?;
+
+    if client_data.ceremony_type != ceremony.client_data_type() {
+        return Err(UserErrors::InvalidPasskey)
+            .attach_printable("Client data type does not match the ceremony");
+    }
+
+    // Compare the decoded bytes so that padding differences do not cause a mismatch
+    if decode(&client_data.challenge)? != decode(expected_challenge)? {
+        return Err(UserErrors::InvalidPasskey).attach_printable("Challenge mismatch");
+    }
+
+    if client_data.cross_origin
+        || client_data.origin.trim_end_matches('/')
+            != state.conf.user.base_url.trim_end_matches('/')
+    {
+        return Err(UserErrors::InvalidPasskey).attach_printable(format!(
+            "Origin {} is not allowed for passkeys",
+            client_data.origin
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn parse_authenticator_data(
+    rp_id: &str,
+    authenticator_data: &[u8],
+) -> UserResult<AuthenticatorData> {
+    if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LENGTH {
+        return Err(UserErrors::InvalidPasskey).attach_printable("Authenticator data too short");
+    }
+
+    let expected_rp_id_hash = openssl::sha::sha256(rp_id.as_bytes());
+    if authenticator_data[..32] != expected_rp_id_hash {
+        return Err(UserErrors::InvalidPasskey).attach_printable("RP ID hash mismatch");
+    }
+
+    let flags = authenticator_data[32];
+    if flags & FLAG_USER_PRESENT == 0 {
+        return Err(UserErrors::InvalidPasskey).attach_printable("User presence flag not set");
+    }
+
+    let sign_count = u32::from_be_bytes(
+        authenticator_data[33..AUTHENTICATOR_DATA_MIN_LENGTH]
+            .try_into()
+            .change_context(UserErrors::InvalidPasskey)?,
+    );
+
+    let attested_credential_data = if flags & FLAG_ATTESTED_CREDENTIAL_DATA != 0 {
+        let id_length_start = AUTHENTICATOR_DATA_MIN_LENGTH + AAGUID_LENGTH;
+        let id_start = id_length_start + 2;
+        let id_length = authenticator_data
+            .get(id_length_start..id_start)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]).into())
+            .ok_or(UserErrors::InvalidPasskey)
+            .attach_printable("Attested credential data too short")?;
+        let credential_id = authenticator_data
+            .get(id_start..id_start + id_length)
+            .ok_or(UserErrors::InvalidPasskey)
+            .attach_printable("Attested credential id too short")?;
+
+        // The credential public key is the COSE key that follows the credential id
+        let mut cose_key_bytes = authenticator_data
+            .get(id_start + id_length..)
+            .unwrap_or_default();
+        let cose_key: ciborium::Value = ciborium::from_reader(&mut cose_key_bytes)
+            .change_context(UserErrors::InvalidPasskey)
+            .attach_printable("Failed to decode credential public key")?;
+        let (public_key, algorithm) = parse_cose_key(&cose_key)?;
+
+        Some(AttestedCredentialData {
+            credential_id: credential_id.to_vec(),
+            public_key,
+            algorithm,
+        })
+    } else {
+        None
+    };
+
+    Ok(AuthenticatorData {
+        sign_count,
+        user_verified: flags & FLAG_USER_VERIFIED != 0,
+        attested_credential_data,
+    })
+}
+
+fn get_entry<'a>(
+    entries: &'a [(ciborium::Value, ciborium::Value)],
+    is_label: impl Fn(&ciborium::Value) -> bool,
+) -> Option<&'a ciborium::Value> {
+    entries
+        .iter()
+        .find(|(label, _)| is_label(label))
+        .map(|(_, value)| value)
+}
+
+fn get_integer_label_entry(
+    entries: &[(ciborium::Value, ciborium::Value)],
+    label: i64,
+) -> Option<&ciborium::Value> {
+    get_entry(entries, |key| {
+        key.as_integer()
+            .and_then(|key| i64::try_from(key).ok())
+            .is_some_and(|key| key == label)
+    })
+}
+
+fn get_text_label_entry<'a>(
+    entries: &'a [(ciborium::Value, ciborium::Value)],
+    label: &str,
+) -> Option<&'a ciborium::Value> {
+    get_entry(entries, |key| key.as_text().is_some_and(|key| key == label))
+}
+
+fn as_i64(value: Option<&ciborium::Value>) -> Option<i64> {
+    value
+        .and_then(ciborium::Value::as_integer)
+        .and_then(|value| i64::try_from(value).ok())
+}
+
+fn as_big_num(value: Option<&ciborium::Value>) -> UserResult<BigNum> {
+    value
+        .and_then(ciborium::Value::as_bytes)
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Credential public key parameter missing")
+        .and_then(|bytes| BigNum::from_slice(bytes).change_context(UserErrors::InvalidPasskey))
+}
+
+/// Converts the COSE encoded credential public key to a DER encoded SubjectPublicKeyInfo,
+/// returning it along with its COSE algorithm identifier.
+fn parse_cose_key(cose_key: &ciborium::Value) -> UserResult<(Vec<u8>, i32)> {
+    let entries = cose_key
+        .as_map()
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Credential public key is not a COSE key")?;
+    let algorithm = as_i64(get_integer_label_entry(entries, COSE_KEY_ALGORITHM))
+        .and_then(|algorithm| i32::try_from(algorithm).ok())
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Credential public key algorithm missing")?;
+
+    let public_key = match (
+        as_i64(get_integer_label_entry(entries, COSE_KEY_TYPE)),
+        algorithm,
+    ) {
+        (Some(COSE_KEY_TYPE_EC2), consts::user::PASSKEY_ALGORITHM_ES256) => {
+            if as_i64(get_integer_label_entry(entries, COSE_EC2_CURVE)) != Some(COSE_EC2_CURVE_P256)
+            {
+                return Err(UserErrors::InvalidPasskey)
+                    .attach_printable("Unsupported curve of passkey public key");
+            }
+            let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1)
+                .change_context(UserErrors::InternalServerError)?;
+            EcKey::from_public_key_affine_coordinates(
+                &group,
+                &as_big_num(get_integer_label_entry(entries, COSE_EC2_X))?,
+                &as_big_num(get_integer_label_entry(entries, COSE_EC2_Y))?,
+            )
+            .and_then(PKey::from_ec_key)
+            .change_context(UserErrors::InvalidPasskey)
+            .attach_printable("Invalid passkey public key")?
+        }
+        (Some(COSE_KEY_TYPE_RSA), consts::user::PASSKEY_ALGORITHM_RS256) => {
+            Rsa::from_public_components(
+                as_big_num(get_integer_label_entry(entries, COSE_RSA_N))?,
+                as_big_num(get_integer_label_entry(entries, COSE_RSA_E))?,
+            )
+            .and_then(PKey::from_rsa)
+            .change_context(UserErrors::InvalidPasskey)
+            .attach_printable("Invalid passkey public key")?
+        }
+        _ => {
+            return Err(UserErrors::InvalidPasskey)
+                .attach_printable(format!("Unsupported passkey algorithm {algorithm}"))
+        }
+    };
+
+    let public_key = public_key
+        .public_key_to_der()
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok((public_key, algorithm))
+}
+
+pub fn parse_attestation_object(attestation_object: &[u8]) -> UserResult<AttestationObject> {
+    let attestation_object: ciborium::Value = ciborium::from_reader(attestation_object)
+        .change_context(UserErrors::InvalidPasskey)
+        .attach_printable("Failed to decode attestation object")?;
+    let entries = attestation_object
+        .as_map()
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Attestation object is not a map")?;
+
+    let format = get_text_label_entry(entries, "fmt")
+        .and_then(ciborium::Value::as_text)
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Attestation format missing")?;
+    let statement = get_text_label_entry(entries, "attStmt")
+        .and_then(ciborium::Value::as_map)
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Attestation statement missing")?;
+    let authenticator_data = get_text_label_entry(entries, "authData")
+        .and_then(ciborium::Value::as_bytes)
+        .ok_or(UserErrors::InvalidPasskey)
+        .attach_printable("Authenticator data missing from attestation object")?;
+
+    Ok(AttestationObject {
+        format: format.to_owned(),
+        statement: statement.clone(),
+        authenticator_data: authenticator_data.clone(),
+    })
+}
+
+/// Verifies the attestation statement of a new credential. Attestation certificates are not
+/// validated against trust anchors, as authenticators are not restricted to certified models,
+/// but a `packed` statement has to be signed by its certificate or by the credential itself.
+pub fn verify_attestation(
+    attestation_object: &AttestationObject,
+    attested_credential_data: &AttestedCredentialData,
+    client_data_json: &[u8],
+) -> UserResult<()> {
+    match attestation_object.format.as_str() {
+        ATTESTATION_FORMAT_NONE => attestation_object
+            .statement
+            .is_empty()
+            .then_some(())
+            .ok_or(UserErrors::InvalidPasskey)
+            .attach_printable("Attestation statement of format none is not empty"),
+        ATTESTATION_FORMAT_PACKED => {
+            let statement = &attestation_object.statement;
+            let algorithm = as_i64(get_text_label_entry(statement, "alg"))
+                .ok_or(UserErrors::InvalidPasskey)
+                .attach_printable("Attestation algorithm missing")?;
+            let signature = get_text_label_entry(statement, "sig")
+                .and_then(ciborium::Value::as_bytes)
+                .ok_or(UserErrors::InvalidPasskey)
+                .attach_printable("Attestation signature missing")?;
+
+            let public_key = match get_text_label_entry(statement, "x5c") {
+                Some(certificates) => {
+                    let certificate = certificates
+                        .as_array()
+                        .and_then(|certificates| certificates.first())
+                        .and_then(ciborium::Value::as_bytes)
+                        .ok_or(UserErrors::InvalidPasskey)
+                        .attach_printable("Attestation certificate missing")?;
+                    X509::from_der(certificate)
+                        .and_then(|certificate| certificate.public_key())
+                        .change_context(UserErrors::InvalidPasskey)
+                        .attach_printable("Invalid attestation certificate")?
+                }
+                // Self attestation is signed with the credential private key
+                None => {
+                    if algorithm != i64::from(attested_credential_data.algorithm) {
+                        return Err(UserErrors::InvalidPasskey).attach_printable(
+                            "Self attestation algorithm does not match the credential",
+                        );
+                    }
+                    PKey::public_key_from_der(&attested_credential_data.public_key)
+                        .change_context(UserErrors::InternalServerError)?
+                }
+            };
+
+            verify_signature(
+                &public_key,
+                &attestation_object.authenticator_data,
+                client_data_json,
+                signature,
+            )
+            .attach_printable("Attestation signature verification failed")
+        }
+        format => Err(UserErrors::InvalidPasskey)
+            .attach_printable(format!("Unsupported attestation format {format}")),
+    }
+}
+
+pub fn verify_assertion_signature(
+    public_key: &[u8],
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    signature: &[u8],
+) -> UserResult<()> {
+    let public_key = PKey::public_key_from_der(public_key)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse stored passkey public key")?;
+
+    verify_signature(&public_key, authenticator_data, client_data_json, signature)
+        .attach_printable("Passkey signature verification failed")
+}
+
+/// Attestations and assertions both sign the authenticator data followed by the hash of the
+/// client data
+fn verify_signature(
+    public_key: &PKey<Public>,
+    authenticator_data: &[u8],
+    client_data_json: &[u8],
+    signature: &[u8],
+) -> UserResult<()> {
+    let mut verifier = Verifier::new(MessageDigest::sha256(), public_key)
+        .change_context(UserErrors::InternalServerError)?;
+    verifier
+        .update(authenticator_data)
+        .change_context(UserErrors::InternalServerError)?;
+    verifier
+        .update(&openssl::sha::sha256(client_data_json))
+        .change_context(UserErrors::InternalServerError)?;
+
+    verifier
+        .verify(signature)
+        .change_context(UserErrors::InvalidPasskey)?
+        .then_some(())
+        .ok_or(UserErrors::InvalidPasskey.into())
+}
+
+/// Authenticators that do not implement counters always report zero, any other
+/// authenticator has to report a strictly increasing value or it may have been cloned.
+pub fn validate_sign_count(stored_sign_count: i64, new_sign_count: u32) -> UserResult<()> {
+    if (stored_sign_count != 0 || new_sign_count != 0)
+        && i64::from(new_sign_count) <= stored_sign_count
+    {
+        return Err(UserErrors::InvalidPasskey)
+            .attach_printable("Passkey sign count did not increase");
+    }
+    Ok(())
+}
+
+fn get_challenge_key(prefix: &str, identifier: &str) -> String {
+    format!("{prefix}{identifier}")
+}
+
+pub async fn insert_challenge_in_redis(
+    state: &SessionState,
+    prefix: &str,
+    identifier: &str,
+    challenge: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_challenge_key(prefix, identifier).into(),
+            challenge,
+            consts::user::REDIS_PASSKEY_CHALLENGE_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Challenges are single use, so they are read and removed from redis atomically, and concurrent
+/// requests cannot both use the same challenge.
+pub async fn get_and_delete_challenge_from_redis(
+    state: &SessionState,
+    prefix: &str,
+    identifier: &str,
+) -> UserResult<String> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_and_delete_key::<Option<String>>(&get_challenge_key(prefix, identifier).into())
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .ok_or(UserErrors::PasskeyChallengeNotFound.into())
+}
+
+pub async fn check_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .exists::<()>(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn insert_passkey_in_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .set_key_with_expiry(
+            &key.as_str().into(),
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.two_factor_auth_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn delete_passkey_from_redis(state: &SessionState, user_id: &str) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    let key = format!("{}{}", consts::user::REDIS_PASSKEY_PREFIX, user_id);
+    redis_conn
+        .delete_key(&key.into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+fn get_passkey_attempts_key(user_id: &str) -> String {
+    format!("{}{}", consts::user::REDIS_PASSKEY_ATTEMPTS_PREFIX, user_id)
+}
+
+pub async fn insert_passkey_attempts_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    user_passkey_attempts: u8,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_passkey_attempts_key(user_id).into(),
+            user_passkey_attempts,
+            consts::user::REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<u8> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_key::<Option<u8>>(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|v| v.unwrap_or(0))
+}
+
+pub async fn delete_passkey_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .delete_key(&get_passkey_attempts_key(user_id).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+/// Sign in is not authenticated, so its attempts are counted per client of the user. Failed
+/// attempts from one client do not lock the user out of signing in from other clients.
+fn get_passkey_signin_attempts_key(user_id: &str, client_ip: Option<&str>) -> String {
+    format!(
+        "{}{}_{}",
+        consts::user::REDIS_PASSKEY_SIGNIN_ATTEMPTS_PREFIX,
+        user_id,
+        client_ip.unwrap_or("unknown")
+    )
+}
+
+pub async fn insert_passkey_signin_attempts_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    client_ip: Option<&str>,
+    user_passkey_attempts: u8,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .set_key_with_expiry(
+            &get_passkey_signin_attempts_key(user_id, client_ip).into(),
+            user_passkey_attempts,
+            consts::user::REDIS_PASSKEY_ATTEMPTS_TTL_IN_SECS,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+pub async fn get_passkey_signin_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+    client_ip: Option<&str>,
+) -> UserResult<u8> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .get_key::<Option<u8>>(&get_passkey_signin_attempts_key(user_id, client_ip).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|v| v.unwrap_or(0))
+}
+
+pub async fn delete_passkey_signin_attempts_from_redis(
+    state: &SessionState,
+    user_id: &str,
+    client_ip: Option<&str>,
+) -> UserResult<()> {
+    let redis_conn = super::get_redis_connection_for_global_tenant(state)?;
+    redis_conn
+        .delete_key(&get_passkey_signin_attempts_key(user_id, client_ip).into())
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .map(|_| ())
+}
+
+pub fn decode_secret(data: &Secret<String>) -> UserResult<Vec<u8>> {
+    decode(data.peek())
+}
+
+pub async fn list_passkeys_for_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<Vec<UserWebauthnCredential>> {
+    state
+        .global_store
+        .list_user_webauthn_credentials_by_user_id(user_id)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+/// Admins can be mandated to use a passkey as their second factor, TOTP and recovery codes
+/// are not sufficient for them in that case.
+pub async fn is_passkey_required_for_user(
+    state: &SessionState,
+    user_id: &str,
+    tenant_id: Option<&common_utils::id_type::TenantId>,
+) -> UserResult<bool> {
+    if !state.conf.user.force_passkey_for_admins {
+        return Ok(false);
+    }
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: tenant_id.unwrap_or(&state.tenant.tenant_id),
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(user_roles.iter().any(|user_role| {
+        [
+            common_utils::consts::ROLE_ID_TENANT_ADMIN,
+            common_utils::consts::ROLE_ID_ORGANIZATION_ADMIN,
+            consts::user_role::ROLE_ID_MERCHANT_ADMIN,
+        ]
+        .contains(&user_role.role_id.as_str())
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::as_conversions)]
+    use openssl::{pkey::Private, sign::Signer};
+
+    use super::*;
+
+    const RP_ID: &str = "localhost";
+    const CREDENTIAL_ID: &[u8] = b"credential";
+    const CLIENT_DATA_JSON: &[u8] = b"{\"type\":\"webauthn.create\"}";
+
+    fn generate_key() -> PKey<Private> {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap()
+    }
+
+    fn get_cose_key(key: &PKey<Private>) -> Vec<u8> {
+        let ec_key = key.ec_key().unwrap();
+        let mut x = openssl::bn::BigNum::new().unwrap();
+        let mut y = openssl::bn::BigNum::new().unwrap();
+        let mut context = openssl::bn::BigNumContext::new().unwrap();
+        ec_key
+            .public_key()
+            .affine_coordinates(ec_key.group(), &mut x, &mut y, &mut context)
+            .unwrap();
+
+        let cose_key = ciborium::Value::Map(vec![
+            (COSE_KEY_TYPE.into(), COSE_KEY_TYPE_EC2.into()),
+            (
+                COSE_KEY_ALGORITHM.into(),
+                consts::user::PASSKEY_ALGORITHM_ES256.into(),
+            ),
+            (COSE_EC2_CURVE.into(), COSE_EC2_CURVE_P256.into()),
+            (COSE_EC2_X.into(), x.to_vec_padded(32).unwrap().into()),
+            (COSE_EC2_Y.into(), y.to_vec_padded(32).unwrap().into()),
+        ]);
+        let mut encoded = Vec::new();
+        ciborium::into_writer(&cose_key, &mut encoded).unwrap();
+        encoded
+    }
+
+    fn get_authenticator_data(rp_id: &str, flags: u8, cose_key: Option<&[u8]>) -> Vec<u8> {
+        let mut authenticator_data = openssl::sha::sha256(rp_id.as_bytes()).to_vec();
+        authenticator_data.push(flags);
+        authenticator_data.extend_from_slice(&1u32.to_be_bytes());
+        if let Some(cose_key) = cose_key {
+            authenticator_data.extend_from_slice(&[0; AAGUID_LENGTH]);
+            authenticator_data.extend_from_slice(&(CREDENTIAL_ID.len() as u16).to_be_bytes());
+            authenticator_data.extend_from_slice(CREDENTIAL_ID);
+            authenticator_data.extend_from_slice(cose_key);
+        }
+        authenticator_data
+    }
+
+    fn sign(key: &PKey<Private>, authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+        let mut signer = Signer::new(MessageDigest::sha256(), key).unwrap();
+        signer.update(authenticator_data).unwrap();
+        signer
+            .update(&openssl::sha::sha256(client_data_json))
+            .unwrap();
+        signer.sign_to_vec().unwrap()
+    }
+
+    #[test]
+    fn test_attested_credential_public_key_is_parsed_from_cose_key() {
+        let key = generate_key();
+        let authenticator_data = get_authenticator_data(
+            RP_ID,
+            FLAG_USER_PRESENT | FLAG_USER_VERIFIED | FLAG_ATTESTED_CREDENTIAL_DATA,
+            Some(&get_cose_key(&key)),
+        );
+
+        let parsed = parse_authenticator_data(RP_ID, &authenticator_data).unwrap();
+        let attested_credential_data = parsed.attested_credential_data.unwrap();
+
+        assert!(parsed.user_verified);
+        assert_eq!(parsed.sign_count, 1);
+        assert_eq!(attested_credential_data.credential_id, CREDENTIAL_ID);
+        assert_eq!(
+            attested_credential_data.algorithm,
+            consts::user::PASSKEY_ALGORITHM_ES256
+        );
+        assert_eq!(
+            attested_credential_data.public_key,
+            key.public_key_to_der().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_authenticator_data_without_attested_credential_data() {
+        let authenticator_data = get_authenticator_data(RP_ID, FLAG_USER_PRESENT, None);
+
+        let parsed = parse_authenticator_data(RP_ID, &authenticator_data).unwrap();
+
+        assert!(!parsed.user_verified);
+        assert!(parsed.attested_credential_data.is_none());
+    }
+
+    #[test]
+    fn test_authenticator_data_of_other_relying_party_is_rejected() {
+        let authenticator_data = get_authenticator_data("example.com", FLAG_USER_PRESENT, None);
+
+        assert!(parse_authenticator_data(RP_ID, &authenticator_data).is_err());
+    }
+
+    #[test]
+    fn test_packed_self_attestation_signature_is_verified() {
+        let key = generate_key();
+        let authenticator_data = get_authenticator_data(
+            RP_ID,
+            FLAG_USER_PRESENT | FLAG_USER_VERIFIED | FLAG_ATTESTED_CREDENTIAL_DATA,
+            Some(&get_cose_key(&key)),
+        );
+        let attested_credential_data = parse_authenticator_data(RP_ID, &authenticator_data)
+            .unwrap()
+            .attested_credential_data
+            .unwrap();
+        let attestation_object = AttestationObject {
+            format: ATTESTATION_FORMAT_PACKED.to_string(),
+            statement: vec![
+                ("alg".into(), consts::user::PASSKEY_ALGORITHM_ES256.into()),
+                (
+                    "sig".into(),
+                    sign(&key, &authenticator_data, CLIENT_DATA_JSON).into(),
+                ),
+            ],
+            authenticator_data,
+        };
+
+        assert!(verify_attestation(
+            &attestation_object,
+            &attested_credential_data,
+            CLIENT_DATA_JSON
+        )
+        .is_ok());
+        assert!(verify_attestation(
+            &attestation_object,
+            &attested_credential_data,
+            b"{\"type\":\"webauthn.get\"}"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_none_attestation_with_statement_is_rejected() {
+        let key = generate_key();
+        let authenticator_data = get_authenticator_data(
+            RP_ID,
+            FLAG_USER_PRESENT | FLAG_ATTESTED_CREDENTIAL_DATA,
+            Some(&get_cose_key(&key)),
+        );
+        let attested_credential_data = parse_authenticator_data(RP_ID, &authenticator_data)
+            .unwrap()
+            .attested_credential_data
+            .unwrap();
+        let mut attestation_object = AttestationObject {
+            format: ATTESTATION_FORMAT_NONE.to_string(),
+            statement: Vec::new(),
+            authenticator_data,
+        };
+
+        assert!(verify_attestation(
+            &attestation_object,
+            &attested_credential_data,
+            CLIENT_DATA_JSON
+        )
+        .is_ok());
+
+        attestation_object.statement = vec![("sig".into(), ciborium::Value::Bytes(vec![0; 64]))];
+        assert!(verify_attestation(
+            &attestation_object,
+            &attested_credential_data,
+            CLIENT_DATA_JSON
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_sign_count_must_increase_unless_unsupported() {
+        assert!(validate_sign_count(0, 0).is_ok());
+        assert!(validate_sign_count(5, 6).is_ok());
+        assert!(validate_sign_count(5, 5).is_err());
+        assert!(validate_sign_count(5, 0).is_err());
+    }
+}
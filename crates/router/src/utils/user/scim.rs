@@ -0,0 +1,710 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::user::scim as scim_api;
+use common_enums::{EntityType, UserStatus};
+use common_utils::{date_time, id_type, pii};
+use diesel_models::{
+    user::scim::{ScimGroup, ScimUser},
+    user_role::UserRole,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+
+use crate::{
+    consts::user as user_consts,
+    core::errors::{StorageErrorExt, UserErrors, UserResult},
+    db::user_role::ListUserRolesByUserIdPayload,
+    routes::SessionState,
+    services::{authentication as auth, authorization::roles::RoleInfo},
+    types::domain,
+};
+
+pub struct ScimPagination {
+    pub start_index: usize,
+    pub count: usize,
+}
+
+impl From<&scim_api::ScimListQuery> for ScimPagination {
+    fn from(query: &scim_api::ScimListQuery) -> Self {
+        Self {
+            start_index: query.start_index.unwrap_or(1).max(1),
+            count: query
+                .count
+                .unwrap_or(user_consts::SCIM_DEFAULT_PAGE_SIZE)
+                .min(user_consts::SCIM_MAX_PAGE_SIZE),
+        }
+    }
+}
+
+impl ScimPagination {
+    pub fn paginate<T>(&self, items: Vec<T>) -> Vec<T> {
+        items
+            .into_iter()
+            .skip(self.start_index - 1)
+            .take(self.count)
+            .collect()
+    }
+
+    pub fn get_limit_and_offset(&self) -> UserResult<(i64, i64)> {
+        let limit = i64::try_from(self.count).change_context(UserErrors::InternalServerError)?;
+        let offset =
+            i64::try_from(self.start_index - 1).change_context(UserErrors::InternalServerError)?;
+        Ok((limit, offset))
+    }
+}
+
+/// Parses a SCIM filter of the form `attribute eq "value"`, which is the only filter
+/// identity providers send while reconciling users and groups
+pub fn parse_eq_filter(filter: &str) -> UserResult<(String, String)> {
+    let mut parts = filter.trim().splitn(3, char::is_whitespace);
+    let (Some(attribute), Some(operator), Some(value)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(UserErrors::InvalidScimRequest(format!("Unsupported filter: {filter}")).into());
+    };
+
+    if !operator.eq_ignore_ascii_case("eq") {
+        return Err(UserErrors::InvalidScimRequest(format!(
+            "Unsupported filter operator: {operator}"
+        ))
+        .into());
+    }
+
+    let value = value.trim();
+    let value = value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value);
+
+    Ok((attribute.to_owned(), value.to_owned()))
+}
+
+pub fn get_email_from_request(
+    request: &scim_api::ScimUserRequest,
+) -> UserResult<domain::UserEmail> {
+    let email = request.emails.as_ref().and_then(|emails| {
+        emails
+            .iter()
+            .find(|email| email.primary.unwrap_or(false))
+            .or(emails.first())
+            .map(|email| email.value.clone())
+    });
+
+    match email {
+        Some(email) => domain::UserEmail::from_pii_email(email),
+        None => domain::UserEmail::new(Secret::new(request.user_name.peek().to_owned())),
+    }
+}
+
+pub fn get_name_from_request(
+    request: &scim_api::ScimUserRequest,
+    email: &domain::UserEmail,
+) -> UserResult<domain::UserName> {
+    let name = request
+        .display_name
+        .clone()
+        .or_else(|| request.name.as_ref().and_then(get_name_from_scim_name));
+
+    match name {
+        Some(name) => domain::UserName::new(name),
+        None => domain::UserName::try_from(email.get_inner().clone()),
+    }
+}
+
+pub fn get_name_from_scim_name(name: &scim_api::ScimName) -> Option<Secret<String>> {
+    name.formatted.clone().or_else(|| {
+        let full_name = [&name.given_name, &name.family_name]
+            .into_iter()
+            .flatten()
+            .map(|part| part.peek().trim())
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!full_name.is_empty()).then(|| Secret::new(full_name))
+    })
+}
+
+pub fn get_user_response(
+    user: &domain::UserFromStorage,
+    scim_user: &ScimUser,
+    groups: &[ScimGroup],
+) -> scim_api::ScimUserResponse {
+    let email = user.get_email();
+    let name = user.get_name();
+
+    scim_api::ScimUserResponse {
+        schemas: vec![user_consts::SCIM_USER_SCHEMA.to_string()],
+        id: scim_user.user_id.clone(),
+        external_id: scim_user.external_id.clone(),
+        user_name: email.clone(),
+        name: scim_api::ScimName {
+            formatted: Some(name.clone()),
+            given_name: None,
+            family_name: None,
+        },
+        display_name: name,
+        active: scim_user.active,
+        emails: vec![scim_api::ScimEmail {
+            value: email,
+            primary: Some(true),
+            email_type: Some("work".to_string()),
+        }],
+        groups: groups
+            .iter()
+            .filter(|group| group.members.contains(&scim_user.user_id))
+            .map(|group| scim_api::ScimGroupMember {
+                value: group.group_id.clone(),
+                display: Some(group.display_name.clone()),
+            })
+            .collect(),
+        meta: scim_api::ScimMeta {
+            resource_type: "User".to_string(),
+            created: scim_user.created_at,
+            last_modified: scim_user.last_modified_at,
+        },
+    }
+}
+
+pub fn get_group_response(
+    group: ScimGroup,
+    users: &HashMap<String, domain::UserFromStorage>,
+) -> scim_api::ScimGroupResponse {
+    let members = group
+        .members
+        .iter()
+        .map(|user_id| scim_api::ScimGroupMember {
+            value: user_id.clone(),
+            display: users
+                .get(user_id)
+                .map(|user| user.get_email().peek().to_owned()),
+        })
+        .collect();
+
+    scim_api::ScimGroupResponse {
+        schemas: vec![
+            user_consts::SCIM_GROUP_SCHEMA.to_string(),
+            user_consts::SCIM_GROUP_ROLE_MAPPING_SCHEMA.to_string(),
+        ],
+        id: group.group_id,
+        display_name: group.display_name,
+        external_id: group.external_id,
+        members,
+        role_mapping: scim_api::ScimGroupRoleMapping {
+            role_id: group.role_id,
+            entity_type: group.entity_type,
+            merchant_id: group.merchant_id,
+            profile_id: group.profile_id,
+        },
+        meta: scim_api::ScimMeta {
+            resource_type: "Group".to_string(),
+            created: group.created_at,
+            last_modified: group.last_modified_at,
+        },
+    }
+}
+
+/// Returns the id of the user with the email, or `None` if the value is not a valid email or no
+/// such user exists
+pub async fn find_user_id_by_email(
+    state: &SessionState,
+    email: String,
+) -> UserResult<Option<String>> {
+    let Ok(email) = domain::UserEmail::new(Secret::new(email)) else {
+        return Ok(None);
+    };
+
+    match state.global_store.find_user_by_email(&email).await {
+        Ok(user) => Ok(Some(user.user_id)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error.change_context(UserErrors::InternalServerError)),
+    }
+}
+
+pub async fn get_users_by_user_ids(
+    state: &SessionState,
+    user_ids: Vec<String>,
+) -> UserResult<HashMap<String, domain::UserFromStorage>> {
+    if user_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    Ok(state
+        .global_store
+        .find_users_by_user_ids(user_ids)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|user| (user.user_id.clone(), domain::UserFromStorage::from(user)))
+        .collect())
+}
+
+pub async fn validate_group_role_mapping(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    role_mapping: &scim_api::ScimGroupRoleMapping,
+) -> UserResult<()> {
+    match (
+        role_mapping.entity_type,
+        &role_mapping.merchant_id,
+        &role_mapping.profile_id,
+    ) {
+        (EntityType::Organization, None, None) => {}
+        (EntityType::Merchant, Some(merchant_id), None) => {
+            validate_merchant_in_org(state, org_id, merchant_id).await?;
+        }
+        (EntityType::Profile, Some(merchant_id), Some(profile_id)) => {
+            let key_store = validate_merchant_in_org(state, org_id, merchant_id).await?;
+            state
+                .store
+                .find_business_profile_by_merchant_id_profile_id(
+                    &state.into(),
+                    &key_store,
+                    merchant_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(UserErrors::InvalidScimRequest(
+                    "Profile not found".to_string(),
+                ))?;
+        }
+        (EntityType::Tenant, _, _) => {
+            return Err(UserErrors::InvalidScimRequest(
+                "Tenant roles cannot be mapped to groups".to_string(),
+            )
+            .into());
+        }
+        (entity_type, _, _) => {
+            return Err(UserErrors::InvalidScimRequest(format!(
+                "Invalid merchant_id or profile_id for {entity_type} level role mapping"
+            ))
+            .into());
+        }
+    }
+
+    let role_info = RoleInfo::from_role_id_org_id_tenant_id(
+        state,
+        &role_mapping.role_id,
+        org_id,
+        &state.tenant.tenant_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleId)?;
+
+    if role_info.get_entity_type() != role_mapping.entity_type
+        || !role_info.is_invitable()
+        || role_info.is_internal()
+    {
+        return Err(UserErrors::InvalidScimRequest(format!(
+            "Role {} cannot be mapped to a {} level group",
+            role_mapping.role_id, role_mapping.entity_type
+        ))
+        .into());
+    }
+
+    Ok(())
+}
+
+async fn validate_merchant_in_org(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+) -> UserResult<domain::MerchantKeyStore> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(UserErrors::InvalidScimRequest(
+            "Merchant not found".to_string(),
+        ))?;
+
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(UserErrors::InvalidScimRequest(
+            "Merchant not found".to_string(),
+        ))?;
+
+    if merchant_account.get_org_id() != org_id {
+        return Err(UserErrors::InvalidScimRequest(
+            "Merchant does not belong to the organization".to_string(),
+        )
+        .into());
+    }
+
+    Ok(key_store)
+}
+
+fn get_group_entity_id(group: &ScimGroup) -> UserResult<String> {
+    match (group.entity_type, &group.merchant_id, &group.profile_id) {
+        (EntityType::Organization, _, _) => Ok(group.org_id.get_string_repr().to_owned()),
+        (EntityType::Merchant, Some(merchant_id), _) => {
+            Ok(merchant_id.get_string_repr().to_owned())
+        }
+        (EntityType::Profile, _, Some(profile_id)) => Ok(profile_id.get_string_repr().to_owned()),
+        _ => Err(report!(UserErrors::InternalServerError))
+            .attach_printable("Invalid role mapping stored for SCIM group"),
+    }
+}
+
+/// Roles granted through SCIM are created by the SCIM token, which distinguishes them from roles
+/// granted by invites in the dashboard
+pub fn is_scim_managed_role(user_role: &UserRole) -> bool {
+    user_role
+        .created_by
+        .strip_prefix(user_consts::SCIM_TOKEN_ID_PREFIX)
+        .is_some_and(|suffix| suffix.starts_with('_'))
+}
+
+/// Gives the user the role mapped to the group, unless the user already holds it
+pub async fn assign_group_role(
+    state: &SessionState,
+    group: &ScimGroup,
+    user_id: &str,
+    token_id: &str,
+) -> UserResult<()> {
+    let entity_id = get_group_entity_id(group)?;
+
+    let existing_user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: Some(&group.org_id),
+            merchant_id: group.merchant_id.as_ref(),
+            profile_id: group.profile_id.as_ref(),
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if existing_user_roles.iter().any(|user_role| {
+        user_role.role_id == group.role_id && user_role.entity_id.as_ref() == Some(&entity_id)
+    }) {
+        return Ok(());
+    }
+
+    if !existing_user_roles.is_empty() {
+        return Err(UserErrors::InvalidScimRequest(format!(
+            "User {user_id} already has a different role in the scope of group {}",
+            group.display_name
+        ))
+        .into());
+    }
+
+    let now = date_time::now();
+    let user_role = domain::NewUserRole {
+        user_id: user_id.to_owned(),
+        role_id: group.role_id.clone(),
+        status: UserStatus::Active,
+        created_by: token_id.to_owned(),
+        last_modified_by: token_id.to_owned(),
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    };
+    let tenant_id = state.tenant.tenant_id.clone();
+    let org_id = group.org_id.clone();
+
+    match (group.entity_type, &group.merchant_id, &group.profile_id) {
+        (EntityType::Organization, _, _) => {
+            user_role
+                .add_entity(domain::OrganizationLevel { tenant_id, org_id })
+                .insert_in_v2(state)
+                .await?;
+        }
+        (EntityType::Merchant, Some(merchant_id), _) => {
+            user_role
+                .add_entity(domain::MerchantLevel {
+                    tenant_id,
+                    org_id,
+                    merchant_id: merchant_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?;
+        }
+        (EntityType::Profile, Some(merchant_id), Some(profile_id)) => {
+            user_role
+                .add_entity(domain::ProfileLevel {
+                    tenant_id,
+                    org_id,
+                    merchant_id: merchant_id.clone(),
+                    profile_id: profile_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?;
+        }
+        _ => {
+            return Err(report!(UserErrors::InternalServerError))
+                .attach_printable("Invalid role mapping stored for SCIM group");
+        }
+    };
+
+    Ok(())
+}
+
+/// Removes the role mapped to the group from the user, unless another group still grants it
+pub async fn revoke_group_role(
+    state: &SessionState,
+    group: &ScimGroup,
+    user_id: &str,
+    other_groups: &[ScimGroup],
+) -> UserResult<()> {
+    let entity_id = get_group_entity_id(group)?;
+
+    let is_granted_by_other_group = other_groups.iter().any(|other_group| {
+        other_group.group_id != group.group_id
+            && other_group.role_id == group.role_id
+            && other_group.members.iter().any(|member| member == user_id)
+            && get_group_entity_id(other_group).is_ok_and(|other| other == entity_id)
+    });
+    if is_granted_by_other_group {
+        return Ok(());
+    }
+
+    let existing_user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: Some(&group.org_id),
+            merchant_id: None,
+            profile_id: None,
+            entity_id: Some(&entity_id),
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    if !existing_user_roles
+        .iter()
+        .any(|user_role| user_role.role_id == group.role_id && is_scim_managed_role(user_role))
+    {
+        return Ok(());
+    }
+
+    state
+        .global_store
+        .delete_user_roles_by_user_id_and_org_id(
+            user_id,
+            &state.tenant.tenant_id,
+            &group.org_id,
+            Some(&entity_id),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to revoke SCIM group role")?;
+
+    Ok(())
+}
+
+/// Removes the roles SCIM granted to the user in the organization and ends their active
+/// sessions, leaving roles granted through invites untouched
+pub async fn deactivate_user_in_org(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    user_id: &str,
+) -> UserResult<()> {
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            tenant_id: &state.tenant.tenant_id,
+            org_id: Some(org_id),
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: None,
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let entity_ids = user_roles
+        .iter()
+        .filter(|user_role| is_scim_managed_role(user_role))
+        .filter_map(|user_role| user_role.entity_id.clone())
+        .collect::<HashSet<_>>();
+    if entity_ids.is_empty() {
+        logger::info!("No SCIM managed roles found for deprovisioned SCIM user {user_id}");
+    }
+
+    for entity_id in entity_ids {
+        state
+            .global_store
+            .delete_user_roles_by_user_id_and_org_id(
+                user_id,
+                &state.tenant.tenant_id,
+                org_id,
+                Some(&entity_id),
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to revoke SCIM managed role")?;
+    }
+
+    auth::blacklist::insert_user_in_blacklist(state, user_id).await
+}
+
+/// Gives the user back the roles of every group they are a member of
+pub async fn reactivate_user_in_org(
+    state: &SessionState,
+    groups: &[ScimGroup],
+    user_id: &str,
+    token_id: &str,
+) -> UserResult<()> {
+    for group in groups
+        .iter()
+        .filter(|group| group.members.iter().any(|member| member == user_id))
+    {
+        assign_group_role(state, group, user_id, token_id).await?;
+    }
+    Ok(())
+}
+
+pub fn parse_bool_value(value: &serde_json::Value) -> UserResult<bool> {
+    match value {
+        serde_json::Value::Bool(value) => Ok(*value),
+        serde_json::Value::String(value) if value.eq_ignore_ascii_case("true") => Ok(true),
+        serde_json::Value::String(value) if value.eq_ignore_ascii_case("false") => Ok(false),
+        _ => Err(UserErrors::InvalidScimRequest("Expected a boolean value".to_string()).into()),
+    }
+}
+
+pub fn parse_string_value(value: &serde_json::Value) -> UserResult<String> {
+    value
+        .as_str()
+        .map(ToOwned::to_owned)
+        .ok_or(UserErrors::InvalidScimRequest("Expected a string value".to_string()).into())
+}
+
+pub fn parse_member_ids(value: &serde_json::Value) -> UserResult<Vec<String>> {
+    let members: Vec<scim_api::ScimGroupMember> = match value {
+        serde_json::Value::Array(_) => serde_json::from_value(value.clone()),
+        _ => serde_json::from_value(value.clone()).map(|member| vec![member]),
+    }
+    .map_err(|_| UserErrors::InvalidScimRequest("Invalid members value".to_string()))?;
+
+    Ok(members.into_iter().map(|member| member.value).collect())
+}
+
+/// Extracts the member id from a path of the form `members[value eq "<id>"]`
+pub fn parse_member_path_filter(path: &str) -> UserResult<Option<String>> {
+    let Some(filter) = path
+        .trim()
+        .strip_prefix("members[")
+        .and_then(|filter| filter.strip_suffix(']'))
+    else {
+        return Ok(None);
+    };
+
+    let (attribute, value) = parse_eq_filter(filter)?;
+    if attribute != "value" {
+        return Err(
+            UserErrors::InvalidScimRequest(format!("Unsupported member filter: {filter}")).into(),
+        );
+    }
+
+    Ok(Some(value))
+}
+
+pub fn matches_email(email: &pii::Email, value: &str) -> bool {
+    email.peek().eq_ignore_ascii_case(value)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::enums::UserRoleVersion;
+
+    use super::*;
+
+    fn get_user_role(created_by: &str) -> UserRole {
+        let now = date_time::now();
+        UserRole {
+            id: 1,
+            user_id: "user_id".to_string(),
+            merchant_id: None,
+            role_id: "org_admin".to_string(),
+            org_id: None,
+            status: UserStatus::Active,
+            created_by: created_by.to_string(),
+            last_modified_by: created_by.to_string(),
+            created_at: now,
+            last_modified: now,
+            profile_id: None,
+            entity_id: None,
+            entity_type: None,
+            version: UserRoleVersion::V2,
+            tenant_id: id_type::TenantId::try_from_string("public".to_string()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_parse_eq_filter() {
+        assert_eq!(
+            parse_eq_filter(r#"userName eq "user@example.com""#).unwrap(),
+            ("userName".to_string(), "user@example.com".to_string())
+        );
+        assert_eq!(
+            parse_eq_filter(r#"displayName EQ "Payments Team""#).unwrap(),
+            ("displayName".to_string(), "Payments Team".to_string())
+        );
+        assert!(parse_eq_filter(r#"userName co "user""#).is_err());
+        assert!(parse_eq_filter("userName").is_err());
+    }
+
+    #[test]
+    fn test_parse_member_path_filter() {
+        assert_eq!(
+            parse_member_path_filter(r#"members[value eq "user_1"]"#).unwrap(),
+            Some("user_1".to_string())
+        );
+        assert_eq!(parse_member_path_filter("members").unwrap(), None);
+        assert!(parse_member_path_filter(r#"members[display eq "user_1"]"#).is_err());
+    }
+
+    #[test]
+    fn test_scim_pagination() {
+        let pagination = ScimPagination::from(&scim_api::ScimListQuery {
+            filter: None,
+            start_index: Some(3),
+            count: Some(2),
+        });
+        assert_eq!(pagination.paginate((1..=10).collect()), vec![3, 4]);
+        assert_eq!(pagination.get_limit_and_offset().unwrap(), (2, 2));
+
+        let pagination = ScimPagination::from(&scim_api::ScimListQuery {
+            filter: None,
+            start_index: Some(0),
+            count: Some(user_consts::SCIM_MAX_PAGE_SIZE + 1),
+        });
+        assert_eq!(pagination.start_index, 1);
+        assert_eq!(pagination.count, user_consts::SCIM_MAX_PAGE_SIZE);
+        assert_eq!(pagination.get_limit_and_offset().unwrap().1, 0);
+    }
+
+    #[test]
+    fn test_is_scim_managed_role() {
+        let token_id = common_utils::generate_id(
+            user_consts::SCIM_TOKEN_LENGTH / 2,
+            user_consts::SCIM_TOKEN_ID_PREFIX,
+        );
+        assert!(is_scim_managed_role(&get_user_role(&token_id)));
+        assert!(!is_scim_managed_role(&get_user_role(
+            "0f8a4bc1-2d6e-4c1a-9b0e-3f3a1e9c5d21"
+        )));
+        assert!(!is_scim_managed_role(&get_user_role("scimitar_user")));
+    }
+}
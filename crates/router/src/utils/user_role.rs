@@ -1,6 +1,7 @@
 use std::{cmp, collections::HashSet};
 
-use common_enums::{EntityType, PermissionGroup};
+use common_enums::{EntityType, PermissionAction, PermissionGroup, Resource};
+use common_types::role::ResourcePermission;
 use common_utils::id_type;
 use diesel_models::{
     enums::{UserRoleVersion, UserStatus},
@@ -19,16 +20,11 @@ use crate::{
         user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
     },
     routes::SessionState,
-    services::authorization::{self as authz, roles},
+    services::authorization::{self as authz, permissions::PermissionActionExt, roles},
     types::domain,
 };
 
 pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
-    if groups.is_empty() {
-        return Err(report!(UserErrors::InvalidRoleOperation))
-            .attach_printable("Role groups cannot be empty");
-    }
-
     let unique_groups: HashSet<_> = groups.iter().copied().collect();
 
     if unique_groups.contains(&PermissionGroup::OrganizationManage)
@@ -46,6 +42,120 @@ pub fn validate_role_groups(groups: &[PermissionGroup]) -> UserResult<()> {
     Ok(())
 }
 
+pub fn validate_role_has_permissions(
+    groups: &[PermissionGroup],
+    resource_permissions: &[ResourcePermission],
+) -> UserResult<()> {
+    if groups.is_empty() && resource_permissions.is_empty() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Role groups and resource permissions cannot both be empty");
+    }
+
+    Ok(())
+}
+
+pub async fn validate_resource_permissions(
+    state: &SessionState,
+    resource_permissions: &[ResourcePermission],
+    role_entity_type: EntityType,
+    merchant_id: &id_type::MerchantId,
+) -> UserResult<()> {
+    let unique_resources: HashSet<_> = resource_permissions
+        .iter()
+        .map(|resource_permission| resource_permission.resource)
+        .collect();
+
+    if unique_resources.len() != resource_permissions.len() {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Duplicate resource found in resource permissions");
+    }
+
+    if unique_resources.contains(&Resource::InternalConnector) {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Invalid resource present in the custom role");
+    }
+
+    let mut requested_profile_ids = HashSet::new();
+    for resource_permission in resource_permissions {
+        if resource_permission.actions.is_empty() {
+            return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+                "No actions present for resource {:?}",
+                resource_permission.resource
+            ));
+        }
+
+        if let Some(action) = resource_permission
+            .actions
+            .iter()
+            .find(|action| !action.is_applicable_to(resource_permission.resource))
+        {
+            return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+                "Action {} is not applicable to resource {:?}",
+                action, resource_permission.resource
+            ));
+        }
+
+        let Some(constraints) = resource_permission.constraints.as_ref() else {
+            continue;
+        };
+
+        // Amount limits are only checked when creating payments and refunds
+        if constraints.max_amount.is_some()
+            && (!matches!(
+                resource_permission.resource,
+                Resource::Payment | Resource::Refund
+            ) || !resource_permission
+                .actions
+                .contains(&PermissionAction::Create))
+        {
+            return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+                "Amount limit is only supported with the create action on payments and refunds, \
+                 found for resource {:?}",
+                resource_permission.resource
+            ));
+        }
+
+        if let Some(profile_ids) = constraints.profile_ids.as_ref() {
+            if role_entity_type != EntityType::Merchant || profile_ids.is_empty() {
+                return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(
+                    "Profile restrictions are only allowed on merchant level roles",
+                );
+            }
+            requested_profile_ids.extend(profile_ids.iter().cloned());
+        }
+    }
+
+    if requested_profile_ids.is_empty() {
+        return Ok(());
+    }
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let merchant_profile_ids = state
+        .store
+        .list_profile_by_merchant_id(&state.into(), &key_store, merchant_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .into_iter()
+        .map(|profile| profile.get_id().to_owned())
+        .collect::<HashSet<_>>();
+
+    if !requested_profile_ids.is_subset(&merchant_profile_ids) {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Profile restriction contains profiles outside the merchant");
+    }
+
+    Ok(())
+}
+
 pub async fn validate_role_name(
     state: &SessionState,
     role_name: &domain::RoleName,
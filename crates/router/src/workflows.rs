@@ -1,7 +1,17 @@
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_alert;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod analytics_report;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+#[cfg(feature = "v1")]
+pub mod card_updater;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod customer_data_export;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod data_retention;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
@@ -10,4 +20,7 @@ pub mod refund_router;
 
 pub mod tokenized_data;
 
+#[cfg(feature = "v1")]
+pub mod vault_migration;
+
 pub mod revenue_recovery;
@@ -0,0 +1,113 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::analytics_alert,
+    db::StorageInterface,
+    errors as router_errors,
+    routes::{metrics, SessionState},
+    types::storage,
+};
+
+pub struct AnalyticsAlertWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: storage::AnalyticsAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsAlertTrackingData")?;
+
+        let alert_rule = match db
+            .find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.alert_rule_id,
+            )
+            .await
+        {
+            Ok(alert_rule) => alert_rule,
+            // The alert rule has been deleted, nothing is left to evaluate
+            Err(error) if error.current_context().is_db_not_found() => {
+                return db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await
+                    .map_err(Into::into);
+            }
+            Err(error) => return Err(error.into()),
+        };
+
+        let evaluation_interval_in_mins = alert_rule.evaluation_interval_in_mins;
+        if alert_rule.enabled {
+            analytics_alert::evaluate_alert_rule(state, alert_rule)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to evaluate alert rule");
+                    errors::ProcessTrackerError::EApiErrorResponse
+                })?;
+        }
+
+        schedule_next_evaluation(db, process, evaluation_interval_in_mins).await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> router_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // A failed evaluation must not stop the rule from being evaluated again on its next
+        // interval
+        logger::error!(%process.id, ?error, "Failed while evaluating alert rule");
+        let db = &*state.store;
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<storage::AnalyticsAlertTrackingData>("AnalyticsAlertTrackingData")
+        {
+            Ok(tracking_data) => {
+                let evaluation_interval_in_mins = db
+                    .find_analytics_alert_rule_by_merchant_id_alert_rule_id(
+                        &tracking_data.merchant_id,
+                        &tracking_data.alert_rule_id,
+                    )
+                    .await
+                    .map(|alert_rule| alert_rule.evaluation_interval_in_mins)
+                    .unwrap_or(
+                        crate::consts::DEFAULT_ANALYTICS_ALERT_EVALUATION_INTERVAL_IN_MINS.into(),
+                    );
+                schedule_next_evaluation(db, process, evaluation_interval_in_mins).await
+            }
+            Err(_) => consumer::consumer_error_handler(db.as_scheduler(), process, error).await,
+        }
+    }
+}
+
+async fn schedule_next_evaluation(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    evaluation_interval_in_mins: i32,
+) -> Result<(), errors::ProcessTrackerError> {
+    let schedule_time = common_utils::date_time::now()
+        .saturating_add(time::Duration::minutes(evaluation_interval_in_mins.into()));
+    db.as_scheduler()
+        .reset_process(process, schedule_time)
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(
+        1,
+        router_env::metric_attributes!(("flow", "AnalyticsAlert")),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::analytics_report::{self, AnalyticsReportTrackingData},
+    errors as router_errors,
+    routes::SessionState,
+    types::storage,
+};
+
+pub struct AnalyticsReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AnalyticsReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let tracking_data: AnalyticsReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AnalyticsReportTrackingData")?;
+
+        analytics_report::generate_and_store_report(state, &tracking_data)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to generate report");
+                errors::ProcessTrackerError::EApiErrorResponse
+            })?;
+
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> router_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
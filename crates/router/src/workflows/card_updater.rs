@@ -0,0 +1,100 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payment_methods::card_updater,
+    db::StorageInterface,
+    errors as router_errors,
+    routes::{metrics, SessionState},
+    types::storage::{self, payment_method::CardUpdaterTrackingData},
+};
+
+pub struct CardUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for CardUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: CardUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardUpdaterTrackingData")?;
+
+        let started_at = common_utils::date_time::now();
+        card_updater::run_card_updater(state, &tracking_data)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to run card updater");
+                errors::ProcessTrackerError::EApiErrorResponse
+            })?;
+
+        schedule_next_run(
+            db,
+            process,
+            CardUpdaterTrackingData {
+                last_run_at: Some(started_at),
+                ..tracking_data
+            },
+        )
+        .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> router_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // A failed run must not stop the card updater from running on its next interval. The
+        // last run time is left unchanged, so that expiring cards are reported in the next run.
+        logger::error!(%process.id, ?error, "Failed while running card updater");
+        let db = &*state.store;
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<CardUpdaterTrackingData>("CardUpdaterTrackingData")
+        {
+            Ok(tracking_data) => schedule_next_run(db, process, tracking_data).await,
+            Err(_) => consumer::consumer_error_handler(db.as_scheduler(), process, error).await,
+        }
+    }
+}
+
+async fn schedule_next_run(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: CardUpdaterTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    let now = common_utils::date_time::now();
+    let schedule_time = now.saturating_add(time::Duration::hours(
+        tracking_data.run_interval_in_hours.into(),
+    ));
+    let tracking_data = serde_json::to_value(&tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(storage::enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(1, router_env::metric_attributes!(("flow", "CardUpdater")));
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::customers::data_subject,
+    db::StorageInterface,
+    errors as router_errors,
+    routes::{metrics, SessionState},
+    types::storage::{self, DataRetentionTrackingData},
+};
+
+pub struct DataRetentionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataRetentionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DataRetentionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DataRetentionTrackingData")?;
+
+        let redacted_until = data_subject::run_data_retention(state, &tracking_data)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to run data retention");
+                errors::ProcessTrackerError::EApiErrorResponse
+            })?;
+
+        schedule_next_run(
+            db,
+            process,
+            DataRetentionTrackingData {
+                redacted_until: Some(redacted_until),
+                ..tracking_data
+            },
+        )
+        .await
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> router_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // A failed run must not stop data from being redacted in the next run. The time up to
+        // which payments were redacted is left unchanged, so that the next run covers this window.
+        logger::error!(%process.id, ?error, "Failed while running data retention");
+        let db = &*state.store;
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<DataRetentionTrackingData>("DataRetentionTrackingData")
+        {
+            Ok(tracking_data) => schedule_next_run(db, process, tracking_data).await,
+            Err(_) => consumer::consumer_error_handler(db.as_scheduler(), process, error).await,
+        }
+    }
+}
+
+async fn schedule_next_run(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: DataRetentionTrackingData,
+) -> Result<(), errors::ProcessTrackerError> {
+    let now = common_utils::date_time::now();
+    let schedule_time = now.saturating_add(time::Duration::hours(
+        consts::DATA_RETENTION_RUN_INTERVAL_IN_HOURS.into(),
+    ));
+    let tracking_data = serde_json::to_value(&tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    db.as_scheduler()
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                tracking_data: Some(tracking_data),
+                business_status: None,
+                status: Some(storage::enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await?;
+    metrics::TASKS_RESET_COUNT.add(1, router_env::metric_attributes!(("flow", "DataRetention")));
+
+    Ok(())
+}
@@ -368,6 +368,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::card_updater,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -569,5 +570,32 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &(&state).into(),
+                    merchant_context.get_merchant_key_store(),
+                    &tracking_data.primary_object_id,
+                    merchant_context.get_merchant_account().storage_scheme,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to fetch payment method");
+                    errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    }
+                })?;
+            let payment_method_response =
+                card_updater::get_payment_method_webhook_response(&payment_method);
+
+            // Payment method events do not correspond to a status of the payment method, the
+            // current details of the payment method are delivered for the original event
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+                Some(tracking_data.event_type),
+            ))
+        }
     }
 }
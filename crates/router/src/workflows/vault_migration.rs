@@ -0,0 +1,79 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::locker_migration::vault_migration,
+    errors as router_errors,
+    routes::SessionState,
+    types::storage::{self, VaultMigrationTrackingData},
+};
+
+pub struct VaultMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for VaultMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: VaultMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VaultMigrationTrackingData")?;
+
+        let (tracking_data, is_complete) =
+            vault_migration::run_vault_migration_batch(state, tracking_data)
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to run vault migration batch");
+                    errors::ProcessTrackerError::EApiErrorResponse
+                })?;
+
+        // The tracking data is stored after every batch, so that the migration can be resumed
+        // from the next batch if it is interrupted
+        let now = common_utils::date_time::now();
+        let tracking_data = serde_json::to_value(&tracking_data)
+            .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+        let process = db
+            .as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(now),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage::enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await?;
+
+        if is_complete {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> router_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // The migration is left in the failed state, and is resumed from the last completed batch
+        // when it is started again
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
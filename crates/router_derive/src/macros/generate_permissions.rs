@@ -72,6 +72,7 @@ pub fn generate_permissions_inner(input: TokenStream) -> TokenStream {
     let mut resource_impl_per = Vec::new();
 
     let mut entity_impl_res = Vec::new();
+    let mut scope_impl_res = Vec::new();
 
     for per in res {
         let resource_name = &per.resource_name;
@@ -91,6 +92,9 @@ pub fn generate_permissions_inner(input: TokenStream) -> TokenStream {
             entity_impl_res
                 .push(quote! { Resource::#resource_name => vec![#(EntityType::#entities_iter),*] });
         }
+        let scopes_iter = per.scopes.iter();
+        scope_impl_res
+            .push(quote! { Resource::#resource_name => vec![#(PermissionScope::#scopes_iter),*] });
     }
 
     let expanded = quote! {
@@ -121,6 +125,7 @@ pub fn generate_permissions_inner(input: TokenStream) -> TokenStream {
 
         pub trait ResourceExt {
             fn entities(&self) -> Vec<EntityType>;
+            fn scopes(&self) -> Vec<PermissionScope>;
         }
 
         impl ResourceExt for Resource {
@@ -129,6 +134,11 @@ pub fn generate_permissions_inner(input: TokenStream) -> TokenStream {
                     #(#entity_impl_res),*
                 }
             }
+            fn scopes(&self) -> Vec<PermissionScope> {
+                match self {
+                    #(#scope_impl_res),*
+                }
+            }
         }
     };
     expanded.into()
@@ -104,6 +104,18 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers data export flow.
+    CustomersDataExport,
+    /// Customers data export retrieve flow.
+    CustomersDataExportRetrieve,
+    /// Customers merge flow.
+    CustomersMerge,
+    /// Data retention configure flow.
+    DataRetentionConfigure,
+    /// Data retention retrieve flow.
+    DataRetentionRetrieve,
+    /// Data retention disable flow.
+    DataRetentionDisable,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -120,10 +132,14 @@ pub enum Flow {
     PaymentMethodsMigrate,
     /// Payment methods list flow.
     PaymentMethodsList,
+    /// Payment method eligibility flow.
+    PaymentMethodEligibility,
     /// Payment method save flow
     PaymentMethodSave,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
+    /// Customer duplicate payment methods merge flow.
+    DuplicatePaymentMethodsMerge,
     /// Payment methods token data get flow.
     GetPaymentMethodTokenData,
     /// List Customers for a merchant
@@ -140,6 +156,12 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Card updater configure flow.
+    CardUpdaterConfigure,
+    /// Card updater retrieve flow.
+    CardUpdaterRetrieve,
+    /// Card updater disable flow.
+    CardUpdaterDisable,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -241,6 +263,10 @@ pub enum Flow {
     RoutingLinkConfig,
     /// Routing link config
     RoutingUnlinkConfig,
+    /// Routing link shadow config
+    RoutingLinkShadowConfig,
+    /// Routing unlink shadow config
+    RoutingUnlinkShadowConfig,
     /// Routing retrieve config
     RoutingRetrieveConfig,
     /// Routing retrieve active config
@@ -337,6 +363,14 @@ pub enum Flow {
     Verification,
     /// Rust locker migration
     RustLockerMigration,
+    /// Vault migration start flow
+    VaultMigrationStart,
+    /// Vault migration retrieve flow
+    VaultMigrationRetrieve,
+    /// Vault migration entries list flow
+    VaultMigrationEntriesList,
+    /// Vault migration rollback flow
+    VaultMigrationRollback,
     /// Gsm Rule Creation flow
     GsmRuleCreate,
     /// Gsm Rule Retrieve flow
@@ -403,6 +437,10 @@ pub enum Flow {
     GetRole,
     /// Get parent info for role
     GetRoleV2,
+    /// Export role as a template
+    ExportRoleTemplate,
+    /// Import role template as a custom role
+    ImportRoleTemplate,
     /// Get role from token
     GetRoleFromToken,
     /// Get resources and groups for role from token
@@ -485,6 +523,54 @@ pub enum Flow {
     TerminateTwoFactorAuth,
     /// Check 2FA status
     TwoFactorAuthStatus,
+    /// Begin passkey registration
+    PasskeyRegisterBegin,
+    /// Register passkey
+    PasskeyRegister,
+    /// Begin passkey verification
+    PasskeyVerifyBegin,
+    /// Verify passkey
+    PasskeyVerify,
+    /// List passkeys
+    ListPasskeys,
+    /// Delete passkey
+    DeletePasskey,
+    /// Begin signin with passkey
+    PasskeySignInBegin,
+    /// Signin with passkey
+    PasskeySignIn,
+    /// Create SCIM token
+    CreateScimToken,
+    /// List SCIM tokens
+    ListScimTokens,
+    /// Delete SCIM token
+    DeleteScimToken,
+    /// SCIM service provider config
+    ScimServiceProviderConfig,
+    /// SCIM list users
+    ScimListUsers,
+    /// SCIM create user
+    ScimCreateUser,
+    /// SCIM get user
+    ScimGetUser,
+    /// SCIM replace user
+    ScimReplaceUser,
+    /// SCIM patch user
+    ScimPatchUser,
+    /// SCIM delete user
+    ScimDeleteUser,
+    /// SCIM list groups
+    ScimListGroups,
+    /// SCIM create group
+    ScimCreateGroup,
+    /// SCIM get group
+    ScimGetGroup,
+    /// SCIM replace group
+    ScimReplaceGroup,
+    /// SCIM patch group
+    ScimPatchGroup,
+    /// SCIM delete group
+    ScimDeleteGroup,
     /// Create user authentication method
     CreateUserAuthenticationMethod,
     /// Update user authentication method
@@ -535,6 +621,14 @@ pub enum Flow {
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
     RefundsManualUpdate,
+    /// Approve a refund that is pending approval
+    RefundsApprove,
+    /// Reject a refund that is pending approval
+    RefundsReject,
+    /// Approve a payout that is pending approval
+    PayoutsApprove,
+    /// Reject a payout that is pending approval
+    PayoutsReject,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
     /// Dynamic Tax Calcultion
@@ -560,6 +654,8 @@ pub enum Flow {
     TokenizeCardBatch,
     /// Incoming Relay Webhook Receive
     IncomingRelayWebhookReceive,
+    /// Incoming network token lifecycle webhook receive
+    IncomingNetworkTokenWebhookReceive,
     /// Generate Hypersense Token
     HypersenseTokenRequest,
     /// Verify Hypersense Token
@@ -600,6 +696,28 @@ pub enum Flow {
     ProfileAcquirerUpdate,
     /// ThreeDs Decision Rule Execute flow
     ThreeDsDecisionRuleExecute,
+    /// Get change approval settings of the organization
+    GetChangeApprovalSettings,
+    /// Update change approval settings of the organization
+    UpdateChangeApprovalSettings,
+    /// Create change request flow
+    CreateChangeRequest,
+    /// List change requests flow
+    ListChangeRequests,
+    /// Get change request flow
+    GetChangeRequest,
+    /// Approve change request flow
+    ApproveChangeRequest,
+    /// Reject change request flow
+    RejectChangeRequest,
+    /// Create or update connector fee schedule flow
+    ConnectorFeeScheduleUpsert,
+    /// Retrieve connector fee schedule flow
+    ConnectorFeeScheduleRetrieve,
+    /// List connector fee schedules flow
+    ConnectorFeeScheduleList,
+    /// Delete connector fee schedule flow
+    ConnectorFeeScheduleDelete,
 }
 
 /// Trait for providing generic behaviour to flow metric
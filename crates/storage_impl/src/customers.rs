@@ -263,6 +263,29 @@ impl<T: DatabaseStore> domain::CustomerInterface for kv_router_store::KVRouterSt
             .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_unredacted_customers_by_merchant_id_modified_before(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
+        modified_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, StorageError> {
+        self.router_store
+            .list_unredacted_customers_by_merchant_id_modified_before(
+                state,
+                merchant_id,
+                key_store,
+                modified_before,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn insert_customer(
@@ -631,6 +654,32 @@ impl<T: DatabaseStore> domain::CustomerInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn list_unredacted_customers_by_merchant_id_modified_before(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
+        modified_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            customers::Customer::list_unredacted_by_merchant_id_modified_before(
+                &conn,
+                merchant_id,
+                modified_before,
+                starting_after_id,
+                limit,
+            ),
+        )
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn insert_customer(
         &self,
@@ -789,6 +838,55 @@ impl domain::CustomerInterface for MockDb {
         Ok(customers)
     }
 
+    #[cfg(feature = "v1")]
+    async fn list_unredacted_customers_by_merchant_id_modified_before(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        key_store: &MerchantKeyStore,
+        modified_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&id_type::CustomerId>,
+        limit: i64,
+    ) -> CustomResult<Vec<domain::Customer>, StorageError> {
+        let mut customers = self
+            .customers
+            .lock()
+            .await
+            .iter()
+            .filter(|customer| {
+                customer.merchant_id == *merchant_id
+                    && customer.modified_at < modified_before
+                    && customer.phone_country_code.as_deref() != Some(pii::REDACTED)
+                    && starting_after_id.map_or(true, |starting_after_id| {
+                        customer.customer_id.get_string_repr() > starting_after_id.get_string_repr()
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        customers.sort_by(|a, b| {
+            a.customer_id
+                .get_string_repr()
+                .cmp(b.customer_id.get_string_repr())
+        });
+
+        try_join_all(
+            customers
+                .into_iter()
+                .take(usize::try_from(limit).unwrap_or(0))
+                .map(|customer| async {
+                    customer
+                        .convert(
+                            state,
+                            key_store.key.get_inner(),
+                            key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(StorageError::DecryptionError)
+                }),
+        )
+        .await
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn update_customer_by_customer_id_merchant_id(
@@ -65,6 +65,18 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    pub user_webauthn_credentials:
+        Arc<Mutex<Vec<store::user::webauthn_credential::UserWebauthnCredential>>>,
+    pub scim_tokens: Arc<Mutex<Vec<store::user::scim::ScimToken>>>,
+    pub scim_users: Arc<Mutex<Vec<store::user::scim::ScimUser>>>,
+    pub scim_groups: Arc<Mutex<Vec<store::user::scim::ScimGroup>>>,
+    pub change_requests: Arc<Mutex<Vec<store::change_request::ChangeRequest>>>,
+    pub analytics_alert_rules: Arc<Mutex<Vec<store::analytics_alert_rule::AnalyticsAlertRule>>>,
+    pub connector_fee_schedules:
+        Arc<Mutex<Vec<store::connector_fee_schedule::ConnectorFeeSchedule>>>,
+    pub vault_migration_entries: Arc<Mutex<Vec<store::vault_migration_entry::VaultMigrationEntry>>>,
+    pub customer_merge_redirects:
+        Arc<Mutex<Vec<store::customer_merge_redirect::CustomerMergeRedirect>>>,
 }
 
 impl MockDb {
@@ -113,6 +125,15 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            user_webauthn_credentials: Default::default(),
+            scim_tokens: Default::default(),
+            scim_users: Default::default(),
+            scim_groups: Default::default(),
+            change_requests: Default::default(),
+            analytics_alert_rules: Default::default(),
+            connector_fee_schedules: Default::default(),
+            vault_migration_entries: Default::default(),
+            customer_merge_redirects: Default::default(),
         })
     }
 
@@ -157,6 +157,17 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempts_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<(), StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(StorageError::MockDbError)?
+    }
+
     #[cfg(feature = "v1")]
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
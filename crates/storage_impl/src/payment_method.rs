@@ -325,6 +325,97 @@ impl<T: DatabaseStore> PaymentMethodInterface for KVRouterStore<T> {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_list_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_payment_method_list_by_merchant_id_payment_method_status(
+                state,
+                key_store,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    // not supported in kv
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_card_payment_method_list_expiring_before(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        expires_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_card_payment_method_list_expiring_before(
+                state,
+                key_store,
+                merchant_id,
+                expires_before,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
+    // not supported in kv
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_card_payment_method_list_starting_after(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        self.router_store
+            .find_card_payment_method_list_starting_after(
+                state,
+                key_store,
+                merchant_id,
+                starting_after_id,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        self.router_store
+            .find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+                state,
+                key_store,
+                merchant_id,
+                network_token_requestor_reference_id,
+            )
+            .await
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
@@ -617,6 +708,107 @@ impl<T: DatabaseStore> PaymentMethodInterface for RouterStore<T> {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_list_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_payment_method_status(
+                &conn,
+                merchant_id,
+                payment_method,
+                status,
+                limit,
+                offset,
+            ),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_card_payment_method_list_expiring_before(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        expires_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_cards_by_merchant_id_expiring_before(
+                &conn,
+                merchant_id,
+                expires_before,
+                starting_after_id,
+                limit,
+            ),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_card_payment_method_list_starting_after(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.find_resources(
+            state,
+            key_store,
+            PaymentMethod::find_cards_by_merchant_id_starting_after(
+                &conn,
+                merchant_id,
+                starting_after_id,
+                limit,
+            ),
+        )
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        self.call_database(
+            state,
+            key_store,
+            PaymentMethod::find_by_merchant_id_network_token_requestor_reference_id(
+                &conn,
+                merchant_id,
+                network_token_requestor_reference_id,
+            ),
+        )
+        .await
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
@@ -871,6 +1063,132 @@ impl PaymentMethodInterface for MockDb {
         .await
     }
 
+    #[cfg(feature = "v1")]
+    #[allow(clippy::too_many_arguments)]
+    async fn find_payment_method_list_by_merchant_id_payment_method_status(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let payment_methods = self
+            .get_resources(
+                state,
+                key_store,
+                payment_methods,
+                |pm| {
+                    pm.merchant_id == *merchant_id
+                        && pm.payment_method == Some(payment_method)
+                        && pm.status == status
+                },
+                "cannot find payment method".to_string(),
+            )
+            .await?;
+        Ok(payment_methods
+            .into_iter()
+            .skip(usize::try_from(offset).unwrap_or_default())
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_expiring_before(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        expires_before: time::PrimitiveDateTime,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods = self
+            .get_resources(
+                state,
+                key_store,
+                payment_methods,
+                |pm| {
+                    pm.merchant_id == *merchant_id
+                        && pm.payment_method == Some(common_enums::PaymentMethod::Card)
+                        && pm.status == common_enums::PaymentMethodStatus::Active
+                        && pm
+                            .card_expires_at
+                            .map_or(true, |card_expires_at| card_expires_at < expires_before)
+                        && starting_after_id.map_or(true, |starting_after_id| {
+                            pm.payment_method_id.as_str() > starting_after_id
+                        })
+                },
+                "cannot find payment method".to_string(),
+            )
+            .await?;
+        payment_methods.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        Ok(payment_methods
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_card_payment_method_list_starting_after(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        starting_after_id: Option<&str>,
+        limit: i64,
+    ) -> CustomResult<Vec<DomainPaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods = self
+            .get_resources(
+                state,
+                key_store,
+                payment_methods,
+                |pm| {
+                    pm.merchant_id == *merchant_id
+                        && pm.payment_method == Some(common_enums::PaymentMethod::Card)
+                        && pm.status == common_enums::PaymentMethodStatus::Active
+                        && starting_after_id.map_or(true, |starting_after_id| {
+                            pm.payment_method_id.as_str() > starting_after_id
+                        })
+                },
+                "cannot find payment method".to_string(),
+            )
+            .await?;
+        payment_methods.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        Ok(payment_methods
+            .into_iter()
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_method_by_merchant_id_network_token_requestor_reference_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        network_token_requestor_reference_id: &str,
+    ) -> CustomResult<DomainPaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        self.get_resource::<PaymentMethod, _>(
+            state,
+            key_store,
+            payment_methods,
+            |pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.network_token_requestor_reference_id.as_deref()
+                        == Some(network_token_requestor_reference_id)
+            },
+            "cannot find payment method".to_string(),
+        )
+        .await
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_method_by_global_customer_id_merchant_id_status(
         &self,
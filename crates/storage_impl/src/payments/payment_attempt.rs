@@ -408,6 +408,24 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
             })
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn redact_payment_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        DieselPaymentAttempt::redact_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })
+            .map(|_| ())
+    }
+
     #[cfg(feature = "v1")]
     #[instrument(skip_all)]
     async fn find_payment_attempt_by_attempt_id_merchant_id(
@@ -1476,6 +1494,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    /// Attempts are redacted in the database only. Redaction runs on payments older than the
+    /// retention period, which have long been drained from the KV store.
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn redact_payment_attempts_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<(), errors::StorageError> {
+        self.router_store
+            .redact_payment_attempts_by_merchant_id_payment_id(
+                merchant_id,
+                payment_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(all(feature = "v1", feature = "olap"))]
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
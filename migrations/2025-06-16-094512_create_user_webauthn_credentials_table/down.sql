@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS user_webauthn_credentials_user_id_index;
+DROP TABLE IF EXISTS user_webauthn_credentials;
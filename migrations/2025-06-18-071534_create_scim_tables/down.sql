@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS scim_groups_org_id_display_name_index;
+DROP TABLE IF EXISTS scim_groups;
+
+DROP TABLE IF EXISTS scim_users;
+
+DROP INDEX IF EXISTS scim_tokens_org_id_index;
+DROP INDEX IF EXISTS scim_tokens_hashed_token_index;
+DROP TABLE IF EXISTS scim_tokens;
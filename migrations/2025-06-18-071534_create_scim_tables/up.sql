@@ -0,0 +1,39 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS scim_tokens (
+    token_id VARCHAR(64) PRIMARY KEY,
+    org_id VARCHAR(32) NOT NULL,
+    name VARCHAR(64) NOT NULL,
+    prefix VARCHAR(16) NOT NULL,
+    hashed_token VARCHAR(128) NOT NULL,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS scim_tokens_hashed_token_index ON scim_tokens (hashed_token);
+CREATE INDEX IF NOT EXISTS scim_tokens_org_id_index ON scim_tokens (org_id);
+
+CREATE TABLE IF NOT EXISTS scim_users (
+    org_id VARCHAR(32) NOT NULL,
+    user_id VARCHAR(64) NOT NULL,
+    external_id VARCHAR(255),
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (org_id, user_id)
+);
+
+CREATE TABLE IF NOT EXISTS scim_groups (
+    group_id VARCHAR(64) PRIMARY KEY,
+    org_id VARCHAR(32) NOT NULL,
+    display_name VARCHAR(255) NOT NULL,
+    external_id VARCHAR(255),
+    role_id VARCHAR(64) NOT NULL,
+    entity_type VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64),
+    profile_id VARCHAR(64),
+    members TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS scim_groups_org_id_display_name_index ON scim_groups (org_id, display_name);
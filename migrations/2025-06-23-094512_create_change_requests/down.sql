@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS change_requests_merchant_id_status_index;
+
+DROP TABLE IF EXISTS change_requests;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS change_requests (
+    change_request_id VARCHAR(64) PRIMARY KEY,
+    org_id VARCHAR(32) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    entity VARCHAR(64) NOT NULL,
+    request_payload BYTEA NOT NULL,
+    description VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    requested_by VARCHAR(64) NOT NULL,
+    decided_by VARCHAR(64),
+    decision_reason TEXT,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    expires_at TIMESTAMP NOT NULL,
+    decided_at TIMESTAMP,
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS change_requests_merchant_id_status_index ON change_requests (merchant_id, status);
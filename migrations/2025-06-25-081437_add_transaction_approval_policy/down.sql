@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS transaction_approval_policy;
+
+-- Enum values cannot be removed from a type without recreating it
+SELECT 1;
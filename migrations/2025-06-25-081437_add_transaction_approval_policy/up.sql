@@ -0,0 +1,13 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS transaction_approval_policy JSONB;
+
+ALTER TYPE "RefundStatus" ADD VALUE IF NOT EXISTS 'pending_approval';
+
+ALTER TYPE "PayoutStatus" ADD VALUE IF NOT EXISTS 'pending_approval';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_pending_approval';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_approved';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'refund_rejected';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_pending_approval';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_approved';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payout_rejected';
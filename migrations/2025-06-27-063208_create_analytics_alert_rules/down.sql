@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS analytics_alert_rules_merchant_id_index;
+
+DROP TABLE IF EXISTS analytics_alert_rules;
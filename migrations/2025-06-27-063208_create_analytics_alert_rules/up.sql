@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS analytics_alert_rules (
+    alert_rule_id VARCHAR(64) PRIMARY KEY,
+    org_id VARCHAR(32) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    name VARCHAR(64) NOT NULL,
+    description VARCHAR(255),
+    metric JSONB NOT NULL,
+    alert_condition JSONB NOT NULL,
+    evaluation_window_in_mins INTEGER NOT NULL,
+    evaluation_interval_in_mins INTEGER NOT NULL,
+    notification_channels JSONB NOT NULL,
+    enabled BOOLEAN NOT NULL DEFAULT TRUE,
+    state VARCHAR(32) NOT NULL,
+    last_observed_value DOUBLE PRECISION,
+    last_evaluated_at TIMESTAMP,
+    last_state_changed_at TIMESTAMP,
+    created_by VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS analytics_alert_rules_merchant_id_index ON analytics_alert_rules (merchant_id);
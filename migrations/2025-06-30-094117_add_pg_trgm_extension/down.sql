@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+-- The extension is not dropped, since it may have been created outside of the migrations and
+-- may be used by other objects in the database
+SELECT 1;
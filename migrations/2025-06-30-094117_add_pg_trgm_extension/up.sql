@@ -0,0 +1,13 @@
+-- Your SQL goes here
+-- pg_trgm is a trusted extension from Postgres 13, so it can be created by any role with the
+-- CREATE privilege on the database. Otherwise, it has to be created by a superuser before the
+-- migrations are run.
+DO $$
+BEGIN
+    CREATE EXTENSION IF NOT EXISTS pg_trgm;
+EXCEPTION
+    WHEN insufficient_privilege THEN
+        RAISE EXCEPTION 'Insufficient privilege to create the pg_trgm extension'
+            USING HINT = 'Run `CREATE EXTENSION IF NOT EXISTS pg_trgm;` on this database as a superuser, and run the migrations again';
+END
+$$;
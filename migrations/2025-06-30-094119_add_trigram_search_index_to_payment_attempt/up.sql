@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Indexes are created concurrently to avoid locking the table against writes, which cannot be done
+-- within a transaction
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payment_attempt_search_trgm_index ON payment_attempt USING GIN (payment_id gin_trgm_ops, attempt_id gin_trgm_ops, connector_transaction_id gin_trgm_ops);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Indexes are created concurrently to avoid locking the table against writes, which cannot be done
+-- within a transaction
+CREATE INDEX CONCURRENTLY IF NOT EXISTS refund_search_trgm_index ON refund USING GIN (refund_id gin_trgm_ops, payment_id gin_trgm_ops, connector_refund_id gin_trgm_ops);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Indexes are created concurrently to avoid locking the table against writes, which cannot be done
+-- within a transaction
+CREATE INDEX CONCURRENTLY IF NOT EXISTS dispute_search_trgm_index ON dispute USING GIN (dispute_id gin_trgm_ops, payment_id gin_trgm_ops, connector_dispute_id gin_trgm_ops);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Indexes are created concurrently to avoid locking the table against writes, which cannot be done
+-- within a transaction
+CREATE INDEX CONCURRENTLY IF NOT EXISTS payouts_search_trgm_index ON payouts USING GIN (payout_id gin_trgm_ops, customer_id gin_trgm_ops);
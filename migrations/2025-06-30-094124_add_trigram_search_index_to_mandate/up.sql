@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Indexes are created concurrently to avoid locking the table against writes, which cannot be done
+-- within a transaction
+CREATE INDEX CONCURRENTLY IF NOT EXISTS mandate_search_trgm_index ON mandate USING GIN (mandate_id gin_trgm_ops, customer_id gin_trgm_ops, connector_mandate_id gin_trgm_ops);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+-- Enum values cannot be removed from a type without recreating it
+SELECT 1;
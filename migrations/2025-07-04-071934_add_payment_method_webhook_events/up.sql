@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_expiring';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_expired';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS vault_migration_entries_migration_id_status_index;
+
+DROP TABLE IF EXISTS vault_migration_entries;
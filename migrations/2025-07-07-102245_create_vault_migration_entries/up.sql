@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS vault_migration_entries (
+    migration_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    source_locker_id VARCHAR(255) NOT NULL,
+    destination_locker_id VARCHAR(255),
+    status VARCHAR(32) NOT NULL,
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    modified_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (migration_id, payment_method_id)
+);
+
+CREATE INDEX IF NOT EXISTS vault_migration_entries_migration_id_status_index ON vault_migration_entries (migration_id, status);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customer_merge_redirects_merchant_id_target_customer_id_index;
+
+DROP TABLE IF EXISTS customer_merge_redirects;
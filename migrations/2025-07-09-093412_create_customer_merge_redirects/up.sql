@@ -0,0 +1,10 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS customer_merge_redirects (
+    merchant_id VARCHAR(64) NOT NULL,
+    source_customer_id VARCHAR(64) NOT NULL,
+    target_customer_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, source_customer_id)
+);
+
+CREATE INDEX IF NOT EXISTS customer_merge_redirects_merchant_id_target_customer_id_index ON customer_merge_redirects (merchant_id, target_customer_id);
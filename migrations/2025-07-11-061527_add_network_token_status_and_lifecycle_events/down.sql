@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+-- Enum values cannot be removed from a type without recreating it
+DROP INDEX IF EXISTS payment_methods_merchant_id_network_token_requestor_reference_id_index;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS network_token_status;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_methods_merchant_id_card_expires_at_index;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS card_expires_at;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods ADD COLUMN IF NOT EXISTS card_expires_at TIMESTAMP DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_card_expires_at_index ON payment_methods (merchant_id, card_expires_at);
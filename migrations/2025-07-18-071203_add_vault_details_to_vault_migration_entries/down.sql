@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE vault_migration_entries
+DROP COLUMN IF EXISTS source_connector_id,
+DROP COLUMN IF EXISTS destination_connector_id,
+DROP COLUMN IF EXISTS destination_reference_created;
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE vault_migration_entries
+ADD COLUMN IF NOT EXISTS source_connector_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS destination_connector_id VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS destination_reference_created BOOLEAN NOT NULL DEFAULT FALSE;